@@ -23,6 +23,7 @@ use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, NullBuffer};
 use arrow_data::bit_iterator::try_for_each_valid_idx;
 use arrow_schema::*;
+use num::ToPrimitive;
 use std::borrow::BorrowMut;
 use std::cmp::{self, Ordering};
 use std::ops::{BitAnd, BitOr, BitXor};
@@ -841,6 +842,386 @@ where
     aggregate::<T::Native, T, MaxAccumulator<T::Native>>(array)
 }
 
+/// Returns the non-null values of `array`
+fn valid_values<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>) -> Vec<T::Native> {
+    match array.nulls().filter(|n| n.null_count() > 0) {
+        None => array.values().to_vec(),
+        Some(nulls) => nulls.valid_indices().map(|i| array.value(i)).collect(),
+    }
+}
+
+/// Returns the median of `values`, reordering them in the process
+fn median_of<T: ArrowNativeTypeOp>(values: &mut [T], is_floating: bool) -> Option<T> {
+    if values.is_empty() {
+        return None;
+    }
+    let len = values.len();
+    let mid = len / 2;
+    let (lower, median, _) = values.select_nth_unstable_by(mid, |a, b| a.compare(*b));
+    let median = *median;
+    if len % 2 == 1 {
+        return Some(median);
+    }
+
+    // The other middle value is the largest of those ordered before `median`
+    let lower = lower.iter().copied().max_by(|a, b| a.compare(*b))?;
+    let two = T::ONE.add_wrapping(T::ONE);
+    let half_sum = lower
+        .div_wrapping(two)
+        .add_wrapping(median.div_wrapping(two));
+    if is_floating {
+        return Some(half_sum);
+    }
+    // Add back the remainders lost by halving each value separately, avoiding overflow,
+    // and then round any remaining half towards zero
+    let remainder = lower
+        .mod_wrapping(two)
+        .add_wrapping(median.mod_wrapping(two));
+    let mean = half_sum.add_wrapping(remainder.div_wrapping(two));
+    if remainder.is_eq(T::ONE) && mean.is_lt(T::ZERO) {
+        Some(mean.add_wrapping(T::ONE))
+    } else if remainder.is_eq(T::ONE.neg_wrapping()) && mean.is_gt(T::ZERO) {
+        Some(mean.sub_wrapping(T::ONE))
+    } else {
+        Some(mean)
+    }
+}
+
+/// Returns the most frequent of `values`, reordering them in the process
+fn mode_of<T: ArrowNativeTypeOp>(values: &mut [T]) -> Option<T> {
+    values.sort_unstable_by(|a, b| a.compare(*b));
+    let mut best: Option<(T, usize)> = None;
+    for run in values.chunk_by(|a, b| a.is_eq(*b)) {
+        if best.map(|(_, count)| run.len() > count).unwrap_or(true) {
+            best = Some((run[0], run.len()));
+        }
+    }
+    best.map(|(value, _)| value)
+}
+
+/// Returns the median of the non-null values in the array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+///
+/// The median is computed exactly, using a selection algorithm with expected linear
+/// run time. If there are an even number of values the result is the mean of the two
+/// middle values, which for integer and decimal types is truncated towards zero.
+/// Floating point values are ordered according to their total order, i.e. NaN values
+/// are considered greater than any other value.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_arith::aggregate::median;
+/// let array = Int32Array::from(vec![Some(5), None, Some(1), Some(3)]);
+/// assert_eq!(median(&array), Some(3));
+///
+/// let array = Int32Array::from(vec![4, 1, 2, 3]);
+/// assert_eq!(median(&array), Some(2));
+/// ```
+pub fn median<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    median_of(&mut valid_values(array), T::DATA_TYPE.is_floating())
+}
+
+/// Returns the most frequent non-null value in the array.
+///
+/// Returns `None` if the array is empty or only contains null values. If several
+/// values occur equally often, the smallest of them is returned.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_arith::aggregate::mode;
+/// let array = Int32Array::from(vec![Some(3), None, Some(1), None, Some(3), Some(1), Some(2)]);
+/// assert_eq!(mode(&array), Some(1));
+/// ```
+pub fn mode<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    mode_of(&mut valid_values(array))
+}
+
+/// The normalization applied by [`variance`] and [`stddev`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarianceType {
+    /// Divide the sum of squared deviations by the number of values `n`
+    Population,
+    /// Divide the sum of squared deviations by `n - 1`, i.e. apply Bessel's correction
+    Sample,
+}
+
+/// The state of [Welford's online algorithm] for computing the variance of a
+/// sequence of values in a single, numerically stable, pass.
+///
+/// Accumulators for disjoint sets of values can be combined with [`Self::merge`],
+/// for example to compute the variance of a column split across multiple batches.
+///
+/// ```
+/// # use arrow_arith::aggregate::{VarianceAccumulator, VarianceType};
+/// let mut a = VarianceAccumulator::new();
+/// a.update(1.0);
+/// a.update(2.0);
+///
+/// let mut b = VarianceAccumulator::new();
+/// b.update(3.0);
+///
+/// a.merge(&b);
+/// assert_eq!(a.count(), 3);
+/// assert_eq!(a.mean(), Some(2.0));
+/// assert_eq!(a.variance(VarianceType::Sample), Some(1.0));
+/// ```
+///
+/// [Welford's online algorithm]: https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct VarianceAccumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl VarianceAccumulator {
+    /// Create a new accumulator with no values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accumulate a value
+    pub fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Merge the values accumulated by `other` into this accumulator
+    pub fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 +=
+            other.m2 + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+        self.count = count;
+    }
+
+    /// Returns the number of accumulated values
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean of the accumulated values, or `None` if there are none
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the variance of the accumulated values
+    ///
+    /// Returns `None` if there are no values, or only one value and `kind` is
+    /// [`VarianceType::Sample`]
+    pub fn variance(&self, kind: VarianceType) -> Option<f64> {
+        let divisor = match kind {
+            VarianceType::Population => self.count,
+            VarianceType::Sample => self.count.checked_sub(1)?,
+        };
+        (divisor > 0).then(|| self.m2 / divisor as f64)
+    }
+
+    /// Returns the standard deviation of the accumulated values, see [`Self::variance`]
+    pub fn stddev(&self, kind: VarianceType) -> Option<f64> {
+        self.variance(kind).map(f64::sqrt)
+    }
+}
+
+fn variance_accumulator<T: ArrowNumericType>(array: &PrimitiveArray<T>) -> VarianceAccumulator
+where
+    T::Native: ToPrimitive,
+{
+    let mut acc = VarianceAccumulator::new();
+    array
+        .iter()
+        .flatten()
+        .for_each(|v| acc.update(v.to_f64().unwrap_or(f64::NAN)));
+    acc
+}
+
+/// Returns the variance of the non-null values in the array, computed in a single pass
+/// using [`VarianceAccumulator`].
+///
+/// Returns `None` if the array is empty or only contains null values, or contains a
+/// single non-null value and `kind` is [`VarianceType::Sample`]. For decimal arrays
+/// the variance of the unscaled values is returned.
+///
+/// ```
+/// # use arrow_array::Float64Array;
+/// # use arrow_arith::aggregate::{variance, VarianceType};
+/// let array = Float64Array::from(vec![Some(2.0), None, Some(4.0), Some(6.0)]);
+/// assert_eq!(variance(&array, VarianceType::Sample), Some(4.0));
+/// assert_eq!(variance(&array, VarianceType::Population), Some(8.0 / 3.0));
+/// ```
+pub fn variance<T: ArrowNumericType>(array: &PrimitiveArray<T>, kind: VarianceType) -> Option<f64>
+where
+    T::Native: ToPrimitive,
+{
+    variance_accumulator(array).variance(kind)
+}
+
+/// Returns the standard deviation of the non-null values in the array, see [`variance`]
+pub fn stddev<T: ArrowNumericType>(array: &PrimitiveArray<T>, kind: VarianceType) -> Option<f64>
+where
+    T::Native: ToPrimitive,
+{
+    variance_accumulator(array).stddev(kind)
+}
+
+/// Calls `f` with the group index and value of each non-null value in `array`,
+/// returning an error if `group_indices` is not valid for `array` and `num_groups`
+fn for_each_grouped<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    group_indices: &[usize],
+    num_groups: usize,
+    mut f: impl FnMut(usize, T::Native),
+) -> Result<(), ArrowError> {
+    if group_indices.len() != array.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected {} group indices, got {}",
+            array.len(),
+            group_indices.len()
+        )));
+    }
+    if let Some(group) = group_indices.iter().find(|g| **g >= num_groups) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Group index {group} is out of bounds for {num_groups} groups"
+        )));
+    }
+    for (idx, group) in group_indices.iter().enumerate() {
+        if array.is_valid(idx) {
+            f(*group, array.value(idx));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the non-null values of `array` partitioned into `num_groups` groups
+fn grouped_values<T: ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+    group_indices: &[usize],
+    num_groups: usize,
+) -> Result<Vec<Vec<T::Native>>, ArrowError> {
+    let mut groups = vec![vec![]; num_groups];
+    for_each_grouped(array, group_indices, num_groups, |group, value| {
+        groups[group].push(value)
+    })?;
+    Ok(groups)
+}
+
+/// Returns the median of the non-null values of each group, see [`median`]
+///
+/// The value at index `i` of `array` belongs to the group `group_indices[i]`, which must
+/// be less than `num_groups`. The returned array has length `num_groups`, with a null
+/// for each group without any non-null values.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_arith::aggregate::median_grouped;
+/// let array = Int32Array::from(vec![Some(1), Some(10), Some(3), None, Some(20)]);
+/// let result = median_grouped(&array, &[0, 1, 0, 2, 1], 3).unwrap();
+/// assert_eq!(result, Int32Array::from(vec![Some(2), Some(15), None]));
+/// ```
+pub fn median_grouped<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+    group_indices: &[usize],
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    let is_floating = T::DATA_TYPE.is_floating();
+    let groups = grouped_values(array, group_indices, num_groups)?;
+    Ok(groups
+        .into_iter()
+        .map(|mut values| median_of(&mut values, is_floating))
+        .collect::<PrimitiveArray<T>>()
+        .with_data_type(array.data_type().clone()))
+}
+
+/// Returns the most frequent non-null value of each group, see [`mode`] and [`median_grouped`]
+pub fn mode_grouped<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+    group_indices: &[usize],
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    let groups = grouped_values(array, group_indices, num_groups)?;
+    Ok(groups
+        .into_iter()
+        .map(|mut values| mode_of(&mut values))
+        .collect::<PrimitiveArray<T>>()
+        .with_data_type(array.data_type().clone()))
+}
+
+fn grouped_variance_accumulators<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+    group_indices: &[usize],
+    num_groups: usize,
+) -> Result<Vec<VarianceAccumulator>, ArrowError>
+where
+    T::Native: ToPrimitive,
+{
+    let mut accumulators = vec![VarianceAccumulator::new(); num_groups];
+    for_each_grouped(array, group_indices, num_groups, |group, value| {
+        accumulators[group].update(value.to_f64().unwrap_or(f64::NAN))
+    })?;
+    Ok(accumulators)
+}
+
+/// Returns the variance of the non-null values of each group, see [`variance`] and
+/// [`median_grouped`]
+///
+/// ```
+/// # use arrow_array::{Float64Array, Int32Array};
+/// # use arrow_arith::aggregate::{variance_grouped, VarianceType};
+/// let array = Int32Array::from(vec![1, 10, 3, 5, 20]);
+/// let result = variance_grouped(&array, &[0, 1, 0, 2, 1], 3, VarianceType::Sample).unwrap();
+/// assert_eq!(result, Float64Array::from(vec![Some(2.0), Some(50.0), None]));
+/// ```
+pub fn variance_grouped<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+    group_indices: &[usize],
+    num_groups: usize,
+    kind: VarianceType,
+) -> Result<Float64Array, ArrowError>
+where
+    T::Native: ToPrimitive,
+{
+    let accumulators = grouped_variance_accumulators(array, group_indices, num_groups)?;
+    Ok(accumulators.iter().map(|a| a.variance(kind)).collect())
+}
+
+/// Returns the standard deviation of the non-null values of each group, see [`stddev`]
+/// and [`median_grouped`]
+pub fn stddev_grouped<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+    group_indices: &[usize],
+    num_groups: usize,
+    kind: VarianceType,
+) -> Result<Float64Array, ArrowError>
+where
+    T::Native: ToPrimitive,
+{
+    let accumulators = grouped_variance_accumulators(array, group_indices, num_groups)?;
+    Ok(accumulators.iter().map(|a| a.stddev(kind)).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1748,4 +2129,139 @@ mod tests {
         sum_checked(&a).expect_err("overflow should be detected");
         sum_array_checked::<Int32Type, _>(&a).expect_err("overflow should be detected");
     }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&Int32Array::from(vec![3, 1, 2])), Some(2));
+        assert_eq!(median(&Int32Array::from(vec![4, 1, 3, 2])), Some(2));
+        assert_eq!(median(&Int32Array::from(vec![-4, -1, -3, -2])), Some(-2));
+        assert_eq!(
+            median(&Int32Array::from(vec![i32::MAX, i32::MAX])),
+            Some(i32::MAX)
+        );
+        assert_eq!(median(&Int32Array::from(vec![i32::MIN, i32::MAX])), Some(0));
+        assert_eq!(median(&Int32Array::from(vec![-3, 4])), Some(0));
+        assert_eq!(median(&Int32Array::from(vec![-4, 1])), Some(-1));
+        assert_eq!(median(&Int32Array::from(vec![-5, -2])), Some(-3));
+        assert_eq!(median(&Int32Array::from(vec![2, 5])), Some(3));
+        assert_eq!(
+            median(&Float64Array::from(vec![4.0, 1.0, 3.0, 2.0])),
+            Some(2.5)
+        );
+        assert_eq!(median(&Int32Array::from(vec![None, None])), None);
+        assert_eq!(median(&Int32Array::from(Vec::<i32>::new())), None);
+
+        let array = Int32Array::from(vec![Some(100), None, Some(1), Some(7), None]);
+        assert_eq!(median(&array), Some(7));
+        assert_eq!(median(&array.slice(1, 3)), Some(4));
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(mode(&Int64Array::from(vec![5, 1, 5, 2, 1, 5])), Some(5));
+        // ties are broken by choosing the smallest value
+        assert_eq!(mode(&Int64Array::from(vec![3, 2, 3, 2])), Some(2));
+        assert_eq!(
+            mode(&Float32Array::from(vec![
+                Some(0.5),
+                None,
+                None,
+                Some(0.5),
+                Some(1.0)
+            ])),
+            Some(0.5)
+        );
+        assert_eq!(mode(&Int64Array::from(vec![None])), None);
+    }
+
+    #[test]
+    fn test_variance() {
+        let array = Int32Array::from(vec![2, 4, 4, 4, 5, 5, 7, 9]);
+        assert_eq!(variance(&array, VarianceType::Population), Some(4.0));
+        assert_eq!(stddev(&array, VarianceType::Population), Some(2.0));
+        assert_eq!(variance(&array, VarianceType::Sample), Some(32.0 / 7.0));
+
+        let single = Float64Array::from(vec![Some(1.0), None]);
+        assert_eq!(variance(&single, VarianceType::Population), Some(0.0));
+        assert_eq!(variance(&single, VarianceType::Sample), None);
+        assert_eq!(
+            variance(&Float64Array::from(vec![None]), VarianceType::Population),
+            None
+        );
+
+        // large offsets do not lose precision as they would with the naive sum of squares
+        let array = Float64Array::from(vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+        assert_eq!(variance(&array, VarianceType::Sample), Some(30.0));
+    }
+
+    #[test]
+    fn test_variance_accumulator_merge() {
+        let values = [1.5, -2.0, 3.25, 8.0, 0.0, 4.5, 2.0];
+        let mut expected = VarianceAccumulator::new();
+        values.iter().for_each(|v| expected.update(*v));
+
+        let mut a = VarianceAccumulator::new();
+        let mut b = VarianceAccumulator::new();
+        values[..3].iter().for_each(|v| a.update(*v));
+        values[3..].iter().for_each(|v| b.update(*v));
+        a.merge(&b);
+        a.merge(&VarianceAccumulator::new());
+
+        assert_eq!(a.count(), expected.count());
+        let (actual, expected) = (
+            a.variance(VarianceType::Sample).unwrap(),
+            expected.variance(VarianceType::Sample).unwrap(),
+        );
+        assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+
+        let mut empty = VarianceAccumulator::new();
+        empty.merge(&b);
+        assert_eq!(empty, b);
+        assert_eq!(VarianceAccumulator::new().mean(), None);
+    }
+
+    #[test]
+    fn test_grouped() {
+        let array = Int32Array::from(vec![Some(1), Some(8), None, Some(3), Some(8), Some(5)]);
+        let groups = [0, 1, 2, 0, 1, 1];
+
+        let result = median_grouped(&array, &groups, 4).unwrap();
+        assert_eq!(result, Int32Array::from(vec![Some(2), Some(8), None, None]));
+
+        let result = mode_grouped(&array, &groups, 3).unwrap();
+        assert_eq!(result, Int32Array::from(vec![Some(1), Some(8), None]));
+
+        let result = variance_grouped(&array, &groups, 3, VarianceType::Population).unwrap();
+        assert_eq!(result, Float64Array::from(vec![Some(1.0), Some(2.0), None]));
+
+        let result = stddev_grouped(&array, &groups, 3, VarianceType::Sample).unwrap();
+        assert_eq!(result.value(0), 2.0_f64.sqrt());
+        assert!(result.is_null(2));
+    }
+
+    #[test]
+    fn test_grouped_preserves_data_type() {
+        let array = Decimal128Array::from(vec![100, 300, 200])
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        let result = median_grouped(&array, &[0, 0, 0], 1).unwrap();
+        assert_eq!(result.data_type(), &DataType::Decimal128(10, 2));
+        assert_eq!(result.value(0), 200);
+    }
+
+    #[test]
+    fn test_grouped_errors() {
+        let array = Int32Array::from(vec![1, 2]);
+        let err = median_grouped(&array, &[0], 1).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected 2 group indices, got 1"
+        );
+
+        let err = variance_grouped(&array, &[0, 2], 2, VarianceType::Sample).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Group index 2 is out of bounds for 2 groups"
+        );
+    }
 }
@@ -20,8 +20,10 @@ use crate::builder::{ArrayBuilder, GenericByteViewBuilder};
 use crate::iterator::ArrayIter;
 use crate::types::bytes::ByteArrayNativeType;
 use crate::types::{BinaryViewType, ByteViewType, StringViewType};
-use crate::{Array, ArrayAccessor, ArrayRef, GenericByteArray, OffsetSizeTrait, Scalar};
-use arrow_buffer::{ArrowNativeType, Buffer, NullBuffer, ScalarBuffer};
+use crate::{
+    Array, ArrayAccessor, ArrayRef, GenericByteArray, GenericStringArray, OffsetSizeTrait, Scalar,
+};
+use arrow_buffer::{ArrowNativeType, Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow_data::{ArrayData, ArrayDataBuilder, ByteView, MAX_INLINE_VIEW_LEN};
 use arrow_schema::{ArrowError, DataType};
 use core::str;
@@ -1027,6 +1029,163 @@ impl StringViewArray {
         unsafe { BinaryViewArray::new_unchecked(self.views, self.buffers, self.nulls) }
     }
 
+    /// Create a [`StringViewArray`] from a [`GenericStringArray`] without copying its string data
+    ///
+    /// The values buffer of `array` is reused as the single data buffer of the
+    /// new array, and only the views are created.
+    ///
+    /// Returns an error if the values buffer of `array` is too large to be
+    /// referenced by views, i.e. not smaller than `u32::MAX` bytes. Use
+    /// [`From`] to fall back to copying the data in that case.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow_array::{Array, StringArray, StringViewArray};
+    /// let array = StringArray::from(vec![Some("hello"), None, Some("a string longer than 12 bytes")]);
+    /// let view = StringViewArray::try_from_string_array_zero_copy(&array).unwrap();
+    /// assert_eq!(view.data_buffers()[0].as_ptr(), array.values().as_ptr());
+    /// assert_eq!(view.value(2), "a string longer than 12 bytes");
+    /// ```
+    pub fn try_from_string_array_zero_copy<O: OffsetSizeTrait + ToPrimitive>(
+        array: &GenericStringArray<O>,
+    ) -> Result<Self, ArrowError> {
+        let values_len = array.offsets().last().map_or(0, |o| o.as_usize());
+        if values_len >= u32::MAX as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot reference {values_len} bytes of string data from a StringViewArray without copying, the maximum is {}",
+                u32::MAX - 1
+            )));
+        }
+        Ok(Self::from(array))
+    }
+
+    /// Convert the [`StringViewArray`] to a [`GenericStringArray`]
+    ///
+    /// If the array has a single data buffer holding all of its values in
+    /// order, as is usually the case for arrays created with
+    /// [`Self::try_from_string_array_zero_copy`], that buffer is reused as the
+    /// values buffer without copying. Otherwise the values are compacted into a
+    /// new values buffer, similarly to [`Self::gc`].
+    ///
+    /// Returns an error if the values are too large for the offsets of `O`.
+    ///
+    /// # Example
+    /// ```
+    /// # use arrow_array::{Array, StringArray, StringViewArray};
+    /// let array = StringArray::from(vec![Some("hello"), None, Some("a string longer than 12 bytes")]);
+    /// let view = StringViewArray::try_from_string_array_zero_copy(&array).unwrap();
+    /// let round_trip: StringArray = view.to_string_array().unwrap();
+    /// assert_eq!(round_trip, array);
+    /// assert_eq!(round_trip.values().as_ptr(), array.values().as_ptr());
+    /// ```
+    pub fn to_string_array<O: OffsetSizeTrait>(&self) -> Result<GenericStringArray<O>, ArrowError> {
+        if let Some(array) = self.try_to_string_array_zero_copy() {
+            return Ok(array);
+        }
+
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        let mut values = Vec::with_capacity(self.total_bytes_len());
+        offsets.push(O::usize_as(0));
+        for i in 0..self.len() {
+            if self.is_valid(i) {
+                // Safety: i < self.len()
+                values.extend_from_slice(unsafe { self.value_unchecked(i) }.as_bytes());
+            }
+            offsets.push(Self::string_offset(values.len())?);
+        }
+
+        // Safety: the offsets are monotonically increasing, and each value is valid UTF-8
+        Ok(unsafe {
+            GenericStringArray::new_unchecked(
+                OffsetBuffer::new_unchecked(offsets.into()),
+                Buffer::from_vec(values),
+                self.nulls.clone(),
+            )
+        })
+    }
+
+    /// Returns the total length in bytes of the non-null values
+    fn total_bytes_len(&self) -> usize {
+        match &self.nulls {
+            Some(nulls) => nulls
+                .valid_indices()
+                .map(|i| self.views[i] as u32 as usize)
+                .sum(),
+            None => self.views.iter().map(|v| *v as u32 as usize).sum(),
+        }
+    }
+
+    fn string_offset<O: OffsetSizeTrait>(offset: usize) -> Result<O, ArrowError> {
+        O::from_usize(offset).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Offset overflow converting StringViewArray to {}StringArray, {offset} bytes of string data",
+                O::PREFIX
+            ))
+        })
+    }
+
+    /// Returns a [`GenericStringArray`] reusing the single data buffer of this
+    /// array, if its values are laid out contiguously and in order.
+    ///
+    /// Inlined values have no offset, so they are matched against the bytes
+    /// following the previous value. Gaps before a value are only allowed if
+    /// they can be assigned to preceding nulls.
+    fn try_to_string_array_zero_copy<O: OffsetSizeTrait>(&self) -> Option<GenericStringArray<O>> {
+        let [buffer] = self.buffers.as_slice() else {
+            return None;
+        };
+
+        let mut offsets = Vec::with_capacity(self.len() + 1);
+        offsets.push(0);
+        // Index in `offsets` of the end of the last non-null value
+        let mut last_valid_end = None;
+        let mut end = 0;
+        for (i, raw) in self.views.iter().enumerate() {
+            if self.is_null(i) {
+                offsets.push(end);
+                continue;
+            }
+            let view = ByteView::from(*raw);
+            let len = view.length as usize;
+            let start = if view.length <= MAX_INLINE_VIEW_LEN {
+                // Safety: the view is inlined
+                let inlined = unsafe { Self::inline_value(raw, len) };
+                (buffer.get(end..end + len)? == inlined).then_some(end)?
+            } else {
+                (view.buffer_index == 0).then_some(view.offset as usize)?
+            };
+            if start != end {
+                // Assign the gap to the preceding nulls, or skip it if this is the first value
+                let first = last_valid_end.map_or(0, |idx| idx + 1);
+                if start < end || first == offsets.len() {
+                    return None;
+                }
+                if last_valid_end.is_some() {
+                    std::str::from_utf8(&buffer[end..start]).ok()?;
+                }
+                offsets[first..].fill(start);
+            }
+            end = start + len;
+            offsets.push(end);
+            last_valid_end = Some(offsets.len() - 1);
+        }
+
+        let offsets = offsets
+            .into_iter()
+            .map(Self::string_offset)
+            .collect::<Result<Vec<O>, _>>()
+            .ok()?;
+        // Safety: the offsets are monotonically increasing and within `buffer`, each
+        // value is valid UTF-8, and so are the gaps between them
+        Some(unsafe {
+            GenericStringArray::new_unchecked(
+                OffsetBuffer::new_unchecked(offsets.into()),
+                buffer.clone(),
+                self.nulls.clone(),
+            )
+        })
+    }
+
     /// Returns true if all data within this array is ASCII
     pub fn is_ascii(&self) -> bool {
         // Alternative (but incorrect): directly check the underlying buffers
@@ -1071,9 +1230,10 @@ mod tests {
     use crate::builder::{BinaryViewBuilder, StringViewBuilder};
     use crate::types::BinaryViewType;
     use crate::{
-        Array, BinaryViewArray, GenericBinaryArray, GenericByteViewArray, StringViewArray,
+        Array, BinaryViewArray, GenericBinaryArray, GenericByteViewArray, LargeStringArray,
+        StringArray, StringViewArray,
     };
-    use arrow_buffer::{Buffer, ScalarBuffer};
+    use arrow_buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
     use arrow_data::{ByteView, MAX_INLINE_VIEW_LEN};
     use rand::prelude::StdRng;
     use rand::{Rng, SeedableRng};
@@ -1392,6 +1552,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_string_array_round_trip_zero_copy() {
+        let array = StringArray::from(vec![
+            None,
+            Some("a string longer than 12 bytes"),
+            Some("short"),
+            None,
+            Some(""),
+            Some("another string longer than 12 bytes"),
+            None,
+        ]);
+        // The position of a leading inlined value is unknown, so the last slice is copied
+        let cases = [
+            (array.clone(), true),
+            (array.slice(1, 5), true),
+            (array.slice(2, 4), false),
+        ];
+        for (array, zero_copy) in cases {
+            let view = StringViewArray::try_from_string_array_zero_copy(&array).unwrap();
+            assert_eq!(view.data_buffers().len(), 1);
+            assert_eq!(view.data_buffers()[0].as_ptr(), array.values().as_ptr());
+            assert!(view.iter().eq(array.iter()));
+
+            let round_trip: StringArray = view.to_string_array().unwrap();
+            assert!(round_trip.iter().eq(array.iter()));
+            assert_eq!(
+                round_trip.values().as_ptr() == array.values().as_ptr(),
+                zero_copy
+            );
+            round_trip.to_data().validate_full().unwrap();
+
+            let large: LargeStringArray = view.to_string_array().unwrap();
+            assert!(large.iter().eq(array.iter()));
+        }
+
+        // Data in the gaps of nulls is skipped
+        let values = b"the first value is long, skipped!the second value is long";
+        let offsets = OffsetBuffer::new(vec![0, 24, 33, 57].into());
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let array = StringArray::new(offsets, Buffer::from(values), Some(nulls));
+        let view = StringViewArray::try_from_string_array_zero_copy(&array).unwrap();
+        let round_trip: StringArray = view.to_string_array().unwrap();
+        assert_eq!(round_trip, array);
+        assert_eq!(round_trip.values().as_ptr(), array.values().as_ptr());
+    }
+
+    #[test]
+    fn test_to_string_array_copy() {
+        // Values in several buffers, or out of order, are copied
+        let mut builder = StringViewBuilder::new().with_fixed_block_size(BLOCK_SIZE);
+        builder.append_value("a string longer than 12 bytes");
+        builder.append_null();
+        builder.append_value("inline");
+        builder.append_value("another string longer than 12 bytes");
+        let array = builder.finish();
+        assert!(array.data_buffers().len() > 1);
+        let reversed = reverse_views(&array);
+
+        for array in [array, reversed] {
+            let string_array: StringArray = array.to_string_array().unwrap();
+            string_array.to_data().validate_full().unwrap();
+            assert!(string_array.iter().eq(array.iter()));
+            assert_eq!(
+                string_array.values().len(),
+                array.iter().flatten().map(str::len).sum::<usize>()
+            );
+        }
+
+        let empty = StringViewArray::from(Vec::<&str>::new());
+        let string_array: StringArray = empty.to_string_array().unwrap();
+        assert!(string_array.is_empty());
+    }
+
+    /// Returns a copy of `array` with its views in reverse order
+    fn reverse_views(array: &StringViewArray) -> StringViewArray {
+        let views = array.views().iter().rev().copied().collect();
+        let nulls = array
+            .nulls()
+            .map(|n| NullBuffer::from(n.iter().rev().collect::<Vec<_>>()));
+        StringViewArray::new(views, array.data_buffers().to_vec(), nulls)
+    }
+
     #[test]
     fn test_eq() {
         let test_data = [
@@ -16,12 +16,18 @@
 // under the License.
 
 use crate::array::{get_offsets, print_long_array};
+use crate::builder::MapFieldNames;
+use crate::cast::AsArray;
 use crate::iterator::MapArrayIter;
-use crate::{make_array, Array, ArrayAccessor, ArrayRef, ListArray, StringArray, StructArray};
+use crate::{
+    downcast_primitive_array, make_array, Array, ArrayAccessor, ArrayRef, ArrowNativeTypeOp,
+    ListArray, StringArray, StructArray,
+};
 use arrow_buffer::{ArrowNativeType, Buffer, NullBuffer, OffsetBuffer, ToByteSlice};
 use arrow_data::{ArrayData, ArrayDataBuilder};
 use arrow_schema::{ArrowError, DataType, Field, FieldRef};
 use std::any::Any;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 /// An array of key-value maps
@@ -341,6 +347,188 @@ impl MapArray {
 
         Ok(MapArray::from(map_data))
     }
+
+    /// Creates a [`MapArray`] from its `keys`, `values` and the `offsets` of the entries of
+    /// each map, validating the keys according to `validation`
+    ///
+    /// The fields of the map are named as in [`MapFieldNames::default`], and the values
+    /// field is nullable. The map is marked as sorted if `validation` is
+    /// [`MapKeyValidation::Sorted`].
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, MapArray, MapKeyValidation, StringArray};
+    /// # use arrow_buffer::OffsetBuffer;
+    /// // [{"a": 1, "b": 2}, {"c": 3}]
+    /// let keys: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+    /// let values: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    /// let offsets = OffsetBuffer::from_lengths([2, 1]);
+    /// let map = MapArray::try_new_from_arrays(
+    ///     keys.clone(),
+    ///     values.clone(),
+    ///     offsets,
+    ///     None,
+    ///     MapKeyValidation::Sorted,
+    /// )
+    /// .unwrap();
+    ///
+    /// let (map_keys, map_values) = map.entries_as::<StringArray, Int32Array>().unwrap();
+    /// assert_eq!(map_keys.value(2), "c");
+    /// assert_eq!(map_values.value(2), 3);
+    ///
+    /// // The keys of the first map are not unique
+    /// let offsets = OffsetBuffer::from_lengths([3]);
+    /// let keys: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+    /// let err = MapArray::try_new_from_arrays(keys, values, offsets, None, MapKeyValidation::Unique)
+    ///     .unwrap_err();
+    /// assert!(err.to_string().contains("duplicate key in map 0"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if `keys` and `values` have different lengths, if `keys` contain nulls, if the
+    /// keys do not pass `validation`, or if [`Self::try_new`] returns an error
+    ///
+    /// [`MapFieldNames::default`]: crate::builder::MapFieldNames
+    pub fn try_new_from_arrays(
+        keys: ArrayRef,
+        values: ArrayRef,
+        offsets: OffsetBuffer<i32>,
+        nulls: Option<NullBuffer>,
+        validation: MapKeyValidation,
+    ) -> Result<Self, ArrowError> {
+        if keys.len() != values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "MapArray keys and values must have the same length, got {} and {}",
+                keys.len(),
+                values.len()
+            )));
+        }
+        if keys.logical_null_count() != 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "MapArray keys cannot contain nulls".to_string(),
+            ));
+        }
+        let end_offset = offsets.last().unwrap().as_usize();
+        if end_offset > keys.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Max offset of {end_offset} exceeds length of entries {}",
+                keys.len()
+            )));
+        }
+        validate_map_keys(keys.as_ref(), &offsets, validation)?;
+
+        let names = MapFieldNames::default();
+        let keys_field = Arc::new(Field::new(names.key, keys.data_type().clone(), false));
+        let values_field = Arc::new(Field::new(names.value, values.data_type().clone(), true));
+        let entries = StructArray::try_new(
+            vec![keys_field, values_field].into(),
+            vec![keys, values],
+            None,
+        )?;
+        let field = Arc::new(Field::new(names.entry, entries.data_type().clone(), false));
+        let sorted = validation == MapKeyValidation::Sorted;
+        Self::try_new(field, offsets, entries, nulls, sorted)
+    }
+
+    /// Returns the keys and values of this map downcast to `K` and `V`, or `None` if they
+    /// are not of these types
+    ///
+    /// See [`Self::try_new_from_arrays`] for an example.
+    pub fn entries_as<K: Array + 'static, V: Array + 'static>(&self) -> Option<(&K, &V)> {
+        let keys = self.keys().as_any().downcast_ref()?;
+        let values = self.values().as_any().downcast_ref()?;
+        Some((keys, values))
+    }
+}
+
+/// The validation of the keys of each map performed by [`MapArray::try_new_from_arrays`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MapKeyValidation {
+    /// Only check that keys are not null
+    #[default]
+    NonNull,
+    /// Also check that the keys of each map are unique
+    Unique,
+    /// Also check that the keys of each map are unique and sorted in ascending order
+    Sorted,
+}
+
+/// Validates the keys of each map delimited by `offsets` according to `validation`
+fn validate_map_keys(
+    keys: &dyn Array,
+    offsets: &OffsetBuffer<i32>,
+    validation: MapKeyValidation,
+) -> Result<(), ArrowError> {
+    if validation == MapKeyValidation::NonNull {
+        return Ok(());
+    }
+    macro_rules! validate {
+        ($array:expr) => {{
+            let array = $array;
+            validate_map_keys_with(offsets, validation, |i, j| {
+                array.value(i).cmp(&array.value(j))
+            })
+        }};
+    }
+    downcast_primitive_array!(
+        keys => {
+            validate_map_keys_with(offsets, validation, |i, j| keys.value(i).compare(keys.value(j)))
+        }
+        DataType::Boolean => validate!(keys.as_boolean()),
+        DataType::Utf8 => validate!(keys.as_string::<i32>()),
+        DataType::LargeUtf8 => validate!(keys.as_string::<i64>()),
+        DataType::Utf8View => validate!(keys.as_string_view()),
+        DataType::Binary => validate!(keys.as_binary::<i32>()),
+        DataType::LargeBinary => validate!(keys.as_binary::<i64>()),
+        DataType::BinaryView => validate!(keys.as_binary_view()),
+        DataType::FixedSizeBinary(_) => validate!(keys.as_fixed_size_binary()),
+        t => Err(ArrowError::NotYetImplemented(format!(
+            "Validating MapArray keys of type {t} is not supported"
+        )))
+    )
+}
+
+/// Validates the keys of each map delimited by `offsets`, where `cmp` compares two keys
+fn validate_map_keys_with(
+    offsets: &OffsetBuffer<i32>,
+    validation: MapKeyValidation,
+    cmp: impl Fn(usize, usize) -> Ordering,
+) -> Result<(), ArrowError> {
+    let mut indices = vec![];
+    for (i, w) in offsets.windows(2).enumerate() {
+        let range = w[0].as_usize()..w[1].as_usize();
+        let ordering = match validation {
+            MapKeyValidation::Sorted => range
+                .clone()
+                .skip(1)
+                .map(|j| cmp(j - 1, j))
+                .find(|o| *o != Ordering::Less),
+            _ => {
+                indices.clear();
+                indices.extend(range);
+                indices.sort_unstable_by(|a, b| cmp(*a, *b));
+                indices
+                    .windows(2)
+                    .map(|w| cmp(w[0], w[1]))
+                    .find(|o| *o == Ordering::Equal)
+            }
+        };
+        match ordering {
+            None => {}
+            Some(Ordering::Equal) => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "MapArray keys must be unique, found a duplicate key in map {i}"
+                )))
+            }
+            Some(_) => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "MapArray keys must be sorted, found unsorted keys in map {i}"
+                )))
+            }
+        }
+    }
+    Ok(())
 }
 
 impl Array for MapArray {
@@ -811,4 +999,114 @@ mod tests {
             "Invalid argument error: MapArray entries must contain two children, got 3"
         );
     }
+
+    #[test]
+    fn test_try_new_from_arrays() {
+        let keys: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a", "c", "c"]));
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), None, None]));
+        let offsets = OffsetBuffer::from_lengths([2, 0, 2, 1]);
+        let nulls = NullBuffer::from(vec![true, false, true, true]);
+
+        let map = MapArray::try_new_from_arrays(
+            keys.clone(),
+            values.clone(),
+            offsets.clone(),
+            Some(nulls.clone()),
+            MapKeyValidation::Sorted,
+        )
+        .unwrap();
+        assert_eq!(map.len(), 4);
+        assert!(map.is_null(1));
+        assert_eq!(map.value_offsets(), &[0, 2, 2, 4, 5]);
+        let DataType::Map(field, sorted) = map.data_type() else {
+            unreachable!()
+        };
+        assert!(sorted);
+        assert_eq!(field.name(), "entries");
+        assert_eq!(map.entries().fields()[0].name(), "keys");
+        assert!(map.entries().fields()[1].is_nullable());
+        let (map_keys, map_values) = map.entries_as::<StringArray, Int32Array>().unwrap();
+        assert_eq!(map_keys, keys.as_string::<i32>());
+        assert_eq!(map_values.null_count(), 3);
+        assert!(map.entries_as::<Int32Array, Int32Array>().is_none());
+
+        let map = MapArray::try_new_from_arrays(
+            keys.clone(),
+            values.clone(),
+            offsets.clone(),
+            None,
+            MapKeyValidation::NonNull,
+        )
+        .unwrap();
+        assert!(!matches!(map.data_type(), DataType::Map(_, true)));
+
+        // Unsorted but unique keys
+        let unsorted: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 3, 5, 4]));
+        let offsets = OffsetBuffer::from_lengths([2, 3]);
+        MapArray::try_new_from_arrays(
+            unsorted.clone(),
+            values.clone(),
+            offsets.clone(),
+            None,
+            MapKeyValidation::Unique,
+        )
+        .unwrap();
+        let err = MapArray::try_new_from_arrays(
+            unsorted,
+            values.clone(),
+            offsets.clone(),
+            None,
+            MapKeyValidation::Sorted,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: MapArray keys must be sorted, found unsorted keys in map 0"
+        );
+
+        // Duplicate keys in the second map
+        let duplicates: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 4, 3, 4]));
+        for validation in [MapKeyValidation::Unique, MapKeyValidation::Sorted] {
+            let err = MapArray::try_new_from_arrays(
+                duplicates.clone(),
+                values.clone(),
+                offsets.clone(),
+                None,
+                validation,
+            )
+            .unwrap_err();
+            let expected = match validation {
+                MapKeyValidation::Sorted => "sorted, found unsorted keys",
+                _ => "unique, found a duplicate key",
+            };
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+
+        let null_keys: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, None, None, None]));
+        let err = MapArray::try_new_from_arrays(
+            null_keys,
+            values.clone(),
+            offsets.clone(),
+            None,
+            MapKeyValidation::NonNull,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: MapArray keys cannot contain nulls"
+        );
+
+        let err = MapArray::try_new_from_arrays(
+            keys.slice(0, 2),
+            values,
+            offsets,
+            None,
+            MapKeyValidation::NonNull,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: MapArray keys and values must have the same length, got 2 and 5"
+        );
+    }
 }
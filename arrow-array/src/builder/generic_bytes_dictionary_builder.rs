@@ -43,6 +43,9 @@ where
 
     keys_builder: PrimitiveBuilder<K>,
     values_builder: GenericByteBuilder<T>,
+
+    /// Whether the dictionary is frozen, see [`Self::freeze`]
+    frozen: bool,
 }
 
 impl<K, T> Default for GenericByteDictionaryBuilder<K, T>
@@ -69,6 +72,7 @@ where
             dedup: HashTable::with_capacity(keys_builder.capacity()),
             keys_builder,
             values_builder,
+            frozen: false,
         }
     }
 
@@ -87,6 +91,7 @@ where
             dedup: Default::default(),
             keys_builder: PrimitiveBuilder::with_capacity(keys_capacity),
             values_builder: GenericByteBuilder::<T>::with_capacity(value_capacity, data_capacity),
+            frozen: false,
         }
     }
 
@@ -153,6 +158,7 @@ where
             dedup,
             keys_builder: PrimitiveBuilder::with_capacity(keys_capacity),
             values_builder,
+            frozen: false,
         })
     }
 
@@ -194,6 +200,7 @@ where
         let state = source.state;
         let dedup = source.dedup;
         let values_builder = source.values_builder;
+        let frozen = source.frozen;
 
         let source_keys = source.keys_builder.finish();
         let new_keys: PrimitiveArray<K> = source_keys.try_unary(|value| {
@@ -218,6 +225,7 @@ where
                 .into_builder()
                 .expect("underlying buffer has no references"),
             values_builder,
+            frozen,
         })
     }
 }
@@ -271,6 +279,18 @@ where
         let storage = &mut self.values_builder;
         let hash = state.hash_one(value_bytes);
 
+        if self.frozen {
+            let idx = self
+                .dedup
+                .find(hash, |idx| value_bytes == get_bytes(storage, *idx))
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(
+                        "Cannot append value not in frozen dictionary".to_string(),
+                    )
+                })?;
+            return K::Native::from_usize(*idx).ok_or(ArrowError::DictionaryKeyOverflowError);
+        }
+
         let idx = *self
             .dedup
             .entry(
@@ -294,7 +314,8 @@ where
     /// if already present in the values array or a new index if the
     /// value is appended to the values array.
     ///
-    /// Returns an error if the new index would overflow the key type.
+    /// Returns an error if the new index would overflow the key type, or if the
+    /// dictionary is frozen and does not contain `value`, see [`Self::freeze`].
     pub fn append(&mut self, value: impl AsRef<T::Native>) -> Result<K::Native, ArrowError> {
         let key = self.get_or_insert_key(value)?;
         self.keys_builder.append_value(key);
@@ -304,7 +325,8 @@ where
     /// Append a value multiple times to the array.
     /// This is the same as `append` but allows to append the same value multiple times without doing multiple lookups.
     ///
-    /// Returns an error if the new index would overflow the key type.
+    /// Returns an error if the new index would overflow the key type, or if the
+    /// dictionary is frozen and does not contain `value`, see [`Self::freeze`].
     pub fn append_n(
         &mut self,
         value: impl AsRef<T::Native>,
@@ -319,7 +341,8 @@ where
     ///
     /// # Panics
     ///
-    /// Panics if the resulting length of the dictionary values array would exceed `T::Native::MAX`
+    /// Panics if the resulting length of the dictionary values array would exceed `T::Native::MAX`,
+    /// or if the dictionary is frozen and does not contain `value`
     pub fn append_value(&mut self, value: impl AsRef<T::Native>) {
         self.append(value).expect("dictionary key overflow");
     }
@@ -430,10 +453,59 @@ where
         Ok(())
     }
 
+    /// Freezes the dictionary of this builder, so that keys remain stable across batches.
+    ///
+    /// Once frozen, appending a value not already in the dictionary returns an error and
+    /// leaves the builder unchanged, allowing the caller to fall back to, for example,
+    /// appending a null or a placeholder value. [`Self::finish`] resets the keys but retains
+    /// the dictionary, so every array built contains the same dictionary values. This allows
+    /// writers to reuse the dictionary across batches, such as IPC writers avoiding dictionary
+    /// replacements or deltas.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use arrow_array::builder::StringDictionaryBuilder;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_array::{Int32Array, StringArray};
+    /// let dictionary = StringArray::from(vec!["red", "green", "blue"]);
+    /// let mut builder =
+    ///     StringDictionaryBuilder::<Int32Type>::new_with_dictionary(4, &dictionary).unwrap();
+    /// builder.freeze();
+    ///
+    /// for color in ["blue", "purple", "red", "blue"] {
+    ///     if builder.append(color).is_err() {
+    ///         builder.append_null();
+    ///     }
+    /// }
+    /// let first = builder.finish();
+    /// assert_eq!(first.keys(), &Int32Array::from(vec![Some(2), None, Some(0), Some(2)]));
+    ///
+    /// builder.append_value("green");
+    /// let second = builder.finish();
+    /// assert_eq!(second.keys(), &Int32Array::from(vec![1]));
+    /// assert_eq!(first.values(), second.values());
+    /// ```
+    pub fn freeze(&mut self) {
+        self.frozen = true;
+    }
+
+    /// Returns true if the dictionary of this builder is frozen, see [`Self::freeze`]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen
+    }
+
     /// Builds the `DictionaryArray` and reset this builder.
+    ///
+    /// If the dictionary is frozen, only the keys are reset, see [`Self::freeze`]
     pub fn finish(&mut self) -> DictionaryArray<K> {
-        self.dedup.clear();
-        let values = self.values_builder.finish();
+        let values = match self.frozen {
+            true => self.values_builder.finish_cloned(),
+            false => {
+                self.dedup.clear();
+                self.values_builder.finish()
+            }
+        };
         let keys = self.keys_builder.finish();
 
         let data_type = DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(T::DATA_TYPE));
@@ -1006,4 +1078,50 @@ mod tests {
 
         assert_eq!(values, [None, None]);
     }
+
+    #[test]
+    fn test_frozen_dictionary() {
+        let dictionary = StringArray::from(vec!["a", "b", "c"]);
+        let mut builder =
+            GenericByteDictionaryBuilder::<Int8Type, Utf8Type>::new_with_dictionary(3, &dictionary)
+                .unwrap();
+        builder.append_value("d");
+        builder.freeze();
+        assert!(builder.is_frozen());
+
+        assert_eq!(builder.append("c").unwrap(), 2);
+        assert_eq!(builder.append_n("d", 2).unwrap(), 3);
+        let err = builder.append("e").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot append value not in frozen dictionary"
+        );
+        let first = builder.finish();
+        assert_eq!(first.keys(), &Int8Array::from(vec![3, 2, 3, 3]));
+        assert_eq!(
+            first.values().as_string::<i32>(),
+            &StringArray::from(vec!["a", "b", "c", "d"])
+        );
+
+        // Unseen values in the extended dictionary fail without appending any keys
+        let other = StringArray::from(vec!["b", "e"]);
+        let other = DictionaryArray::new(Int8Array::from(vec![0, 0]), Arc::new(other));
+        let err = builder.extend_dictionary(&other.downcast_dict().unwrap());
+        assert!(err.is_err());
+        assert_eq!(builder.len(), 0);
+
+        builder.append_value("a");
+        builder.append_null();
+        let second = builder.finish();
+        assert_eq!(second.keys(), &Int8Array::from(vec![Some(0), None]));
+        assert_eq!(first.values(), second.values());
+
+        // Changing the key type retains the frozen dictionary
+        let mut builder =
+            GenericByteDictionaryBuilder::<Int16Type, Utf8Type>::try_new_from_builder(builder)
+                .unwrap();
+        assert!(builder.is_frozen());
+        assert!(builder.append("e").is_err());
+        assert_eq!(builder.append("b").unwrap(), 1);
+    }
 }
@@ -63,6 +63,7 @@ pub struct MapBuilder<K: ArrayBuilder, V: ArrayBuilder> {
     value_builder: V,
     key_field: Option<FieldRef>,
     value_field: Option<FieldRef>,
+    keys_sorted: bool,
 }
 
 /// The [`Field`] names for a [`MapArray`]
@@ -110,6 +111,7 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             value_builder,
             key_field: None,
             value_field: None,
+            keys_sorted: false,
         }
     }
 
@@ -139,6 +141,17 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
         }
     }
 
+    /// Sets whether the keys of each map are sorted, see [`DataType::Map`]
+    ///
+    /// Defaults to `false`. The builder does not check that the keys are sorted, use
+    /// [`MapArray::try_new_from_arrays`] to build a validated map.
+    pub fn with_keys_sorted(self, keys_sorted: bool) -> Self {
+        Self {
+            keys_sorted,
+            ..self
+        }
+    }
+
     /// Returns the key array builder of the map
     pub fn keys(&mut self) -> &mut K {
         &mut self.key_builder
@@ -237,7 +250,7 @@ impl<K: ArrayBuilder, V: ArrayBuilder> MapBuilder<K, V> {
             struct_array.data_type().clone(),
             false, // always non-nullable
         ));
-        let array_data = ArrayData::builder(DataType::Map(map_field, false)) // TODO: support sorted keys
+        let array_data = ArrayData::builder(DataType::Map(map_field, self.keys_sorted))
             .len(len)
             .add_buffer(offset_buffer)
             .add_child_data(struct_array.into_data())
@@ -284,8 +297,10 @@ impl<K: ArrayBuilder, V: ArrayBuilder> ArrayBuilder for MapBuilder<K, V> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::builder::{make_builder, Int32Builder, StringBuilder};
-    use crate::{Int32Array, StringArray};
+    use crate::builder::{make_builder, Int32Builder, ListBuilder, StringBuilder};
+    use crate::cast::AsArray;
+    use crate::types::Int32Type;
+    use crate::{Int32Array, ListArray, StringArray};
     use std::collections::HashMap;
 
     #[test]
@@ -461,4 +476,65 @@ mod tests {
 
         builder.finish();
     }
+
+    #[test]
+    fn test_with_keys_sorted() {
+        let mut builder =
+            MapBuilder::new(None, Int32Builder::new(), Int32Builder::new()).with_keys_sorted(true);
+        builder.keys().append_value(1);
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+        assert!(matches!(map.data_type(), DataType::Map(_, true)));
+
+        let mut builder = make_builder(map.data_type(), 1);
+        let map = builder.finish();
+        assert!(matches!(map.data_type(), DataType::Map(_, true)));
+    }
+
+    #[test]
+    fn test_nested_map_builder() {
+        // A list of maps from strings to lists of integers:
+        // [[{"a": [1, 2]}, {}], null, [{"b": null}]]
+        let values = ListBuilder::new(Int32Builder::new());
+        let map = MapBuilder::new(None, StringBuilder::new(), values);
+        let mut builder = ListBuilder::new(map);
+
+        let map = builder.values();
+        map.keys().append_value("a");
+        map.values().append_value([Some(1), Some(2)]);
+        map.append(true).unwrap();
+        map.append(true).unwrap();
+        builder.append(true);
+        builder.append(false);
+        let map = builder.values();
+        map.keys().append_value("b");
+        map.values().append_null();
+        map.append(true).unwrap();
+        builder.append(true);
+
+        let list = builder.finish();
+        assert_eq!(list.len(), 3);
+        assert!(list.is_null(1));
+        let maps = list.values().as_map();
+        assert_eq!(maps.value_offsets(), &[0, 1, 1, 2]);
+        let (keys, values) = maps.entries_as::<StringArray, ListArray>().unwrap();
+        assert_eq!(keys, &StringArray::from(vec!["a", "b"]));
+        assert_eq!(values.value_offsets(), &[0, 2, 2]);
+        assert!(values.is_null(1));
+        assert_eq!(
+            values.value(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, 2])
+        );
+
+        // The same map built from its data type
+        let mut builder = make_builder(list.data_type(), 0);
+        let builder = builder
+            .as_any_mut()
+            .downcast_mut::<ListBuilder<Box<dyn ArrayBuilder>>>()
+            .unwrap();
+        builder.append(true);
+        let list = builder.finish();
+        assert_eq!(list.values().data_type(), maps.data_type());
+    }
 }
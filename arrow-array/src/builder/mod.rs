@@ -545,7 +545,7 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                 LargeListViewBuilder::with_capacity(builder, capacity).with_field(field.clone()),
             )
         }
-        DataType::Map(field, _) => match field.data_type() {
+        DataType::Map(field, keys_sorted) => match field.data_type() {
             DataType::Struct(fields) => {
                 let map_field_names = MapFieldNames {
                     key: fields[0].name().clone(),
@@ -562,7 +562,8 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<dyn ArrayBuilde
                         capacity,
                     )
                     .with_keys_field(fields[0].clone())
-                    .with_values_field(fields[1].clone()),
+                    .with_values_field(fields[1].clone())
+                    .with_keys_sorted(*keys_sorted),
                 )
             }
             t => panic!("The field of Map data type {t:?} should have a child Struct field"),
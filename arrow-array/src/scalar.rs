@@ -15,7 +15,12 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::Array;
+use arrow_buffer::ArrowNativeType;
+use arrow_data::transform::MutableArrayData;
+use arrow_schema::ArrowError;
+
+use crate::types::{ArrowDictionaryKeyType, RunEndIndexType};
+use crate::{make_array, Array, ArrayRef, DictionaryArray, PrimitiveArray, RunArray};
 
 /// A possibly [`Scalar`] [`Array`]
 ///
@@ -150,3 +155,206 @@ impl<T: Array> Datum for Scalar<T> {
         (&self.0, true)
     }
 }
+
+/// A [`Scalar`] value logically repeated `len` times, such as a literal
+/// column in an expression.
+///
+/// Like [`Scalar`], a [`ConstantArray`] implements [`Datum`] as a scalar, and
+/// so can be passed to [compute] kernels without allocating an array of `len`
+/// values. When an [`Array`] is required, it can be materialized with
+/// [`Self::to_array`], or more compactly with [`Self::to_run_end_encoded`]
+/// or [`Self::to_dictionary`].
+///
+/// # Example
+///
+/// ```rust
+/// # use arrow_array::{Array, ConstantArray, Int32Array, RunArray, StringArray};
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::{Int16Type, Int32Type};
+/// let constant = ConstantArray::new(StringArray::new_scalar("foo"), 3);
+/// assert_eq!(constant.len(), 3);
+///
+/// // Fully materialize the constant
+/// let array = constant.to_array();
+/// assert_eq!(array.as_string::<i32>(), &StringArray::from(vec!["foo"; 3]));
+///
+/// // Or materialize it as a single run
+/// let run_array: RunArray<Int32Type> = constant.to_run_end_encoded().unwrap();
+/// assert_eq!(run_array.len(), 3);
+/// assert_eq!(run_array.run_ends().values(), &[3]);
+///
+/// // Or as a dictionary with a single value
+/// let dictionary = constant.to_dictionary::<Int16Type>();
+/// assert_eq!(dictionary.keys(), &vec![0, 0, 0].into());
+/// ```
+///
+/// [compute]: https://docs.rs/arrow/latest/arrow/compute/index.html
+#[derive(Debug, Clone)]
+pub struct ConstantArray<T: Array> {
+    value: Scalar<T>,
+    len: usize,
+}
+
+impl<T: Array> ConstantArray<T> {
+    /// Create a new [`ConstantArray`] repeating `value` `len` times
+    pub fn new(value: Scalar<T>, len: usize) -> Self {
+        Self { value, len }
+    }
+
+    /// Returns the logical length of this [`ConstantArray`]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the logical length of this [`ConstantArray`] is zero
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the repeated value is null
+    pub fn is_null(&self) -> bool {
+        self.value.0.is_null(0)
+    }
+
+    /// Returns the repeated value
+    #[inline]
+    pub fn scalar(&self) -> &Scalar<T> {
+        &self.value
+    }
+
+    /// Returns the repeated value
+    #[inline]
+    pub fn into_scalar(self) -> Scalar<T> {
+        self.value
+    }
+}
+
+impl<T: Array + 'static> ConstantArray<T> {
+    /// Materialize this [`ConstantArray`] as an array containing `len` copies of its value
+    pub fn to_array(&self) -> ArrayRef {
+        let data = self.value.0.to_data();
+        let mut mutable = MutableArrayData::new(vec![&data], false, self.len);
+        for _ in 0..self.len {
+            mutable.extend(0, 0, 1);
+        }
+        make_array(mutable.freeze())
+    }
+
+    /// Materialize this [`ConstantArray`] as a [`RunArray`] with a single run
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `len` cannot be represented by `R`
+    pub fn to_run_end_encoded<R: RunEndIndexType>(&self) -> Result<RunArray<R>, ArrowError> {
+        if self.len == 0 {
+            let values = self.value.0.slice(0, 0);
+            return RunArray::try_new(
+                &PrimitiveArray::<R>::new(vec![].into(), None),
+                values.as_ref(),
+            );
+        }
+        let run_end = R::Native::from_usize(self.len).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot represent constant array of length {} with run end type {}",
+                self.len,
+                R::DATA_TYPE
+            ))
+        })?;
+        RunArray::try_new(&PrimitiveArray::<R>::from_value(run_end, 1), &self.value.0)
+    }
+
+    /// Materialize this [`ConstantArray`] as a [`DictionaryArray`] with a single value
+    pub fn to_dictionary<K: ArrowDictionaryKeyType>(&self) -> DictionaryArray<K> {
+        let keys = PrimitiveArray::<K>::from_value(K::Native::usize_as(0), self.len);
+        DictionaryArray::new(keys, make_array(self.value.0.to_data()))
+    }
+}
+
+impl<T: Array> Datum for ConstantArray<T> {
+    fn get(&self) -> (&dyn Array, bool) {
+        (&self.value.0, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cast::AsArray;
+    use crate::types::{Int16Type, Int32Type, Int8Type};
+    use crate::{Int32Array, StringArray};
+
+    #[test]
+    fn test_constant_array() {
+        let constant = ConstantArray::new(Int32Array::new_scalar(42), 4);
+        assert_eq!(constant.len(), 4);
+        assert!(!constant.is_empty());
+        assert!(!constant.is_null());
+
+        let (array, is_scalar) = constant.get();
+        assert!(is_scalar);
+        assert_eq!(array.len(), 1);
+
+        let array = constant.to_array();
+        assert_eq!(
+            array.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![42; 4])
+        );
+
+        let run_array = constant.to_run_end_encoded::<Int16Type>().unwrap();
+        assert_eq!(run_array.len(), 4);
+        assert_eq!(run_array.run_ends().values(), &[4]);
+        assert_eq!(
+            run_array.values().as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![42])
+        );
+
+        let dictionary = constant.to_dictionary::<Int8Type>();
+        assert_eq!(dictionary.len(), 4);
+        assert_eq!(dictionary.keys(), &vec![0, 0, 0, 0].into());
+        assert_eq!(
+            dictionary.values().as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![42])
+        );
+    }
+
+    #[test]
+    fn test_constant_array_null() {
+        let constant = ConstantArray::new(Scalar::new(StringArray::new_null(1)), 3);
+        assert!(constant.is_null());
+
+        let array = constant.to_array();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 3);
+
+        let dictionary = constant.to_dictionary::<Int32Type>();
+        assert_eq!(dictionary.logical_null_count(), 3);
+    }
+
+    #[test]
+    fn test_constant_array_empty() {
+        let constant = ConstantArray::new(Int32Array::new_scalar(1), 0);
+        assert!(constant.is_empty());
+        assert_eq!(constant.to_array().len(), 0);
+        assert_eq!(constant.to_dictionary::<Int8Type>().len(), 0);
+
+        let run_array = constant.to_run_end_encoded::<Int32Type>().unwrap();
+        assert_eq!(run_array.len(), 0);
+        assert_eq!(run_array.values().len(), 0);
+    }
+
+    #[test]
+    fn test_constant_array_run_end_overflow() {
+        let constant = ConstantArray::new(Int32Array::new_scalar(1), i16::MAX as usize);
+        let run_array = constant.to_run_end_encoded::<Int16Type>().unwrap();
+        assert_eq!(run_array.len(), i16::MAX as usize);
+
+        let constant = ConstantArray::new(Int32Array::new_scalar(1), 40_000);
+        let err = constant.to_run_end_encoded::<Int16Type>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot represent constant array of length 40000 with run end type Int16"
+        );
+    }
+}
@@ -15,6 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use crate::bit_iterator::{BitIndexIterator, BitIterator, BitSliceIterator};
 use crate::buffer::BooleanBuffer;
 use crate::{Buffer, MutableBuffer};
@@ -27,26 +29,59 @@ use crate::{Buffer, MutableBuffer};
 ///
 /// `NullBuffer`s can be creating using [`NullBufferBuilder`]
 ///
+/// # Null Count
+///
+/// Computing the number of nulls requires a scan of the entire bitmask. To avoid this
+/// where the count may never be needed, the null count can be left unknown by creating
+/// the [`NullBuffer`] with [`Self::new_lazy`]. It is then computed on the first call to
+/// [`Self::null_count`], and cached for subsequent calls.
+///
+/// The other constructors, and kernels such as `take` and `filter`, compute the null
+/// count eagerly, so that they can return `None` rather than a [`NullBuffer`] without
+/// nulls.
+///
 /// [Arrow specification]: https://arrow.apache.org/docs/format/Columnar.html#validity-bitmaps
 /// [`NullBufferBuilder`]: crate::NullBufferBuilder
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct NullBuffer {
     buffer: BooleanBuffer,
-    null_count: usize,
+    null_count: NullCount,
 }
 
 impl NullBuffer {
     /// Create a new [`NullBuffer`] computing the null count
     pub fn new(buffer: BooleanBuffer) -> Self {
         let null_count = buffer.len() - buffer.count_set_bits();
-        Self { buffer, null_count }
+        Self {
+            buffer,
+            null_count: NullCount::new(null_count),
+        }
+    }
+
+    /// Create a new [`NullBuffer`] without computing the null count
+    ///
+    /// The null count is instead computed on the first call to [`Self::null_count`]. The
+    /// count is cached per instance, so clones made before this call compute it again
+    ///
+    /// ```
+    /// # use arrow_buffer::{BooleanBuffer, NullBuffer};
+    /// let nulls = NullBuffer::new_lazy(BooleanBuffer::from(vec![true, false, true]));
+    /// assert_eq!(nulls.null_count_if_known(), None);
+    /// assert_eq!(nulls.null_count(), 1);
+    /// assert_eq!(nulls.null_count_if_known(), Some(1));
+    /// ```
+    pub fn new_lazy(buffer: BooleanBuffer) -> Self {
+        Self {
+            buffer,
+            null_count: NullCount::unknown(),
+        }
     }
 
     /// Create a new [`NullBuffer`] of length `len` where all values are null
     pub fn new_null(len: usize) -> Self {
         Self {
             buffer: BooleanBuffer::new_unset(len),
-            null_count: len,
+            null_count: NullCount::new(len),
         }
     }
 
@@ -57,7 +92,7 @@ impl NullBuffer {
     pub fn new_valid(len: usize) -> Self {
         Self {
             buffer: BooleanBuffer::new_set(len),
-            null_count: 0,
+            null_count: NullCount::new(0),
         }
     }
 
@@ -67,7 +102,10 @@ impl NullBuffer {
     ///
     /// `buffer` must contain `null_count` `0` bits
     pub unsafe fn new_unchecked(buffer: BooleanBuffer, null_count: usize) -> Self {
-        Self { buffer, null_count }
+        Self {
+            buffer,
+            null_count: NullCount::new(null_count),
+        }
     }
 
     /// Computes the union of the nulls in two optional [`NullBuffer`]
@@ -85,7 +123,7 @@ impl NullBuffer {
 
     /// Returns true if all nulls in `other` also exist in self
     pub fn contains(&self, other: &NullBuffer) -> bool {
-        if other.null_count == 0 {
+        if other.null_count() == 0 {
             return true;
         }
         let lhs = self.inner().bit_chunks().iter_padded();
@@ -110,9 +148,13 @@ impl NullBuffer {
                 crate::bit_util::set_bit(buffer.as_mut(), i * count + j)
             }
         }
+        let null_count = match self.null_count_if_known() {
+            Some(null_count) => NullCount::new(null_count * count),
+            None => NullCount::unknown(),
+        };
         Self {
             buffer: BooleanBuffer::new(buffer.into(), 0, capacity),
-            null_count: self.null_count * count,
+            null_count,
         }
     }
 
@@ -140,9 +182,28 @@ impl NullBuffer {
     }
 
     /// Returns the null count for this [`NullBuffer`]
+    ///
+    /// If the null count is not yet known, see [`Self::new_lazy`], this computes and
+    /// caches it
     #[inline]
     pub fn null_count(&self) -> usize {
-        self.null_count
+        match self.null_count.get() {
+            Some(null_count) => null_count,
+            None => self.compute_null_count(),
+        }
+    }
+
+    /// Returns the null count for this [`NullBuffer`] if it is known, without computing it
+    #[inline]
+    pub fn null_count_if_known(&self) -> Option<usize> {
+        self.null_count.get()
+    }
+
+    #[cold]
+    fn compute_null_count(&self) -> usize {
+        let null_count = self.buffer.len() - self.buffer.count_set_bits();
+        self.null_count.set(null_count);
+        null_count
     }
 
     /// Returns `true` if the value at `idx` is not null
@@ -164,8 +225,20 @@ impl NullBuffer {
     }
 
     /// Slices this [`NullBuffer`] by the provided `offset` and `length`
+    ///
+    /// The null count of the slice is computed, unless it follows from the null count of
+    /// this [`NullBuffer`], i.e. if it contains no nulls or only nulls
     pub fn slice(&self, offset: usize, len: usize) -> Self {
-        Self::new(self.buffer.slice(offset, len))
+        let buffer = self.buffer.slice(offset, len);
+        let null_count = match self.null_count_if_known() {
+            Some(0) => 0,
+            Some(null_count) if null_count == self.len() => len,
+            _ => return Self::new(buffer),
+        };
+        Self {
+            buffer,
+            null_count: NullCount::new(null_count),
+        }
     }
 
     /// Returns an iterator over the bits in this [`NullBuffer`]
@@ -198,7 +271,7 @@ impl NullBuffer {
         &self,
         f: F,
     ) -> Result<(), E> {
-        if self.null_count == self.len() {
+        if self.null_count() == self.len() {
             return Ok(());
         }
         self.valid_indices().try_for_each(f)
@@ -223,6 +296,61 @@ impl NullBuffer {
     }
 }
 
+impl PartialEq for NullBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        // The null count is determined by the buffer
+        self.buffer == other.buffer
+    }
+}
+
+impl Eq for NullBuffer {}
+
+impl std::fmt::Debug for NullBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NullBuffer")
+            .field("buffer", &self.buffer)
+            .field("null_count", &self.null_count())
+            .finish()
+    }
+}
+
+/// The null count of a [`NullBuffer`], which may not be known yet
+///
+/// A [`NullBuffer`] can not contain `usize::MAX` bits, so this is used to encode an
+/// unknown null count. As the null count is a function of the immutable buffer, races
+/// between threads computing it are benign.
+struct NullCount(AtomicUsize);
+
+impl NullCount {
+    const UNKNOWN: usize = usize::MAX;
+
+    fn new(null_count: usize) -> Self {
+        Self(AtomicUsize::new(null_count))
+    }
+
+    fn unknown() -> Self {
+        Self::new(Self::UNKNOWN)
+    }
+
+    #[inline]
+    fn get(&self) -> Option<usize> {
+        match self.0.load(Ordering::Relaxed) {
+            Self::UNKNOWN => None,
+            null_count => Some(null_count),
+        }
+    }
+
+    fn set(&self, null_count: usize) {
+        self.0.store(null_count, Ordering::Relaxed)
+    }
+}
+
+impl Clone for NullCount {
+    fn clone(&self) -> Self {
+        Self(AtomicUsize::new(self.0.load(Ordering::Relaxed)))
+    }
+}
+
 impl<'a> IntoIterator for &'a NullBuffer {
     type Item = bool;
     type IntoIter = BitIterator<'a>;
@@ -273,4 +401,31 @@ mod tests {
             std::mem::size_of::<Option<NullBuffer>>()
         );
     }
+
+    #[test]
+    fn test_lazy_null_count() {
+        let buffer = BooleanBuffer::from(vec![true, false, false, true, true, false]);
+        let nulls = NullBuffer::new_lazy(buffer.clone());
+        assert_eq!(nulls.null_count_if_known(), None);
+        assert_eq!(nulls, NullBuffer::new(buffer.clone()));
+
+        // Clones made before the count is computed compute it separately
+        let cloned = nulls.clone();
+        assert_eq!(nulls.null_count(), 3);
+        assert_eq!(nulls.null_count_if_known(), Some(3));
+        assert_eq!(cloned.null_count_if_known(), None);
+        assert_eq!(nulls.clone().null_count_if_known(), Some(3));
+
+        // Slices compute their null count unless it follows from the parent
+        let slice = NullBuffer::new_lazy(buffer.clone()).slice(1, 3);
+        assert_eq!(slice.null_count_if_known(), Some(2));
+        let valid = NullBuffer::new_valid(10).slice(2, 5);
+        assert_eq!(valid.null_count_if_known(), Some(0));
+        let null = NullBuffer::new_null(10).slice(2, 5);
+        assert_eq!(null.null_count_if_known(), Some(5));
+
+        let union = NullBuffer::union(Some(&nulls), Some(&NullBuffer::new_valid(6)));
+        assert_eq!(union.unwrap().null_count_if_known(), Some(3));
+        assert_eq!(NullBuffer::new_lazy(buffer).expand(2).null_count(), 6);
+    }
 }
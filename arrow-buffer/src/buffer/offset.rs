@@ -17,6 +17,7 @@
 
 use crate::buffer::ScalarBuffer;
 use crate::{ArrowNativeType, MutableBuffer, OffsetBufferBuilder};
+use arrow_schema::ArrowError;
 use std::ops::Deref;
 
 /// A non-empty buffer of monotonically increasing, positive integers.
@@ -133,6 +134,65 @@ impl<O: ArrowNativeType> OffsetBuffer<O> {
         Self(out.into())
     }
 
+    /// Create a new [`OffsetBuffer`] from the iterator of slice lengths, returning an error
+    /// if the offsets overflow `O`
+    ///
+    /// Unlike [`Self::from_lengths`], which panics on overflow, this stops as soon as an offset
+    /// exceeds the maximum value of `O`, e.g. `i32::MAX`.
+    ///
+    /// ```
+    /// # use arrow_buffer::OffsetBuffer;
+    /// let offsets = OffsetBuffer::<i32>::try_from_lengths([1, 3, 5]).unwrap();
+    /// assert_eq!(offsets.as_ref(), &[0, 1, 4, 9]);
+    ///
+    /// let offsets = OffsetBuffer::<i32>::try_from_lengths([i32::MAX as usize, 1]);
+    /// assert!(offsets.is_err());
+    /// ```
+    pub fn try_from_lengths<I>(lengths: I) -> Result<Self, ArrowError>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let iter = lengths.into_iter();
+        let mut out = Vec::with_capacity(iter.size_hint().0 + 1);
+        out.push(O::usize_as(0));
+
+        let mut acc = 0_usize;
+        for length in iter {
+            acc = acc.saturating_add(length);
+            let offset = O::from_usize(acc).ok_or(ArrowError::OffsetOverflowError(acc))?;
+            out.push(offset);
+        }
+        Ok(Self(out.into()))
+    }
+
+    /// Create a new [`OffsetBuffer`] from the iterator of slice lengths, returning an error
+    /// if the offsets overflow `O`, or the last offset is not `values_len`
+    ///
+    /// This is useful for creating the offsets of an array directly from the lengths of its
+    /// values, checking they cover exactly the `values_len` values of the array.
+    ///
+    /// ```
+    /// # use arrow_buffer::OffsetBuffer;
+    /// let values = "helloworld";
+    /// let offsets = OffsetBuffer::<i32>::from_lengths_checked([5, 5], values.len()).unwrap();
+    /// assert_eq!(offsets.as_ref(), &[0, 5, 10]);
+    ///
+    /// assert!(OffsetBuffer::<i32>::from_lengths_checked([5, 4], values.len()).is_err());
+    /// ```
+    pub fn from_lengths_checked<I>(lengths: I, values_len: usize) -> Result<Self, ArrowError>
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let offsets = Self::try_from_lengths(lengths)?;
+        let end = offsets[offsets.len() - 1].as_usize();
+        if end != values_len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected the lengths to sum to {values_len}, got {end}"
+            )));
+        }
+        Ok(offsets)
+    }
+
     /// Get an Iterator over the lengths of this [`OffsetBuffer`]
     ///
     /// ```
@@ -283,6 +343,38 @@ mod tests {
         OffsetBuffer::<i32>::from_lengths([usize::MAX, 1]);
     }
 
+    #[test]
+    fn try_from_lengths() {
+        let buffer = OffsetBuffer::<i32>::try_from_lengths([2, 6, 3, 7, 2]).unwrap();
+        assert_eq!(buffer.as_ref(), &[0, 2, 8, 11, 18, 20]);
+
+        let buffer = OffsetBuffer::<i32>::try_from_lengths([]).unwrap();
+        assert_eq!(buffer.as_ref(), &[0]);
+
+        let buffer = OffsetBuffer::<i32>::try_from_lengths([i32::MAX as usize]).unwrap();
+        assert_eq!(buffer.as_ref(), &[0, i32::MAX]);
+
+        let err = OffsetBuffer::<i32>::try_from_lengths([i32::MAX as usize, 1]).unwrap_err();
+        assert_eq!(err.to_string(), "Offset overflow error: 2147483648");
+        assert!(OffsetBuffer::<i32>::try_from_lengths([usize::MAX, 1]).is_err());
+        assert!(OffsetBuffer::<i64>::try_from_lengths([i32::MAX as usize, 1]).is_ok());
+    }
+
+    #[test]
+    fn from_lengths_checked() {
+        let buffer = OffsetBuffer::<i32>::from_lengths_checked([1, 0, 2], 3).unwrap();
+        assert_eq!(buffer.as_ref(), &[0, 1, 1, 3]);
+        assert!(OffsetBuffer::<i32>::from_lengths_checked([], 0).is_ok());
+
+        let err = OffsetBuffer::<i32>::from_lengths_checked([1, 0, 2], 4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected the lengths to sum to 4, got 3"
+        );
+        assert!(OffsetBuffer::<i32>::from_lengths_checked([1, 0, 2], 2).is_err());
+        assert!(OffsetBuffer::<i32>::from_lengths_checked([i32::MAX as usize, 1], 0).is_err());
+    }
+
     #[test]
     fn get_lengths() {
         let offsets = OffsetBuffer::<i32>::new(ScalarBuffer::<i32>::from(vec![0, 1, 4, 9]));
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::{BooleanBufferBuilder, MutableBuffer, NullBuffer};
+use crate::{BooleanBuffer, BooleanBufferBuilder, MutableBuffer, NullBuffer};
 
 /// Builder for creating [`NullBuffer`]
 ///
@@ -58,6 +58,9 @@ pub struct NullBufferBuilder {
     len: usize,
     /// Initial capacity of the `bitmap_builder`, when it is materialized.
     capacity: usize,
+    /// The number of nulls appended, tracked so that [`Self::finish`] does not need to
+    /// count them, or `None` if unknown as the bits were modified directly.
+    null_count: Option<usize>,
 }
 
 impl NullBufferBuilder {
@@ -71,6 +74,7 @@ impl NullBufferBuilder {
             bitmap_builder: None,
             len: 0,
             capacity,
+            null_count: Some(0),
         }
     }
 
@@ -80,6 +84,7 @@ impl NullBufferBuilder {
             bitmap_builder: None,
             len,
             capacity: len,
+            null_count: Some(0),
         }
     }
 
@@ -93,6 +98,7 @@ impl NullBufferBuilder {
             bitmap_builder,
             len,
             capacity,
+            null_count: None,
         }
     }
 
@@ -124,6 +130,7 @@ impl NullBufferBuilder {
     pub fn append_n_nulls(&mut self, n: usize) {
         self.materialize_if_needed();
         self.bitmap_builder.as_mut().unwrap().append_n(n, false);
+        self.add_nulls(n);
     }
 
     /// Appends a `false` into the builder
@@ -132,6 +139,7 @@ impl NullBufferBuilder {
     pub fn append_null(&mut self) {
         self.materialize_if_needed();
         self.bitmap_builder.as_mut().unwrap().append(false);
+        self.add_nulls(1);
     }
 
     /// Appends a boolean value into the builder.
@@ -160,6 +168,10 @@ impl NullBufferBuilder {
     #[inline]
     pub fn truncate(&mut self, len: usize) {
         if let Some(buf) = self.bitmap_builder.as_mut() {
+            if len < buf.len() {
+                // The number of nulls removed is unknown
+                self.null_count = None;
+            }
             buf.truncate(len);
         } else if len <= self.len {
             self.len = len
@@ -169,8 +181,10 @@ impl NullBufferBuilder {
     /// Appends a boolean slice into the builder
     /// to indicate the validations of these items.
     pub fn append_slice(&mut self, slice: &[bool]) {
-        if slice.iter().any(|v| !v) {
-            self.materialize_if_needed()
+        let null_count = slice.iter().filter(|v| !**v).count();
+        if null_count > 0 {
+            self.materialize_if_needed();
+            self.add_nulls(null_count);
         }
         if let Some(buf) = self.bitmap_builder.as_mut() {
             buf.append_slice(slice)
@@ -183,8 +197,10 @@ impl NullBufferBuilder {
     ///
     /// This is useful when you want to concatenate two null buffers.
     pub fn append_buffer(&mut self, buffer: &NullBuffer) {
-        if buffer.null_count() > 0 {
+        let null_count = buffer.null_count();
+        if null_count > 0 {
             self.materialize_if_needed();
+            self.add_nulls(null_count);
         }
         if let Some(buf) = self.bitmap_builder.as_mut() {
             buf.append_buffer(buffer.inner())
@@ -195,15 +211,43 @@ impl NullBufferBuilder {
 
     /// Builds the null buffer and resets the builder.
     /// Returns `None` if the builder only contains `true`s.
+    ///
+    /// The null count of the returned [`NullBuffer`] is known without scanning the
+    /// buffer, unless the bits were modified through [`Self::as_slice_mut`], the builder
+    /// was created with [`Self::new_from_buffer`], or it was truncated.
     pub fn finish(&mut self) -> Option<NullBuffer> {
         self.len = 0;
-        Some(NullBuffer::new(self.bitmap_builder.take()?.finish()))
+        let null_count = self.null_count.replace(0);
+        let buffer = self.bitmap_builder.take()?.finish();
+        Some(Self::build(buffer, null_count))
     }
 
     /// Builds the [NullBuffer] without resetting the builder.
     pub fn finish_cloned(&self) -> Option<NullBuffer> {
         let buffer = self.bitmap_builder.as_ref()?.finish_cloned();
-        Some(NullBuffer::new(buffer))
+        Some(Self::build(buffer, self.null_count))
+    }
+
+    /// Returns the number of nulls appended to this builder, if known
+    ///
+    /// See [`Self::finish`] for when the null count is unknown
+    pub fn null_count(&self) -> Option<usize> {
+        self.null_count
+    }
+
+    fn build(buffer: BooleanBuffer, null_count: Option<usize>) -> NullBuffer {
+        match null_count {
+            // SAFETY: the null count was tracked as the bits were appended
+            Some(null_count) => unsafe { NullBuffer::new_unchecked(buffer, null_count) },
+            None => NullBuffer::new(buffer),
+        }
+    }
+
+    #[inline]
+    fn add_nulls(&mut self, n: usize) {
+        if let Some(null_count) = self.null_count.as_mut() {
+            *null_count += n;
+        }
     }
 
     /// Returns the inner bitmap builder as slice
@@ -227,8 +271,15 @@ impl NullBufferBuilder {
     }
 
     /// Return a mutable reference to the inner bitmap slice.
+    ///
+    /// As the bits may be modified, the null count of the [`NullBuffer`] returned by
+    /// [`Self::finish`] is no longer tracked
     pub fn as_slice_mut(&mut self) -> Option<&mut [u8]> {
-        self.bitmap_builder.as_mut().map(|b| b.as_slice_mut())
+        let slice = self.bitmap_builder.as_mut().map(|b| b.as_slice_mut());
+        if slice.is_some() {
+            self.null_count = None;
+        }
+        slice
     }
 
     /// Return the allocated size of this builder, in bytes, useful for memory accounting.
@@ -402,4 +453,33 @@ mod tests {
 
         assert_eq!(builder.finish(), None);
     }
+
+    #[test]
+    fn test_null_buffer_builder_null_count() {
+        let mut builder = NullBufferBuilder::new(0);
+        builder.append_n_non_nulls(3);
+        builder.append_null();
+        builder.append_n_nulls(2);
+        builder.append_slice(&[true, false]);
+        builder.append_buffer(&NullBuffer::from(&[false, true]));
+        assert_eq!(builder.null_count(), Some(5));
+
+        let nulls = builder.finish_cloned().unwrap();
+        assert_eq!(nulls.null_count_if_known(), Some(5));
+        let nulls = builder.finish().unwrap();
+        assert_eq!(nulls.null_count_if_known(), Some(5));
+        assert_eq!(builder.null_count(), Some(0));
+
+        // Truncating or modifying the bits directly leaves the null count to be counted
+        builder.append_n_nulls(4);
+        builder.truncate(2);
+        assert_eq!(builder.null_count(), None);
+        let nulls = builder.finish().unwrap();
+        assert_eq!(nulls.null_count_if_known(), Some(2));
+
+        builder.append_null();
+        builder.as_slice_mut().unwrap()[0] = 1;
+        let nulls = builder.finish().unwrap();
+        assert_eq!(nulls.null_count_if_known(), Some(0));
+    }
 }
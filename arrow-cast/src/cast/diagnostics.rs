@@ -0,0 +1,228 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Cast kernels that report every failing row rather than only the first
+
+use std::fmt::{Display, Formatter};
+
+use arrow_array::{Array, ArrayRef};
+use arrow_schema::{ArrowError, DataType};
+
+use crate::cast::{cast_with_options, CastOptions};
+use crate::display::{ArrayFormatter, FormatOptions};
+
+/// A single value that could not be cast, see [`CastErrorReport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastFailure {
+    /// The index of the row in the input array
+    pub row: usize,
+    /// The offending input value, rendered using [`ArrayFormatter`]
+    pub value: String,
+}
+
+/// A structured error describing the rows that failed a cast
+///
+/// Returned (wrapped in [`ArrowError::ExternalError`]) by [`cast_with_error_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CastErrorReport {
+    /// The type the input was being cast from
+    pub from_type: DataType,
+    /// The type the input was being cast to
+    pub to_type: DataType,
+    /// The total number of rows that failed to cast
+    pub failure_count: usize,
+    /// The first failing rows, at most the limit passed to [`cast_with_error_report`]
+    pub failures: Vec<CastFailure>,
+}
+
+impl Display for CastErrorReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Failed to cast {} of the values from {} to {}:",
+            self.failure_count, self.from_type, self.to_type
+        )?;
+        for failure in &self.failures {
+            write!(f, " [row {}: '{}']", failure.row, failure.value)?;
+        }
+        if self.failure_count > self.failures.len() {
+            write!(f, " and {} more", self.failure_count - self.failures.len())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CastErrorReport {}
+
+/// Cast `array` to `to_type`, collecting diagnostics for all failing rows
+///
+/// When `cast_options.safe` is `true` this behaves exactly like [`cast_with_options`].
+///
+/// When `cast_options.safe` is `false`, rather than returning an error describing only
+/// the first value that could not be cast, this returns an [`ArrowError::ExternalError`]
+/// wrapping a [`CastErrorReport`] with the total number of failures and the row index and
+/// value of up to `max_failures` of them.
+///
+/// A row is considered to have failed if it is valid in the input but null in the output
+/// of a safe cast. Only top-level nulls are considered.
+///
+/// # Example
+/// ```
+/// # use arrow_array::StringArray;
+/// # use arrow_cast::{cast_with_error_report, CastErrorReport, CastOptions};
+/// # use arrow_schema::{ArrowError, DataType};
+/// let array = StringArray::from(vec!["1", "foo", "3", "bar", "baz"]);
+/// let options = CastOptions::default().with_safe(false);
+/// let err = cast_with_error_report(&array, &DataType::Int32, &options, 2).unwrap_err();
+///
+/// let ArrowError::ExternalError(err) = err else { unreachable!() };
+/// let report = err.downcast_ref::<CastErrorReport>().unwrap();
+/// assert_eq!(report.failure_count, 3);
+/// assert_eq!(report.failures.len(), 2);
+/// assert_eq!(report.failures[0].row, 1);
+/// assert_eq!(report.failures[0].value, "foo");
+/// ```
+pub fn cast_with_error_report(
+    array: &dyn Array,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+    max_failures: usize,
+) -> Result<ArrayRef, ArrowError> {
+    if cast_options.safe {
+        return cast_with_options(array, to_type, cast_options);
+    }
+
+    let safe_options = CastOptions {
+        safe: true,
+        ..cast_options.clone()
+    };
+    let result = cast_with_options(array, to_type, &safe_options)?;
+
+    let Some(result_nulls) = result.logical_nulls() else {
+        return Ok(result);
+    };
+    let input_nulls = array.logical_nulls();
+    if result_nulls.null_count() == input_nulls.as_ref().map(|n| n.null_count()).unwrap_or(0) {
+        return Ok(result);
+    }
+
+    let failed_rows = result_nulls.iter().enumerate().filter(|(row, is_valid)| {
+        !is_valid
+            && input_nulls
+                .as_ref()
+                .map(|n| n.is_valid(*row))
+                .unwrap_or(true)
+    });
+
+    let formatter = ArrayFormatter::try_new(array, &FormatOptions::default())?;
+    let mut failure_count = 0;
+    let mut failures = Vec::with_capacity(max_failures.min(result_nulls.null_count()));
+    for (row, _) in failed_rows {
+        if failures.len() < max_failures {
+            failures.push(CastFailure {
+                row,
+                value: formatter.value(row).to_string(),
+            });
+        }
+        failure_count += 1;
+    }
+
+    Err(ArrowError::ExternalError(Box::new(CastErrorReport {
+        from_type: array.data_type().clone(),
+        to_type: to_type.clone(),
+        failure_count,
+        failures,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Float64Array, Int32Array, StringArray};
+
+    fn unsafe_options() -> CastOptions<'static> {
+        CastOptions {
+            safe: false,
+            ..Default::default()
+        }
+    }
+
+    fn report(err: ArrowError) -> CastErrorReport {
+        match err {
+            ArrowError::ExternalError(e) => e.downcast_ref::<CastErrorReport>().unwrap().clone(),
+            e => panic!("unexpected error {e}"),
+        }
+    }
+
+    #[test]
+    fn test_cast_with_error_report() {
+        let array = StringArray::from(vec![Some("1"), None, Some("x"), Some("4"), Some("1.5")]);
+        let err =
+            cast_with_error_report(&array, &DataType::Int32, &unsafe_options(), 10).unwrap_err();
+        let report = report(err);
+        assert_eq!(report.failure_count, 2);
+        assert_eq!(
+            report.failures,
+            vec![
+                CastFailure {
+                    row: 2,
+                    value: "x".to_string()
+                },
+                CastFailure {
+                    row: 4,
+                    value: "1.5".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            report.to_string(),
+            "Failed to cast 2 of the values from Utf8 to Int32: [row 2: 'x'] [row 4: '1.5']"
+        );
+    }
+
+    #[test]
+    fn test_cast_with_error_report_limit() {
+        let array = Float64Array::from(vec![1e20, 1.0, 2e20, 3e20]);
+        let err =
+            cast_with_error_report(&array, &DataType::Int32, &unsafe_options(), 1).unwrap_err();
+        let report = report(err);
+        assert_eq!(report.failure_count, 3);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].row, 0);
+        assert_eq!(
+            report.to_string(),
+            "Failed to cast 3 of the values from Float64 to Int32: [row 0: '1e20'] and 2 more"
+        );
+    }
+
+    #[test]
+    fn test_cast_with_error_report_success() {
+        let array = StringArray::from(vec![Some("1"), None, Some("3")]);
+        let result =
+            cast_with_error_report(&array, &DataType::Int32, &unsafe_options(), 10).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(3)])
+        );
+
+        // safe casts never report errors
+        let array = StringArray::from(vec!["x"]);
+        let result =
+            cast_with_error_report(&array, &DataType::Int32, &CastOptions::default(), 10).unwrap();
+        assert_eq!(result.null_count(), 1);
+    }
+}
@@ -71,9 +71,10 @@ where
     OffsetSize: OffsetSizeTrait,
 {
     let cap = array.len() * size as usize;
+    let truncate_or_pad = cast_options.list_length_mismatch == ListLengthMismatch::TruncateOrPad;
 
     // Whether the resulting array may contain null lists
-    let nullable = cast_options.safe || array.null_count() != 0;
+    let nullable = (cast_options.safe && !truncate_or_pad) || array.null_count() != 0;
     let mut nulls = nullable.then(|| {
         let mut buffer = BooleanBufferBuilder::new(array.len());
         match array.nulls() {
@@ -85,19 +86,12 @@ where
 
     // Nulls in FixedSizeListArray take up space and so we must pad the values
     let values = array.values().to_data();
-    let mut mutable = MutableArrayData::new(vec![&values], nullable, cap);
+    let mut mutable = MutableArrayData::new(vec![&values], nullable || truncate_or_pad, cap);
     // The end position in values of the last incorrectly-sized list slice
-    let mut last_pos = 0;
-
-    // Need to flag when previous vector(s) are empty/None to distinguish from 'All slices were correct length' cases.
-    let is_prev_empty = if array.offsets().len() < 2 {
-        false
-    } else {
-        let first_offset = array.offsets()[0].as_usize();
-        let second_offset = array.offsets()[1].as_usize();
-
-        first_offset == 0 && second_offset == 0
-    };
+    let first_pos = array.offsets()[0].as_usize();
+    let mut last_pos = first_pos;
+    // Whether any list slice was incorrectly-sized
+    let mut resized = false;
 
     for (idx, w) in array.offsets().windows(2).enumerate() {
         let start_pos = w[0].as_usize();
@@ -105,27 +99,33 @@ where
         let len = end_pos - start_pos;
 
         if len != size as usize {
-            if cast_options.safe || array.is_null(idx) {
-                if last_pos != start_pos {
-                    // Extend with valid slices
-                    mutable.extend(0, last_pos, start_pos);
-                }
+            if last_pos != start_pos {
+                // Extend with valid slices
+                mutable.extend(0, last_pos, start_pos);
+            }
+            if truncate_or_pad && array.is_valid(idx) {
+                // Truncate this slice to size, or pad it with nulls
+                let kept = len.min(size as usize);
+                mutable.extend(0, start_pos, start_pos + kept);
+                mutable.extend_nulls(size as usize - kept);
+            } else if cast_options.safe || array.is_null(idx) {
                 // Pad this slice with nulls
                 mutable.extend_nulls(size as _);
                 nulls.as_mut().unwrap().set_bit(idx, false);
-                // Set last_pos to the end of this slice's values
-                last_pos = end_pos
             } else {
                 return Err(ArrowError::CastError(format!(
                     "Cannot cast to FixedSizeList({size}): value at index {idx} has length {len}",
                 )));
             }
+            // Set last_pos to the end of this slice's values
+            last_pos = end_pos;
+            resized = true;
         }
     }
 
-    let values = match last_pos {
-        0 if !is_prev_empty => array.values().slice(0, cap), // All slices were the correct length
-        _ => {
+    let values = match resized {
+        false => array.values().slice(first_pos, cap), // All slices were the correct length
+        true => {
             if mutable.len() != cap {
                 // Remaining slices were all correct length
                 let remaining = cap - mutable.len();
@@ -140,7 +140,7 @@ where
 
     // Construct the FixedSizeListArray
     let nulls = nulls.map(|mut x| x.finish().into());
-    let array = FixedSizeListArray::new(field.clone(), size, values, nulls);
+    let array = FixedSizeListArray::try_new(field.clone(), size, values, nulls)?;
     Ok(Arc::new(array))
 }
 
@@ -38,11 +38,13 @@
 //! ```
 
 mod decimal;
+mod diagnostics;
 mod dictionary;
 mod list;
 mod map;
 mod string;
 use crate::cast::decimal::*;
+pub use crate::cast::diagnostics::*;
 use crate::cast::dictionary::*;
 use crate::cast::list::*;
 use crate::cast::map::*;
@@ -66,15 +68,37 @@ use arrow_data::ArrayData;
 use arrow_schema::*;
 use arrow_select::take::take;
 use num::cast::AsPrimitive;
-use num::{NumCast, ToPrimitive};
+use num::{Bounded, NumCast, ToPrimitive};
 
 /// CastOptions provides a way to override the default cast behaviors
+///
+/// This struct is `#[non_exhaustive]`, so that options can be added without breaking
+/// changes. Outside of this crate, it is constructed from [`CastOptions::default`] and
+/// the `with_` methods:
+///
+/// ```
+/// # use arrow_cast::CastOptions;
+/// let options = CastOptions::default().with_safe(false).with_saturate(true);
+/// assert!(!options.safe);
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub struct CastOptions<'a> {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
     pub safe: bool,
     /// Formatting options when casting from temporal types to string
     pub format_options: FormatOptions<'a>,
+    /// How to handle numeric values that are out of range of the target type of a cast
+    /// between numeric types, either clamp them to the minimum or maximum value of the
+    /// target type (saturate=true) or handle them as cast failures according to `safe`
+    /// (saturate=false)
+    ///
+    /// `NaN` can not be clamped, and is always handled according to `safe` when cast to an
+    /// integer type.
+    pub saturate: bool,
+    /// How to handle lists whose length differs from the size of the target type of a cast
+    /// from `List` or `LargeList` to `FixedSizeList`
+    pub list_length_mismatch: ListLengthMismatch,
 }
 
 impl Default for CastOptions<'_> {
@@ -82,10 +106,53 @@ impl Default for CastOptions<'_> {
         Self {
             safe: true,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::default(),
+        }
+    }
+}
+
+impl<'a> CastOptions<'a> {
+    /// Sets [`Self::safe`]
+    pub fn with_safe(self, safe: bool) -> Self {
+        Self { safe, ..self }
+    }
+
+    /// Sets [`Self::format_options`]
+    pub fn with_format_options(self, format_options: FormatOptions<'a>) -> Self {
+        Self {
+            format_options,
+            ..self
+        }
+    }
+
+    /// Sets [`Self::saturate`]
+    pub fn with_saturate(self, saturate: bool) -> Self {
+        Self { saturate, ..self }
+    }
+
+    /// Sets [`Self::list_length_mismatch`]
+    pub fn with_list_length_mismatch(self, list_length_mismatch: ListLengthMismatch) -> Self {
+        Self {
+            list_length_mismatch,
+            ..self
         }
     }
 }
 
+/// How to handle lists whose length differs from the size of the target type when casting
+/// a `List` or `LargeList` to a `FixedSizeList`, see [`CastOptions::list_length_mismatch`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ListLengthMismatch {
+    /// Handle the list as a cast failure: replace it with NULL if [`CastOptions::safe`] is
+    /// true, otherwise return an error
+    #[default]
+    Fail,
+    /// Truncate longer lists to the size of the target type, and pad shorter lists with
+    /// NULL elements, which requires the field of the target type to be nullable
+    TruncateOrPad,
+}
+
 /// Return true if a value of type `from_type` can be cast into a value of `to_type`.
 ///
 /// See [`cast_with_options`] for more information
@@ -593,9 +660,14 @@ fn timestamp_to_date32<T: ArrowTimestampType>(
 /// * `Utf8` to Numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
 /// * Numeric to `Boolean`: 0 returns `false`, any other value returns `true`
+/// * Numeric to Numeric: values that are out of range of the target type return null or
+///   error, or are clamped to the minimum or maximum value of the target type if
+///   [`CastOptions::saturate`] is true
 /// * `List` to `List`: the underlying data type is cast
 /// * `List` to `FixedSizeList`: the underlying data type is cast. If safe is true and a list element
-///   has the wrong length it will be replaced with NULL, otherwise an error will be returned
+///   has the wrong length it will be replaced with NULL, otherwise an error will be returned,
+///   unless [`CastOptions::list_length_mismatch`] is [`ListLengthMismatch::TruncateOrPad`]
+/// * `FixedSizeList` to `List`: the underlying data type is cast
 /// * Primitive to `List`: a list array with 1 value per slot is created
 /// * `Date32` and `Date64`: precision lost when going to higher interval
 /// * `Time32 and `Time64`: precision lost when going to higher interval
@@ -2115,9 +2187,12 @@ where
     FROM: ArrowPrimitiveType,
     TO: ArrowPrimitiveType,
     FROM::Native: NumCast,
-    TO::Native: NumCast,
+    TO::Native: NumCast + Bounded,
 {
-    if cast_options.safe {
+    if cast_options.saturate {
+        // If the value is out of range of `TO::Native`, clamp it to the min or max value
+        saturating_numeric_cast::<FROM, TO>(from.as_primitive::<FROM>(), cast_options.safe)
+    } else if cast_options.safe {
         // If the value can't be casted to the `TO::Native`, return null
         Ok(Arc::new(numeric_cast::<FROM, TO>(
             from.as_primitive::<FROM>(),
@@ -2162,6 +2237,46 @@ where
     from.unary_opt::<_, R>(num::cast::cast::<T::Native, R::Native>)
 }
 
+// Natural cast between numeric types
+// If the value of T is out of range of R, it will be clamped to the min or max value of R.
+// Values that can't be clamped (NaN to an integer) are converted to null if `safe`, and
+// return an error otherwise
+fn saturating_numeric_cast<T, R>(
+    from: &PrimitiveArray<T>,
+    safe: bool,
+) -> Result<ArrayRef, ArrowError>
+where
+    T: ArrowPrimitiveType,
+    R: ArrowPrimitiveType,
+    T::Native: NumCast,
+    R::Native: NumCast + Bounded,
+{
+    let saturate = |value: T::Native| -> Option<R::Native> {
+        let v = value.to_f64()?;
+        match num::cast::cast::<T::Native, R::Native>(value) {
+            // Casting a finite float to a narrower float type overflows to infinity
+            Some(r) if !(v.is_finite() && r.to_f64().is_some_and(f64::is_infinite)) => Some(r),
+            _ if v.is_nan() => None,
+            _ if v > 0.0 => Some(R::Native::max_value()),
+            _ => Some(R::Native::min_value()),
+        }
+    };
+    let array: PrimitiveArray<R> = if safe {
+        from.unary_opt(saturate)
+    } else {
+        from.try_unary(|value| {
+            saturate(value).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Can't cast value {:?} to type {}",
+                    value,
+                    R::DATA_TYPE
+                ))
+            })
+        })?
+    };
+    Ok(Arc::new(array))
+}
+
 fn cast_numeric_to_binary<FROM: ArrowPrimitiveType, O: OffsetSizeTrait>(
     array: &dyn Array,
 ) -> Result<ArrayRef, ArrowError> {
@@ -2458,6 +2573,8 @@ mod tests {
             let cast_option = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let result = cast_with_options($INPUT_ARRAY, $OUTPUT_TYPE, &cast_option).unwrap();
             assert_eq!($OUTPUT_TYPE, result.data_type());
@@ -2734,6 +2851,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 38). Overflowing on 170141183460469231731687303715884105727",
@@ -2754,6 +2873,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 76). Overflowing on 170141183460469231731687303715884105727",
@@ -2793,6 +2914,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal128(38, 7). Overflowing on 170141183460469231731687303715884105727",
@@ -2812,6 +2935,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Cast error: Cannot cast to Decimal256(76, 55). Overflowing on 170141183460469231731687303715884105727",
@@ -2972,6 +3097,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!(
@@ -2985,6 +3112,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -2999,6 +3128,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!(
@@ -3012,6 +3143,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -3175,6 +3308,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!(
@@ -3188,6 +3323,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -3605,6 +3742,8 @@ mod tests {
         let cast_option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let result = cast_with_options(&array, &DataType::UInt8, &cast_option);
         assert!(result.is_err());
@@ -3625,6 +3764,124 @@ mod tests {
         assert!(!c.is_valid(2));
     }
 
+    #[test]
+    fn test_cast_saturating() {
+        let options = CastOptions {
+            saturate: true,
+            list_length_mismatch: ListLengthMismatch::Fail,
+            ..Default::default()
+        };
+        let array = Int32Array::from(vec![Some(300), Some(-300), Some(5), None]);
+        let b = cast_with_options(&array, &DataType::Int8, &options).unwrap();
+        let expected = Int8Array::from(vec![Some(127), Some(-128), Some(5), None]);
+        assert_eq!(b.as_primitive::<Int8Type>(), &expected);
+
+        let b = cast_with_options(&array, &DataType::UInt8, &options).unwrap();
+        let expected = UInt8Array::from(vec![Some(255), Some(0), Some(5), None]);
+        assert_eq!(b.as_primitive::<UInt8Type>(), &expected);
+
+        // Finite floats saturate, while infinity is preserved
+        let array = Float64Array::from(vec![1e300, -1e300, f64::INFINITY, 1.5]);
+        let b = cast_with_options(&array, &DataType::Float32, &options).unwrap();
+        let expected = Float32Array::from(vec![f32::MAX, f32::MIN, f32::INFINITY, 1.5]);
+        assert_eq!(b.as_primitive::<Float32Type>(), &expected);
+
+        let b = cast_with_options(&array, &DataType::Int64, &options).unwrap();
+        let expected = Int64Array::from(vec![i64::MAX, i64::MIN, i64::MAX, 1]);
+        assert_eq!(b.as_primitive::<Int64Type>(), &expected);
+
+        // NaN can not be clamped to an integer
+        let array = Float64Array::from(vec![f64::NAN, 1e10]);
+        let b = cast_with_options(&array, &DataType::Int32, &options).unwrap();
+        let expected = Int32Array::from(vec![None, Some(i32::MAX)]);
+        assert_eq!(b.as_primitive::<Int32Type>(), &expected);
+
+        let options = CastOptions {
+            safe: false,
+            ..options
+        };
+        let err = cast_with_options(&array, &DataType::Int32, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Can't cast value NaN to type Int32"
+        );
+    }
+
+    /// Checks that saturating casts of the extremes of `T` to `R` are clamped to the
+    /// extremes of `R` if they are out of range
+    fn check_saturating_cast<T, R>()
+    where
+        T: ArrowPrimitiveType,
+        R: ArrowPrimitiveType,
+        T::Native: NumCast + Bounded,
+        R::Native: NumCast + Bounded,
+    {
+        let (min, max) = (T::Native::min_value(), T::Native::max_value());
+        let array =
+            PrimitiveArray::<T>::from_iter([Some(min), Some(max), Some(T::default_value()), None]);
+        let options = CastOptions {
+            safe: false,
+            saturate: true,
+            list_length_mismatch: ListLengthMismatch::Fail,
+            ..Default::default()
+        };
+        let b = cast_with_options(&array, &R::DATA_TYPE, &options).unwrap();
+        let b = b.as_primitive::<R>();
+
+        let to_f64 = |v: T::Native| v.to_f64().unwrap();
+        let (r_min, r_max) = (R::Native::min_value(), R::Native::max_value());
+        let expected_min = match to_f64(min) <= r_min.to_f64().unwrap() {
+            true => r_min,
+            false => num::cast(min).unwrap(),
+        };
+        let expected_max = match to_f64(max) >= r_max.to_f64().unwrap() {
+            true => r_max,
+            false => num::cast(max).unwrap(),
+        };
+        let expected = vec![
+            Some(expected_min),
+            Some(expected_max),
+            Some(R::default_value()),
+            None,
+        ];
+        let (from, to) = (T::DATA_TYPE, R::DATA_TYPE);
+        assert_eq!(b.iter().collect::<Vec<_>>(), expected, "{from} to {to}");
+    }
+
+    #[test]
+    fn test_cast_saturating_all_numeric_types() {
+        macro_rules! check_saturating_casts {
+            ($($from:ty),*) => {
+                $(
+                    check_saturating_cast::<$from, Int8Type>();
+                    check_saturating_cast::<$from, Int16Type>();
+                    check_saturating_cast::<$from, Int32Type>();
+                    check_saturating_cast::<$from, Int64Type>();
+                    check_saturating_cast::<$from, UInt8Type>();
+                    check_saturating_cast::<$from, UInt16Type>();
+                    check_saturating_cast::<$from, UInt32Type>();
+                    check_saturating_cast::<$from, UInt64Type>();
+                    check_saturating_cast::<$from, Float16Type>();
+                    check_saturating_cast::<$from, Float32Type>();
+                    check_saturating_cast::<$from, Float64Type>();
+                )*
+            };
+        }
+        check_saturating_casts!(
+            Int8Type,
+            Int16Type,
+            Int32Type,
+            Int64Type,
+            UInt8Type,
+            UInt16Type,
+            UInt32Type,
+            UInt64Type,
+            Float16Type,
+            Float32Type,
+            Float64Type
+        );
+    }
+
     #[test]
     fn test_cast_i32_to_i32() {
         let array = Int32Array::from(vec![5, 6, 7, 8, 9]);
@@ -3817,6 +4074,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         match result {
@@ -3856,6 +4115,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         match casted {
@@ -4199,6 +4460,8 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let res = cast_with_options(&str, &DataType::Int16, &options).expect("should cast to i16");
         let expected =
@@ -4272,6 +4535,8 @@ mod tests {
                 let options = CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    saturate: false,
+                    list_length_mismatch: ListLengthMismatch::Fail,
                 };
                 let err = cast_with_options(array, &to_type, &options).unwrap_err();
                 assert_eq!(
@@ -4318,6 +4583,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4341,6 +4608,8 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let b = cast_with_options(&array, &to_type, &options).unwrap();
         let c = b.as_primitive::<Date32Type>();
@@ -4361,6 +4630,8 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let err = cast_with_options(&array, &to_type, &options).unwrap_err();
         assert_eq!(
@@ -4389,6 +4660,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let result = cast_with_options(&array, &to_type, &options).unwrap();
             let c = result.as_primitive::<Date32Type>();
@@ -4439,6 +4712,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Second) type");
@@ -4481,6 +4756,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string '08:08:61.091323414' to value of Time32(Millisecond) type");
@@ -4515,6 +4792,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Microsecond) type");
@@ -4549,6 +4828,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(err.to_string(), "Cast error: Cannot cast string 'Not a valid time' to value of Time64(Nanosecond) type");
@@ -4583,6 +4864,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let err = cast_with_options(array, &to_type, &options).unwrap_err();
             assert_eq!(
@@ -4599,6 +4882,8 @@ mod tests {
             let options = CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
 
             let target_interval_array = cast_with_options(
@@ -4726,6 +5011,8 @@ mod tests {
             let options = CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             };
             let arrow_err = cast_with_options(
                 &string_array.clone(),
@@ -4835,6 +5122,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(array_ref.is_err());
@@ -4845,6 +5134,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(array_ref.is_err());
@@ -5050,6 +5341,8 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let b = cast_with_options(&array, &DataType::Date64, &options);
         assert!(b.is_err());
@@ -5598,6 +5891,8 @@ mod tests {
             format_options: FormatOptions::default()
                 .with_timestamp_format(Some(ts_format))
                 .with_timestamp_tz_format(Some(ts_format)),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
 
         // "2018-12-25T00:00:02.001", "1997-05-19T00:00:03.005", None
@@ -7976,6 +8271,65 @@ mod tests {
         assert_eq!(expected.as_ref(), res.as_ref());
     }
 
+    #[test]
+    fn test_cast_list_to_fsl_truncate_or_pad() {
+        let array = Arc::new(LargeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![
+                Some(vec![Some(1), Some(2), Some(3)]),
+                Some(vec![Some(4)]),
+                None,
+                Some(vec![Some(6), Some(7), Some(8), Some(9)]),
+                Some(vec![]),
+                Some(vec![Some(3), Some(4), Some(5)]),
+            ],
+        ))
+        .slice(1, 5);
+        let field = Arc::new(Field::new_list_field(DataType::Int64, true));
+        let options = CastOptions::default()
+            .with_safe(false)
+            .with_list_length_mismatch(ListLengthMismatch::TruncateOrPad);
+        let res = cast_with_options(&array, &DataType::FixedSizeList(field, 3), &options).unwrap();
+        let expected = Arc::new(FixedSizeListArray::from_iter_primitive::<Int64Type, _, _>(
+            vec![
+                Some(vec![Some(4), None, None]),
+                None,
+                Some(vec![Some(6), Some(7), Some(8)]),
+                Some(vec![None, None, None]),
+                Some(vec![Some(3), Some(4), Some(5)]),
+            ],
+            3,
+        )) as ArrayRef;
+        assert_eq!(expected.as_ref(), res.as_ref());
+
+        // Lists can only be padded if the target field is nullable
+        let field = Arc::new(Field::new_list_field(DataType::Int32, false));
+        let res = cast_with_options(&array, &DataType::FixedSizeList(field.clone(), 3), &options);
+        assert!(res.is_err());
+
+        // Truncated lists are valid
+        let res = cast_with_options(
+            &array.slice(2, 1),
+            &DataType::FixedSizeList(field, 3),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(
+            res.as_fixed_size_list()
+                .value(0)
+                .as_primitive::<Int32Type>()
+                .values(),
+            &[6, 7, 8]
+        );
+
+        // The result can be cast back to a list
+        let list = cast(
+            expected.as_ref(),
+            &DataType::LargeList(Arc::new(Field::new_list_field(DataType::Int64, true))),
+        )
+        .unwrap();
+        assert_eq!(list.as_list::<i64>().value_offsets(), &[0, 3, 6, 9, 12, 15]);
+    }
+
     #[test]
     fn test_cast_large_list_to_fsl() {
         let values = vec![Some(vec![Some(1), Some(2)]), Some(vec![Some(3), Some(4)])];
@@ -8495,6 +8849,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -8506,6 +8862,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_err());
@@ -8521,6 +8879,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -8532,6 +8892,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_err());
@@ -8547,6 +8909,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -8558,6 +8922,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8578,6 +8944,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -8589,6 +8957,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8609,6 +8979,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -8620,6 +8992,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -8640,6 +9014,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -8651,6 +9027,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         let err = casted_array.unwrap_err().to_string();
@@ -9115,6 +9493,8 @@ mod tests {
         let option = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let casted_err = cast_with_options(&array, &output_type, &option).unwrap_err();
         assert!(casted_err
@@ -9157,6 +9537,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -9168,6 +9550,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal128 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -9240,6 +9624,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -9251,6 +9637,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Invalid argument error: 100000000000 is too large to store in a Decimal256 of precision 10. Max is 9999999999", err.unwrap_err().to_string());
@@ -9297,6 +9685,8 @@ mod tests {
         let cast_options = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
 
         let result = cast_string_to_timestamp::<i32, TimestampNanosecondType>(
@@ -9425,6 +9815,8 @@ mod tests {
                 &CastOptions {
                     safe: false,
                     format_options: FormatOptions::default(),
+                    saturate: false,
+                    list_length_mismatch: ListLengthMismatch::Fail,
                 },
             )
             .unwrap();
@@ -9476,6 +9868,8 @@ mod tests {
         let options = CastOptions {
             safe: true,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let array = cast_with_options(&s, &DataType::Utf8, &options).unwrap();
         let a = array.as_string::<i32>();
@@ -9608,6 +10002,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -9619,6 +10015,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal128 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9634,6 +10032,8 @@ mod tests {
             &CastOptions {
                 safe: true,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_ok());
@@ -9645,6 +10045,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert_eq!("Invalid argument error: 1234567000 is too large to store in a Decimal256 of precision 7. Max is 9999999", err.unwrap_err().to_string());
@@ -9692,6 +10094,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_err());
@@ -9725,6 +10129,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_err());
@@ -9758,6 +10164,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         );
         assert!(casted_array.is_err());
@@ -9784,6 +10192,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         )
         .unwrap();
@@ -9814,6 +10224,8 @@ mod tests {
         let fallible = CastOptions {
             safe: false,
             format_options: FormatOptions::default(),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let v = IntervalMonthDayNano::new(0, 0, 1234567);
 
@@ -9981,6 +10393,8 @@ mod tests {
             &CastOptions {
                 safe: false,
                 format_options: FormatOptions::default(),
+                saturate: false,
+                list_length_mismatch: ListLengthMismatch::Fail,
             },
         )
         .unwrap();
@@ -10042,6 +10456,8 @@ mod tests {
     const CAST_OPTIONS: CastOptions<'static> = CastOptions {
         safe: true,
         format_options: FormatOptions::new(),
+        saturate: false,
+        list_length_mismatch: ListLengthMismatch::Fail,
     };
 
     #[test]
@@ -10055,6 +10471,8 @@ mod tests {
         let options = CastOptions {
             safe: false,
             format_options: FormatOptions::default().with_null("null"),
+            saturate: false,
+            list_length_mismatch: ListLengthMismatch::Fail,
         };
         let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
             Some(vec![Some(0), Some(1), Some(2)]),
@@ -48,6 +48,22 @@ pub enum DurationFormat {
     Pretty,
 }
 
+/// Output format of the tables created by the [`pretty`] module
+///
+/// [`pretty`]: crate::pretty
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TableFormat {
+    /// A table drawn with ASCII characters
+    Ascii,
+    /// A [GitHub Flavored Markdown] table
+    ///
+    /// [GitHub Flavored Markdown]: https://github.github.com/gfm/#tables-extension-
+    Markdown,
+    /// An HTML `<table>` element
+    Html,
+}
+
 /// Options for formatting arrays
 ///
 /// By default nulls are formatted as `""` and temporal types formatted
@@ -74,6 +90,14 @@ pub struct FormatOptions<'a> {
     duration_format: DurationFormat,
     /// Show types in visual representation batches
     types_info: bool,
+    /// Maximum width of the values in visual representation of batches
+    max_column_width: Option<usize>,
+    /// Number of rows to show from the start of visual representation of batches
+    head_rows: Option<usize>,
+    /// Number of rows to show from the end of visual representation of batches
+    tail_rows: Option<usize>,
+    /// Output format of visual representation of batches
+    table_format: TableFormat,
 }
 
 impl Default for FormatOptions<'_> {
@@ -95,6 +119,10 @@ impl<'a> FormatOptions<'a> {
             time_format: None,
             duration_format: DurationFormat::ISO8601,
             types_info: false,
+            max_column_width: None,
+            head_rows: None,
+            tail_rows: None,
+            table_format: TableFormat::Ascii,
         }
     }
 
@@ -173,6 +201,67 @@ impl<'a> FormatOptions<'a> {
     pub const fn types_info(&self) -> bool {
         self.types_info
     }
+
+    /// Overrides the maximum width in characters of the values in visual representation of
+    /// batches, where longer values are truncated and end with an ellipsis (`…`)
+    ///
+    /// Defaults to `None`, which does not truncate values. A width of `0` does not truncate
+    /// values either.
+    pub const fn with_max_column_width(self, max_column_width: Option<usize>) -> Self {
+        Self {
+            max_column_width,
+            ..self
+        }
+    }
+
+    /// Returns the maximum width of the values in visual representation of batches, if any
+    pub const fn max_column_width(&self) -> Option<usize> {
+        self.max_column_width
+    }
+
+    /// Overrides the number of rows to show from the start of visual representation of
+    /// batches
+    ///
+    /// If this or [`Self::with_tail_rows`] is set, only the first `head_rows` and last
+    /// `tail_rows` rows are shown, with a row of `...` in place of the omitted rows.
+    ///
+    /// Defaults to `None`, which shows all rows unless [`Self::with_tail_rows`] is set
+    pub const fn with_head_rows(self, head_rows: Option<usize>) -> Self {
+        Self { head_rows, ..self }
+    }
+
+    /// Returns the number of rows to show from the start of visual representation of batches
+    pub const fn head_rows(&self) -> Option<usize> {
+        self.head_rows
+    }
+
+    /// Overrides the number of rows to show from the end of visual representation of
+    /// batches, see [`Self::with_head_rows`]
+    ///
+    /// Defaults to `None`, which shows all rows unless [`Self::with_head_rows`] is set
+    pub const fn with_tail_rows(self, tail_rows: Option<usize>) -> Self {
+        Self { tail_rows, ..self }
+    }
+
+    /// Returns the number of rows to show from the end of visual representation of batches
+    pub const fn tail_rows(&self) -> Option<usize> {
+        self.tail_rows
+    }
+
+    /// Overrides the output format of visual representation of batches
+    ///
+    /// Defaults to [`TableFormat::Ascii`]
+    pub const fn with_table_format(self, table_format: TableFormat) -> Self {
+        Self {
+            table_format,
+            ..self
+        }
+    }
+
+    /// Returns the output format of visual representation of batches
+    pub const fn table_format(&self) -> TableFormat {
+        self.table_format
+    }
 }
 
 /// Implements [`Display`] for a specific array value
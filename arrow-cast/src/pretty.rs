@@ -24,12 +24,13 @@
 
 use std::fmt::Display;
 
+use comfy_table::presets::ASCII_MARKDOWN;
 use comfy_table::{Cell, Table};
 
 use arrow_array::{Array, ArrayRef, RecordBatch};
 use arrow_schema::{ArrowError, SchemaRef};
 
-use crate::display::{ArrayFormatter, FormatOptions};
+use crate::display::{ArrayFormatter, FormatOptions, TableFormat};
 
 /// Create a visual representation of [`RecordBatch`]es
 ///
@@ -175,10 +176,7 @@ fn create_table(
     schema_opt: Option<SchemaRef>,
     results: &[RecordBatch],
     options: &FormatOptions,
-) -> Result<Table, ArrowError> {
-    let mut table = Table::new();
-    table.load_preset("||--+-++|    ++++++");
-
+) -> Result<PrettyTable, ArrowError> {
     let schema_opt = schema_opt.or_else(|| {
         if results.is_empty() {
             None
@@ -187,26 +185,19 @@ fn create_table(
         }
     });
 
-    if let Some(schema) = schema_opt {
-        let mut header = Vec::new();
-        for field in schema.fields() {
-            if options.types_info() {
-                header.push(Cell::new(format!(
-                    "{}\n{}",
-                    field.name(),
-                    field.data_type()
-                )))
-            } else {
-                header.push(Cell::new(field.name()));
-            }
-        }
-        table.set_header(header);
-    }
-
-    if results.is_empty() {
-        return Ok(table);
-    }
+    let header = schema_opt.map(|schema| {
+        schema
+            .fields()
+            .iter()
+            .map(|field| match options.types_info() {
+                true => format!("{}\n{}", field.name(), field.data_type()),
+                false => field.name().to_string(),
+            })
+            .collect()
+    });
 
+    let num_rows = results.iter().map(|batch| batch.num_rows()).sum();
+    let mut rows = RowsBuilder::new(num_rows, options);
     for batch in results {
         let formatters = batch
             .columns()
@@ -214,42 +205,193 @@ fn create_table(
             .map(|c| ArrayFormatter::try_new(c.as_ref(), options))
             .collect::<Result<Vec<_>, ArrowError>>()?;
 
-        for row in 0..batch.num_rows() {
-            let mut cells = Vec::new();
-            for formatter in &formatters {
-                cells.push(Cell::new(formatter.value(row)));
-            }
-            table.add_row(cells);
-        }
+        rows.append(batch.num_rows(), |row| {
+            formatters
+                .iter()
+                .map(|formatter| formatter.value(row).to_string())
+                .collect()
+        });
     }
 
-    Ok(table)
+    Ok(PrettyTable::new(header, rows.finish(), options))
 }
 
 fn create_column(
     field: &str,
     columns: &[ArrayRef],
     options: &FormatOptions,
-) -> Result<Table, ArrowError> {
-    let mut table = Table::new();
-    table.load_preset("||--+-++|    ++++++");
-
+) -> Result<PrettyTable, ArrowError> {
     if columns.is_empty() {
-        return Ok(table);
+        return Ok(PrettyTable::new(None, vec![], options));
     }
 
-    let header = vec![Cell::new(field)];
-    table.set_header(header);
-
+    let num_rows = columns.iter().map(|col| col.len()).sum();
+    let mut rows = RowsBuilder::new(num_rows, options);
     for col in columns {
         let formatter = ArrayFormatter::try_new(col.as_ref(), options)?;
-        for row in 0..col.len() {
-            let cells = vec![Cell::new(formatter.value(row))];
-            table.add_row(cells);
+        rows.append(col.len(), |row| vec![formatter.value(row).to_string()]);
+    }
+
+    Ok(PrettyTable::new(
+        Some(vec![field.to_string()]),
+        rows.finish(),
+        options,
+    ))
+}
+
+/// Collects the formatted rows of a table, keeping only the rows selected by
+/// [`FormatOptions::with_head_rows`] and [`FormatOptions::with_tail_rows`]
+struct RowsBuilder<'a> {
+    options: &'a FormatOptions<'a>,
+    num_rows: usize,
+    /// The index of the next row to append
+    row: usize,
+    /// The rows of the table, where `None` stands for the omitted rows
+    rows: Vec<Option<Vec<String>>>,
+}
+
+impl<'a> RowsBuilder<'a> {
+    fn new(num_rows: usize, options: &'a FormatOptions<'a>) -> Self {
+        Self {
+            options,
+            num_rows,
+            row: 0,
+            rows: vec![],
+        }
+    }
+
+    /// Append `len` rows, formatting the selected rows with `format_row`
+    fn append(&mut self, len: usize, mut format_row: impl FnMut(usize) -> Vec<String>) {
+        for i in 0..len {
+            if self.is_selected(self.row) {
+                let cells = format_row(i)
+                    .into_iter()
+                    .map(|cell| self.truncate(cell))
+                    .collect();
+                self.rows.push(Some(cells));
+            } else if self.rows.last().is_none_or(Option::is_some) {
+                self.rows.push(None);
+            }
+            self.row += 1;
+        }
+    }
+
+    fn is_selected(&self, row: usize) -> bool {
+        match (self.options.head_rows(), self.options.tail_rows()) {
+            (None, None) => true,
+            (head, tail) => row < head.unwrap_or(0) || self.num_rows - row <= tail.unwrap_or(0),
         }
     }
 
-    Ok(table)
+    fn truncate(&self, cell: String) -> String {
+        match self.options.max_column_width() {
+            Some(width) if width > 0 && cell.chars().count() > width => {
+                let mut truncated: String = cell.chars().take(width.saturating_sub(1)).collect();
+                truncated.push('…');
+                truncated
+            }
+            _ => cell,
+        }
+    }
+
+    fn finish(self) -> Vec<Option<Vec<String>>> {
+        self.rows
+    }
+}
+
+/// A table in the [`TableFormat`] of its [`FormatOptions`]
+enum PrettyTable {
+    Table(Table),
+    Html(String),
+}
+
+impl PrettyTable {
+    /// Create a table with the cells of `header` and `rows`, where `None` rows stand for
+    /// omitted rows
+    fn new(
+        header: Option<Vec<String>>,
+        rows: Vec<Option<Vec<String>>>,
+        options: &FormatOptions,
+    ) -> Self {
+        let num_columns = header
+            .as_ref()
+            .or_else(|| rows.iter().flatten().next())
+            .map_or(0, Vec::len);
+        let rows = rows
+            .into_iter()
+            .map(|row| row.unwrap_or_else(|| vec!["...".to_string(); num_columns]));
+
+        match options.table_format() {
+            TableFormat::Ascii => {
+                let mut table = Table::new();
+                table.load_preset("||--+-++|    ++++++");
+                if let Some(header) = header {
+                    table.set_header(header.into_iter().map(Cell::new));
+                }
+                for row in rows {
+                    table.add_row(row.into_iter().map(Cell::new));
+                }
+                Self::Table(table)
+            }
+            TableFormat::Markdown => {
+                let escape =
+                    |cell: String| Cell::new(cell.replace('|', "\\|").replace('\n', "<br>"));
+                let mut table = Table::new();
+                table.load_preset(ASCII_MARKDOWN);
+                if let Some(header) = header {
+                    table.set_header(header.into_iter().map(escape));
+                }
+                for row in rows {
+                    table.add_row(row.into_iter().map(escape));
+                }
+                Self::Table(table)
+            }
+            TableFormat::Html => {
+                let mut html = String::from("<table>\n");
+                if let Some(header) = header {
+                    html.push_str("<thead>\n");
+                    push_html_row(&mut html, "th", header);
+                    html.push_str("</thead>\n");
+                }
+                html.push_str("<tbody>\n");
+                for row in rows {
+                    push_html_row(&mut html, "td", row);
+                }
+                html.push_str("</tbody>\n</table>");
+                Self::Html(html)
+            }
+        }
+    }
+}
+
+impl Display for PrettyTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Table(table) => write!(f, "{table}"),
+            Self::Html(html) => write!(f, "{html}"),
+        }
+    }
+}
+
+/// Append an HTML table row of `cells` with elements `tag` to `html`
+fn push_html_row(html: &mut String, tag: &str, cells: Vec<String>) {
+    html.push_str("<tr>");
+    for cell in cells {
+        html.push_str(&format!("<{tag}>"));
+        for c in cell.chars() {
+            match c {
+                '&' => html.push_str("&amp;"),
+                '<' => html.push_str("&lt;"),
+                '>' => html.push_str("&gt;"),
+                '"' => html.push_str("&quot;"),
+                '\'' => html.push_str("&#39;"),
+                '\n' => html.push_str("<br>"),
+                c => html.push(c),
+            }
+        }
+        html.push_str(&format!("</{tag}>"));
+    }
+    html.push_str("</tr>\n");
 }
 
 #[cfg(test)]
@@ -1282,4 +1424,161 @@ mod tests {
         let actual: Vec<&str> = iso.lines().collect();
         assert_eq!(expected_iso, actual, "Actual result:\n{iso}");
     }
+
+    fn preview_batches() -> Vec<RecordBatch> {
+        let batch = |ids: Vec<i32>, names: Vec<Option<&str>>| {
+            RecordBatch::try_from_iter(vec![
+                ("id", Arc::new(Int32Array::from(ids)) as ArrayRef),
+                ("name", Arc::new(StringArray::from(names)) as ArrayRef),
+            ])
+            .unwrap()
+        };
+        vec![
+            batch(vec![1, 2], vec![Some("a|b"), Some("a long name")]),
+            batch(vec![3, 4, 5], vec![None, Some("<tag>"), Some("e")]),
+        ]
+    }
+
+    #[test]
+    fn test_pretty_format_head_tail_rows() {
+        let batches = preview_batches();
+
+        let options = FormatOptions::new()
+            .with_head_rows(Some(1))
+            .with_tail_rows(Some(2));
+        let table = pretty_format_batches_with_options(&batches, &options)
+            .unwrap()
+            .to_string();
+        let expected = vec![
+            "+-----+-------+",
+            "| id  | name  |",
+            "+-----+-------+",
+            "| 1   | a|b   |",
+            "| ... | ...   |",
+            "| 4   | <tag> |",
+            "| 5   | e     |",
+            "+-----+-------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        let options = FormatOptions::new().with_tail_rows(Some(1));
+        let table = pretty_format_batches_with_options(&batches, &options)
+            .unwrap()
+            .to_string();
+        let expected = vec![
+            "+-----+------+",
+            "| id  | name |",
+            "+-----+------+",
+            "| ... | ...  |",
+            "| 5   | e    |",
+            "+-----+------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        // No rows are omitted if the head and tail overlap
+        let options = FormatOptions::new()
+            .with_head_rows(Some(3))
+            .with_tail_rows(Some(3));
+        let table = pretty_format_batches_with_options(&batches, &options).unwrap();
+        assert_eq!(
+            table.to_string(),
+            pretty_format_batches(&batches).unwrap().to_string()
+        );
+
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let options = FormatOptions::new().with_head_rows(Some(2));
+        let table = pretty_format_columns_with_options("a", &[array], &options)
+            .unwrap()
+            .to_string();
+        let expected = vec![
+            "+-----+", "| a   |", "+-----+", "| 1   |", "| 2   |", "| ... |", "+-----+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+    }
+
+    #[test]
+    fn test_pretty_format_max_column_width() {
+        let options = FormatOptions::new().with_max_column_width(Some(6));
+        let table = pretty_format_batches_with_options(&preview_batches(), &options)
+            .unwrap()
+            .to_string();
+        let expected = vec![
+            "+----+--------+",
+            "| id | name   |",
+            "+----+--------+",
+            "| 1  | a|b    |",
+            "| 2  | a lon… |",
+            "| 3  |        |",
+            "| 4  | <tag>  |",
+            "| 5  | e      |",
+            "+----+--------+",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        // A width of 0 does not truncate values
+        let options = FormatOptions::new().with_max_column_width(Some(0));
+        let table = pretty_format_batches_with_options(&preview_batches(), &options).unwrap();
+        let expected = pretty_format_batches(&preview_batches()).unwrap();
+        assert_eq!(table.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_pretty_format_markdown() {
+        let options = FormatOptions::new()
+            .with_table_format(TableFormat::Markdown)
+            .with_head_rows(Some(2))
+            .with_null("NULL");
+        let table = pretty_format_batches_with_options(&preview_batches(), &options)
+            .unwrap()
+            .to_string();
+        let expected = vec![
+            "| id  | name        |",
+            "|-----|-------------|",
+            "| 1   | a\\|b        |",
+            "| 2   | a long name |",
+            "| ... | ...         |",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+    }
+
+    #[test]
+    fn test_pretty_format_html() {
+        let options = FormatOptions::new()
+            .with_table_format(TableFormat::Html)
+            .with_types_info(true)
+            .with_tail_rows(Some(2));
+        let table = pretty_format_batches_with_options(&preview_batches(), &options)
+            .unwrap()
+            .to_string();
+        let expected = vec![
+            "<table>",
+            "<thead>",
+            "<tr><th>id<br>Int32</th><th>name<br>Utf8</th></tr>",
+            "</thead>",
+            "<tbody>",
+            "<tr><td>...</td><td>...</td></tr>",
+            "<tr><td>4</td><td>&lt;tag&gt;</td></tr>",
+            "<tr><td>5</td><td>e</td></tr>",
+            "</tbody>",
+            "</table>",
+        ];
+        let actual: Vec<&str> = table.lines().collect();
+        assert_eq!(expected, actual, "Actual result:\n{table}");
+
+        // Empty batches only have a header
+        let schema = preview_batches()[0].schema();
+        let options = FormatOptions::new().with_table_format(TableFormat::Html);
+        let table = create_table(Some(schema), &[], &options)
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            table,
+            "<table>\n<thead>\n<tr><th>id</th><th>name</th></tr>\n</thead>\n<tbody>\n</tbody>\n</table>"
+        );
+    }
 }
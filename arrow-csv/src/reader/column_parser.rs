@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow_array::builder::PrimitiveBuilder;
+use arrow_array::{ArrayRef, ArrowPrimitiveType, PrimitiveArray};
+use arrow_schema::{ArrowError, Field};
+
+/// A custom parser for the values of a CSV column
+///
+/// Parsers are registered for specific columns with
+/// [`ReaderBuilder::with_column_parser`](super::ReaderBuilder::with_column_parser), and are
+/// invoked instead of the default parser for the column's data type. This allows reading
+/// formats the default parsers do not support, such as numbers with a decimal comma.
+///
+/// See [`PrimitiveColumnParser`] for parsing primitive columns one value at a time.
+pub trait ColumnParser: Debug + Send + Sync {
+    /// Parse the values of the column for a batch of rows into an array of the data type
+    /// of `field`
+    ///
+    /// `values` yields the value of each row in order, with `None` for values matching the
+    /// reader's null regex. If a value cannot be parsed, an error should be returned before
+    /// taking any further values from `values`, in which case the reader adds the column and
+    /// line number of the value to the error.
+    fn parse(
+        &self,
+        field: &Field,
+        values: &mut dyn Iterator<Item = Option<&str>>,
+    ) -> Result<ArrayRef, ArrowError>;
+}
+
+/// A [`ColumnParser`] that parses each non-null value of a primitive column with a function
+///
+/// The data type of the column may be any type compatible with `T`, such as a decimal with
+/// a specific precision and scale, or a timestamp with a timezone.
+///
+/// # Example
+///
+/// Reading floating point numbers with a decimal comma
+///
+/// ```
+/// # use std::io::Cursor;
+/// # use std::sync::Arc;
+/// # use arrow_array::cast::AsArray;
+/// # use arrow_array::types::Float64Type;
+/// # use arrow_csv::reader::PrimitiveColumnParser;
+/// # use arrow_csv::ReaderBuilder;
+/// # use arrow_schema::{ArrowError, DataType, Field, Schema};
+/// let schema = Schema::new(vec![
+///     Field::new("city", DataType::Utf8, false),
+///     Field::new("temperature", DataType::Float64, true),
+/// ]);
+/// let parser = PrimitiveColumnParser::<Float64Type, _>::new(|s| {
+///     s.replacen(',', ".", 1)
+///         .parse()
+///         .map_err(|e| ArrowError::ParseError(format!("invalid number '{s}': {e}")))
+/// });
+///
+/// let csv = "Paris;12,5\nBerlin;-3,25\n";
+/// let mut reader = ReaderBuilder::new(Arc::new(schema))
+///     .with_delimiter(b';')
+///     .with_column_parser(1, Arc::new(parser))
+///     .build(Cursor::new(csv))
+///     .unwrap();
+///
+/// let batch = reader.next().unwrap().unwrap();
+/// let temperature = batch.column(1).as_primitive::<Float64Type>();
+/// assert_eq!(temperature.values(), &[12.5, -3.25]);
+/// ```
+pub struct PrimitiveColumnParser<T, F> {
+    parse: F,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, F> PrimitiveColumnParser<T, F>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(&str) -> Result<T::Native, ArrowError> + Send + Sync,
+{
+    /// Create a new parser that parses each non-null value with `parse`
+    pub fn new(parse: F) -> Self {
+        Self {
+            parse,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: ArrowPrimitiveType, F> Debug for PrimitiveColumnParser<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrimitiveColumnParser")
+            .field("data_type", &T::DATA_TYPE)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, F> ColumnParser for PrimitiveColumnParser<T, F>
+where
+    T: ArrowPrimitiveType,
+    F: Fn(&str) -> Result<T::Native, ArrowError> + Send + Sync,
+{
+    fn parse(
+        &self,
+        field: &Field,
+        values: &mut dyn Iterator<Item = Option<&str>>,
+    ) -> Result<ArrayRef, ArrowError> {
+        if !PrimitiveArray::<T>::is_compatible(field.data_type()) {
+            return Err(ArrowError::SchemaError(format!(
+                "Cannot parse column '{}' of type {} as {}",
+                field.name(),
+                field.data_type(),
+                T::DATA_TYPE
+            )));
+        }
+
+        let mut builder = PrimitiveBuilder::<T>::with_capacity(values.size_hint().0)
+            .with_data_type(field.data_type().clone());
+        for value in values {
+            match value {
+                Some(s) => builder.append_value((self.parse)(s)?),
+                None => builder.append_null(),
+            }
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+}
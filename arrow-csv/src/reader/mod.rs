@@ -123,6 +123,7 @@
 //! ```
 //!
 
+mod column_parser;
 mod records;
 
 use arrow_array::builder::{NullBuilder, PrimitiveBuilder};
@@ -141,6 +142,9 @@ use std::sync::{Arc, LazyLock};
 use crate::map_csv_error;
 use crate::reader::records::{RecordDecoder, StringRecords};
 use arrow_array::timezone::Tz;
+use std::collections::HashMap;
+
+pub use column_parser::{ColumnParser, PrimitiveColumnParser};
 
 /// Order should match [`InferredDataType`]
 static REGEX_SET: LazyLock<RegexSet> = LazyLock::new(|| {
@@ -576,6 +580,9 @@ pub struct Decoder {
 
     /// Check if the string matches this pattern for `NULL`.
     null_regex: NullRegex,
+
+    /// Custom parsers for specific columns (zero-based column indices)
+    column_parsers: HashMap<usize, Arc<dyn ColumnParser>>,
 }
 
 impl Decoder {
@@ -622,6 +629,7 @@ impl Decoder {
             self.projection.as_ref(),
             self.line_number,
             &self.null_regex,
+            &self.column_parsers,
         )?;
         self.line_number += rows.len();
         Ok(Some(batch))
@@ -641,6 +649,7 @@ fn parse(
     projection: Option<&Vec<usize>>,
     line_number: usize,
     null_regex: &NullRegex,
+    column_parsers: &HashMap<usize, Arc<dyn ColumnParser>>,
 ) -> Result<RecordBatch, ArrowError> {
     let projection: Vec<usize> = match projection {
         Some(v) => v.clone(),
@@ -652,6 +661,16 @@ fn parse(
         .map(|i| {
             let i = *i;
             let field = &fields[i];
+            if let Some(parser) = column_parsers.get(&i) {
+                return build_custom_array(
+                    line_number,
+                    rows,
+                    i,
+                    field,
+                    parser.as_ref(),
+                    null_regex,
+                );
+            }
             match field.data_type() {
                 DataType::Boolean => build_boolean_array(line_number, rows, i, null_regex),
                 DataType::Decimal128(precision, scale) => build_decimal_array::<Decimal128Type>(
@@ -883,6 +902,34 @@ fn parse_bool(string: &str) -> Option<bool> {
     }
 }
 
+// parses a specific column (col_idx) into an Arrow Array using a custom parser
+fn build_custom_array(
+    line_number: usize,
+    rows: &StringRecords<'_>,
+    col_idx: usize,
+    field: &Field,
+    parser: &dyn ColumnParser,
+    null_regex: &NullRegex,
+) -> Result<ArrayRef, ArrowError> {
+    // The number of values taken by the parser, used to locate the value that failed to parse
+    let mut taken = 0usize;
+    let result = {
+        let mut values = rows.iter().map(|row| {
+            taken += 1;
+            let s = row.get(col_idx);
+            (!null_regex.is_null(s)).then_some(s)
+        });
+        parser.parse(field, &mut values)
+    };
+    result.map_err(|e| match taken {
+        0 => ArrowError::ParseError(format!("Error parsing column {col_idx}: {e}")),
+        _ => ArrowError::ParseError(format!(
+            "Error parsing column {col_idx} at line {}: {e}",
+            line_number + taken - 1
+        )),
+    })
+}
+
 // parse the column string to an Arrow Array
 fn build_decimal_array<T: DecimalType>(
     _line_number: usize,
@@ -1052,6 +1099,8 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// Custom parsers for specific columns (zero-based column indices)
+    column_parsers: HashMap<usize, Arc<dyn ColumnParser>>,
 }
 
 impl ReaderBuilder {
@@ -1083,6 +1132,7 @@ impl ReaderBuilder {
             batch_size: 1024,
             bounds: None,
             projection: None,
+            column_parsers: HashMap::new(),
         }
     }
 
@@ -1164,6 +1214,16 @@ impl ReaderBuilder {
         self
     }
 
+    /// Parse the column at index `col_idx` of the schema with `parser`, instead of the
+    /// default parser for its data type
+    ///
+    /// Errors returned by the parser are reported with the column index and line number
+    /// of the value that failed to parse. See [`PrimitiveColumnParser`] for an example.
+    pub fn with_column_parser(mut self, col_idx: usize, parser: Arc<dyn ColumnParser>) -> Self {
+        self.column_parsers.insert(col_idx, parser);
+        self
+    }
+
     /// Create a new `Reader` from a non-buffered reader
     ///
     /// If `R: BufRead` consider using [`Self::build_buffered`] to avoid unnecessary additional
@@ -1205,6 +1265,7 @@ impl ReaderBuilder {
             projection: self.projection,
             batch_size: self.batch_size,
             null_regex: self.format.null_regex,
+            column_parsers: self.column_parsers,
         }
     }
 }
@@ -2731,4 +2792,104 @@ mod tests {
         assert_eq!(c2.value(1), "something_cannot_be_inlined");
         assert_eq!(c2.value(2), "bar");
     }
+
+    /// Parses coordinates such as `51°30'26"N` into decimal degrees
+    #[derive(Debug)]
+    struct DmsParser;
+
+    impl DmsParser {
+        fn parse_value(s: &str) -> Result<f64, ArrowError> {
+            let err = || ArrowError::ParseError(format!("invalid coordinate '{s}'"));
+            let (degrees, rest) = s.split_once('°').ok_or_else(err)?;
+            let (minutes, rest) = rest.split_once('\'').ok_or_else(err)?;
+            let (seconds, hemisphere) = rest.split_once('"').ok_or_else(err)?;
+            let parse = |v: &str| v.parse::<f64>().map_err(|_| err());
+            let value = parse(degrees)? + parse(minutes)? / 60. + parse(seconds)? / 3600.;
+            match hemisphere {
+                "N" | "E" => Ok(value),
+                "S" | "W" => Ok(-value),
+                _ => Err(err()),
+            }
+        }
+    }
+
+    impl ColumnParser for DmsParser {
+        fn parse(
+            &self,
+            _field: &Field,
+            values: &mut dyn Iterator<Item = Option<&str>>,
+        ) -> Result<ArrayRef, ArrowError> {
+            let values = values
+                .map(|v| v.map(Self::parse_value).transpose())
+                .collect::<Result<Float64Array, _>>()?;
+            Ok(Arc::new(values))
+        }
+    }
+
+    #[test]
+    fn test_column_parser() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("city", DataType::Utf8, false),
+            Field::new("lat", DataType::Float64, true),
+            Field::new("price", DataType::Decimal128(10, 2), true),
+        ]));
+        let csv =
+            "city|lat|price\nLondon|51°30'0\"N|12,50\nSydney|33°52'12\"S|nil\nNowhere|nil|0,01\n";
+        let price = PrimitiveColumnParser::<Decimal128Type, _>::new(|s| {
+            parse_decimal::<Decimal128Type>(&s.replacen(',', ".", 1), 10, 2)
+        });
+        let mut reader = ReaderBuilder::new(schema)
+            .with_header(true)
+            .with_delimiter(b'|')
+            .with_null_regex(Regex::new("^nil$").unwrap())
+            .with_column_parser(1, Arc::new(DmsParser))
+            .with_column_parser(2, Arc::new(price))
+            .with_projection(vec![1, 2])
+            .build(Cursor::new(csv))
+            .unwrap();
+
+        let batch = reader.next().unwrap().unwrap();
+        let lat = batch.column(0).as_primitive::<Float64Type>();
+        assert_eq!(lat.len(), 3);
+        assert_eq!(lat.value(0), 51.5);
+        assert!((lat.value(1) + 33.87).abs() < 1e-9);
+        assert!(lat.is_null(2));
+
+        let price = batch.column(1).as_primitive::<Decimal128Type>();
+        assert_eq!(price.data_type(), &DataType::Decimal128(10, 2));
+        assert_eq!(
+            price.iter().collect::<Vec<_>>(),
+            vec![Some(1250), None, Some(1)]
+        );
+    }
+
+    #[test]
+    fn test_column_parser_error() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("lat", DataType::Float64, true),
+        ]));
+        let csv = "1,10°0'0\"N\n2,91°0'0\"X\n3,0°0'0\"E\n";
+        let mut reader = ReaderBuilder::new(schema.clone())
+            .with_column_parser(1, Arc::new(DmsParser))
+            .build(Cursor::new(csv))
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error parsing column 1 at line 1: Parser error: invalid coordinate '91°0'0\"X'"
+        );
+
+        // Parsers must produce arrays compatible with the column
+        let parser = PrimitiveColumnParser::<Int32Type, _>::new(|_| Ok(0));
+        let mut reader = ReaderBuilder::new(schema)
+            .with_column_parser(1, Arc::new(parser))
+            .build(Cursor::new(csv))
+            .unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Error parsing column 1: Schema error: Cannot parse column 'lat' of type Float64 as Int32"
+        );
+    }
 }
@@ -31,10 +31,14 @@ use futures::{
     Stream, StreamExt, TryStreamExt,
 };
 use prost::Message;
-use tonic::{metadata::MetadataMap, transport::Channel};
+use tonic::{
+    metadata::MetadataMap,
+    transport::{Channel, Endpoint},
+};
 
 use crate::error::{FlightError, Result};
 use crate::streams::{FallibleRequestStream, FallibleTonicResponseStream};
+use crate::transport::TransportConfig;
 
 /// A "Mid level" [Apache Arrow Flight](https://arrow.apache.org/docs/format/Flight.html) client.
 ///
@@ -81,6 +85,18 @@ impl FlightClient {
         Self::new_from_inner(FlightServiceClient::new(channel))
     }
 
+    /// Connects to the server at `endpoint`, with the transport settings of `config`
+    ///
+    /// See [`TransportConfig`] for an example.
+    pub async fn connect(endpoint: Endpoint, config: &TransportConfig) -> Result<Self> {
+        let channel = config
+            .configure_endpoint(endpoint)
+            .connect()
+            .await
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        Ok(Self::new(channel))
+    }
+
     /// Creates a new higher level client with the provided lower level client
     pub fn new_from_inner(inner: FlightServiceClient<Channel>) -> Self {
         Self {
@@ -671,3 +687,136 @@ impl FlightClient {
         request
     }
 }
+
+/// Drives the `PollFlightInfo` protocol for a long-running query.
+///
+/// Each call to [`Self::poll`] issues a `PollFlightInfo` request using the
+/// most recent retry descriptor returned by the server, and returns any
+/// [`FlightEndpoint`]s that were not returned by a previous call, so that
+/// clients can begin fetching results with [`FlightClient::do_get`] before
+/// the query has completed.
+///
+/// The query is complete once the server returns a [`PollInfo`] without a
+/// `flight_descriptor`, see [`Self::is_complete`]. A running query can be
+/// cancelled with [`Self::cancel`].
+///
+/// The poller does not wait between requests; callers should apply whatever
+/// backoff is appropriate for their runtime between calls to [`Self::poll`].
+///
+/// # Example:
+/// ```no_run
+/// # async fn run() {
+/// # use arrow_flight::{FlightClient, FlightDescriptor};
+/// # use arrow_flight::client::FlightInfoPoller;
+/// # let channel: tonic::transport::Channel = unimplemented!();
+/// let mut client = FlightClient::new(channel);
+///
+/// let request = FlightDescriptor::new_cmd(b"MOAR DATA".to_vec());
+/// let mut poller = FlightInfoPoller::new(request);
+///
+/// while !poller.is_complete() {
+///     for endpoint in poller.poll(&mut client).await.expect("error polling") {
+///         let ticket = endpoint.ticket.expect("expected ticket");
+///         let data = client.do_get(ticket).await.expect("error fetching data");
+///     }
+///     println!("progress: {:?}", poller.progress());
+///     // sleep before polling again
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlightInfoPoller {
+    /// The descriptor to use for the next request, `None` once complete
+    next_descriptor: Option<FlightDescriptor>,
+    /// The most recent response from the server
+    last_response: Option<PollInfo>,
+    /// The number of endpoints already returned from [`Self::poll`]
+    endpoints_seen: usize,
+}
+
+impl FlightInfoPoller {
+    /// Create a new [`FlightInfoPoller`] that will start the query
+    /// described by `descriptor` on the first call to [`Self::poll`]
+    pub fn new(descriptor: FlightDescriptor) -> Self {
+        Self {
+            next_descriptor: Some(descriptor),
+            last_response: None,
+            endpoints_seen: 0,
+        }
+    }
+
+    /// Returns true if the server has reported the query complete,
+    /// or it has been cancelled with [`Self::cancel`]
+    pub fn is_complete(&self) -> bool {
+        self.next_descriptor.is_none()
+    }
+
+    /// Returns the progress of the query in `[0.0, 1.0]`, if reported
+    /// by the server in the most recent response
+    pub fn progress(&self) -> Option<f64> {
+        self.last_response.as_ref().and_then(|r| r.progress)
+    }
+
+    /// Returns the most recent [`FlightInfo`] received from the server, if any
+    ///
+    /// Each response contains the complete [`FlightInfo`] so far, including
+    /// endpoints already returned by [`Self::poll`]
+    pub fn flight_info(&self) -> Option<&FlightInfo> {
+        self.last_response.as_ref().and_then(|r| r.info.as_ref())
+    }
+
+    /// Returns the most recent [`PollInfo`] received from the server, if any
+    pub fn poll_info(&self) -> Option<&PollInfo> {
+        self.last_response.as_ref()
+    }
+
+    /// Make a `PollFlightInfo` call to the server, returning the
+    /// [`FlightEndpoint`]s not returned by any previous call.
+    ///
+    /// Returns an error if the query is already complete, or if the server
+    /// returns fewer endpoints than a previous response, as endpoints may
+    /// only be appended.
+    pub async fn poll(&mut self, client: &mut FlightClient) -> Result<Vec<FlightEndpoint>> {
+        let descriptor = self
+            .next_descriptor
+            .clone()
+            .ok_or_else(|| FlightError::protocol("PollFlightInfo query is already complete"))?;
+
+        let response = client.poll_flight_info(descriptor).await?;
+
+        let endpoints = response
+            .info
+            .as_ref()
+            .map(|info| info.endpoint.as_slice())
+            .unwrap_or_default();
+        if endpoints.len() < self.endpoints_seen {
+            return Err(FlightError::protocol(format!(
+                "PollFlightInfo response contained {} endpoints, but {} were previously returned",
+                endpoints.len(),
+                self.endpoints_seen
+            )));
+        }
+        let new_endpoints = endpoints[self.endpoints_seen..].to_vec();
+
+        self.endpoints_seen = endpoints.len();
+        self.next_descriptor = response.flight_descriptor.clone();
+        self.last_response = Some(response);
+        Ok(new_endpoints)
+    }
+
+    /// Make a `CancelFlightInfo` call to the server for the running query,
+    /// using the most recent [`FlightInfo`], and mark the query complete
+    ///
+    /// Returns an error if no [`FlightInfo`] has been received yet
+    pub async fn cancel(&mut self, client: &mut FlightClient) -> Result<CancelFlightInfoResult> {
+        let info = self.flight_info().cloned().ok_or_else(|| {
+            FlightError::protocol("Cannot cancel PollFlightInfo query before receiving FlightInfo")
+        })?;
+
+        let result = client
+            .cancel_flight_info(CancelFlightInfoRequest::new(info))
+            .await?;
+        self.next_descriptor = None;
+        Ok(result)
+    }
+}
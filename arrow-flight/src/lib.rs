@@ -123,9 +123,19 @@ pub use gen::Result;
 pub use gen::SchemaResult;
 pub use gen::Ticket;
 
+/// Experimental transport over Unix domain sockets for co-located clients and servers
+#[cfg(all(feature = "local-transport", unix))]
+pub mod local;
+
+/// Helpers for splitting results into pages retrieved with separate [`Ticket`]s
+pub mod pagination;
+
 /// Helper to extract HTTP/gRPC trailers from a tonic stream.
 mod trailers;
 
+/// HTTP/2 keepalive, concurrency, timeout and flow control settings of clients and servers
+pub mod transport;
+
 pub mod utils;
 
 #[cfg(feature = "flight-sql")]
@@ -0,0 +1,91 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Experimental transport over Unix domain sockets for co-located clients and servers
+//!
+//! Flight clients and servers running on the same host can avoid the overhead of the TCP
+//! stack by connecting over a Unix domain socket. The Flight protocol is unchanged, so the
+//! existing [`FlightClient`], [`FlightService`] implementations and the
+//! [encoder](crate::encode) and [decoder](crate::decode) are used as is.
+//!
+//! Requires the `local-transport` feature, and is only available on Unix platforms.
+//!
+//! # Example
+//! ```no_run
+//! # use arrow_flight::flight_service_server::{FlightService, FlightServiceServer};
+//! # async fn run(service: FlightServiceServer<impl FlightService>) {
+//! # use arrow_flight::local::{bind_unix, connect_unix};
+//! # use arrow_flight::FlightClient;
+//! # use tonic::transport::Server;
+//! // Server
+//! let incoming = bind_unix("/tmp/flight.sock").unwrap();
+//! let server = Server::builder()
+//!     .add_service(service)
+//!     .serve_with_incoming(incoming);
+//! tokio::spawn(server);
+//!
+//! // Client
+//! let channel = connect_unix("/tmp/flight.sock").await.unwrap();
+//! let client = FlightClient::new(channel);
+//! # }
+//! ```
+//!
+//! [`FlightClient`]: crate::FlightClient
+//! [`FlightService`]: crate::flight_service_server::FlightService
+
+use std::path::{Path, PathBuf};
+
+use hyper_util::rt::TokioIo;
+use tokio::net::{UnixListener, UnixStream};
+use tokio_stream::wrappers::UnixListenerStream;
+use tonic::transport::{Channel, Endpoint, Uri};
+use tower::service_fn;
+
+/// Binds a Unix domain socket at `path`, returning the stream of incoming connections to
+/// pass to [`Server::serve_with_incoming`]
+///
+/// Fails if a file already exists at `path`. The socket file is not removed once the
+/// server shuts down.
+///
+/// Must be called within the context of a tokio runtime.
+///
+/// [`Server::serve_with_incoming`]: tonic::transport::Server::serve_with_incoming
+pub fn bind_unix(path: impl AsRef<Path>) -> std::io::Result<UnixListenerStream> {
+    UnixListener::bind(path).map(UnixListenerStream::new)
+}
+
+/// Returns a [`Channel`] connected to the server listening on the Unix domain socket at
+/// `path`, see [`bind_unix`]
+pub async fn connect_unix(path: impl AsRef<Path>) -> Result<Channel, tonic::transport::Error> {
+    connect_unix_endpoint(Endpoint::from_static("http://localhost"), path).await
+}
+
+/// Returns a [`Channel`] connected to the server listening on the Unix domain socket at
+/// `path` with the settings of `endpoint`, such as its timeouts
+///
+/// The URI of `endpoint` is only used for the `:authority` of the requests.
+pub async fn connect_unix_endpoint(
+    endpoint: Endpoint,
+    path: impl AsRef<Path>,
+) -> Result<Channel, tonic::transport::Error> {
+    let path: PathBuf = path.as_ref().into();
+    let connector = service_fn(move |_: Uri| {
+        let path = path.clone();
+        async move { UnixStream::connect(path).await.map(TokioIo::new) }
+    });
+    endpoint.connect_with_connector(connector).await
+}
@@ -0,0 +1,287 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers for splitting a result into pages, each retrieved with its own [`Ticket`]
+//!
+//! A common pattern for Flight services is to return a [`FlightInfo`] with one
+//! [`FlightEndpoint`] per partition of a result, such as a file or a Parquet row group, so
+//! that clients can retrieve the partitions in parallel or from different locations. The
+//! server then needs to identify the partition from the [`Ticket`] of the endpoint in
+//! `do_get`, and clients that lose their connection need a way to resume where they left off.
+//!
+//! [`ResultPage`] standardizes this pattern. The server creates the endpoints with
+//! [`partition_endpoints`] or [`row_range_endpoints`], and decodes the tickets it receives with
+//! [`ResultPage::try_from_ticket`]. A client that has read some rows of a page can create a
+//! ticket for the remaining rows with [`ResultPage::resume_after`].
+//!
+//! Note tickets are opaque to clients but not authenticated, so servers must check that a
+//! decoded page refers to a result the client may access.
+//!
+//! # Example
+//! ```
+//! # use arrow_flight::pagination::{partition_endpoints, ResultPage};
+//! # use arrow_flight::FlightInfo;
+//! // Server: one endpoint for each of the 3 partitions of query "q1"
+//! let info = partition_endpoints("q1", 3)
+//!     .into_iter()
+//!     .fold(FlightInfo::new(), FlightInfo::with_endpoint);
+//!
+//! // Client: redeem the ticket of the second endpoint
+//! let ticket = info.endpoint[1].ticket.clone().unwrap();
+//!
+//! // Server: decode the ticket in `do_get`
+//! let page = ResultPage::try_from_ticket(&ticket).unwrap();
+//! assert_eq!(page.result_id, "q1");
+//! assert_eq!(page.partition, 1);
+//! assert_eq!(page.offset, 0);
+//!
+//! // Client: the connection was lost after 100 rows, resume after them
+//! let ticket = page.resume_after(100).to_ticket();
+//! let page = ResultPage::try_from_ticket(&ticket).unwrap();
+//! assert_eq!(page.offset, 100);
+//! ```
+//!
+//! [`FlightInfo`]: crate::FlightInfo
+
+use bytes::{BufMut, Bytes, BytesMut};
+use prost::Message;
+
+use crate::error::{FlightError, Result};
+use crate::{FlightEndpoint, Ticket};
+
+/// Prefix of the tickets of [`ResultPage`]s, followed by the format version
+const TICKET_MAGIC: &[u8] = b"ARROWPAGE";
+
+/// The current version of the ticket format
+const TICKET_VERSION: u8 = 1;
+
+/// A page of a result, identifying the rows to return for a [`Ticket`]
+///
+/// See the [module documentation](self) for more details.
+#[derive(Clone, PartialEq, Message)]
+pub struct ResultPage {
+    /// Opaque identifier of the result, such as a query handle, assigned by the server
+    #[prost(bytes = "bytes", tag = "1")]
+    pub result_id: Bytes,
+    /// The partition of the result, such as a file or a Parquet row group
+    #[prost(uint64, tag = "2")]
+    pub partition: u64,
+    /// The number of rows of the partition to skip
+    #[prost(uint64, tag = "3")]
+    pub offset: u64,
+    /// The maximum number of rows to return, or all remaining rows of the partition if `None`
+    #[prost(uint64, optional, tag = "4")]
+    pub limit: Option<u64>,
+}
+
+impl ResultPage {
+    /// Create a new [`ResultPage`] for all rows of `partition` of the result `result_id`
+    pub fn new(result_id: impl Into<Bytes>, partition: u64) -> Self {
+        Self {
+            result_id: result_id.into(),
+            partition,
+            offset: 0,
+            limit: None,
+        }
+    }
+
+    /// Skip the first `offset` rows of the partition
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Return at most `limit` rows
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the page of the rows remaining after the first `rows_read` rows of this page
+    pub fn resume_after(&self, rows_read: u64) -> Self {
+        Self {
+            result_id: self.result_id.clone(),
+            partition: self.partition,
+            offset: self.offset.saturating_add(rows_read),
+            limit: self.limit.map(|limit| limit.saturating_sub(rows_read)),
+        }
+    }
+
+    /// Encode this page into an opaque [`Ticket`]
+    pub fn to_ticket(&self) -> Ticket {
+        let mut buf = BytesMut::with_capacity(TICKET_MAGIC.len() + 1 + self.encoded_len());
+        buf.put_slice(TICKET_MAGIC);
+        buf.put_u8(TICKET_VERSION);
+        // Encoding into a `BytesMut` can not fail as it grows as needed
+        self.encode(&mut buf).unwrap();
+        Ticket::new(buf.freeze())
+    }
+
+    /// Create a [`FlightEndpoint`] with the [`Ticket`] of this page
+    pub fn to_endpoint(&self) -> FlightEndpoint {
+        FlightEndpoint::new().with_ticket(self.to_ticket())
+    }
+
+    /// Decode a [`ResultPage`] from a [`Ticket`] created by [`Self::to_ticket`]
+    ///
+    /// Returns an error if the ticket was not created by [`Self::to_ticket`], was created by
+    /// an incompatible version, or is corrupt.
+    pub fn try_from_ticket(ticket: &Ticket) -> Result<Self> {
+        let Some(payload) = ticket.ticket.strip_prefix(TICKET_MAGIC) else {
+            return Err(FlightError::DecodeError(
+                "Ticket is not a result page ticket".to_string(),
+            ));
+        };
+        match payload.split_first() {
+            Some((&TICKET_VERSION, payload)) => {
+                let page = Self::decode(payload).map_err(|e| {
+                    FlightError::DecodeError(format!("Invalid result page ticket: {e}"))
+                })?;
+                if page.result_id.is_empty() {
+                    return Err(FlightError::DecodeError(
+                        "Invalid result page ticket: missing result id".to_string(),
+                    ));
+                }
+                Ok(page)
+            }
+            Some((version, _)) => Err(FlightError::DecodeError(format!(
+                "Unsupported result page ticket version {version}"
+            ))),
+            None => Err(FlightError::DecodeError(
+                "Invalid result page ticket: missing version".to_string(),
+            )),
+        }
+    }
+}
+
+/// Create one [`FlightEndpoint`] for each of the `num_partitions` partitions of the result
+/// `result_id`, such as the row groups of a Parquet file
+pub fn partition_endpoints(
+    result_id: impl Into<Bytes>,
+    num_partitions: u64,
+) -> Vec<FlightEndpoint> {
+    let result_id = result_id.into();
+    (0..num_partitions)
+        .map(|partition| ResultPage::new(result_id.clone(), partition).to_endpoint())
+        .collect()
+}
+
+/// Create [`FlightEndpoint`]s for pages of at most `page_size` rows of a result `result_id`
+/// with a single partition of `num_rows` rows
+///
+/// # Panics
+///
+/// Panics if `page_size` is 0
+pub fn row_range_endpoints(
+    result_id: impl Into<Bytes>,
+    num_rows: u64,
+    page_size: u64,
+) -> Vec<FlightEndpoint> {
+    assert_ne!(page_size, 0, "page size must be greater than 0");
+    let result_id = result_id.into();
+    (0..num_rows.div_ceil(page_size))
+        .map(|page| page * page_size)
+        .map(|offset| {
+            ResultPage::new(result_id.clone(), 0)
+                .with_offset(offset)
+                .with_limit(page_size.min(num_rows - offset))
+                .to_endpoint()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(endpoint: &FlightEndpoint) -> ResultPage {
+        ResultPage::try_from_ticket(endpoint.ticket.as_ref().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_ticket_roundtrip() {
+        let page = ResultPage::new("query", 7).with_offset(10).with_limit(5);
+        let decoded = ResultPage::try_from_ticket(&page.to_ticket()).unwrap();
+        assert_eq!(decoded, page);
+
+        let resumed = decoded.resume_after(3);
+        assert_eq!(resumed.offset, 13);
+        assert_eq!(resumed.limit, Some(2));
+        assert_eq!(resumed.resume_after(10).limit, Some(0));
+
+        let page = ResultPage::new(vec![0, 1, 2], 0);
+        let decoded = ResultPage::try_from_ticket(&page.to_ticket()).unwrap();
+        assert_eq!(decoded.limit, None);
+        assert_eq!(decoded.resume_after(100).limit, None);
+    }
+
+    #[test]
+    fn test_invalid_tickets() {
+        let err = |ticket: Ticket| {
+            ResultPage::try_from_ticket(&ticket)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            err(Ticket::new("SELECT 1")),
+            "Decode error: Ticket is not a result page ticket"
+        );
+        assert_eq!(
+            err(Ticket::new(TICKET_MAGIC)),
+            "Decode error: Invalid result page ticket: missing version"
+        );
+        let mut ticket = ResultPage::new("query", 0).to_ticket().ticket.to_vec();
+        ticket[TICKET_MAGIC.len()] = 2;
+        assert_eq!(
+            err(Ticket::new(ticket.clone())),
+            "Decode error: Unsupported result page ticket version 2"
+        );
+        ticket[TICKET_MAGIC.len()] = TICKET_VERSION;
+        ticket.truncate(ticket.len() - 1);
+        assert!(err(Ticket::new(ticket)).starts_with("Decode error: Invalid result page ticket"));
+        assert_eq!(
+            err(ResultPage::new("", 0).to_ticket()),
+            "Decode error: Invalid result page ticket: missing result id"
+        );
+    }
+
+    #[test]
+    fn test_partition_endpoints() {
+        let endpoints = partition_endpoints("query", 3);
+        let pages: Vec<_> = endpoints.iter().map(decode).collect();
+        assert_eq!(
+            pages,
+            (0..3)
+                .map(|p| ResultPage::new("query", p))
+                .collect::<Vec<_>>()
+        );
+        assert!(partition_endpoints("query", 0).is_empty());
+    }
+
+    #[test]
+    fn test_row_range_endpoints() {
+        let endpoints = row_range_endpoints("query", 10, 4);
+        let ranges: Vec<_> = endpoints
+            .iter()
+            .map(decode)
+            .map(|page| (page.offset, page.limit))
+            .collect();
+        assert_eq!(ranges, vec![(0, Some(4)), (4, Some(4)), (8, Some(2))]);
+        assert!(row_range_endpoints("query", 0, 4).is_empty());
+    }
+}
@@ -0,0 +1,460 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`GetForeignKeysBuilder`] for building responses to [`CommandGetExportedKeys`],
+//! [`CommandGetImportedKeys`] and [`CommandGetCrossReference`] queries.
+//!
+//! [`CommandGetExportedKeys`]: crate::sql::CommandGetExportedKeys
+//! [`CommandGetImportedKeys`]: crate::sql::CommandGetImportedKeys
+//! [`CommandGetCrossReference`]: crate::sql::CommandGetCrossReference
+
+use std::sync::Arc;
+
+use arrow_array::builder::{Int32Builder, StringBuilder, UInt8Builder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use once_cell::sync::Lazy;
+
+use super::filter_and_sort;
+use crate::error::*;
+use crate::sql::{
+    CommandGetCrossReference, CommandGetExportedKeys, CommandGetImportedKeys, UpdateDeleteRules,
+};
+
+/// A column of a foreign key, appended to a [`GetForeignKeysBuilder`]
+///
+/// In case a catalog or schema should be considered as empty, use an empty string '""'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForeignKey<'a> {
+    /// The catalog of the table of the referenced primary key
+    pub pk_catalog_name: &'a str,
+    /// The schema of the table of the referenced primary key
+    pub pk_db_schema_name: &'a str,
+    /// The table of the referenced primary key
+    pub pk_table_name: &'a str,
+    /// The referenced primary key column
+    pub pk_column_name: &'a str,
+    /// The catalog of the table of the foreign key
+    pub fk_catalog_name: &'a str,
+    /// The schema of the table of the foreign key
+    pub fk_db_schema_name: &'a str,
+    /// The table of the foreign key
+    pub fk_table_name: &'a str,
+    /// The foreign key column
+    pub fk_column_name: &'a str,
+    /// The 1-based position of the column in the foreign key
+    pub key_sequence: i32,
+    /// The name of the foreign key, if any
+    pub fk_key_name: Option<&'a str>,
+    /// The name of the referenced primary key, if any
+    pub pk_key_name: Option<&'a str>,
+    /// The action when the primary key is updated
+    pub update_rule: UpdateDeleteRules,
+    /// The action when the primary key is deleted
+    pub delete_rule: UpdateDeleteRules,
+}
+
+/// The table a foreign key request is narrowed to
+struct TableFilter {
+    // - An empty string retrieves those without a catalog.
+    // - If omitted the catalog name is not used to narrow the search.
+    catalog: Option<String>,
+    // - An empty string retrieves those without a schema.
+    // - If omitted the schema name is not used to narrow the search.
+    db_schema: Option<String>,
+    table: String,
+}
+
+impl TableFilter {
+    fn new(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            catalog: catalog.map(|v| v.into()),
+            db_schema: db_schema.map(|v| v.into()),
+            table: table.into(),
+        }
+    }
+}
+
+/// A builder for a [`CommandGetExportedKeys`], [`CommandGetImportedKeys`] or
+/// [`CommandGetCrossReference`] response.
+///
+/// Builds rows like this:
+///
+/// * pk_catalog_name: utf8,
+/// * pk_db_schema_name: utf8,
+/// * pk_table_name: utf8 not null,
+/// * pk_column_name: utf8 not null,
+/// * fk_catalog_name: utf8,
+/// * fk_db_schema_name: utf8,
+/// * fk_table_name: utf8 not null,
+/// * fk_column_name: utf8 not null,
+/// * key_sequence: int32 not null,
+/// * fk_key_name: utf8,
+/// * pk_key_name: utf8,
+/// * update_rule: uint8 not null,
+/// * delete_rule: uint8 not null
+pub struct GetForeignKeysBuilder {
+    // Table of the primary keys to retrieve the foreign keys of, if any
+    pk_filter: Option<TableFilter>,
+    // Table of the foreign keys to retrieve, if any
+    fk_filter: Option<TableFilter>,
+    // Whether to order the results by foreign key, rather than primary key
+    order_by_fk: bool,
+    pk_catalog_name: StringBuilder,
+    pk_db_schema_name: StringBuilder,
+    pk_table_name: StringBuilder,
+    pk_column_name: StringBuilder,
+    fk_catalog_name: StringBuilder,
+    fk_db_schema_name: StringBuilder,
+    fk_table_name: StringBuilder,
+    fk_column_name: StringBuilder,
+    key_sequence: Int32Builder,
+    fk_key_name: StringBuilder,
+    pk_key_name: StringBuilder,
+    update_rule: UInt8Builder,
+    delete_rule: UInt8Builder,
+}
+
+impl CommandGetExportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetExportedKeys> for GetForeignKeysBuilder {
+    fn from(value: CommandGetExportedKeys) -> Self {
+        Self::new_exported(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl CommandGetImportedKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetImportedKeys> for GetForeignKeysBuilder {
+    fn from(value: CommandGetImportedKeys) -> Self {
+        Self::new_imported(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl CommandGetCrossReference {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetForeignKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetCrossReference> for GetForeignKeysBuilder {
+    fn from(value: CommandGetCrossReference) -> Self {
+        Self::new_cross_reference(
+            value.pk_catalog,
+            value.pk_db_schema,
+            value.pk_table,
+            value.fk_catalog,
+            value.fk_db_schema,
+            value.fk_table,
+        )
+    }
+}
+
+impl GetForeignKeysBuilder {
+    fn new(
+        pk_filter: Option<TableFilter>,
+        fk_filter: Option<TableFilter>,
+        order_by_fk: bool,
+    ) -> Self {
+        Self {
+            pk_filter,
+            fk_filter,
+            order_by_fk,
+            pk_catalog_name: StringBuilder::new(),
+            pk_db_schema_name: StringBuilder::new(),
+            pk_table_name: StringBuilder::new(),
+            pk_column_name: StringBuilder::new(),
+            fk_catalog_name: StringBuilder::new(),
+            fk_db_schema_name: StringBuilder::new(),
+            fk_table_name: StringBuilder::new(),
+            fk_column_name: StringBuilder::new(),
+            key_sequence: Int32Builder::new(),
+            fk_key_name: StringBuilder::new(),
+            pk_key_name: StringBuilder::new(),
+            update_rule: UInt8Builder::new(),
+            delete_rule: UInt8Builder::new(),
+        }
+    }
+
+    /// Create a new instance of [`GetForeignKeysBuilder`] for a [`CommandGetExportedKeys`]
+    /// response, with the foreign keys that reference the primary key of `table`, ordered
+    /// by foreign key
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the table of the primary key.
+    pub fn new_exported(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            Some(TableFilter::new(catalog, db_schema, table)),
+            None,
+            true,
+        )
+    }
+
+    /// Create a new instance of [`GetForeignKeysBuilder`] for a [`CommandGetImportedKeys`]
+    /// response, with the foreign keys of `table`, ordered by referenced primary key
+    ///
+    /// See [`Self::new_exported`] for the parameters, which narrow the search by the table
+    /// of the foreign keys.
+    pub fn new_imported(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            None,
+            Some(TableFilter::new(catalog, db_schema, table)),
+            false,
+        )
+    }
+
+    /// Create a new instance of [`GetForeignKeysBuilder`] for a
+    /// [`CommandGetCrossReference`] response, with the foreign keys of `fk_table` that
+    /// reference the primary key of `pk_table`, ordered by referenced primary key
+    ///
+    /// See [`Self::new_exported`] for the catalog and schema parameters.
+    pub fn new_cross_reference(
+        pk_catalog: Option<impl Into<String>>,
+        pk_db_schema: Option<impl Into<String>>,
+        pk_table: impl Into<String>,
+        fk_catalog: Option<impl Into<String>>,
+        fk_db_schema: Option<impl Into<String>>,
+        fk_table: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            Some(TableFilter::new(pk_catalog, pk_db_schema, pk_table)),
+            Some(TableFilter::new(fk_catalog, fk_db_schema, fk_table)),
+            false,
+        )
+    }
+
+    /// Append a row
+    pub fn append(&mut self, key: ForeignKey<'_>) {
+        self.pk_catalog_name.append_value(key.pk_catalog_name);
+        self.pk_db_schema_name.append_value(key.pk_db_schema_name);
+        self.pk_table_name.append_value(key.pk_table_name);
+        self.pk_column_name.append_value(key.pk_column_name);
+        self.fk_catalog_name.append_value(key.fk_catalog_name);
+        self.fk_db_schema_name.append_value(key.fk_db_schema_name);
+        self.fk_table_name.append_value(key.fk_table_name);
+        self.fk_column_name.append_value(key.fk_column_name);
+        self.key_sequence.append_value(key.key_sequence);
+        self.fk_key_name.append_option(key.fk_key_name);
+        self.pk_key_name.append_option(key.pk_key_name);
+        self.update_rule.append_value(key.update_rule as u8);
+        self.delete_rule.append_value(key.delete_rule as u8);
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetExportedKeys`,
+    /// `CommandGetImportedKeys` or `CommandGetCrossReference` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let schema = self.schema();
+        let Self {
+            pk_filter,
+            fk_filter,
+            order_by_fk,
+            mut pk_catalog_name,
+            mut pk_db_schema_name,
+            mut pk_table_name,
+            mut pk_column_name,
+            mut fk_catalog_name,
+            mut fk_db_schema_name,
+            mut fk_table_name,
+            mut fk_column_name,
+            mut key_sequence,
+            mut fk_key_name,
+            mut pk_key_name,
+            mut update_rule,
+            mut delete_rule,
+        } = self;
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(pk_catalog_name.finish()) as ArrayRef,
+                Arc::new(pk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(pk_table_name.finish()) as ArrayRef,
+                Arc::new(pk_column_name.finish()) as ArrayRef,
+                Arc::new(fk_catalog_name.finish()) as ArrayRef,
+                Arc::new(fk_db_schema_name.finish()) as ArrayRef,
+                Arc::new(fk_table_name.finish()) as ArrayRef,
+                Arc::new(fk_column_name.finish()) as ArrayRef,
+                Arc::new(key_sequence.finish()) as ArrayRef,
+                Arc::new(fk_key_name.finish()) as ArrayRef,
+                Arc::new(pk_key_name.finish()) as ArrayRef,
+                Arc::new(update_rule.finish()) as ArrayRef,
+                Arc::new(delete_rule.finish()) as ArrayRef,
+            ],
+        )?;
+
+        // The catalog, schema and table columns start at 0 for the primary keys, and
+        // 4 for the foreign keys
+        let mut filters = vec![];
+        for (filter, start) in [(&pk_filter, 0), (&fk_filter, 4)] {
+            if let Some(filter) = filter {
+                filters.push((start, filter.catalog.as_deref()));
+                filters.push((start + 1, filter.db_schema.as_deref()));
+                filters.push((start + 2, Some(filter.table.as_str())));
+            }
+        }
+
+        // Order filtered results by the catalog, schema, table and key name of either the
+        // foreign or primary key, then key_sequence
+        let sort_columns = match order_by_fk {
+            true => [4, 5, 6, 9, 8],
+            false => [0, 1, 2, 10, 8],
+        };
+        filter_and_sort(batch, &filters, &sort_columns)
+    }
+
+    /// Return the schema of the RecordBatch that will be returned from
+    /// [`CommandGetExportedKeys`], [`CommandGetImportedKeys`] and
+    /// [`CommandGetCrossReference`]
+    pub fn schema(&self) -> SchemaRef {
+        get_foreign_keys_schema()
+    }
+}
+
+fn get_foreign_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_FOREIGN_KEYS_SCHEMA)
+}
+
+/// The schema for GetExportedKeys, GetImportedKeys and GetCrossReference
+static GET_FOREIGN_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("pk_catalog_name", DataType::Utf8, true),
+        Field::new("pk_db_schema_name", DataType::Utf8, true),
+        Field::new("pk_table_name", DataType::Utf8, false),
+        Field::new("pk_column_name", DataType::Utf8, false),
+        Field::new("fk_catalog_name", DataType::Utf8, true),
+        Field::new("fk_db_schema_name", DataType::Utf8, true),
+        Field::new("fk_table_name", DataType::Utf8, false),
+        Field::new("fk_column_name", DataType::Utf8, false),
+        Field::new("key_sequence", DataType::Int32, false),
+        Field::new("fk_key_name", DataType::Utf8, true),
+        Field::new("pk_key_name", DataType::Utf8, true),
+        Field::new("update_rule", DataType::UInt8, false),
+        Field::new("delete_rule", DataType::UInt8, false),
+    ]))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::UInt8Type;
+
+    fn key<'a>(pk_table: &'a str, fk_table: &'a str, fk_column: &'a str) -> ForeignKey<'a> {
+        ForeignKey {
+            pk_catalog_name: "catalog",
+            pk_db_schema_name: "schema",
+            pk_table_name: pk_table,
+            pk_column_name: "id",
+            fk_catalog_name: "catalog",
+            fk_db_schema_name: "schema",
+            fk_table_name: fk_table,
+            fk_column_name: fk_column,
+            key_sequence: 1,
+            fk_key_name: None,
+            pk_key_name: None,
+            update_rule: UpdateDeleteRules::Cascade,
+            delete_rule: UpdateDeleteRules::SetNull,
+        }
+    }
+
+    fn build(mut builder: GetForeignKeysBuilder) -> Vec<String> {
+        builder.append(key("b_pk", "a_fk", "x"));
+        builder.append(key("a_pk", "b_fk", "y"));
+        builder.append(key("a_pk", "a_fk", "z"));
+        builder.append(key("b_pk", "b_fk", "w"));
+        let batch = builder.build().unwrap();
+        assert_eq!(batch.schema(), get_foreign_keys_schema());
+        let rules = batch.column(12).as_primitive::<UInt8Type>();
+        assert!(rules.values().iter().all(|r| *r == 2));
+        batch
+            .column(7)
+            .as_string::<i32>()
+            .iter()
+            .map(|v| v.unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_exported_keys() {
+        let query = CommandGetExportedKeys {
+            catalog: None,
+            db_schema: Some("schema".to_string()),
+            table: "a_pk".to_string(),
+        };
+        assert_eq!(build(query.into_builder()), ["z", "y"]);
+    }
+
+    #[test]
+    fn test_imported_keys() {
+        let query = CommandGetImportedKeys {
+            catalog: Some("catalog".to_string()),
+            db_schema: None,
+            table: "b_fk".to_string(),
+        };
+        assert_eq!(build(query.into_builder()), ["y", "w"]);
+
+        let query = CommandGetImportedKeys {
+            catalog: Some("".to_string()),
+            db_schema: None,
+            table: "b_fk".to_string(),
+        };
+        assert!(build(query.into_builder()).is_empty());
+    }
+
+    #[test]
+    fn test_cross_reference() {
+        let query = CommandGetCrossReference {
+            pk_catalog: None,
+            pk_db_schema: None,
+            pk_table: "b_pk".to_string(),
+            fk_catalog: None,
+            fk_db_schema: None,
+            fk_table: "a_fk".to_string(),
+        };
+        assert_eq!(build(query.into_builder()), ["x"]);
+    }
+}
@@ -21,17 +21,28 @@
 //! - [`GetCatalogsBuilder`] for building responses to [`CommandGetCatalogs`] queries.
 //! - [`GetDbSchemasBuilder`] for building responses to [`CommandGetDbSchemas`] queries.
 //! - [`GetTablesBuilder`]for building responses to [`CommandGetTables`] queries.
+//! - [`GetTableTypesBuilder`] for building responses to [`CommandGetTableTypes`] queries.
+//! - [`GetPrimaryKeysBuilder`] for building responses to [`CommandGetPrimaryKeys`] queries.
+//! - [`GetForeignKeysBuilder`] for building responses to [`CommandGetExportedKeys`],
+//!   [`CommandGetImportedKeys`] and [`CommandGetCrossReference`] queries.
 //! - [`SqlInfoDataBuilder`]for building responses to [`CommandGetSqlInfo`] queries.
 //! - [`XdbcTypeInfoDataBuilder`]for building responses to [`CommandGetXdbcTypeInfo`] queries.
 //!
 //! [`CommandGetCatalogs`]: crate::sql::CommandGetCatalogs
 //! [`CommandGetDbSchemas`]: crate::sql::CommandGetDbSchemas
 //! [`CommandGetTables`]: crate::sql::CommandGetTables
+//! [`CommandGetTableTypes`]: crate::sql::CommandGetTableTypes
+//! [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+//! [`CommandGetExportedKeys`]: crate::sql::CommandGetExportedKeys
+//! [`CommandGetImportedKeys`]: crate::sql::CommandGetImportedKeys
+//! [`CommandGetCrossReference`]: crate::sql::CommandGetCrossReference
 //! [`CommandGetSqlInfo`]: crate::sql::CommandGetSqlInfo
 //! [`CommandGetXdbcTypeInfo`]: crate::sql::CommandGetXdbcTypeInfo
 
 mod catalogs;
 mod db_schemas;
+mod foreign_keys;
+mod primary_keys;
 mod sql_info;
 mod table_types;
 mod tables;
@@ -39,14 +50,58 @@ mod xdbc_info;
 
 pub use catalogs::GetCatalogsBuilder;
 pub use db_schemas::GetDbSchemasBuilder;
+pub use foreign_keys::{ForeignKey, GetForeignKeysBuilder};
+pub use primary_keys::GetPrimaryKeysBuilder;
 pub use sql_info::{SqlInfoData, SqlInfoDataBuilder};
+pub use table_types::GetTableTypesBuilder;
 pub use tables::GetTablesBuilder;
 pub use xdbc_info::{XdbcTypeInfo, XdbcTypeInfoData, XdbcTypeInfoDataBuilder};
 
+use std::sync::Arc;
+
+use arrow_arith::boolean::and;
 use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_array::UInt32Array;
+use arrow_ord::cmp::eq;
 use arrow_row::RowConverter;
 use arrow_row::SortField;
+use arrow_select::filter::filter_record_batch;
+use arrow_select::take::take_record_batch;
+
+use crate::error::Result;
+
+/// Helper function to keep the rows of `batch` whose string column at each index of
+/// `filters` is equal to the corresponding value, if any, and order them by the columns
+/// at `sort_columns`
+fn filter_and_sort(
+    batch: RecordBatch,
+    filters: &[(usize, Option<&str>)],
+    sort_columns: &[usize],
+) -> Result<RecordBatch> {
+    let mut total_filter = None;
+    for (column, value) in filters {
+        let Some(value) = value else { continue };
+        let filter = eq(batch.column(*column), &StringArray::new_scalar(*value))?;
+        total_filter = Some(match total_filter {
+            Some(total_filter) => and(&total_filter, &filter)?,
+            None => filter,
+        });
+    }
+
+    let batch = match total_filter {
+        Some(filter) => filter_record_batch(&batch, &filter)?,
+        None => batch,
+    };
+
+    let sort_columns: Vec<_> = sort_columns
+        .iter()
+        .map(|i| Arc::clone(batch.column(*i)))
+        .collect();
+    let indices = lexsort_to_indices(&sort_columns);
+    Ok(take_record_batch(&batch, &indices)?)
+}
 
 /// Helper function to sort all the columns in an array
 fn lexsort_to_indices(arrays: &[ArrayRef]) -> UInt32Array {
@@ -0,0 +1,227 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`GetPrimaryKeysBuilder`] for building responses to [`CommandGetPrimaryKeys`] queries.
+//!
+//! [`CommandGetPrimaryKeys`]: crate::sql::CommandGetPrimaryKeys
+
+use std::sync::Arc;
+
+use arrow_array::builder::{Int32Builder, StringBuilder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use once_cell::sync::Lazy;
+
+use super::filter_and_sort;
+use crate::error::*;
+use crate::sql::CommandGetPrimaryKeys;
+
+/// A builder for a [`CommandGetPrimaryKeys`] response.
+///
+/// Builds rows like this:
+///
+/// * catalog_name: utf8,
+/// * db_schema_name: utf8,
+/// * table_name: utf8 not null,
+/// * column_name: utf8 not null,
+/// * key_name: utf8,
+/// * key_sequence: int32 not null
+pub struct GetPrimaryKeysBuilder {
+    // Specifies the Catalog to search for the table.
+    // - An empty string retrieves those without a catalog.
+    // - If omitted the catalog name is not used to narrow the search.
+    catalog_filter: Option<String>,
+    // Specifies the schema to search for the table.
+    // - An empty string retrieves those without a schema.
+    // - If omitted the schema name is not used to narrow the search.
+    db_schema_filter: Option<String>,
+    // Specifies the table to get the primary keys for.
+    table_filter: String,
+    // array builder for catalog names
+    catalog_name: StringBuilder,
+    // array builder for db schema names
+    db_schema_name: StringBuilder,
+    // array builder for table names
+    table_name: StringBuilder,
+    // array builder for column names
+    column_name: StringBuilder,
+    // array builder for key names
+    key_name: StringBuilder,
+    // array builder for key sequences
+    key_sequence: Int32Builder,
+}
+
+impl CommandGetPrimaryKeys {
+    /// Create a builder suitable for constructing a response
+    pub fn into_builder(self) -> GetPrimaryKeysBuilder {
+        self.into()
+    }
+}
+
+impl From<CommandGetPrimaryKeys> for GetPrimaryKeysBuilder {
+    fn from(value: CommandGetPrimaryKeys) -> Self {
+        Self::new(value.catalog, value.db_schema, value.table)
+    }
+}
+
+impl GetPrimaryKeysBuilder {
+    /// Create a new instance of [`GetPrimaryKeysBuilder`]
+    ///
+    /// # Parameters
+    ///
+    /// - `catalog`:  Specifies the Catalog to search for the table.
+    ///   - An empty string retrieves those without a catalog.
+    ///   - If omitted the catalog name is not used to narrow the search.
+    /// - `db_schema`: Specifies the schema to search for the table.
+    ///   - An empty string retrieves those without a schema.
+    ///   - If omitted the schema name is not used to narrow the search.
+    /// - `table`: Specifies the table to get the primary keys for.
+    pub fn new(
+        catalog: Option<impl Into<String>>,
+        db_schema: Option<impl Into<String>>,
+        table: impl Into<String>,
+    ) -> Self {
+        Self {
+            catalog_filter: catalog.map(|v| v.into()),
+            db_schema_filter: db_schema.map(|v| v.into()),
+            table_filter: table.into(),
+            catalog_name: StringBuilder::new(),
+            db_schema_name: StringBuilder::new(),
+            table_name: StringBuilder::new(),
+            column_name: StringBuilder::new(),
+            key_name: StringBuilder::new(),
+            key_sequence: Int32Builder::new(),
+        }
+    }
+
+    /// Append a row for the column `column_name` of a primary key, whose 1-based
+    /// position in the key is `key_sequence`
+    ///
+    /// In case the catalog or schema should be considered as empty, pass in an empty
+    /// string '""'.
+    pub fn append(
+        &mut self,
+        catalog_name: impl AsRef<str>,
+        schema_name: impl AsRef<str>,
+        table_name: impl AsRef<str>,
+        column_name: impl AsRef<str>,
+        key_name: Option<&str>,
+        key_sequence: i32,
+    ) {
+        self.catalog_name.append_value(catalog_name);
+        self.db_schema_name.append_value(schema_name);
+        self.table_name.append_value(table_name);
+        self.column_name.append_value(column_name);
+        self.key_name.append_option(key_name);
+        self.key_sequence.append_value(key_sequence);
+    }
+
+    /// builds a `RecordBatch` with the correct schema for a `CommandGetPrimaryKeys` response
+    pub fn build(self) -> Result<RecordBatch> {
+        let schema = self.schema();
+        let Self {
+            catalog_filter,
+            db_schema_filter,
+            table_filter,
+            mut catalog_name,
+            mut db_schema_name,
+            mut table_name,
+            mut column_name,
+            mut key_name,
+            mut key_sequence,
+        } = self;
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(catalog_name.finish()) as ArrayRef,
+                Arc::new(db_schema_name.finish()) as ArrayRef,
+                Arc::new(table_name.finish()) as ArrayRef,
+                Arc::new(column_name.finish()) as ArrayRef,
+                Arc::new(key_name.finish()) as ArrayRef,
+                Arc::new(key_sequence.finish()) as ArrayRef,
+            ],
+        )?;
+
+        let filters = [
+            (0, catalog_filter.as_deref()),
+            (1, db_schema_filter.as_deref()),
+            (2, Some(table_filter.as_str())),
+        ];
+        // Order filtered results by catalog_name, db_schema_name, table_name, key_name,
+        // then key_sequence
+        filter_and_sort(batch, &filters, &[0, 1, 2, 4, 5])
+    }
+
+    /// Return the schema of the RecordBatch that will be returned
+    /// from [`CommandGetPrimaryKeys`]
+    pub fn schema(&self) -> SchemaRef {
+        get_primary_keys_schema()
+    }
+}
+
+fn get_primary_keys_schema() -> SchemaRef {
+    Arc::clone(&GET_PRIMARY_KEYS_SCHEMA)
+}
+
+/// The schema for GetPrimaryKeys
+static GET_PRIMARY_KEYS_SCHEMA: Lazy<SchemaRef> = Lazy::new(|| {
+    Arc::new(Schema::new(vec![
+        Field::new("catalog_name", DataType::Utf8, true),
+        Field::new("db_schema_name", DataType::Utf8, true),
+        Field::new("table_name", DataType::Utf8, false),
+        Field::new("column_name", DataType::Utf8, false),
+        Field::new("key_name", DataType::Utf8, true),
+        Field::new("key_sequence", DataType::Int32, false),
+    ]))
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql::metadata::tests::assert_batches_eq;
+
+    #[test]
+    fn test_primary_keys_are_filtered_and_sorted() {
+        let query = CommandGetPrimaryKeys {
+            catalog: Some("a_catalog".to_string()),
+            db_schema: None,
+            table: "a_table".to_string(),
+        };
+        let mut builder = query.into_builder();
+        builder.append("a_catalog", "b_schema", "a_table", "b", Some("pk"), 1);
+        builder.append("a_catalog", "a_schema", "a_table", "y", None, 2);
+        builder.append("a_catalog", "a_schema", "a_table", "x", None, 1);
+        builder.append("b_catalog", "a_schema", "a_table", "x", None, 1);
+        builder.append("a_catalog", "a_schema", "b_table", "x", None, 1);
+        let batch = builder.build().unwrap();
+
+        assert_eq!(batch.schema(), get_primary_keys_schema());
+        assert_batches_eq(
+            &[batch],
+            &[
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| catalog_name | db_schema_name | table_name | column_name | key_name | key_sequence |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+                "| a_catalog    | a_schema       | a_table    | x           |          | 1            |",
+                "| a_catalog    | a_schema       | a_table    | y           |          | 2            |",
+                "| a_catalog    | b_schema       | a_table    | b           | pk       | 1            |",
+                "+--------------+----------------+------------+-------------+----------+--------------+",
+            ],
+        );
+    }
+}
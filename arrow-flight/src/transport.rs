@@ -0,0 +1,247 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Transport settings shared by Flight clients and servers
+//!
+//! Long running Flight streams are sensitive to the HTTP/2 settings of the underlying
+//! connection, such as keepalives that detect dead peers behind load balancers, or the
+//! flow control windows that limit the throughput of large [`FlightData`] messages.
+//! [`TransportConfig`] collects these settings in one place, and applies them to a tonic
+//! [`Endpoint`] with [`TransportConfig::configure_endpoint`], used by
+//! [`FlightClient::connect`], or to a tonic [`Server`] with
+//! [`TransportConfig::configure_server`].
+//!
+//! # Example
+//! ```no_run
+//! # async fn run() {
+//! # use std::time::Duration;
+//! # use arrow_flight::transport::TransportConfig;
+//! # use arrow_flight::FlightClient;
+//! # use tonic::transport::Endpoint;
+//! let config = TransportConfig::new()
+//!     .with_http2_keepalive_interval(Duration::from_secs(30))
+//!     .with_keepalive_timeout(Duration::from_secs(10))
+//!     .with_timeout(Duration::from_secs(60))
+//!     .with_initial_stream_window_size(4 * 1024 * 1024);
+//!
+//! // Client
+//! let endpoint = Endpoint::from_static("http://localhost:1234");
+//! let client = FlightClient::connect(endpoint, &config).await.unwrap();
+//!
+//! // Server
+//! let server = config.server_builder();
+//! # }
+//! ```
+//!
+//! [`FlightData`]: crate::FlightData
+//! [`FlightClient::connect`]: crate::FlightClient::connect
+
+use std::time::Duration;
+
+use tonic::transport::{Endpoint, Server};
+
+/// HTTP/2 and TCP settings of the connections of Flight clients and servers
+///
+/// Settings that are not set keep the defaults of tonic. Some settings only apply to
+/// clients or to servers, as documented on their setters.
+///
+/// See the [module level documentation](self) for an example.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransportConfig {
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    http2_keepalive_interval: Option<Duration>,
+    keepalive_timeout: Option<Duration>,
+    keepalive_while_idle: Option<bool>,
+    tcp_keepalive: Option<Duration>,
+    concurrency_limit: Option<usize>,
+    max_concurrent_streams: Option<u32>,
+    initial_stream_window_size: Option<u32>,
+    initial_connection_window_size: Option<u32>,
+}
+
+impl TransportConfig {
+    /// Create a new [`TransportConfig`] with the defaults of tonic
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout of each call, after which clients cancel the call and servers
+    /// respond with a `DEADLINE_EXCEEDED` status
+    ///
+    /// A client can still set a shorter deadline for a call with the `grpc-timeout` header.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout to establish the connection to the server
+    ///
+    /// Only applies to clients.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the interval at which HTTP/2 ping frames are sent to keep the connection alive
+    pub fn with_http2_keepalive_interval(mut self, interval: Duration) -> Self {
+        self.http2_keepalive_interval = Some(interval);
+        self
+    }
+
+    /// Set the time to wait for the acknowledgement of a keepalive ping before closing the
+    /// connection
+    ///
+    /// Has no effect without [`Self::with_http2_keepalive_interval`].
+    pub fn with_keepalive_timeout(mut self, timeout: Duration) -> Self {
+        self.keepalive_timeout = Some(timeout);
+        self
+    }
+
+    /// Set whether to send keepalive pings when there are no active calls on the
+    /// connection
+    ///
+    /// Only applies to clients, servers always send keepalive pings.
+    pub fn with_keepalive_while_idle(mut self, enabled: bool) -> Self {
+        self.keepalive_while_idle = Some(enabled);
+        self
+    }
+
+    /// Set the interval of the TCP keepalive probes of the connection
+    pub fn with_tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Set the maximum number of calls processed concurrently on each connection
+    ///
+    /// Calls above the limit wait for another call of the connection to complete.
+    pub fn with_concurrency_limit(mut self, limit: usize) -> Self {
+        self.concurrency_limit = Some(limit);
+        self
+    }
+
+    /// Set the maximum number of concurrent HTTP/2 streams a client may open on each
+    /// connection
+    ///
+    /// Only applies to servers, which advertise it to their clients.
+    pub fn with_max_concurrent_streams(mut self, max: u32) -> Self {
+        self.max_concurrent_streams = Some(max);
+        self
+    }
+
+    /// Set the initial HTTP/2 flow control window size of each stream, in bytes
+    pub fn with_initial_stream_window_size(mut self, size: u32) -> Self {
+        self.initial_stream_window_size = Some(size);
+        self
+    }
+
+    /// Set the initial HTTP/2 flow control window size of each connection, in bytes
+    pub fn with_initial_connection_window_size(mut self, size: u32) -> Self {
+        self.initial_connection_window_size = Some(size);
+        self
+    }
+
+    /// Apply the client settings to `endpoint`
+    pub fn configure_endpoint(&self, mut endpoint: Endpoint) -> Endpoint {
+        if let Some(timeout) = self.timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(interval) = self.http2_keepalive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = self.keepalive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+        if let Some(enabled) = self.keepalive_while_idle {
+            endpoint = endpoint.keep_alive_while_idle(enabled);
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            endpoint = endpoint.tcp_keepalive(Some(interval));
+        }
+        if let Some(limit) = self.concurrency_limit {
+            endpoint = endpoint.concurrency_limit(limit);
+        }
+        if let Some(size) = self.initial_stream_window_size {
+            endpoint = endpoint.initial_stream_window_size(size);
+        }
+        if let Some(size) = self.initial_connection_window_size {
+            endpoint = endpoint.initial_connection_window_size(size);
+        }
+        endpoint
+    }
+
+    /// Apply the server settings to `server`
+    pub fn configure_server<L>(&self, mut server: Server<L>) -> Server<L> {
+        if let Some(timeout) = self.timeout {
+            server = server.timeout(timeout);
+        }
+        if let Some(interval) = self.http2_keepalive_interval {
+            server = server.http2_keepalive_interval(Some(interval));
+        }
+        if let Some(timeout) = self.keepalive_timeout {
+            server = server.http2_keepalive_timeout(Some(timeout));
+        }
+        if let Some(interval) = self.tcp_keepalive {
+            server = server.tcp_keepalive(Some(interval));
+        }
+        if let Some(limit) = self.concurrency_limit {
+            server = server.concurrency_limit_per_connection(limit);
+        }
+        if let Some(max) = self.max_concurrent_streams {
+            server = server.max_concurrent_streams(max);
+        }
+        if let Some(size) = self.initial_stream_window_size {
+            server = server.initial_stream_window_size(size);
+        }
+        if let Some(size) = self.initial_connection_window_size {
+            server = server.initial_connection_window_size(size);
+        }
+        server
+    }
+
+    /// Returns a new [`Server`] builder with the server settings applied
+    ///
+    /// Flight services are then added with [`Server::add_service`].
+    pub fn server_builder(&self) -> Server {
+        self.configure_server(Server::builder())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configure_endpoint() {
+        let endpoint = Endpoint::from_static("http://localhost:1234");
+        let default = TransportConfig::new().configure_endpoint(endpoint.clone());
+        assert_eq!(default.get_connect_timeout(), None);
+        assert_eq!(default.get_tcp_keepalive(), None);
+
+        let config = TransportConfig::new()
+            .with_connect_timeout(Duration::from_secs(5))
+            .with_tcp_keepalive(Duration::from_secs(60))
+            .with_max_concurrent_streams(10);
+        let endpoint = config.configure_endpoint(endpoint);
+        assert_eq!(endpoint.get_connect_timeout(), Some(Duration::from_secs(5)));
+        assert_eq!(endpoint.get_tcp_keepalive(), Some(Duration::from_secs(60)));
+    }
+}
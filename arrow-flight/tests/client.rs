@@ -21,20 +21,24 @@ mod common;
 
 use crate::common::fixture::TestFixture;
 use arrow_array::{RecordBatch, UInt64Array};
+use arrow_flight::transport::TransportConfig;
 use arrow_flight::{
-    decode::FlightRecordBatchStream, encode::FlightDataEncoderBuilder, error::FlightError, Action,
-    ActionType, CancelFlightInfoRequest, CancelFlightInfoResult, CancelStatus, Criteria, Empty,
-    FlightClient, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, HandshakeRequest,
-    HandshakeResponse, PollInfo, PutResult, RenewFlightEndpointRequest, Ticket,
+    client::FlightInfoPoller, decode::FlightRecordBatchStream, encode::FlightDataEncoderBuilder,
+    error::FlightError, Action, ActionType, CancelFlightInfoRequest, CancelFlightInfoResult,
+    CancelStatus, Criteria, Empty, FlightClient, FlightData, FlightDescriptor, FlightEndpoint,
+    FlightInfo, HandshakeRequest, HandshakeResponse, PollInfo, PutResult,
+    RenewFlightEndpointRequest, Ticket,
 };
 use arrow_schema::{DataType, Field, Schema};
 use bytes::Bytes;
 use common::server::TestFlightServer;
 use futures::{Future, StreamExt, TryStreamExt};
 use prost::Message;
+use tonic::transport::Endpoint;
 use tonic::Status;
 
 use std::sync::Arc;
+use std::time::Duration;
 
 #[tokio::test]
 async fn test_handshake() {
@@ -75,6 +79,33 @@ async fn test_handshake_error() {
     .await;
 }
 
+#[tokio::test]
+async fn test_connect_with_transport_config() {
+    let test_server = TestFlightServer::new();
+    let fixture = TestFixture::new(test_server.service()).await;
+
+    let config = TransportConfig::new()
+        .with_timeout(Duration::from_secs(30))
+        .with_connect_timeout(Duration::from_secs(5))
+        .with_http2_keepalive_interval(Duration::from_secs(10))
+        .with_keepalive_timeout(Duration::from_secs(5))
+        .with_keepalive_while_idle(true)
+        .with_concurrency_limit(4)
+        .with_initial_stream_window_size(1024 * 1024);
+    let endpoint = Endpoint::from_shared(format!("http://{}", fixture.addr)).unwrap();
+    let mut client = FlightClient::connect(endpoint, &config).await.unwrap();
+
+    let response = HandshakeResponse {
+        payload: Bytes::from("pong"),
+        protocol_version: 0,
+    };
+    test_server.set_handshake_response(Ok(response));
+    let response = client.handshake(Bytes::from("ping")).await.unwrap();
+    assert_eq!(response, Bytes::from("pong"));
+
+    fixture.shutdown_and_wait().await
+}
+
 /// Verifies that all headers sent from the the client are in the request_metadata
 fn ensure_metadata(client: &FlightClient, test_server: &TestFlightServer) {
     let client_metadata = client.metadata().clone().into_headers();
@@ -178,6 +209,124 @@ async fn test_poll_flight_info_error() {
     .await;
 }
 
+fn test_endpoint(ticket: &'static str) -> FlightEndpoint {
+    FlightEndpoint::new().with_ticket(Ticket::new(ticket))
+}
+
+fn test_incomplete_poll_info(
+    request: &FlightDescriptor,
+    endpoints: Vec<FlightEndpoint>,
+    progress: f64,
+) -> PollInfo {
+    let mut info = test_flight_info(request);
+    info.endpoint = endpoints;
+    PollInfo {
+        info: Some(info),
+        flight_descriptor: Some(FlightDescriptor::new_cmd(b"Retry".to_vec())),
+        progress: Some(progress),
+        expiration_time: None,
+    }
+}
+
+#[tokio::test]
+async fn test_flight_info_poller() {
+    do_test(|test_server, mut client| async move {
+        client.add_header("foo-header", "bar-header-value").unwrap();
+        let request = FlightDescriptor::new_cmd(b"My Command".to_vec());
+        let retry = FlightDescriptor::new_cmd(b"Retry".to_vec());
+        let mut poller = FlightInfoPoller::new(request.clone());
+        assert!(!poller.is_complete());
+        assert_eq!(poller.progress(), None);
+
+        // first poll returns the first endpoint
+        let response = test_incomplete_poll_info(&request, vec![test_endpoint("a")], 0.5);
+        test_server.set_poll_flight_info_response(Ok(response.clone()));
+        let endpoints = poller.poll(&mut client).await.unwrap();
+        assert_eq!(endpoints, vec![test_endpoint("a")]);
+        assert_eq!(
+            test_server.take_poll_flight_info_request(),
+            Some(request.clone())
+        );
+        assert_eq!(poller.progress(), Some(0.5));
+        assert_eq!(poller.poll_info(), Some(&response));
+        assert!(!poller.is_complete());
+        ensure_metadata(&client, &test_server);
+
+        // second poll uses the retry descriptor and only returns new endpoints
+        let mut response = test_poll_info(&request);
+        response.info.as_mut().unwrap().endpoint = vec![test_endpoint("a"), test_endpoint("b")];
+        test_server.set_poll_flight_info_response(Ok(response));
+        let endpoints = poller.poll(&mut client).await.unwrap();
+        assert_eq!(endpoints, vec![test_endpoint("b")]);
+        assert_eq!(test_server.take_poll_flight_info_request(), Some(retry));
+        assert_eq!(poller.progress(), Some(1.0));
+        assert_eq!(poller.flight_info().unwrap().endpoint.len(), 2);
+        assert!(poller.is_complete());
+
+        // polling a complete query is an error
+        let err = poller.poll(&mut client).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: PollFlightInfo query is already complete"
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_flight_info_poller_removed_endpoints() {
+    do_test(|test_server, mut client| async move {
+        let request = FlightDescriptor::new_cmd(b"My Command".to_vec());
+        let mut poller = FlightInfoPoller::new(request.clone());
+
+        let endpoints = vec![test_endpoint("a"), test_endpoint("b")];
+        let response = test_incomplete_poll_info(&request, endpoints, 0.5);
+        test_server.set_poll_flight_info_response(Ok(response));
+        assert_eq!(poller.poll(&mut client).await.unwrap().len(), 2);
+
+        let response = test_incomplete_poll_info(&request, vec![test_endpoint("a")], 0.7);
+        test_server.set_poll_flight_info_response(Ok(response));
+        let err = poller.poll(&mut client).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: PollFlightInfo response contained 1 endpoints, but 2 were previously returned"
+        );
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_flight_info_poller_cancel() {
+    do_test(|test_server, mut client| async move {
+        let request = FlightDescriptor::new_cmd(b"My Command".to_vec());
+        let mut poller = FlightInfoPoller::new(request.clone());
+
+        // cannot cancel before the query has started
+        let err = poller.cancel(&mut client).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Protocol error: Cannot cancel PollFlightInfo query before receiving FlightInfo"
+        );
+
+        let response = test_incomplete_poll_info(&request, vec![test_endpoint("a")], 0.5);
+        test_server.set_poll_flight_info_response(Ok(response.clone()));
+        poller.poll(&mut client).await.unwrap();
+
+        let expected_response = CancelFlightInfoResult::new(CancelStatus::Cancelled);
+        let encoded = expected_response.encode_to_vec();
+        test_server.set_do_action_response(vec![Ok(arrow_flight::Result::new(encoded))]);
+
+        let result = poller.cancel(&mut client).await.unwrap();
+        assert_eq!(result, expected_response);
+        assert!(poller.is_complete());
+
+        let expected_request = CancelFlightInfoRequest::new(response.info.unwrap());
+        let expected_request = Action::new("CancelFlightInfo", expected_request.encode_to_vec());
+        assert_eq!(test_server.take_do_action_request(), Some(expected_request));
+    })
+    .await;
+}
+
 // TODO more negative  tests (like if there are endpoints defined, etc)
 
 #[tokio::test]
@@ -1110,6 +1259,48 @@ async fn test_flight_data2() -> Vec<FlightData> {
 }
 
 /// Runs the future returned by the function,  passing it a test server and client
+#[cfg(all(feature = "local-transport", unix))]
+#[tokio::test]
+async fn test_do_get_local_transport() {
+    use arrow_flight::local::{bind_unix, connect_unix};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("flight.sock");
+
+    let test_server = TestFlightServer::new();
+    let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+    let server = tonic::transport::Server::builder()
+        .add_service(test_server.service())
+        .serve_with_incoming_shutdown(bind_unix(&path).unwrap(), async move {
+            rx.await.ok();
+        });
+    let handle = tokio::task::spawn(server);
+
+    let mut client = FlightClient::new(connect_unix(&path).await.unwrap());
+    let batch = RecordBatch::try_from_iter(vec![(
+        "col",
+        Arc::new(UInt64Array::from_iter([1, 2, 3, 4])) as _,
+    )])
+    .unwrap();
+    test_server.set_do_get_response(vec![Ok(batch.clone())]);
+
+    let ticket = Ticket {
+        ticket: Bytes::from("my awesome flight ticket"),
+    };
+    let response: Vec<_> = client
+        .do_get(ticket.clone())
+        .await
+        .expect("error making request")
+        .try_collect()
+        .await
+        .expect("Error streaming data");
+    assert_eq!(response, vec![batch]);
+    assert_eq!(test_server.take_do_get_request(), Some(ticket));
+
+    tx.send(()).unwrap();
+    handle.await.unwrap().unwrap();
+}
+
 async fn do_test<F, Fut>(f: F)
 where
     F: Fn(TestFlightServer, FlightClient) -> Fut,
@@ -32,6 +32,7 @@ use flatbuffers::{VectorIter, VerifierOptions};
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::ops::Range;
 use std::sync::Arc;
 
 use arrow_array::*;
@@ -549,6 +550,53 @@ impl<'a> RecordBatchDecoder<'a> {
         })
     }
 
+    /// Returns the sorted ranges of the message body containing the buffers of the projected
+    /// fields, which are all that [`Self::read_record_batch`] reads from the body
+    fn projected_body_ranges(mut self, body_len: usize) -> Result<Vec<Range<usize>>, ArrowError> {
+        let mut variadic_counts: VecDeque<i64> = self
+            .batch
+            .variadicBufferCounts()
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let schema = Arc::clone(&self.schema);
+        let mut ranges = vec![];
+        for (idx, field) in schema.fields().iter().enumerate() {
+            let buffers = self.buffers.clone();
+            self.skip_field(field, &mut variadic_counts)?;
+            if self.projection.is_some_and(|p| !p.contains(&idx)) {
+                continue;
+            }
+            let consumed = buffers.len() - self.buffers.len();
+            for buffer in buffers.take(consumed) {
+                let start = buffer.offset().to_usize();
+                let end = start.zip(buffer.length().to_usize());
+                match end.and_then(|(start, len)| start.checked_add(len)) {
+                    Some(end) if end <= body_len => ranges.push(start.unwrap()..end),
+                    _ => {
+                        return Err(ArrowError::IpcError(format!(
+                            "Buffer at offset {} with length {} is out of bounds of message body of length {body_len}",
+                            buffer.offset(),
+                            buffer.length()
+                        )))
+                    }
+                }
+            }
+        }
+
+        // Merge the ranges separated by no more than the padding between buffers
+        ranges.sort_unstable_by_key(|r| r.start);
+        let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end + 64 => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        Ok(merged)
+    }
+
     fn skip_field(
         &mut self,
         field: &Field,
@@ -739,6 +787,32 @@ fn read_block<R: Read + Seek>(mut reader: R, block: &Block) -> Result<Buffer, Ar
     Ok(buf.into())
 }
 
+/// Read the metadata of `block` and the ranges of its body returned by `ranges`, which is
+/// called with the metadata and the length of the body
+///
+/// The remainder of the body is zeroed
+fn read_projected_block<R, F>(mut reader: R, block: &Block, ranges: F) -> Result<Buffer, ArrowError>
+where
+    R: Read + Seek,
+    F: FnOnce(&[u8], usize) -> Result<Vec<Range<usize>>, ArrowError>,
+{
+    let offset = block.offset() as u64;
+    let body_len = block.bodyLength().to_usize().unwrap();
+    let metadata_len = block.metaDataLength().to_usize().unwrap();
+    let total_len = body_len.checked_add(metadata_len).unwrap();
+
+    let mut buf = MutableBuffer::from_len_zeroed(total_len);
+    reader.seek(SeekFrom::Start(offset))?;
+    reader.read_exact(&mut buf[..metadata_len])?;
+    for range in ranges(&buf[..metadata_len], body_len)? {
+        reader.seek(SeekFrom::Start(
+            offset + (metadata_len + range.start) as u64,
+        ))?;
+        reader.read_exact(&mut buf[metadata_len + range.start..metadata_len + range.end])?;
+    }
+    Ok(buf.into())
+}
+
 /// Parse an encapsulated message
 ///
 /// <https://arrow.apache.org/docs/format/Columnar.html#encapsulated-message-format>
@@ -932,6 +1006,17 @@ impl FileDecoder {
         &self,
         block: &Block,
         buf: &Buffer,
+    ) -> Result<Option<RecordBatch>, ArrowError> {
+        self.read_projected_record_batch(block, buf, self.projection.as_deref())
+    }
+
+    /// Read the RecordBatch with the given block and data buffer, decoding only the columns
+    /// of the file schema in `projection` instead of the projection of this decoder
+    fn read_projected_record_batch(
+        &self,
+        block: &Block,
+        buf: &Buffer,
+        projection: Option<&[usize]>,
     ) -> Result<Option<RecordBatch>, ArrowError> {
         let message = self.read_message(buf)?;
         match message.header_type() {
@@ -950,7 +1035,7 @@ impl FileDecoder {
                     &self.dictionaries,
                     &message.version(),
                 )?
-                .with_projection(self.projection.as_deref())
+                .with_projection(projection)
                 .with_require_alignment(self.require_alignment)
                 .with_skip_validation(self.skip_validation.clone())
                 .read_record_batch()
@@ -1241,6 +1326,69 @@ impl<R: Read + Seek> FileReader<R> {
         }
     }
 
+    /// Read the [`RecordBatch`] at `index`, decoding only the columns in `projection`
+    ///
+    /// The indices of `projection` refer to the columns of the file schema, and replace any
+    /// projection this reader was created with. Only the buffers of the projected columns are
+    /// read from the underlying reader, which allows using an IPC file as a random access
+    /// dataset. Unlike [`Self::set_index`], this does not change the next batch returned by
+    /// the iterator.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::io::Cursor;
+    /// # use arrow_array::record_batch;
+    /// # use arrow_ipc::reader::FileReader;
+    /// # use arrow_ipc::writer::FileWriter;
+    /// let batch = record_batch!(("a", Int32, [1, 2]), ("b", Utf8, ["x", "y"])).unwrap();
+    /// let mut file = vec![];
+    /// let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.write(&batch.slice(1, 1)).unwrap();
+    /// writer.write_metadata("created_by", "example");
+    /// writer.finish().unwrap();
+    ///
+    /// let mut reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+    /// assert_eq!(reader.custom_metadata()["created_by"], "example");
+    /// let read = reader.read_batch(1, Some(&[1])).unwrap();
+    /// assert_eq!(read, record_batch!(("b", Utf8, ["y"])).unwrap());
+    /// // The iterator still starts at the first batch
+    /// assert_eq!(reader.next().unwrap().unwrap(), batch);
+    /// ```
+    pub fn read_batch(
+        &mut self,
+        index: usize,
+        projection: Option<&[usize]>,
+    ) -> Result<RecordBatch, ArrowError> {
+        let block = self.blocks.get(index).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot read batch at index {} from {} total batches",
+                index, self.total_blocks
+            ))
+        })?;
+        let decoder = &self.decoder;
+        let buffer = read_projected_block(&mut self.reader, block, |metadata, body_len| {
+            let message = decoder.read_message(metadata)?;
+            let batch = message.header_as_record_batch().ok_or_else(|| {
+                ArrowError::IpcError(format!("Block at index {index} is not a record batch"))
+            })?;
+            RecordBatchDecoder::try_new(
+                &Buffer::from_vec(Vec::<u8>::new()),
+                batch,
+                decoder.schema.clone(),
+                &decoder.dictionaries,
+                &message.version(),
+            )?
+            .with_projection(projection)
+            .projected_body_ranges(body_len)
+        })?;
+        decoder
+            .read_projected_record_batch(block, &buffer, projection)?
+            .ok_or_else(|| {
+                ArrowError::IpcError(format!("Block at index {index} is not a record batch"))
+            })
+    }
+
     fn maybe_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
         let block = &self.blocks[self.current_block];
         self.current_block += 1;
@@ -1778,6 +1926,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_batch_by_index() {
+        /// A reader that counts the bytes read
+        struct CountingReader {
+            inner: std::io::Cursor<Vec<u8>>,
+            bytes_read: usize,
+        }
+
+        impl Read for CountingReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let read = self.inner.read(buf)?;
+                self.bytes_read += read;
+                Ok(read)
+            }
+        }
+
+        impl Seek for CountingReader {
+            fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+                self.inner.seek(pos)
+            }
+        }
+
+        let schema = create_test_projection_schema();
+        let batch = create_test_projection_batch_data(&schema);
+        let batches = [batch.slice(0, 1), batch.slice(1, 2), batch.clone()];
+        let mut buf = Vec::new();
+        {
+            let mut writer = crate::writer::FileWriter::try_new(&mut buf, &schema).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        let reader = CountingReader {
+            inner: std::io::Cursor::new(buf),
+            bytes_read: 0,
+        };
+        // The projection of the reader is replaced by that of `read_batch`
+        let mut reader = FileReader::try_new(reader, Some(vec![0])).unwrap();
+        // Sliced run arrays can not be compared, so the run array column 10 is skipped
+        let columns: Vec<_> = (0..schema.fields().len()).filter(|c| *c != 10).collect();
+        for (index, expected) in batches.iter().enumerate().rev() {
+            for column in &columns {
+                let read = reader.read_batch(index, Some(&[*column])).unwrap();
+                assert_eq!(read, expected.project(&[*column]).unwrap());
+            }
+            let read = reader.read_batch(index, Some(&[13, 9, 2])).unwrap();
+            assert_eq!(read, expected.project(&[13, 9, 2]).unwrap());
+            let read = reader.read_batch(index, None).unwrap();
+            assert_eq!(read.schema(), expected.schema());
+            let read = read.project(&columns).unwrap();
+            assert_eq!(read, expected.project(&columns).unwrap());
+        }
+        let read = reader.read_batch(2, Some(&[10])).unwrap();
+        assert_eq!(read, batch.project(&[10]).unwrap());
+
+        // Only the buffers of the projected columns are read
+        let block_len =
+            |block: &Block| (block.metaDataLength() as i64 + block.bodyLength()) as usize;
+        let full_len = block_len(&reader.blocks[2]);
+        reader.get_mut().bytes_read = 0;
+        reader.read_batch(2, Some(&[11])).unwrap();
+        assert!(reader.get_ref().bytes_read < full_len / 2);
+
+        // Reading a batch does not change the position of the iterator
+        let read = reader.next().unwrap().unwrap();
+        assert_eq!(read, batches[0].project(&[0]).unwrap());
+
+        let err = reader.read_batch(3, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read batch at index 3 from 3 total batches"
+        );
+    }
+
     #[test]
     fn test_arrow_single_float_row() {
         let schema = Schema::new(vec![
@@ -14,6 +14,7 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
+use std::collections::HashMap;
 use std::io::Write;
 use std::sync::Arc;
 
@@ -33,6 +34,10 @@ use serde::Serializer;
 pub struct EncoderOptions {
     /// Whether to include nulls in the output or elide them.
     explicit_nulls: bool,
+    /// Overrides of `explicit_nulls` for the fields with a given name.
+    column_explicit_nulls: HashMap<String, bool>,
+    /// Whether to omit fields whose value is an empty JSON object.
+    omit_empty_objects: bool,
     /// Whether to encode structs as JSON objects or JSON arrays of their values.
     struct_mode: StructMode,
     /// An optional hook for customizing encoding behavior.
@@ -46,6 +51,30 @@ impl EncoderOptions {
         self
     }
 
+    /// Set whether to include nulls in the output or elide them for the fields named
+    /// `name`, overriding [`Self::with_explicit_nulls`] for them.
+    ///
+    /// This applies to the fields of nested structs as well as to top-level columns.
+    pub fn with_column_explicit_nulls(
+        mut self,
+        name: impl Into<String>,
+        explicit_nulls: bool,
+    ) -> Self {
+        self.column_explicit_nulls
+            .insert(name.into(), explicit_nulls);
+        self
+    }
+
+    /// Set whether to omit the fields of structs whose value is an empty JSON object,
+    /// such as a struct whose fields are all omitted nulls, or an empty map.
+    ///
+    /// Fields are omitted recursively, so a struct containing only empty objects is
+    /// itself omitted. Top-level rows are always written.
+    pub fn with_omit_empty_objects(mut self, omit_empty_objects: bool) -> Self {
+        self.omit_empty_objects = omit_empty_objects;
+        self
+    }
+
     /// Set whether to encode structs as JSON objects or JSON arrays of their values.
     pub fn with_struct_mode(mut self, struct_mode: StructMode) -> Self {
         self.struct_mode = struct_mode;
@@ -63,6 +92,20 @@ impl EncoderOptions {
         self.explicit_nulls
     }
 
+    /// Get whether to include nulls in the output or elide them for the fields named
+    /// `name`, taking into account [`Self::with_column_explicit_nulls`].
+    pub fn column_explicit_nulls(&self, name: &str) -> bool {
+        self.column_explicit_nulls
+            .get(name)
+            .copied()
+            .unwrap_or(self.explicit_nulls)
+    }
+
+    /// Get whether to omit the fields of structs whose value is an empty JSON object.
+    pub fn omit_empty_objects(&self) -> bool {
+        self.omit_empty_objects
+    }
+
     /// Get whether to encode structs as JSON objects or JSON arrays of their values.
     pub fn struct_mode(&self) -> StructMode {
         self.struct_mode
@@ -328,12 +371,13 @@ pub fn make_encoder<'a>(
                 Ok(FieldEncoder{
                     field: field.clone(),
                     encoder,
+                    explicit_nulls: options.column_explicit_nulls(field.name()),
                 })
             }).collect::<Result<Vec<_>, ArrowError>>()?;
 
             let encoder = StructArrayEncoder{
                 encoders,
-                explicit_nulls: options.explicit_nulls(),
+                omit_empty_objects: options.omit_empty_objects(),
                 struct_mode: options.struct_mode(),
             };
             let nulls = array.nulls().cloned();
@@ -372,6 +416,7 @@ fn encode_string(s: &str, out: &mut Vec<u8>) {
 struct FieldEncoder<'a> {
     field: FieldRef,
     encoder: NullableEncoder<'a>,
+    explicit_nulls: bool,
 }
 
 impl FieldEncoder<'_> {
@@ -382,7 +427,7 @@ impl FieldEncoder<'_> {
 
 struct StructArrayEncoder<'a> {
     encoders: Vec<FieldEncoder<'a>>,
-    explicit_nulls: bool,
+    omit_empty_objects: bool,
     struct_mode: StructMode,
 }
 
@@ -393,21 +438,21 @@ impl Encoder for StructArrayEncoder<'_> {
             StructMode::ListOnly => out.push(b'['),
         }
         let mut is_first = true;
-        // Nulls can only be dropped in explicit mode
-        let drop_nulls = (self.struct_mode == StructMode::ObjectOnly) && !self.explicit_nulls;
+        // Fields can only be dropped in object mode
+        let is_object = self.struct_mode == StructMode::ObjectOnly;
 
         for field_encoder in self.encoders.iter_mut() {
             let is_null = field_encoder.is_null(idx);
-            if is_null && drop_nulls {
+            if is_null && is_object && !field_encoder.explicit_nulls {
                 continue;
             }
 
+            let field_start = out.len();
             if !is_first {
                 out.push(b',');
             }
-            is_first = false;
 
-            if self.struct_mode == StructMode::ObjectOnly {
+            if is_object {
                 encode_string(field_encoder.field.name(), out);
                 out.push(b':');
             }
@@ -415,8 +460,14 @@ impl Encoder for StructArrayEncoder<'_> {
             if is_null {
                 out.extend_from_slice(b"null");
             } else {
+                let value_start = out.len();
                 field_encoder.encoder.encode(idx, out);
+                if is_object && self.omit_empty_objects && out[value_start..] == *b"{}" {
+                    out.truncate(field_start);
+                    continue;
+                }
             }
+            is_first = false;
         }
         match self.struct_mode {
             StructMode::ObjectOnly => out.push(b'}'),
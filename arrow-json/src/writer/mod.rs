@@ -255,6 +255,66 @@ impl WriterBuilder {
         self
     }
 
+    /// Returns `true` if this writer is configured to keep keys with null values for
+    /// fields named `name`.
+    pub fn column_explicit_nulls(&self, name: &str) -> bool {
+        self.0.column_explicit_nulls(name)
+    }
+
+    /// Set whether to keep keys with null values, or to omit writing them, for the fields
+    /// named `name`, overriding [`Self::with_explicit_nulls`] for them.
+    ///
+    /// This applies to the fields of nested structs as well as to top-level columns. For
+    /// example, to only write the nulls of the `bar` column:
+    ///
+    /// ```json
+    /// {"foo":1,"bar":null}
+    /// {"foo":1,"bar":2}
+    /// {"bar":null}
+    /// ```
+    pub fn with_column_explicit_nulls(
+        mut self,
+        name: impl Into<String>,
+        explicit_nulls: bool,
+    ) -> Self {
+        self.0 = self.0.with_column_explicit_nulls(name, explicit_nulls);
+        self
+    }
+
+    /// Returns `true` if this writer is configured to omit fields whose value is an
+    /// empty JSON object.
+    pub fn omit_empty_objects(&self) -> bool {
+        self.0.omit_empty_objects()
+    }
+
+    /// Set whether to omit the fields of structs whose value is an empty JSON object,
+    /// such as a struct whose fields are all omitted nulls, or an empty map.
+    ///
+    /// For example, with nulls skipped, a struct column `foo` with a single nullable
+    /// field `bar`:
+    ///
+    /// Keep empty objects (set to `false`):
+    ///
+    /// ```json
+    /// {"foo":{"bar":1}}
+    /// {"foo":{}}
+    /// ```
+    ///
+    /// Omit empty objects (set to `true`):
+    ///
+    /// ```json
+    /// {"foo":{"bar":1}}
+    /// {}
+    /// ```
+    ///
+    /// Fields are omitted recursively, so a struct containing only empty objects is
+    /// itself omitted. Default is to keep empty objects (set to `false`). This has no
+    /// effect if `struct_mode == ListOnly`.
+    pub fn with_omit_empty_objects(mut self, omit_empty_objects: bool) -> Self {
+        self.0 = self.0.with_omit_empty_objects(omit_empty_objects);
+        self
+    }
+
     /// Returns if this writer is configured to write structs as JSON Objects or Arrays.
     pub fn struct_mode(&self) -> StructMode {
         self.0.struct_mode()
@@ -1431,6 +1491,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_writer_column_explicit_nulls_and_empty_objects() {
+        let inner = StructArray::from(vec![(
+            Arc::new(Field::new("y", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![None, None, Some(3)])) as ArrayRef,
+        )]);
+        let nested = StructArray::from(vec![
+            (
+                Arc::new(Field::new("x", DataType::Int32, true)),
+                Arc::new(Int32Array::from(vec![None, Some(2), None])) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("inner", inner.data_type().clone(), true)),
+                Arc::new(inner) as ArrayRef,
+            ),
+        ]);
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        map.append(true).unwrap();
+        map.keys().append_value("k");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+        map.append(false).unwrap();
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "a",
+                Arc::new(Int32Array::from(vec![None, Some(1), None])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(Int32Array::from(vec![None, None, Some(4)])) as ArrayRef,
+            ),
+            ("s", Arc::new(nested) as ArrayRef),
+            ("m", Arc::new(map.finish()) as ArrayRef),
+        ])
+        .unwrap();
+
+        let write = |builder: WriterBuilder| {
+            let mut buf = Vec::new();
+            let mut writer = builder.build::<_, LineDelimited>(&mut buf);
+            writer.write_batches(&[&batch]).unwrap();
+            writer.finish().unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        let builder = WriterBuilder::new().with_column_explicit_nulls("b", true);
+        assert!(builder.column_explicit_nulls("b"));
+        assert!(!builder.column_explicit_nulls("a"));
+        assert_eq!(
+            write(builder.clone()),
+            concat!(
+                r#"{"b":null,"s":{"inner":{}},"m":{}}"#,
+                "\n",
+                r#"{"a":1,"b":null,"s":{"x":2,"inner":{}},"m":{"k":1}}"#,
+                "\n",
+                r#"{"b":4,"s":{"inner":{"y":3}}}"#,
+                "\n",
+            )
+        );
+
+        let builder = builder.with_omit_empty_objects(true);
+        assert!(builder.omit_empty_objects());
+        assert_eq!(
+            write(builder),
+            concat!(
+                r#"{"b":null}"#,
+                "\n",
+                r#"{"a":1,"b":null,"s":{"x":2},"m":{"k":1}}"#,
+                "\n",
+                r#"{"b":4,"s":{"inner":{"y":3}}}"#,
+                "\n",
+            )
+        );
+
+        let builder = WriterBuilder::new()
+            .with_explicit_nulls(true)
+            .with_column_explicit_nulls("x", false)
+            .with_column_explicit_nulls("inner", false)
+            .with_omit_empty_objects(true);
+        assert_eq!(
+            write(builder),
+            concat!(
+                r#"{"a":null,"b":null,"s":{"inner":{"y":null}}}"#,
+                "\n",
+                r#"{"a":1,"b":null,"s":{"x":2,"inner":{"y":null}},"m":{"k":1}}"#,
+                "\n",
+                r#"{"a":null,"b":4,"s":{"inner":{"y":3}},"m":null}"#,
+                "\n",
+            )
+        );
+    }
+
     #[test]
     fn test_writer_explicit_nulls() -> Result<(), ArrowError> {
         fn nested_list() -> (Arc<ListArray>, Arc<Field>) {
@@ -31,11 +31,13 @@ use arrow_array::{
 };
 use arrow_buffer::bit_util::ceil;
 use arrow_buffer::{BooleanBuffer, MutableBuffer, NullBuffer};
-use arrow_schema::ArrowError;
+use arrow_schema::{ArrowError, SortOptions};
 use arrow_select::take::take;
 use std::cmp::Ordering;
 use std::ops::Not;
 
+use crate::ord::make_comparator;
+
 #[derive(Debug, Copy, Clone)]
 enum Op {
     Equal,
@@ -177,8 +179,8 @@ pub fn gt_eq(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowErro
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are supported, in which case NULLs nested at any
+/// level are considered equal to each other, and distinct from any other value.
 pub fn distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::Distinct, lhs, rhs)
 }
@@ -195,8 +197,8 @@ pub fn distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowE
 /// to treat them as equal, please normalize zeros before calling this kernel. See
 /// [`f32::total_cmp`] and [`f64::total_cmp`].
 ///
-/// Nested types, such as lists, are not supported as the null semantics are not well-defined.
-/// For comparisons involving nested types see [`crate::ord::make_comparator`]
+/// Nested types, such as lists and structs, are supported, in which case NULLs nested at any
+/// level are considered equal to each other, and distinct from any other value.
 pub fn not_distinct(lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray, ArrowError> {
     compare_op(Op::NotDistinct, lhs, rhs)
 }
@@ -234,6 +236,9 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
     let r_t = r.data_type();
 
     if r_t.is_nested() || l_t.is_nested() {
+        if matches!(op, Op::Distinct | Op::NotDistinct) {
+            return distinct_nested(op, lhs, rhs, len);
+        }
         return Err(ArrowError::InvalidArgumentError(format!(
             "Nested comparison: {l_t} {op} {r_t} (hint: use make_comparator instead)"
         )));
@@ -326,6 +331,26 @@ fn compare_op(op: Op, lhs: &dyn Datum, rhs: &dyn Datum) -> Result<BooleanArray,
     })
 }
 
+/// Perform `IS DISTINCT FROM` or `IS NOT DISTINCT FROM` on the provided `Datum` of nested types
+fn distinct_nested(
+    op: Op,
+    lhs: &dyn Datum,
+    rhs: &dyn Datum,
+    len: usize,
+) -> Result<BooleanArray, ArrowError> {
+    let (l, l_s) = lhs.get();
+    let (r, r_s) = rhs.get();
+    // The comparator orders NULLs, so that they compare equal to each other at any level
+    let cmp = make_comparator(l, r, SortOptions::default())?;
+    let distinct = matches!(op, Op::Distinct);
+    let values = (0..len).map(|i| {
+        let l_idx = if l_s { 0 } else { i };
+        let r_idx = if r_s { 0 } else { i };
+        cmp(l_idx, r_idx).is_eq() != distinct
+    });
+    Ok(BooleanBuffer::from_iter(values).into())
+}
+
 /// Perform a potentially vectored `op` on the provided `ArrayOrd`
 fn apply<T: ArrayOrd>(
     op: Op,
@@ -659,7 +684,11 @@ pub fn compare_byte_view<T: ByteViewType>(
 mod tests {
     use std::sync::Arc;
 
-    use arrow_array::{DictionaryArray, Int32Array, Scalar, StringArray};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{
+        ArrayRef, DictionaryArray, Int32Array, ListArray, Scalar, StringArray, StructArray,
+    };
+    use arrow_schema::{DataType, Field};
 
     use super::*;
 
@@ -735,6 +764,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_distinct_nested() {
+        let a = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            Some(vec![Some(1), None]),
+            None,
+            None,
+            Some(vec![]),
+            Some(vec![Some(2)]),
+        ]);
+        let b = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+            Some(vec![]),
+            Some(vec![Some(3)]),
+        ]);
+        let expected = BooleanArray::from(vec![true, false, true, false, true, false]);
+        assert_eq!(not_distinct(&a, &b).unwrap(), expected);
+        let expected = BooleanArray::from(vec![false, true, false, true, false, true]);
+        assert_eq!(distinct(&a, &b).unwrap(), expected);
+
+        let scalar = Scalar::new(a.slice(0, 1));
+        let expected = BooleanArray::from(vec![true, false, false, false, false, false]);
+        assert_eq!(not_distinct(&b, &scalar).unwrap(), expected);
+        let expected = BooleanArray::from(vec![false, true, true, true, true, true]);
+        assert_eq!(distinct(&scalar, &b).unwrap(), expected);
+
+        let null_scalar = Scalar::new(a.slice(2, 1));
+        let expected = BooleanArray::from(vec![false, false, true, false, false, false]);
+        assert_eq!(not_distinct(&null_scalar, &b).unwrap(), expected);
+
+        // Nulls within structs are not distinct
+        let s = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![None, Some(1), None])) as ArrayRef,
+        )]);
+        let t = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![None, Some(1), Some(2)])) as ArrayRef,
+        )]);
+        let expected = BooleanArray::from(vec![true, true, false]);
+        assert_eq!(not_distinct(&s, &t).unwrap(), expected);
+
+        // Other comparisons of nested types are not supported
+        let err = eq(&a, &b).unwrap_err().to_string();
+        assert!(err.contains("Nested comparison"), "{err}");
+
+        let err = distinct(&a.slice(0, 3), &s).unwrap_err().to_string();
+        assert!(err.contains("Can't compare"), "{err}");
+    }
+
     #[test]
     fn test_distinct_scalar() {
         let a = Int32Array::new_scalar(12);
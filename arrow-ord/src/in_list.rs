@@ -0,0 +1,351 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Set membership kernels, see [`in_list`]
+
+use std::collections::HashSet;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    BinaryViewType, ByteArrayType, ByteViewType, GenericBinaryType, GenericStringType,
+    StringViewType,
+};
+use arrow_array::{Array, ArrayRef, BooleanArray, UInt32Array};
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use arrow_data::ArrayData;
+use arrow_schema::{ArrowError, DataType, SortOptions};
+use arrow_select::take::take;
+
+use crate::ord::make_comparator;
+use crate::search::lower_bound;
+
+/// Returns whether each row of `array` is contained in `set`, like SQL `IN`
+///
+/// This builds an [`InList`] from `set`, see [`InList::evaluate`] for details. To test
+/// several arrays against the same set, build the [`InList`] once instead.
+///
+/// ```
+/// # use arrow_array::{BooleanArray, StringArray, StringViewArray};
+/// # use arrow_ord::in_list::in_list;
+/// let array = StringViewArray::from(vec![Some("a"), Some("b"), None]);
+/// let set = StringViewArray::from(vec!["a", "c"]);
+/// let result = in_list(&array, &set).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), Some(false), None]));
+///
+/// // As in SQL, rows not found in a set containing null are null
+/// let set = StringViewArray::from(vec![Some("a"), None]);
+/// let result = in_list(&array, &set).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), None, None]));
+/// ```
+pub fn in_list(array: &dyn Array, set: &dyn Array) -> Result<BooleanArray, ArrowError> {
+    InList::try_new(set)?.evaluate(array)
+}
+
+/// A set of values prepared for membership tests, see [`in_list`]
+///
+/// Sets of primitive, string and binary values are hashed, while sets of other values, such
+/// as structs and lists, are sorted and searched with [`lower_bound`]. In both cases testing
+/// an array of `N` rows against a set of `M` values takes `O(N)` or `O(N log M)` comparisons
+/// rather than the `O(N * M)` of comparing against each value of the set.
+#[derive(Debug)]
+pub struct InList {
+    data_type: DataType,
+    members: Members,
+    contains_null: bool,
+}
+
+#[derive(Debug)]
+enum Members {
+    /// The values of primitive types at most 16 bytes wide, as zero-extended integers
+    Primitive(HashSet<u128>),
+    /// The bytes of wider primitive types and of string and binary types
+    Bytes(HashSet<Vec<u8>>),
+    /// The sorted distinct values of other types
+    Sorted(ArrayRef),
+}
+
+impl InList {
+    /// Prepares the values of `set` for membership tests
+    ///
+    /// Dictionary encoded sets are unpacked to their values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if values of the type of `set` can not be compared
+    pub fn try_new(set: &dyn Array) -> Result<Self, ArrowError> {
+        let set = match set.as_any_dictionary_opt() {
+            Some(dictionary) => take(dictionary.values().as_ref(), dictionary.keys(), None)?,
+            None => set.slice(0, set.len()),
+        };
+        let data_type = set.data_type().clone();
+        let contains_null = set.logical_null_count() > 0;
+        let nulls = set.logical_nulls();
+        let valid = |i: usize| nulls.as_ref().is_none_or(|n| n.is_valid(i));
+
+        let members = if let Some(width) = primitive_width(&data_type) {
+            let data = set.to_data();
+            let values = primitive_values(&data, width);
+            match width <= 16 {
+                true => Members::Primitive(
+                    (0..set.len())
+                        .filter(|i| valid(*i))
+                        .map(|i| primitive_key(values, width, i))
+                        .collect(),
+                ),
+                false => Members::Bytes(
+                    (0..set.len())
+                        .filter(|i| valid(*i))
+                        .map(|i| values[i * width..(i + 1) * width].to_vec())
+                        .collect(),
+                ),
+            }
+        } else if let Some(bytes) = byte_values(set.as_ref()) {
+            Members::Bytes(bytes.flatten().map(<[u8]>::to_vec).collect())
+        } else {
+            let cmp = make_comparator(set.as_ref(), set.as_ref(), SortOptions::default())?;
+            let mut indices: Vec<u32> = (0..set.len() as u32)
+                .filter(|i| valid(*i as usize))
+                .collect();
+            indices.sort_unstable_by(|a, b| cmp(*a as usize, *b as usize));
+            indices.dedup_by(|a, b| cmp(*a as usize, *b as usize).is_eq());
+            Members::Sorted(take(set.as_ref(), &UInt32Array::from(indices), None)?)
+        };
+        Ok(Self {
+            data_type,
+            members,
+            contains_null,
+        })
+    }
+
+    /// Returns the data type of the values of the set
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns whether each row of `array` is contained in the set
+    ///
+    /// Values are compared as by [`crate::cmp::eq`], so for instance `NaN` is contained in a
+    /// set containing `NaN`. Following the semantics of SQL `IN`, the result is null for null
+    /// rows, and for rows not contained in a set that contains null. Dictionary encoded
+    /// arrays are tested by testing their values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the values of `array` are not of the data type of the set
+    pub fn evaluate(&self, array: &dyn Array) -> Result<BooleanArray, ArrowError> {
+        if let Some(dictionary) = array.as_any_dictionary_opt() {
+            let values = self.evaluate(dictionary.values().as_ref())?;
+            let result = take(&values, dictionary.keys(), None)?;
+            return Ok(result.as_boolean().clone());
+        }
+        if array.data_type() != &self.data_type {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "in_list requires an array of the set type {}, got {}",
+                self.data_type,
+                array.data_type()
+            )));
+        }
+
+        let contains: BooleanBuffer = match &self.members {
+            Members::Primitive(members) => {
+                let width = primitive_width(&self.data_type).unwrap();
+                let data = array.to_data();
+                let values = primitive_values(&data, width);
+                (0..array.len())
+                    .map(|i| members.contains(&primitive_key(values, width, i)))
+                    .collect()
+            }
+            Members::Bytes(members) => match byte_values(array) {
+                Some(bytes) => bytes
+                    .map(|v| v.is_some_and(|v| members.contains(v)))
+                    .collect(),
+                None => {
+                    let width = primitive_width(&self.data_type).unwrap();
+                    let data = array.to_data();
+                    let values = primitive_values(&data, width);
+                    (0..array.len())
+                        .map(|i| members.contains(&values[i * width..(i + 1) * width]))
+                        .collect()
+                }
+            },
+            Members::Sorted(members) => {
+                let indices = lower_bound(members.as_ref(), &array, None)?;
+                let cmp = make_comparator(members.as_ref(), array, SortOptions::default())?;
+                indices
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, idx)| idx < members.len() && cmp(idx, i).is_eq())
+                    .collect()
+            }
+        };
+
+        let mut nulls = array.logical_nulls();
+        if self.contains_null {
+            let found = NullBuffer::new(contains.clone());
+            nulls = NullBuffer::union(nulls.as_ref(), Some(&found));
+        }
+        // Mask the values of null rows, which are not compared
+        let contains = match &nulls {
+            Some(nulls) => &contains & nulls.inner(),
+            None => contains,
+        };
+        Ok(BooleanArray::new(contains, nulls))
+    }
+}
+
+/// Returns the width in bytes of the values of primitive `data_type`, if any
+fn primitive_width(data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::Boolean => None,
+        _ => data_type.primitive_width(),
+    }
+}
+
+/// Returns the bytes of the values of a primitive array
+fn primitive_values(data: &ArrayData, width: usize) -> &[u8] {
+    let start = data.offset() * width;
+    &data.buffers()[0].as_slice()[start..start + data.len() * width]
+}
+
+/// Returns the value `i` of `values`, of `width` bytes, as a zero-extended integer
+fn primitive_key(values: &[u8], width: usize, i: usize) -> u128 {
+    let mut key = [0; 16];
+    key[..width].copy_from_slice(&values[i * width..(i + 1) * width]);
+    u128::from_le_bytes(key)
+}
+
+/// Returns an iterator over the bytes of the values of a string or binary `array`
+fn byte_values(array: &dyn Array) -> Option<Box<dyn Iterator<Item = Option<&[u8]>> + '_>> {
+    fn bytes<T: ByteArrayType>(array: &dyn Array) -> Box<dyn Iterator<Item = Option<&[u8]>> + '_> {
+        Box::new(array.as_bytes::<T>().iter().map(|v| v.map(AsRef::as_ref)))
+    }
+    fn views<T: ByteViewType>(array: &dyn Array) -> Box<dyn Iterator<Item = Option<&[u8]>> + '_> {
+        Box::new(
+            array
+                .as_byte_view::<T>()
+                .iter()
+                .map(|v| v.map(AsRef::as_ref)),
+        )
+    }
+    Some(match array.data_type() {
+        DataType::Utf8 => bytes::<GenericStringType<i32>>(array),
+        DataType::LargeUtf8 => bytes::<GenericStringType<i64>>(array),
+        DataType::Binary => bytes::<GenericBinaryType<i32>>(array),
+        DataType::LargeBinary => bytes::<GenericBinaryType<i64>>(array),
+        DataType::Utf8View => views::<StringViewType>(array),
+        DataType::BinaryView => views::<BinaryViewType>(array),
+        DataType::FixedSizeBinary(_) => Box::new(array.as_fixed_size_binary().iter()),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{
+        DictionaryArray, Float64Array, Int32Array, Int8Array, ListArray, StringArray, StructArray,
+    };
+    use arrow_buffer::i256;
+    use arrow_schema::Field;
+
+    #[test]
+    fn test_in_list_primitive() {
+        let array = Int32Array::from(vec![Some(1), Some(2), None, Some(4)]).slice(1, 3);
+        let set = Int32Array::from(vec![5, 4, 2, 4]);
+        let result = in_list(&array, &set).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), None, Some(true)])
+        );
+
+        let array = Float64Array::from(vec![f64::NAN, 0.0, -0.0, 1.0]);
+        let set = Float64Array::from(vec![Some(f64::NAN), Some(0.0), None]);
+        let result = in_list(&array, &set).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), Some(true), None, None])
+        );
+
+        let array = arrow_array::Decimal256Array::from(vec![i256::from(1), i256::MAX]);
+        let set = arrow_array::Decimal256Array::from(vec![i256::MAX]);
+        let result = in_list(&array, &set).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![false, true]));
+    }
+
+    #[test]
+    fn test_in_list_dictionary() {
+        let array: DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b"), Some("c"), Some("a")]
+                .into_iter()
+                .collect();
+        let set: DictionaryArray<Int32Type> = vec!["c", "a"].into_iter().collect();
+        let result = in_list(&array, &set).unwrap();
+        let expected = vec![Some(true), None, Some(false), Some(true), Some(true)];
+        assert_eq!(result, BooleanArray::from(expected));
+
+        let set = StringArray::from(vec!["b"]);
+        let result = in_list(&array, &set).unwrap();
+        let expected = vec![Some(false), None, Some(true), Some(false), Some(false)];
+        assert_eq!(result, BooleanArray::from(expected));
+
+        let err = in_list(&array, &Int8Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: in_list requires an array of the set type Int8, got Utf8"
+        );
+    }
+
+    #[test]
+    fn test_in_list_sorted() {
+        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let set = BooleanArray::from(vec![false, false]);
+        let result = in_list(&array, &set).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(false), Some(true), None])
+        );
+
+        let lists = |values: Vec<Option<Vec<Option<i32>>>>| {
+            ListArray::from_iter_primitive::<Int32Type, _, _>(values)
+        };
+        let array = lists(vec![
+            Some(vec![Some(1)]),
+            Some(vec![]),
+            Some(vec![None]),
+            None,
+        ]);
+        let set = lists(vec![
+            Some(vec![None]),
+            Some(vec![Some(1)]),
+            Some(vec![Some(1)]),
+        ]);
+        let result = in_list(&array, &set).unwrap();
+        let expected = vec![Some(true), Some(false), Some(true), None];
+        assert_eq!(result, BooleanArray::from(expected));
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let field = Arc::new(Field::new("a", DataType::Int32, false));
+        let array = StructArray::from(vec![(field.clone(), a)]);
+        let set = StructArray::from(vec![(field, Arc::new(Int32Array::from(vec![3, 1])) as _)]);
+        let in_list = InList::try_new(&set).unwrap();
+        let result = in_list.evaluate(&array).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false, true]));
+    }
+}
@@ -52,7 +52,9 @@
 pub mod cmp;
 #[doc(hidden)]
 pub mod comparison;
+pub mod in_list;
 pub mod ord;
 pub mod partition;
 pub mod rank;
+pub mod search;
 pub mod sort;
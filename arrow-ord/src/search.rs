@@ -0,0 +1,311 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Binary search kernels for sorted arrays
+//!
+//! These can be used to locate the range of rows matching a key in sorted data,
+//! for example when performing a merge join or pruning sorted data by range.
+
+use std::cmp::Ordering;
+
+use arrow_array::{Array, ArrayRef, Datum};
+use arrow_schema::{ArrowError, SortOptions};
+
+use crate::ord::{make_comparator, DynComparator};
+use crate::sort::SortColumn;
+
+/// Returns the index of the first element in `0..len` for which `pred` returns `false`
+///
+/// `pred` must be partitioned, i.e. return `true` for all indexes before some index,
+/// and `false` for all indexes from it onwards. This is the index-based equivalent of
+/// [`slice::partition_point`], and can be used with any random-access sorted data,
+/// such as [`Rows`] from the `arrow-row` crate.
+///
+/// ```
+/// # use arrow_ord::search::partition_point;
+/// let values = [1, 2, 2, 3];
+/// assert_eq!(partition_point(values.len(), |i| values[i] < 2), 1);
+/// assert_eq!(partition_point(values.len(), |i| values[i] <= 2), 3);
+/// ```
+///
+/// [`Rows`]: https://docs.rs/arrow-row/latest/arrow_row/struct.Rows.html
+pub fn partition_point(len: usize, mut pred: impl FnMut(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// For each probe returns the index of the first element of `values` that is not
+/// less than the probe, i.e. the first index at which the probe could be inserted
+/// while keeping `values` sorted
+///
+/// `values` must be sorted according to `options`, with the same null ordering.
+/// `probes` may be an [`Array`] or a [`Scalar`](arrow_array::Scalar), in which case
+/// a single index is returned.
+///
+/// ```
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::search::{lower_bound, upper_bound};
+/// let values = Int32Array::from(vec![1, 3, 3, 5]);
+///
+/// let probes = Int32Array::from(vec![0, 3, 4, 6]);
+/// assert_eq!(lower_bound(&values, &probes, None).unwrap(), vec![0, 1, 3, 4]);
+/// assert_eq!(upper_bound(&values, &probes, None).unwrap(), vec![0, 3, 3, 4]);
+///
+/// let probe = Int32Array::new_scalar(3);
+/// assert_eq!(lower_bound(&values, &probe, None).unwrap(), vec![1]);
+/// ```
+pub fn lower_bound(
+    values: &dyn Array,
+    probes: &dyn Datum,
+    options: Option<SortOptions>,
+) -> Result<Vec<usize>, ArrowError> {
+    search(values, probes, options, Ordering::is_lt)
+}
+
+/// For each probe returns the index of the first element of `values` that is
+/// greater than the probe, i.e. the last index at which the probe could be inserted
+/// while keeping `values` sorted
+///
+/// See [`lower_bound`] for details
+pub fn upper_bound(
+    values: &dyn Array,
+    probes: &dyn Datum,
+    options: Option<SortOptions>,
+) -> Result<Vec<usize>, ArrowError> {
+    search(values, probes, options, Ordering::is_le)
+}
+
+/// For each probe row returns the index of the first row of `columns` that is not
+/// less than the probe row, comparing lexicographically
+///
+/// `columns` must be sorted lexicographically according to their [`SortOptions`], as
+/// produced by [`lexsort_to_indices`](crate::sort::lexsort_to_indices). `probes` must
+/// contain one array per column, all of the same length.
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int32Array, StringArray};
+/// # use arrow_ord::search::{lexicographical_lower_bound, lexicographical_upper_bound};
+/// # use arrow_ord::sort::SortColumn;
+/// let columns = vec![
+///     SortColumn { values: Arc::new(Int32Array::from(vec![1, 1, 2, 2])), options: None },
+///     SortColumn { values: Arc::new(StringArray::from(vec!["a", "b", "a", "b"])), options: None },
+/// ];
+/// let probes: Vec<ArrayRef> = vec![
+///     Arc::new(Int32Array::from(vec![1, 2])),
+///     Arc::new(StringArray::from(vec!["b", "a"])),
+/// ];
+/// assert_eq!(lexicographical_lower_bound(&columns, &probes).unwrap(), vec![1, 2]);
+/// assert_eq!(lexicographical_upper_bound(&columns, &probes).unwrap(), vec![2, 3]);
+/// ```
+pub fn lexicographical_lower_bound(
+    columns: &[SortColumn],
+    probes: &[ArrayRef],
+) -> Result<Vec<usize>, ArrowError> {
+    lexicographical_search(columns, probes, Ordering::is_lt)
+}
+
+/// For each probe row returns the index of the first row of `columns` that is
+/// greater than the probe row, comparing lexicographically
+///
+/// See [`lexicographical_lower_bound`] for details
+pub fn lexicographical_upper_bound(
+    columns: &[SortColumn],
+    probes: &[ArrayRef],
+) -> Result<Vec<usize>, ArrowError> {
+    lexicographical_search(columns, probes, Ordering::is_le)
+}
+
+/// Returns for each probe `j` the first index `i` of `values` where `!pred(cmp(i, j))`
+fn search(
+    values: &dyn Array,
+    probes: &dyn Datum,
+    options: Option<SortOptions>,
+    pred: fn(Ordering) -> bool,
+) -> Result<Vec<usize>, ArrowError> {
+    let (probes, is_scalar) = probes.get();
+    let cmp = make_comparator(values, probes, options.unwrap_or_default())?;
+    let num_probes = if is_scalar { 1 } else { probes.len() };
+    Ok((0..num_probes)
+        .map(|j| partition_point(values.len(), |i| pred(cmp(i, j))))
+        .collect())
+}
+
+fn lexicographical_search(
+    columns: &[SortColumn],
+    probes: &[ArrayRef],
+    pred: fn(Ordering) -> bool,
+) -> Result<Vec<usize>, ArrowError> {
+    if columns.len() != probes.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected {} probe columns, got {}",
+            columns.len(),
+            probes.len()
+        )));
+    }
+    let Some(first) = columns.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Search requires at least one column".to_string(),
+        ));
+    };
+    let len = first.values.len();
+    let num_probes = probes[0].len();
+    if columns.iter().any(|c| c.values.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Searched columns must all have the same length".to_string(),
+        ));
+    }
+    if probes.iter().any(|p| p.len() != num_probes) {
+        return Err(ArrowError::InvalidArgumentError(
+            "Probe columns must all have the same length".to_string(),
+        ));
+    }
+
+    let comparators = columns
+        .iter()
+        .zip(probes)
+        .map(|(c, p)| make_comparator(c.values.as_ref(), p.as_ref(), c.options.unwrap_or_default()))
+        .collect::<Result<Vec<DynComparator>, _>>()?;
+    let cmp = |i: usize, j: usize| {
+        comparators
+            .iter()
+            .map(|cmp| cmp(i, j))
+            .find(|o| o.is_ne())
+            .unwrap_or(Ordering::Equal)
+    };
+
+    Ok((0..num_probes)
+        .map(|j| partition_point(len, |i| pred(cmp(i, j))))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Float64Array, Int32Array, StringArray};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_partition_point() {
+        assert_eq!(partition_point(0, |_| true), 0);
+        assert_eq!(partition_point(5, |_| true), 5);
+        assert_eq!(partition_point(5, |_| false), 0);
+        assert_eq!(partition_point(5, |i| i < 3), 3);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let values = Int32Array::from(vec![1, 3, 3, 3, 5, 7]);
+        let probes = Int32Array::from(vec![0, 1, 3, 4, 7, 8]);
+        assert_eq!(
+            lower_bound(&values, &probes, None).unwrap(),
+            vec![0, 0, 1, 4, 5, 6]
+        );
+        assert_eq!(
+            upper_bound(&values, &probes, None).unwrap(),
+            vec![0, 1, 4, 4, 6, 6]
+        );
+
+        let probe = Int32Array::new_scalar(3);
+        assert_eq!(lower_bound(&values, &probe, None).unwrap(), vec![1]);
+        assert_eq!(upper_bound(&values, &probe, None).unwrap(), vec![4]);
+
+        let empty = Int32Array::from(Vec::<i32>::new());
+        assert_eq!(lower_bound(&empty, &probe, None).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_bounds_descending_nulls() {
+        let options = SortOptions {
+            descending: true,
+            nulls_first: true,
+        };
+        let values = Float64Array::from(vec![None, Some(3.0), Some(2.0), Some(2.0), Some(1.0)]);
+        let probes = Float64Array::from(vec![None, Some(2.0), Some(0.0)]);
+        assert_eq!(
+            lower_bound(&values, &probes, Some(options)).unwrap(),
+            vec![0, 2, 5]
+        );
+        assert_eq!(
+            upper_bound(&values, &probes, Some(options)).unwrap(),
+            vec![1, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_bounds_type_mismatch() {
+        let values = Int32Array::from(vec![1, 2]);
+        let probes = StringArray::from(vec!["a"]);
+        assert!(lower_bound(&values, &probes, None).is_err());
+    }
+
+    #[test]
+    fn test_lexicographical_bounds() {
+        let columns = vec![
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![1, 1, 1, 2, 2])),
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(StringArray::from(vec!["c", "b", "b", "z", "a"])),
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }),
+            },
+        ];
+        let probes: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![1, 1, 2, 3])),
+            Arc::new(StringArray::from(vec!["b", "d", "b", "a"])),
+        ];
+        assert_eq!(
+            lexicographical_lower_bound(&columns, &probes).unwrap(),
+            vec![1, 0, 4, 5]
+        );
+        assert_eq!(
+            lexicographical_upper_bound(&columns, &probes).unwrap(),
+            vec![3, 0, 4, 5]
+        );
+    }
+
+    #[test]
+    fn test_lexicographical_bounds_errors() {
+        let columns = vec![SortColumn {
+            values: Arc::new(Int32Array::from(vec![1, 2])),
+            options: None,
+        }];
+        let err = lexicographical_lower_bound(&columns, &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Expected 1 probe columns, got 0"
+        );
+
+        let err = lexicographical_lower_bound(&[], &[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Search requires at least one column"
+        );
+    }
+}
@@ -25,9 +25,10 @@ use arrow_array::*;
 use arrow_buffer::ArrowNativeType;
 use arrow_buffer::BooleanBufferBuilder;
 use arrow_data::{ArrayDataBuilder, ByteView, MAX_INLINE_VIEW_LEN};
-use arrow_schema::{ArrowError, DataType};
+use arrow_schema::{ArrowError, DataType, Field};
 use arrow_select::take::take;
 use std::cmp::Ordering;
+use std::ops::Range;
 use std::sync::Arc;
 
 use crate::rank::{can_rank, rank};
@@ -55,6 +56,17 @@ pub use arrow_schema::SortOptions;
 /// assert_eq!(sorted_array.as_ref(), &Int32Array::from(vec![1, 2, 3, 4, 5]));
 /// ```
 pub fn sort(values: &dyn Array, options: Option<SortOptions>) -> Result<ArrayRef, ArrowError> {
+    match presorted(values, options.unwrap_or_default()) {
+        Some(Presorted::Sorted) => return Ok(values.slice(0, values.len())),
+        Some(Presorted::Reversed) => {
+            return take(
+                values,
+                &Presorted::Reversed.indices(values.len(), None),
+                None,
+            )
+        }
+        None => {}
+    }
     downcast_primitive_array!(
         values => sort_native_type(values, options),
         DataType::RunEndEncoded(_, _) => sort_run(values, options, None),
@@ -214,14 +226,231 @@ fn can_sort_to_indices(data_type: &DataType) -> bool {
         }
 }
 
+/// Returns true if `array` is sorted according to `options`
+///
+/// An array is sorted if each value is ordered before, or equal to, the value following it,
+/// where nulls are ordered according to the `nulls_first` flag in `options`, and floats are
+/// compared using IEEE 754 totalOrder, consistent with [`sort`].
+///
+/// Returns an error if the array type can not be compared, see [`make_comparator`]
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::{is_sorted, SortOptions};
+/// // Nulls are ordered first by default
+/// let array = Int32Array::from(vec![None, Some(1), Some(2), Some(2)]);
+/// assert!(is_sorted(&array, None).unwrap());
+///
+/// let options = SortOptions::default().nulls_last();
+/// assert!(!is_sorted(&array, Some(options)).unwrap());
+/// ```
+pub fn is_sorted(array: &dyn Array, options: Option<SortOptions>) -> Result<bool, ArrowError> {
+    if array.len() < 2 {
+        return Ok(true);
+    }
+    let cmp = make_comparator(array, array, options.unwrap_or_default())?;
+    Ok((1..array.len()).all(|i| cmp(i - 1, i).is_le()))
+}
+
+/// Returns the maximal runs of consecutive values of `array` that are sorted according to
+/// `options`, see [`is_sorted`]
+///
+/// The runs are non-empty, contiguous ranges of indices that together cover the whole array,
+/// so an array that is already sorted has a single run. Merge-based operators can use the runs
+/// to merge partially sorted data, rather than sorting it from scratch.
+///
+/// Returns an error if the array type can not be compared, see [`make_comparator`]
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::sorted_runs;
+/// let array = Int32Array::from(vec![1, 3, 5, 2, 4, 0]);
+/// let runs = sorted_runs(&array, None).unwrap();
+/// assert_eq!(runs, vec![0..3, 3..5, 5..6]);
+/// ```
+pub fn sorted_runs(
+    array: &dyn Array,
+    options: Option<SortOptions>,
+) -> Result<Vec<Range<usize>>, ArrowError> {
+    if array.is_empty() {
+        return Ok(vec![]);
+    }
+    let cmp = make_comparator(array, array, options.unwrap_or_default())?;
+    let mut runs = vec![];
+    let mut start = 0;
+    for i in 1..array.len() {
+        if cmp(i - 1, i).is_gt() {
+            runs.push(start..i);
+            start = i;
+        }
+    }
+    runs.push(start..array.len());
+    Ok(runs)
+}
+
+/// How the sort kernels use the order the values of a field are declared to be sorted in,
+/// see [`Field::sort_order`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeclaredOrder {
+    /// Ignore the declared order, and check whether the values are sorted
+    #[default]
+    Ignore,
+    /// Trust the declared order without checking the values
+    Trust,
+    /// Check the values are sorted in the declared order, returning an error if they are not
+    Verify,
+}
+
+/// Returns whether `array`, the values of `field`, can be treated as sorted according to
+/// `options` because of the order `field` is declared to be sorted in, see [`DeclaredOrder`]
+///
+/// The declared order matches `options` if both are equal, or if `array` contains no nulls
+/// and both are in the same direction.
+///
+/// # Example
+/// ```rust
+/// # use arrow_array::Int32Array;
+/// # use arrow_ord::sort::{is_declared_sorted, DeclaredOrder, SortOptions};
+/// # use arrow_schema::{DataType, Field};
+/// let field = Field::new("a", DataType::Int32, false).with_sort_order(Some(SortOptions::default()));
+/// let array = Int32Array::from(vec![3, 2, 1]);
+///
+/// // The declared order is wrong, which is only detected when verifying it
+/// let sorted = is_declared_sorted(&field, &array, SortOptions::default(), DeclaredOrder::Trust);
+/// assert!(sorted.unwrap());
+/// let sorted = is_declared_sorted(&field, &array, SortOptions::default(), DeclaredOrder::Verify);
+/// assert!(sorted.is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `mode` is [`DeclaredOrder::Verify`] and the values are not sorted in
+/// the declared order, or if the array type can not be compared, see [`make_comparator`]
+pub fn is_declared_sorted(
+    field: &Field,
+    array: &dyn Array,
+    options: SortOptions,
+    mode: DeclaredOrder,
+) -> Result<bool, ArrowError> {
+    let Some(order) = field.sort_order() else {
+        return Ok(false);
+    };
+    let matches = order == options
+        || (order.descending == options.descending && array.logical_null_count() == 0);
+    match mode {
+        _ if !matches => Ok(false),
+        DeclaredOrder::Ignore => Ok(false),
+        DeclaredOrder::Trust => Ok(true),
+        DeclaredOrder::Verify => match is_sorted(array, Some(order))? {
+            true => Ok(true),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "Field {} is declared sorted {order} but its values are not",
+                field.name()
+            ))),
+        },
+    }
+}
+
+/// Sort `array`, the values of `field`, into indices like [`sort_to_indices`], returning the
+/// identity indices without sorting if `field` is declared sorted according to `options`,
+/// see [`is_declared_sorted`]
+pub fn sort_field_to_indices(
+    field: &Field,
+    array: &dyn Array,
+    options: Option<SortOptions>,
+    limit: Option<usize>,
+    mode: DeclaredOrder,
+) -> Result<UInt32Array, ArrowError> {
+    match is_declared_sorted(field, array, options.unwrap_or_default(), mode)? {
+        true => Ok(Presorted::Sorted.indices(array.len(), limit)),
+        false => sort_to_indices(array, options, limit),
+    }
+}
+
+/// Returns the sorted runs of `array`, the values of `field`, like [`sorted_runs`], returning
+/// a single run without comparing the values if `field` is declared sorted according to
+/// `options`, see [`is_declared_sorted`]
+pub fn sorted_field_runs(
+    field: &Field,
+    array: &dyn Array,
+    options: Option<SortOptions>,
+    mode: DeclaredOrder,
+) -> Result<Vec<Range<usize>>, ArrowError> {
+    match is_declared_sorted(field, array, options.unwrap_or_default(), mode)? {
+        true if array.is_empty() => Ok(vec![]),
+        true => Ok(std::iter::once(0..array.len()).collect()),
+        false => sorted_runs(array, options),
+    }
+}
+
+/// The order of an array whose values are already sorted, detected by [`presorted`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Presorted {
+    /// The values are sorted
+    Sorted,
+    /// The values are strictly sorted in reverse, i.e. reversing them sorts them
+    ///
+    /// Equal values are not allowed, as reversing them would not preserve their relative
+    /// order, unlike sorting, which orders nulls by index
+    Reversed,
+}
+
+impl Presorted {
+    /// Returns the first `limit` indices that sort an array of `len` values in this order
+    fn indices(self, len: usize, limit: Option<usize>) -> UInt32Array {
+        let limit = limit.unwrap_or(len).min(len);
+        match self {
+            Self::Sorted => (0..limit as u32).collect(),
+            Self::Reversed => (0..len as u32).rev().take(limit).collect(),
+        }
+    }
+}
+
+/// Returns the order of `array` if it is already sorted, or sorted in reverse, according to
+/// `options`, so the sort kernels can skip sorting it
+///
+/// The check stops at the first pair of values that rules out both orders, so it is cheap
+/// for unsorted input.
+fn presorted(array: &dyn Array, options: SortOptions) -> Option<Presorted> {
+    if !can_sort_to_indices(array.data_type()) {
+        return None;
+    }
+    if array.len() < 2 {
+        return Some(Presorted::Sorted);
+    }
+    let cmp = make_comparator(array, array, options).ok()?;
+    let (mut sorted, mut reversed) = (true, true);
+    for i in 1..array.len() {
+        match cmp(i - 1, i) {
+            Ordering::Less | Ordering::Equal => reversed = false,
+            Ordering::Greater => sorted = false,
+        }
+        if !sorted && !reversed {
+            return None;
+        }
+    }
+    match sorted {
+        true => Some(Presorted::Sorted),
+        false => Some(Presorted::Reversed),
+    }
+}
+
 /// Sort elements from `ArrayRef` into an unsigned integer (`UInt32Array`) of indices.
 /// Floats are sorted using IEEE 754 totalOrder.  `limit` is an option for [partial_sort].
+///
+/// Input that is already sorted, or strictly sorted in reverse, is detected in a single pass,
+/// and returns the identity or reversed indices without sorting.
 pub fn sort_to_indices(
     array: &dyn Array,
     options: Option<SortOptions>,
     limit: Option<usize>,
 ) -> Result<UInt32Array, ArrowError> {
     let options = options.unwrap_or_default();
+    if let Some(presorted) = presorted(array, options) {
+        return Ok(presorted.indices(array.len(), limit));
+    }
 
     let (v, n) = partition_validity(array);
 
@@ -4681,4 +4910,124 @@ mod tests {
 
         assert_eq!(&sorted[0], &expected_struct_array);
     }
+
+    #[test]
+    fn test_is_sorted_and_sorted_runs() {
+        let array = Int32Array::from(vec![Some(1), Some(4), Some(4), Some(2), None, Some(3)]);
+        assert!(!is_sorted(&array, None).unwrap());
+        assert_eq!(sorted_runs(&array, None).unwrap(), vec![0..3, 3..4, 4..6]);
+        let options = SortOptions::default().nulls_last();
+        assert_eq!(
+            sorted_runs(&array, Some(options)).unwrap(),
+            vec![0..3, 3..5, 5..6]
+        );
+
+        let array = StringArray::from(vec![Some("c"), Some("b"), Some("b"), None]);
+        let options = SortOptions::default().desc().nulls_last();
+        assert!(is_sorted(&array, Some(options)).unwrap());
+        assert_eq!(sorted_runs(&array, Some(options)).unwrap(), vec![0..4]);
+
+        let empty = Int32Array::from(Vec::<i32>::new());
+        assert!(is_sorted(&empty, None).unwrap());
+        assert!(sorted_runs(&empty, None).unwrap().is_empty());
+        assert!(is_sorted(&Int32Array::from(vec![1]), None).unwrap());
+    }
+
+    #[test]
+    fn test_declared_sort_order() {
+        let options = SortOptions::default();
+        let field = Field::new("a", DataType::Int32, true);
+        let unsorted = Int32Array::from(vec![Some(2), None, Some(1)]);
+        let sorted = Int32Array::from(vec![None, Some(1), Some(2)]);
+        let no_nulls = Int32Array::from(vec![1, 2]);
+        for mode in [
+            DeclaredOrder::Ignore,
+            DeclaredOrder::Trust,
+            DeclaredOrder::Verify,
+        ] {
+            assert!(!is_declared_sorted(&field, &sorted, options, mode).unwrap());
+        }
+
+        let field = field.with_sort_order(Some(options));
+        let declared_sorted =
+            |array: &dyn Array, options, mode| is_declared_sorted(&field, array, options, mode);
+        assert!(!declared_sorted(&sorted, options, DeclaredOrder::Ignore).unwrap());
+        assert!(declared_sorted(&sorted, options, DeclaredOrder::Trust).unwrap());
+        assert!(declared_sorted(&sorted, options, DeclaredOrder::Verify).unwrap());
+        // Nulls are ordered differently, and there is no declared descending order
+        let nulls_last = options.nulls_last();
+        assert!(!declared_sorted(&sorted, nulls_last, DeclaredOrder::Trust).unwrap());
+        assert!(declared_sorted(&no_nulls, nulls_last, DeclaredOrder::Trust).unwrap());
+        assert!(!declared_sorted(&no_nulls, options.desc(), DeclaredOrder::Trust).unwrap());
+
+        // Trusting a wrong declaration returns the identity indices
+        let trusted = sort_field_to_indices(&field, &unsorted, None, None, DeclaredOrder::Trust);
+        assert_eq!(trusted.unwrap(), UInt32Array::from(vec![0, 1, 2]));
+        let err = sort_field_to_indices(&field, &unsorted, None, None, DeclaredOrder::Verify)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Field a is declared sorted ASC NULLS FIRST but its values are not"
+        );
+        let ignored =
+            sort_field_to_indices(&field, &unsorted, None, Some(2), DeclaredOrder::Ignore);
+        assert_eq!(ignored.unwrap(), UInt32Array::from(vec![1, 2]));
+
+        let runs = sorted_field_runs(&field, &unsorted, None, DeclaredOrder::Trust).unwrap();
+        assert_eq!(runs, vec![0..3]);
+        let runs = sorted_field_runs(&field, &unsorted, None, DeclaredOrder::Ignore).unwrap();
+        assert_eq!(runs, vec![0..1, 1..3]);
+        let empty = Int32Array::from(Vec::<i32>::new());
+        let runs = sorted_field_runs(&field, &empty, None, DeclaredOrder::Trust).unwrap();
+        assert!(runs.is_empty());
+    }
+
+    #[test]
+    fn test_sort_presorted() {
+        let sorted = Int32Array::from(vec![None, Some(1), Some(1), Some(5)]);
+        assert_eq!(
+            presorted(&sorted, SortOptions::default()),
+            Some(Presorted::Sorted)
+        );
+        assert_eq!(
+            sort_to_indices(&sorted, None, None).unwrap(),
+            UInt32Array::from(vec![0, 1, 2, 3])
+        );
+        assert_eq!(
+            sort_to_indices(&sorted, None, Some(2)).unwrap(),
+            UInt32Array::from(vec![0, 1])
+        );
+        assert_eq!(sort(&sorted, None).unwrap().as_ref(), &sorted);
+
+        let reversed = Int32Array::from(vec![Some(5), Some(3), Some(1), None]);
+        assert_eq!(
+            presorted(&reversed, SortOptions::default()),
+            Some(Presorted::Reversed)
+        );
+        let options = SortOptions::default().nulls_last();
+        assert_eq!(presorted(&reversed, options), None);
+        assert_eq!(
+            sort_to_indices(&reversed, None, Some(3)).unwrap(),
+            UInt32Array::from(vec![3, 2, 1])
+        );
+        assert_eq!(
+            sort(&reversed, None).unwrap().as_ref(),
+            &Int32Array::from(vec![None, Some(1), Some(3), Some(5)])
+        );
+
+        // Equal values are not reversed, to preserve the order of nulls
+        let ties = Int32Array::from(vec![Some(2), None, None]);
+        assert_eq!(presorted(&ties, SortOptions::default()), None);
+        assert_eq!(
+            sort_to_indices(&ties, None, None).unwrap(),
+            UInt32Array::from(vec![1, 2, 0])
+        );
+
+        let unsorted = Int32Array::from(vec![2, 1, 3]);
+        assert_eq!(presorted(&unsorted, SortOptions::default()), None);
+        assert_eq!(
+            sort(&unsorted, None).unwrap().as_ref(),
+            &Int32Array::from(vec![1, 2, 3])
+        );
+    }
 }
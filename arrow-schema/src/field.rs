@@ -27,9 +27,13 @@ use crate::extension::CanonicalExtensionType;
 use crate::schema::SchemaBuilder;
 use crate::{
     extension::{ExtensionType, EXTENSION_TYPE_METADATA_KEY, EXTENSION_TYPE_NAME_KEY},
-    Fields, UnionFields, UnionMode,
+    Fields, SortOptions, UnionFields, UnionMode,
 };
 
+/// The key of the [`Field::metadata`] declaring the order the values of the field are
+/// sorted in, see [`Field::sort_order`]
+pub const SORT_ORDER_METADATA_KEY: &str = "arrow.sort_order";
+
 /// A reference counted [`Field`]
 pub type FieldRef = Arc<Field>;
 
@@ -448,6 +452,52 @@ impl Field {
             .map(String::as_ref)
     }
 
+    /// Returns the order the values of this [`Field`] are declared to be sorted in, if set.
+    ///
+    /// This returns the value of [`SORT_ORDER_METADATA_KEY`] in [`Field::metadata`], parsed
+    /// as [`SortOptions`]. If the key is missing, or its value is not valid, the order of the
+    /// values is unknown and this returns `None`.
+    ///
+    /// The declared order is a hint, for instance from the sorting columns of a Parquet file,
+    /// that sort kernels can trust to skip sorting values that are already sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use arrow_schema::{DataType, Field, SortOptions};
+    /// let field = Field::new("ts", DataType::Int64, true);
+    /// assert_eq!(field.sort_order(), None);
+    ///
+    /// let field = field.with_sort_order(Some(SortOptions::default().desc()));
+    /// assert_eq!(field.metadata()["arrow.sort_order"], "DESC NULLS FIRST");
+    /// assert_eq!(field.sort_order(), Some(SortOptions::default().desc()));
+    /// ```
+    pub fn sort_order(&self) -> Option<SortOptions> {
+        self.metadata()
+            .get(SORT_ORDER_METADATA_KEY)
+            .and_then(|order| order.parse().ok())
+    }
+
+    /// Sets the order the values of this [`Field`] are declared to be sorted in, see
+    /// [`Field::sort_order`]
+    ///
+    /// `None` removes a previously declared order.
+    pub fn set_sort_order(&mut self, sort_order: Option<SortOptions>) {
+        match sort_order {
+            Some(order) => self
+                .metadata
+                .insert(SORT_ORDER_METADATA_KEY.to_owned(), order.to_string()),
+            None => self.metadata.remove(SORT_ORDER_METADATA_KEY),
+        };
+    }
+
+    /// Sets the order the values of this [`Field`] are declared to be sorted in and returns
+    /// self, see [`Field::sort_order`]
+    pub fn with_sort_order(mut self, sort_order: Option<SortOptions>) -> Self {
+        self.set_sort_order(sort_order);
+        self
+    }
+
     /// Returns an instance of the given [`ExtensionType`] of this [`Field`],
     /// if set in the [`Field::metadata`].
     ///
@@ -1128,6 +1178,36 @@ mod test {
         assert!(f1.cmp(&f3).is_lt());
     }
 
+    #[test]
+    fn test_sort_order() {
+        let mut field = Field::new("x", DataType::Int32, true)
+            .with_metadata(HashMap::from([(String::from("k1"), String::from("v1"))]));
+        for order in [
+            SortOptions::new(false, false),
+            SortOptions::new(false, true),
+            SortOptions::new(true, false),
+            SortOptions::new(true, true),
+        ] {
+            field.set_sort_order(Some(order));
+            assert_eq!(field.sort_order(), Some(order));
+        }
+        assert_eq!(field.metadata().len(), 2);
+
+        field.set_sort_order(None);
+        assert_eq!(field.sort_order(), None);
+        assert_eq!(field.metadata().len(), 1);
+
+        field
+            .metadata_mut()
+            .insert(SORT_ORDER_METADATA_KEY.to_string(), "ASC".to_string());
+        assert_eq!(field.sort_order(), None);
+        let err = "ASC NULLS FIRST LAST".parse::<SortOptions>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Invalid sort options, expected e.g. ASC NULLS FIRST, got ASC NULLS FIRST LAST"
+        );
+    }
+
     #[test]
     fn test_contains_reflexivity() {
         let mut field = Field::new("field1", DataType::Float16, false);
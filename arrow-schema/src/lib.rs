@@ -39,6 +39,7 @@ pub use fields::*;
 mod schema;
 pub use schema::*;
 use std::ops;
+use std::str::FromStr;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
@@ -104,6 +105,42 @@ impl Display for SortOptions {
     }
 }
 
+/// Parses the format of [`Display`], e.g. `"ASC NULLS FIRST"`, ignoring ASCII case
+///
+/// ```
+/// # use arrow_schema::SortOptions;
+/// let options: SortOptions = "desc nulls last".parse().unwrap();
+/// assert_eq!(options, !SortOptions::default());
+/// ```
+impl FromStr for SortOptions {
+    type Err = ArrowError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_ascii_whitespace();
+        let descending = match parts.next() {
+            Some(p) if p.eq_ignore_ascii_case("ASC") => Some(false),
+            Some(p) if p.eq_ignore_ascii_case("DESC") => Some(true),
+            _ => None,
+        };
+        let nulls = parts
+            .next()
+            .is_some_and(|p| p.eq_ignore_ascii_case("NULLS"));
+        let nulls_first = match parts.next() {
+            Some(p) if p.eq_ignore_ascii_case("FIRST") => Some(true),
+            Some(p) if p.eq_ignore_ascii_case("LAST") => Some(false),
+            _ => None,
+        };
+        match (descending, nulls, nulls_first, parts.next()) {
+            (Some(descending), true, Some(nulls_first), None) => {
+                Ok(Self::new(descending, nulls_first))
+            }
+            _ => Err(ArrowError::ParseError(format!(
+                "Invalid sort options, expected e.g. ASC NULLS FIRST, got {s}"
+            ))),
+        }
+    }
+}
+
 impl SortOptions {
     /// Create a new `SortOptions` struct
     pub fn new(descending: bool, nulls_first: bool) -> Self {
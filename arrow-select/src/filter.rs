@@ -189,6 +189,51 @@ pub fn filter_record_batch(
     RecordBatch::try_new_with_options(record_batch.schema(), filtered_arrays, &options)
 }
 
+/// Returns a filtered [RecordBatch] containing only the columns at `projection`
+/// and the rows where the corresponding elements of `predicate` are true.
+///
+/// This is equivalent to calling [RecordBatch::project] followed by
+/// [filter_record_batch], but only the projected columns are filtered,
+/// avoiding materializing columns that would be discarded.
+///
+/// # Example
+/// ```rust
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, BooleanArray, Int32Array, RecordBatch, StringArray};
+/// # use arrow_select::filter::filter_project;
+/// let batch = RecordBatch::try_from_iter([
+///     ("a", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+///     ("b", Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef),
+/// ])
+/// .unwrap();
+/// let predicate = BooleanArray::from(vec![true, false, true]);
+/// let filtered = filter_project(&batch, &predicate, &[1]).unwrap();
+/// assert_eq!(filtered.num_columns(), 1);
+/// assert_eq!(filtered.schema().field(0).name(), "b");
+/// assert_eq!(filtered.column(0).as_ref(), &StringArray::from(vec!["x", "z"]));
+/// ```
+pub fn filter_project(
+    record_batch: &RecordBatch,
+    predicate: &BooleanArray,
+    projection: &[usize],
+) -> Result<RecordBatch, ArrowError> {
+    let schema = Arc::new(record_batch.schema().project(projection)?);
+
+    let mut filter_builder = FilterBuilder::new(predicate);
+    if projection.len() > 1 {
+        // Only optimize if filtering more than one column
+        filter_builder = filter_builder.optimize();
+    }
+    let filter = filter_builder.build();
+
+    let filtered_arrays = projection
+        .iter()
+        .map(|i| filter_array(record_batch.column(*i), &filter))
+        .collect::<Result<Vec<_>, _>>()?;
+    let options = RecordBatchOptions::default().with_row_count(Some(filter.count()));
+    RecordBatch::try_new_with_options(schema, filtered_arrays, &options)
+}
+
 /// A builder to construct [`FilterPredicate`]
 #[derive(Debug)]
 pub struct FilterBuilder {
@@ -955,6 +1000,15 @@ mod tests {
         TimestampNanosecondArray::from(vec![1, 2, 3, 4])
     );
 
+    #[test]
+    fn test_filter_no_nulls_in_output() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), None, Some(5)]);
+        let b = BooleanArray::from(vec![true, false, true, false, true]);
+        let c = filter(&a, &b).unwrap();
+        assert!(c.nulls().is_none());
+        assert_eq!(c.as_primitive::<Int32Type>().values(), &[1, 3, 5]);
+    }
+
     #[test]
     fn test_filter_array_slice() {
         let a = Int32Array::from(vec![5, 6, 7, 8, 9]).slice(1, 4);
@@ -1426,6 +1480,31 @@ mod tests {
         assert_eq!(out.num_rows(), 2);
     }
 
+    #[test]
+    fn test_filter_project() {
+        let a = Arc::new(Int32Array::from(vec![Some(1), None, Some(3), Some(4)]));
+        let b = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let c = Arc::new(Int64Array::from(vec![10, 20, 30, 40]));
+        let batch =
+            RecordBatch::try_from_iter([("a", a as ArrayRef), ("b", b as _), ("c", c as _)])
+                .unwrap();
+        let pred = BooleanArray::from(vec![Some(true), Some(true), None, Some(true)]);
+
+        let out = filter_project(&batch, &pred, &[2, 0]).unwrap();
+        let expected = filter_record_batch(&batch.project(&[2, 0]).unwrap(), &pred).unwrap();
+        assert_eq!(out, expected);
+        assert_eq!(out.schema().field(0).name(), "c");
+        assert_eq!(out.num_rows(), 3);
+
+        // Empty projection still reports the filtered row count
+        let out = filter_project(&batch, &pred, &[]).unwrap();
+        assert_eq!(out.num_columns(), 0);
+        assert_eq!(out.num_rows(), 3);
+
+        // Out of bounds projection is an error
+        assert!(filter_project(&batch, &pred, &[3]).is_err());
+    }
+
     #[test]
     fn test_fast_path() {
         let a: PrimitiveArray<Int64Type> = PrimitiveArray::from(vec![Some(1), Some(2), None]);
@@ -1206,6 +1206,15 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_take_no_nulls_in_output() {
+        let values = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let indices = UInt32Array::from(vec![2, 0]);
+        let taken = take(&values, &indices, None).unwrap();
+        assert!(taken.nulls().is_none());
+        assert_eq!(taken.as_primitive::<Int32Type>().values(), &[3, 1]);
+    }
+
     #[test]
     fn test_take_primitive() {
         let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(2)]);
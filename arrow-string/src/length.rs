@@ -22,6 +22,7 @@ use arrow_array::{cast::AsArray, types::*};
 use arrow_buffer::{ArrowNativeType, NullBuffer, OffsetBuffer};
 use arrow_schema::{ArrowError, DataType};
 use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
 
 fn length_impl<P: ArrowPrimitiveType>(
     offsets: &OffsetBuffer<P::Native>,
@@ -44,10 +45,96 @@ fn bit_length_impl<P: ArrowPrimitiveType>(
     Arc::new(PrimitiveArray::<P>::new(v.into(), nulls.cloned()))
 }
 
+/// Computes the length of each string in `array` using `f`
+///
+/// If `is_trivial` returns true for the bytes of all the values, the length of each value
+/// is instead its length in bytes, computed from the offsets without inspecting the values
+fn string_length_impl<P: ArrowPrimitiveType>(
+    array: &GenericStringArray<P::Native>,
+    is_trivial: fn(&[u8]) -> bool,
+    f: fn(&str) -> usize,
+) -> ArrayRef
+where
+    P::Native: OffsetSizeTrait,
+{
+    let offsets = array.offsets();
+    let start = offsets.first().unwrap().as_usize();
+    let end = offsets.last().unwrap().as_usize();
+    if is_trivial(&array.value_data()[start..end]) {
+        return length_impl::<P>(offsets, array.nulls());
+    }
+
+    let v: Vec<_> = offsets
+        .windows(2)
+        .map(|w| {
+            let bytes = &array.value_data()[w[0].as_usize()..w[1].as_usize()];
+            // SAFETY: the offsets of a valid string array are char boundaries
+            let s = unsafe { std::str::from_utf8_unchecked(bytes) };
+            P::Native::usize_as(f(s))
+        })
+        .collect();
+    Arc::new(PrimitiveArray::<P>::new(v.into(), array.nulls().cloned()))
+}
+
+/// Computes the length of each string in `array` using `f`, see [`string_length_impl`]
+fn string_view_length_impl(
+    array: &StringViewArray,
+    is_trivial: fn(&[u8]) -> bool,
+    f: fn(&str) -> usize,
+) -> ArrayRef {
+    let v: Vec<_> = (0..array.len())
+        .map(|i| {
+            // SAFETY: i is within the bounds of the array
+            let s = unsafe { array.value_unchecked(i) };
+            match is_trivial(s.as_bytes()) {
+                true => s.len() as i32,
+                false => f(s) as i32,
+            }
+        })
+        .collect();
+    Arc::new(Int32Array::new(v.into(), array.nulls().cloned()))
+}
+
+/// Computes the length of each value of a string array, or dictionary of string arrays,
+/// in the units described by `is_trivial` and `f`, see [`string_length_impl`]
+fn string_length(
+    array: &dyn Array,
+    name: &str,
+    is_trivial: fn(&[u8]) -> bool,
+    f: fn(&str) -> usize,
+) -> Result<ArrayRef, ArrowError> {
+    if let Some(d) = array.as_any_dictionary_opt() {
+        let lengths = string_length(d.values().as_ref(), name, is_trivial, f)?;
+        return Ok(d.with_values(lengths));
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(string_length_impl::<Int32Type>(
+            array.as_string::<i32>(),
+            is_trivial,
+            f,
+        )),
+        DataType::LargeUtf8 => Ok(string_length_impl::<Int64Type>(
+            array.as_string::<i64>(),
+            is_trivial,
+            f,
+        )),
+        DataType::Utf8View => Ok(string_view_length_impl(
+            array.as_string_view(),
+            is_trivial,
+            f,
+        )),
+        other => Err(ArrowError::ComputeError(format!(
+            "{name} not supported for {other:?}"
+        ))),
+    }
+}
+
 /// Returns an array of Int32/Int64 denoting the length of each value in the array.
 ///
 /// For list array, length is the number of elements in each list.
 /// For string array and binary array, length is the number of bytes of each value.
+/// See [`char_length`] and [`grapheme_length`] for the length of strings in other units.
 ///
 /// * this only accepts ListArray/LargeListArray, StringArray/LargeStringArray/StringViewArray, BinaryArray/LargeBinaryArray, and FixedSizeListArray,
 ///   or DictionaryArray with above Arrays as values
@@ -164,6 +251,99 @@ pub fn bit_length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
     }
 }
 
+/// Returns an array of Int32/Int64 denoting the number of bytes in each value in the array.
+///
+/// This is the same as [`length`], but only accepts string and binary arrays.
+///
+/// * this only accepts StringArray/LargeStringArray/StringViewArray and
+///   BinaryArray/LargeBinaryArray/BinaryViewArray/FixedSizeBinaryArray,
+///   or DictionaryArray with above Arrays as values
+/// * octet_length of null is null.
+pub fn octet_length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let values = match array.as_any_dictionary_opt() {
+        Some(d) => d.values().as_ref(),
+        None => array,
+    };
+    match values.data_type() {
+        DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Utf8View
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_) => length(array),
+        other => Err(ArrowError::ComputeError(format!(
+            "octet_length not supported for {other:?}"
+        ))),
+    }
+}
+
+/// Returns an array of Int32/Int64 denoting the number of characters, i.e. Unicode scalar
+/// values, in each string in the array.
+///
+/// Strings containing only ASCII are handled without decoding, as each of their
+/// characters is a single byte.
+///
+/// * this only accepts StringArray/LargeStringArray/StringViewArray,
+///   or DictionaryArray with above Arrays as values
+/// * char_length of null is null.
+///
+/// ```
+/// # use arrow_array::{cast::AsArray, types::Int32Type, StringArray};
+/// # use arrow_string::length::{char_length, octet_length};
+/// let array = StringArray::from(vec![Some("arrow"), Some("café"), None]);
+///
+/// let chars = char_length(&array).unwrap();
+/// let chars = chars.as_primitive::<Int32Type>();
+/// assert_eq!(chars.iter().collect::<Vec<_>>(), vec![Some(5), Some(4), None]);
+///
+/// let bytes = octet_length(&array).unwrap();
+/// let bytes = bytes.as_primitive::<Int32Type>();
+/// assert_eq!(bytes.iter().collect::<Vec<_>>(), vec![Some(5), Some(5), None]);
+/// ```
+pub fn char_length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    string_length(array, "char_length", <[u8]>::is_ascii, |s| {
+        s.chars().count()
+    })
+}
+
+/// Returns an array of Int32/Int64 denoting the number of extended grapheme clusters, i.e.
+/// user-perceived characters, in each string in the array.
+///
+/// Grapheme clusters are determined according to [Unicode Standard Annex #29]. For example
+/// `"é"` written as `e` followed by a combining accent is one grapheme cluster, but two
+/// characters. Strings containing only ASCII are handled without segmentation, as each of
+/// their characters, other than `"\r\n"`, is a single grapheme cluster.
+///
+/// * this only accepts StringArray/LargeStringArray/StringViewArray,
+///   or DictionaryArray with above Arrays as values
+/// * grapheme_length of null is null.
+///
+/// ```
+/// # use arrow_array::{cast::AsArray, types::Int32Type, StringArray};
+/// # use arrow_string::length::{char_length, grapheme_length};
+/// let array = StringArray::from(vec!["e\u{301}", "👨‍👩‍👧", "a\r\n"]);
+///
+/// let graphemes = grapheme_length(&array).unwrap();
+/// assert_eq!(graphemes.as_primitive::<Int32Type>().values(), &[1, 1, 2]);
+///
+/// let chars = char_length(&array).unwrap();
+/// assert_eq!(chars.as_primitive::<Int32Type>().values(), &[2, 5, 3]);
+/// ```
+///
+/// [Unicode Standard Annex #29]: https://www.unicode.org/reports/tr29/
+pub fn grapheme_length(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    string_length(array, "grapheme_length", is_simple_ascii, |s| {
+        s.graphemes(true).count()
+    })
+}
+
+/// Returns true if `bytes` is ASCII without any `"\r\n"`, in which case every byte
+/// is its own grapheme cluster
+fn is_simple_ascii(bytes: &[u8]) -> bool {
+    bytes.is_ascii() && memchr::memmem::find(bytes, b"\r\n").is_none()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -738,4 +918,131 @@ mod tests {
         let result = bit_length(&array).unwrap();
         assert_eq!(result.as_ref(), &Int32Array::from(vec![32; 4]));
     }
+
+    #[test]
+    fn test_char_length() {
+        let input = vec![
+            Some("hello"),
+            Some("café"),
+            None,
+            Some(""),
+            Some("💖 arrow"),
+        ];
+        let expected = vec![Some(5), Some(4), None, Some(0), Some(7)];
+
+        let array = StringArray::from(input.clone());
+        let result = char_length(&array).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(expected.clone())
+        );
+
+        let array = LargeStringArray::from(input.clone());
+        let result = char_length(&array).unwrap();
+        let expected_large: Vec<_> = expected.iter().map(|v| v.map(i64::from)).collect();
+        assert_eq!(
+            result.as_primitive::<Int64Type>(),
+            &Int64Array::from(expected_large)
+        );
+
+        let array = StringViewArray::from(input);
+        let result = char_length(&array).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(expected)
+        );
+    }
+
+    #[test]
+    fn test_char_length_ascii_fast_path() {
+        // Only the sliced values are ASCII
+        let array = StringArray::from(vec!["é", "ab", "c", "ü"]);
+        let sliced = array.slice(1, 2);
+        let result = char_length(&sliced).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>().values(), &[2, 1]);
+
+        let result = char_length(&array).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>().values(), &[1, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_grapheme_length() {
+        let input = vec![
+            Some("e\u{301}"),
+            None,
+            Some("🇩🇪🇫🇷"),
+            Some("a\r\nb"),
+            Some("longer than twelve bytes"),
+        ];
+        let expected = vec![Some(1), None, Some(2), Some(3), Some(24)];
+
+        let array = StringArray::from(input.clone());
+        let result = grapheme_length(&array).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(expected.clone())
+        );
+
+        let array = StringViewArray::from(input);
+        let result = grapheme_length(&array).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(expected)
+        );
+
+        // "\r\n" is a single grapheme cluster even when all values are ASCII
+        let array = LargeStringArray::from(vec!["\r\n", "\r", "ab"]);
+        let result = grapheme_length(&array).unwrap();
+        assert_eq!(result.as_primitive::<Int64Type>().values(), &[1, 1, 2]);
+    }
+
+    #[test]
+    fn test_octet_length() {
+        let array = StringArray::from(vec![Some("café"), None]);
+        let result = octet_length(&array).unwrap();
+        assert_eq!(
+            result.as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![Some(5), None])
+        );
+
+        let array = BinaryViewArray::from(vec![b"abc".as_slice()]);
+        let result = octet_length(&array).unwrap();
+        assert_eq!(result.as_primitive::<Int32Type>().values(), &[3]);
+
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![Some(vec![Some(1)])]);
+        let err = octet_length(&list).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: octet_length not supported for List(Field { name: \"item\", data_type: Int32, nullable: true, dict_id: 0, dict_is_ordered: false, metadata: {} })"
+        );
+    }
+
+    #[test]
+    fn test_length_kernels_dictionary() {
+        let array: DictionaryArray<Int8Type> =
+            vec!["e\u{301}", "ab", "e\u{301}"].into_iter().collect();
+        let result = char_length(&array).unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        let values: Vec<_> = result
+            .downcast_dict::<Int32Array>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some(2), Some(2), Some(2)]);
+
+        let result = grapheme_length(&array).unwrap();
+        let result = result.as_dictionary::<Int8Type>();
+        let values: Vec<_> = result
+            .downcast_dict::<Int32Array>()
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(values, vec![Some(1), Some(2), Some(1)]);
+
+        let err = char_length(&Int32Array::from(vec![1])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Compute error: char_length not supported for Int32"
+        );
+    }
 }
@@ -0,0 +1,906 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for casting between Arrow arrays and Variants, see [`cast_to_variant`] and
+//! [`cast_from_variant`]
+
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, GenericListArray, MapArray, OffsetSizeTrait, StructArray,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::CastOptions;
+use arrow::datatypes::{
+    ArrowDictionaryKeyType, ArrowNativeType, ArrowTemporalType, Date32Type, Date64Type,
+    Decimal128Type, Decimal256Type, Decimal32Type, Decimal64Type, Float16Type, Float32Type,
+    Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Time32MillisecondType,
+    Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_schema::{ArrowError, DataType, FieldRef, Fields};
+use chrono::NaiveDateTime;
+use parquet_variant::path::{VariantPath, VariantPathElement};
+use parquet_variant::{
+    ObjectFieldBuilder, Variant, VariantBuilderExt, VariantDecimal16, VariantDecimal4,
+    VariantDecimal8,
+};
+
+use crate::variant_get::variants_to_primitive;
+use crate::{unshred_variant, VariantArray, VariantArrayBuilder};
+
+/// Casts each row of `input` to a [`Variant`]
+///
+/// Null rows are null in the output, and nulls nested within structs, lists and maps are
+/// [`Variant::Null`]. Structs and maps, whose keys must be strings, are cast to objects, and
+/// lists to lists. Primitive values are cast to the closest variant type, in particular:
+///
+/// * Unsigned integers are cast to the smallest signed integer that can hold them, or to a
+///   decimal for `UInt64` values above `i64::MAX`
+/// * Timestamps are cast to timestamps with microsecond precision, unless their unit is
+///   nanoseconds, and are only UTC adjusted if they have a timezone
+/// * Fixed size binaries are cast to binaries
+///
+/// Durations and intervals can not be cast to variants.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Int32Array, StringArray, StructArray};
+/// # use arrow_schema::{DataType, Field};
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::cast_to_variant;
+/// let input = StructArray::from(vec![
+///     (
+///         Arc::new(Field::new("id", DataType::Int32, false)),
+///         Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+///     ),
+///     (
+///         Arc::new(Field::new("name", DataType::Utf8, true)),
+///         Arc::new(StringArray::from(vec![Some("a"), None])) as ArrayRef,
+///     ),
+/// ]);
+/// let variants = cast_to_variant(&input).unwrap();
+///
+/// let row = variants.value(0);
+/// assert_eq!(row.get_object_field("id"), Some(Variant::from(1)));
+/// assert_eq!(row.get_object_field("name"), Some(Variant::from("a")));
+/// let row = variants.value(1);
+/// assert_eq!(row.get_object_field("name"), Some(Variant::Null));
+/// ```
+pub fn cast_to_variant(input: &dyn Array) -> Result<VariantArray, ArrowError> {
+    let nulls = input.logical_nulls();
+    let mut builder = VariantArrayBuilder::new(input.len());
+    for i in 0..input.len() {
+        match nulls.as_ref().is_some_and(|n| n.is_null(i)) {
+            true => builder.append_null(),
+            false => builder.append_with(|builder| append_array_value(builder, input, i))?,
+        }
+    }
+    Ok(builder.build())
+}
+
+/// Extension trait to append the rows of Arrow arrays to variant builders, such as
+/// [`VariantBuilder`] and [`ListBuilder`]
+///
+/// The rows are converted as by [`cast_to_variant`], without an intermediate
+/// [`VariantArray`] or JSON representation, except that null rows are appended as
+/// [`Variant::Null`].
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, Decimal128Array, Int32Array, StringArray, StructArray};
+/// # use arrow_schema::{DataType, Field};
+/// # use parquet_variant::{Variant, VariantBuilder, VariantDecimal8};
+/// # use parquet_variant_compute::ArrowVariantBuilderExt;
+/// let prices = Decimal128Array::from(vec![Some(1234), None]).with_precision_and_scale(10, 2);
+/// let input = StructArray::from(vec![
+///     (
+///         Arc::new(Field::new("id", DataType::Int32, false)),
+///         Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+///     ),
+///     (
+///         Arc::new(Field::new("price", DataType::Decimal128(10, 2), true)),
+///         Arc::new(prices.unwrap()) as ArrayRef,
+///     ),
+/// ]);
+///
+/// let mut builder = VariantBuilder::new();
+/// builder.append_arrow_scalar(&input, 0).unwrap();
+/// let (metadata, value) = builder.finish();
+/// let variant = Variant::new(&metadata, &value);
+/// let price = VariantDecimal8::try_new(1234, 2).unwrap();
+/// assert_eq!(variant.get_object_field("price"), Some(Variant::from(price)));
+///
+/// // All rows of an array are appended as a list
+/// let mut builder = VariantBuilder::new();
+/// builder.append_arrow_array(&StringArray::from(vec![Some("a"), None])).unwrap();
+/// let (metadata, value) = builder.finish();
+/// let variant = Variant::new(&metadata, &value);
+/// let list = variant.as_list().unwrap();
+/// assert_eq!(list.get(0), Some(Variant::from("a")));
+/// assert_eq!(list.get(1), Some(Variant::Null));
+/// ```
+///
+/// [`VariantBuilder`]: parquet_variant::VariantBuilder
+/// [`ListBuilder`]: parquet_variant::ListBuilder
+pub trait ArrowVariantBuilderExt {
+    /// Appends row `row` of `array`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data type of `array` can not be converted to a variant
+    fn append_arrow_scalar(&mut self, array: &dyn Array, row: usize) -> Result<(), ArrowError>;
+
+    /// Appends all rows of `array` as a list
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data type of `array` can not be converted to a variant
+    fn append_arrow_array(&mut self, array: &dyn Array) -> Result<(), ArrowError>;
+}
+
+impl<B> ArrowVariantBuilderExt for B
+where
+    B: for<'a> VariantBuilderExt<'a, 'a>,
+{
+    fn append_arrow_scalar(&mut self, array: &dyn Array, row: usize) -> Result<(), ArrowError> {
+        append_array_value(self, array, row)
+    }
+
+    fn append_arrow_array(&mut self, array: &dyn Array) -> Result<(), ArrowError> {
+        let mut list = self.new_list();
+        for i in 0..array.len() {
+            append_array_value(&mut list, array, i)?;
+        }
+        list.finish();
+        Ok(())
+    }
+}
+
+/// Casts each row of `input` to `data_type`, see [`cast_from_variant_with_options`]
+pub fn cast_from_variant(
+    input: &VariantArray,
+    data_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    cast_from_variant_with_options(input, data_type, &CastOptions::default())
+}
+
+/// Casts each row of `input` to `data_type`
+///
+/// This is the inverse of [`cast_to_variant`]: objects are cast to structs, whose missing
+/// fields are null, or to maps with string keys, and lists are cast to lists. Primitive
+/// values are converted as by [`variant_get`].
+///
+/// Null rows and [`Variant::Null`] values are null in the output. Values that can not be
+/// cast to their type are also null if [`CastOptions::safe`] is true, and result in an error
+/// otherwise. Shredded inputs are [unshredded] first.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, AsArray};
+/// # use arrow::compute::CastOptions;
+/// # use arrow::datatypes::{Int64Type};
+/// # use arrow_schema::{DataType, Field};
+/// # use parquet_variant_compute::{cast_from_variant_with_options, VariantArrayBuilder};
+/// let mut builder = VariantArrayBuilder::new(3);
+/// builder.append_json_str("[1, 2]").unwrap();
+/// builder.append_json_str("[3, null]").unwrap();
+/// builder.append_json_str(r#""four""#).unwrap();
+/// let variants = builder.build();
+///
+/// let list = DataType::new_list(DataType::Int64, true);
+/// let result = cast_from_variant_with_options(&variants, &list, &CastOptions::default()).unwrap();
+/// let result = result.as_list::<i32>();
+/// assert!(result.is_null(2));
+/// let elements = result.values().as_primitive::<Int64Type>();
+/// assert_eq!(elements.iter().collect::<Vec<_>>(), [Some(1), Some(2), Some(3), None]);
+///
+/// let options = CastOptions::default().with_safe(false);
+/// assert!(cast_from_variant_with_options(&variants, &list, &options).is_err());
+/// ```
+///
+/// [`variant_get`]: crate::variant_get::variant_get
+/// [unshredded]: crate::unshred_variant
+pub fn cast_from_variant_with_options(
+    input: &VariantArray,
+    data_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let unshredded;
+    let input = match input.typed_value_field() {
+        Some(_) => {
+            unshredded = unshred_variant(input)?;
+            &unshredded
+        }
+        None => input,
+    };
+    let values: Vec<_> = (0..input.len())
+        .map(|i| input.is_valid(i).then(|| input.value(i)))
+        .collect();
+    variants_to_array(&values, &VariantPath::default(), data_type, cast_options)
+}
+
+/// Converts the variant `values` at `path` into an array of `data_type`
+///
+/// Missing values and [`Variant::Null`] are null.
+pub(crate) fn variants_to_array(
+    values: &[Option<Variant>],
+    path: &VariantPath,
+    data_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    match data_type {
+        DataType::Struct(fields) => variants_to_struct(values, path, fields, cast_options),
+        DataType::List(field) => variants_to_list::<i32>(values, path, field, cast_options),
+        DataType::LargeList(field) => variants_to_list::<i64>(values, path, field, cast_options),
+        DataType::Map(entries, sorted) => {
+            variants_to_map(values, path, entries, *sorted, cast_options)
+        }
+        _ => variants_to_primitive(values, path, data_type, cast_options),
+    }
+}
+
+/// Returns an error for the `value` at index `i` that can not be converted to `data_type`,
+/// unless `cast_options` are safe
+fn cast_failed(
+    value: &Variant,
+    path: &VariantPath,
+    data_type: &DataType,
+    cast_options: &CastOptions,
+    i: usize,
+) -> Result<(), ArrowError> {
+    match cast_options.safe {
+        true => Ok(()),
+        false => Err(ArrowError::CastError(format!(
+            "Failed to extract {data_type} from variant {value:?} at path {path} in row {i}"
+        ))),
+    }
+}
+
+fn variants_to_struct(
+    values: &[Option<Variant>],
+    path: &VariantPath,
+    fields: &Fields,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let data_type = DataType::Struct(fields.clone());
+    let objects = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| match value {
+            Some(Variant::Object(object)) => Ok(Some(object)),
+            None | Some(Variant::Null) => Ok(None),
+            Some(value) => cast_failed(value, path, &data_type, cast_options, i).map(|_| None),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let nulls = NullBuffer::from_iter(objects.iter().map(Option::is_some));
+
+    let columns = fields
+        .iter()
+        .map(|field| {
+            let values: Vec<_> = objects
+                .iter()
+                .map(|object| object.and_then(|object| object.get(field.name())))
+                .collect();
+            let name = VariantPathElement::field(field.name().into());
+            let path = VariantPath::new(path.iter().cloned().chain([name]).collect());
+            variants_to_array(&values, &path, field.data_type(), cast_options)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let array =
+        StructArray::try_new_with_length(fields.clone(), columns, Some(nulls), values.len())?;
+    Ok(Arc::new(array))
+}
+
+fn variants_to_list<O: OffsetSizeTrait>(
+    values: &[Option<Variant>],
+    path: &VariantPath,
+    field: &FieldRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let data_type = GenericListArray::<O>::DATA_TYPE_CONSTRUCTOR(field.clone());
+    let mut elements = vec![];
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut valid = Vec::with_capacity(values.len());
+    for (i, value) in values.iter().enumerate() {
+        match value {
+            Some(Variant::List(list)) => elements.extend(list.iter().map(Some)),
+            None | Some(Variant::Null) => {}
+            Some(value) => cast_failed(value, path, &data_type, cast_options, i)?,
+        }
+        valid.push(matches!(value, Some(Variant::List(_))));
+        offsets.push(O::usize_as(elements.len()));
+    }
+
+    let elements = variants_to_array(&elements, path, field.data_type(), cast_options)?;
+    let offsets = OffsetBuffer::new(offsets.into());
+    let array = GenericListArray::try_new(field.clone(), offsets, elements, Some(valid.into()))?;
+    Ok(Arc::new(array))
+}
+
+fn variants_to_map(
+    values: &[Option<Variant>],
+    path: &VariantPath,
+    entries: &FieldRef,
+    sorted: bool,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef, ArrowError> {
+    let DataType::Struct(entry_fields) = entries.data_type() else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid map entries type {}",
+            entries.data_type()
+        )));
+    };
+    let [key_field, value_field] = entry_fields.iter().collect::<Vec<_>>()[..] else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Map entries must have two fields, got {}",
+            entry_fields.len()
+        )));
+    };
+    if !matches!(
+        key_field.data_type(),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    ) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Casting a variant to a map with {} keys is not supported",
+            key_field.data_type()
+        )));
+    }
+
+    let data_type = DataType::Map(entries.clone(), sorted);
+    let mut keys = vec![];
+    let mut items = vec![];
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    offsets.push(0);
+    let mut valid = Vec::with_capacity(values.len());
+    for (i, value) in values.iter().enumerate() {
+        match value {
+            Some(Variant::Object(object)) => {
+                for (key, item) in object.iter() {
+                    keys.push(Some(Variant::from(key)));
+                    items.push(Some(item));
+                }
+            }
+            None | Some(Variant::Null) => {}
+            Some(value) => cast_failed(value, path, &data_type, cast_options, i)?,
+        }
+        valid.push(matches!(value, Some(Variant::Object(_))));
+        offsets.push(i32::try_from(keys.len()).map_err(|_| {
+            ArrowError::ComputeError("Map entries overflow i32 offsets".to_string())
+        })?);
+    }
+
+    let keys = variants_to_primitive(&keys, path, key_field.data_type(), cast_options)?;
+    let items = variants_to_array(&items, path, value_field.data_type(), cast_options)?;
+    let entries_array = StructArray::try_new(entry_fields.clone(), vec![keys, items], None)?;
+    let offsets = OffsetBuffer::new(offsets.into());
+    let array = MapArray::try_new(
+        entries.clone(),
+        offsets,
+        entries_array,
+        Some(valid.into()),
+        sorted,
+    )?;
+    Ok(Arc::new(array))
+}
+
+/// Appends row `i` of `array` to `builder`, where nulls are [`Variant::Null`]
+fn append_array_value<'a>(
+    builder: &mut impl VariantBuilderExt<'a, 'a>,
+    array: &'a dyn Array,
+    i: usize,
+) -> Result<(), ArrowError> {
+    if array.is_null(i) {
+        builder.append_value(Variant::Null);
+        return Ok(());
+    }
+    match array.data_type() {
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let mut object = builder.new_object();
+            for (field, column) in fields.iter().zip(array.columns()) {
+                let mut field_builder = ObjectFieldBuilder::new(field.name(), &mut object);
+                append_array_value(&mut field_builder, column.as_ref(), i)?;
+            }
+            object.finish()?;
+        }
+        DataType::List(_) => append_list(builder, array.as_list::<i32>(), i)?,
+        DataType::LargeList(_) => append_list(builder, array.as_list::<i64>(), i)?,
+        DataType::FixedSizeList(_, _) => {
+            let array = array.as_fixed_size_list();
+            let values = array.values().as_ref();
+            let offset = array.value_offset(i) as usize;
+            let mut list = builder.new_list();
+            for j in offset..offset + array.value_length() as usize {
+                append_array_value(&mut list, values, j)?;
+            }
+            list.finish();
+        }
+        DataType::Map(_, _) => {
+            let array = array.as_map();
+            let offsets = array.value_offsets();
+            let (keys, values) = (array.keys().as_ref(), array.values().as_ref());
+            let mut object = builder.new_object();
+            for j in offsets[i] as usize..offsets[i + 1] as usize {
+                let key = match keys.data_type() {
+                    DataType::Utf8 => keys.as_string::<i32>().value(j),
+                    DataType::LargeUtf8 => keys.as_string::<i64>().value(j),
+                    DataType::Utf8View => keys.as_string_view().value(j),
+                    other => {
+                        return Err(ArrowError::NotYetImplemented(format!(
+                            "Casting a map with {other} keys to variant is not supported"
+                        )))
+                    }
+                };
+                append_array_value(&mut ObjectFieldBuilder::new(key, &mut object), values, j)?;
+            }
+            object.finish()?;
+        }
+        DataType::Dictionary(key_type, _) => {
+            let array = array.as_any_dictionary();
+            let key = match key_type.as_ref() {
+                DataType::Int8 => dictionary_key::<Int8Type>(array.keys(), i),
+                DataType::Int16 => dictionary_key::<Int16Type>(array.keys(), i),
+                DataType::Int32 => dictionary_key::<Int32Type>(array.keys(), i),
+                DataType::Int64 => dictionary_key::<Int64Type>(array.keys(), i),
+                DataType::UInt8 => dictionary_key::<UInt8Type>(array.keys(), i),
+                DataType::UInt16 => dictionary_key::<UInt16Type>(array.keys(), i),
+                DataType::UInt32 => dictionary_key::<UInt32Type>(array.keys(), i),
+                DataType::UInt64 => dictionary_key::<UInt64Type>(array.keys(), i),
+                other => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Invalid dictionary key type {other}"
+                    )))
+                }
+            };
+            append_array_value(builder, array.values().as_ref(), key)?;
+        }
+        _ => builder.append_value(primitive_to_variant(array, i)?),
+    }
+    Ok(())
+}
+
+fn append_list<'a, O: OffsetSizeTrait>(
+    builder: &mut impl VariantBuilderExt<'a, 'a>,
+    array: &'a GenericListArray<O>,
+    i: usize,
+) -> Result<(), ArrowError> {
+    let offsets = array.value_offsets();
+    let values = array.values().as_ref();
+    let mut list = builder.new_list();
+    for j in offsets[i].as_usize()..offsets[i + 1].as_usize() {
+        append_array_value(&mut list, values, j)?;
+    }
+    list.finish();
+    Ok(())
+}
+
+fn dictionary_key<K: ArrowDictionaryKeyType>(keys: &dyn Array, i: usize) -> usize {
+    keys.as_primitive::<K>().value(i).as_usize()
+}
+
+/// Returns the variant of the non-null row `i` of a primitive `array`
+pub(crate) fn primitive_to_variant(
+    array: &dyn Array,
+    i: usize,
+) -> Result<Variant<'_, '_>, ArrowError> {
+    let out_of_range = || {
+        ArrowError::CastError(format!(
+            "Failed to cast {} in row {i} to variant: value out of range",
+            array.data_type()
+        ))
+    };
+    Ok(match array.data_type() {
+        DataType::Null => Variant::Null,
+        DataType::Boolean => array.as_boolean().value(i).into(),
+        DataType::Int8 => array.as_primitive::<Int8Type>().value(i).into(),
+        DataType::Int16 => array.as_primitive::<Int16Type>().value(i).into(),
+        DataType::Int32 => array.as_primitive::<Int32Type>().value(i).into(),
+        DataType::Int64 => array.as_primitive::<Int64Type>().value(i).into(),
+        DataType::UInt8 => i16::from(array.as_primitive::<UInt8Type>().value(i)).into(),
+        DataType::UInt16 => i32::from(array.as_primitive::<UInt16Type>().value(i)).into(),
+        DataType::UInt32 => i64::from(array.as_primitive::<UInt32Type>().value(i)).into(),
+        DataType::UInt64 => {
+            let v = array.as_primitive::<UInt64Type>().value(i);
+            match i64::try_from(v) {
+                Ok(v) => v.into(),
+                Err(_) => VariantDecimal16::try_new(v.into(), 0)?.into(),
+            }
+        }
+        DataType::Float16 => f32::from(array.as_primitive::<Float16Type>().value(i)).into(),
+        DataType::Float32 => array.as_primitive::<Float32Type>().value(i).into(),
+        DataType::Float64 => array.as_primitive::<Float64Type>().value(i).into(),
+        DataType::Decimal32(_, scale) => {
+            let v = array.as_primitive::<Decimal32Type>().value(i);
+            VariantDecimal4::try_new(v, decimal_scale(*scale)?)?.into()
+        }
+        DataType::Decimal64(_, scale) => {
+            let v = array.as_primitive::<Decimal64Type>().value(i);
+            VariantDecimal8::try_new(v, decimal_scale(*scale)?)?.into()
+        }
+        DataType::Decimal128(precision, scale) => {
+            let v = array.as_primitive::<Decimal128Type>().value(i);
+            decimal128_to_variant(v, *precision, decimal_scale(*scale)?)?
+        }
+        DataType::Decimal256(_, scale) => {
+            let v = array.as_primitive::<Decimal256Type>().value(i);
+            let v = v.to_i128().ok_or_else(out_of_range)?;
+            VariantDecimal16::try_new(v, decimal_scale(*scale)?)?.into()
+        }
+        DataType::Date32 => Variant::Date(temporal::<Date32Type>(array, i)?.date()),
+        DataType::Date64 => Variant::Date(temporal::<Date64Type>(array, i)?.date()),
+        DataType::Time32(TimeUnit::Second) => {
+            Variant::Time(temporal::<Time32SecondType>(array, i)?.time())
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            Variant::Time(temporal::<Time32MillisecondType>(array, i)?.time())
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            Variant::Time(temporal::<Time64MicrosecondType>(array, i)?.time())
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            Variant::Time(temporal::<Time64NanosecondType>(array, i)?.time())
+        }
+        DataType::Timestamp(unit, tz) => {
+            let timestamp = match unit {
+                TimeUnit::Second => temporal::<TimestampSecondType>(array, i)?,
+                TimeUnit::Millisecond => temporal::<TimestampMillisecondType>(array, i)?,
+                TimeUnit::Microsecond => temporal::<TimestampMicrosecondType>(array, i)?,
+                TimeUnit::Nanosecond => temporal::<TimestampNanosecondType>(array, i)?,
+            };
+            match (unit, tz) {
+                (TimeUnit::Nanosecond, Some(_)) => Variant::TimestampNanos(timestamp.and_utc()),
+                (TimeUnit::Nanosecond, None) => Variant::TimestampNtzNanos(timestamp),
+                (_, Some(_)) => Variant::TimestampMicros(timestamp.and_utc()),
+                (_, None) => Variant::TimestampNtzMicros(timestamp),
+            }
+        }
+        DataType::Utf8 => array.as_string::<i32>().value(i).into(),
+        DataType::LargeUtf8 => array.as_string::<i64>().value(i).into(),
+        DataType::Utf8View => array.as_string_view().value(i).into(),
+        DataType::Binary => array.as_binary::<i32>().value(i).into(),
+        DataType::LargeBinary => array.as_binary::<i64>().value(i).into(),
+        DataType::BinaryView => array.as_binary_view().value(i).into(),
+        DataType::FixedSizeBinary(_) => array.as_fixed_size_binary().value(i).into(),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Casting {other} to variant is not supported"
+            )))
+        }
+    })
+}
+
+/// Returns the smallest variant decimal that fits `precision`
+///
+/// The values of a decimal array are not guaranteed to fit its precision, in which case
+/// a wider variant decimal is used
+fn decimal128_to_variant(
+    v: i128,
+    precision: u8,
+    scale: u8,
+) -> Result<Variant<'static, 'static>, ArrowError> {
+    if precision <= 9 {
+        let decimal = i32::try_from(v)
+            .ok()
+            .map(|v| VariantDecimal4::try_new(v, scale));
+        if let Some(Ok(decimal)) = decimal {
+            return Ok(decimal.into());
+        }
+    }
+    if precision <= 18 {
+        let decimal = i64::try_from(v)
+            .ok()
+            .map(|v| VariantDecimal8::try_new(v, scale));
+        if let Some(Ok(decimal)) = decimal {
+            return Ok(decimal.into());
+        }
+    }
+    Ok(VariantDecimal16::try_new(v, scale)?.into())
+}
+
+/// Returns row `i` of the temporal `array` as a date time
+fn temporal<T: ArrowTemporalType>(array: &dyn Array, i: usize) -> Result<NaiveDateTime, ArrowError>
+where
+    i64: From<T::Native>,
+{
+    array
+        .as_primitive::<T>()
+        .value_as_datetime(i)
+        .ok_or_else(|| {
+            ArrowError::CastError(format!(
+                "Failed to cast {} in row {i} to variant: value out of range",
+                array.data_type()
+            ))
+        })
+}
+
+/// Returns the scale of a variant decimal, which can not be negative
+fn decimal_scale(scale: i8) -> Result<u8, ArrowError> {
+    u8::try_from(scale).map_err(|_| {
+        ArrowError::NotYetImplemented(format!(
+            "Casting a decimal with negative scale {scale} to variant is not supported"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{batch_variant_to_json_string, shred_variant};
+    use arrow::array::{
+        BooleanArray, Date32Array, Decimal128Array, DictionaryArray, Int32Array, Int32Builder,
+        Int64Array, ListArray, MapBuilder, StringArray, StringBuilder, TimestampMillisecondArray,
+        UInt64Array,
+    };
+    use arrow::datatypes::Int64Type;
+    use arrow_schema::Field;
+    use parquet_variant::VariantBuilder;
+
+    fn to_json(array: &VariantArray) -> Vec<Option<String>> {
+        let json = batch_variant_to_json_string(&(Arc::new(array.clone()) as ArrayRef)).unwrap();
+        json.iter().map(|s| s.map(str::to_string)).collect()
+    }
+
+    #[test]
+    fn test_cast_primitives_to_variant() {
+        let cases: Vec<(ArrayRef, Vec<Variant>)> = vec![
+            (
+                Arc::new(BooleanArray::from(vec![true, false])),
+                vec![true.into(), false.into()],
+            ),
+            (
+                Arc::new(UInt64Array::from(vec![1, u64::MAX])),
+                vec![
+                    1i64.into(),
+                    VariantDecimal16::try_new(u64::MAX.into(), 0)
+                        .unwrap()
+                        .into(),
+                ],
+            ),
+            (
+                Arc::new(
+                    Decimal128Array::from(vec![12345, -1])
+                        .with_precision_and_scale(5, 2)
+                        .unwrap(),
+                ),
+                vec![
+                    VariantDecimal4::try_new(12345, 2).unwrap().into(),
+                    VariantDecimal4::try_new(-1, 2).unwrap().into(),
+                ],
+            ),
+            (
+                // Values that exceed the precision use a wider decimal
+                Arc::new(
+                    Decimal128Array::from(vec![1 << 40, i128::MAX / 2])
+                        .with_precision_and_scale(5, 2)
+                        .unwrap(),
+                ),
+                vec![
+                    VariantDecimal8::try_new(1 << 40, 2).unwrap().into(),
+                    VariantDecimal16::try_new(i128::MAX / 2, 2).unwrap().into(),
+                ],
+            ),
+            (
+                Arc::new(Date32Array::from(vec![0, 1])),
+                vec![
+                    Variant::Date(chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()),
+                    Variant::Date(chrono::NaiveDate::from_ymd_opt(1970, 1, 2).unwrap()),
+                ],
+            ),
+            (
+                Arc::new(TimestampMillisecondArray::from(vec![1, 2]).with_timezone("UTC")),
+                vec![
+                    Variant::TimestampMicros(chrono::DateTime::from_timestamp_millis(1).unwrap()),
+                    Variant::TimestampMicros(chrono::DateTime::from_timestamp_millis(2).unwrap()),
+                ],
+            ),
+            (
+                Arc::new(DictionaryArray::<Int32Type>::from_iter(["a", "b", "a"])),
+                vec!["a".into(), "b".into(), "a".into()],
+            ),
+        ];
+        for (input, expected) in cases {
+            let result = cast_to_variant(&input).unwrap();
+            assert_eq!(result.len(), expected.len());
+            for (i, expected) in expected.iter().enumerate() {
+                assert_eq!(&result.value(i), expected, "{}", input.data_type());
+            }
+        }
+    }
+
+    #[test]
+    fn test_cast_nested_to_variant() {
+        let mut map = MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        map.keys().append_value("a");
+        map.values().append_value(1);
+        map.append(true).unwrap();
+        map.append(false).unwrap();
+        map.keys().append_value("b");
+        map.values().append_null();
+        map.append(true).unwrap();
+        let map: ArrayRef = Arc::new(map.finish());
+        let list: ArrayRef = Arc::new(ListArray::from_iter_primitive::<Int64Type, _, _>([
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+        ]));
+        let input = StructArray::try_new(
+            vec![
+                Field::new("list", list.data_type().clone(), true),
+                Field::new("map", map.data_type().clone(), true),
+            ]
+            .into(),
+            vec![list, map],
+            Some(NullBuffer::from(vec![true, true, false])),
+        )
+        .unwrap();
+
+        let result = cast_to_variant(&input).unwrap();
+        assert_eq!(
+            to_json(&result),
+            [
+                Some(r#"{"list":[1,null],"map":{"a":1}}"#.to_string()),
+                Some(r#"{"list":null,"map":null}"#.to_string()),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_append_arrow_to_builders() {
+        let values = StringArray::from(vec!["x", "y"]);
+        let keys = Int32Array::from(vec![Some(1), None, Some(0)]);
+        let dictionary = DictionaryArray::new(keys, Arc::new(values));
+        let input = StructArray::try_new(
+            vec![Field::new("d", dictionary.data_type().clone(), true)].into(),
+            vec![Arc::new(dictionary.clone())],
+            None,
+        )
+        .unwrap();
+
+        let mut builder = VariantBuilder::new();
+        let mut object = builder.new_object();
+        let mut field = ObjectFieldBuilder::new("rows", &mut object);
+        field.append_arrow_array(&input).unwrap();
+        let mut list = object.new_list("last");
+        list.append_arrow_scalar(&dictionary, 2).unwrap();
+        list.append_arrow_scalar(&dictionary, 1).unwrap();
+        list.finish();
+        object.finish().unwrap();
+        let (metadata, value) = builder.finish();
+
+        let mut array = VariantArrayBuilder::new(1);
+        array.append_variant(Variant::new(&metadata, &value));
+        assert_eq!(
+            to_json(&array.build()),
+            [Some(
+                r#"{"last":["x",null],"rows":[{"d":"y"},{"d":null},{"d":"x"}]}"#.to_string()
+            )]
+        );
+
+        let durations = arrow::array::DurationSecondArray::from(vec![1]);
+        let err = VariantBuilder::new()
+            .append_arrow_array(&durations)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Casting Duration(Second) to variant is not supported"
+        );
+    }
+
+    #[test]
+    fn test_cast_from_variant() {
+        let mut builder = VariantArrayBuilder::new(5);
+        builder
+            .append_json_str(r#"{"id": 1, "tags": ["a", "b"], "attrs": {"x": 1.5}}"#)
+            .unwrap();
+        builder
+            .append_json_str(r#"{"id": "two", "tags": [], "attrs": {}}"#)
+            .unwrap();
+        builder.append_json_str(r#"{"tags": null}"#).unwrap();
+        builder.append_json_str("1").unwrap();
+        builder.append_null();
+        let variants = builder.build();
+
+        let attrs = DataType::Map(
+            Arc::new(Field::new(
+                "entries",
+                DataType::Struct(
+                    vec![
+                        Field::new("keys", DataType::Utf8, false),
+                        Field::new("values", DataType::Float64, true),
+                    ]
+                    .into(),
+                ),
+                false,
+            )),
+            false,
+        );
+        let fields: Fields = vec![
+            Field::new("id", DataType::Int64, true),
+            Field::new("tags", DataType::new_list(DataType::Utf8, true), true),
+            Field::new("attrs", attrs, true),
+        ]
+        .into();
+        let data_type = DataType::Struct(fields);
+        let result = cast_from_variant(&variants, &data_type).unwrap();
+        let result = result.as_struct();
+        assert_eq!(result.len(), 5);
+        assert_eq!(result.logical_nulls().unwrap().null_count(), 2);
+
+        let ids = result.column(0).as_primitive::<Int64Type>();
+        assert_eq!(
+            ids.iter().collect::<Vec<_>>(),
+            [Some(1), None, None, None, None]
+        );
+        let tags = result.column(1).as_list::<i32>();
+        assert_eq!(tags.value_offsets(), &[0, 2, 2, 2, 2, 2]);
+        assert!(tags.is_valid(1) && tags.is_null(2));
+        let attrs = result.column(2).as_map();
+        assert_eq!(attrs.value_offsets(), &[0, 1, 1, 1, 1, 1]);
+        assert_eq!(attrs.keys().as_string::<i32>().value(0), "x");
+        assert_eq!(
+            attrs
+                .values()
+                .as_primitive::<arrow::datatypes::Float64Type>()
+                .value(0),
+            1.5
+        );
+
+        // Round trip through a variant
+        let roundtrip = cast_to_variant(result).unwrap();
+        assert_eq!(
+            to_json(&roundtrip)[0].as_deref(),
+            Some(r#"{"attrs":{"x":1.5},"id":1,"tags":["a","b"]}"#)
+        );
+
+        let options = CastOptions::default().with_safe(false);
+        let err = cast_from_variant_with_options(&variants, &data_type, &options).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("from variant Int8(1) at path $ in row 3"));
+
+        let mut builder = VariantArrayBuilder::new(2);
+        builder.append_json_str(r#"{"id": 1}"#).unwrap();
+        builder.append_json_str(r#"{"id": "two"}"#).unwrap();
+        let err = cast_from_variant_with_options(&builder.build(), &data_type, &options);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "Cast error: Failed to extract Int64 from variant ShortString(ShortString(\"two\")) \
+             at path $.id in row 1"
+        );
+    }
+
+    #[test]
+    fn test_cast_from_shredded_variant() {
+        let input: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let variants = cast_to_variant(&input).unwrap();
+        let shredded = shred_variant(&variants, &DataType::Int64).unwrap();
+        let result = cast_from_variant(&shredded, &DataType::Int32).unwrap();
+        assert_eq!(result.as_ref(), input.as_ref());
+
+        let expected: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
+        let result = cast_from_variant(&variants, &DataType::Int64).unwrap();
+        assert_eq!(&result, &expected);
+        let strings = cast_from_variant(&variants, &DataType::Utf8).unwrap();
+        assert_eq!(strings.as_ref(), &StringArray::new_null(3) as &dyn Array);
+    }
+}
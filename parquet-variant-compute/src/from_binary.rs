@@ -0,0 +1,257 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for transforming separate `metadata` and `value` binary columns, such as those
+//! written by Spark without the Parquet `VARIANT` logical type, into a batch of Variants
+//! represented as STRUCT<metadata: BINARY, value: BINARY>
+
+use crate::VariantArray;
+use arrow::array::{Array, ArrayRef, AsArray, BinaryViewArray, StructArray};
+use arrow::buffer::ScalarBuffer;
+use arrow::compute::cast;
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use parquet_variant::Variant;
+use std::sync::Arc;
+
+/// Combine a `metadata` and a `value` binary column into a batch of Variants represented as
+/// STRUCT<metadata: BINARY, value: BINARY>, validating every non-null row.
+///
+/// This is intended for reading variants from Parquet files that store them as two plain
+/// `BYTE_ARRAY` columns, as Spark does for files written before the `VARIANT` logical type.
+///
+/// Both columns may be [`DataType::Binary`], [`DataType::LargeBinary`] or
+/// [`DataType::BinaryView`]. A row is null if its `value` is null. `metadata` must either have
+/// the same length as `value`, or contain a single entry which is then shared by every row, as
+/// all values written by a single Spark task reference the same top-level metadata. Shared
+/// metadata is stored only once in the output.
+///
+/// # Errors
+///
+/// Returns an error if either column is not binary, the lengths are incompatible, the metadata
+/// of a non-null row is null, or any non-null row is not a valid variant.
+pub fn batch_binary_to_variant(
+    metadata: &ArrayRef,
+    value: &ArrayRef,
+) -> Result<VariantArray, ArrowError> {
+    let metadata = binary_view(metadata, "metadata")?;
+    let value = binary_view(value, "value")?;
+
+    let shared_metadata = metadata.len() == 1 && value.len() != 1;
+    if !shared_metadata && metadata.len() != value.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Variant metadata column has {} rows but value column has {} rows",
+            metadata.len(),
+            value.len()
+        )));
+    }
+    let metadata_index = |row: usize| if shared_metadata { 0 } else { row };
+
+    for row in (0..value.len()).filter(|row| value.is_valid(*row)) {
+        let metadata_row = metadata_index(row);
+        if metadata.is_null(metadata_row) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Variant metadata is null for non-null value at row {row}"
+            )));
+        }
+        Variant::try_new(metadata.value(metadata_row), value.value(row)).map_err(|e| {
+            ArrowError::InvalidArgumentError(format!("Invalid variant at row {row}: {e}"))
+        })?;
+    }
+
+    // The subfields are expected to be non-nullable according to the parquet variant spec,
+    // so null rows are given an empty view, and shared metadata repeats the same view
+    let nulls = value.nulls().cloned();
+    let metadata_views = (0..value.len())
+        .map(|row| match value.is_valid(row) {
+            true => metadata.views()[metadata_index(row)],
+            false => 0,
+        })
+        .collect::<ScalarBuffer<u128>>();
+    let value_views = (0..value.len())
+        .map(|row| match value.is_valid(row) {
+            true => value.views()[row],
+            false => 0,
+        })
+        .collect::<ScalarBuffer<u128>>();
+
+    // Safety: the views are either copied from valid arrays with the same data buffers,
+    // or are empty inline views
+    let metadata_array = unsafe {
+        BinaryViewArray::new_unchecked(metadata_views, metadata.data_buffers().to_vec(), None)
+    };
+    let value_array =
+        unsafe { BinaryViewArray::new_unchecked(value_views, value.data_buffers().to_vec(), None) };
+
+    let fields = Fields::from(vec![
+        Field::new("metadata", DataType::BinaryView, false),
+        Field::new("value", DataType::BinaryView, false),
+    ]);
+    let inner = StructArray::new(
+        fields,
+        vec![
+            Arc::new(metadata_array) as ArrayRef,
+            Arc::new(value_array) as ArrayRef,
+        ],
+        nulls,
+    );
+    VariantArray::try_new(Arc::new(inner))
+}
+
+/// Casts a binary column to a [`BinaryViewArray`]
+fn binary_view(array: &ArrayRef, name: &str) -> Result<BinaryViewArray, ArrowError> {
+    match array.data_type() {
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+            Ok(cast(array, &DataType::BinaryView)?.as_binary_view().clone())
+        }
+        other => Err(ArrowError::CastError(format!(
+            "Expected Binary, LargeBinary or BinaryView variant {name} column, got {other}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::batch_binary_to_variant;
+    use arrow::array::{Array, ArrayRef, AsArray, BinaryArray, Int32Array, LargeBinaryArray};
+    use arrow_schema::ArrowError;
+    use parquet_variant::{Variant, VariantBuilder};
+    use std::sync::Arc;
+
+    fn variant_buffers(f: impl FnOnce(&mut VariantBuilder)) -> (Vec<u8>, Vec<u8>) {
+        let mut builder = VariantBuilder::new();
+        f(&mut builder);
+        builder.finish()
+    }
+
+    #[test]
+    fn test_batch_binary_to_variant() -> Result<(), ArrowError> {
+        let (m1, v1) = variant_buffers(|b| b.append_value(42));
+        let (m2, v2) = variant_buffers(|b| {
+            let mut obj = b.new_object();
+            obj.insert("a", "b");
+            obj.finish().unwrap();
+        });
+
+        let metadata: ArrayRef = Arc::new(BinaryArray::from(vec![
+            Some(m1.as_slice()),
+            None,
+            Some(m2.as_slice()),
+        ]));
+        let value: ArrayRef = Arc::new(LargeBinaryArray::from(vec![
+            Some(v1.as_slice()),
+            None,
+            Some(v2.as_slice()),
+        ]));
+        let variant_array = batch_binary_to_variant(&metadata, &value)?;
+
+        assert_eq!(variant_array.len(), 3);
+        assert_eq!(variant_array.value(0), Variant::from(42));
+        assert!(variant_array.is_null(1));
+        let obj = variant_array.value(2);
+        assert_eq!(obj.get_object_field("a"), Some(Variant::from("b")));
+
+        // Ensure that the subfields are not nullable
+        assert_eq!(variant_array.metadata_field().null_count(), 0);
+        assert_eq!(variant_array.value_field().null_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_binary_to_variant_shared_metadata() -> Result<(), ArrowError> {
+        // field names long enough that the metadata is not inlined in the views
+        let field_names = ["first_field", "second_field"];
+        let mut builder = VariantBuilder::new().with_field_names(field_names.into_iter());
+        let mut obj = builder.new_object();
+        obj.insert("first_field", 1);
+        obj.finish().unwrap();
+        let (metadata_bytes, v1) = builder.finish();
+
+        let mut builder = VariantBuilder::new().with_field_names(field_names.into_iter());
+        let mut obj = builder.new_object();
+        obj.insert("second_field", 2);
+        obj.finish().unwrap();
+        let (_, v2) = builder.finish();
+
+        let metadata: ArrayRef = Arc::new(BinaryArray::from(vec![metadata_bytes.as_slice()]));
+        let value: ArrayRef = Arc::new(BinaryArray::from(vec![
+            Some(v1.as_slice()),
+            Some(v2.as_slice()),
+            None,
+        ]));
+        let variant_array = batch_binary_to_variant(&metadata, &value)?;
+
+        assert_eq!(variant_array.len(), 3);
+        assert_eq!(
+            variant_array.value(0).get_object_field("first_field"),
+            Some(Variant::from(1))
+        );
+        assert_eq!(
+            variant_array.value(1).get_object_field("second_field"),
+            Some(Variant::from(2))
+        );
+        assert!(variant_array.is_null(2));
+
+        // the shared metadata is only stored once
+        let metadata_field = variant_array.metadata_field().as_binary_view();
+        assert_eq!(metadata_field.value(0), metadata_bytes.as_slice());
+        assert_eq!(metadata_field.value(1), metadata_bytes.as_slice());
+        let metadata_buffer_len: usize =
+            metadata_field.data_buffers().iter().map(|b| b.len()).sum();
+        assert!(metadata_bytes.len() > 12);
+        assert_eq!(metadata_buffer_len, metadata_bytes.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_binary_to_variant_errors() {
+        let (m1, v1) = variant_buffers(|b| b.append_value("hello"));
+
+        // mismatched lengths
+        let metadata: ArrayRef = Arc::new(BinaryArray::from(vec![m1.as_slice(); 2]));
+        let value: ArrayRef = Arc::new(BinaryArray::from(vec![v1.as_slice(); 3]));
+        let err = batch_binary_to_variant(&metadata, &value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant metadata column has 2 rows but value column has 3 rows"
+        );
+
+        // null metadata for a valid value
+        let metadata: ArrayRef = Arc::new(BinaryArray::from(vec![Some(m1.as_slice()), None]));
+        let value: ArrayRef = Arc::new(BinaryArray::from(vec![v1.as_slice(); 2]));
+        let err = batch_binary_to_variant(&metadata, &value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant metadata is null for non-null value at row 1"
+        );
+
+        // invalid variant bytes
+        let metadata: ArrayRef = Arc::new(BinaryArray::from(vec![m1.as_slice(); 2]));
+        let value: ArrayRef = Arc::new(BinaryArray::from(vec![v1.as_slice(), &[0xFF]]));
+        let err = batch_binary_to_variant(&metadata, &value).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Invalid argument error: Invalid variant at row 1:"));
+
+        // not a binary column
+        let value: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let err = batch_binary_to_variant(&metadata, &value).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Expected Binary, LargeBinary or BinaryView variant value column, got Int32"
+        );
+    }
+}
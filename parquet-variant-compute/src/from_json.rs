@@ -18,44 +18,93 @@
 //! Module for transforming a batch of JSON strings into a batch of Variants represented as
 //! STRUCT<metadata: BINARY, value: BINARY>
 
-use crate::{VariantArray, VariantArrayBuilder};
-use arrow::array::{Array, ArrayRef, StringArray};
-use arrow_schema::ArrowError;
-use parquet_variant::VariantBuilder;
+use crate::variant_array_builder::binary_view_array_from_buffers;
+use crate::VariantArray;
+use arrow::array::{Array, ArrayRef, AsArray, NullBufferBuilder, StructArray};
+use arrow_schema::{ArrowError, DataType, Field};
+use parquet_variant::VariantMetadataArena;
 use parquet_variant_json::json_to_variant;
+use std::sync::Arc;
 
 /// Parse a batch of JSON strings into a batch of Variants represented as
 /// STRUCT<metadata: BINARY, value: BINARY> where nulls are preserved. The JSON strings in the input
 /// must be valid.
+///
+/// The input may be a [`StringArray`], [`LargeStringArray`] or [`StringViewArray`].
+///
+/// The values of all rows are encoded into a single buffer, and share a single metadata
+/// dictionary containing the field names of all rows, which is only stored once.
+///
+/// [`StringArray`]: arrow::array::StringArray
+/// [`LargeStringArray`]: arrow::array::LargeStringArray
+/// [`StringViewArray`]: arrow::array::StringViewArray
 pub fn batch_json_string_to_variant(input: &ArrayRef) -> Result<VariantArray, ArrowError> {
-    let input_string_array = match input.as_any().downcast_ref::<StringArray>() {
-        Some(string_array) => Ok(string_array),
-        None => Err(ArrowError::CastError(
-            "Expected reference to StringArray as input".into(),
-        )),
-    }?;
-
-    let mut variant_array_builder = VariantArrayBuilder::new(input_string_array.len());
-    for i in 0..input.len() {
-        if input.is_null(i) {
+    match input.data_type() {
+        DataType::Utf8 => json_strings_to_variant(input.as_string::<i32>().iter()),
+        DataType::LargeUtf8 => json_strings_to_variant(input.as_string::<i64>().iter()),
+        DataType::Utf8View => json_strings_to_variant(input.as_string_view().iter()),
+        data_type => Err(ArrowError::CastError(format!(
+            "Expected a string array as input, got {data_type}"
+        ))),
+    }
+}
+
+fn json_strings_to_variant<'a>(
+    input: impl ExactSizeIterator<Item = Option<&'a str>>,
+) -> Result<VariantArray, ArrowError> {
+    let len = input.len();
+    let mut arena = VariantMetadataArena::new();
+    let mut nulls = NullBufferBuilder::new(len);
+    let mut value_buffer = Vec::new();
+    let mut value_locations = Vec::with_capacity(len);
+    let mut metadata_valid = Vec::with_capacity(len);
+    for json in input {
+        let value_offset = value_buffer.len();
+        match json {
             // The subfields are expected to be non-nullable according to the parquet variant spec.
-            variant_array_builder.append_null();
-        } else {
-            let mut vb = VariantBuilder::new();
-            json_to_variant(input_string_array.value(i), &mut vb)?;
-            let (metadata, value) = vb.finish();
-            variant_array_builder.append_variant_buffers(&metadata, &value);
+            None => nulls.append_null(),
+            Some(json) => {
+                let mut builder = arena.new_builder_with_buffer(value_buffer);
+                let result = json_to_variant(json, &mut builder);
+                value_buffer = builder.finish();
+                result?;
+                nulls.append_non_null();
+            }
         }
+        metadata_valid.push(json.is_some());
+        value_locations.push((value_offset, value_buffer.len() - value_offset));
     }
-    Ok(variant_array_builder.build())
+
+    // Every non-null row references the same metadata
+    let metadata = arena.finish();
+    let metadata_locations = metadata_valid
+        .into_iter()
+        .map(|valid| (0, if valid { metadata.len() } else { 0 }))
+        .collect();
+    let metadata_array = binary_view_array_from_buffers(metadata, metadata_locations);
+    let value_array = binary_view_array_from_buffers(value_buffer, value_locations);
+
+    let inner = StructArray::new(
+        vec![
+            Field::new("metadata", DataType::BinaryView, false),
+            Field::new("value", DataType::BinaryView, false),
+        ]
+        .into(),
+        vec![
+            Arc::new(metadata_array) as ArrayRef,
+            Arc::new(value_array) as ArrayRef,
+        ],
+        nulls.finish(),
+    );
+    VariantArray::try_new(Arc::new(inner))
 }
 
 #[cfg(test)]
 mod test {
     use crate::batch_json_string_to_variant;
-    use arrow::array::{Array, ArrayRef, AsArray, StringArray};
+    use arrow::array::{Array, ArrayRef, AsArray, StringArray, StringViewArray};
     use arrow_schema::ArrowError;
-    use parquet_variant::{Variant, VariantBuilder};
+    use parquet_variant::{Variant, VariantBuilder, VariantMetadata};
     use std::sync::Arc;
 
     #[test]
@@ -106,4 +155,55 @@ mod test {
         assert!(!value_array.is_null(4));
         Ok(())
     }
+
+    #[test]
+    fn test_batch_json_string_view_to_variant() {
+        let input = StringViewArray::from(vec![
+            Some(r#"{"name": "a long enough name to not be inlined", "id": 1}"#),
+            None,
+            Some(r#"{"id": 2, "tags": ["x"]}"#),
+            Some("[1, 2]"),
+        ]);
+        let array_ref: ArrayRef = Arc::new(input.clone());
+        let variant_array = batch_json_string_to_variant(&array_ref).unwrap();
+        assert_eq!(variant_array.len(), 4);
+        assert!(variant_array.is_null(1));
+        assert_eq!(
+            variant_array.value(0).get_object_field("id"),
+            Some(Variant::Int8(1))
+        );
+        assert_eq!(
+            variant_array.value(2).get_object_field("id"),
+            Some(Variant::Int8(2))
+        );
+        assert_eq!(variant_array.value(3).as_list().unwrap().len(), 2);
+
+        // The field names of all rows are interned once in the shared metadata
+        let metadata_array = variant_array.metadata_field().as_binary_view();
+        let metadata = VariantMetadata::try_new(metadata_array.value(0)).unwrap();
+        assert_eq!(metadata.len(), 3);
+        assert_eq!(metadata_array.value(0), metadata_array.value(2));
+        assert_eq!(metadata_array.data_buffers().len(), 1);
+
+        // Other string arrays produce the same variants
+        let large: ArrayRef = Arc::new(input.iter().collect::<arrow::array::LargeStringArray>());
+        let large_array = batch_json_string_to_variant(&large).unwrap();
+        for i in [0, 2, 3] {
+            assert_eq!(large_array.value(i), variant_array.value(i));
+        }
+    }
+
+    #[test]
+    fn test_batch_json_string_to_variant_errors() {
+        let input: ArrayRef = Arc::new(StringArray::from(vec!["1", "{\"a\": "]));
+        let err = batch_json_string_to_variant(&input).unwrap_err();
+        assert!(err.to_string().contains("JSON format error"), "{err}");
+
+        let input: ArrayRef = Arc::new(arrow::array::Int32Array::from(vec![1]));
+        let err = batch_json_string_to_variant(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Expected a string array as input, got Int32"
+        );
+    }
 }
@@ -0,0 +1,89 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for transforming a batch of Arrow microsecond times into a batch of Variants
+//! represented as STRUCT<metadata: BINARY, value: BINARY>
+
+use crate::{VariantArray, VariantArrayBuilder};
+use arrow::array::{Array, ArrayRef, Time64MicrosecondArray};
+use arrow::temporal_conversions::time64us_to_time;
+use arrow_schema::ArrowError;
+use parquet_variant::Variant;
+
+/// Convert a batch of microsecond times of day into a batch of [`Variant::Time`] values
+/// represented as STRUCT<metadata: BINARY, value: BINARY> where nulls are preserved.
+///
+/// Returns an error if any value is not within a single day.
+pub fn batch_time_micros_to_variant(input: &ArrayRef) -> Result<VariantArray, ArrowError> {
+    let time_array = match input.as_any().downcast_ref::<Time64MicrosecondArray>() {
+        Some(time_array) => Ok(time_array),
+        None => Err(ArrowError::CastError(
+            "Expected reference to Time64MicrosecondArray as input".into(),
+        )),
+    }?;
+
+    let mut variant_array_builder = VariantArrayBuilder::new(time_array.len());
+    for i in 0..time_array.len() {
+        if time_array.is_null(i) {
+            variant_array_builder.append_null();
+        } else {
+            let micros = time_array.value(i);
+            let time = time64us_to_time(micros).ok_or_else(|| {
+                ArrowError::CastError(format!(
+                    "Could not cast `{micros}` microseconds into a NaiveTime"
+                ))
+            })?;
+            variant_array_builder.append_variant(Variant::Time(time));
+        }
+    }
+    Ok(variant_array_builder.build())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::batch_time_micros_to_variant;
+    use arrow::array::{Array, ArrayRef, Time64MicrosecondArray};
+    use arrow_schema::ArrowError;
+    use chrono::NaiveTime;
+    use parquet_variant::Variant;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_batch_time_micros_to_variant() -> Result<(), ArrowError> {
+        let micros = ((17 * 60 + 14) * 60 + 56) * 1_000_000 + 780012;
+        let input = Time64MicrosecondArray::from(vec![Some(micros), None, Some(0)]);
+        let array_ref: ArrayRef = Arc::new(input);
+        let variant_array = batch_time_micros_to_variant(&array_ref)?;
+
+        let expected = NaiveTime::from_hms_micro_opt(17, 14, 56, 780012).unwrap();
+        assert_eq!(variant_array.value(0), Variant::Time(expected));
+        assert!(variant_array.is_null(1));
+        assert_eq!(variant_array.value(2), Variant::Time(NaiveTime::MIN));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_time_micros_to_variant_out_of_range() {
+        let input = Time64MicrosecondArray::from(vec![-1]);
+        let array_ref: ArrayRef = Arc::new(input);
+        let err = batch_time_micros_to_variant(&array_ref).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Could not cast `-1` microseconds into a NaiveTime"
+        );
+    }
+}
@@ -0,0 +1,97 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for transforming a batch of Arrow nanosecond timestamps into a batch of Variants
+//! represented as STRUCT<metadata: BINARY, value: BINARY>
+
+use crate::{VariantArray, VariantArrayBuilder};
+use arrow::array::{Array, ArrayRef, TimestampNanosecondArray};
+use arrow::datatypes::DataType;
+use arrow_schema::ArrowError;
+use parquet_variant::Variant;
+
+/// Convert a batch of nanosecond timestamps into a batch of Variants represented as
+/// STRUCT<metadata: BINARY, value: BINARY> where nulls are preserved.
+///
+/// Timestamps with a timezone become [`Variant::TimestampNanos`] values, adjusted to UTC, and
+/// timestamps without a timezone become [`Variant::TimestampNtzNanos`] values, so that no
+/// precision is lost.
+pub fn batch_timestamp_nanos_to_variant(input: &ArrayRef) -> Result<VariantArray, ArrowError> {
+    let timestamp_array = match input.as_any().downcast_ref::<TimestampNanosecondArray>() {
+        Some(timestamp_array) => Ok(timestamp_array),
+        None => Err(ArrowError::CastError(
+            "Expected reference to TimestampNanosecondArray as input".into(),
+        )),
+    }?;
+    let has_timezone = matches!(timestamp_array.data_type(), DataType::Timestamp(_, Some(_)));
+
+    let mut variant_array_builder = VariantArrayBuilder::new(timestamp_array.len());
+    for i in 0..timestamp_array.len() {
+        if timestamp_array.is_null(i) {
+            variant_array_builder.append_null();
+        } else {
+            let timestamp = chrono::DateTime::from_timestamp_nanos(timestamp_array.value(i));
+            if has_timezone {
+                variant_array_builder.append_variant(Variant::TimestampNanos(timestamp));
+            } else {
+                variant_array_builder
+                    .append_variant(Variant::TimestampNtzNanos(timestamp.naive_utc()));
+            }
+        }
+    }
+    Ok(variant_array_builder.build())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::batch_timestamp_nanos_to_variant;
+    use arrow::array::{Array, ArrayRef, Int64Array, TimestampNanosecondArray};
+    use arrow_schema::ArrowError;
+    use parquet_variant::Variant;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_batch_timestamp_nanos_to_variant() -> Result<(), ArrowError> {
+        let nanos = 1_703_500_245_123_456_789;
+        let expected = chrono::DateTime::from_timestamp_nanos(nanos);
+
+        let input = TimestampNanosecondArray::from(vec![Some(nanos), None]);
+        let array_ref: ArrayRef = Arc::new(input.clone());
+        let variant_array = batch_timestamp_nanos_to_variant(&array_ref)?;
+        assert_eq!(
+            variant_array.value(0),
+            Variant::TimestampNtzNanos(expected.naive_utc())
+        );
+        assert!(variant_array.is_null(1));
+
+        let array_ref: ArrayRef = Arc::new(input.with_timezone("+01:00"));
+        let variant_array = batch_timestamp_nanos_to_variant(&array_ref)?;
+        assert_eq!(variant_array.value(0), Variant::TimestampNanos(expected));
+        assert!(variant_array.is_null(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_timestamp_nanos_to_variant_wrong_type() {
+        let array_ref: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        let err = batch_timestamp_nanos_to_variant(&array_ref).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Expected reference to TimestampNanosecondArray as input"
+        );
+    }
+}
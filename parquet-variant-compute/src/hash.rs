@@ -0,0 +1,100 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for hashing the logical values of a batch of Variants
+
+use crate::VariantArray;
+use arrow::array::{Array, UInt64Array};
+use std::hash::BuildHasher;
+
+/// Hash each row of a [`VariantArray`] with hashers built by `build_hasher`, where nulls are
+/// preserved.
+///
+/// Rows are hashed by their logical value, as described in the [`Hash`] implementation of
+/// [`Variant`], so rows that are logically equal have the same hash even if they are encoded
+/// differently. The hashes are only stable across processes if `build_hasher` is.
+///
+/// # Example
+/// ```
+/// # use std::collections::hash_map::RandomState;
+/// # use arrow::array::Array;
+/// # use parquet_variant_compute::{batch_variant_hash, VariantArrayBuilder};
+/// let mut builder = VariantArrayBuilder::new(3);
+/// builder.append_variant(42i8.into());
+/// builder.append_null();
+/// builder.append_variant(42i64.into());
+/// let array = builder.build();
+///
+/// let hashes = batch_variant_hash(&array, &RandomState::new());
+/// assert!(hashes.is_null(1));
+/// assert_eq!(hashes.value(0), hashes.value(2));
+/// ```
+///
+/// # Panics
+///
+/// Panics if a non-null row contains an invalid variant
+///
+/// [`Hash`]: std::hash::Hash
+/// [`Variant`]: parquet_variant::Variant
+pub fn batch_variant_hash<S: BuildHasher>(input: &VariantArray, build_hasher: &S) -> UInt64Array {
+    let hashes = (0..input.len())
+        .map(|i| match input.is_valid(i) {
+            true => build_hasher.hash_one(input.value(i)),
+            false => 0,
+        })
+        .collect::<Vec<_>>();
+    UInt64Array::new(hashes.into(), input.nulls().cloned())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{batch_variant_hash, VariantArrayBuilder};
+    use arrow::array::Array;
+    use parquet_variant::{Variant, VariantBuilder, VariantDecimal4};
+    use std::collections::hash_map::RandomState;
+
+    #[test]
+    fn test_batch_variant_hash() {
+        let mut builder = VariantArrayBuilder::new(6);
+        builder.append_variant(Variant::from(1.5));
+        builder.append_variant(Variant::from(VariantDecimal4::try_new(150, 2).unwrap()));
+        builder.append_null();
+        builder.append_variant(Variant::from("1.5"));
+
+        // The same object built with different field name dictionaries
+        for field_names in [["x", "y"], ["y", "x"]] {
+            let mut variant_builder =
+                VariantBuilder::new().with_field_names(field_names.into_iter());
+            let mut obj = variant_builder.new_object();
+            obj.insert("x", 1);
+            obj.insert("y", "a");
+            obj.finish().unwrap();
+            let (metadata, value) = variant_builder.finish();
+            builder.append_variant(Variant::new(&metadata, &value));
+        }
+        let array = builder.build();
+
+        let hashes = batch_variant_hash(&array, &RandomState::new());
+        assert_eq!(hashes.len(), 6);
+        assert_eq!(hashes.null_count(), 1);
+        assert!(hashes.is_null(2));
+        assert_eq!(hashes.value(0), hashes.value(1));
+        assert_ne!(hashes.value(0), hashes.value(3));
+        assert_eq!(hashes.value(4), hashes.value(5));
+        assert_ne!(hashes.value(0), hashes.value(4));
+    }
+}
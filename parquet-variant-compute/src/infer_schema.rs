@@ -0,0 +1,431 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for inferring the Arrow schema of Variants, see [`infer_arrow_schema`]
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow_schema::{ArrowError, DataType, Field, Fields, Schema, TimeUnit};
+use parquet_variant::Variant;
+
+use crate::VariantType;
+
+/// Infers the Arrow [`Schema`] of a sample of top-level Variant objects
+///
+/// Each field of the schema is a field present in at least one of the objects, with the
+/// tightest Arrow [`DataType`] of its values:
+///
+/// * Objects are inferred as a struct of the union of their fields, each inferred
+///   recursively, and lists as a list of their inferred elements
+/// * Integers are widened to the widest integer type of the values, and decimals to a
+///   decimal with enough integer digits and scale for all of the values, including
+///   integers. Integers, decimals and floating point values combined are inferred as
+///   [`DataType::Float64`]
+/// * Dates combined with timestamps are inferred as timestamps, and timestamps of
+///   different precisions as nanosecond timestamps
+/// * Other primitive values are inferred as the corresponding Arrow type, and UUIDs as
+///   `FixedSizeBinary(16)`
+/// * [`Variant::Null`] does not constrain the type, and paths whose values are all null
+///   are inferred as [`DataType::Null`]
+///
+/// Values that can not be combined, such as strings and integers, or timestamps with and
+/// without a time zone, are inferred as an unshredded Variant, i.e. a field annotated with
+/// [`VariantType`]. All fields are nullable, and ordered by name.
+///
+/// # Example
+/// ```
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use parquet_variant::VariantBuilder;
+/// # use parquet_variant_compute::{infer_arrow_schema, VariantType};
+/// let rows = [
+///     r#"{"id": 1, "score": 1.5, "tags": ["a"], "misc": 1}"#,
+///     r#"{"id": 1000, "score": 2, "tags": [], "misc": "x"}"#,
+/// ];
+/// let rows: Vec<_> = rows
+///     .iter()
+///     .map(|json| {
+///         let mut builder = VariantBuilder::new();
+///         parquet_variant_json::json_to_variant(json, &mut builder).unwrap();
+///         builder.finish()
+///     })
+///     .collect();
+/// let variants: Vec<_> = rows
+///     .iter()
+///     .map(|(metadata, value)| parquet_variant::Variant::new(metadata, value))
+///     .collect();
+///
+/// let schema = infer_arrow_schema(&variants).unwrap();
+/// assert_eq!(schema.field_with_name("id").unwrap().data_type(), &DataType::Int16);
+/// assert_eq!(schema.field_with_name("score").unwrap().data_type(), &DataType::Float64);
+/// let tags = schema.field_with_name("tags").unwrap().data_type();
+/// let element = Field::new("element", DataType::Utf8, true);
+/// assert_eq!(tags, &DataType::List(element.into()));
+/// let misc = schema.field_with_name("misc").unwrap();
+/// assert!(misc.try_extension_type::<VariantType>().is_ok());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a value is neither an object nor [`Variant::Null`]
+pub fn infer_arrow_schema(values: &[Variant<'_, '_>]) -> Result<Schema, ArrowError> {
+    let mut fields = BTreeMap::new();
+    for value in values {
+        match value {
+            Variant::Object(object) => merge_fields(&mut fields, object.iter()),
+            Variant::Null => {}
+            other => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "infer_arrow_schema requires Variant objects, got {other:?}"
+                )))
+            }
+        }
+    }
+    Ok(Schema::new(struct_fields(fields)))
+}
+
+/// The inferred type of the values of a path
+#[derive(Debug, Clone, PartialEq)]
+enum InferredType {
+    Null,
+    Boolean,
+    /// An integer of the given width in bytes
+    Integer(u8),
+    Float,
+    Double,
+    /// A decimal with the given number of integer digits and scale
+    Decimal(u8, u8),
+    String,
+    Binary,
+    Uuid,
+    Date,
+    Time,
+    /// A timestamp, with nanosecond precision if true, and a time zone if true
+    Timestamp(bool, bool),
+    Object(BTreeMap<String, InferredType>),
+    List(Box<InferredType>),
+    /// Values that can not be combined, kept as Variants
+    Variant,
+}
+
+/// The maximum precision of a decimal
+const MAX_DECIMAL_PRECISION: u8 = 38;
+
+impl InferredType {
+    fn of(value: Variant<'_, '_>) -> Self {
+        match value {
+            Variant::Null => Self::Null,
+            Variant::BooleanTrue | Variant::BooleanFalse => Self::Boolean,
+            Variant::Int8(_) => Self::Integer(1),
+            Variant::Int16(_) => Self::Integer(2),
+            Variant::Int32(_) => Self::Integer(4),
+            Variant::Int64(_) => Self::Integer(8),
+            Variant::Float(_) => Self::Float,
+            Variant::Double(_) => Self::Double,
+            Variant::Decimal4(d) => Self::Decimal(9 - d.scale(), d.scale()),
+            Variant::Decimal8(d) => Self::Decimal(18 - d.scale(), d.scale()),
+            Variant::Decimal16(d) => Self::Decimal(38 - d.scale(), d.scale()),
+            Variant::String(_) | Variant::ShortString(_) => Self::String,
+            Variant::Binary(_) => Self::Binary,
+            Variant::Uuid(_) => Self::Uuid,
+            Variant::Date(_) => Self::Date,
+            Variant::Time(_) => Self::Time,
+            Variant::TimestampMicros(_) => Self::Timestamp(false, true),
+            Variant::TimestampNtzMicros(_) => Self::Timestamp(false, false),
+            Variant::TimestampNanos(_) => Self::Timestamp(true, true),
+            Variant::TimestampNtzNanos(_) => Self::Timestamp(true, false),
+            Variant::Object(object) => {
+                let mut fields = BTreeMap::new();
+                merge_fields(&mut fields, object.iter());
+                Self::Object(fields)
+            }
+            Variant::List(list) => {
+                let element = list
+                    .iter()
+                    .fold(Self::Null, |element, value| element.merge(Self::of(value)));
+                Self::List(Box::new(element))
+            }
+        }
+    }
+
+    /// Returns the number of integer digits of an integer of `width` bytes
+    fn integer_digits(width: u8) -> u8 {
+        match width {
+            1 => 3,
+            2 => 5,
+            4 => 10,
+            _ => 19,
+        }
+    }
+
+    /// Returns the tightest type of both the values of `self` and `other`
+    fn merge(self, other: Self) -> Self {
+        use InferredType::*;
+        match (self, other) {
+            (Null, other) | (other, Null) => other,
+            (Integer(a), Integer(b)) => Integer(a.max(b)),
+            (Float, Float) => Float,
+            (Decimal(digits, scale), Integer(width)) | (Integer(width), Decimal(digits, scale)) => {
+                Decimal(digits.max(Self::integer_digits(width)), scale).checked_decimal()
+            }
+            (Decimal(a_digits, a_scale), Decimal(b_digits, b_scale)) => {
+                Decimal(a_digits.max(b_digits), a_scale.max(b_scale)).checked_decimal()
+            }
+            (
+                Float | Double | Integer(_) | Decimal(_, _),
+                Float | Double | Integer(_) | Decimal(_, _),
+            ) => Double,
+            (Date, Timestamp(nanos, tz)) | (Timestamp(nanos, tz), Date) => Timestamp(nanos, tz),
+            (Timestamp(a_nanos, a_tz), Timestamp(b_nanos, b_tz)) if a_tz == b_tz => {
+                Timestamp(a_nanos || b_nanos, a_tz)
+            }
+            (Object(mut a), Object(b)) => {
+                for (name, field) in b {
+                    merge_field(&mut a, name, field);
+                }
+                Object(a)
+            }
+            (List(a), List(b)) => List(Box::new(a.merge(*b))),
+            (a, b) if a == b => a,
+            _ => Variant,
+        }
+    }
+
+    /// Returns `self`, or [`Self::Double`] if `self` is a decimal too wide to be represented
+    fn checked_decimal(self) -> Self {
+        match self {
+            Self::Decimal(digits, scale) if digits + scale > MAX_DECIMAL_PRECISION => Self::Double,
+            other => other,
+        }
+    }
+
+    fn data_type(self) -> DataType {
+        let timestamp = |nanos, tz: bool| {
+            let unit = match nanos {
+                true => TimeUnit::Nanosecond,
+                false => TimeUnit::Microsecond,
+            };
+            DataType::Timestamp(unit, tz.then(|| Arc::from("UTC")))
+        };
+        match self {
+            Self::Null => DataType::Null,
+            Self::Boolean => DataType::Boolean,
+            Self::Integer(1) => DataType::Int8,
+            Self::Integer(2) => DataType::Int16,
+            Self::Integer(4) => DataType::Int32,
+            Self::Integer(_) => DataType::Int64,
+            Self::Float => DataType::Float32,
+            Self::Double => DataType::Float64,
+            Self::Decimal(digits, scale) => {
+                let precision = digits + scale;
+                match precision {
+                    0..=9 => DataType::Decimal32(precision, scale as i8),
+                    10..=18 => DataType::Decimal64(precision, scale as i8),
+                    _ => DataType::Decimal128(precision, scale as i8),
+                }
+            }
+            Self::String => DataType::Utf8,
+            Self::Binary => DataType::Binary,
+            Self::Uuid => DataType::FixedSizeBinary(16),
+            Self::Date => DataType::Date32,
+            Self::Time => DataType::Time64(TimeUnit::Microsecond),
+            Self::Timestamp(nanos, tz) => timestamp(nanos, tz),
+            Self::Object(fields) => DataType::Struct(struct_fields(fields)),
+            Self::List(element) => DataType::List(Arc::new(element.field("element"))),
+            Self::Variant => unreachable!("Variant fields are created by InferredType::field"),
+        }
+    }
+
+    fn field(self, name: &str) -> Field {
+        match self {
+            Self::Variant => {
+                let fields = Fields::from(vec![
+                    Field::new("metadata", DataType::BinaryView, false),
+                    Field::new("value", DataType::BinaryView, false),
+                ]);
+                Field::new(name, DataType::Struct(fields), true).with_extension_type(VariantType)
+            }
+            other => Field::new(name, other.data_type(), true),
+        }
+    }
+}
+
+fn merge_field(fields: &mut BTreeMap<String, InferredType>, name: String, field: InferredType) {
+    match fields.remove(&name) {
+        Some(existing) => fields.insert(name, existing.merge(field)),
+        None => fields.insert(name, field),
+    };
+}
+
+fn merge_fields<'m, 'v>(
+    fields: &mut BTreeMap<String, InferredType>,
+    values: impl Iterator<Item = (&'m str, Variant<'m, 'v>)>,
+) {
+    for (name, value) in values {
+        merge_field(fields, name.to_string(), InferredType::of(value));
+    }
+}
+
+fn struct_fields(fields: BTreeMap<String, InferredType>) -> Fields {
+    fields
+        .into_iter()
+        .map(|(name, field)| field.field(&name))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use parquet_variant::{VariantBuilder, VariantDecimal16, VariantDecimal4, VariantDecimal8};
+    use parquet_variant_json::json_to_variant;
+
+    fn infer(values: &[&str]) -> Result<Schema, ArrowError> {
+        let buffers: Vec<_> = values
+            .iter()
+            .map(|json| {
+                let mut builder = VariantBuilder::new();
+                json_to_variant(json, &mut builder).unwrap();
+                builder.finish()
+            })
+            .collect();
+        let variants: Vec<_> = buffers
+            .iter()
+            .map(|(metadata, value)| Variant::new(metadata, value))
+            .collect();
+        infer_arrow_schema(&variants)
+    }
+
+    fn infer_type(values: &[&str]) -> DataType {
+        let values: Vec<_> = values.iter().map(|v| format!(r#"{{"v": {v}}}"#)).collect();
+        let values: Vec<_> = values.iter().map(|v| v.as_str()).collect();
+        let schema = infer(&values).unwrap();
+        schema.field(0).data_type().clone()
+    }
+
+    /// Returns the type inferred for `values` in the field "v" of objects
+    fn infer_values(values: &[Variant]) -> DataType {
+        let buffers: Vec<_> = values
+            .iter()
+            .map(|value| {
+                let mut builder = VariantBuilder::new();
+                let mut object = builder.new_object();
+                object.insert("v", value.clone());
+                object.finish().unwrap();
+                builder.finish()
+            })
+            .collect();
+        let variants: Vec<_> = buffers
+            .iter()
+            .map(|(metadata, value)| Variant::new(metadata, value))
+            .collect();
+        let schema = infer_arrow_schema(&variants).unwrap();
+        schema.field(0).data_type().clone()
+    }
+
+    #[test]
+    fn test_infer_primitive() {
+        assert_eq!(infer_type(&["1", "null", "100000"]), DataType::Int32);
+        assert_eq!(infer_type(&["1.25", "1"]), DataType::Float64);
+        assert_eq!(infer_type(&["true", "false"]), DataType::Boolean);
+        assert_eq!(infer_type(&[r#""a""#, r#""b""#]), DataType::Utf8);
+        assert_eq!(infer_type(&["null"]), DataType::Null);
+
+        let decimal4 = Variant::from(VariantDecimal4::try_new(25, 1).unwrap());
+        let decimal8 = Variant::from(VariantDecimal8::try_new(25, 10).unwrap());
+        assert_eq!(
+            infer_values(&[decimal4.clone(), Variant::Int8(1)]),
+            DataType::Decimal32(9, 1)
+        );
+        assert_eq!(
+            infer_values(&[decimal4.clone(), Variant::Int32(1)]),
+            DataType::Decimal64(11, 1)
+        );
+        assert_eq!(
+            infer_values(&[decimal4.clone(), decimal8]),
+            DataType::Decimal64(18, 10)
+        );
+        assert_eq!(
+            infer_values(&[decimal4, Variant::Double(1.0)]),
+            DataType::Float64
+        );
+        let decimal16 = Variant::from(VariantDecimal16::try_new(1, 30).unwrap());
+        assert_eq!(
+            infer_values(&[decimal16, Variant::Int64(1)]),
+            DataType::Float64
+        );
+
+        let utc = chrono::DateTime::from_timestamp(0, 0).unwrap();
+        let date = Variant::from(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        let nanos = DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()));
+        assert_eq!(
+            infer_values(&[
+                date,
+                Variant::TimestampMicros(utc),
+                Variant::TimestampNanos(utc)
+            ]),
+            nanos
+        );
+        let ntz = Variant::TimestampNtzMicros(utc.naive_utc());
+        let variant = infer_values(&[ntz, Variant::TimestampMicros(utc)]);
+        assert!(matches!(variant, DataType::Struct(_)));
+    }
+
+    #[test]
+    fn test_infer_variant() {
+        let values = [
+            Variant::from(1),
+            Variant::from(chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()),
+        ];
+        let mut inferred = InferredType::Null;
+        for value in values {
+            inferred = inferred.merge(InferredType::of(value));
+        }
+        assert_eq!(inferred, InferredType::Variant);
+
+        let schema = infer(&[r#"{"v": 1}"#, r#"{"v": "x"}"#]).unwrap();
+        assert!(schema.field(0).try_extension_type::<VariantType>().is_ok());
+    }
+
+    #[test]
+    fn test_infer_nested() {
+        let schema = infer(&[
+            r#"{"b": {"x": 1, "l": [1, null]}, "a": [{"y": "s"}]}"#,
+            "null",
+            r#"{"b": {"x": 300, "z": true}, "a": [{"y": null, "w": 1}, {}]}"#,
+        ])
+        .unwrap();
+
+        let list = |element| DataType::List(Arc::new(Field::new("element", element, true)));
+        let a = list(DataType::Struct(Fields::from(vec![
+            Field::new("w", DataType::Int8, true),
+            Field::new("y", DataType::Utf8, true),
+        ])));
+        let b = DataType::Struct(Fields::from(vec![
+            Field::new("l", list(DataType::Int8), true),
+            Field::new("x", DataType::Int16, true),
+            Field::new("z", DataType::Boolean, true),
+        ]));
+        let expected = Schema::new(vec![Field::new("a", a, true), Field::new("b", b, true)]);
+        assert_eq!(schema, expected);
+
+        let err = infer(&["1"]).unwrap_err();
+        assert!(
+            err.to_string().contains("requires Variant objects"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,337 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for inferring the shredding schema of Variants, see [`infer_shredding_schema`]
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use arrow_schema::{ArrowError, DataType, Field, Fields, TimeUnit};
+
+use crate::{VariantArray, VariantKind, VariantPathStats, VariantStats, VariantStatsBuilder};
+
+/// Options for [`infer_shredding_schema`]
+#[derive(Debug, Clone)]
+pub struct ShreddingSchemaOptions {
+    min_frequency: f64,
+    min_type_stability: f64,
+    max_depth: usize,
+}
+
+impl Default for ShreddingSchemaOptions {
+    fn default() -> Self {
+        Self {
+            min_frequency: 0.5,
+            min_type_stability: 0.9,
+            max_depth: 8,
+        }
+    }
+}
+
+impl ShreddingSchemaOptions {
+    /// Create new options with the default thresholds
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only shred the fields present in at least this fraction of the objects containing
+    /// them, defaults to `0.5`
+    pub fn with_min_frequency(mut self, min_frequency: f64) -> Self {
+        self.min_frequency = min_frequency;
+        self
+    }
+
+    /// Only shred the paths where at least this fraction of the values other than
+    /// [`Variant::Null`] have the same type, defaults to `0.9`
+    ///
+    /// [`Variant::Null`]: parquet_variant::Variant::Null
+    pub fn with_min_type_stability(mut self, min_type_stability: f64) -> Self {
+        self.min_type_stability = min_type_stability;
+        self
+    }
+
+    /// Only shred the paths nested in at most this many objects and lists, defaults to `8`
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Returns the minimum frequency of shredded fields
+    pub fn min_frequency(&self) -> f64 {
+        self.min_frequency
+    }
+
+    /// Returns the minimum type stability of shredded paths
+    pub fn min_type_stability(&self) -> f64 {
+        self.min_type_stability
+    }
+
+    /// Returns the maximum depth of shredded paths
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+/// Infers a shredding schema for the rows of a [`VariantArray`], see
+/// [`infer_shredding_schema_from_stats`]
+///
+/// # Example
+/// ```
+/// # use arrow_schema::{DataType, Field};
+/// # use parquet_variant_compute::{
+/// #     infer_shredding_schema, shred_variant, ShreddingSchemaOptions, VariantArrayBuilder,
+/// # };
+/// let mut builder = VariantArrayBuilder::new(4);
+/// builder.append_json_str(r#"{"id": 1, "name": "a", "tags": ["x"]}"#).unwrap();
+/// builder.append_json_str(r#"{"id": 200, "name": 2}"#).unwrap();
+/// builder.append_json_str(r#"{"id": 3, "extra": true}"#).unwrap();
+/// builder.append_json_str(r#"{"id": null, "tags": ["y", "z"]}"#).unwrap();
+/// let array = builder.build();
+///
+/// let options = ShreddingSchemaOptions::new().with_min_type_stability(0.75);
+/// let as_type = infer_shredding_schema(&array, &options).unwrap().unwrap();
+/// // `name` is not type-stable, and `extra` is not frequent enough
+/// let tags = Field::new_list("tags", Field::new("element", DataType::Utf8, true), true);
+/// let expected = vec![Field::new("id", DataType::Int16, true), tags];
+/// assert_eq!(as_type, DataType::Struct(expected.into()));
+///
+/// shred_variant(&array, &as_type).unwrap();
+/// ```
+pub fn infer_shredding_schema(
+    array: &VariantArray,
+    options: &ShreddingSchemaOptions,
+) -> Result<Option<DataType>, ArrowError> {
+    let mut stats = VariantStatsBuilder::new();
+    stats.append_array(array)?;
+    Ok(infer_shredding_schema_from_stats(&stats.finish(), options))
+}
+
+/// Infers a shredding schema from the [`VariantStats`] of Variants, for use with
+/// [`shred_variant`]
+///
+/// Starting from the top-level values, a path is shredded if at least
+/// [`ShreddingSchemaOptions::with_min_type_stability`] of its values other than
+/// [`Variant::Null`] have the same type:
+///
+/// * Objects are shredded as a struct of their fields present in at least
+///   [`ShreddingSchemaOptions::with_min_frequency`] of the objects, which are shredded
+///   recursively. Objects without such fields are not shredded.
+/// * Lists are shredded as a list of their shredded elements.
+/// * Integers are shredded as the widest integer type of the values, booleans, floats,
+///   doubles, strings, binaries, dates, times and timestamps as the corresponding Arrow
+///   type. Decimals and UUIDs are not shredded.
+///
+/// Returns `None` if no path is shredded.
+///
+/// [`shred_variant`]: crate::shred_variant
+/// [`Variant::Null`]: parquet_variant::Variant::Null
+pub fn infer_shredding_schema_from_stats(
+    stats: &VariantStats,
+    options: &ShreddingSchemaOptions,
+) -> Option<DataType> {
+    infer_type(stats.root(), options, 0)
+}
+
+/// The types of [`VariantKind`] that are shredded as the same Arrow type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ShreddedKind {
+    Boolean,
+    Integer,
+    Float,
+    Double,
+    String,
+    Binary,
+    Date,
+    Time,
+    TimestampMicros,
+    TimestampNtzMicros,
+    TimestampNanos,
+    TimestampNtzNanos,
+    Object,
+    List,
+    Unsupported,
+}
+
+impl ShreddedKind {
+    fn of(kind: VariantKind) -> Option<Self> {
+        Some(match kind {
+            VariantKind::Null => return None,
+            VariantKind::Boolean => Self::Boolean,
+            VariantKind::Int8 | VariantKind::Int16 | VariantKind::Int32 | VariantKind::Int64 => {
+                Self::Integer
+            }
+            VariantKind::Float => Self::Float,
+            VariantKind::Double => Self::Double,
+            VariantKind::String => Self::String,
+            VariantKind::Binary => Self::Binary,
+            VariantKind::Date => Self::Date,
+            VariantKind::Time => Self::Time,
+            VariantKind::TimestampMicros => Self::TimestampMicros,
+            VariantKind::TimestampNtzMicros => Self::TimestampNtzMicros,
+            VariantKind::TimestampNanos => Self::TimestampNanos,
+            VariantKind::TimestampNtzNanos => Self::TimestampNtzNanos,
+            VariantKind::Object => Self::Object,
+            VariantKind::List => Self::List,
+            VariantKind::Decimal4
+            | VariantKind::Decimal8
+            | VariantKind::Decimal16
+            | VariantKind::Uuid => Self::Unsupported,
+        })
+    }
+}
+
+/// Returns the shredding schema of the values of `stats` nested at `depth`
+fn infer_type(
+    stats: &VariantPathStats,
+    options: &ShreddingSchemaOptions,
+    depth: usize,
+) -> Option<DataType> {
+    let mut counts = BTreeMap::new();
+    for (kind, count) in stats.type_counts() {
+        if let Some(kind) = ShreddedKind::of(*kind) {
+            *counts.entry(kind).or_insert(0) += count;
+        }
+    }
+    let non_null: usize = counts.values().sum();
+    // The first of the most frequent kinds
+    let (kind, count) = counts.into_iter().rev().max_by_key(|(_, count)| *count)?;
+    if (count as f64) < options.min_type_stability * non_null as f64 {
+        return None;
+    }
+
+    let timestamp = |unit, tz: Option<&str>| Some(DataType::Timestamp(unit, tz.map(Arc::from)));
+    match kind {
+        ShreddedKind::Boolean => Some(DataType::Boolean),
+        ShreddedKind::Integer => {
+            let widest = [
+                (VariantKind::Int64, DataType::Int64),
+                (VariantKind::Int32, DataType::Int32),
+                (VariantKind::Int16, DataType::Int16),
+                (VariantKind::Int8, DataType::Int8),
+            ];
+            widest
+                .into_iter()
+                .find(|(kind, _)| stats.type_count(*kind) > 0)
+                .map(|(_, data_type)| data_type)
+        }
+        ShreddedKind::Float => Some(DataType::Float32),
+        ShreddedKind::Double => Some(DataType::Float64),
+        ShreddedKind::String => Some(DataType::Utf8),
+        ShreddedKind::Binary => Some(DataType::Binary),
+        ShreddedKind::Date => Some(DataType::Date32),
+        ShreddedKind::Time => Some(DataType::Time64(TimeUnit::Microsecond)),
+        ShreddedKind::TimestampMicros => timestamp(TimeUnit::Microsecond, Some("UTC")),
+        ShreddedKind::TimestampNtzMicros => timestamp(TimeUnit::Microsecond, None),
+        ShreddedKind::TimestampNanos => timestamp(TimeUnit::Nanosecond, Some("UTC")),
+        ShreddedKind::TimestampNtzNanos => timestamp(TimeUnit::Nanosecond, None),
+        ShreddedKind::Object if depth < options.max_depth => {
+            let objects = stats.type_count(VariantKind::Object) as f64;
+            let fields: Fields = stats
+                .fields()
+                .iter()
+                .filter(|(_, field)| field.count() as f64 >= options.min_frequency * objects)
+                .filter_map(|(name, field)| {
+                    let data_type = infer_type(field, options, depth + 1)?;
+                    Some(Field::new(name, data_type, true))
+                })
+                .collect();
+            (!fields.is_empty()).then_some(DataType::Struct(fields))
+        }
+        ShreddedKind::List if depth < options.max_depth => {
+            let element = infer_type(stats.elements()?, options, depth + 1)?;
+            let element = Field::new("element", element, true);
+            Some(DataType::List(Arc::new(element)))
+        }
+        ShreddedKind::Object | ShreddedKind::List | ShreddedKind::Unsupported => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shred_variant;
+    use crate::test_util::variant_array;
+
+    fn infer(values: &[&str], options: &ShreddingSchemaOptions) -> Option<DataType> {
+        let values: Vec<_> = values.iter().copied().map(Some).collect();
+        infer_shredding_schema(&variant_array(&values), options).unwrap()
+    }
+
+    #[test]
+    fn test_infer_primitive() {
+        let options = ShreddingSchemaOptions::new();
+        assert_eq!(
+            infer(&["1", "null", "100000"], &options),
+            Some(DataType::Int32)
+        );
+        assert_eq!(infer(&["1.5", "2.5"], &options), Some(DataType::Float64));
+        assert_eq!(infer(&[r#""a""#], &options), Some(DataType::Utf8));
+        assert_eq!(infer(&["true", "false"], &options), Some(DataType::Boolean));
+        assert_eq!(infer(&["null"], &options), None);
+        assert_eq!(infer(&["1", r#""a""#], &options), None);
+
+        // The most frequent type is chosen if it is stable enough
+        let options = options.with_min_type_stability(0.6);
+        let values = ["1", "2", r#""a""#];
+        assert_eq!(infer(&values, &options), Some(DataType::Int8));
+    }
+
+    #[test]
+    fn test_infer_nested() {
+        let values = [
+            r#"{"a": {"b": 1, "c": "x"}, "d": [{"e": true}]}"#,
+            r#"{"a": {"b": 2}, "d": []}"#,
+            r#"{"a": {"b": 3}, "f": 1}"#,
+            r#"{"a": 4}"#,
+        ];
+        let options = ShreddingSchemaOptions::new().with_min_type_stability(0.75);
+        let a = DataType::Struct(vec![Field::new("b", DataType::Int8, true)].into());
+        let e = DataType::Struct(vec![Field::new("e", DataType::Boolean, true)].into());
+        let expected = DataType::Struct(
+            vec![
+                Field::new("a", a.clone(), true),
+                Field::new_list("d", Field::new("element", e, true), true),
+            ]
+            .into(),
+        );
+        let as_type = infer(&values, &options).unwrap();
+        assert_eq!(as_type, expected);
+        shred_variant(&variant_array(&values.map(Some)), &as_type).unwrap();
+
+        // `c` is frequent enough among the objects at `a`
+        let options = options.with_min_frequency(0.25);
+        let Some(DataType::Struct(fields)) = infer(&values, &options) else {
+            panic!()
+        };
+        let names: Vec<_> = fields.iter().map(|f| f.name()).collect();
+        assert_eq!(names, ["a", "d", "f"]);
+        let DataType::Struct(a) = fields[0].data_type() else {
+            panic!()
+        };
+        assert_eq!(a[1], Arc::new(Field::new("c", DataType::Utf8, true)));
+
+        // Only `a.b` is nested in at most two objects and lists
+        let options = ShreddingSchemaOptions::new().with_min_type_stability(0.75);
+        let a = DataType::Struct(vec![Field::new("b", DataType::Int8, true)].into());
+        let expected = DataType::Struct(vec![Field::new("a", a, true)].into());
+        assert_eq!(
+            infer(&values, &options.clone().with_max_depth(2)),
+            Some(expected)
+        );
+        assert_eq!(infer(&values, &options.with_max_depth(1)), None);
+    }
+}
@@ -15,14 +15,46 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod cast;
+mod from_binary;
 mod from_json;
+mod from_time;
+mod from_timestamp;
+mod hash;
+mod infer_schema;
+mod infer_shredding;
+mod shred_variant;
+#[cfg(test)]
+mod test_util;
 mod to_json;
+mod to_json_dictionary;
+mod to_map;
+mod unshred_variant;
 mod variant_array;
 mod variant_array_builder;
 pub mod variant_get;
+mod variant_null;
+mod variant_stats;
 
-pub use variant_array::VariantArray;
+pub use variant_array::{VariantArray, VariantType};
 pub use variant_array_builder::VariantArrayBuilder;
+pub use variant_null::{variant_coalesce, variant_is_null, VariantNullMode};
+pub use variant_stats::{VariantKind, VariantPathStats, VariantStats, VariantStatsBuilder};
 
+pub use cast::{
+    cast_from_variant, cast_from_variant_with_options, cast_to_variant, ArrowVariantBuilderExt,
+};
+pub use from_binary::batch_binary_to_variant;
 pub use from_json::batch_json_string_to_variant;
-pub use to_json::batch_variant_to_json_string;
+pub use from_time::batch_time_micros_to_variant;
+pub use from_timestamp::batch_timestamp_nanos_to_variant;
+pub use hash::batch_variant_hash;
+pub use infer_schema::infer_arrow_schema;
+pub use infer_shredding::{
+    infer_shredding_schema, infer_shredding_schema_from_stats, ShreddingSchemaOptions,
+};
+pub use shred_variant::{shred_variant, shredded_variant_type};
+pub use to_json::{batch_variant_to_json_string, batch_variant_to_json_string_with_options};
+pub use to_json_dictionary::{batch_variant_to_json_dictionary, JsonDictionaryOptions};
+pub use to_map::{batch_variant_to_map, VariantToMapOptions};
+pub use unshred_variant::unshred_variant;
@@ -0,0 +1,587 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for shredding a [`VariantArray`] into typed columns, see [`shred_variant`]
+
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayBuilder, ArrayRef, BinaryBuilder, BinaryViewArray, BinaryViewBuilder,
+    BooleanBuilder, LargeBinaryBuilder, LargeStringBuilder, ListArray, NullBufferBuilder,
+    PrimitiveBuilder, StringBuilder, StringViewBuilder, StructArray,
+};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{
+    ArrowPrimitiveType, Date32Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, Time64MicrosecondType, TimeUnit, TimestampMicrosecondType, TimestampNanosecondType,
+};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef, Fields};
+use chrono::Timelike;
+use parquet_variant::{Variant, VariantBuilder};
+
+use crate::VariantArray;
+
+/// Shreds the variants of `array` according to the shredding schema `as_type`
+///
+/// Returns a [`VariantArray`] with the `metadata`, `value` and `typed_value` fields defined by
+/// the [Variant Shredding specification]:
+///
+/// * Values that match `as_type` are stored in `typed_value`, and their `value` is null
+/// * Values that do not match `as_type` are stored unchanged in `value`, and their
+///   `typed_value` is null
+/// * If `as_type` is a `Struct`, the fields of objects are shredded recursively. The fields
+///   of an object that are not part of `as_type` are stored as an object in `value`
+/// * If `as_type` is a `List`, the elements of lists are shredded recursively
+///
+/// When written to Parquet, `typed_value` becomes regular columns with statistics and
+/// dictionary encoding, while the residual `value` only holds what could not be shredded.
+/// The data type of the result is [`shredded_variant_type`].
+///
+/// The supported types for shredding primitive values are `Boolean`, `Int8` to `Int64`,
+/// `Float32`, `Float64`, `Utf8`, `LargeUtf8`, `Utf8View`, `Binary`, `LargeBinary`,
+/// `BinaryView`, `Date32`, `Time64(Microsecond)` and `Timestamp` with microsecond or
+/// nanosecond precision, where a timezone matches variant timestamps and no timezone
+/// matches variant timestamps without timezone.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, AsArray};
+/// # use arrow::datatypes::{DataType, Field, Int64Type};
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::{shred_variant, VariantArrayBuilder};
+/// let mut builder = VariantArrayBuilder::new(3);
+/// builder.append_json_str(r#"{"id": 1, "name": "a"}"#).unwrap();
+/// builder.append_json_str(r#"{"id": "two"}"#).unwrap();
+/// builder.append_json_str("3").unwrap();
+/// let array = builder.build();
+///
+/// let as_type = DataType::Struct(vec![Field::new("id", DataType::Int64, true)].into());
+/// let shredded = shred_variant(&array, &as_type).unwrap();
+///
+/// // The `id` fields that are integers are shredded into a typed column
+/// let typed_value = shredded.inner().column_by_name("typed_value").unwrap();
+/// let id = typed_value.as_struct().column_by_name("id").unwrap().as_struct();
+/// let id = id.column_by_name("typed_value").unwrap().as_primitive::<Int64Type>();
+/// assert_eq!(id.iter().collect::<Vec<_>>(), vec![Some(1), None, None]);
+///
+/// // While the remaining values are kept in `value`
+/// let value = shredded.value_field();
+/// assert!(value.is_valid(0)); // {"name": "a"}
+/// assert!(value.is_null(1)); // an object without unshredded fields
+/// assert!(value.is_valid(2)); // 3 is not an object
+/// ```
+///
+/// [Variant Shredding specification]: https://github.com/apache/parquet-format/blob/master/VariantShredding.md
+pub fn shred_variant(array: &VariantArray, as_type: &DataType) -> Result<VariantArray, ArrowError> {
+    if array.inner().column_by_name("typed_value").is_some() {
+        return Err(ArrowError::NotYetImplemented(
+            "Shredding an already shredded VariantArray is not supported".to_string(),
+        ));
+    }
+    let DataType::Struct(fields) = shredded_variant_type(as_type)? else {
+        unreachable!("shredded variant type is a struct")
+    };
+
+    let values: Vec<_> = (0..array.len())
+        .map(|i| array.is_valid(i).then(|| array.value(i)))
+        .collect();
+    let (value, typed_value) = shred_values(&values, as_type)?;
+
+    let inner = StructArray::try_new(
+        fields,
+        vec![array.metadata_field().clone(), Arc::new(value), typed_value],
+        array.nulls().cloned(),
+    )?;
+    VariantArray::try_new(Arc::new(inner))
+}
+
+/// Returns the data type of the [`VariantArray`] returned by [`shred_variant`] for the
+/// shredding schema `as_type`
+///
+/// This is a `Struct` with a non-nullable `metadata` field, a nullable `value` field and a
+/// nullable `typed_value` field. The `typed_value` of primitive types is `as_type` itself,
+/// while each field and element of a `Struct` or `List` is in turn a non-nullable `Struct`
+/// of a `value` and a `typed_value`.
+pub fn shredded_variant_type(as_type: &DataType) -> Result<DataType, ArrowError> {
+    let typed_value = typed_value_type(as_type)?;
+    Ok(DataType::Struct(Fields::from(vec![
+        Field::new("metadata", DataType::BinaryView, false),
+        Field::new("value", DataType::BinaryView, true),
+        Field::new("typed_value", typed_value, true),
+    ])))
+}
+
+/// Returns the data type of the `typed_value` for the shredding schema `as_type`
+fn typed_value_type(as_type: &DataType) -> Result<DataType, ArrowError> {
+    match as_type {
+        DataType::Struct(fields) => {
+            let fields = fields
+                .iter()
+                .map(|f| shredded_field(f.name(), f.data_type()))
+                .collect::<Result<Fields, _>>()?;
+            Ok(DataType::Struct(fields))
+        }
+        DataType::List(element) => Ok(DataType::List(shredded_field(
+            element.name(),
+            element.data_type(),
+        )?)),
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Utf8View
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::Date32
+        | DataType::Time64(TimeUnit::Microsecond)
+        | DataType::Timestamp(TimeUnit::Microsecond | TimeUnit::Nanosecond, _) => {
+            Ok(as_type.clone())
+        }
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Shredding variants as {as_type} is not supported"
+        ))),
+    }
+}
+
+/// Returns the non-nullable field of a shredded object field or list element
+fn shredded_field(name: &str, as_type: &DataType) -> Result<FieldRef, ArrowError> {
+    let fields = value_fields(typed_value_type(as_type)?);
+    Ok(Arc::new(Field::new(name, DataType::Struct(fields), false)))
+}
+
+/// Returns the `value` and `typed_value` fields of a shredded value
+fn value_fields(typed_value: DataType) -> Fields {
+    Fields::from(vec![
+        Field::new("value", DataType::BinaryView, true),
+        Field::new("typed_value", typed_value, true),
+    ])
+}
+
+/// Shreds `values`, where `None` is a missing value, into the `value` and `typed_value`
+/// arrays for the shredding schema `as_type`
+fn shred_values(
+    values: &[Option<Variant>],
+    as_type: &DataType,
+) -> Result<(BinaryViewArray, ArrayRef), ArrowError> {
+    let len = values.len();
+    match as_type {
+        DataType::Struct(fields) => shred_objects(values, fields),
+        DataType::List(element) => shred_lists(values, element),
+        DataType::Boolean => shred_primitives(
+            values,
+            BooleanBuilder::with_capacity(len),
+            |b, v| v.as_boolean().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::Int8 => shred_primitive::<Int8Type>(values, as_type, |v| v.as_int8()),
+        DataType::Int16 => shred_primitive::<Int16Type>(values, as_type, |v| v.as_int16()),
+        DataType::Int32 => shred_primitive::<Int32Type>(values, as_type, |v| v.as_int32()),
+        DataType::Int64 => shred_primitive::<Int64Type>(values, as_type, |v| v.as_int64()),
+        DataType::Float32 => shred_primitive::<Float32Type>(values, as_type, |v| match v {
+            Variant::Float(f) => Some(*f),
+            _ => None,
+        }),
+        DataType::Float64 => shred_primitive::<Float64Type>(values, as_type, |v| match v {
+            Variant::Double(f) => Some(*f),
+            _ => None,
+        }),
+        DataType::Date32 => shred_primitive::<Date32Type>(values, as_type, |v| match v {
+            Variant::Date(d) => Some(Date32Type::from_naive_date(*d)),
+            _ => None,
+        }),
+        DataType::Time64(TimeUnit::Microsecond) => {
+            shred_primitive::<Time64MicrosecondType>(values, as_type, |v| match v {
+                Variant::Time(t) => {
+                    let seconds = t.num_seconds_from_midnight() as i64;
+                    Some(seconds * 1_000_000 + (t.nanosecond() / 1_000) as i64)
+                }
+                _ => None,
+            })
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+            shred_primitive::<TimestampMicrosecondType>(values, as_type, |v| {
+                match (v, tz.is_some()) {
+                    (Variant::TimestampMicros(d), true) => Some(d.timestamp_micros()),
+                    (Variant::TimestampNtzMicros(d), false) => Some(d.and_utc().timestamp_micros()),
+                    _ => None,
+                }
+            })
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
+            shred_primitive::<TimestampNanosecondType>(values, as_type, |v| {
+                match (v, tz.is_some()) {
+                    (Variant::TimestampNanos(d), true) => d.timestamp_nanos_opt(),
+                    (Variant::TimestampNtzNanos(d), false) => d.and_utc().timestamp_nanos_opt(),
+                    _ => None,
+                }
+            })
+        }
+        DataType::Utf8 => shred_primitives(
+            values,
+            StringBuilder::with_capacity(len, 0),
+            |b, v| v.as_string().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::LargeUtf8 => shred_primitives(
+            values,
+            LargeStringBuilder::with_capacity(len, 0),
+            |b, v| v.as_string().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::Utf8View => shred_primitives(
+            values,
+            StringViewBuilder::with_capacity(len),
+            |b, v| v.as_string().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::Binary => shred_primitives(
+            values,
+            BinaryBuilder::with_capacity(len, 0),
+            |b, v| v.as_u8_slice().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::LargeBinary => shred_primitives(
+            values,
+            LargeBinaryBuilder::with_capacity(len, 0),
+            |b, v| v.as_u8_slice().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::BinaryView => shred_primitives(
+            values,
+            BinaryViewBuilder::with_capacity(len),
+            |b, v| v.as_u8_slice().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Shredding variants as {as_type} is not supported"
+        ))),
+    }
+}
+
+/// Shreds `values` into a primitive `typed_value` of `data_type`, converting each value with
+/// `convert`
+fn shred_primitive<T: ArrowPrimitiveType>(
+    values: &[Option<Variant>],
+    data_type: &DataType,
+    convert: impl Fn(&Variant) -> Option<T::Native>,
+) -> Result<(BinaryViewArray, ArrayRef), ArrowError> {
+    let builder =
+        PrimitiveBuilder::<T>::with_capacity(values.len()).with_data_type(data_type.clone());
+    shred_primitives(
+        values,
+        builder,
+        |b, v| convert(v).map(|v| b.append_value(v)).is_some(),
+        |b| b.append_null(),
+    )
+}
+
+/// Shreds `values` into the `typed_value` built by `builder`
+///
+/// `append` appends a value to the builder, returning false if the value does not match the
+/// type of the builder, in which case the value is stored in `value` instead.
+fn shred_primitives<B: ArrayBuilder>(
+    values: &[Option<Variant>],
+    mut builder: B,
+    append: impl Fn(&mut B, &Variant) -> bool,
+    append_null: impl Fn(&mut B),
+) -> Result<(BinaryViewArray, ArrayRef), ArrowError> {
+    let mut value = BinaryViewBuilder::with_capacity(values.len());
+    for v in values {
+        match v {
+            Some(v) if append(&mut builder, v) => value.append_null(),
+            Some(v) => {
+                value.append_value(encode_value(v));
+                append_null(&mut builder);
+            }
+            None => {
+                value.append_null();
+                append_null(&mut builder);
+            }
+        }
+    }
+    Ok((value.finish(), builder.finish()))
+}
+
+/// Shreds the `fields` of the objects in `values`
+fn shred_objects(
+    values: &[Option<Variant>],
+    fields: &Fields,
+) -> Result<(BinaryViewArray, ArrayRef), ArrowError> {
+    let mut value = BinaryViewBuilder::with_capacity(values.len());
+    let mut nulls = NullBufferBuilder::new(values.len());
+    for v in values {
+        match v {
+            Some(Variant::Object(obj)) => {
+                nulls.append_non_null();
+                // The fields that are not shredded remain in `value`, as a partial object
+                let mut residual = obj
+                    .iter()
+                    .filter(|(name, _)| fields.find(name).is_none())
+                    .peekable();
+                if residual.peek().is_none() {
+                    value.append_null();
+                    continue;
+                }
+                let mut builder = VariantBuilder::new().with_metadata(obj.metadata.clone());
+                let mut object = builder.new_object();
+                for (name, field) in residual {
+                    object.try_insert(name, field)?;
+                }
+                object.finish()?;
+                value.append_value(builder.finish().1);
+            }
+            Some(v) => {
+                nulls.append_null();
+                value.append_value(encode_value(v));
+            }
+            None => {
+                nulls.append_null();
+                value.append_null();
+            }
+        }
+    }
+
+    let mut shredded_fields = Vec::with_capacity(fields.len());
+    let mut columns = Vec::with_capacity(fields.len());
+    for field in fields {
+        let field_values: Vec<_> = values
+            .iter()
+            .map(|v| match v {
+                Some(Variant::Object(obj)) => obj.get(field.name()),
+                _ => None,
+            })
+            .collect();
+        columns.push(shred_field(&field_values, field.data_type())?);
+        shredded_fields.push(shredded_field(field.name(), field.data_type())?);
+    }
+    let typed_value = StructArray::try_new(shredded_fields.into(), columns, nulls.finish())?;
+    Ok((value.finish(), Arc::new(typed_value)))
+}
+
+/// Shreds the elements of the lists in `values` according to `element`
+fn shred_lists(
+    values: &[Option<Variant>],
+    element: &FieldRef,
+) -> Result<(BinaryViewArray, ArrayRef), ArrowError> {
+    let mut value = BinaryViewBuilder::with_capacity(values.len());
+    let mut nulls = NullBufferBuilder::new(values.len());
+    let mut lengths = Vec::with_capacity(values.len());
+    let mut elements = vec![];
+    for v in values {
+        match v {
+            Some(Variant::List(list)) => {
+                nulls.append_non_null();
+                value.append_null();
+                lengths.push(list.len());
+                elements.extend(list.iter().map(Some));
+            }
+            Some(v) => {
+                nulls.append_null();
+                value.append_value(encode_value(v));
+                lengths.push(0);
+            }
+            None => {
+                nulls.append_null();
+                value.append_null();
+                lengths.push(0);
+            }
+        }
+    }
+
+    let field = shredded_field(element.name(), element.data_type())?;
+    let elements = shred_field(&elements, element.data_type())?;
+    let offsets = OffsetBuffer::from_lengths(lengths);
+    let typed_value = ListArray::try_new(field, offsets, elements, nulls.finish())?;
+    Ok((value.finish(), Arc::new(typed_value)))
+}
+
+/// Shreds `values` into the non-nullable struct of a shredded object field or list element
+fn shred_field(values: &[Option<Variant>], as_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    let (value, typed_value) = shred_values(values, as_type)?;
+    let fields = value_fields(typed_value.data_type().clone());
+    let array = StructArray::try_new(fields, vec![Arc::new(value), typed_value], None)?;
+    Ok(Arc::new(array))
+}
+
+/// Encodes `value` to be stored in the `value` field of a shredded variant
+///
+/// Shredding does not add field names, so the value remains valid with the existing metadata
+/// of its row, which contains the field names of any objects within `value`.
+fn encode_value(value: &Variant) -> Vec<u8> {
+    let mut builder = VariantBuilder::new();
+    if let Some(metadata) = value.metadata() {
+        builder = builder.with_metadata(metadata.clone());
+    }
+    builder.append_value(value.clone());
+    builder.finish().1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::variant_array;
+    use arrow::array::{AsArray, Int64Array, StringArray};
+    use parquet_variant::VariantMetadata;
+
+    /// Returns the residual variant of row `i` of `value`, decoded with `metadata`
+    fn residual<'m, 'v>(
+        metadata: &'m [u8],
+        value: &'v ArrayRef,
+        i: usize,
+    ) -> Option<Variant<'m, 'v>> {
+        let value = value.as_binary_view();
+        value
+            .is_valid(i)
+            .then(|| Variant::try_new(metadata, value.value(i)).unwrap())
+    }
+
+    #[test]
+    fn test_shred_primitive() {
+        let array = variant_array(&[Some("1"), Some(r#""two""#), Some("null"), None, Some("3")]);
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+        assert_eq!(
+            shredded.data_type(),
+            &shredded_variant_type(&DataType::Int64).unwrap()
+        );
+        assert_eq!(shredded.len(), 5);
+        assert!(shredded.is_null(3));
+
+        let typed_value = shredded.inner().column_by_name("typed_value").unwrap();
+        let expected = Int64Array::from(vec![Some(1), None, None, None, Some(3)]);
+        assert_eq!(typed_value.as_primitive(), &expected);
+
+        let metadata = shredded.metadata_field().as_binary_view().value(1);
+        let value = shredded.value_field();
+        assert_eq!(residual(metadata, value, 0), None);
+        assert_eq!(residual(metadata, value, 1), Some(Variant::from("two")));
+        assert_eq!(residual(metadata, value, 2), Some(Variant::Null));
+        assert_eq!(residual(metadata, value, 3), None);
+        assert_eq!(residual(metadata, value, 4), None);
+    }
+
+    #[test]
+    fn test_shred_object() {
+        let array = variant_array(&[
+            Some(r#"{"a": 1, "b": "x", "c": true}"#),
+            Some(r#"{"a": "one", "b": "y"}"#),
+            Some(r#"{"c": [1]}"#),
+            Some(r#"[1, 2]"#),
+        ]);
+        let as_type = DataType::Struct(Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let shredded = shred_variant(&array, &as_type).unwrap();
+        assert_eq!(
+            shredded.data_type(),
+            &shredded_variant_type(&as_type).unwrap()
+        );
+
+        let typed_value = shredded.inner().column_by_name("typed_value").unwrap();
+        let typed_value = typed_value.as_struct();
+        assert_eq!(typed_value.nulls().unwrap().null_count(), 1);
+        assert!(typed_value.is_null(3));
+
+        let a = typed_value.column_by_name("a").unwrap().as_struct();
+        let a_typed = a.column_by_name("typed_value").unwrap();
+        let expected = Int64Array::from(vec![Some(1), None, None, None]);
+        assert_eq!(a_typed.as_primitive(), &expected);
+
+        let b = typed_value.column_by_name("b").unwrap().as_struct();
+        let b_typed = b.column_by_name("typed_value").unwrap();
+        let expected = StringArray::from(vec![Some("x"), Some("y"), None, None]);
+        assert_eq!(b_typed.as_string::<i32>(), &expected);
+
+        let metadata = shredded.metadata_field().as_binary_view();
+        let a_value = a.column_by_name("value").unwrap();
+        assert_eq!(residual(metadata.value(0), a_value, 0), None);
+        assert_eq!(
+            residual(metadata.value(1), a_value, 1),
+            Some(Variant::from("one"))
+        );
+        assert_eq!(residual(metadata.value(2), a_value, 2), None);
+
+        // The unshredded fields remain in the residual object
+        let value = shredded.value_field();
+        let row0 = residual(metadata.value(0), value, 0).unwrap();
+        let row0 = row0.as_object().unwrap();
+        assert_eq!(row0.len(), 1);
+        assert_eq!(row0.get("c"), Some(Variant::from(true)));
+        assert_eq!(residual(metadata.value(1), value, 1), None);
+        let row2 = residual(metadata.value(2), value, 2).unwrap();
+        assert!(row2.get_object_field("c").unwrap().as_list().is_some());
+        let row3 = residual(metadata.value(3), value, 3).unwrap();
+        assert_eq!(row3.as_list().unwrap().len(), 2);
+
+        // Shredding does not add field names to the metadata
+        let metadata = VariantMetadata::try_new(metadata.value(0)).unwrap();
+        assert_eq!(metadata.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_shred_list() {
+        let array = variant_array(&[Some("[1, \"a\", 3]"), Some("[]"), Some("1")]);
+        let element = Field::new("element", DataType::Int64, true);
+        let as_type = DataType::List(Arc::new(element));
+        let shredded = shred_variant(&array, &as_type).unwrap();
+        assert_eq!(
+            shredded.data_type(),
+            &shredded_variant_type(&as_type).unwrap()
+        );
+
+        let typed_value = shredded.inner().column_by_name("typed_value").unwrap();
+        let typed_value = typed_value.as_list::<i32>();
+        assert_eq!(typed_value.value_offsets(), &[0, 3, 3, 3]);
+        assert!(typed_value.is_null(2));
+
+        let elements = typed_value.values().as_struct();
+        let typed = elements.column_by_name("typed_value").unwrap();
+        let expected = Int64Array::from(vec![Some(1), None, Some(3)]);
+        assert_eq!(typed.as_primitive(), &expected);
+
+        let metadata = shredded.metadata_field().as_binary_view().value(0);
+        let value = elements.column_by_name("value").unwrap();
+        assert_eq!(residual(metadata, value, 1), Some(Variant::from("a")));
+
+        let value = shredded.value_field();
+        assert_eq!(residual(metadata, value, 0), None);
+        assert_eq!(residual(metadata, value, 2), Some(Variant::from(1i8)));
+    }
+
+    #[test]
+    fn test_shred_unsupported() {
+        let array = variant_array(&[Some("1")]);
+        let err = shred_variant(&array, &DataType::Float16).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Shredding variants as Float16 is not supported"
+        );
+
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+        let err = shred_variant(&shredded, &DataType::Int64).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: Shredding an already shredded VariantArray is not supported"
+        );
+    }
+}
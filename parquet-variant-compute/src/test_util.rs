@@ -0,0 +1,33 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers shared by the tests of this crate
+
+use crate::{VariantArray, VariantArrayBuilder};
+
+/// Returns a [`VariantArray`] with a row for each of the JSON `values`, and a null row for
+/// each `None`
+pub(crate) fn variant_array(values: &[Option<&str>]) -> VariantArray {
+    let mut builder = VariantArrayBuilder::new(values.len());
+    for value in values {
+        match value {
+            Some(json) => builder.append_json_str(json).unwrap(),
+            None => builder.append_null(),
+        }
+    }
+    builder.build()
+}
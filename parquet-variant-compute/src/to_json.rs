@@ -18,77 +18,117 @@
 //! Module for transforming a batch of Variants represented as
 //! STRUCT<metadata: BINARY, value: BINARY> into a batch of JSON strings.
 
-use arrow::array::{Array, ArrayRef, BinaryArray, BooleanBufferBuilder, StringArray, StructArray};
+use crate::VariantArray;
+use arrow::array::{
+    Array, ArrayAccessor, ArrayRef, AsArray, BooleanBufferBuilder, StringArray, StructArray,
+};
 use arrow::buffer::{Buffer, NullBuffer, OffsetBuffer, ScalarBuffer};
 use arrow::datatypes::DataType;
 use arrow_schema::ArrowError;
 use parquet_variant::Variant;
-use parquet_variant_json::variant_to_json;
+use parquet_variant_json::{variant_to_json_with_options, VariantToJsonOptions};
 
-/// Transform a batch of Variant represented as STRUCT<metadata: BINARY, value: BINARY> to a batch
-/// of JSON strings where nulls are preserved. The JSON strings in the input must be valid.
+/// Convert a batch of Variants into a batch of JSON strings, where nulls are preserved
+///
+/// The input must be a `StructArray` with a `metadata` and a `value` field, which are
+/// either both `Binary` or both `BinaryView`, such as a [`VariantArray`].
 pub fn batch_variant_to_json_string(input: &ArrayRef) -> Result<StringArray, ArrowError> {
+    batch_variant_to_json_string_with_options(input, &VariantToJsonOptions::default())
+}
+
+/// Convert a batch of Variants into a batch of JSON strings like
+/// [`batch_variant_to_json_string`], formatting timestamps and decimals according to `options`
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::ArrayRef;
+/// # use parquet_variant::VariantDecimal4;
+/// # use parquet_variant_compute::{batch_variant_to_json_string_with_options, VariantArrayBuilder};
+/// # use parquet_variant_json::VariantToJsonOptions;
+/// let mut builder = VariantArrayBuilder::new(2);
+/// builder.append_variant(VariantDecimal4::try_new(1234, 2).unwrap().into());
+/// builder.append_null();
+/// let array: ArrayRef = Arc::new(builder.build());
+///
+/// let options = VariantToJsonOptions::new().with_decimal_as_string(true);
+/// let json = batch_variant_to_json_string_with_options(&array, &options).unwrap();
+/// assert_eq!(json.iter().collect::<Vec<_>>(), [Some("\"12.34\""), None]);
+/// ```
+pub fn batch_variant_to_json_string_with_options(
+    input: &ArrayRef,
+    options: &VariantToJsonOptions,
+) -> Result<StringArray, ArrowError> {
     let struct_array = input
         .as_any()
         .downcast_ref::<StructArray>()
+        .or_else(|| {
+            input
+                .as_any()
+                .downcast_ref::<VariantArray>()
+                .map(|array| array.inner())
+        })
         .ok_or_else(|| ArrowError::CastError("Expected StructArray as input".into()))?;
 
     // Validate field types
-    let data_type = struct_array.data_type();
-    match data_type {
-        DataType::Struct(inner_fields) => {
-            if inner_fields.len() != 2
-                || inner_fields[0].data_type() != &DataType::Binary
-                || inner_fields[1].data_type() != &DataType::Binary
-            {
-                return Err(ArrowError::CastError(
-                    "Expected struct with two binary fields".into(),
-                ));
-            }
-        }
+    let (metadata, value) = match (
+        struct_array.column_by_name("metadata"),
+        struct_array.column_by_name("value"),
+    ) {
+        (Some(metadata), Some(value)) if struct_array.num_columns() == 2 => (metadata, value),
         _ => {
             return Err(ArrowError::CastError(
-                "Expected StructArray with known fields".into(),
+                "Expected struct with metadata and value fields".into(),
             ))
         }
+    };
+    match (metadata.data_type(), value.data_type()) {
+        (DataType::Binary, DataType::Binary) => variants_to_json(
+            struct_array,
+            metadata.as_binary::<i32>(),
+            value.as_binary::<i32>(),
+            options,
+        ),
+        (DataType::BinaryView, DataType::BinaryView) => variants_to_json(
+            struct_array,
+            metadata.as_binary_view(),
+            value.as_binary_view(),
+            options,
+        ),
+        _ => Err(ArrowError::CastError(
+            "Expected struct with two binary or two binary view fields".into(),
+        )),
     }
+}
 
-    let metadata_array = struct_array
-        .column(0)
-        .as_any()
-        .downcast_ref::<BinaryArray>()
-        .ok_or_else(|| ArrowError::CastError("Expected BinaryArray for 'metadata'".into()))?;
-
-    let value_array = struct_array
-        .column(1)
-        .as_any()
-        .downcast_ref::<BinaryArray>()
-        .ok_or_else(|| ArrowError::CastError("Expected BinaryArray for 'value'".into()))?;
-
+fn variants_to_json<'a>(
+    struct_array: &StructArray,
+    metadata_array: impl ArrayAccessor<Item = &'a [u8]>,
+    value_array: impl ArrayAccessor<Item = &'a [u8]>,
+    options: &VariantToJsonOptions,
+) -> Result<StringArray, ArrowError> {
     // Zero-copy builder
     // The size per JSON string is assumed to be 128 bytes. If this holds true, resizing could be
     // minimized for performance.
     let mut json_buffer: Vec<u8> = Vec::with_capacity(struct_array.len() * 128);
     let mut offsets: Vec<i32> = Vec::with_capacity(struct_array.len() + 1);
     let mut validity = BooleanBufferBuilder::new(struct_array.len());
-    let mut current_offset: i32 = 0;
-    offsets.push(current_offset);
+    offsets.push(0);
 
     for i in 0..struct_array.len() {
         if struct_array.is_null(i) {
             validity.append(false);
-            offsets.push(current_offset);
         } else {
             let metadata = metadata_array.value(i);
             let value = value_array.value(i);
             let variant = Variant::new(metadata, value);
-            let start_len = json_buffer.len();
-            variant_to_json(&mut json_buffer, &variant)?;
-            let written = (json_buffer.len() - start_len) as i32;
-            current_offset += written;
-            offsets.push(current_offset);
+            variant_to_json_with_options(&mut json_buffer, &variant, options)?;
             validity.append(true);
         }
+        let offset = i32::try_from(json_buffer.len()).map_err(|_| {
+            ArrowError::ComputeError("JSON strings exceed the maximum size of a StringArray".into())
+        })?;
+        offsets.push(offset);
     }
 
     let offsets_buffer = OffsetBuffer::new(ScalarBuffer::from(offsets));
@@ -104,12 +144,18 @@ pub fn batch_variant_to_json_string(input: &ArrayRef) -> Result<StringArray, Arr
 
 #[cfg(test)]
 mod test {
-    use crate::batch_variant_to_json_string;
+    use crate::{
+        batch_variant_to_json_string, batch_variant_to_json_string_with_options,
+        VariantArrayBuilder,
+    };
     use arrow::array::{Array, ArrayRef, BinaryBuilder, BooleanBufferBuilder, StructArray};
     use arrow::buffer::NullBuffer;
     use arrow::datatypes::DataType;
     use arrow::datatypes::Field;
     use arrow_schema::Fields;
+    use chrono::NaiveDate;
+    use parquet_variant::{Variant, VariantDecimal8};
+    use parquet_variant_json::VariantToJsonOptions;
     use std::sync::Arc;
 
     #[test]
@@ -178,4 +224,43 @@ mod test {
 
         assert_eq!(result_vec, expected);
     }
+
+    #[test]
+    fn test_variant_array_to_json_string_with_options() {
+        let ts = NaiveDate::from_ymd_opt(2025, 7, 1)
+            .unwrap()
+            .and_hms_micro_opt(12, 30, 0, 500)
+            .unwrap();
+        let mut builder = VariantArrayBuilder::new(3);
+        builder.append_variant(Variant::TimestampNtzMicros(ts));
+        builder.append_null();
+        builder.append_json_str(r#"{"a": [1.5, null]}"#).unwrap();
+        builder.append_variant(VariantDecimal8::try_new(-15, 1).unwrap().into());
+        let input: ArrayRef = Arc::new(builder.build());
+
+        let result = batch_variant_to_json_string(&input).unwrap();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            [
+                Some("\"2025-07-01T12:30:00.000500\""),
+                None,
+                Some(r#"{"a":[1.5,null]}"#),
+                Some("-1.5"),
+            ]
+        );
+
+        let options = VariantToJsonOptions::new()
+            .with_timestamp_ntz_format("%d.%m.%Y")
+            .with_decimal_as_string(true);
+        let result = batch_variant_to_json_string_with_options(&input, &options).unwrap();
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            [
+                Some("\"01.07.2025\""),
+                None,
+                Some(r#"{"a":[1.5,null]}"#),
+                Some("\"-1.5\""),
+            ]
+        );
+    }
 }
@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for rendering a batch of Variants as a dictionary of (possibly truncated) JSON
+//! strings, for display layers that can not consume Variants natively.
+
+use crate::VariantArray;
+use arrow::array::{Array, BooleanArray, DictionaryArray, StringDictionaryBuilder};
+use arrow::datatypes::Int32Type;
+use arrow_schema::ArrowError;
+use parquet_variant_json::variant_to_json;
+
+/// Options for [`batch_variant_to_json_dictionary`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonDictionaryOptions {
+    max_length: Option<usize>,
+    truncation_marker: String,
+}
+
+impl Default for JsonDictionaryOptions {
+    fn default() -> Self {
+        Self {
+            max_length: None,
+            truncation_marker: "...".to_string(),
+        }
+    }
+}
+
+impl JsonDictionaryOptions {
+    /// Create new options that do not truncate the JSON strings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Truncate JSON strings longer than `max_length` bytes to at most `max_length` bytes,
+    /// followed by the truncation marker
+    ///
+    /// Strings are truncated at a character boundary, so they remain valid UTF-8.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the marker appended to truncated JSON strings, defaults to `"..."`
+    pub fn with_truncation_marker(mut self, truncation_marker: impl Into<String>) -> Self {
+        self.truncation_marker = truncation_marker.into();
+        self
+    }
+
+    /// Returns the maximum length of the JSON strings in bytes before truncation, if any
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Returns the marker appended to truncated JSON strings
+    pub fn truncation_marker(&self) -> &str {
+        &self.truncation_marker
+    }
+}
+
+/// Render each row of a [`VariantArray`] as a JSON string, returning a `Dictionary(Int32, Utf8)`
+/// array of the strings, and a [`BooleanArray`] indicating which rows were truncated, where
+/// nulls are preserved in both.
+///
+/// Repeated strings are stored once in the dictionary, which is common for the low
+/// cardinality values typically found in display layers.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, ArrayAccessor, StringArray};
+/// # use parquet_variant_compute::{
+/// #     batch_variant_to_json_dictionary, JsonDictionaryOptions, VariantArrayBuilder,
+/// # };
+/// let mut builder = VariantArrayBuilder::new(3);
+/// builder.append_variant("a short string".into());
+/// builder.append_null();
+/// builder.append_variant("a much longer string".into());
+/// let array = builder.build();
+///
+/// let options = JsonDictionaryOptions::new().with_max_length(16);
+/// let (json, truncated) = batch_variant_to_json_dictionary(&array, &options).unwrap();
+/// let json = json.downcast_dict::<StringArray>().unwrap();
+/// assert_eq!(json.value(0), "\"a short string\"");
+/// assert!(json.is_null(1));
+/// assert_eq!(json.value(2), "\"a much longer s...");
+/// assert_eq!(truncated.iter().collect::<Vec<_>>(), vec![Some(false), None, Some(true)]);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the dictionary keys overflow, or a Variant can not be rendered as JSON
+///
+/// # Panics
+///
+/// Panics if a non-null row contains an invalid variant
+pub fn batch_variant_to_json_dictionary(
+    input: &VariantArray,
+    options: &JsonDictionaryOptions,
+) -> Result<(DictionaryArray<Int32Type>, BooleanArray), ArrowError> {
+    let mut builder = StringDictionaryBuilder::<Int32Type>::new();
+    let mut truncated = Vec::with_capacity(input.len());
+    let mut json = Vec::new();
+
+    for i in 0..input.len() {
+        if input.is_null(i) {
+            builder.append_null();
+            truncated.push(false);
+            continue;
+        }
+
+        json.clear();
+        variant_to_json(&mut json, &input.value(i))?;
+        let json = std::str::from_utf8(&json)
+            .map_err(|e| ArrowError::InvalidArgumentError(format!("Invalid JSON string: {e}")))?;
+
+        match options.max_length {
+            Some(max_length) if json.len() > max_length => {
+                let end = (0..=max_length)
+                    .rev()
+                    .find(|&end| json.is_char_boundary(end))
+                    .unwrap_or(0);
+                let mut value = String::with_capacity(end + options.truncation_marker.len());
+                value.push_str(&json[..end]);
+                value.push_str(&options.truncation_marker);
+                builder.append(value)?;
+                truncated.push(true);
+            }
+            _ => {
+                builder.append(json)?;
+                truncated.push(false);
+            }
+        }
+    }
+
+    let truncated = BooleanArray::new(truncated.into(), input.nulls().cloned());
+    Ok((builder.finish(), truncated))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{batch_variant_to_json_dictionary, JsonDictionaryOptions, VariantArrayBuilder};
+    use arrow::array::{Array, StringArray};
+    use parquet_variant::{Variant, VariantBuilder};
+
+    #[test]
+    fn test_batch_variant_to_json_dictionary() {
+        let mut builder = VariantArrayBuilder::new(6);
+        builder.append_variant(Variant::from(1));
+        builder.append_variant(Variant::from("héllo"));
+        builder.append_null();
+        builder.append_variant(Variant::from(1));
+        let mut variant_builder = VariantBuilder::new();
+        let mut obj = variant_builder.new_object();
+        obj.insert("a", 1);
+        obj.finish().unwrap();
+        let (metadata, value) = variant_builder.finish();
+        builder.append_variant(Variant::new(&metadata, &value));
+        builder.append_variant(Variant::Null);
+        let array = builder.build();
+
+        // Without truncation
+        let (json, truncated) =
+            batch_variant_to_json_dictionary(&array, &JsonDictionaryOptions::new()).unwrap();
+        let values = json.downcast_dict::<StringArray>().unwrap();
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            vec![
+                Some("1"),
+                Some("\"héllo\""),
+                None,
+                Some("1"),
+                Some("{\"a\":1}"),
+                Some("null")
+            ]
+        );
+        // Repeated strings share a dictionary entry
+        assert_eq!(json.values().len(), 4);
+        assert_eq!(json.keys().value(0), json.keys().value(3));
+        assert_eq!(truncated.null_count(), 1);
+        assert!(truncated.is_null(2));
+        assert_eq!(truncated.true_count(), 0);
+
+        // Truncation splitting the two byte 'é' backs off to the previous character boundary
+        let options = JsonDictionaryOptions::new()
+            .with_max_length(3)
+            .with_truncation_marker("…");
+        let (json, truncated) = batch_variant_to_json_dictionary(&array, &options).unwrap();
+        let values = json.downcast_dict::<StringArray>().unwrap();
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            vec![
+                Some("1"),
+                Some("\"h…"),
+                None,
+                Some("1"),
+                Some("{\"a…"),
+                Some("nul…")
+            ]
+        );
+        assert_eq!(
+            truncated.iter().collect::<Vec<_>>(),
+            vec![
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                Some(true),
+                Some(true)
+            ]
+        );
+    }
+}
@@ -0,0 +1,254 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for flattening the top-level objects of a batch of Variants into an Arrow
+//! `MapArray`, so key-level operations can use the native map kernels.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use crate::{unshred_variant, VariantArray, VariantArrayBuilder};
+use arrow::array::{Array, ArrayRef, BooleanArray, MapArray, StringBuilder, StructArray};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use parquet_variant::{Variant, VariantBuilder};
+
+/// Options for [`batch_variant_to_map`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariantToMapOptions {
+    max_entries: Option<usize>,
+    max_bytes: Option<usize>,
+}
+
+impl VariantToMapOptions {
+    /// Create new options that do not truncate the maps
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most the first `max_entries` fields of each object
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// Keep only the first fields of each object whose keys and encoded values total at
+    /// most `max_bytes` bytes
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Returns the maximum number of entries of each map, if any
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    /// Returns the maximum size in bytes of the entries of each map, if any
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.max_bytes
+    }
+}
+
+/// Convert the top-level object of each row of a [`VariantArray`] to a map from its field
+/// names to its field values, returning a `Map(Utf8, Variant)` [`MapArray`], and a
+/// [`BooleanArray`] indicating which maps were truncated according to `options`.
+///
+/// The values of the map are a [`VariantArray`], each field value being encoded as a
+/// standalone variant with its own metadata, and the values field is annotated with the
+/// [`VariantType`] extension type. Entries are in the order of the fields of the objects,
+/// i.e. sorted by field name. Null rows, and rows that are not objects, are null in both
+/// results. Shredded inputs are [unshredded] first.
+///
+/// # Example
+/// ```
+/// # use arrow::array::{Array, AsArray};
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::{
+/// #     batch_variant_to_map, VariantArray, VariantArrayBuilder, VariantToMapOptions,
+/// # };
+/// let mut builder = VariantArrayBuilder::new(3);
+/// builder.append_json_str(r#"{"b": [1, 2], "a": "x", "c": {"d": null}}"#).unwrap();
+/// builder.append_null();
+/// builder.append_json_str("42").unwrap();
+/// let array = builder.build();
+///
+/// let options = VariantToMapOptions::new().with_max_entries(2);
+/// let (map, truncated) = batch_variant_to_map(&array, &options).unwrap();
+/// let keys = map.keys().as_string::<i32>();
+/// assert_eq!(keys.iter().collect::<Vec<_>>(), [Some("a"), Some("b")]);
+/// let values = VariantArray::try_new(map.values().clone()).unwrap();
+/// assert_eq!(values.value(0), Variant::from("x"));
+/// assert_eq!(values.value(1).as_list().unwrap().len(), 2);
+/// assert!(map.is_null(1) && map.is_null(2));
+/// assert_eq!(truncated.iter().collect::<Vec<_>>(), [Some(true), None, None]);
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if a shredded input can not be unshredded, or the keys overflow
+///
+/// # Panics
+///
+/// Panics if a non-null row contains an invalid variant
+///
+/// [`VariantType`]: crate::VariantType
+/// [unshredded]: crate::unshred_variant
+pub fn batch_variant_to_map(
+    input: &VariantArray,
+    options: &VariantToMapOptions,
+) -> Result<(MapArray, BooleanArray), ArrowError> {
+    let input = match input.typed_value_field() {
+        Some(_) => Cow::Owned(unshred_variant(input)?),
+        None => Cow::Borrowed(input),
+    };
+
+    let mut keys = StringBuilder::new();
+    let mut values = VariantArrayBuilder::new(input.len());
+    let mut offsets = Vec::with_capacity(input.len() + 1);
+    offsets.push(0i32);
+    let mut valid = Vec::with_capacity(input.len());
+    let mut truncated = Vec::with_capacity(input.len());
+
+    for i in 0..input.len() {
+        let object = match input.is_valid(i).then(|| input.value(i)) {
+            Some(Variant::Object(object)) => object,
+            _ => {
+                offsets.push(*offsets.last().unwrap());
+                valid.push(false);
+                truncated.push(None);
+                continue;
+            }
+        };
+
+        let max_entries = options.max_entries.unwrap_or(usize::MAX);
+        let mut remaining_bytes = options.max_bytes.unwrap_or(usize::MAX);
+        let mut entries = 0;
+        for (key, value) in object.iter() {
+            if entries == max_entries {
+                break;
+            }
+            let mut builder = VariantBuilder::new();
+            builder.append_value(value);
+            let (metadata, value) = builder.finish();
+            let Some(remaining) = remaining_bytes.checked_sub(key.len() + value.len()) else {
+                break;
+            };
+            remaining_bytes = remaining;
+            keys.append_value(key);
+            values.append_variant_buffers(&metadata, &value);
+            entries += 1;
+        }
+
+        let end = i32::try_from(values.len())
+            .map_err(|_| ArrowError::ComputeError("Map offset overflow".to_string()))?;
+        offsets.push(end);
+        valid.push(true);
+        truncated.push(Some(entries < object.len()));
+    }
+
+    let keys: ArrayRef = Arc::new(keys.finish());
+    let values = values.build();
+    let fields = Fields::from(vec![
+        Field::new("keys", DataType::Utf8, false),
+        values.field("values"),
+    ]);
+    let values: ArrayRef = Arc::new(values.into_inner());
+    let entries = StructArray::try_new(fields.clone(), vec![keys, values], None)?;
+    let entries_field = Arc::new(Field::new("entries", DataType::Struct(fields), false));
+    let map = MapArray::try_new(
+        entries_field,
+        OffsetBuffer::new(offsets.into()),
+        entries,
+        Some(NullBuffer::from(valid)),
+        false,
+    )?;
+    Ok((map, BooleanArray::from(truncated)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::variant_array;
+    use crate::{shred_variant, VariantType};
+    use arrow::array::AsArray;
+    use parquet_variant_json::variant_to_json_string;
+
+    /// Returns the entries of row `row` of `map`, with the values rendered as JSON
+    fn entries(map: &MapArray, row: usize) -> Vec<(&str, String)> {
+        let start = map.value_offsets()[row] as usize;
+        let end = map.value_offsets()[row + 1] as usize;
+        let keys = map.keys().as_string::<i32>();
+        let values = VariantArray::try_new(map.values().clone()).unwrap();
+        (start..end)
+            .map(|i| {
+                (
+                    keys.value(i),
+                    variant_to_json_string(&values.value(i)).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_variant_to_map() {
+        let array = variant_array(&[
+            Some(r#"{"z": 1, "a": "x", "m": null}"#),
+            Some("{}"),
+            Some(r#"["a"]"#),
+            Some(r#"{"b": "y"}"#),
+            None,
+        ]);
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+
+        for input in [&array, &shredded] {
+            let (map, truncated) =
+                batch_variant_to_map(input, &VariantToMapOptions::new()).unwrap();
+            assert_eq!(map.len(), 5);
+            let field = &map.entries().fields()[1];
+            assert!(field.try_extension_type::<VariantType>().is_ok());
+            assert_eq!(
+                entries(&map, 0),
+                [
+                    ("a", "\"x\"".to_string()),
+                    ("m", "null".to_string()),
+                    ("z", "1".to_string())
+                ]
+            );
+            assert!(entries(&map, 1).is_empty());
+            assert_eq!(entries(&map, 3), [("b", "\"y\"".to_string())]);
+            let valid: Vec<_> = (0..5).map(|i| map.is_valid(i)).collect();
+            assert_eq!(valid, [true, true, false, true, false]);
+            let expected = [Some(false), Some(false), None, Some(false), None];
+            assert_eq!(truncated.iter().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_variant_to_map_max_bytes() {
+        let array = variant_array(&[Some(r#"{"a": "xyz", "b": 1}"#), Some(r#"{"b": 1}"#)]);
+        // The key "a" and its encoded string value take 1 + 4 bytes, and "b" 1 + 2 bytes
+        let options = VariantToMapOptions::new().with_max_bytes(7);
+        let (map, truncated) = batch_variant_to_map(&array, &options).unwrap();
+        assert_eq!(entries(&map, 0), [("a", "\"xyz\"".to_string())]);
+        assert_eq!(entries(&map, 1), [("b", "1".to_string())]);
+        assert_eq!(
+            truncated.iter().collect::<Vec<_>>(),
+            [Some(true), Some(false)]
+        );
+    }
+}
@@ -0,0 +1,376 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for reassembling a shredded [`VariantArray`], see [`unshred_variant`]
+
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, GenericListArray, OffsetSizeTrait};
+use arrow_schema::{ArrowError, DataType, Fields};
+use parquet_variant::{
+    ObjectBuilder, ObjectFieldBuilder, Variant, VariantBuilder, VariantBuilderExt, VariantMetadata,
+};
+
+use crate::cast::primitive_to_variant;
+use crate::{VariantArray, VariantArrayBuilder};
+
+/// Reassembles the shredded variants of `array` into unshredded variants
+///
+/// This is the inverse of [`shred_variant`]: the `value` and `typed_value` fields defined by
+/// the [Variant Shredding specification] are merged back into a single binary `value`, so
+/// that the rows can be accessed with [`VariantArray::value`]. In particular:
+///
+/// * Rows whose `typed_value` is null are read from the residual `value`
+/// * Shredded object fields are merged with the unshredded fields of partially shredded
+///   objects, which are stored as an object in `value`. Object fields whose `value` and
+///   `typed_value` are both null are missing from the object
+/// * Shredded list elements are reassembled into lists
+///
+/// An `array` that is not shredded, i.e. that has no `typed_value`, is returned unchanged.
+///
+/// The residual `value` of nested fields may be `Binary`, `LargeBinary` or `BinaryView`.
+/// Besides the types produced by [`shred_variant`], primitive `typed_value`s may be
+/// `Decimal32`, `Decimal64`, `Decimal128` or a 16 byte `FixedSizeBinary`, which is read as a
+/// UUID.
+///
+/// # Example
+/// ```
+/// # use arrow::datatypes::{DataType, Field};
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::{shred_variant, unshred_variant, VariantArrayBuilder};
+/// let mut builder = VariantArrayBuilder::new(2);
+/// builder.append_json_str(r#"{"id": 1, "name": "a"}"#).unwrap();
+/// builder.append_json_str(r#"{"id": "two"}"#).unwrap();
+/// let array = builder.build();
+///
+/// let as_type = DataType::Struct(vec![Field::new("id", DataType::Int64, true)].into());
+/// let shredded = shred_variant(&array, &as_type).unwrap();
+/// let unshredded = unshred_variant(&shredded).unwrap();
+///
+/// let row = unshredded.value(0);
+/// assert_eq!(row.get_object_field("id"), Some(Variant::from(1i64)));
+/// assert_eq!(row.get_object_field("name"), Some(Variant::from("a")));
+/// let row = unshredded.value(1);
+/// assert_eq!(row.get_object_field("id"), Some(Variant::from("two")));
+/// ```
+///
+/// [`shred_variant`]: crate::shred_variant
+/// [Variant Shredding specification]: https://github.com/apache/parquet-format/blob/master/VariantShredding.md
+pub fn unshred_variant(array: &VariantArray) -> Result<VariantArray, ArrowError> {
+    let Some(typed_value) = array.typed_value_field() else {
+        return VariantArray::try_new(Arc::new(array.inner().clone()));
+    };
+    let metadata = array.metadata_field().as_binary_view();
+    let value = array.value_field();
+
+    let mut output = VariantArrayBuilder::new(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            output.append_null();
+            continue;
+        }
+        let metadata = VariantMetadata::try_new(metadata.value(i))?;
+        let mut builder = VariantBuilder::new().with_metadata(metadata.clone());
+        if !append_shredded(&mut builder, &metadata, Some(value), Some(typed_value), i)? {
+            // A valid row must have a value, treat a missing one as a variant null
+            builder.append_value(Variant::Null);
+        }
+        let (metadata, value) = builder.finish();
+        output.append_variant_buffers(&metadata, &value);
+    }
+    Ok(output.build())
+}
+
+/// Appends row `i` of a shredded value to `builder`, returning false without appending
+/// anything if both `value` and `typed_value` are null, i.e. if the value is missing
+fn append_shredded<'a>(
+    builder: &mut impl VariantBuilderExt<'a, 'a>,
+    metadata: &VariantMetadata<'a>,
+    value: Option<&'a ArrayRef>,
+    typed_value: Option<&'a ArrayRef>,
+    i: usize,
+) -> Result<bool, ArrowError> {
+    let residual = match value {
+        Some(value) => binary_value(value, i)?,
+        None => None,
+    };
+    let Some(typed_value) = typed_value.filter(|t| t.is_valid(i)) else {
+        let Some(residual) = residual else {
+            return Ok(false);
+        };
+        let residual = Variant::try_new_with_metadata(metadata.clone(), residual)?;
+        builder.append_value(residual);
+        return Ok(true);
+    };
+
+    match typed_value.data_type() {
+        DataType::Struct(fields) => {
+            let typed_value = typed_value.as_struct();
+            let mut object = builder.new_object();
+            for (field, column) in fields.iter().zip(typed_value.columns()) {
+                let (value, typed_value) = shredded_columns(column)?;
+                let mut field_builder = ObjectFieldBuilder::new(field.name(), &mut object);
+                append_shredded(&mut field_builder, metadata, value, typed_value, i)?;
+            }
+            // The unshredded fields of a partially shredded object
+            if let Some(residual) = residual {
+                let residual = Variant::try_new_with_metadata(metadata.clone(), residual)?;
+                append_residual_fields(&mut object, fields, residual)?;
+            }
+            object.finish()?;
+        }
+        DataType::List(_) => append_list(builder, metadata, typed_value.as_list::<i32>(), i)?,
+        DataType::LargeList(_) => append_list(builder, metadata, typed_value.as_list::<i64>(), i)?,
+        _ => {
+            if residual.is_some() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Invalid shredded variant: both value and typed_value are set in row {i}"
+                )));
+            }
+            builder.append_value(typed_to_variant(typed_value, i)?);
+        }
+    }
+    Ok(true)
+}
+
+/// Appends the fields of the `residual` value of a partially shredded object to `object`
+fn append_residual_fields<'a>(
+    object: &mut ObjectBuilder,
+    shredded: &Fields,
+    residual: Variant<'a, 'a>,
+) -> Result<(), ArrowError> {
+    let Variant::Object(residual) = residual else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid shredded variant: expected the value of a partially shredded object to \
+             be an object, found {residual:?}"
+        )));
+    };
+    for (name, value) in residual.iter() {
+        if shredded.find(name).is_some() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid shredded variant: field {name} is both shredded and in the value of \
+                 a partially shredded object"
+            )));
+        }
+        object.try_insert(name, value)?;
+    }
+    Ok(())
+}
+
+/// Appends the shredded list in row `i` of `typed_value` to `builder`
+fn append_list<'a, O: OffsetSizeTrait>(
+    builder: &mut impl VariantBuilderExt<'a, 'a>,
+    metadata: &VariantMetadata<'a>,
+    typed_value: &'a GenericListArray<O>,
+    i: usize,
+) -> Result<(), ArrowError> {
+    let (value, element) = shredded_columns(typed_value.values())?;
+    let offsets = typed_value.value_offsets();
+    let mut list = builder.new_list();
+    for j in offsets[i].as_usize()..offsets[i + 1].as_usize() {
+        if !append_shredded(&mut list, metadata, value, element, j)? {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid shredded variant: missing list element in row {i}"
+            )));
+        }
+    }
+    list.finish();
+    Ok(())
+}
+
+/// Returns the `value` and `typed_value` columns of a shredded object field or list element
+fn shredded_columns(
+    array: &ArrayRef,
+) -> Result<(Option<&ArrayRef>, Option<&ArrayRef>), ArrowError> {
+    let Some(array) = array.as_struct_opt() else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid shredded variant: expected a struct of value and typed_value, found {}",
+            array.data_type()
+        )));
+    };
+    Ok((
+        array.column_by_name("value"),
+        array.column_by_name("typed_value"),
+    ))
+}
+
+/// Returns the bytes in row `i` of the residual `value`, or `None` if it is null
+fn binary_value(value: &ArrayRef, i: usize) -> Result<Option<&[u8]>, ArrowError> {
+    if value.is_null(i) {
+        return Ok(None);
+    }
+    Ok(Some(match value.data_type() {
+        DataType::Binary => value.as_binary::<i32>().value(i),
+        DataType::LargeBinary => value.as_binary::<i64>().value(i),
+        DataType::BinaryView => value.as_binary_view().value(i),
+        other => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid shredded variant: expected a binary value, found {other}"
+            )))
+        }
+    }))
+}
+
+/// Returns the variant of row `i` of a primitive `typed_value`
+fn typed_to_variant(typed_value: &ArrayRef, i: usize) -> Result<Variant<'_, '_>, ArrowError> {
+    match typed_value.data_type() {
+        // A shredded 16 byte fixed size binary is a UUID
+        DataType::FixedSizeBinary(16) => {
+            let uuid = typed_value.as_fixed_size_binary().value(i);
+            Ok(Variant::Uuid(uuid.try_into().unwrap()))
+        }
+        _ => primitive_to_variant(typed_value.as_ref(), i),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::variant_array;
+    use crate::{batch_variant_to_json_string, shred_variant};
+    use arrow::array::{BinaryViewArray, Int64Array, StringArray, StructArray};
+    use arrow::buffer::NullBuffer;
+    use arrow_schema::Field;
+
+    /// Checks that shredding `values` as `as_type` and unshredding them is lossless
+    ///
+    /// The rows are compared as JSON, as shredding widens integers to the shredded type.
+    fn assert_roundtrip(values: &[Option<&str>], as_type: DataType) {
+        let array = variant_array(values);
+        let shredded = shred_variant(&array, &as_type).unwrap();
+        let unshredded = unshred_variant(&shredded).unwrap();
+        assert!(unshredded.typed_value_field().is_none());
+        let expected = batch_variant_to_json_string(&(Arc::new(array) as ArrayRef)).unwrap();
+        let actual = batch_variant_to_json_string(&(Arc::new(unshredded) as ArrayRef)).unwrap();
+        assert_eq!(actual, expected, "{as_type}");
+    }
+
+    #[test]
+    fn test_unshred_roundtrip() {
+        let values = [
+            Some(r#"{"a": 1, "b": "x", "c": [1, {"d": 2}]}"#),
+            Some(r#"{"a": "one", "c": "not a list"}"#),
+            None,
+            Some("3"),
+            Some(r#"{"b": null, "c": [], "e": {"f": true}}"#),
+            Some("[1, 2]"),
+        ];
+        let element = DataType::Struct(vec![Field::new("d", DataType::Int64, true)].into());
+        let as_type = DataType::Struct(
+            vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::Utf8, true),
+                Field::new_list("c", Field::new("element", element, true), true),
+            ]
+            .into(),
+        );
+        assert_roundtrip(&values, as_type);
+        assert_roundtrip(&values, DataType::Int64);
+        assert_roundtrip(
+            &values,
+            DataType::List(Arc::new(Field::new("element", DataType::Int64, true))),
+        );
+    }
+
+    #[test]
+    fn test_unshred_not_shredded() {
+        let array = variant_array(&[Some("1"), None]);
+        let unshredded = unshred_variant(&array).unwrap();
+        assert_eq!(unshredded.inner(), array.inner());
+    }
+
+    /// Returns a shredded variant with the given `value` and `typed_value`, whose metadata
+    /// contains the field names `a` and `b`
+    fn shredded(value: BinaryViewArray, typed_value: ArrayRef) -> VariantArray {
+        let mut builder = VariantBuilder::new().with_field_names(["a", "b"].into_iter());
+        builder.append_value(Variant::Null);
+        let (metadata, _) = builder.finish();
+        let metadata = BinaryViewArray::from_iter_values(vec![metadata; value.len()]);
+        let inner = StructArray::try_from(vec![
+            ("metadata", Arc::new(metadata) as ArrayRef),
+            ("value", Arc::new(value) as ArrayRef),
+            ("typed_value", typed_value),
+        ])
+        .unwrap();
+        VariantArray::try_new(Arc::new(inner)).unwrap()
+    }
+
+    /// Returns the encoded value of the object `{"b": <b>}`
+    fn object_value(b: &str) -> Vec<u8> {
+        let mut builder = VariantBuilder::new().with_field_names(["a", "b"].into_iter());
+        let mut object = builder.new_object();
+        object.insert("b", b);
+        object.finish().unwrap();
+        builder.finish().1
+    }
+
+    /// Returns a `typed_value` of objects with a shredded string field `a`
+    fn typed_objects(a: StringArray, nulls: Option<NullBuffer>) -> ArrayRef {
+        let value = BinaryViewArray::new_null(a.len());
+        let field = StructArray::try_from(vec![
+            ("value", Arc::new(value) as ArrayRef),
+            ("typed_value", Arc::new(a) as ArrayRef),
+        ])
+        .unwrap();
+        let fields = vec![Field::new("a", field.data_type().clone(), false)];
+        Arc::new(StructArray::new(
+            fields.into(),
+            vec![Arc::new(field)],
+            nulls,
+        ))
+    }
+
+    #[test]
+    fn test_unshred_partially_shredded() {
+        let b = object_value("y");
+        let value = BinaryViewArray::from(vec![Some(b.as_slice()), None]);
+        let typed_value = typed_objects(StringArray::from(vec![Some("x"), None]), None);
+        let array = unshred_variant(&shredded(value, typed_value)).unwrap();
+
+        let row = array.value(0);
+        assert_eq!(row.get_object_field("a"), Some(Variant::from("x")));
+        assert_eq!(row.get_object_field("b"), Some(Variant::from("y")));
+        // A shredded field that is missing is not part of the object
+        let Variant::Object(row) = array.value(1) else {
+            panic!("expected an object")
+        };
+        assert!(row.is_empty());
+    }
+
+    #[test]
+    fn test_unshred_invalid() {
+        // The residual value of a shredded object must be an object
+        let value = BinaryViewArray::from(vec![Some([0x0Cu8, 1].as_slice())]);
+        let typed_value = typed_objects(StringArray::from(vec![Some("x")]), None);
+        let err = unshred_variant(&shredded(value, typed_value)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid shredded variant: expected the value of a \
+             partially shredded object to be an object, found Int8(1)"
+        );
+
+        // A primitive can not be both in value and typed_value
+        let value = BinaryViewArray::from(vec![Some([0x0Cu8, 1].as_slice())]);
+        let typed_value = Arc::new(Int64Array::from(vec![1]));
+        let err = unshred_variant(&shredded(value, typed_value)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid shredded variant: both value and typed_value \
+             are set in row 0"
+        );
+    }
+}
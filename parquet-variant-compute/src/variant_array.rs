@@ -17,9 +17,10 @@
 
 //! [`VariantArray`] implementation
 
-use arrow::array::{Array, ArrayData, ArrayRef, AsArray, StructArray};
+use arrow::array::{make_array, Array, ArrayData, ArrayRef, AsArray, StructArray};
 use arrow::buffer::NullBuffer;
-use arrow_schema::{ArrowError, DataType};
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{ArrowError, DataType, Field, Fields};
 use parquet_variant::Variant;
 use std::any::Any;
 use std::sync::Arc;
@@ -40,9 +41,12 @@ use std::sync::Arc;
 ///    defined by [Extension Type for Parquet Variant arrow] and this [document].
 ///    At the time of this writing, this is not yet a standardized Arrow extension type.
 ///
+/// 2. Fields of a [`VariantArray`] are annotated with the [`VariantType`] extension type,
+///    see [`VariantArray::field`].
+///
 /// [Extension Type for Parquet Variant arrow]: https://github.com/apache/arrow/issues/46908
 /// [document]: https://docs.google.com/document/d/1pw0AWoMQY3SjD7R4LgbPvMjG_xSCtXp3rZHkVp9jpZ4/edit?usp=sharing
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VariantArray {
     /// StructArray of up to three fields:
     ///
@@ -87,35 +91,31 @@ impl VariantArray {
                 "Invalid VariantArray: requires StructArray as input".to_string(),
             ));
         };
-        // Ensure the StructArray has a metadata field of BinaryView
-        let Some(metadata_field) = inner.fields().iter().find(|f| f.name() == "metadata") else {
-            return Err(ArrowError::InvalidArgumentError(
-                "Invalid VariantArray: StructArray must contain a 'metadata' field".to_string(),
-            ));
-        };
-        if metadata_field.data_type() != &DataType::BinaryView {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "VariantArray 'metadata' field must be BinaryView, got {}",
-                metadata_field.data_type()
-            )));
-        }
-        let Some(value_field) = inner.fields().iter().find(|f| f.name() == "value") else {
-            return Err(ArrowError::InvalidArgumentError(
-                "Invalid VariantArray: StructArray must contain a 'value' field".to_string(),
-            ));
-        };
-        if value_field.data_type() != &DataType::BinaryView {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "VariantArray 'value' field must be BinaryView, got {}",
-                value_field.data_type()
-            )));
-        }
+        validate_fields(inner.fields())?;
 
         Ok(Self {
             inner: inner.clone(),
         })
     }
 
+    /// Returns a nullable [`Field`] named `name` for this array, annotated with the
+    /// [`VariantType`] extension type
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::Variant;
+    /// # use parquet_variant_compute::{VariantArrayBuilder, VariantType};
+    /// let mut builder = VariantArrayBuilder::new(1);
+    /// builder.append_variant(Variant::from(1));
+    /// let array = builder.build();
+    ///
+    /// let field = array.field("v");
+    /// assert!(field.try_extension_type::<VariantType>().is_ok());
+    /// ```
+    pub fn field(&self, name: impl Into<String>) -> Field {
+        Field::new(name, self.data_type().clone(), true).with_extension_type(VariantType)
+    }
+
     /// Returns a reference to the underlying [`StructArray`].
     pub fn inner(&self) -> &StructArray {
         &self.inner
@@ -132,6 +132,11 @@ impl VariantArray {
     ///
     /// Note: Does not do deep validation of the [`Variant`], so it is up to the
     /// caller to ensure that the metadata and value were constructed correctly.
+    ///
+    /// Note: Only reads the `value` field, the rows of a shredded array must first be
+    /// reassembled with [`unshred_variant`].
+    ///
+    /// [`unshred_variant`]: crate::unshred_variant
     pub fn value(&self, index: usize) -> Variant {
         let metadata = self.metadata_field().as_binary_view().value(index);
         let value = self.value_field().as_binary_view().value(index);
@@ -149,6 +154,93 @@ impl VariantArray {
         // spec says fields order is not guaranteed, so we search by name
         self.inner.column_by_name("value").unwrap()
     }
+
+    /// Return a reference to the `typed_value` field of the `StructArray` of a shredded
+    /// array, or `None` if the array is not shredded
+    pub fn typed_value_field(&self) -> Option<&ArrayRef> {
+        self.inner.column_by_name("typed_value")
+    }
+}
+
+impl TryFrom<ArrayData> for VariantArray {
+    type Error = ArrowError;
+
+    fn try_from(data: ArrayData) -> Result<Self, Self::Error> {
+        Self::try_new(make_array(data))
+    }
+}
+
+/// Returns an error if `fields` are not the fields of a [`VariantArray`]
+fn validate_fields(fields: &Fields) -> Result<(), ArrowError> {
+    // Ensure the StructArray has a metadata field of BinaryView
+    let Some(metadata_field) = fields.iter().find(|f| f.name() == "metadata") else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Invalid VariantArray: StructArray must contain a 'metadata' field".to_string(),
+        ));
+    };
+    if metadata_field.data_type() != &DataType::BinaryView {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "VariantArray 'metadata' field must be BinaryView, got {}",
+            metadata_field.data_type()
+        )));
+    }
+    let Some(value_field) = fields.iter().find(|f| f.name() == "value") else {
+        return Err(ArrowError::InvalidArgumentError(
+            "Invalid VariantArray: StructArray must contain a 'value' field".to_string(),
+        ));
+    };
+    if value_field.data_type() != &DataType::BinaryView {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "VariantArray 'value' field must be BinaryView, got {}",
+            value_field.data_type()
+        )));
+    }
+    Ok(())
+}
+
+/// The Arrow extension type of a [`VariantArray`]
+///
+/// Extension name: `arrow.parquet.variant`.
+///
+/// The storage type of the extension is a `Struct` with the `metadata` and `value` fields
+/// of the variants, see [`VariantArray`]. The extension type has no metadata.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct VariantType;
+
+impl ExtensionType for VariantType {
+    const NAME: &'static str = "arrow.parquet.variant";
+
+    type Metadata = ();
+
+    fn metadata(&self) -> &Self::Metadata {
+        &()
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        None
+    }
+
+    fn deserialize_metadata(metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+        match metadata {
+            None => Ok(()),
+            Some(_) => Err(ArrowError::InvalidArgumentError(
+                "Variant extension type expects no metadata".to_string(),
+            )),
+        }
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        match data_type {
+            DataType::Struct(fields) => validate_fields(fields),
+            data_type => Err(ArrowError::InvalidArgumentError(format!(
+                "Variant data type mismatch, expected Struct, found {data_type}"
+            ))),
+        }
+    }
+
+    fn try_new(data_type: &DataType, _metadata: Self::Metadata) -> Result<Self, ArrowError> {
+        Self.supports_data_type(data_type).map(|_| Self)
+    }
 }
 
 impl Array for VariantArray {
@@ -202,8 +294,8 @@ impl Array for VariantArray {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::VariantArrayBuilder;
     use arrow::array::{BinaryArray, BinaryViewArray};
-    use arrow_schema::{Field, Fields};
 
     #[test]
     fn invalid_not_a_struct_array() {
@@ -276,6 +368,65 @@ mod test {
         );
     }
 
+    #[test]
+    fn extension_type() {
+        let mut builder = VariantArrayBuilder::new(1);
+        builder.append_variant(Variant::from("a"));
+        let array = builder.build();
+
+        let field = array.field("v");
+        assert_eq!(field.extension_type_name(), Some(VariantType::NAME));
+        assert_eq!(field.extension_type_metadata(), None);
+        field.try_extension_type::<VariantType>().unwrap();
+        assert!(field.is_nullable());
+
+        let err = Field::new("v", DataType::Int32, true)
+            .try_with_extension_type(VariantType)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant data type mismatch, expected Struct, found Int32"
+        );
+        let fields = Fields::from(vec![Field::new("metadata", DataType::BinaryView, false)]);
+        let err = Field::new("v", DataType::Struct(fields), true)
+            .try_with_extension_type(VariantType)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid VariantArray: StructArray must contain a 'value' field"
+        );
+    }
+
+    #[test]
+    fn array_data_roundtrip() {
+        let mut builder = VariantArrayBuilder::new(4);
+        builder.append_variant(Variant::from(1));
+        builder.append_null();
+        builder.append_variant(Variant::from("two"));
+        builder.append_variant(Variant::from(3.5));
+        let array = builder.build();
+
+        let roundtrip = VariantArray::try_from(array.to_data()).unwrap();
+        assert_eq!(roundtrip.len(), 4);
+        assert_eq!(roundtrip.null_count(), 1);
+        assert!(roundtrip.is_null(1));
+        assert_eq!(roundtrip.value(2), Variant::from("two"));
+
+        let sliced = array.slice(1, 3);
+        assert!(sliced.as_any().downcast_ref::<VariantArray>().is_some());
+        let sliced = VariantArray::try_from(sliced.into_data()).unwrap();
+        assert_eq!(sliced.len(), 3);
+        assert!(sliced.is_null(0));
+        assert_eq!(sliced.value(1), Variant::from("two"));
+        assert_eq!(sliced.value(2), Variant::from(3.5));
+
+        let err = VariantArray::try_from(make_binary_view_array().into_data()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid VariantArray: requires StructArray as input"
+        );
+    }
+
     fn make_binary_view_array() -> ArrayRef {
         Arc::new(BinaryViewArray::from(vec![b"test" as &[u8]]))
     }
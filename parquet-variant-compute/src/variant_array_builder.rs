@@ -19,8 +19,9 @@
 
 use crate::VariantArray;
 use arrow::array::{ArrayRef, BinaryViewArray, BinaryViewBuilder, NullBufferBuilder, StructArray};
-use arrow_schema::{DataType, Field, Fields};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
 use parquet_variant::{Variant, VariantBuilder};
+use parquet_variant_json::json_to_variant;
 use std::sync::Arc;
 
 /// A builder for [`VariantArray`]
@@ -31,6 +32,10 @@ use std::sync::Arc;
 /// This builder always creates a `VariantArray` using [`BinaryViewArray`] for both
 /// the metadata and value fields.
 ///
+/// The variants of all rows are encoded directly into a single metadata buffer and a
+/// single value buffer, which the [`BinaryViewArray`]s of the final `VariantArray`
+/// reference, so the allocations are amortized across rows.
+///
 /// # TODO
 /// 1. Support shredding: <https://github.com/apache/arrow-rs/issues/7895>
 ///
@@ -54,10 +59,12 @@ use std::sync::Arc;
 ///   vb.finish()
 /// };
 /// builder.append_variant_buffers(&metadata, &value);
+/// // append a JSON document
+/// builder.append_json_str(r#"{"a": [1, 2]}"#).unwrap();
 ///
 /// // create the final VariantArray
 /// let variant_array = builder.build();
-/// assert_eq!(variant_array.len(), 3);
+/// assert_eq!(variant_array.len(), 4);
 /// // // Access the values
 /// // row 1 is not null and is an integer
 /// assert!(!variant_array.is_null(0));
@@ -67,6 +74,8 @@ use std::sync::Arc;
 /// // row 2 is not null and is an object
 /// assert!(!variant_array.is_null(2));
 /// assert!(variant_array.value(2).as_object().is_some());
+/// // row 3 is the parsed JSON document
+/// assert_eq!(variant_array.value(3).get_object_field("a").unwrap().as_list().unwrap().len(), 2);
 /// ```
 #[derive(Debug)]
 pub struct VariantArrayBuilder {
@@ -82,8 +91,7 @@ pub struct VariantArrayBuilder {
     value_locations: Vec<(usize, usize)>,
     /// The fields of the final `StructArray`
     ///
-    /// TODO: 1) Add extension type metadata
-    /// TODO: 2) Add support for shredding
+    /// TODO: Add support for shredding
     fields: Fields,
 }
 
@@ -104,34 +112,48 @@ impl VariantArrayBuilder {
     }
 
     /// Build the final builder
-    pub fn build(self) -> VariantArray {
-        let Self {
-            mut nulls,
-            metadata_buffer,
-            metadata_locations,
-            value_buffer,
-            value_locations,
-            fields,
-        } = self;
+    pub fn build(mut self) -> VariantArray {
+        self.finish()
+    }
 
-        let metadata_array = binary_view_array_from_buffers(metadata_buffer, metadata_locations);
+    /// Builds the [`VariantArray`] of the rows appended so far, and resets the builder
+    ///
+    /// Use [`VariantArray::field`] to create a field for the array, annotated with the
+    /// variant extension type.
+    pub fn finish(&mut self) -> VariantArray {
+        let metadata_array = binary_view_array_from_buffers(
+            std::mem::take(&mut self.metadata_buffer),
+            std::mem::take(&mut self.metadata_locations),
+        );
 
-        let value_array = binary_view_array_from_buffers(value_buffer, value_locations);
+        let value_array = binary_view_array_from_buffers(
+            std::mem::take(&mut self.value_buffer),
+            std::mem::take(&mut self.value_locations),
+        );
 
         // The build the final struct array
         let inner = StructArray::new(
-            fields,
+            self.fields.clone(),
             vec![
                 Arc::new(metadata_array) as ArrayRef,
                 Arc::new(value_array) as ArrayRef,
             ],
-            nulls.finish(),
+            self.nulls.finish(),
         );
-        // TODO add arrow extension type metadata
 
         VariantArray::try_new(Arc::new(inner)).expect("valid VariantArray by construction")
     }
 
+    /// Returns the number of rows appended to the builder
+    pub fn len(&self) -> usize {
+        self.value_locations.len()
+    }
+
+    /// Returns true if no rows have been appended to the builder
+    pub fn is_empty(&self) -> bool {
+        self.value_locations.is_empty()
+    }
+
     /// Appends a null row to the builder.
     pub fn append_null(&mut self) {
         self.nulls.append_null();
@@ -147,11 +169,54 @@ impl VariantArrayBuilder {
 
     /// Append the [`Variant`] to the builder as the next row
     pub fn append_variant(&mut self, variant: Variant) {
-        // TODO make this more efficient by avoiding the intermediate buffers
-        let mut variant_builder = VariantBuilder::new();
-        variant_builder.append_value(variant);
-        let (metadata, value) = variant_builder.finish();
-        self.append_variant_buffers(&metadata, &value);
+        self.try_append_variant(variant)
+            .expect("Failed to append variant")
+    }
+
+    /// Append the [`Variant`] to the builder as the next row, returning an error if it is
+    /// invalid
+    ///
+    /// The builder is unchanged if an error is returned.
+    pub fn try_append_variant(&mut self, variant: Variant) -> Result<(), ArrowError> {
+        self.append_with(|builder| builder.try_append_value(variant))
+    }
+
+    /// Parse the JSON document `json` and append it to the builder as the next row
+    ///
+    /// The builder is unchanged if an error is returned.
+    pub fn append_json_str(&mut self, json: &str) -> Result<(), ArrowError> {
+        self.append_with(|builder| json_to_variant(json, builder))
+    }
+
+    /// Appends the variant built by `f` as the next row, encoding it directly into the
+    /// shared buffers of this builder
+    pub(crate) fn append_with<F>(&mut self, f: F) -> Result<(), ArrowError>
+    where
+        F: FnOnce(&mut VariantBuilder) -> Result<(), ArrowError>,
+    {
+        let metadata_offset = self.metadata_buffer.len();
+        let value_offset = self.value_buffer.len();
+        let mut builder = VariantBuilder::new_with_buffers(
+            std::mem::take(&mut self.metadata_buffer),
+            std::mem::take(&mut self.value_buffer),
+        );
+        let result = f(&mut builder);
+        (self.metadata_buffer, self.value_buffer) = builder.finish();
+        if let Err(e) = result {
+            // Discard any partially written variant
+            self.metadata_buffer.truncate(metadata_offset);
+            self.value_buffer.truncate(value_offset);
+            return Err(e);
+        }
+
+        self.nulls.append_non_null();
+        self.metadata_locations.push((
+            metadata_offset,
+            self.metadata_buffer.len() - metadata_offset,
+        ));
+        self.value_locations
+            .push((value_offset, self.value_buffer.len() - value_offset));
+        Ok(())
     }
 
     /// Append a metadata and values buffer to the builder
@@ -171,7 +236,7 @@ impl VariantArrayBuilder {
     // TODO: Return a Variant builder that will write to the underlying buffers (TODO)
 }
 
-fn binary_view_array_from_buffers(
+pub(crate) fn binary_view_array_from_buffers(
     buffer: Vec<u8>,
     locations: Vec<(usize, usize)>,
 ) -> BinaryViewArray {
@@ -191,7 +256,7 @@ fn binary_view_array_from_buffers(
 #[cfg(test)]
 mod test {
     use super::*;
-    use arrow::array::Array;
+    use arrow::array::{Array, AsArray};
 
     /// Test that both the metadata and value buffers are non nullable
     #[test]
@@ -220,4 +285,43 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn test_variant_array_builder_shared_buffers() {
+        let mut builder = VariantArrayBuilder::new(4);
+        assert!(builder.is_empty());
+        builder
+            .append_json_str(r#"{"a": 1, "b": [true, "x"]}"#)
+            .unwrap();
+        builder.append_null();
+        builder.append_variant(Variant::from("hello"));
+
+        // Invalid rows leave the builder unchanged
+        let err = builder.append_json_str("{\"a\": ").unwrap_err();
+        assert!(err.to_string().contains("JSON"), "{err}");
+        assert_eq!(builder.len(), 3);
+        builder.append_json_str("[1, 2, 3]").unwrap();
+
+        let array = builder.finish();
+        assert!(builder.is_empty());
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.null_count(), 1);
+        let object = array.value(0);
+        assert_eq!(object.get_object_field("a"), Some(Variant::from(1i8)));
+        assert_eq!(array.value(2), Variant::from("hello"));
+        assert_eq!(array.value(3).as_list().unwrap().len(), 3);
+
+        // All rows share a single data buffer for each of the metadata and values
+        assert_eq!(
+            array.metadata_field().as_binary_view().data_buffers().len(),
+            1
+        );
+        assert_eq!(array.value_field().as_binary_view().data_buffers().len(), 1);
+
+        // The builder can be reused after finish
+        builder.append_variant(Variant::from(1));
+        let array = builder.build();
+        assert_eq!(array.len(), 1);
+        assert_eq!(array.value(0), Variant::from(1));
+    }
 }
@@ -17,13 +17,24 @@
 use std::sync::Arc;
 
 use arrow::{
-    array::{Array, ArrayRef},
+    array::{
+        Array, ArrayBuilder, ArrayRef, BinaryBuilder, BinaryViewBuilder, BooleanBuilder,
+        LargeBinaryBuilder, LargeStringBuilder, PrimitiveBuilder, StringBuilder, StringViewBuilder,
+    },
     compute::CastOptions,
+    datatypes::{
+        ArrowPrimitiveType, DataType, Date32Type, Float32Type, Float64Type, Int16Type, Int32Type,
+        Int64Type, Int8Type, Time64MicrosecondType, TimeUnit, TimestampMicrosecondType,
+        TimestampNanosecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
     error::Result,
 };
 use arrow_schema::{ArrowError, Field};
+use chrono::Timelike;
 use parquet_variant::path::VariantPath;
+use parquet_variant::Variant;
 
+use crate::cast::variants_to_array;
 use crate::{VariantArray, VariantArrayBuilder};
 
 /// Returns an array with the specified path extracted from the variant values.
@@ -32,6 +43,36 @@ use crate::{VariantArray, VariantArrayBuilder};
 /// 1. `as_type: None`: a VariantArray is returned. The values in this new VariantArray will point
 ///    to the specified path.
 /// 2. `as_type: Some(<specific field>)`: an array of the specified type is returned.
+///
+/// When extracting a specific type, rows that are null, do not contain the path, or contain a
+/// variant null at the path are null in the output. Values that can not be converted to the
+/// requested type are also null if [`CastOptions::safe`] is true (the default), and result in
+/// an error otherwise.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, AsArray, StringArray};
+/// # use arrow::datatypes::{DataType, Int64Type};
+/// # use arrow_schema::Field;
+/// # use parquet_variant::path::VariantPath;
+/// # use parquet_variant_compute::batch_json_string_to_variant;
+/// # use parquet_variant_compute::variant_get::{variant_get, GetOptions};
+/// let input: ArrayRef = Arc::new(StringArray::from(vec![
+///     Some(r#"{"a": 1}"#),
+///     Some(r#"{"a": "one"}"#),
+///     Some(r#"{"b": 2}"#),
+///     None,
+/// ]));
+/// let input: ArrayRef = Arc::new(batch_json_string_to_variant(&input).unwrap());
+///
+/// let path = VariantPath::try_parse("$.a").unwrap();
+/// let options = GetOptions::new_with_path(path)
+///     .with_as_type(Some(Field::new("a", DataType::Int64, true)));
+/// let result = variant_get(&input, options).unwrap();
+/// let result = result.as_primitive::<Int64Type>();
+/// assert_eq!(result.iter().collect::<Vec<_>>(), vec![Some(1), None, None, None]);
+/// ```
 pub fn variant_get(input: &ArrayRef, options: GetOptions) -> Result<ArrayRef> {
     let variant_array: &VariantArray = input.as_any().downcast_ref().ok_or_else(|| {
         ArrowError::InvalidArgumentError(
@@ -39,11 +80,8 @@ pub fn variant_get(input: &ArrayRef, options: GetOptions) -> Result<ArrayRef> {
         )
     })?;
 
-    if let Some(as_type) = options.as_type {
-        return Err(ArrowError::NotYetImplemented(format!(
-            "getting a {} from a VariantArray is not implemented yet",
-            as_type
-        )));
+    if let Some(as_type) = &options.as_type {
+        return variant_get_typed(variant_array, &options, as_type.data_type());
     }
 
     let mut builder = VariantArrayBuilder::new(variant_array.len());
@@ -86,14 +124,268 @@ impl<'a> GetOptions<'a> {
     }
 }
 
+impl<'a> GetOptions<'a> {
+    /// Sets the type to extract the path as, see [`Self::as_type`]
+    pub fn with_as_type(mut self, as_type: Option<Field>) -> Self {
+        self.as_type = as_type;
+        self
+    }
+
+    /// Sets the [`CastOptions`] used when extracting a specific type
+    pub fn with_cast_options(mut self, cast_options: CastOptions<'a>) -> Self {
+        self.cast_options = cast_options;
+        self
+    }
+}
+
+/// Extracts the path of every row of `input` into an array of `data_type`
+fn variant_get_typed(
+    input: &VariantArray,
+    options: &GetOptions,
+    data_type: &DataType,
+) -> Result<ArrayRef> {
+    let values: Vec<_> = (0..input.len())
+        .map(|i| match input.is_null(i) {
+            true => None,
+            false => input.value(i).get_path(&options.path),
+        })
+        .collect();
+    variants_to_array(&values, &options.path, data_type, &options.cast_options)
+}
+
+/// Converts the primitive variant `values` into an array of `data_type`
+///
+/// Missing values and [`Variant::Null`] are null. `path` is the path of the values, used in
+/// error messages.
+pub(crate) fn variants_to_primitive(
+    values: &[Option<Variant>],
+    path: &VariantPath,
+    data_type: &DataType,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let len = values.len();
+    match data_type {
+        DataType::Boolean => extract_values(
+            values,
+            path,
+            cast_options,
+            data_type,
+            BooleanBuilder::with_capacity(len),
+            |b, v| v.as_boolean().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::Int8 => {
+            extract_primitive::<Int8Type>(values, path, cast_options, data_type, |v| v.as_int8())
+        }
+        DataType::Int16 => {
+            extract_primitive::<Int16Type>(values, path, cast_options, data_type, |v| v.as_int16())
+        }
+        DataType::Int32 => {
+            extract_primitive::<Int32Type>(values, path, cast_options, data_type, |v| v.as_int32())
+        }
+        DataType::Int64 => {
+            extract_primitive::<Int64Type>(values, path, cast_options, data_type, |v| v.as_int64())
+        }
+        DataType::UInt8 => {
+            extract_primitive::<UInt8Type>(values, path, cast_options, data_type, |v| {
+                v.as_int64().and_then(|v| v.try_into().ok())
+            })
+        }
+        DataType::UInt16 => {
+            extract_primitive::<UInt16Type>(values, path, cast_options, data_type, |v| {
+                v.as_int64().and_then(|v| v.try_into().ok())
+            })
+        }
+        DataType::UInt32 => {
+            extract_primitive::<UInt32Type>(values, path, cast_options, data_type, |v| {
+                v.as_int64().and_then(|v| v.try_into().ok())
+            })
+        }
+        DataType::UInt64 => {
+            extract_primitive::<UInt64Type>(values, path, cast_options, data_type, |v| {
+                v.as_int64().and_then(|v| v.try_into().ok())
+            })
+        }
+        DataType::Float32 => {
+            extract_primitive::<Float32Type>(values, path, cast_options, data_type, |v| {
+                // Integers are widened to floating point, as in a numeric cast
+                v.as_f32().or_else(|| v.as_int64().map(|i| i as f32))
+            })
+        }
+        DataType::Float64 => {
+            extract_primitive::<Float64Type>(values, path, cast_options, data_type, |v| {
+                v.as_f64().or_else(|| v.as_int64().map(|i| i as f64))
+            })
+        }
+        DataType::Date32 => {
+            extract_primitive::<Date32Type>(values, path, cast_options, data_type, |v| {
+                v.as_naive_date().map(Date32Type::from_naive_date)
+            })
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            extract_primitive::<Time64MicrosecondType>(values, path, cast_options, data_type, |v| {
+                v.as_naive_time().map(|t| {
+                    let seconds = t.num_seconds_from_midnight() as i64;
+                    seconds * 1_000_000 + (t.nanosecond() / 1_000) as i64
+                })
+            })
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => {
+            extract_primitive::<TimestampMicrosecondType>(
+                values,
+                path,
+                cast_options,
+                data_type,
+                |v| match tz {
+                    Some(_) => v.as_datetime_utc().map(|d| d.timestamp_micros()),
+                    None => v
+                        .as_naive_datetime()
+                        .map(|d| d.and_utc().timestamp_micros()),
+                },
+            )
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, tz) => {
+            extract_primitive::<TimestampNanosecondType>(
+                values,
+                path,
+                cast_options,
+                data_type,
+                |v| match tz {
+                    Some(_) => v.as_datetime_utc().and_then(|d| d.timestamp_nanos_opt()),
+                    None => v
+                        .as_naive_datetime()
+                        .and_then(|d| d.and_utc().timestamp_nanos_opt()),
+                },
+            )
+        }
+        DataType::Utf8 => extract_values(
+            values,
+            path,
+            cast_options,
+            data_type,
+            StringBuilder::with_capacity(len, 0),
+            |b, v| v.as_string().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::LargeUtf8 => extract_values(
+            values,
+            path,
+            cast_options,
+            data_type,
+            LargeStringBuilder::with_capacity(len, 0),
+            |b, v| v.as_string().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::Utf8View => extract_values(
+            values,
+            path,
+            cast_options,
+            data_type,
+            StringViewBuilder::with_capacity(len),
+            |b, v| v.as_string().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::Binary => extract_values(
+            values,
+            path,
+            cast_options,
+            data_type,
+            BinaryBuilder::with_capacity(len, 0),
+            |b, v| v.as_u8_slice().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::LargeBinary => extract_values(
+            values,
+            path,
+            cast_options,
+            data_type,
+            LargeBinaryBuilder::with_capacity(len, 0),
+            |b, v| v.as_u8_slice().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        DataType::BinaryView => extract_values(
+            values,
+            path,
+            cast_options,
+            data_type,
+            BinaryViewBuilder::with_capacity(len),
+            |b, v| v.as_u8_slice().map(|v| b.append_value(v)).is_some(),
+            |b| b.append_null(),
+        ),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "getting a {data_type} from a VariantArray is not implemented yet"
+        ))),
+    }
+}
+
+/// Converts `values` into a primitive array, converting each value with `convert`
+fn extract_primitive<T: ArrowPrimitiveType>(
+    values: &[Option<Variant>],
+    path: &VariantPath,
+    cast_options: &CastOptions,
+    data_type: &DataType,
+    convert: impl Fn(&Variant) -> Option<T::Native>,
+) -> Result<ArrayRef> {
+    let builder =
+        PrimitiveBuilder::<T>::with_capacity(values.len()).with_data_type(data_type.clone());
+    extract_values(
+        values,
+        path,
+        cast_options,
+        data_type,
+        builder,
+        |b, v| convert(v).map(|v| b.append_value(v)).is_some(),
+        |b| b.append_null(),
+    )
+}
+
+/// Converts `values` into `builder`
+///
+/// `append` appends a value to the builder, returning false if the value could not be
+/// converted to the type of the builder, in which case `append_null` is used instead, unless
+/// `cast_options` are not safe.
+fn extract_values<B: ArrayBuilder>(
+    values: &[Option<Variant>],
+    path: &VariantPath,
+    cast_options: &CastOptions,
+    data_type: &DataType,
+    mut builder: B,
+    append: impl Fn(&mut B, &Variant) -> bool,
+    append_null: impl Fn(&mut B),
+) -> Result<ArrayRef> {
+    for (i, value) in values.iter().enumerate() {
+        match value {
+            None | Some(Variant::Null) => append_null(&mut builder),
+            Some(value) => {
+                if !append(&mut builder, value) {
+                    if !cast_options.safe {
+                        return Err(ArrowError::CastError(format!(
+                            "Failed to extract {data_type} from variant {value:?} at path {path} in row {i}"
+                        )));
+                    }
+                    append_null(&mut builder);
+                }
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
 
-    use arrow::array::{Array, ArrayRef, StringArray};
+    use arrow::array::{
+        Array, ArrayRef, AsArray, BooleanArray, Date32Array, Float64Array, Int64Array, StringArray,
+        TimestampMicrosecondArray, UInt8Array,
+    };
+    use arrow::compute::CastOptions;
+    use arrow::datatypes::{DataType, TimeUnit};
+    use arrow_schema::Field;
     use parquet_variant::path::{VariantPath, VariantPathElement};
 
     use crate::batch_json_string_to_variant;
+    use crate::test_util::variant_array;
     use crate::VariantArray;
 
     use super::{variant_get, GetOptions};
@@ -194,4 +486,98 @@ mod test {
             r#"{"inner_field": 1234}"#,
         );
     }
+
+    fn typed_options(path: &str, as_type: DataType) -> GetOptions<'_> {
+        GetOptions::new_with_path(VariantPath::try_parse(path).unwrap())
+            .with_as_type(Some(Field::new("result", as_type, true)))
+    }
+
+    #[test]
+    fn get_typed_primitives() {
+        let input: ArrayRef = Arc::new(variant_array(&[
+            Some(r#"{"a": 1, "b": true, "c": 1.5, "d": "x"}"#),
+            Some(r#"{"a": 300, "b": false, "c": 2, "d": "yy"}"#),
+            Some(r#"{"a": "1", "b": 1, "c": "2", "d": 3}"#),
+            Some(r#"{"a": null, "b": null, "c": null, "d": null}"#),
+            Some("{}"),
+            None,
+        ]));
+
+        let result = variant_get(&input, typed_options("$.a", DataType::Int64)).unwrap();
+        let expected = Int64Array::from(vec![Some(1), Some(300), None, None, None, None]);
+        assert_eq!(result.as_primitive(), &expected);
+
+        // Values that do not fit in the type are null
+        let result = variant_get(&input, typed_options("$.a", DataType::UInt8)).unwrap();
+        let expected = UInt8Array::from(vec![Some(1), None, None, None, None, None]);
+        assert_eq!(result.as_primitive(), &expected);
+
+        let result = variant_get(&input, typed_options("$.b", DataType::Boolean)).unwrap();
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None, None, None, None]);
+        assert_eq!(result.as_boolean(), &expected);
+
+        let result = variant_get(&input, typed_options("$.c", DataType::Float64)).unwrap();
+        let expected = Float64Array::from(vec![Some(1.5), Some(2.0), None, None, None, None]);
+        assert_eq!(result.as_primitive(), &expected);
+
+        for data_type in [DataType::Utf8, DataType::LargeUtf8, DataType::Utf8View] {
+            let result = variant_get(&input, typed_options("$.d", data_type.clone())).unwrap();
+            assert_eq!(result.data_type(), &data_type);
+            let result = arrow::compute::cast(&result, &DataType::Utf8).unwrap();
+            let expected = StringArray::from(vec![Some("x"), Some("yy"), None, None, None, None]);
+            assert_eq!(result.as_string::<i32>(), &expected);
+        }
+    }
+
+    #[test]
+    fn get_typed_temporal() {
+        let mut builder = crate::VariantArrayBuilder::new(2);
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 1, 2).unwrap();
+        builder.append_variant(date.into());
+        builder.append_variant(date.and_hms_opt(1, 2, 3).unwrap().and_utc().into());
+        let input: ArrayRef = Arc::new(builder.build());
+
+        let result = variant_get(&input, typed_options("$", DataType::Date32)).unwrap();
+        let expected = Date32Array::from(vec![Some(20090), None]);
+        assert_eq!(result.as_primitive(), &expected);
+
+        let data_type = DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()));
+        let result = variant_get(&input, typed_options("$", data_type)).unwrap();
+        let expected = TimestampMicrosecondArray::from(vec![None, Some(1_735_779_723_000_000)])
+            .with_timezone("UTC");
+        assert_eq!(result.as_primitive(), &expected);
+    }
+
+    #[test]
+    fn get_typed_error_on_mismatch() {
+        let input: ArrayRef = Arc::new(variant_array(&[
+            Some(r#"{"a": 1}"#),
+            Some(r#"{"a": "one"}"#),
+        ]));
+        let options = typed_options("$.a", DataType::Int64)
+            .with_cast_options(CastOptions::default().with_safe(false));
+        let err = variant_get(&input, options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cast error: Failed to extract Int64 from variant ShortString(ShortString(\"one\")) \
+             at path $.a in row 1"
+        );
+
+        // Missing paths and nulls are not errors
+        let input: ArrayRef = Arc::new(variant_array(&[Some(r#"{"a": null}"#), Some("{}"), None]));
+        let options = typed_options("$.a", DataType::Int64)
+            .with_cast_options(CastOptions::default().with_safe(false));
+        let result = variant_get(&input, options).unwrap();
+        assert_eq!(result.null_count(), 3);
+    }
+
+    #[test]
+    fn get_typed_unsupported() {
+        let input: ArrayRef = Arc::new(variant_array(&[Some("1")]));
+        let err = variant_get(&input, typed_options("$", DataType::Float16)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Not yet implemented: getting a Float16 from a VariantArray is not implemented yet"
+        );
+    }
 }
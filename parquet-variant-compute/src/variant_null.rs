@@ -0,0 +1,214 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for null-aware kernels on Variants
+//!
+//! A row of a [`VariantArray`] can be null in two distinct ways: the row itself can be null,
+//! i.e. SQL `NULL`, or it can contain the variant value [`Variant::Null`], e.g. the JSON
+//! `null`. The kernels in this module allow engines to tell them apart.
+
+use std::borrow::Cow;
+
+use crate::{unshred_variant, VariantArray, VariantArrayBuilder};
+use arrow::array::{Array, BooleanArray};
+use arrow::buffer::BooleanBuffer;
+use arrow_schema::ArrowError;
+use parquet_variant::Variant;
+
+/// The kind of null tested by [`variant_is_null`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantNullMode {
+    /// Only null rows, i.e. SQL `NULL`, are null
+    Sql,
+    /// Only non-null rows containing [`Variant::Null`] are null
+    Variant,
+    /// Both null rows and rows containing [`Variant::Null`] are null
+    Any,
+}
+
+/// Returns whether each row of a [`VariantArray`] is null, according to `mode`
+///
+/// Like SQL `IS NULL`, the result never contains nulls.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::{variant_is_null, VariantArrayBuilder, VariantNullMode};
+/// let mut builder = VariantArrayBuilder::new(3);
+/// builder.append_variant(Variant::from(1));
+/// builder.append_null();
+/// builder.append_variant(Variant::Null);
+/// let array = builder.build();
+///
+/// let is_null = variant_is_null(&array, VariantNullMode::Sql);
+/// assert_eq!(is_null.values().iter().collect::<Vec<_>>(), [false, true, false]);
+/// let is_null = variant_is_null(&array, VariantNullMode::Variant);
+/// assert_eq!(is_null.values().iter().collect::<Vec<_>>(), [false, false, true]);
+/// let is_null = variant_is_null(&array, VariantNullMode::Any);
+/// assert_eq!(is_null.values().iter().collect::<Vec<_>>(), [false, true, true]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if a non-null row contains an invalid variant
+pub fn variant_is_null(input: &VariantArray, mode: VariantNullMode) -> BooleanArray {
+    // A shredded row is only Variant::Null if its residual value is, so the typed value
+    // does not need to be read
+    let values = input.value_field();
+    let is_variant_null = |i: usize| {
+        input.is_valid(i) && values.is_valid(i) && matches!(input.value(i), Variant::Null)
+    };
+    let is_null = (0..input.len()).map(|i| match mode {
+        VariantNullMode::Sql => input.is_null(i),
+        VariantNullMode::Variant => is_variant_null(i),
+        VariantNullMode::Any => input.is_null(i) || is_variant_null(i),
+    });
+    BooleanArray::new(BooleanBuffer::from_iter(is_null), None)
+}
+
+/// Returns the first non-null row of `inputs` for each row, like SQL `COALESCE`
+///
+/// Only null rows, i.e. SQL `NULL`, are skipped: [`Variant::Null`] is a value, and is
+/// returned if it is the first non-null row. A row of the result is null if the row is null
+/// in every input. Shredded inputs are [unshredded] first.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::Variant;
+/// # use arrow::array::Array;
+/// # use parquet_variant_compute::{variant_coalesce, VariantArrayBuilder};
+/// let mut a = VariantArrayBuilder::new(3);
+/// a.append_null();
+/// a.append_variant(Variant::Null);
+/// a.append_null();
+/// let a = a.build();
+///
+/// let mut b = VariantArrayBuilder::new(3);
+/// b.append_variant(Variant::from(1));
+/// b.append_variant(Variant::from(2));
+/// b.append_null();
+/// let b = b.build();
+///
+/// let result = variant_coalesce(&[&a, &b]).unwrap();
+/// assert_eq!(result.value(0), Variant::from(1));
+/// assert_eq!(result.value(1), Variant::Null);
+/// assert!(result.is_null(2));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `inputs` is empty, or if the inputs have different lengths
+///
+/// [unshredded]: crate::unshred_variant
+pub fn variant_coalesce(inputs: &[&VariantArray]) -> Result<VariantArray, ArrowError> {
+    let Some(first) = inputs.first() else {
+        return Err(ArrowError::InvalidArgumentError(
+            "variant_coalesce requires at least one input".to_string(),
+        ));
+    };
+    let len = first.len();
+    if let Some(input) = inputs.iter().find(|input| input.len() != len) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "variant_coalesce requires inputs of the same length, got {} and {}",
+            len,
+            input.len()
+        )));
+    }
+
+    let inputs = inputs
+        .iter()
+        .map(|input| match input.typed_value_field() {
+            Some(_) => unshred_variant(input).map(Cow::Owned),
+            None => Ok(Cow::Borrowed(*input)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut builder = VariantArrayBuilder::new(len);
+    for i in 0..len {
+        match inputs.iter().find(|input| input.is_valid(i)) {
+            Some(input) => builder.try_append_variant(input.value(i))?,
+            None => builder.append_null(),
+        }
+    }
+    Ok(builder.build())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shred_variant;
+    use crate::test_util::variant_array;
+    use arrow_schema::DataType;
+
+    #[test]
+    fn test_variant_is_null_shredded() {
+        let array = variant_array(&[Some("1"), None, Some("null"), Some(r#""a""#)]);
+        let shredded = shred_variant(&array, &DataType::Int64).unwrap();
+        assert!(shredded.typed_value_field().is_some());
+
+        for input in [&array, &shredded] {
+            let is_null = |mode| {
+                let result = variant_is_null(input, mode);
+                assert_eq!(result.null_count(), 0);
+                result.values().iter().collect::<Vec<_>>()
+            };
+            assert_eq!(is_null(VariantNullMode::Sql), [false, true, false, false]);
+            assert_eq!(
+                is_null(VariantNullMode::Variant),
+                [false, false, true, false]
+            );
+            assert_eq!(is_null(VariantNullMode::Any), [false, true, true, false]);
+        }
+    }
+
+    #[test]
+    fn test_variant_coalesce() {
+        let a = variant_array(&[None, Some("null"), None, None]);
+        let b = variant_array(&[Some("1"), Some("2"), None, None]);
+        let c = variant_array(&[None, None, Some(r#""c""#), None]);
+        let c = shred_variant(&c, &DataType::Utf8).unwrap();
+
+        let result = variant_coalesce(&[&a, &b, &c]).unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(result.value(0), Variant::from(1i8));
+        assert_eq!(result.value(1), Variant::Null);
+        assert_eq!(result.value(2), Variant::from("c"));
+        assert!(result.is_null(3));
+        assert_eq!(result.null_count(), 1);
+
+        let result = variant_coalesce(&[&b]).unwrap();
+        assert_eq!(result.field("v"), b.field("v"));
+        assert!(result.is_null(2));
+    }
+
+    #[test]
+    fn test_variant_coalesce_invalid() {
+        let err = variant_coalesce(&[]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: variant_coalesce requires at least one input"
+        );
+
+        let a = variant_array(&[None]);
+        let b = variant_array(&[None, None]);
+        let err = variant_coalesce(&[&a, &b]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: variant_coalesce requires inputs of the same length, got 1 and 2"
+        );
+    }
+}
@@ -0,0 +1,519 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module for collecting statistics of the paths of Variants, see [`VariantStatsBuilder`]
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::hash::{BuildHasher, BuildHasherDefault};
+
+use arrow::array::Array;
+use arrow_schema::ArrowError;
+use parquet_variant::path::{VariantPath, VariantPathElement};
+use parquet_variant::{Variant, VariantBuilder};
+
+use crate::{unshred_variant, VariantArray};
+
+/// The kind of a [`Variant`] value, as counted by [`VariantPathStats::type_counts`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VariantKind {
+    /// [`Variant::Null`]
+    Null,
+    /// [`Variant::BooleanTrue`] and [`Variant::BooleanFalse`]
+    Boolean,
+    /// [`Variant::Int8`]
+    Int8,
+    /// [`Variant::Int16`]
+    Int16,
+    /// [`Variant::Int32`]
+    Int32,
+    /// [`Variant::Int64`]
+    Int64,
+    /// [`Variant::Float`]
+    Float,
+    /// [`Variant::Double`]
+    Double,
+    /// [`Variant::Decimal4`]
+    Decimal4,
+    /// [`Variant::Decimal8`]
+    Decimal8,
+    /// [`Variant::Decimal16`]
+    Decimal16,
+    /// [`Variant::Date`]
+    Date,
+    /// [`Variant::Time`]
+    Time,
+    /// [`Variant::TimestampMicros`]
+    TimestampMicros,
+    /// [`Variant::TimestampNtzMicros`]
+    TimestampNtzMicros,
+    /// [`Variant::TimestampNanos`]
+    TimestampNanos,
+    /// [`Variant::TimestampNtzNanos`]
+    TimestampNtzNanos,
+    /// [`Variant::Binary`]
+    Binary,
+    /// [`Variant::String`] and [`Variant::ShortString`]
+    String,
+    /// [`Variant::Uuid`]
+    Uuid,
+    /// [`Variant::Object`]
+    Object,
+    /// [`Variant::List`]
+    List,
+}
+
+impl VariantKind {
+    /// Returns the kind of `variant`
+    pub fn of(variant: &Variant) -> Self {
+        match variant {
+            Variant::Null => Self::Null,
+            Variant::BooleanTrue | Variant::BooleanFalse => Self::Boolean,
+            Variant::Int8(_) => Self::Int8,
+            Variant::Int16(_) => Self::Int16,
+            Variant::Int32(_) => Self::Int32,
+            Variant::Int64(_) => Self::Int64,
+            Variant::Float(_) => Self::Float,
+            Variant::Double(_) => Self::Double,
+            Variant::Decimal4(_) => Self::Decimal4,
+            Variant::Decimal8(_) => Self::Decimal8,
+            Variant::Decimal16(_) => Self::Decimal16,
+            Variant::Date(_) => Self::Date,
+            Variant::Time(_) => Self::Time,
+            Variant::TimestampMicros(_) => Self::TimestampMicros,
+            Variant::TimestampNtzMicros(_) => Self::TimestampNtzMicros,
+            Variant::TimestampNanos(_) => Self::TimestampNanos,
+            Variant::TimestampNtzNanos(_) => Self::TimestampNtzNanos,
+            Variant::Binary(_) => Self::Binary,
+            Variant::String(_) | Variant::ShortString(_) => Self::String,
+            Variant::Uuid(_) => Self::Uuid,
+            Variant::Object(_) => Self::Object,
+            Variant::List(_) => Self::List,
+        }
+    }
+}
+
+/// A primitive [`Variant`] value owned by [`VariantPathStats`]
+#[derive(Debug, Clone)]
+struct OwnedVariant {
+    metadata: Vec<u8>,
+    value: Vec<u8>,
+}
+
+impl OwnedVariant {
+    fn new(variant: &Variant) -> Self {
+        let mut builder = VariantBuilder::new();
+        builder.append_value(variant.clone());
+        let (metadata, value) = builder.finish();
+        Self { metadata, value }
+    }
+
+    fn as_variant(&self) -> Variant<'_, '_> {
+        Variant::new(&self.metadata, &self.value)
+    }
+}
+
+/// Statistics of the values at a path of a [`VariantArray`], see [`VariantStatsBuilder`]
+///
+/// The statistics of the fields of objects at the path are in [`Self::fields`], and those of
+/// the elements of lists at the path are in [`Self::elements`].
+#[derive(Debug, Clone, Default)]
+pub struct VariantPathStats {
+    count: usize,
+    types: BTreeMap<VariantKind, usize>,
+    min: Option<OwnedVariant>,
+    max: Option<OwnedVariant>,
+    /// Whether all primitive values so far were comparable, see [`Self::min`]
+    incomparable: bool,
+    /// The hashes of the distinct primitive values, or `None` if there were too many
+    distinct: Option<HashSet<u64>>,
+    fields: BTreeMap<String, VariantPathStats>,
+    elements: Option<Box<VariantPathStats>>,
+}
+
+impl VariantPathStats {
+    /// Returns the number of values at this path, including [`Variant::Null`]
+    ///
+    /// For the fields of objects this is the number of objects containing the field, and for
+    /// list elements the total number of elements.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of [`Variant::Null`] values at this path
+    pub fn null_count(&self) -> usize {
+        self.type_count(VariantKind::Null)
+    }
+
+    /// Returns the number of values of each [`VariantKind`] at this path
+    pub fn type_counts(&self) -> &BTreeMap<VariantKind, usize> {
+        &self.types
+    }
+
+    /// Returns the number of values of `kind` at this path
+    pub fn type_count(&self, kind: VariantKind) -> usize {
+        self.types.get(&kind).copied().unwrap_or_default()
+    }
+
+    /// Returns the minimum primitive value at this path
+    ///
+    /// Values are compared with [`Variant::partial_cmp_logical`]. Returns `None` if there are
+    /// no primitive values other than [`Variant::Null`], or if some of them are not
+    /// comparable, e.g. strings and numbers.
+    pub fn min(&self) -> Option<Variant<'_, '_>> {
+        self.min.as_ref().map(OwnedVariant::as_variant)
+    }
+
+    /// Returns the maximum primitive value at this path, see [`Self::min`]
+    pub fn max(&self) -> Option<Variant<'_, '_>> {
+        self.max.as_ref().map(OwnedVariant::as_variant)
+    }
+
+    /// Returns the number of distinct primitive values at this path, other than
+    /// [`Variant::Null`], or `None` if it exceeds
+    /// [`VariantStatsBuilder::with_max_distinct_count`]
+    ///
+    /// Logically equal values, such as `1` and `1.0`, are counted once. The count is based on
+    /// hashes of the values, and may therefore in rare cases be too low.
+    pub fn distinct_count(&self) -> Option<usize> {
+        self.distinct.as_ref().map(HashSet::len)
+    }
+
+    /// Returns the statistics of the fields of the objects at this path, by field name
+    pub fn fields(&self) -> &BTreeMap<String, VariantPathStats> {
+        &self.fields
+    }
+
+    /// Returns the statistics of the field `name` of the objects at this path
+    pub fn field(&self, name: &str) -> Option<&VariantPathStats> {
+        self.fields.get(name)
+    }
+
+    /// Returns the statistics of the elements of the lists at this path
+    pub fn elements(&self) -> Option<&VariantPathStats> {
+        self.elements.as_deref()
+    }
+
+    fn append(&mut self, variant: &Variant, max_distinct: usize) {
+        self.count += 1;
+        *self.types.entry(VariantKind::of(variant)).or_default() += 1;
+        match variant {
+            Variant::Null => {}
+            Variant::Object(object) => {
+                for (name, value) in object.iter() {
+                    let field = match self.fields.get_mut(name) {
+                        Some(field) => field,
+                        None => self.fields.entry(name.to_string()).or_insert_with(|| {
+                            VariantPathStats {
+                                distinct: Some(HashSet::new()),
+                                ..Default::default()
+                            }
+                        }),
+                    };
+                    field.append(&value, max_distinct);
+                }
+            }
+            Variant::List(list) => {
+                let elements = self.elements.get_or_insert_with(|| {
+                    Box::new(VariantPathStats {
+                        distinct: Some(HashSet::new()),
+                        ..Default::default()
+                    })
+                });
+                list.iter()
+                    .for_each(|element| elements.append(&element, max_distinct));
+            }
+            primitive => {
+                self.append_primitive(primitive, max_distinct);
+            }
+        }
+    }
+
+    fn append_primitive(&mut self, variant: &Variant, max_distinct: usize) {
+        if let Some(distinct) = &mut self.distinct {
+            distinct.insert(BuildHasherDefault::<DefaultHasher>::default().hash_one(variant));
+            if distinct.len() > max_distinct {
+                self.distinct = None;
+            }
+        }
+
+        if self.incomparable {
+            return;
+        }
+        let (Some(min), Some(max)) = (&self.min, &self.max) else {
+            self.min = Some(OwnedVariant::new(variant));
+            self.max = self.min.clone();
+            return;
+        };
+        let min_ordering = variant.partial_cmp_logical(&min.as_variant());
+        let max_ordering = variant.partial_cmp_logical(&max.as_variant());
+        match (min_ordering, max_ordering) {
+            (Some(Ordering::Less), _) => self.min = Some(OwnedVariant::new(variant)),
+            (_, Some(Ordering::Greater)) => self.max = Some(OwnedVariant::new(variant)),
+            (Some(_), Some(_)) => {}
+            _ => {
+                self.incomparable = true;
+                self.min = None;
+                self.max = None;
+            }
+        }
+    }
+}
+
+/// Collects per-path statistics of the rows of [`VariantArray`]s
+///
+/// For the top-level values, the fields of objects and the elements of lists, recursively,
+/// the builder collects the number of values, a histogram of their [`VariantKind`]s and, for
+/// primitive values, their minimum, maximum and number of distinct values. Writers can use
+/// these statistics to choose which paths to shred, and as which type.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::path::VariantPath;
+/// # use parquet_variant::Variant;
+/// # use parquet_variant_compute::{VariantArrayBuilder, VariantKind, VariantStatsBuilder};
+/// let mut builder = VariantArrayBuilder::new(3);
+/// builder.append_json_str(r#"{"id": 1, "tags": ["a", "b"]}"#).unwrap();
+/// builder.append_json_str(r#"{"id": 5, "tags": ["a"]}"#).unwrap();
+/// builder.append_json_str(r#"{"id": "x"}"#).unwrap();
+/// let array = builder.build();
+///
+/// let mut stats = VariantStatsBuilder::new();
+/// stats.append_array(&array).unwrap();
+/// let stats = stats.finish();
+/// assert_eq!(stats.num_rows(), 3);
+///
+/// let id = stats.get(&VariantPath::try_parse("$.id").unwrap()).unwrap();
+/// assert_eq!(id.count(), 3);
+/// assert_eq!(id.type_count(VariantKind::Int8), 2);
+/// assert_eq!(id.type_count(VariantKind::String), 1);
+/// // Strings and numbers are not comparable
+/// assert_eq!(id.min(), None);
+///
+/// let tags = stats.get(&VariantPath::try_parse("$.tags[0]").unwrap()).unwrap();
+/// assert_eq!(tags.count(), 3);
+/// assert_eq!(tags.distinct_count(), Some(2));
+/// assert_eq!(tags.min(), Some(Variant::from("a")));
+/// assert_eq!(tags.max(), Some(Variant::from("b")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VariantStatsBuilder {
+    max_distinct: usize,
+    stats: VariantStats,
+}
+
+impl Default for VariantStatsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VariantStatsBuilder {
+    /// Creates a new builder
+    pub fn new() -> Self {
+        Self {
+            max_distinct: 1024,
+            stats: VariantStats {
+                num_rows: 0,
+                null_count: 0,
+                root: VariantPathStats {
+                    distinct: Some(HashSet::new()),
+                    ..Default::default()
+                },
+            },
+        }
+    }
+
+    /// Sets the maximum number of distinct values counted per path, defaults to 1024
+    ///
+    /// [`VariantPathStats::distinct_count`] is `None` for paths with more distinct values.
+    pub fn with_max_distinct_count(mut self, max_distinct: usize) -> Self {
+        self.max_distinct = max_distinct;
+        self
+    }
+
+    /// Appends the rows of `array`, which are [unshredded] first if `array` is shredded
+    ///
+    /// [unshredded]: crate::unshred_variant
+    pub fn append_array(&mut self, array: &VariantArray) -> Result<(), ArrowError> {
+        if array.typed_value_field().is_some() {
+            return self.append_array(&unshred_variant(array)?);
+        }
+        for i in 0..array.len() {
+            match array.is_null(i) {
+                true => self.append_null(),
+                false => self.append_variant(&array.value(i)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends a row containing `variant`
+    pub fn append_variant(&mut self, variant: &Variant) {
+        self.stats.num_rows += 1;
+        self.stats.root.append(variant, self.max_distinct);
+    }
+
+    /// Appends a null row
+    pub fn append_null(&mut self) {
+        self.stats.num_rows += 1;
+        self.stats.null_count += 1;
+    }
+
+    /// Returns the statistics of the rows appended so far
+    pub fn finish(self) -> VariantStats {
+        self.stats
+    }
+}
+
+/// Statistics of the rows of [`VariantArray`]s, see [`VariantStatsBuilder`]
+#[derive(Debug, Clone)]
+pub struct VariantStats {
+    num_rows: usize,
+    null_count: usize,
+    root: VariantPathStats,
+}
+
+impl VariantStats {
+    /// Returns the number of rows, including null rows
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the number of null rows, which unlike [`Variant::Null`] values are not counted
+    /// by the statistics of any path
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// Returns the statistics of the top-level values
+    pub fn root(&self) -> &VariantPathStats {
+        &self.root
+    }
+
+    /// Returns the statistics of the values at `path`, or `None` if there are none
+    ///
+    /// Index elements of the path refer to all elements of the lists, regardless of the
+    /// index.
+    pub fn get(&self, path: &VariantPath) -> Option<&VariantPathStats> {
+        path.iter()
+            .try_fold(&self.root, |stats, element| match element {
+                VariantPathElement::Field { name } => stats.field(name),
+                VariantPathElement::Index { .. } => stats.elements(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shred_variant;
+    use crate::test_util::variant_array;
+    use arrow_schema::{DataType, Field};
+
+    fn stats(values: &[Option<&str>]) -> VariantStats {
+        let mut stats = VariantStatsBuilder::new();
+        stats.append_array(&variant_array(values)).unwrap();
+        stats.finish()
+    }
+
+    fn path(path: &str) -> VariantPath<'_> {
+        VariantPath::try_parse(path).unwrap()
+    }
+
+    #[test]
+    fn test_variant_stats() {
+        let stats = stats(&[
+            Some(r#"{"a": 1, "b": {"c": "x"}}"#),
+            Some(r#"{"a": 2.5, "b": {"c": null}}"#),
+            Some(r#"{"a": null, "d": [1, [2]]}"#),
+            None,
+            Some("3"),
+        ]);
+        assert_eq!(stats.num_rows(), 5);
+        assert_eq!(stats.null_count(), 1);
+
+        let root = stats.root();
+        assert_eq!(root.count(), 4);
+        assert_eq!(root.type_count(VariantKind::Object), 3);
+        assert_eq!(root.type_count(VariantKind::Int8), 1);
+        assert_eq!(root.min(), Some(Variant::from(3i8)));
+        assert_eq!(root.fields().keys().collect::<Vec<_>>(), ["a", "b", "d"]);
+
+        let a = stats.get(&path("$.a")).unwrap();
+        assert_eq!(a.count(), 3);
+        assert_eq!(a.null_count(), 1);
+        assert_eq!(a.min(), Some(Variant::from(1i8)));
+        assert_eq!(a.max(), Some(Variant::from(2.5)));
+        assert_eq!(a.distinct_count(), Some(2));
+        assert!(a.fields().is_empty() && a.elements().is_none());
+
+        let c = stats.get(&path("$.b.c")).unwrap();
+        assert_eq!(c.count(), 2);
+        assert_eq!(c.null_count(), 1);
+        assert_eq!(c.min(), Some(Variant::from("x")));
+
+        let elements = stats.get(&path("$.d[0]")).unwrap();
+        assert_eq!(elements.count(), 2);
+        assert_eq!(elements.type_count(VariantKind::List), 1);
+        assert_eq!(elements.min(), Some(Variant::from(1i8)));
+        let nested = stats.get(&path("$.d[1][0]")).unwrap();
+        assert_eq!(nested.max(), Some(Variant::from(2i8)));
+
+        assert!(stats.get(&path("$.missing")).is_none());
+        assert!(stats.get(&path("$.a[0]")).is_none());
+    }
+
+    #[test]
+    fn test_variant_stats_distinct_count() {
+        let mut builder = VariantStatsBuilder::new().with_max_distinct_count(3);
+        for v in [1, 2, 1, 3] {
+            builder.append_variant(&Variant::from(v));
+        }
+        builder.append_variant(&Variant::from(1.0));
+        assert_eq!(builder.clone().finish().root().distinct_count(), Some(3));
+        builder.append_variant(&Variant::from(4));
+        let stats = builder.finish();
+        assert_eq!(stats.root().distinct_count(), None);
+        assert_eq!(stats.root().min(), Some(Variant::from(1)));
+        assert_eq!(stats.root().max(), Some(Variant::from(4)));
+    }
+
+    #[test]
+    fn test_variant_stats_shredded() {
+        let array = variant_array(&[Some(r#"{"a": 1}"#), Some(r#"{"a": "x", "b": true}"#), None]);
+        let as_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let shredded = shred_variant(&array, &as_type).unwrap();
+
+        let mut stats = VariantStatsBuilder::new();
+        stats.append_array(&shredded).unwrap();
+        let stats = stats.finish();
+        assert_eq!(stats.num_rows(), 3);
+        assert_eq!(stats.null_count(), 1);
+        let a = stats.get(&path("a")).unwrap();
+        assert_eq!(a.type_count(VariantKind::Int64), 1);
+        assert_eq!(a.type_count(VariantKind::String), 1);
+        assert_eq!(
+            stats
+                .get(&path("b"))
+                .unwrap()
+                .type_count(VariantKind::Boolean),
+            1
+        );
+    }
+}
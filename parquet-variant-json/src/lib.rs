@@ -35,4 +35,7 @@ mod from_json;
 mod to_json;
 
 pub use from_json::json_to_variant;
-pub use to_json::{variant_to_json, variant_to_json_string, variant_to_json_value};
+pub use to_json::{
+    variant_to_json, variant_to_json_string, variant_to_json_value, variant_to_json_with_options,
+    VariantToJsonOptions,
+};
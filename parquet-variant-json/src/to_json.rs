@@ -26,6 +26,8 @@ use parquet_variant::{Variant, VariantList, VariantObject};
 // Format string constants to avoid duplication and reduce errors
 const DATE_FORMAT: &str = "%Y-%m-%d";
 const TIMESTAMP_NTZ_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.6f";
+const TIMESTAMP_NTZ_NANOS_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.9f";
+const TIME_FORMAT: &str = "%H:%M:%S%.6f";
 
 // Helper functions for consistent formatting
 fn format_date_string(date: &chrono::NaiveDate) -> String {
@@ -36,10 +38,128 @@ fn format_timestamp_ntz_string(ts: &chrono::NaiveDateTime) -> String {
     ts.format(TIMESTAMP_NTZ_FORMAT).to_string()
 }
 
+fn format_time_string(time: &chrono::NaiveTime) -> String {
+    time.format(TIME_FORMAT).to_string()
+}
+
+fn format_timestamp_nanos_string(ts: &chrono::DateTime<chrono::Utc>) -> String {
+    ts.to_rfc3339_opts(chrono::SecondsFormat::Nanos, false)
+}
+
+fn format_timestamp_ntz_nanos_string(ts: &chrono::NaiveDateTime) -> String {
+    ts.format(TIMESTAMP_NTZ_NANOS_FORMAT).to_string()
+}
+
 fn format_binary_base64(bytes: &[u8]) -> String {
     general_purpose::STANDARD.encode(bytes)
 }
 
+/// Formats UUID bytes in the canonical hyphenated form, e.g. `67e55044-10b1-426f-9247-bb680e5fe0c8`
+fn format_uuid_string(bytes: &[u8; 16]) -> String {
+    let mut s = String::with_capacity(36);
+    for (i, b) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            s.push('-');
+        }
+        s.push_str(&format!("{b:02x}"));
+    }
+    s
+}
+
+/// Options for [`variant_to_json_with_options`]
+///
+/// By default timestamps are written as RFC 3339 strings, and decimals as JSON numbers.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, VariantDecimal8};
+/// # use parquet_variant_json::{variant_to_json_with_options, VariantToJsonOptions};
+/// # use chrono::NaiveDate;
+/// let options = VariantToJsonOptions::new()
+///     .with_timestamp_ntz_format("%Y/%m/%d %H:%M")
+///     .with_decimal_as_string(true);
+///
+/// let ts = NaiveDate::from_ymd_opt(2025, 1, 2).unwrap().and_hms_opt(3, 4, 5).unwrap();
+/// let mut json = Vec::new();
+/// variant_to_json_with_options(&mut json, &Variant::TimestampNtzMicros(ts), &options).unwrap();
+/// assert_eq!(json, br#""2025/01/02 03:04""#);
+///
+/// let decimal = VariantDecimal8::try_new(123456789, 2).unwrap();
+/// let mut json = Vec::new();
+/// variant_to_json_with_options(&mut json, &decimal.into(), &options).unwrap();
+/// assert_eq!(json, br#""1234567.89""#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VariantToJsonOptions {
+    timestamp_format: Option<String>,
+    timestamp_ntz_format: Option<String>,
+    decimal_as_string: bool,
+}
+
+impl VariantToJsonOptions {
+    /// Create new options with the default formatting
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [chrono format string] for timestamps with a time zone, which are in UTC
+    ///
+    /// [chrono format string]: chrono::format::strftime
+    pub fn with_timestamp_format(mut self, format: impl Into<String>) -> Self {
+        self.timestamp_format = Some(format.into());
+        self
+    }
+
+    /// Set the [chrono format string] for timestamps without a time zone
+    ///
+    /// [chrono format string]: chrono::format::strftime
+    pub fn with_timestamp_ntz_format(mut self, format: impl Into<String>) -> Self {
+        self.timestamp_ntz_format = Some(format.into());
+        self
+    }
+
+    /// Write decimals as JSON strings rather than numbers
+    ///
+    /// Many JSON parsers read numbers as 64 bit floats, which can not represent all
+    /// decimals exactly.
+    pub fn with_decimal_as_string(mut self, decimal_as_string: bool) -> Self {
+        self.decimal_as_string = decimal_as_string;
+        self
+    }
+
+    /// Returns the format string for timestamps with a time zone, if any
+    pub fn timestamp_format(&self) -> Option<&str> {
+        self.timestamp_format.as_deref()
+    }
+
+    /// Returns the format string for timestamps without a time zone, if any
+    pub fn timestamp_ntz_format(&self) -> Option<&str> {
+        self.timestamp_ntz_format.as_deref()
+    }
+
+    /// Returns true if decimals are written as JSON strings
+    pub fn decimal_as_string(&self) -> bool {
+        self.decimal_as_string
+    }
+}
+
+/// Writes `value` formatted with the chrono `format` string as a JSON string
+fn write_formatted<'a>(
+    buffer: &mut impl Write,
+    value: chrono::format::DelayedFormat<chrono::format::StrftimeItems<'a>>,
+    format: &'a str,
+) -> Result<(), ArrowError> {
+    use std::fmt::Write as _;
+    let mut formatted = String::new();
+    write!(formatted, "{value}").map_err(|_| {
+        ArrowError::InvalidArgumentError(format!("Invalid timestamp format '{format}'"))
+    })?;
+    let json_str = serde_json::to_string(&formatted)
+        .map_err(|e| ArrowError::InvalidArgumentError(format!("JSON encoding error: {e}")))?;
+    write!(buffer, "{json_str}")?;
+    Ok(())
+}
+
 ///
 /// This function writes JSON directly to any type that implements [`Write`],
 /// making it efficient for streaming or when you want to control the output destination.
@@ -92,6 +212,16 @@ fn format_binary_base64(bytes: &[u8]) -> String {
 /// # Ok::<(), ArrowError>(())
 /// ```
 pub fn variant_to_json(json_buffer: &mut impl Write, variant: &Variant) -> Result<(), ArrowError> {
+    variant_to_json_with_options(json_buffer, variant, &VariantToJsonOptions::default())
+}
+
+/// Converts a Variant to JSON like [`variant_to_json`], with the formatting of timestamps and
+/// decimals controlled by `options`
+pub fn variant_to_json_with_options(
+    json_buffer: &mut impl Write,
+    variant: &Variant,
+    options: &VariantToJsonOptions,
+) -> Result<(), ArrowError> {
     match variant {
         Variant::Null => write!(json_buffer, "null")?,
         Variant::BooleanTrue => write!(json_buffer, "true")?,
@@ -102,14 +232,42 @@ pub fn variant_to_json(json_buffer: &mut impl Write, variant: &Variant) -> Resul
         Variant::Int64(i) => write!(json_buffer, "{i}")?,
         Variant::Float(f) => write!(json_buffer, "{f}")?,
         Variant::Double(f) => write!(json_buffer, "{f}")?,
+        Variant::Decimal4(decimal) if options.decimal_as_string => {
+            write!(json_buffer, "\"{decimal}\"")?
+        }
+        Variant::Decimal8(decimal) if options.decimal_as_string => {
+            write!(json_buffer, "\"{decimal}\"")?
+        }
+        Variant::Decimal16(decimal) if options.decimal_as_string => {
+            write!(json_buffer, "\"{decimal}\"")?
+        }
         Variant::Decimal4(decimal) => write!(json_buffer, "{decimal}")?,
         Variant::Decimal8(decimal) => write!(json_buffer, "{decimal}")?,
         Variant::Decimal16(decimal) => write!(json_buffer, "{decimal}")?,
         Variant::Date(date) => write!(json_buffer, "\"{}\"", format_date_string(date))?,
+        Variant::TimestampMicros(ts) | Variant::TimestampNanos(ts)
+            if options.timestamp_format.is_some() =>
+        {
+            let format = options.timestamp_format.as_deref().unwrap();
+            write_formatted(json_buffer, ts.format(format), format)?
+        }
+        Variant::TimestampNtzMicros(ts) | Variant::TimestampNtzNanos(ts)
+            if options.timestamp_ntz_format.is_some() =>
+        {
+            let format = options.timestamp_ntz_format.as_deref().unwrap();
+            write_formatted(json_buffer, ts.format(format), format)?
+        }
         Variant::TimestampMicros(ts) => write!(json_buffer, "\"{}\"", ts.to_rfc3339())?,
         Variant::TimestampNtzMicros(ts) => {
             write!(json_buffer, "\"{}\"", format_timestamp_ntz_string(ts))?
         }
+        Variant::Time(t) => write!(json_buffer, "\"{}\"", format_time_string(t))?,
+        Variant::TimestampNanos(ts) => {
+            write!(json_buffer, "\"{}\"", format_timestamp_nanos_string(ts))?
+        }
+        Variant::TimestampNtzNanos(ts) => {
+            write!(json_buffer, "\"{}\"", format_timestamp_ntz_nanos_string(ts))?
+        }
         Variant::Binary(bytes) => {
             // Encode binary as base64 string
             let base64_str = format_binary_base64(bytes);
@@ -132,18 +290,23 @@ pub fn variant_to_json(json_buffer: &mut impl Write, variant: &Variant) -> Resul
             })?;
             write!(json_buffer, "{json_str}")?
         }
+        Variant::Uuid(bytes) => write!(json_buffer, "\"{}\"", format_uuid_string(bytes))?,
         Variant::Object(obj) => {
-            convert_object_to_json(json_buffer, obj)?;
+            convert_object_to_json(json_buffer, obj, options)?;
         }
         Variant::List(arr) => {
-            convert_array_to_json(json_buffer, arr)?;
+            convert_array_to_json(json_buffer, arr, options)?;
         }
     }
     Ok(())
 }
 
 /// Convert object fields to JSON
-fn convert_object_to_json(buffer: &mut impl Write, obj: &VariantObject) -> Result<(), ArrowError> {
+fn convert_object_to_json(
+    buffer: &mut impl Write,
+    obj: &VariantObject,
+    options: &VariantToJsonOptions,
+) -> Result<(), ArrowError> {
     write!(buffer, "{{")?;
 
     // Get all fields from the object
@@ -162,7 +325,7 @@ fn convert_object_to_json(buffer: &mut impl Write, obj: &VariantObject) -> Resul
         write!(buffer, "{json_key}:")?;
 
         // Recursively convert the value
-        variant_to_json(buffer, &value)?;
+        variant_to_json_with_options(buffer, &value, options)?;
     }
 
     write!(buffer, "}}")?;
@@ -170,7 +333,11 @@ fn convert_object_to_json(buffer: &mut impl Write, obj: &VariantObject) -> Resul
 }
 
 /// Convert array elements to JSON
-fn convert_array_to_json(buffer: &mut impl Write, arr: &VariantList) -> Result<(), ArrowError> {
+fn convert_array_to_json(
+    buffer: &mut impl Write,
+    arr: &VariantList,
+    options: &VariantToJsonOptions,
+) -> Result<(), ArrowError> {
     write!(buffer, "[")?;
 
     let mut first = true;
@@ -180,7 +347,7 @@ fn convert_array_to_json(buffer: &mut impl Write, arr: &VariantList) -> Result<(
         }
         first = false;
 
-        variant_to_json(buffer, &element)?;
+        variant_to_json_with_options(buffer, &element, options)?;
     }
 
     write!(buffer, "]")?;
@@ -348,9 +515,13 @@ pub fn variant_to_json_value(variant: &Variant) -> Result<Value, ArrowError> {
         Variant::Date(date) => Ok(Value::String(format_date_string(date))),
         Variant::TimestampMicros(ts) => Ok(Value::String(ts.to_rfc3339())),
         Variant::TimestampNtzMicros(ts) => Ok(Value::String(format_timestamp_ntz_string(ts))),
+        Variant::Time(t) => Ok(Value::String(format_time_string(t))),
+        Variant::TimestampNanos(ts) => Ok(Value::String(format_timestamp_nanos_string(ts))),
+        Variant::TimestampNtzNanos(ts) => Ok(Value::String(format_timestamp_ntz_nanos_string(ts))),
         Variant::Binary(bytes) => Ok(Value::String(format_binary_base64(bytes))),
         Variant::String(s) => Ok(Value::String(s.to_string())),
         Variant::ShortString(s) => Ok(Value::String(s.to_string())),
+        Variant::Uuid(bytes) => Ok(Value::String(format_uuid_string(bytes))),
         Variant::Object(obj) => {
             let map = obj
                 .iter()
@@ -372,7 +543,7 @@ pub fn variant_to_json_value(variant: &Variant) -> Result<Value, ArrowError> {
 mod tests {
     use super::*;
     use chrono::{DateTime, NaiveDate, Utc};
-    use parquet_variant::{VariantDecimal16, VariantDecimal4, VariantDecimal8};
+    use parquet_variant::{VariantBuilder, VariantDecimal16, VariantDecimal4, VariantDecimal8};
 
     #[test]
     fn test_decimal_edge_cases() -> Result<(), ArrowError> {
@@ -457,6 +628,104 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_to_json_with_options() -> Result<(), ArrowError> {
+        let ts = DateTime::from_timestamp(1703500245, 123456789).unwrap();
+        let decimal = VariantDecimal16::try_new(12345678901234567890123, 3)?;
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_value(Variant::TimestampMicros(ts));
+        list.append_value(Variant::TimestampNanos(ts));
+        list.append_value(Variant::TimestampNtzNanos(ts.naive_utc()));
+        let mut obj = list.new_object();
+        obj.insert("d", decimal);
+        obj.finish()?;
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value)?;
+
+        let to_json = |options: &VariantToJsonOptions| {
+            let mut buffer = Vec::new();
+            variant_to_json_with_options(&mut buffer, &variant, options)?;
+            Ok::<_, ArrowError>(String::from_utf8(buffer).unwrap())
+        };
+
+        // The default options match variant_to_json
+        let json = to_json(&VariantToJsonOptions::new())?;
+        assert_eq!(json, variant_to_json_string(&variant)?);
+
+        let options = VariantToJsonOptions::new()
+            .with_timestamp_format("%s%.3f \"UTC\"")
+            .with_timestamp_ntz_format("%Y-%m-%d %H:%M:%S")
+            .with_decimal_as_string(true);
+        assert_eq!(
+            to_json(&options)?,
+            r#"["1703500245.123 \"UTC\"","1703500245.123 \"UTC\"","2023-12-25 10:30:45",{"d":"12345678901234567890.123"}]"#
+        );
+
+        let options = VariantToJsonOptions::new().with_timestamp_format("%Q");
+        let err = to_json(&options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Invalid timestamp format '%Q'"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_to_json() -> Result<(), ArrowError> {
+        let time = chrono::NaiveTime::from_hms_micro_opt(17, 14, 56, 780012).unwrap();
+        let variant = Variant::Time(time);
+        let json = variant_to_json_string(&variant)?;
+        assert_eq!(json, "\"17:14:56.780012\"");
+
+        let json_value = variant_to_json_value(&variant)?;
+        assert_eq!(json_value, Value::String("17:14:56.780012".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_nanos_to_json() -> Result<(), ArrowError> {
+        let timestamp = DateTime::from_timestamp(1703500245, 123456789).unwrap();
+        let variant = Variant::TimestampNanos(timestamp);
+        let json = variant_to_json_string(&variant)?;
+        assert_eq!(json, "\"2023-12-25T10:30:45.123456789+00:00\"");
+
+        let json_value = variant_to_json_value(&variant)?;
+        assert_eq!(
+            json_value,
+            Value::String("2023-12-25T10:30:45.123456789+00:00".to_string())
+        );
+
+        let variant = Variant::TimestampNtzNanos(timestamp.naive_utc());
+        let json = variant_to_json_string(&variant)?;
+        assert_eq!(json, "\"2023-12-25T10:30:45.123456789\"");
+
+        let json_value = variant_to_json_value(&variant)?;
+        assert_eq!(
+            json_value,
+            Value::String("2023-12-25T10:30:45.123456789".to_string())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_uuid_to_json() -> Result<(), ArrowError> {
+        let variant = Variant::Uuid([
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ]);
+        let json = variant_to_json_string(&variant)?;
+        assert_eq!(json, "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"");
+
+        let json_value = variant_to_json_value(&variant)?;
+        assert_eq!(
+            json_value,
+            Value::String("67e55044-10b1-426f-9247-bb680e5fe0c8".to_string())
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_binary_to_json() -> Result<(), ArrowError> {
         let binary_data = b"Hello, World!";
@@ -14,14 +14,24 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
-use crate::decoder::{VariantBasicType, VariantPrimitiveType};
+use crate::decoder::{get_basic_type, get_primitive_type, VariantBasicType, VariantPrimitiveType};
+use crate::utils::{array_from_slice, first_byte_from_slice, slice_from_slice};
+use crate::variant::check_short_string_len;
 use crate::{
-    ShortString, Variant, VariantDecimal16, VariantDecimal4, VariantDecimal8, VariantMetadata,
+    check_canonical, ShortString, Variant, VariantDecimal16, VariantDecimal4, VariantDecimal8,
+    VariantList, VariantMetadata, VariantObject,
 };
 use arrow_schema::ArrowError;
+use chrono::Timelike;
 use indexmap::{IndexMap, IndexSet};
 use std::collections::{HashMap, HashSet};
 
+mod slice;
+mod streaming;
+
+pub use slice::VariantSliceBuilder;
+pub use streaming::StreamingListBuilder;
+
 const BASIC_TYPE_BITS: u8 = 2;
 const UNIX_EPOCH_DATE: chrono::NaiveDate = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
 
@@ -57,6 +67,16 @@ fn int_size(v: usize) -> u8 {
     }
 }
 
+/// Returns the nanoseconds since the epoch of `value`, which must lie
+/// between 1677-09-21T00:12:43.145224192 and 2262-04-11T23:47:16.854775807
+fn timestamp_nanos(value: &chrono::DateTime<chrono::Utc>) -> Result<i64, ArrowError> {
+    value.timestamp_nanos_opt().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Timestamp {value} is out of range for nanosecond precision"
+        ))
+    })
+}
+
 /// Write little-endian integer to buffer
 fn write_offset(buf: &mut Vec<u8>, value: usize, nbytes: u8) {
     let bytes = value.to_le_bytes();
@@ -180,6 +200,35 @@ impl ValueBuffer {
         self.append_slice(&micros.to_le_bytes());
     }
 
+    fn append_time_micros(&mut self, value: chrono::NaiveTime) {
+        self.append_primitive_header(VariantPrimitiveType::Time);
+        // leap seconds are represented by chrono as nanoseconds beyond the last second of the day
+        let nanos = value.nanosecond().min(999_999_999);
+        let micros =
+            i64::from(value.num_seconds_from_midnight()) * 1_000_000 + i64::from(nanos / 1_000);
+        self.append_slice(&micros.to_le_bytes());
+    }
+
+    fn append_timestamp_nanos(
+        &mut self,
+        value: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ArrowError> {
+        let nanos = timestamp_nanos(&value)?;
+        self.append_primitive_header(VariantPrimitiveType::TimestampNanos);
+        self.append_slice(&nanos.to_le_bytes());
+        Ok(())
+    }
+
+    fn append_timestamp_ntz_nanos(
+        &mut self,
+        value: chrono::NaiveDateTime,
+    ) -> Result<(), ArrowError> {
+        let nanos = timestamp_nanos(&value.and_utc())?;
+        self.append_primitive_header(VariantPrimitiveType::TimestampNtzNanos);
+        self.append_slice(&nanos.to_le_bytes());
+        Ok(())
+    }
+
     fn append_decimal4(&mut self, decimal4: VariantDecimal4) {
         self.append_primitive_header(VariantPrimitiveType::Decimal4);
         self.append_u8(decimal4.scale());
@@ -204,6 +253,11 @@ impl ValueBuffer {
         self.append_slice(value);
     }
 
+    fn append_uuid(&mut self, value: [u8; 16]) {
+        self.append_primitive_header(VariantPrimitiveType::Uuid);
+        self.append_slice(&value);
+    }
+
     fn append_short_string(&mut self, value: ShortString) {
         let inner = value.0;
         self.append_u8(short_string_header(inner.len()));
@@ -246,7 +300,8 @@ impl ValueBuffer {
     /// # Panics
     ///
     /// This method will panic if the variant contains duplicate field names in objects
-    /// when validation is enabled. For a fallible version, use [`ValueBuffer::try_append_variant`]
+    /// when validation is enabled, or a nanosecond timestamp that can not be represented by
+    /// an `i64`. For a fallible version, use [`ValueBuffer::try_append_variant`]
     fn append_variant<'m, 'd>(
         &mut self,
         variant: Variant<'m, 'd>,
@@ -260,6 +315,7 @@ impl ValueBuffer {
         variant: Variant<'m, 'd>,
         metadata_builder: &mut MetadataBuilder,
     ) -> Result<(), ArrowError> {
+        metadata_builder.spec_version.check(&variant)?;
         match variant {
             Variant::Null => self.append_null(),
             Variant::BooleanTrue => self.append_bool(true),
@@ -271,6 +327,9 @@ impl ValueBuffer {
             Variant::Date(v) => self.append_date(v),
             Variant::TimestampMicros(v) => self.append_timestamp_micros(v),
             Variant::TimestampNtzMicros(v) => self.append_timestamp_ntz_micros(v),
+            Variant::Time(v) => self.append_time_micros(v),
+            Variant::TimestampNanos(v) => self.append_timestamp_nanos(v)?,
+            Variant::TimestampNtzNanos(v) => self.append_timestamp_ntz_nanos(v)?,
             Variant::Decimal4(decimal4) => self.append_decimal4(decimal4),
             Variant::Decimal8(decimal8) => self.append_decimal8(decimal8),
             Variant::Decimal16(decimal16) => self.append_decimal16(decimal16),
@@ -278,7 +337,12 @@ impl ValueBuffer {
             Variant::Double(v) => self.append_double(v),
             Variant::Binary(v) => self.append_binary(v),
             Variant::String(s) => self.append_string(s),
-            Variant::ShortString(s) => self.append_short_string(s),
+            Variant::ShortString(s) if s.is_valid_len() => self.append_short_string(s),
+            Variant::ShortString(s) if metadata_builder.downgrade_short_strings => {
+                self.append_string(s.0)
+            }
+            Variant::ShortString(s) => check_short_string_len(s.len())?,
+            Variant::Uuid(v) => self.append_uuid(v),
             Variant::Object(obj) => {
                 let metadata_field_names = metadata_builder
                     .field_names
@@ -296,7 +360,7 @@ impl ValueBuffer {
                     .sort_by_key(|(field_name, _)| metadata_field_names.get(field_name as &str));
 
                 for (field_name, value) in object_fields {
-                    object_builder.insert(field_name, value);
+                    object_builder.try_insert(field_name, value)?;
                 }
 
                 object_builder.finish()?;
@@ -304,7 +368,7 @@ impl ValueBuffer {
             Variant::List(list) => {
                 let mut list_builder = self.new_list(metadata_builder);
                 for value in list.iter() {
-                    list_builder.append_value(value);
+                    list_builder.try_append_value(value)?;
                 }
                 list_builder.finish();
             }
@@ -344,6 +408,230 @@ impl ValueBuffer {
     }
 }
 
+/// The layout of the [`Variant::List`] and [`Variant::Object`] values written by a
+/// [`VariantBuilder`].
+///
+/// By default, lists and objects use the smallest encoding that fits their contents: the
+/// number of elements is stored in a single byte unless there are more than 255, and field
+/// ids and offsets use as few bytes as possible. A layout instead forces `is_large` headers,
+/// and minimum widths for field ids and offsets, so that values of the same shape are
+/// encoded with the same widths regardless of their element counts and sizes. This gives
+/// byte-stable encodings that delta-encode and diff well. Widths are still increased when
+/// the forced ones are too small for a value.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, VariantBuilder, VariantLayout};
+/// let layout = VariantLayout::new().with_large(true).with_offset_size(4);
+/// let mut builder = VariantBuilder::new().with_layout(layout);
+/// let mut list = builder.new_list();
+/// list.append_value(1i8);
+/// list.finish();
+/// let (metadata, value) = builder.finish();
+///
+/// // header, 4 byte element count, 2 offsets of 4 bytes, then the int8 value
+/// assert_eq!(value.len(), 1 + 4 + 2 * 4 + 2);
+/// let variant = Variant::try_new(&metadata, &value).unwrap();
+/// assert_eq!(variant.get_list_element(0), Some(Variant::Int8(1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VariantLayout {
+    large: bool,
+    offset_size: u8,
+    field_id_size: u8,
+}
+
+impl Default for VariantLayout {
+    fn default() -> Self {
+        Self {
+            large: false,
+            offset_size: 1,
+            field_id_size: 1,
+        }
+    }
+}
+
+impl VariantLayout {
+    /// Create a layout that uses the smallest encoding of each value
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write `is_large` headers, with a 4 byte element count, for all lists and objects
+    pub fn with_large(mut self, large: bool) -> Self {
+        self.large = large;
+        self
+    }
+
+    /// Write list and object offsets with at least `offset_size` bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset_size` is not between 1 and 4
+    pub fn with_offset_size(mut self, offset_size: u8) -> Self {
+        assert!(
+            (1..=4).contains(&offset_size),
+            "offset size must be between 1 and 4, got {offset_size}"
+        );
+        self.offset_size = offset_size;
+        self
+    }
+
+    /// Write object field ids with at least `field_id_size` bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics if `field_id_size` is not between 1 and 4
+    pub fn with_field_id_size(mut self, field_id_size: u8) -> Self {
+        assert!(
+            (1..=4).contains(&field_id_size),
+            "field id size must be between 1 and 4, got {field_id_size}"
+        );
+        self.field_id_size = field_id_size;
+        self
+    }
+
+    /// Returns true if all lists and objects are written with `is_large` headers
+    pub fn is_large(&self) -> bool {
+        self.large
+    }
+
+    /// Returns the minimum size in bytes of list and object offsets
+    pub fn offset_size(&self) -> u8 {
+        self.offset_size
+    }
+
+    /// Returns the minimum size in bytes of object field ids
+    pub fn field_id_size(&self) -> u8 {
+        self.field_id_size
+    }
+}
+
+/// Limits on the [`Variant`] values written by a [`VariantBuilder`].
+///
+/// The limits are checked up front when creating nested builders with the `try_new_object`
+/// and `try_new_list` methods of [`VariantBuilder`], [`ListBuilder`] and [`ObjectBuilder`],
+/// and when inserting fields with [`ObjectBuilder::try_insert`], which return an error if
+/// the new value would exceed them. This allows writers of untrusted data to reject it
+/// before encoding values that are too large or deeply nested. By default there are no
+/// limits.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{VariantBuilder, VariantLimits};
+/// let limits = VariantLimits::new().with_max_depth(2).with_max_field_count(1);
+/// let mut builder = VariantBuilder::new().with_limits(limits);
+/// let mut object = builder.try_new_object().unwrap();
+/// let mut list = object.try_new_list("list").unwrap();
+/// assert!(list.try_new_list().is_err());
+/// list.finish();
+/// assert!(object.try_insert("other", 1).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VariantLimits {
+    max_depth: Option<usize>,
+    max_field_count: Option<usize>,
+    max_metadata_size: Option<usize>,
+}
+
+impl VariantLimits {
+    /// Create limits that do not limit anything
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the nesting depth of lists and objects, where a top-level list or object
+    /// has a depth of 1
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Limit the number of fields of each object
+    pub fn with_max_field_count(mut self, max_field_count: usize) -> Self {
+        self.max_field_count = Some(max_field_count);
+        self
+    }
+
+    /// Limit the size in bytes of the encoded metadata, which grows with the number and
+    /// length of the distinct field names
+    pub fn with_max_metadata_size(mut self, max_metadata_size: usize) -> Self {
+        self.max_metadata_size = Some(max_metadata_size);
+        self
+    }
+
+    /// Returns the maximum nesting depth of lists and objects, if any
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
+    }
+
+    /// Returns the maximum number of fields of each object, if any
+    pub fn max_field_count(&self) -> Option<usize> {
+        self.max_field_count
+    }
+
+    /// Returns the maximum size in bytes of the encoded metadata, if any
+    pub fn max_metadata_size(&self) -> Option<usize> {
+        self.max_metadata_size
+    }
+}
+
+/// The revision of the [Variant spec] targeted by a [`VariantBuilder`].
+///
+/// Later revisions of the spec added primitive types that readers of earlier revisions
+/// can not decode. A builder targeting an earlier revision returns an error instead of
+/// writing a value whose primitive type the revision does not define, including values
+/// copied with [`VariantBuilder::append_encoded`]. Values of such types must then be
+/// converted, e.g. nanosecond timestamps to microseconds, before being appended.
+///
+/// # Example
+/// ```
+/// # use chrono::NaiveTime;
+/// # use parquet_variant::{Variant, VariantBuilder, VariantSpecVersion};
+/// let time = Variant::Time(NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+/// assert!(!VariantSpecVersion::Initial.supports(&time));
+///
+/// let mut builder = VariantBuilder::new().with_spec_version(VariantSpecVersion::Initial);
+/// let mut list = builder.new_list();
+/// list.append_value(1i64);
+/// assert!(list.try_append_value(time).is_err());
+/// ```
+///
+/// [Variant spec]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VariantSpecVersion {
+    /// The initial revision of the spec, whose primitive types have type ids 0 to 16, i.e.
+    /// which does not define `Time`, nanosecond timestamps and `Uuid`
+    Initial,
+    /// The current revision of the spec, which defines all the primitive types of [`Variant`]
+    #[default]
+    Current,
+}
+
+impl VariantSpecVersion {
+    /// Returns true if the primitive type of `variant` is defined by this revision of the
+    /// spec. The values of lists and objects are not checked.
+    pub fn supports(&self, variant: &Variant<'_, '_>) -> bool {
+        match variant {
+            Variant::Time(_)
+            | Variant::TimestampNanos(_)
+            | Variant::TimestampNtzNanos(_)
+            | Variant::Uuid(_) => *self >= Self::Current,
+            _ => true,
+        }
+    }
+
+    /// Returns an error if the primitive type of `variant` is not defined by this revision
+    fn check(&self, variant: &Variant<'_, '_>) -> Result<(), ArrowError> {
+        if self.supports(variant) {
+            return Ok(());
+        }
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Variant spec version {self:?} does not support {variant:?}"
+        )))
+    }
+}
+
 /// Builder for constructing metadata for [`Variant`] values.
 ///
 /// This is used internally by the [`VariantBuilder`] to construct the metadata
@@ -359,6 +647,18 @@ struct MetadataBuilder {
 
     /// Output buffer. Metadata is written to the end of this buffer
     metadata_buffer: Vec<u8>,
+
+    /// Layout of the lists and objects of the values using this metadata
+    layout: VariantLayout,
+
+    /// Whether to write short strings that are too long as strings, rather than erroring
+    downgrade_short_strings: bool,
+
+    /// Limits on the values using this metadata
+    limits: VariantLimits,
+
+    /// The revision of the spec of the values using this metadata
+    spec_version: VariantSpecVersion,
 }
 
 /// Create a new MetadataBuilder that will write to the specified metadata buffer
@@ -372,6 +672,41 @@ impl From<Vec<u8>> for MetadataBuilder {
 }
 
 impl MetadataBuilder {
+    /// Create a new MetadataBuilder whose dictionary is `field_names`, which must be
+    /// strictly sorted, without checking their order or uniqueness
+    ///
+    /// The order is only validated in debug builds. If `field_names` is not strictly
+    /// sorted, the metadata is incorrectly marked as sorted, and readers may fail to find
+    /// fields in it.
+    fn from_sorted_unchecked(field_names: Vec<String>) -> Self {
+        debug_assert!(
+            field_names.windows(2).all(|w| w[0] < w[1]),
+            "field names are not strictly sorted"
+        );
+        Self {
+            is_sorted: !field_names.is_empty(),
+            field_names: field_names.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the dictionary to `field_names`, see [`Self::from_sorted_unchecked`]
+    ///
+    /// Field names already in the dictionary are kept, and `field_names` are inserted
+    /// with order tracking instead.
+    fn set_sorted_field_names_unchecked(&mut self, field_names: Vec<String>) {
+        if self.num_field_names() > 0 {
+            return self.extend(field_names);
+        }
+        let Self {
+            field_names,
+            is_sorted,
+            ..
+        } = Self::from_sorted_unchecked(field_names);
+        self.field_names = field_names;
+        self.is_sorted = is_sorted;
+    }
+
     /// Upsert field name to dictionary, return its ID
     fn upsert_field_name(&mut self, field_name: &str) -> u32 {
         let (id, new_entry) = self.field_names.insert_full(field_name.to_string());
@@ -390,6 +725,55 @@ impl MetadataBuilder {
         id as u32
     }
 
+    /// Returns an error if a list or object at `depth` exceeds the maximum depth
+    fn check_depth(&self, depth: usize) -> Result<(), ArrowError> {
+        match self.limits.max_depth {
+            Some(max_depth) if depth > max_depth => Err(ArrowError::InvalidArgumentError(format!(
+                "Variant nesting depth {depth} exceeds the maximum of {max_depth}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if adding `field_name` to an object with `fields` exceeds the
+    /// maximum field count or metadata size
+    fn check_new_field(
+        &self,
+        fields: &IndexMap<u32, usize>,
+        field_name: &str,
+    ) -> Result<(), ArrowError> {
+        let VariantLimits {
+            max_field_count,
+            max_metadata_size,
+            ..
+        } = self.limits;
+        if max_field_count.is_none() && max_metadata_size.is_none() {
+            return Ok(());
+        }
+        let field_id = self.field_names.get_index_of(field_name);
+        let is_new_field = match field_id {
+            Some(id) => !fields.contains_key(&(id as u32)),
+            None => true,
+        };
+        if let Some(max_field_count) = max_field_count {
+            if is_new_field && fields.len() >= max_field_count {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Variant object field '{field_name}' exceeds the maximum of {max_field_count} fields"
+                )));
+            }
+        }
+        if let (Some(max_metadata_size), None) = (max_metadata_size, field_id) {
+            // A new field name adds its bytes and an offset of at most 4 bytes
+            let (_, size) = self.encoded_size();
+            if size + field_name.len() + 4 > max_metadata_size {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Variant field name '{field_name}' exceeds the maximum metadata size of {max_metadata_size} bytes"
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the number of field names stored in the metadata builder.
     /// Note: this method should be the only place to call `self.field_names.len()`
     ///
@@ -411,25 +795,32 @@ impl MetadataBuilder {
         self.field_names.iter().map(|k| k.len()).sum()
     }
 
-    fn finish(self) -> Vec<u8> {
+    /// Returns the offset size and total number of bytes the encoded metadata will occupy
+    fn encoded_size(&self) -> (u8, usize) {
         let nkeys = self.num_field_names();
 
         // Calculate metadata size
         let total_dict_size: usize = self.metadata_size();
 
-        let Self {
-            field_names,
-            is_sorted,
-            mut metadata_buffer,
-        } = self;
-
         // Determine appropriate offset size based on the larger of dict size or total string size
         let max_offset = std::cmp::max(total_dict_size, nkeys);
         let offset_size = int_size(max_offset);
 
         let offset_start = 1 + offset_size as usize;
         let string_start = offset_start + (nkeys + 1) * offset_size as usize;
-        let metadata_size = string_start + total_dict_size;
+        (offset_size, string_start + total_dict_size)
+    }
+
+    fn finish(self) -> Vec<u8> {
+        let nkeys = self.num_field_names();
+        let (offset_size, metadata_size) = self.encoded_size();
+
+        let Self {
+            field_names,
+            is_sorted,
+            mut metadata_buffer,
+            ..
+        } = self;
 
         metadata_buffer.reserve(metadata_size);
 
@@ -500,12 +891,14 @@ enum ParentState<'a> {
         buffer: &'a mut ValueBuffer,
         metadata_builder: &'a mut MetadataBuilder,
         offsets: &'a mut Vec<usize>,
+        depth: usize,
     },
     Object {
         buffer: &'a mut ValueBuffer,
         metadata_builder: &'a mut MetadataBuilder,
         fields: &'a mut IndexMap<u32, usize>,
         field_name: &'a str,
+        depth: usize,
     },
 }
 
@@ -518,6 +911,14 @@ impl ParentState<'_> {
         }
     }
 
+    /// Returns the nesting depth of the parent, 0 for a [`VariantBuilder`]
+    fn depth(&self) -> usize {
+        match self {
+            ParentState::Variant { .. } => 0,
+            ParentState::List { depth, .. } | ParentState::Object { depth, .. } => *depth,
+        }
+    }
+
     fn metadata_builder(&mut self) -> &mut MetadataBuilder {
         match self {
             ParentState::Variant {
@@ -787,6 +1188,10 @@ pub struct VariantBuilder {
     buffer: ValueBuffer,
     metadata_builder: MetadataBuilder,
     validate_unique_fields: bool,
+    /// Whether to assert that the finished variant is canonically encoded
+    canonical_assertions: bool,
+    /// Offset of the value in `buffer`, which may contain existing data
+    value_start: usize,
 }
 
 impl VariantBuilder {
@@ -796,6 +1201,8 @@ impl VariantBuilder {
             buffer: ValueBuffer::new(),
             metadata_builder: MetadataBuilder::default(),
             validate_unique_fields: false,
+            canonical_assertions: false,
+            value_start: 0,
         }
     }
 
@@ -809,12 +1216,58 @@ impl VariantBuilder {
     /// the specified buffers.
     pub fn new_with_buffers(metadata_buffer: Vec<u8>, value_buffer: Vec<u8>) -> Self {
         Self {
+            value_start: value_buffer.len(),
             buffer: ValueBuffer::from(value_buffer),
             metadata_builder: MetadataBuilder::from(metadata_buffer),
             validate_unique_fields: false,
+            canonical_assertions: false,
         }
     }
 
+    /// Sets the [`VariantLayout`] of all lists and objects written by this builder,
+    /// including nested ones.
+    pub fn with_layout(mut self, layout: VariantLayout) -> Self {
+        self.metadata_builder.layout = layout;
+        self
+    }
+
+    /// Sets the [`VariantLimits`] of the values written by this builder
+    pub fn with_limits(mut self, limits: VariantLimits) -> Self {
+        self.metadata_builder.limits = limits;
+        self
+    }
+
+    /// Sets the [`VariantSpecVersion`] targeted by this builder, which defaults to
+    /// [`VariantSpecVersion::Current`]
+    pub fn with_spec_version(mut self, spec_version: VariantSpecVersion) -> Self {
+        self.metadata_builder.spec_version = spec_version;
+        self
+    }
+
+    /// Sets whether to write [`ShortString`]s longer than the maximum of 63 bytes, which
+    /// can only be created with [`ShortString::new_unchecked`], as [`Variant::String`]s.
+    ///
+    /// By default, appending such a short string returns an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{ShortString, Variant, VariantBuilder};
+    /// let long = "a".repeat(100);
+    /// // SAFETY: the bytes are valid UTF-8
+    /// let value = Variant::ShortString(unsafe { ShortString::new_unchecked(long.as_bytes()) });
+    /// assert!(VariantBuilder::new().try_append_value(value.clone()).is_err());
+    ///
+    /// let mut builder = VariantBuilder::new().with_short_string_downgrade(true);
+    /// builder.append_value(value);
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value).unwrap();
+    /// assert!(matches!(variant, Variant::String(s) if s == long));
+    /// ```
+    pub fn with_short_string_downgrade(mut self, downgrade: bool) -> Self {
+        self.metadata_builder.downgrade_short_strings = downgrade;
+        self
+    }
+
     /// Enables validation of unique field keys in nested objects.
     ///
     /// This setting is propagated to all [`ObjectBuilder`]s created through this [`VariantBuilder`]
@@ -825,6 +1278,42 @@ impl VariantBuilder {
         self
     }
 
+    /// Enables assertions that the variant returned by [`Self::finish`] or
+    /// [`Self::finish_sorted`] is the [canonical encoding] of its value, see
+    /// [`check_canonical`].
+    ///
+    /// This is intended for tests of code producing variants that are compared by their
+    /// bytes. A variant built with the default layout is canonical if its field names are
+    /// inserted in sorted order, or it is finished with [`Self::finish_sorted`], all field
+    /// names are used, the fields of each object are inserted in sorted order, and strings
+    /// of at most 63 bytes are appended as short strings, as done by `Variant::from(&str)`.
+    ///
+    /// # Panics
+    ///
+    /// With assertions enabled, [`Self::finish`] and [`Self::finish_sorted`] panic if the
+    /// variant is not canonically encoded.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// # use parquet_variant::VariantBuilder;
+    /// let mut builder = VariantBuilder::new()
+    ///     .with_canonical_assertions(true)
+    ///     .with_field_names(["a", "b"].into_iter());
+    /// let mut obj = builder.new_object();
+    /// obj.insert("b", 1);
+    /// obj.insert("a", 2);
+    /// obj.finish().unwrap();
+    /// // panics, the fields are not inserted in sorted order
+    /// builder.finish();
+    /// ```
+    ///
+    /// [canonical encoding]: crate::canonicalize
+    /// [`check_canonical`]: crate::check_canonical
+    pub fn with_canonical_assertions(mut self, canonical_assertions: bool) -> Self {
+        self.canonical_assertions = canonical_assertions;
+        self
+    }
+
     /// This method pre-populates the field name directory in the Variant metadata with
     /// the specific field names, in order.
     ///
@@ -837,6 +1326,37 @@ impl VariantBuilder {
         self
     }
 
+    /// Pre-populates the field name directory in the Variant metadata with `field_names`,
+    /// which must be strictly sorted.
+    ///
+    /// This is a faster alternative to [`VariantBuilder::with_field_names`] for writers that
+    /// already know their field names are sorted, as it skips tracking the order of each
+    /// inserted field name. If the builder already has field names, `field_names` are
+    /// added like [`VariantBuilder::with_field_names`].
+    ///
+    /// The order of `field_names` is only validated in debug builds. Passing field names
+    /// that are not strictly sorted produces metadata that is incorrectly marked as
+    /// sorted, in which readers may fail to find fields.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let field_names = vec!["age".to_string(), "name".to_string()];
+    /// let mut builder = VariantBuilder::new().with_sorted_field_names(field_names);
+    /// let mut obj = builder.new_object();
+    /// obj.insert("name", "Alice");
+    /// obj.insert("age", 30);
+    /// obj.finish().unwrap();
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value).unwrap();
+    /// assert!(variant.metadata().unwrap().is_sorted());
+    /// ```
+    pub fn with_sorted_field_names(mut self, field_names: Vec<String>) -> Self {
+        self.metadata_builder
+            .set_sorted_field_names_unchecked(field_names);
+        self
+    }
+
     /// This method reserves capacity for field names in the Variant metadata,
     /// which can improve performance when you know the approximate number of unique field
     /// names that will be used across all objects in the [`Variant`].
@@ -851,6 +1371,49 @@ impl VariantBuilder {
         self.metadata_builder.upsert_field_name(field_name);
     }
 
+    /// Reserves capacity for at least `additional` more bytes in the value buffer.
+    ///
+    /// Callers that know the approximate encoded size of the values they are about to
+    /// append (for example when batching many variants into a shared buffer) can use this
+    /// to avoid repeated reallocation.
+    pub fn reserve_value(&mut self, additional: usize) {
+        self.buffer.inner_mut().reserve(additional);
+    }
+
+    /// Returns the size in bytes of the value buffer that [`VariantBuilder::finish`] would
+    /// return if called now.
+    ///
+    /// This includes any bytes already present in a buffer passed to
+    /// [`VariantBuilder::new_with_buffers`].
+    pub fn estimated_value_size(&self) -> usize {
+        self.buffer.offset()
+    }
+
+    /// Returns the size in bytes of the metadata buffer that [`VariantBuilder::finish`]
+    /// would return if called now.
+    ///
+    /// This includes any bytes already present in a buffer passed to
+    /// [`VariantBuilder::new_with_buffers`].
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::VariantBuilder;
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("name", "Alice");
+    /// obj.finish().unwrap();
+    ///
+    /// let (metadata_size, value_size) =
+    ///     (builder.estimated_metadata_size(), builder.estimated_value_size());
+    /// let (metadata, value) = builder.finish();
+    /// assert_eq!(metadata.len(), metadata_size);
+    /// assert_eq!(value.len(), value_size);
+    /// ```
+    pub fn estimated_metadata_size(&self) -> usize {
+        let (_, encoded_size) = self.metadata_builder.encoded_size();
+        self.metadata_builder.metadata_buffer.len() + encoded_size
+    }
+
     // Returns validate_unique_fields because we can no longer reference self once this method returns.
     fn parent_state(&mut self) -> (ParentState, bool) {
         let state = ParentState::Variant {
@@ -863,17 +1426,41 @@ impl VariantBuilder {
     /// Create an [`ListBuilder`] for creating [`Variant::List`] values.
     ///
     /// See the examples on [`VariantBuilder`] for usage.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the list exceeds the [`VariantLimits`] of the builder.
+    /// For a fallible version, use [`VariantBuilder::try_new_list`]
     pub fn new_list(&mut self) -> ListBuilder {
-        let (parent_state, validate_unique_fields) = self.parent_state();
-        ListBuilder::new(parent_state, validate_unique_fields)
+        self.try_new_list().unwrap()
     }
 
     /// Create an [`ObjectBuilder`] for creating [`Variant::Object`] values.
     ///
     /// See the examples on [`VariantBuilder`] for usage.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the object exceeds the [`VariantLimits`] of the builder.
+    /// For a fallible version, use [`VariantBuilder::try_new_object`]
     pub fn new_object(&mut self) -> ObjectBuilder {
+        self.try_new_object().unwrap()
+    }
+
+    /// Create an [`ListBuilder`] for creating [`Variant::List`] values, or return an
+    /// error if the list exceeds the [`VariantLimits`] of the builder
+    pub fn try_new_list(&mut self) -> Result<ListBuilder<'_>, ArrowError> {
+        self.metadata_builder.check_depth(1)?;
         let (parent_state, validate_unique_fields) = self.parent_state();
-        ObjectBuilder::new(parent_state, validate_unique_fields)
+        Ok(ListBuilder::new(parent_state, validate_unique_fields))
+    }
+
+    /// Create an [`ObjectBuilder`] for creating [`Variant::Object`] values, or return an
+    /// error if the object exceeds the [`VariantLimits`] of the builder
+    pub fn try_new_object(&mut self) -> Result<ObjectBuilder<'_>, ArrowError> {
+        self.metadata_builder.check_depth(1)?;
+        let (parent_state, validate_unique_fields) = self.parent_state();
+        Ok(ObjectBuilder::new(parent_state, validate_unique_fields))
     }
 
     /// Append a value to the builder.
@@ -881,7 +1468,10 @@ impl VariantBuilder {
     /// # Panics
     ///
     /// This method will panic if the variant contains duplicate field names in objects
-    /// when validation is enabled. For a fallible version, use [`VariantBuilder::try_append_value`]
+    /// when validation is enabled, a value whose type is not supported by the
+    /// [`VariantSpecVersion`] of the builder, or a [`Variant::TimestampNanos`] or
+    /// [`Variant::TimestampNtzNanos`] outside of the range of an `i64` of nanoseconds
+    /// since the epoch. For a fallible version, use [`VariantBuilder::try_append_value`]
     ///
     /// # Example
     /// ```
@@ -908,36 +1498,471 @@ impl VariantBuilder {
         Ok(())
     }
 
-    /// Finish the builder and return the metadata and value buffers.
-    pub fn finish(self) -> (Vec<u8>, Vec<u8>) {
-        (self.metadata_builder.finish(), self.buffer.into_inner())
+    /// Append an already encoded value to the builder without decoding it.
+    ///
+    /// The caller must ensure any field ids in `value` refer to the same names in
+    /// this builder's metadata, e.g. by seeding it with [`Self::with_metadata`].
+    pub(crate) fn append_raw(&mut self, value: &[u8]) {
+        self.buffer.append_slice(value);
     }
-}
-
-/// A builder for creating [`Variant::List`] values.
-///
-/// See the examples on [`VariantBuilder`] for usage.
-pub struct ListBuilder<'a> {
-    parent_state: ParentState<'a>,
-    offsets: Vec<usize>,
-    buffer: ValueBuffer,
-    validate_unique_fields: bool,
-}
 
-impl<'a> ListBuilder<'a> {
-    fn new(parent_state: ParentState<'a>, validate_unique_fields: bool) -> Self {
-        Self {
-            parent_state,
-            offsets: vec![],
-            buffer: ValueBuffer::default(),
-            validate_unique_fields,
-        }
+    /// Append an already encoded `value` with its `metadata` to the builder, copying its
+    /// primitive values without decoding and re-encoding them.
+    ///
+    /// `value` may use any metadata dictionary: the field names of its objects are added
+    /// to this builder's metadata, and its objects and lists are re-encoded with the field
+    /// ids of this builder. Primitive values are validated, but copied as is, which is much
+    /// faster than appending the decoded [`Variant`] with [`Self::append_value`], e.g. for
+    /// copy or merge workloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `metadata` or `value` are invalid, in which case nothing is
+    /// appended, although field names may have been added to this builder's metadata.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let mut source = VariantBuilder::new();
+    /// let mut obj = source.new_object();
+    /// obj.insert("name", "Alice");
+    /// obj.finish().unwrap();
+    /// let (metadata, value) = source.finish();
+    ///
+    /// // The field names of this builder have different field ids
+    /// let mut builder = VariantBuilder::new().with_field_names(["id", "name"].into_iter());
+    /// builder.append_encoded(&metadata, &value).unwrap();
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value).unwrap();
+    /// assert_eq!(variant.get_object_field("name"), Some(Variant::from("Alice")));
+    /// ```
+    pub fn append_encoded(&mut self, metadata: &[u8], value: &[u8]) -> Result<(), ArrowError> {
+        let metadata = VariantMetadata::try_new(metadata)?;
+        append_encoded_value(self, &metadata, value)
     }
 
-    /// Enables unique field key validation for objects created within this list.
+    /// Append an object containing the `(key, value)` pairs from `iter` to the builder.
     ///
-    /// Propagates the validation flag to any [`ObjectBuilder`]s created using
-    /// [`ListBuilder::new_object`].
+    /// This is equivalent to calling [`ObjectBuilder::extend`] on the result of
+    /// [`VariantBuilder::new_object`], and then [`ObjectBuilder::finish`].
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if `iter` contains duplicate field names
+    /// when validation is enabled. For a fallible version, use [`VariantBuilder::try_append_object`]
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let map = HashMap::from([("name", "Alice"), ("city", "Paris")]);
+    ///
+    /// let mut builder = VariantBuilder::new();
+    /// builder.append_object(map.iter().map(|(k, v)| (*k, *v)));
+    ///
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::new(&metadata, &value);
+    /// assert_eq!(variant.get_object_field("city"), Some(Variant::from("Paris")));
+    /// ```
+    pub fn append_object<'k, 'm, 'd, T, I>(&mut self, iter: I)
+    where
+        T: Into<Variant<'m, 'd>>,
+        I: IntoIterator<Item = (&'k str, T)>,
+    {
+        self.try_append_object(iter).unwrap();
+    }
+
+    /// Append an object containing the `(key, value)` pairs from `iter` to the builder.
+    ///
+    /// See [`VariantBuilder::append_object`] for details
+    pub fn try_append_object<'k, 'm, 'd, T, I>(&mut self, iter: I) -> Result<(), ArrowError>
+    where
+        T: Into<Variant<'m, 'd>>,
+        I: IntoIterator<Item = (&'k str, T)>,
+    {
+        let mut object_builder = self.new_object();
+        object_builder.try_extend(iter)?;
+        object_builder.finish()
+    }
+
+    /// Finish the builder and return the metadata and value buffers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [canonical assertions] are enabled and the variant is not canonical
+    ///
+    /// [canonical assertions]: Self::with_canonical_assertions
+    pub fn finish(self) -> (Vec<u8>, Vec<u8>) {
+        let metadata_start = self.metadata_builder.metadata_buffer.len();
+        let metadata = self.metadata_builder.finish();
+        let value = self.buffer.into_inner();
+        if self.canonical_assertions {
+            assert_canonical(&metadata[metadata_start..], &value[self.value_start..]);
+        }
+        (metadata, value)
+    }
+
+    /// Finish the builder and return the metadata and value buffers, with the field names
+    /// in the metadata sorted lexicographically.
+    ///
+    /// Field ids are assigned in the order field names are first inserted, so the metadata
+    /// returned by [`Self::finish`] is only marked as sorted if the names happened to be
+    /// inserted in order. Readers can look up field names in a sorted dictionary using a
+    /// binary search rather than a linear scan.
+    ///
+    /// If the field names are not already sorted, the value is rewritten with the field ids
+    /// remapped to the sorted dictionary, which requires decoding and re-encoding it.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder, VariantMetadata};
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("zebra", 1);
+    /// obj.insert("apple", 2);
+    /// obj.finish().unwrap();
+    /// let (metadata, value) = builder.finish_sorted();
+    ///
+    /// let metadata = VariantMetadata::try_new(&metadata).unwrap();
+    /// assert!(metadata.is_sorted());
+    /// assert_eq!(metadata.iter().collect::<Vec<_>>(), vec!["apple", "zebra"]);
+    ///
+    /// let variant = Variant::try_new_with_metadata(metadata, &value).unwrap();
+    /// assert_eq!(variant.get_object_field("zebra"), Some(Variant::from(1)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if [canonical assertions] are enabled and the variant is not canonical
+    ///
+    /// [canonical assertions]: Self::with_canonical_assertions
+    pub fn finish_sorted(self) -> (Vec<u8>, Vec<u8>) {
+        // An empty dictionary is always written as unsorted, see MetadataBuilder::upsert_field_name
+        if self.metadata_builder.is_sorted || self.metadata_builder.num_field_names() == 0 {
+            return self.finish();
+        }
+
+        let Self {
+            buffer,
+            mut metadata_builder,
+            value_start,
+            canonical_assertions,
+            ..
+        } = self;
+
+        // Write the sorted metadata after any existing data in the metadata buffer
+        let metadata_buffer = std::mem::take(&mut metadata_builder.metadata_buffer);
+        let metadata_start = metadata_buffer.len();
+        let mut names: Vec<_> = metadata_builder.field_names.iter().cloned().collect();
+        names.sort_unstable();
+        let mut sorted_builder = MetadataBuilder::from(metadata_buffer);
+        sorted_builder.extend(names);
+
+        let metadata = metadata_builder.finish();
+        let mut value_buffer = buffer.into_inner();
+        let value = value_buffer.split_off(value_start);
+
+        let mut buffer = ValueBuffer::from(value_buffer);
+        buffer.append_variant(Variant::new(&metadata, &value), &mut sorted_builder);
+        let (metadata, value) = (sorted_builder.finish(), buffer.into_inner());
+        if canonical_assertions {
+            assert_canonical(&metadata[metadata_start..], &value[value_start..]);
+        }
+        (metadata, value)
+    }
+
+    /// Create a [`VariantSliceBuilder`] that encodes a value directly into caller-provided
+    /// metadata and value slices, rather than into newly allocated `Vec`s.
+    ///
+    /// This is useful for embedded and FFI users that manage their own arena memory.
+    /// [`VariantSliceBuilder::try_append_value`] returns an error as soon as the encoded
+    /// variant does not fit in the provided slices.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// // Build an object with a VariantBuilder
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("name", "hello");
+    /// obj.finish().unwrap();
+    /// let (object_metadata, object_value) = builder.finish();
+    /// let object = Variant::new(&object_metadata, &object_value);
+    ///
+    /// // Encode it into pre-allocated memory
+    /// let mut metadata = [0u8; 16];
+    /// let mut value = [0u8; 16];
+    /// let mut builder = VariantBuilder::new_with_slices(&mut metadata, &mut value);
+    /// builder.try_append_value(object.clone()).unwrap();
+    /// let (metadata_len, value_len) = builder.finish().unwrap();
+    ///
+    /// let variant = Variant::try_new(&metadata[..metadata_len], &value[..value_len]).unwrap();
+    /// assert_eq!(variant.get_object_field("name"), Some(Variant::from("hello")));
+    ///
+    /// // Values that do not fit are rejected
+    /// let mut value = [0u8; 4];
+    /// let mut builder = VariantBuilder::new_with_slices(&mut metadata, &mut value);
+    /// assert!(builder.try_append_value(object).is_err());
+    /// ```
+    pub fn new_with_slices<'a>(
+        metadata: &'a mut [u8],
+        value: &'a mut [u8],
+    ) -> VariantSliceBuilder<'a> {
+        VariantSliceBuilder::new(metadata, value)
+    }
+}
+
+/// Panics if `metadata` and `value` are not canonically encoded, see [`check_canonical`]
+fn assert_canonical(metadata: &[u8], value: &[u8]) {
+    if let Err(e) = check_canonical(metadata, value) {
+        panic!("{e}");
+    }
+}
+
+/// A metadata dictionary shared by many variant values.
+///
+/// By default every [`VariantBuilder`] produces its own metadata buffer. When building many
+/// values with the same structure, such as the rows of a variant column, the field names are
+/// then repeated in every row's metadata. Instead, each value can be built with an
+/// [`ArenaVariantBuilder`] from [`Self::new_builder`], which records its field names in the
+/// arena and returns only the value buffer. [`Self::finish`] then returns a single metadata
+/// buffer that is valid for all the values built from the arena, as engines such as Spark
+/// do for variant columns.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, VariantMetadataArena};
+/// let mut arena = VariantMetadataArena::new();
+/// let values: Vec<Vec<u8>> = (0..3)
+///     .map(|i| {
+///         let mut builder = arena.new_builder();
+///         let mut obj = builder.new_object();
+///         obj.insert("id", i);
+///         obj.insert("name", "row");
+///         obj.finish().unwrap();
+///         builder.finish()
+///     })
+///     .collect();
+///
+/// // One metadata buffer for all the rows
+/// let metadata = arena.finish();
+/// for (i, value) in values.iter().enumerate() {
+///     let variant = Variant::try_new(&metadata, value).unwrap();
+///     assert_eq!(variant.get_object_field("id"), Some(Variant::from(i as i32)));
+/// }
+/// ```
+#[derive(Default, Debug)]
+pub struct VariantMetadataArena {
+    metadata_builder: MetadataBuilder,
+    validate_unique_fields: bool,
+}
+
+impl VariantMetadataArena {
+    /// Create a new arena with an empty dictionary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed the dictionary with the field names of an existing [`VariantMetadata`]
+    ///
+    /// The field ids of `metadata` are preserved, so values encoded against it remain
+    /// valid with the metadata returned by [`Self::finish`].
+    pub fn with_metadata(mut self, metadata: VariantMetadata) -> Self {
+        self.metadata_builder.extend(metadata.iter());
+        self
+    }
+
+    /// Seed the dictionary with `field_names`, which must be strictly sorted
+    ///
+    /// See [`VariantBuilder::with_sorted_field_names`]
+    pub fn with_sorted_field_names(mut self, field_names: Vec<String>) -> Self {
+        self.metadata_builder
+            .set_sorted_field_names_unchecked(field_names);
+        self
+    }
+
+    /// Enables validation of unique field keys in nested objects.
+    ///
+    /// See [`VariantBuilder::with_validate_unique_fields`]
+    pub fn with_validate_unique_fields(mut self, validate_unique_fields: bool) -> Self {
+        self.validate_unique_fields = validate_unique_fields;
+        self
+    }
+
+    /// Sets the [`VariantLayout`] of the values written by the builders of this arena
+    ///
+    /// See [`VariantBuilder::with_layout`]
+    pub fn with_layout(mut self, layout: VariantLayout) -> Self {
+        self.metadata_builder.layout = layout;
+        self
+    }
+
+    /// Sets the [`VariantLimits`] of the values written by the builders of this arena
+    ///
+    /// See [`VariantBuilder::with_limits`]
+    pub fn with_limits(mut self, limits: VariantLimits) -> Self {
+        self.metadata_builder.limits = limits;
+        self
+    }
+
+    /// Sets the [`VariantSpecVersion`] targeted by the builders of this arena
+    ///
+    /// See [`VariantBuilder::with_spec_version`]
+    pub fn with_spec_version(mut self, spec_version: VariantSpecVersion) -> Self {
+        self.metadata_builder.spec_version = spec_version;
+        self
+    }
+
+    /// Sets whether the builders of this arena write short strings that are too long as
+    /// strings
+    ///
+    /// See [`VariantBuilder::with_short_string_downgrade`]
+    pub fn with_short_string_downgrade(mut self, downgrade: bool) -> Self {
+        self.metadata_builder.downgrade_short_strings = downgrade;
+        self
+    }
+
+    /// Returns the number of field names in the dictionary
+    pub fn len(&self) -> usize {
+        self.metadata_builder.num_field_names()
+    }
+
+    /// Returns true if the dictionary contains no field names
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a builder for a new value, whose field names are added to this arena
+    ///
+    /// The arena is borrowed until the returned builder is finished or dropped. Field names
+    /// added by a builder that is dropped without being finished remain in the dictionary.
+    pub fn new_builder(&mut self) -> ArenaVariantBuilder<'_> {
+        let builder = VariantBuilder {
+            buffer: ValueBuffer::default(),
+            metadata_builder: std::mem::take(&mut self.metadata_builder),
+            validate_unique_fields: self.validate_unique_fields,
+            canonical_assertions: false,
+            value_start: 0,
+        };
+        ArenaVariantBuilder {
+            arena: self,
+            builder,
+        }
+    }
+
+    /// Returns a builder for a new value like [`Self::new_builder`], which appends the value
+    /// to `value_buffer` after any existing data
+    ///
+    /// [`ArenaVariantBuilder::finish`] returns `value_buffer`, so a single buffer can hold the
+    /// values of many rows without allocating for each of them.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantMetadataArena};
+    /// let mut arena = VariantMetadataArena::new();
+    /// let mut value_buffer = vec![];
+    /// let mut offsets = vec![0];
+    /// for i in 0..3 {
+    ///     let mut builder = arena.new_builder_with_buffer(value_buffer);
+    ///     builder.append_value(i);
+    ///     value_buffer = builder.finish();
+    ///     offsets.push(value_buffer.len());
+    /// }
+    /// let metadata = arena.finish();
+    /// let value = &value_buffer[offsets[2]..offsets[3]];
+    /// assert_eq!(Variant::try_new(&metadata, value).unwrap(), Variant::from(2));
+    /// ```
+    pub fn new_builder_with_buffer(&mut self, value_buffer: Vec<u8>) -> ArenaVariantBuilder<'_> {
+        let builder = VariantBuilder {
+            value_start: value_buffer.len(),
+            buffer: ValueBuffer::from(value_buffer),
+            metadata_builder: std::mem::take(&mut self.metadata_builder),
+            validate_unique_fields: self.validate_unique_fields,
+            canonical_assertions: false,
+        };
+        ArenaVariantBuilder {
+            arena: self,
+            builder,
+        }
+    }
+
+    /// Finish the arena and return the shared metadata buffer
+    pub fn finish(self) -> Vec<u8> {
+        self.metadata_builder.finish()
+    }
+}
+
+/// A [`VariantBuilder`] whose field names are stored in a shared [`VariantMetadataArena`].
+///
+/// All [`VariantBuilder`] methods taking `&mut self` are available via [`DerefMut`].
+///
+/// See [`VariantMetadataArena`] for an example.
+///
+/// [`DerefMut`]: std::ops::DerefMut
+#[derive(Debug)]
+pub struct ArenaVariantBuilder<'a> {
+    arena: &'a mut VariantMetadataArena,
+    builder: VariantBuilder,
+}
+
+impl ArenaVariantBuilder<'_> {
+    /// Finish the builder and return the value buffer
+    ///
+    /// The value must be decoded with the metadata returned by [`VariantMetadataArena::finish`].
+    pub fn finish(mut self) -> Vec<u8> {
+        std::mem::take(&mut self.builder.buffer).into_inner()
+    }
+}
+
+/// Returns the dictionary, including any new field names, to the arena
+impl Drop for ArenaVariantBuilder<'_> {
+    fn drop(&mut self) {
+        self.arena.metadata_builder = std::mem::take(&mut self.builder.metadata_builder);
+    }
+}
+
+impl std::ops::Deref for ArenaVariantBuilder<'_> {
+    type Target = VariantBuilder;
+
+    fn deref(&self) -> &Self::Target {
+        &self.builder
+    }
+}
+
+impl std::ops::DerefMut for ArenaVariantBuilder<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.builder
+    }
+}
+
+/// A builder for creating [`Variant::List`] values.
+///
+/// See the examples on [`VariantBuilder`] for usage.
+pub struct ListBuilder<'a> {
+    parent_state: ParentState<'a>,
+    offsets: Vec<usize>,
+    buffer: ValueBuffer,
+    validate_unique_fields: bool,
+    /// Nesting depth of this list, 1 for a top-level list
+    depth: usize,
+}
+
+impl<'a> ListBuilder<'a> {
+    fn new(parent_state: ParentState<'a>, validate_unique_fields: bool) -> Self {
+        let depth = parent_state.depth() + 1;
+        Self {
+            parent_state,
+            offsets: vec![],
+            buffer: ValueBuffer::default(),
+            validate_unique_fields,
+            depth,
+        }
+    }
+
+    /// Enables unique field key validation for objects created within this list.
+    ///
+    /// Propagates the validation flag to any [`ObjectBuilder`]s created using
+    /// [`ListBuilder::new_object`].
     pub fn with_validate_unique_fields(mut self, validate_unique_fields: bool) -> Self {
         self.validate_unique_fields = validate_unique_fields;
         self
@@ -949,6 +1974,7 @@ impl<'a> ListBuilder<'a> {
             buffer: &mut self.buffer,
             metadata_builder: self.parent_state.metadata_builder(),
             offsets: &mut self.offsets,
+            depth: self.depth,
         };
         (state, self.validate_unique_fields)
     }
@@ -956,17 +1982,43 @@ impl<'a> ListBuilder<'a> {
     /// Returns an object builder that can be used to append a new (nested) object to this list.
     ///
     /// WARNING: The builder will have no effect unless/until [`ObjectBuilder::finish`] is called.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the object exceeds the [`VariantLimits`] of the builder.
+    /// For a fallible version, use [`ListBuilder::try_new_object`]
     pub fn new_object(&mut self) -> ObjectBuilder {
-        let (parent_state, validate_unique_fields) = self.parent_state();
-        ObjectBuilder::new(parent_state, validate_unique_fields)
+        self.try_new_object().unwrap()
     }
 
     /// Returns a list builder that can be used to append a new (nested) list to this list.
     ///
     /// WARNING: The builder will have no effect unless/until [`ListBuilder::finish`] is called.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the list exceeds the [`VariantLimits`] of the builder.
+    /// For a fallible version, use [`ListBuilder::try_new_list`]
     pub fn new_list(&mut self) -> ListBuilder {
+        self.try_new_list().unwrap()
+    }
+
+    /// Returns an object builder for a new (nested) object like [`ListBuilder::new_object`],
+    /// or an error if the object exceeds the [`VariantLimits`] of the builder
+    pub fn try_new_object(&mut self) -> Result<ObjectBuilder<'_>, ArrowError> {
+        let depth = self.depth + 1;
+        self.parent_state.metadata_builder().check_depth(depth)?;
         let (parent_state, validate_unique_fields) = self.parent_state();
-        ListBuilder::new(parent_state, validate_unique_fields)
+        Ok(ObjectBuilder::new(parent_state, validate_unique_fields))
+    }
+
+    /// Returns a list builder for a new (nested) list like [`ListBuilder::new_list`], or an
+    /// error if the list exceeds the [`VariantLimits`] of the builder
+    pub fn try_new_list(&mut self) -> Result<ListBuilder<'_>, ArrowError> {
+        let depth = self.depth + 1;
+        self.parent_state.metadata_builder().check_depth(depth)?;
+        let (parent_state, validate_unique_fields) = self.parent_state();
+        Ok(ListBuilder::new(parent_state, validate_unique_fields))
     }
 
     /// Appends a variant to the list.
@@ -974,29 +2026,96 @@ impl<'a> ListBuilder<'a> {
     /// # Panics
     ///
     /// This method will panic if the variant contains duplicate field names in objects
-    /// when validation is enabled. For a fallible version, use [`ListBuilder::try_append_value`].
+    /// when validation is enabled, a value whose type is not supported by the
+    /// [`VariantSpecVersion`] of the builder, or a [`Variant::TimestampNanos`] or
+    /// [`Variant::TimestampNtzNanos`] outside of the range of an `i64` of nanoseconds
+    /// since the epoch. For a fallible version, use [`ListBuilder::try_append_value`].
     pub fn append_value<'m, 'd, T: Into<Variant<'m, 'd>>>(&mut self, value: T) {
         self.try_append_value(value).unwrap();
     }
 
     /// Appends a new primitive value to this list
+    ///
+    /// The list is unchanged if an error is returned.
     pub fn try_append_value<'m, 'd, T: Into<Variant<'m, 'd>>>(
         &mut self,
         value: T,
     ) -> Result<(), ArrowError> {
-        self.offsets.push(self.buffer.offset());
-        self.buffer
-            .try_append_variant(value.into(), self.parent_state.metadata_builder())?;
+        let offset = self.buffer.offset();
+        if let Err(e) = self
+            .buffer
+            .try_append_variant(value.into(), self.parent_state.metadata_builder())
+        {
+            self.buffer.inner_mut().truncate(offset);
+            return Err(e);
+        }
+        self.offsets.push(offset);
+        Ok(())
+    }
 
+    /// Appends each variant of `values` to the list.
+    ///
+    /// This is faster than calling [`ListBuilder::append_value`] for each value, as space
+    /// for the offsets and values is reserved up front.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a variant contains duplicate field names in objects
+    /// when validation is enabled. For a fallible version, use [`ListBuilder::append_iter`].
+    pub fn extend<'m, 'd, T, I>(&mut self, values: I)
+    where
+        T: Into<Variant<'m, 'd>>,
+        I: IntoIterator<Item = T>,
+    {
+        self.append_iter(values).unwrap();
+    }
+
+    /// Appends each variant of `values` to the list, see [`ListBuilder::extend`]
+    ///
+    /// The list is unchanged if an error is returned.
+    pub fn append_iter<'m, 'd, T, I>(&mut self, values: I) -> Result<(), ArrowError>
+    where
+        T: Into<Variant<'m, 'd>>,
+        I: IntoIterator<Item = T>,
+    {
+        let values = values.into_iter();
+        let num_offsets = self.offsets.len();
+        let data_size = self.buffer.offset();
+        // Every value takes at least its header byte
+        let (lower, _) = values.size_hint();
+        self.offsets.reserve(lower);
+        self.buffer.inner_mut().reserve(lower);
+
+        let metadata_builder = self.parent_state.metadata_builder();
+        for value in values {
+            self.offsets.push(self.buffer.offset());
+            if let Err(e) = self
+                .buffer
+                .try_append_variant(value.into(), metadata_builder)
+            {
+                self.offsets.truncate(num_offsets);
+                self.buffer.inner_mut().truncate(data_size);
+                return Err(e);
+            }
+        }
         Ok(())
     }
 
+    /// Appends an already encoded value to this list without decoding it.
+    ///
+    /// See [`VariantBuilder::append_raw`] for the requirements on `value`.
+    pub(crate) fn append_raw(&mut self, value: &[u8]) {
+        self.offsets.push(self.buffer.offset());
+        self.buffer.append_slice(value);
+    }
+
     /// Finalizes this list and appends it to its parent, which otherwise remains unmodified.
     pub fn finish(mut self) {
+        let layout = self.parent_state.metadata_builder().layout;
         let data_size = self.buffer.offset();
         let num_elements = self.offsets.len();
-        let is_large = num_elements > u8::MAX as usize;
-        let offset_size = int_size(data_size);
+        let is_large = layout.large || num_elements > u8::MAX as usize;
+        let offset_size = int_size(data_size).max(layout.offset_size);
 
         // Get parent's buffer
         let parent_buffer = self.parent_state.buffer();
@@ -1032,16 +2151,20 @@ pub struct ObjectBuilder<'a> {
     validate_unique_fields: bool,
     /// Set of duplicate fields to report for errors
     duplicate_fields: HashSet<u32>,
+    /// Nesting depth of this object, 1 for a top-level object
+    depth: usize,
 }
 
 impl<'a> ObjectBuilder<'a> {
     fn new(parent_state: ParentState<'a>, validate_unique_fields: bool) -> Self {
+        let depth = parent_state.depth() + 1;
         Self {
             parent_state,
             fields: IndexMap::new(),
             buffer: ValueBuffer::default(),
             validate_unique_fields,
             duplicate_fields: HashSet::new(),
+            depth,
         }
     }
 
@@ -1050,7 +2173,11 @@ impl<'a> ObjectBuilder<'a> {
     /// # Panics
     ///
     /// This method will panic if the variant contains duplicate field names in objects
-    /// when validation is enabled. For a fallible version, use [`ObjectBuilder::try_insert`]
+    /// when validation is enabled, a value whose type is not supported by the
+    /// [`VariantSpecVersion`] of the builder, a [`Variant::TimestampNanos`] or
+    /// [`Variant::TimestampNtzNanos`] outside of the range of an `i64` of nanoseconds
+    /// since the epoch, or if the new field exceeds the [`VariantLimits`] of the builder.
+    /// For a fallible version, use [`ObjectBuilder::try_insert`]
     pub fn insert<'m, 'd, T: Into<Variant<'m, 'd>>>(&mut self, key: &str, value: T) {
         self.try_insert(key, value).unwrap();
     }
@@ -1059,6 +2186,8 @@ impl<'a> ObjectBuilder<'a> {
     ///
     /// Note: when inserting duplicate keys, the new value overwrites the previous mapping,
     /// but the old value remains in the buffer, resulting in a larger variant
+    ///
+    /// The object is unchanged if an error is returned.
     pub fn try_insert<'m, 'd, T: Into<Variant<'m, 'd>>>(
         &mut self,
         key: &str,
@@ -1066,20 +2195,124 @@ impl<'a> ObjectBuilder<'a> {
     ) -> Result<(), ArrowError> {
         // Get metadata_builder from parent state
         let metadata_builder = self.parent_state.metadata_builder();
+        metadata_builder.check_new_field(&self.fields, key)?;
 
         let field_id = metadata_builder.upsert_field_name(key);
         let field_start = self.buffer.offset();
 
+        if let Err(e) = self
+            .buffer
+            .try_append_variant(value.into(), metadata_builder)
+        {
+            self.buffer.inner_mut().truncate(field_start);
+            return Err(e);
+        }
+
         if self.fields.insert(field_id, field_start).is_some() && self.validate_unique_fields {
             self.duplicate_fields.insert(field_id);
         }
+        Ok(())
+    }
 
-        self.buffer
-            .try_append_variant(value.into(), metadata_builder)?;
+    /// Add a field whose value is already encoded, without decoding it.
+    ///
+    /// See [`VariantBuilder::append_raw`] for the requirements on `value`.
+    pub(crate) fn insert_raw(&mut self, key: &str, value: &[u8]) {
+        let field_id = self.parent_state.metadata_builder().upsert_field_name(key);
+        let field_start = self.buffer.offset();
 
+        if self.fields.insert(field_id, field_start).is_some() && self.validate_unique_fields {
+            self.duplicate_fields.insert(field_id);
+        }
+
+        self.buffer.append_slice(value);
+    }
+
+    /// Add all the `(key, value)` pairs from `iter` to the object, in order
+    ///
+    /// Space for the fields is reserved up front using the iterator's size hint.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the variant contains duplicate field names in objects
+    /// when validation is enabled. For a fallible version, use [`ObjectBuilder::try_extend`]
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let map = BTreeMap::from([("a", 1), ("b", 2)]);
+    ///
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("c", "three");
+    /// obj.extend(map.iter().map(|(k, v)| (*k, *v)));
+    /// obj.finish().unwrap();
+    ///
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::new(&metadata, &value);
+    /// let obj = variant.as_object().unwrap();
+    /// assert_eq!(obj.len(), 3);
+    /// assert_eq!(obj.get("b"), Some(Variant::from(2)));
+    /// ```
+    pub fn extend<'k, 'm, 'd, T, I>(&mut self, iter: I)
+    where
+        T: Into<Variant<'m, 'd>>,
+        I: IntoIterator<Item = (&'k str, T)>,
+    {
+        self.try_extend(iter).unwrap();
+    }
+
+    /// Add all the `(key, value)` pairs from `iter` to the object, in order
+    ///
+    /// See [`ObjectBuilder::extend`] for details
+    pub fn try_extend<'k, 'm, 'd, T, I>(&mut self, iter: I) -> Result<(), ArrowError>
+    where
+        T: Into<Variant<'m, 'd>>,
+        I: IntoIterator<Item = (&'k str, T)>,
+    {
+        let iter = iter.into_iter();
+        let (additional, _) = iter.size_hint();
+        self.fields.reserve(additional);
+        self.parent_state
+            .metadata_builder()
+            .field_names
+            .reserve(additional);
+        // every value requires at least a one byte header
+        self.buffer.inner_mut().reserve(additional);
+
+        for (key, value) in iter {
+            self.try_insert(key, value)?;
+        }
         Ok(())
     }
 
+    /// Reserves space for `num_fields` fields, whose encoded values take about
+    /// `approx_value_bytes` bytes in total, and returns self.
+    ///
+    /// This is only a hint: objects with more fields or larger values can still be built, but
+    /// sizing the buffers up front avoids growing them while inserting the fields of large
+    /// objects.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let mut builder = VariantBuilder::new();
+    /// let mut object = builder.new_object().with_capacity(100, 200);
+    /// for i in 0..100 {
+    ///     object.insert(&format!("field_{i}"), i as i16);
+    /// }
+    /// object.finish().unwrap();
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value).unwrap();
+    /// assert_eq!(variant.get_object_field("field_42"), Some(Variant::Int16(42)));
+    /// ```
+    pub fn with_capacity(mut self, num_fields: usize, approx_value_bytes: usize) -> Self {
+        self.fields.reserve(num_fields);
+        self.buffer.inner_mut().reserve(approx_value_bytes);
+        self
+    }
+
     /// Enables validation for unique field keys when inserting into this object.
     ///
     /// When this is enabled, calling [`ObjectBuilder::finish`] will return an error
@@ -1096,6 +2329,7 @@ impl<'a> ObjectBuilder<'a> {
             metadata_builder: self.parent_state.metadata_builder(),
             fields: &mut self.fields,
             field_name: key,
+            depth: self.depth,
         };
         (state, self.validate_unique_fields)
     }
@@ -1103,17 +2337,49 @@ impl<'a> ObjectBuilder<'a> {
     /// Returns an object builder that can be used to append a new (nested) object to this object.
     ///
     /// WARNING: The builder will have no effect unless/until [`ObjectBuilder::finish`] is called.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the object exceeds the [`VariantLimits`] of the builder.
+    /// For a fallible version, use [`ObjectBuilder::try_new_object`]
     pub fn new_object<'b>(&'b mut self, key: &'b str) -> ObjectBuilder<'b> {
-        let (parent_state, validate_unique_fields) = self.parent_state(key);
-        ObjectBuilder::new(parent_state, validate_unique_fields)
+        self.try_new_object(key).unwrap()
     }
 
     /// Returns a list builder that can be used to append a new (nested) list to this object.
     ///
     /// WARNING: The builder will have no effect unless/until [`ListBuilder::finish`] is called.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the list exceeds the [`VariantLimits`] of the builder.
+    /// For a fallible version, use [`ObjectBuilder::try_new_list`]
     pub fn new_list<'b>(&'b mut self, key: &'b str) -> ListBuilder<'b> {
+        self.try_new_list(key).unwrap()
+    }
+
+    /// Returns an object builder for a new (nested) object like [`ObjectBuilder::new_object`],
+    /// or an error if the object or the new field exceed the [`VariantLimits`] of the builder
+    pub fn try_new_object<'b>(&'b mut self, key: &'b str) -> Result<ObjectBuilder<'b>, ArrowError> {
+        self.check_nested(key)?;
         let (parent_state, validate_unique_fields) = self.parent_state(key);
-        ListBuilder::new(parent_state, validate_unique_fields)
+        Ok(ObjectBuilder::new(parent_state, validate_unique_fields))
+    }
+
+    /// Returns a list builder for a new (nested) list like [`ObjectBuilder::new_list`], or an
+    /// error if the list or the new field exceed the [`VariantLimits`] of the builder
+    pub fn try_new_list<'b>(&'b mut self, key: &'b str) -> Result<ListBuilder<'b>, ArrowError> {
+        self.check_nested(key)?;
+        let (parent_state, validate_unique_fields) = self.parent_state(key);
+        Ok(ListBuilder::new(parent_state, validate_unique_fields))
+    }
+
+    /// Checks the limits for a new nested list or object in field `key`
+    fn check_nested(&mut self, key: &str) -> Result<(), ArrowError> {
+        let depth = self.depth + 1;
+        let metadata_builder = self.parent_state.metadata_builder();
+        metadata_builder.check_depth(depth)?;
+        metadata_builder.check_new_field(&self.fields, key)
     }
 
     /// Finalizes this object and appends it to its parent, which otherwise remains unmodified.
@@ -1134,9 +2400,10 @@ impl<'a> ObjectBuilder<'a> {
             )));
         }
 
+        let layout = metadata_builder.layout;
         let data_size = self.buffer.offset();
         let num_fields = self.fields.len();
-        let is_large = num_fields > u8::MAX as usize;
+        let is_large = layout.large || num_fields > u8::MAX as usize;
 
         self.fields.sort_by(|&field_a_id, _, &field_b_id, _| {
             let key_a = &metadata_builder.field_name(field_a_id as usize);
@@ -1146,12 +2413,18 @@ impl<'a> ObjectBuilder<'a> {
 
         let max_id = self.fields.iter().map(|(i, _)| *i).max().unwrap_or(0);
 
-        let id_size = int_size(max_id as usize);
-        let offset_size = int_size(data_size);
+        let id_size = int_size(max_id as usize).max(layout.field_id_size);
+        let offset_size = int_size(data_size).max(layout.offset_size);
 
-        // Get parent's buffer
+        // Get parent's buffer, sized for the header, field ids, offsets and values at once
         let parent_buffer = self.parent_state.buffer();
         let starting_offset = parent_buffer.offset();
+        let num_fields_size = if is_large { 4 } else { 1 };
+        let header_size = 1 + num_fields_size + num_fields * id_size as usize;
+        let offsets_size = (num_fields + 1) * offset_size as usize;
+        parent_buffer
+            .inner_mut()
+            .reserve(header_size + offsets_size + data_size);
 
         // Write header
         let header = object_header(is_large, id_size, offset_size);
@@ -1179,6 +2452,136 @@ impl Drop for ObjectBuilder<'_> {
     fn drop(&mut self) {}
 }
 
+/// Writes the encoded `value` to `target`, re-encoding its objects and lists with the field ids
+/// of `target` and copying its primitive values, see [`VariantBuilder::append_encoded`]
+fn append_encoded_value<T: AppendTarget>(
+    target: &mut T,
+    metadata: &VariantMetadata<'_>,
+    value: &[u8],
+) -> Result<(), ArrowError> {
+    let header = first_byte_from_slice(value)?;
+    let len = match get_basic_type(header) {
+        VariantBasicType::Object => {
+            let object = VariantObject::try_new_with_shallow_validation(metadata.clone(), value)?;
+            let mut builder = target.new_object();
+            for i in 0..object.len() {
+                let mut field = ObjectFieldTarget {
+                    builder: &mut builder,
+                    key: object.try_field_name(i)?,
+                };
+                append_encoded_value(&mut field, metadata, object.try_field_bytes(i)?)?;
+            }
+            return builder.finish();
+        }
+        VariantBasicType::Array => {
+            let list = VariantList::try_new_with_shallow_validation(metadata.clone(), value)?;
+            let mut builder = target.new_list();
+            for i in 0..list.len() {
+                append_encoded_value(&mut builder, metadata, list.try_get_bytes(i)?)?;
+            }
+            builder.finish();
+            return Ok(());
+        }
+        VariantBasicType::ShortString => 1 + (header >> 2) as usize,
+        VariantBasicType::Primitive => match get_primitive_type(header)?.fixed_size() {
+            Some(size) => 1 + size,
+            None => 5 + u32::from_le_bytes(array_from_slice(value, 1)?) as usize,
+        },
+    };
+
+    // Field values of objects extend to the end of the object, so slice the primitive value
+    let value = slice_from_slice(value, ..len)?;
+    let variant = Variant::try_new_with_metadata(metadata.clone(), value)?;
+    target.spec_version().check(&variant)?;
+    target.append_raw(value);
+    Ok(())
+}
+
+/// A location a single value can be written to
+pub(crate) trait AppendTarget {
+    fn append_value(&mut self, value: Variant<'_, '_>) -> Result<(), ArrowError>;
+
+    fn append_raw(&mut self, value: &[u8]);
+
+    fn spec_version(&mut self) -> VariantSpecVersion;
+
+    fn new_list(&mut self) -> ListBuilder<'_>;
+
+    fn new_object(&mut self) -> ObjectBuilder<'_>;
+}
+
+impl AppendTarget for VariantBuilder {
+    fn append_value(&mut self, value: Variant<'_, '_>) -> Result<(), ArrowError> {
+        self.try_append_value(value)
+    }
+
+    fn append_raw(&mut self, value: &[u8]) {
+        VariantBuilder::append_raw(self, value)
+    }
+
+    fn spec_version(&mut self) -> VariantSpecVersion {
+        self.metadata_builder.spec_version
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        VariantBuilder::new_list(self)
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        VariantBuilder::new_object(self)
+    }
+}
+
+impl AppendTarget for ListBuilder<'_> {
+    fn append_value(&mut self, value: Variant<'_, '_>) -> Result<(), ArrowError> {
+        self.try_append_value(value)
+    }
+
+    fn append_raw(&mut self, value: &[u8]) {
+        ListBuilder::append_raw(self, value)
+    }
+
+    fn spec_version(&mut self) -> VariantSpecVersion {
+        self.parent_state.metadata_builder().spec_version
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        ListBuilder::new_list(self)
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        ListBuilder::new_object(self)
+    }
+}
+
+/// A field of an object being built
+pub(crate) struct ObjectFieldTarget<'o, 'a, 'k> {
+    pub(crate) builder: &'o mut ObjectBuilder<'a>,
+    pub(crate) key: &'k str,
+}
+
+impl AppendTarget for ObjectFieldTarget<'_, '_, '_> {
+    fn append_value(&mut self, value: Variant<'_, '_>) -> Result<(), ArrowError> {
+        self.builder.try_insert(self.key, value)
+    }
+
+    fn append_raw(&mut self, value: &[u8]) {
+        self.builder.insert_raw(self.key, value)
+    }
+
+    fn spec_version(&mut self) -> VariantSpecVersion {
+        self.builder.parent_state.metadata_builder().spec_version
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        self.builder.new_list(self.key)
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        self.builder.new_object(self.key)
+    }
+}
+
 /// Extends [`VariantBuilder`] to help building nested [`Variant`]s
 ///
 /// Allows users to append values to a [`VariantBuilder`], [`ListBuilder`] or
@@ -1200,22 +2603,74 @@ impl<'m, 'v> VariantBuilderExt<'m, 'v> for ListBuilder<'_> {
         self.new_list()
     }
 
-    fn new_object(&mut self) -> ObjectBuilder {
-        self.new_object()
+    fn new_object(&mut self) -> ObjectBuilder {
+        self.new_object()
+    }
+}
+
+impl<'m, 'v> VariantBuilderExt<'m, 'v> for VariantBuilder {
+    fn append_value(&mut self, value: impl Into<Variant<'m, 'v>>) {
+        self.append_value(value);
+    }
+
+    fn new_list(&mut self) -> ListBuilder {
+        self.new_list()
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder {
+        self.new_object()
+    }
+}
+
+/// A field of an [`ObjectBuilder`], which a value can be appended to with
+/// [`VariantBuilderExt`]
+///
+/// This allows writing code that builds a value generically, whether it is the top-level
+/// value of a [`VariantBuilder`], an element of a list or a field of an object.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{ObjectFieldBuilder, Variant, VariantBuilder, VariantBuilderExt};
+/// fn append_pair<'m, 'v>(builder: &mut impl VariantBuilderExt<'m, 'v>) {
+///     let mut list = builder.new_list();
+///     list.append_value(1);
+///     list.append_value(2);
+///     list.finish();
+/// }
+///
+/// let mut builder = VariantBuilder::new();
+/// let mut obj = builder.new_object();
+/// append_pair(&mut ObjectFieldBuilder::new("pair", &mut obj));
+/// obj.finish().unwrap();
+/// let (metadata, value) = builder.finish();
+///
+/// let variant = Variant::try_new(&metadata, &value).unwrap();
+/// let pair = variant.get_object_field("pair").unwrap();
+/// assert!(matches!(pair, Variant::List(list) if list.len() == 2));
+/// ```
+pub struct ObjectFieldBuilder<'o, 'a, 'k> {
+    builder: &'o mut ObjectBuilder<'a>,
+    key: &'k str,
+}
+
+impl<'o, 'a, 'k> ObjectFieldBuilder<'o, 'a, 'k> {
+    /// Creates a builder for the field `key` of the object being built by `builder`
+    pub fn new(key: &'k str, builder: &'o mut ObjectBuilder<'a>) -> Self {
+        Self { builder, key }
     }
 }
 
-impl<'m, 'v> VariantBuilderExt<'m, 'v> for VariantBuilder {
+impl<'m, 'v> VariantBuilderExt<'m, 'v> for ObjectFieldBuilder<'_, '_, '_> {
     fn append_value(&mut self, value: impl Into<Variant<'m, 'v>>) {
-        self.append_value(value);
+        self.builder.insert(self.key, value);
     }
 
-    fn new_list(&mut self) -> ListBuilder {
-        self.new_list()
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        self.builder.new_list(self.key)
     }
 
-    fn new_object(&mut self) -> ObjectBuilder {
-        self.new_object()
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        self.builder.new_object(self.key)
     }
 }
 
@@ -1324,6 +2779,76 @@ mod tests {
             let variant = Variant::try_new(&metadata, &value).unwrap();
             assert_eq!(variant, Variant::Binary(binary_data.as_slice()));
         }
+
+        {
+            let mut builder = VariantBuilder::new();
+            let uuid = [
+                0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+                0xe0, 0xc8,
+            ];
+            builder.append_value(Variant::Uuid(uuid));
+            let (metadata, value) = builder.finish();
+            // header byte (primitive type 20) followed by the 16 big-endian bytes
+            assert_eq!(value[0], 20 << 2);
+            assert_eq!(&value[1..], &uuid);
+            let variant = Variant::try_new(&metadata, &value).unwrap();
+            assert_eq!(variant, Variant::Uuid(uuid));
+        }
+    }
+
+    #[test]
+    fn test_time_micros() {
+        let time = chrono::NaiveTime::from_hms_micro_opt(17, 14, 56, 780012).unwrap();
+
+        let mut builder = VariantBuilder::new();
+        builder.append_value(time);
+        let (metadata, value) = builder.finish();
+        assert_eq!(value[0], 17 << 2);
+        let micros = ((17 * 60 + 14) * 60 + 56) * 1_000_000 + 780012_i64;
+        assert_eq!(&value[1..], &micros.to_le_bytes());
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(variant, Variant::Time(time));
+        assert_eq!(variant.as_naive_time(), Some(time));
+
+        // sub-microsecond precision is truncated
+        let time = chrono::NaiveTime::from_hms_nano_opt(1, 2, 3, 4_005_006).unwrap();
+        let mut builder = VariantBuilder::new();
+        builder.append_value(time);
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let expected = chrono::NaiveTime::from_hms_micro_opt(1, 2, 3, 4_005).unwrap();
+        assert_eq!(variant, Variant::Time(expected));
+    }
+
+    #[test]
+    fn test_timestamp_nanos() {
+        let timestamp = chrono::DateTime::from_timestamp(1703500245, 123456789).unwrap();
+
+        let mut builder = VariantBuilder::new();
+        builder.append_value(Variant::TimestampNanos(timestamp));
+        let (metadata, value) = builder.finish();
+        assert_eq!(value[0], 18 << 2);
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(variant, Variant::TimestampNanos(timestamp));
+        assert_eq!(variant.as_datetime_utc(), Some(timestamp));
+
+        let mut builder = VariantBuilder::new();
+        builder.append_value(Variant::TimestampNtzNanos(timestamp.naive_utc()));
+        let (metadata, value) = builder.finish();
+        assert_eq!(value[0], 19 << 2);
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(variant, Variant::TimestampNtzNanos(timestamp.naive_utc()));
+        assert_eq!(variant.as_naive_datetime(), Some(timestamp.naive_utc()));
+
+        // nanosecond timestamps can only represent ~584 years around the epoch
+        let out_of_range = chrono::DateTime::from_timestamp(10_413_792_000, 0).unwrap();
+        let mut builder = VariantBuilder::new();
+        let err = builder
+            .try_append_value(Variant::TimestampNanos(out_of_range))
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("is out of range for nanosecond precision"));
     }
 
     #[test]
@@ -1359,6 +2884,295 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_list_extend() {
+        let values: Vec<Variant> = vec![1i8.into(), "test".into(), Variant::Null, 1.5f64.into()];
+
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        for value in &values {
+            list.append_value(value.clone());
+        }
+        list.finish();
+        let expected = builder.finish();
+
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_value(1i8);
+        list.extend(values[1..].iter().cloned());
+        list.finish();
+        assert_eq!(builder.finish(), expected);
+
+        // The list is unchanged by a failed append
+        let out_of_range = chrono::DateTime::from_timestamp(10_413_792_000, 0).unwrap();
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_iter(values[..2].iter().cloned()).unwrap();
+        let invalid = [Variant::from(2i8), Variant::TimestampNanos(out_of_range)];
+        let err = list.append_iter(invalid).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("is out of range for nanosecond precision"));
+        list.extend(values[2..].iter().cloned());
+        list.finish();
+        assert_eq!(builder.finish(), expected);
+
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.extend(0..1000i32);
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let list = variant.as_list().unwrap();
+        assert_eq!(list.len(), 1000);
+        assert_eq!(list.get(999), Some(Variant::Int32(999)));
+    }
+
+    #[test]
+    fn test_limits_depth() {
+        let limits = VariantLimits::new().with_max_depth(2);
+        let mut builder = VariantBuilder::new().with_limits(limits);
+        let mut list = builder.try_new_list().unwrap();
+        let mut object = list.try_new_object().unwrap();
+        let err = object.try_new_list("a").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant nesting depth 3 exceeds the maximum of 2"
+        );
+        assert!(object.try_new_object("a").is_err());
+        object.insert("a", 1);
+        object.finish().unwrap();
+        assert!(list.try_new_list().unwrap().try_new_list().is_err());
+        list.finish();
+        builder.finish();
+
+        let mut builder = VariantBuilder::new().with_limits(VariantLimits::new().with_max_depth(0));
+        assert!(builder.try_new_object().is_err());
+        assert!(builder.try_new_list().is_err());
+        builder.append_value(1);
+    }
+
+    #[test]
+    fn test_limits_fields() {
+        let limits = VariantLimits::new().with_max_field_count(2);
+        let mut builder = VariantBuilder::new().with_limits(limits);
+        let mut object = builder.try_new_object().unwrap();
+        object.try_insert("a", 1).unwrap();
+        object.try_new_list("b").unwrap().finish();
+        // Existing fields can be overwritten
+        object.try_insert("a", 2).unwrap();
+        object.try_new_object("b").unwrap().finish().unwrap();
+        let err = object.try_insert("c", 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant object field 'c' exceeds the maximum of 2 fields"
+        );
+        assert!(object.try_new_list("c").is_err());
+        object.finish().unwrap();
+        builder.finish();
+
+        // The metadata of the field names "a" and "b" takes 5 bytes
+        let limits = VariantLimits::new().with_max_metadata_size(10);
+        let mut builder = VariantBuilder::new().with_limits(limits);
+        let mut object = builder.try_new_object().unwrap();
+        object.try_insert("a", 1).unwrap();
+        object.try_insert("b", 1).unwrap();
+        assert!(object.try_insert("long", 1).is_err());
+        assert!(object.try_new_object("long").is_err());
+        // Existing field names do not grow the metadata
+        let mut nested = object.try_new_object("a").unwrap();
+        nested.try_insert("b", 1).unwrap();
+        nested.finish().unwrap();
+        object.finish().unwrap();
+        let (metadata, _) = builder.finish();
+        assert!(metadata.len() <= 10);
+    }
+
+    #[test]
+    fn test_spec_version() {
+        let mut source = VariantBuilder::new();
+        let mut object = source.new_object();
+        object.insert("a", 1i64);
+        object.insert("b", Variant::Uuid([1; 16]));
+        object.finish().unwrap();
+        let (metadata, value) = source.finish();
+        let variant = Variant::new(&metadata, &value);
+
+        let initial = || VariantBuilder::new().with_spec_version(VariantSpecVersion::Initial);
+        let err = initial()
+            .try_append_value(Variant::Uuid([1; 16]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant spec version Initial does not support Uuid([1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1])"
+        );
+        let nanos = chrono::DateTime::from_timestamp_nanos(1);
+        assert!(initial()
+            .try_append_value(Variant::TimestampNanos(nanos))
+            .is_err());
+        assert!(initial()
+            .try_append_value(Variant::TimestampNtzNanos(nanos.naive_utc()))
+            .is_err());
+        // Nested values are checked, whether they are decoded or copied
+        assert!(initial().try_append_value(variant).is_err());
+        assert!(initial().append_encoded(&metadata, &value).is_err());
+
+        let mut builder = initial();
+        builder.append_value(chrono::DateTime::from_timestamp_micros(1).unwrap());
+        let (m, v) = builder.finish();
+        Variant::try_new(&m, &v).unwrap();
+
+        let mut builder = VariantBuilder::new().with_spec_version(VariantSpecVersion::Current);
+        builder.append_encoded(&metadata, &value).unwrap();
+        assert_eq!(builder.finish(), (metadata, value));
+
+        // A list or object is unchanged by an unsupported value
+        let mut builder = initial();
+        let mut object = builder.new_object();
+        let mut list = object.new_list("l");
+        list.append_value(1i64);
+        assert!(list.try_append_value(Variant::Uuid([1; 16])).is_err());
+        list.append_value(2i64);
+        list.finish();
+        assert!(object.try_insert("u", Variant::Uuid([1; 16])).is_err());
+        object.finish().unwrap();
+        let (m, v) = builder.finish();
+        let variant = Variant::try_new(&m, &v).unwrap();
+        let object = variant.as_object().unwrap();
+        assert_eq!(object.len(), 1);
+        assert!(object.get("u").is_none());
+        let list = object.get("l").unwrap();
+        let list = list.as_list().unwrap();
+        let values: Vec<_> = list.iter().collect();
+        assert_eq!(values, [Variant::Int64(1), Variant::Int64(2)]);
+    }
+
+    #[test]
+    fn test_canonical_assertions() {
+        // {"b": {"a": [1, "x"]}}
+        let build = |mut builder: VariantBuilder| {
+            let mut object = builder.new_object();
+            let mut inner = object.new_object("b");
+            let mut list = inner.new_list("a");
+            list.append_value(1);
+            list.append_value("x");
+            list.finish();
+            inner.finish().unwrap();
+            object.finish().unwrap();
+            builder
+        };
+        let strict = || VariantBuilder::new().with_canonical_assertions(true);
+
+        let (metadata, value) = build(strict()).finish();
+        assert!(check_canonical(&metadata, &value).is_ok());
+        // The dictionary is only sorted by finish_sorted
+        let unsorted = || strict().with_field_names(["b", "a"].into_iter());
+        let result = std::panic::catch_unwind(|| build(unsorted()).finish());
+        assert!(result.is_err());
+        let (m, v) = build(unsorted()).finish_sorted();
+        assert_eq!((&m, &v), (&metadata, &value));
+
+        // Existing data in the buffers is ignored
+        let builder = strict().with_field_names(["a", "b"].into_iter());
+        let (m, v) = build(VariantBuilder {
+            buffer: ValueBuffer::from(vec![0xFF]),
+            metadata_builder: MetadataBuilder {
+                metadata_buffer: vec![0xFF],
+                ..builder.metadata_builder
+            },
+            value_start: 1,
+            ..builder
+        })
+        .finish();
+        assert_eq!((&m[1..], &v[1..]), (&metadata[..], &value[..]));
+
+        let layout = VariantLayout::new().with_offset_size(2);
+        let result = std::panic::catch_unwind(|| build(strict().with_layout(layout)).finish());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Variant is not canonical: metadata dictionary contains unused field names"
+    )]
+    fn test_canonical_assertions_unused_field_names() {
+        let mut builder = VariantBuilder::new()
+            .with_canonical_assertions(true)
+            .with_field_names(["a", "b"].into_iter());
+        builder.append_value(1);
+        builder.finish();
+    }
+
+    #[test]
+    fn test_layout() {
+        let layout = VariantLayout::new()
+            .with_large(true)
+            .with_offset_size(3)
+            .with_field_id_size(2);
+        let build = |layout: VariantLayout, values: &[&str]| {
+            let mut builder = VariantBuilder::new().with_layout(layout);
+            let mut object = builder.new_object();
+            let mut list = object.new_list("l");
+            list.extend(values.iter().copied());
+            list.finish();
+            object.finish().unwrap();
+            builder.finish()
+        };
+
+        let (metadata, short) = build(layout, &["a"]);
+        let (_, long) = build(layout, &["a", "b", "c"]);
+        // object header, 4 byte field count, 2 byte field id and 2 offsets of 3 bytes
+        let header_len = 1 + 4 + 2 + 2 * 3;
+        assert_eq!(short[..header_len - 3], long[..header_len - 3]);
+        // large list header with 3 byte offsets, then the 4 byte element count
+        assert_eq!(short[header_len], long[header_len]);
+        assert_eq!(short[header_len..header_len + 5], [0b11011, 1, 0, 0, 0]);
+
+        let variant = Variant::try_new(&metadata, &short).unwrap();
+        let list = variant.get_object_field("l").unwrap();
+        assert_eq!(list.get_list_element(0), Some(Variant::from("a")));
+        let (_, default_value) = build(VariantLayout::new(), &["a"]);
+        assert_eq!(default_value.len() + 3 + 1 + 4 + 3 + 2 * 2, short.len());
+
+        // Appended variants are re-encoded with the layout
+        let mut builder = VariantBuilder::new().with_layout(layout);
+        builder.append_value(variant.clone());
+        let (metadata, value) = builder.finish();
+        assert_eq!(value, short);
+        assert_eq!(Variant::try_new(&metadata, &value).unwrap(), variant);
+    }
+
+    #[test]
+    fn test_object_with_capacity() {
+        let build = |capacity: Option<(usize, usize)>| {
+            let mut builder = VariantBuilder::new();
+            let mut list = builder.new_list();
+            for num_fields in [0, 3, 300] {
+                let mut object = list.new_object();
+                if let Some((num_fields, approx_value_bytes)) = capacity {
+                    object = object.with_capacity(num_fields, approx_value_bytes);
+                }
+                for i in (0..num_fields).rev() {
+                    object.insert(&format!("f{i}"), i);
+                }
+                object.finish().unwrap();
+            }
+            list.finish();
+            builder.finish()
+        };
+        let expected = build(None);
+        assert_eq!(build(Some((300, 1500))), expected);
+        // The capacity is only a hint
+        assert_eq!(build(Some((1, 0))), expected);
+
+        let (metadata, value) = expected;
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let object = variant.as_list().unwrap().get(2).unwrap();
+        let object = object.as_object().unwrap();
+        assert_eq!(object.len(), 300);
+        assert_eq!(object.get("f299"), Some(Variant::Int32(299)));
+    }
+
     #[test]
     fn test_object() {
         let mut builder = VariantBuilder::new();
@@ -1402,6 +3216,59 @@ mod tests {
         assert_eq!(field_ids, vec![1, 2, 0]);
     }
 
+    #[test]
+    fn test_object_extend() {
+        let map = std::collections::BTreeMap::from([("b", 2i32), ("a", 1i32)]);
+
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("c", "three");
+        obj.extend(map.iter().map(|(k, v)| (*k, *v)));
+        obj.finish().unwrap();
+
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let obj = variant.as_object().unwrap();
+        assert_eq!(
+            obj.iter().collect::<Vec<_>>(),
+            vec![
+                ("a", Variant::from(1)),
+                ("b", Variant::from(2)),
+                ("c", Variant::from("three")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variant_builder_append_object() {
+        let mut builder = VariantBuilder::new();
+        builder.append_object([("x", Variant::from(1.5f64)), ("y", Variant::Null)]);
+
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(variant.get_object_field("x"), Some(Variant::from(1.5f64)));
+        assert_eq!(variant.get_object_field("y"), Some(Variant::Null));
+
+        // empty iterators produce an empty object
+        let mut builder = VariantBuilder::new();
+        builder.append_object(std::iter::empty::<(&str, i32)>());
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert!(variant.as_object().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_try_append_object_duplicate_fields() {
+        let mut builder = VariantBuilder::new().with_validate_unique_fields(true);
+        let err = builder
+            .try_append_object([("a", 1), ("b", 2), ("a", 3)])
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Duplicate field keys detected: [a]"
+        );
+    }
+
     #[test]
     fn test_duplicate_fields_in_object() {
         let mut builder = VariantBuilder::new();
@@ -2048,6 +3915,39 @@ mod tests {
         assert_eq!(field_names, vec!["a", "b", "c", "d"]);
     }
 
+    #[test]
+    fn test_sorted_field_names() {
+        let sorted = vec!["a".to_string(), "b".to_string(), "d".to_string()];
+        let builder = VariantBuilder::new().with_sorted_field_names(sorted.clone());
+        assert!(builder.metadata_builder.is_sorted);
+        assert_eq!(builder.metadata_builder.num_field_names(), 3);
+
+        let builder = VariantBuilder::new().with_sorted_field_names(vec![]);
+        assert!(!builder.metadata_builder.is_sorted);
+
+        // Order is tracked for field names added later
+        let mut builder = VariantBuilder::new().with_sorted_field_names(sorted.clone());
+        builder.add_field_name("e");
+        assert!(builder.metadata_builder.is_sorted);
+        builder.add_field_name("c");
+        assert!(!builder.metadata_builder.is_sorted);
+
+        // Field names are merged into an existing dictionary
+        let builder = VariantBuilder::new()
+            .with_field_names(["c"].into_iter())
+            .with_sorted_field_names(sorted);
+        assert!(!builder.metadata_builder.is_sorted);
+        let field_names: Vec<_> = builder.metadata_builder.field_names.iter().collect();
+        assert_eq!(field_names, ["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "field names are not strictly sorted")]
+    fn test_sorted_field_names_unsorted() {
+        VariantBuilder::new().with_sorted_field_names(vec!["b".to_string(), "a".to_string()]);
+    }
+
     #[test]
     fn test_building_sorted_dictionary() {
         let mut builder = VariantBuilder::new();
@@ -2556,6 +4456,178 @@ mod tests {
         builder.finish()
     }
 
+    #[test]
+    fn test_finish_sorted() {
+        let build = |builder: &mut VariantBuilder| {
+            let mut obj = builder.new_object();
+            obj.insert("c", 1i8);
+            let mut list = obj.new_list("b");
+            let mut inner = list.new_object();
+            inner.insert("a", "x");
+            inner.insert("d", true);
+            inner.finish().unwrap();
+            list.finish();
+            obj.finish().unwrap();
+        };
+
+        let mut builder = VariantBuilder::new();
+        build(&mut builder);
+        let (unsorted_metadata, unsorted_value) = builder.finish();
+        let unsorted = Variant::new(&unsorted_metadata, &unsorted_value);
+        assert!(!unsorted.metadata().unwrap().is_sorted());
+
+        let mut builder = VariantBuilder::new();
+        build(&mut builder);
+        let (metadata, value) = builder.finish_sorted();
+        let metadata = VariantMetadata::try_new(&metadata).unwrap();
+        assert!(metadata.is_sorted());
+        assert_eq!(
+            metadata.iter().collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d"]
+        );
+        let sorted = Variant::try_new_with_metadata(metadata, &value).unwrap();
+        for variant in [sorted, unsorted] {
+            let obj = variant.as_object().unwrap();
+            assert_eq!(obj.get("c"), Some(Variant::Int8(1)));
+            let list = obj.get("b").unwrap();
+            let inner = list.as_list().unwrap().get(0).unwrap();
+            assert_eq!(inner.get_object_field("a"), Some(Variant::from("x")));
+            assert_eq!(inner.get_object_field("d"), Some(Variant::from(true)));
+        }
+
+        // Already sorted dictionaries are returned unchanged
+        let mut builder = VariantBuilder::new();
+        builder.append_object([("a", 1i8), ("b", 2i8)]);
+        let (metadata, value) = builder.finish_sorted();
+        let mut builder = VariantBuilder::new();
+        builder.append_object([("a", 1i8), ("b", 2i8)]);
+        assert_eq!(builder.finish(), (metadata, value));
+    }
+
+    #[test]
+    fn test_finish_sorted_with_buffers() {
+        // Remapping moves ids below 256 above it, requiring wider field ids
+        let names: Vec<_> = (0..300).rev().map(|i| format!("field_{i:03}")).collect();
+        let mut builder = VariantBuilder::new_with_buffers(vec![0xAA], vec![0xBB, 0xCC]);
+        let mut obj = builder.new_object();
+        for (i, name) in names.iter().enumerate() {
+            obj.insert(name, i as i32);
+        }
+        obj.finish().unwrap();
+        let (metadata, value) = builder.finish_sorted();
+
+        assert_eq!(metadata[0], 0xAA);
+        assert_eq!(&value[..2], &[0xBB, 0xCC]);
+        let metadata = VariantMetadata::try_new(&metadata[1..]).unwrap();
+        assert!(metadata.is_sorted());
+        let variant = Variant::try_new_with_metadata(metadata, &value[2..]).unwrap();
+        let obj = variant.as_object().unwrap();
+        assert_eq!(obj.len(), 300);
+        assert_eq!(obj.get("field_299"), Some(Variant::Int32(0)));
+        assert_eq!(obj.get("field_000"), Some(Variant::Int32(299)));
+    }
+
+    #[test]
+    fn test_metadata_arena() {
+        let mut arena = VariantMetadataArena::new();
+
+        let mut builder = arena.new_builder();
+        let mut obj = builder.new_object();
+        obj.insert("b", 1i8);
+        obj.insert("a", "x");
+        obj.finish().unwrap();
+        let value1 = builder.finish();
+
+        let mut builder = arena.new_builder();
+        let mut list = builder.new_list();
+        let mut obj = list.new_object();
+        obj.insert("a", 2i8);
+        obj.insert("c", true);
+        obj.finish().unwrap();
+        list.finish();
+        let value2 = builder.finish();
+
+        // Field names added by an unfinished builder remain in the dictionary
+        let mut builder = arena.new_builder();
+        let mut obj = builder.new_object();
+        obj.insert("d", 3i8);
+        obj.finish().unwrap();
+        drop(builder);
+
+        assert_eq!(arena.len(), 4);
+        let metadata = arena.finish();
+        let metadata = VariantMetadata::try_new(&metadata).unwrap();
+        assert_eq!(
+            metadata.iter().collect::<Vec<_>>(),
+            vec!["b", "a", "c", "d"]
+        );
+
+        let variant1 = Variant::try_new_with_metadata(metadata.clone(), &value1).unwrap();
+        let obj = variant1.as_object().unwrap();
+        assert_eq!(obj.get("a"), Some(Variant::from("x")));
+        assert_eq!(obj.get("b"), Some(Variant::Int8(1)));
+
+        let variant2 = Variant::try_new_with_metadata(metadata, &value2).unwrap();
+        let obj = variant2.as_list().unwrap().get(0).unwrap();
+        assert_eq!(obj.get_object_field("c"), Some(Variant::from(true)));
+    }
+
+    #[test]
+    fn test_metadata_arena_with_metadata() {
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("z", 1i8);
+        obj.finish().unwrap();
+        let (metadata, value) = builder.finish();
+
+        let existing = VariantMetadata::try_new(&metadata).unwrap();
+        let mut arena = VariantMetadataArena::new()
+            .with_metadata(existing)
+            .with_validate_unique_fields(true);
+
+        let mut builder = arena.new_builder();
+        let mut obj = builder.new_object();
+        obj.insert("y", 2i8);
+        obj.insert("y", 3i8);
+        assert!(obj.finish().is_err());
+        drop(builder);
+
+        // Values encoded against the seed metadata remain valid
+        let shared = arena.finish();
+        let variant = Variant::try_new(&shared, &value).unwrap();
+        assert_eq!(variant.get_object_field("z"), Some(Variant::Int8(1)));
+    }
+
+    #[test]
+    fn test_estimated_sizes() {
+        let mut builder = VariantBuilder::new();
+        builder.reserve_value(1024);
+        assert_eq!(builder.estimated_value_size(), 0);
+
+        let mut list = builder.new_list();
+        for i in 0..300 {
+            let mut obj = list.new_object();
+            obj.insert(&format!("field_{i}"), i);
+            obj.finish().unwrap();
+        }
+        list.finish();
+
+        let metadata_size = builder.estimated_metadata_size();
+        let value_size = builder.estimated_value_size();
+        let (metadata, value) = builder.finish();
+        assert_eq!(metadata.len(), metadata_size);
+        assert_eq!(value.len(), value_size);
+
+        // Sizes include existing bytes of reused buffers
+        let mut builder = VariantBuilder::new_with_buffers(metadata, value);
+        builder.append_value("foo");
+        let metadata_size = builder.estimated_metadata_size();
+        let value_size = builder.estimated_value_size();
+        let (metadata, value) = builder.finish();
+        assert_eq!(metadata.len(), metadata_size);
+        assert_eq!(value.len(), value_size);
+    }
+
     #[test]
     fn test_append_nested_list() {
         let (m1, v1) = make_nested_list();
@@ -2581,4 +4653,75 @@ mod tests {
 
         builder.finish()
     }
+
+    #[test]
+    fn test_append_encoded() {
+        // {"b": [1, {"c": "a long string value", "a": 1.5}], "a": Decimal4(12.34)}
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        let mut list = obj.new_list("b");
+        list.append_value(1);
+        let mut inner = list.new_object();
+        inner.insert(
+            "c",
+            "a long string value that does not fit in a short string.....",
+        );
+        inner.insert("a", 1.5);
+        inner.finish().unwrap();
+        list.finish();
+        obj.insert("a", VariantDecimal4::try_new(1234, 2).unwrap());
+        obj.finish().unwrap();
+        let (source_metadata, source_value) = builder.finish();
+        let source = Variant::try_new(&source_metadata, &source_value).unwrap();
+
+        // Append to a builder whose dictionary assigns different field ids
+        let mut builder = VariantBuilder::new().with_field_names(["x", "c", "a"].into_iter());
+        builder
+            .append_encoded(&source_metadata, &source_value)
+            .unwrap();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert!(variant.logical_eq(&source));
+        let metadata = VariantMetadata::try_new(&metadata).unwrap();
+        assert_eq!(
+            metadata.iter().collect::<Vec<_>>(),
+            vec!["x", "c", "a", "b"]
+        );
+
+        // Primitive values are copied as is
+        for value in [
+            Variant::from(42i64),
+            Variant::from("short"),
+            Variant::from(b"binary".as_slice()),
+        ] {
+            let mut source = VariantBuilder::new();
+            source.append_value(value.clone());
+            let (source_metadata, source_value) = source.finish();
+            let mut builder = VariantBuilder::new();
+            builder
+                .append_encoded(&source_metadata, &source_value)
+                .unwrap();
+            let (_, value_bytes) = builder.finish();
+            assert_eq!(value_bytes, source_value);
+        }
+    }
+
+    #[test]
+    fn test_append_encoded_invalid() {
+        let (metadata, value) = make_nested_list();
+        let mut builder = VariantBuilder::new();
+        let err = builder
+            .append_encoded(&metadata, &value[..value.len() - 1])
+            .unwrap_err();
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)));
+        assert!(builder.append_encoded(&metadata[..1], &value).is_err());
+
+        // Nothing was appended
+        builder.append_value(1);
+        let (metadata, value) = builder.finish();
+        assert_eq!(
+            Variant::try_new(&metadata, &value).unwrap(),
+            Variant::Int32(1)
+        );
+    }
 }
@@ -0,0 +1,562 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`VariantSliceBuilder`] for encoding variants into caller-provided memory
+
+use arrow_schema::ArrowError;
+use chrono::Timelike;
+
+use super::{
+    array_header, int_size, object_header, primitive_header, short_string_header, timestamp_nanos,
+    VariantSpecVersion, UNIX_EPOCH_DATE,
+};
+use crate::decoder::VariantPrimitiveType;
+use crate::variant::check_short_string_len;
+use crate::{Variant, VariantList, VariantObject};
+
+/// A builder that encodes a [`Variant`] directly into caller-provided metadata and value
+/// slices, for embedded and FFI users that manage their own arena memory.
+///
+/// A [`VariantBuilder`] encodes the elements of lists and objects into buffers of their
+/// own, as their headers can only be written once their sizes are known. This builder
+/// instead encodes a complete value with [`Self::try_append_value`]: the sizes of its lists
+/// and objects are computed up front, so that every byte is written in place. Each write
+/// checks the remaining capacity of its slice, and an error is returned as soon as a slice
+/// is too small. The field names of the objects of the value are written as a sorted
+/// dictionary, and the only memory allocated is the list of their distinct names.
+///
+/// See [`VariantBuilder::new_with_slices`] for an example.
+///
+/// [`VariantBuilder`]: super::VariantBuilder
+/// [`VariantBuilder::new_with_slices`]: super::VariantBuilder::new_with_slices
+#[derive(Debug)]
+pub struct VariantSliceBuilder<'a> {
+    metadata: SliceWriter<'a>,
+    value: SliceWriter<'a>,
+    spec_version: VariantSpecVersion,
+    /// Whether a value was successfully appended
+    appended: bool,
+}
+
+impl<'a> VariantSliceBuilder<'a> {
+    pub(super) fn new(metadata: &'a mut [u8], value: &'a mut [u8]) -> Self {
+        Self {
+            metadata: SliceWriter::new("metadata", metadata),
+            value: SliceWriter::new("value", value),
+            spec_version: VariantSpecVersion::default(),
+            appended: false,
+        }
+    }
+
+    /// Sets the revision of the spec whose primitive types may be appended.
+    ///
+    /// See [`VariantBuilder::with_spec_version`](super::VariantBuilder::with_spec_version)
+    pub fn with_spec_version(mut self, spec_version: VariantSpecVersion) -> Self {
+        self.spec_version = spec_version;
+        self
+    }
+
+    /// Encodes `value` into the provided slices.
+    ///
+    /// Returns an error if a value was already appended, if `value` contains a type not
+    /// supported by the spec version of this builder, or as soon as either slice is too
+    /// small to hold the encoded variant. The contents of the slices are then unspecified,
+    /// and no value is considered appended.
+    pub fn try_append_value<'m, 'd>(
+        &mut self,
+        value: impl Into<Variant<'m, 'd>>,
+    ) -> Result<(), ArrowError> {
+        if self.appended {
+            return Err(ArrowError::InvalidArgumentError(
+                "A value was already appended to this VariantSliceBuilder".to_string(),
+            ));
+        }
+        let value = value.into();
+        let mut field_names = vec![];
+        collect_field_names(&value, &mut field_names);
+        field_names.sort_unstable();
+        field_names.dedup();
+
+        let result = write_metadata(&mut self.metadata, &field_names).and_then(|_| {
+            let mut encoder = Encoder {
+                field_names: &field_names,
+                spec_version: self.spec_version,
+                out: &mut self.value,
+            };
+            encoder.write_variant(&value)
+        });
+        match result {
+            Ok(()) => self.appended = true,
+            Err(_) => {
+                self.metadata.len = 0;
+                self.value.len = 0;
+            }
+        }
+        result
+    }
+
+    /// Finish the builder, returning the number of bytes written to the metadata and value
+    /// slices respectively, or an error if no value was appended.
+    pub fn finish(self) -> Result<(usize, usize), ArrowError> {
+        if !self.appended {
+            return Err(ArrowError::InvalidArgumentError(
+                "No value was appended to this VariantSliceBuilder".to_string(),
+            ));
+        }
+        Ok((self.metadata.len, self.value.len))
+    }
+}
+
+/// Writes bytes to the start of a slice, failing if they exceed its length
+#[derive(Debug)]
+struct SliceWriter<'a> {
+    /// Name of the written buffer, for error messages
+    name: &'static str,
+    buf: &'a mut [u8],
+    /// Number of bytes written to `buf`
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    fn new(name: &'static str, buf: &'a mut [u8]) -> Self {
+        Self { name, buf, len: 0 }
+    }
+
+    fn append_slice(&mut self, bytes: &[u8]) -> Result<(), ArrowError> {
+        let end = self.len + bytes.len();
+        let Some(dest) = self.buf.get_mut(self.len..end) else {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Variant {} does not fit in the provided buffer of {} bytes",
+                self.name,
+                self.buf.len()
+            )));
+        };
+        dest.copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    fn append_u8(&mut self, byte: u8) -> Result<(), ArrowError> {
+        self.append_slice(&[byte])
+    }
+
+    /// Appends the `nbytes` least significant bytes of `value` in little-endian order
+    fn append_offset(&mut self, value: usize, nbytes: u8) -> Result<(), ArrowError> {
+        self.append_slice(&value.to_le_bytes()[..nbytes as usize])
+    }
+}
+
+/// Appends the names of the fields of all objects in `variant` to `field_names`
+fn collect_field_names<'m>(variant: &Variant<'m, '_>, field_names: &mut Vec<&'m str>) {
+    match variant {
+        Variant::Object(obj) => {
+            for (name, value) in obj.iter() {
+                field_names.push(name);
+                collect_field_names(&value, field_names);
+            }
+        }
+        Variant::List(list) => {
+            for value in list.iter() {
+                collect_field_names(&value, field_names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Writes the metadata of a dictionary of strictly sorted `field_names`
+fn write_metadata(out: &mut SliceWriter<'_>, field_names: &[&str]) -> Result<(), ArrowError> {
+    let nkeys = field_names.len();
+    let total_dict_size: usize = field_names.iter().map(|name| name.len()).sum();
+    let offset_size = int_size(total_dict_size.max(nkeys));
+    // An empty dictionary is written as unsorted, as by the VariantBuilder
+    let is_sorted = nkeys > 0;
+
+    out.append_u8(0x01 | (is_sorted as u8) << 4 | ((offset_size - 1) << 6))?;
+    out.append_offset(nkeys, offset_size)?;
+    let mut cur_offset = 0;
+    for name in field_names {
+        out.append_offset(cur_offset, offset_size)?;
+        cur_offset += name.len();
+    }
+    out.append_offset(cur_offset, offset_size)?;
+    for name in field_names {
+        out.append_slice(name.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Encodes values whose field names are ids into a sorted dictionary
+struct Encoder<'e, 'a> {
+    field_names: &'e [&'e str],
+    spec_version: VariantSpecVersion,
+    out: &'e mut SliceWriter<'a>,
+}
+
+impl Encoder<'_, '_> {
+    /// Returns the id of `name`, which must be in the dictionary
+    fn field_id(&self, name: &str) -> usize {
+        self.field_names
+            .binary_search(&name)
+            .expect("field names of the value are in the dictionary")
+    }
+
+    /// Returns the number of bytes [`Self::write_variant`] writes for `variant`
+    fn encoded_size(&self, variant: &Variant<'_, '_>) -> usize {
+        match variant {
+            Variant::Null | Variant::BooleanTrue | Variant::BooleanFalse => 1,
+            Variant::Int8(_) => 2,
+            Variant::Int16(_) => 3,
+            Variant::Int32(_) | Variant::Date(_) | Variant::Float(_) => 5,
+            Variant::Int64(_)
+            | Variant::Double(_)
+            | Variant::TimestampMicros(_)
+            | Variant::TimestampNtzMicros(_)
+            | Variant::TimestampNanos(_)
+            | Variant::TimestampNtzNanos(_)
+            | Variant::Time(_) => 9,
+            Variant::Decimal4(_) => 6,
+            Variant::Decimal8(_) => 10,
+            Variant::Decimal16(_) => 18,
+            Variant::Uuid(_) => 17,
+            Variant::Binary(v) => 5 + v.len(),
+            Variant::String(v) => 5 + v.len(),
+            Variant::ShortString(v) => 1 + v.len(),
+            Variant::List(list) => self.list_layout(list).size(),
+            Variant::Object(obj) => self.object_layout(obj).size(),
+        }
+    }
+
+    fn list_layout(&self, list: &VariantList<'_, '_>) -> ContainerLayout {
+        let data_size = list.iter().map(|v| self.encoded_size(&v)).sum();
+        ContainerLayout::new(list.len(), data_size, 0)
+    }
+
+    fn object_layout(&self, obj: &VariantObject<'_, '_>) -> ContainerLayout {
+        let (data_size, max_id) = obj.iter().fold((0, 0), |(size, max_id), (name, value)| {
+            let size = size + self.encoded_size(&value);
+            (size, max_id.max(self.field_id(name)))
+        });
+        ContainerLayout::new(obj.len(), data_size, int_size(max_id))
+    }
+
+    fn write_variant(&mut self, variant: &Variant<'_, '_>) -> Result<(), ArrowError> {
+        self.spec_version.check(variant)?;
+        let out = &mut *self.out;
+        match variant {
+            Variant::Null => out.append_u8(primitive_header(VariantPrimitiveType::Null)),
+            Variant::BooleanTrue => {
+                out.append_u8(primitive_header(VariantPrimitiveType::BooleanTrue))
+            }
+            Variant::BooleanFalse => {
+                out.append_u8(primitive_header(VariantPrimitiveType::BooleanFalse))
+            }
+            Variant::Int8(v) => write_primitive(out, VariantPrimitiveType::Int8, &v.to_le_bytes()),
+            Variant::Int16(v) => {
+                write_primitive(out, VariantPrimitiveType::Int16, &v.to_le_bytes())
+            }
+            Variant::Int32(v) => {
+                write_primitive(out, VariantPrimitiveType::Int32, &v.to_le_bytes())
+            }
+            Variant::Int64(v) => {
+                write_primitive(out, VariantPrimitiveType::Int64, &v.to_le_bytes())
+            }
+            Variant::Float(v) => {
+                write_primitive(out, VariantPrimitiveType::Float, &v.to_le_bytes())
+            }
+            Variant::Double(v) => {
+                write_primitive(out, VariantPrimitiveType::Double, &v.to_le_bytes())
+            }
+            Variant::Date(v) => {
+                let days_since_epoch = v.signed_duration_since(UNIX_EPOCH_DATE).num_days() as i32;
+                write_primitive(
+                    out,
+                    VariantPrimitiveType::Date,
+                    &days_since_epoch.to_le_bytes(),
+                )
+            }
+            Variant::TimestampMicros(v) => write_primitive(
+                out,
+                VariantPrimitiveType::TimestampMicros,
+                &v.timestamp_micros().to_le_bytes(),
+            ),
+            Variant::TimestampNtzMicros(v) => write_primitive(
+                out,
+                VariantPrimitiveType::TimestampNtzMicros,
+                &v.and_utc().timestamp_micros().to_le_bytes(),
+            ),
+            Variant::TimestampNanos(v) => write_primitive(
+                out,
+                VariantPrimitiveType::TimestampNanos,
+                &timestamp_nanos(v)?.to_le_bytes(),
+            ),
+            Variant::TimestampNtzNanos(v) => write_primitive(
+                out,
+                VariantPrimitiveType::TimestampNtzNanos,
+                &timestamp_nanos(&v.and_utc())?.to_le_bytes(),
+            ),
+            Variant::Time(v) => {
+                // leap seconds are represented by chrono as nanoseconds beyond the last second of the day
+                let nanos = v.nanosecond().min(999_999_999);
+                let micros =
+                    i64::from(v.num_seconds_from_midnight()) * 1_000_000 + i64::from(nanos / 1_000);
+                write_primitive(out, VariantPrimitiveType::Time, &micros.to_le_bytes())
+            }
+            Variant::Decimal4(v) => {
+                out.append_u8(primitive_header(VariantPrimitiveType::Decimal4))?;
+                out.append_u8(v.scale())?;
+                out.append_slice(&v.integer().to_le_bytes())
+            }
+            Variant::Decimal8(v) => {
+                out.append_u8(primitive_header(VariantPrimitiveType::Decimal8))?;
+                out.append_u8(v.scale())?;
+                out.append_slice(&v.integer().to_le_bytes())
+            }
+            Variant::Decimal16(v) => {
+                out.append_u8(primitive_header(VariantPrimitiveType::Decimal16))?;
+                out.append_u8(v.scale())?;
+                out.append_slice(&v.integer().to_le_bytes())
+            }
+            Variant::Uuid(v) => write_primitive(out, VariantPrimitiveType::Uuid, v),
+            Variant::Binary(v) => {
+                write_primitive(
+                    out,
+                    VariantPrimitiveType::Binary,
+                    &(v.len() as u32).to_le_bytes(),
+                )?;
+                out.append_slice(v)
+            }
+            Variant::String(v) => {
+                write_primitive(
+                    out,
+                    VariantPrimitiveType::String,
+                    &(v.len() as u32).to_le_bytes(),
+                )?;
+                out.append_slice(v.as_bytes())
+            }
+            Variant::ShortString(v) => {
+                check_short_string_len(v.len())?;
+                out.append_u8(short_string_header(v.len()))?;
+                out.append_slice(v.as_bytes())
+            }
+            Variant::List(list) => self.write_list(list),
+            Variant::Object(obj) => self.write_object(obj),
+        }
+    }
+
+    fn write_list(&mut self, list: &VariantList<'_, '_>) -> Result<(), ArrowError> {
+        let layout = self.list_layout(list);
+        let header = array_header(layout.is_large, layout.offset_size);
+        layout.write_header(self.out, header)?;
+
+        let mut offset = 0;
+        for value in list.iter() {
+            self.out.append_offset(offset, layout.offset_size)?;
+            offset += self.encoded_size(&value);
+        }
+        self.out.append_offset(offset, layout.offset_size)?;
+
+        for value in list.iter() {
+            self.write_variant(&value)?;
+        }
+        Ok(())
+    }
+
+    fn write_object(&mut self, obj: &VariantObject<'_, '_>) -> Result<(), ArrowError> {
+        let layout = self.object_layout(obj);
+        let header = object_header(layout.is_large, layout.id_size, layout.offset_size);
+        layout.write_header(self.out, header)?;
+
+        // The fields of an object are sorted by name, and so by id in the sorted dictionary
+        for (name, _) in obj.iter() {
+            let id = self.field_id(name);
+            self.out.append_offset(id, layout.id_size)?;
+        }
+        let mut offset = 0;
+        for (_, value) in obj.iter() {
+            self.out.append_offset(offset, layout.offset_size)?;
+            offset += self.encoded_size(&value);
+        }
+        self.out.append_offset(offset, layout.offset_size)?;
+
+        for (_, value) in obj.iter() {
+            self.write_variant(&value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the header byte of a primitive value, followed by `bytes`
+fn write_primitive(
+    out: &mut SliceWriter<'_>,
+    primitive_type: VariantPrimitiveType,
+    bytes: &[u8],
+) -> Result<(), ArrowError> {
+    out.append_u8(primitive_header(primitive_type))?;
+    out.append_slice(bytes)
+}
+
+/// The sizes of the header, field ids and offsets of an encoded list or object
+struct ContainerLayout {
+    num_elements: usize,
+    data_size: usize,
+    is_large: bool,
+    /// Size of the field ids of an object, 0 for a list
+    id_size: u8,
+    offset_size: u8,
+}
+
+impl ContainerLayout {
+    fn new(num_elements: usize, data_size: usize, id_size: u8) -> Self {
+        Self {
+            num_elements,
+            data_size,
+            is_large: num_elements > u8::MAX as usize,
+            id_size,
+            offset_size: int_size(data_size),
+        }
+    }
+
+    /// Returns the size of the encoded list or object
+    fn size(&self) -> usize {
+        let num_elements_size = if self.is_large { 4 } else { 1 };
+        1 + num_elements_size
+            + self.num_elements * self.id_size as usize
+            + (self.num_elements + 1) * self.offset_size as usize
+            + self.data_size
+    }
+
+    /// Writes `header`, followed by the number of elements
+    fn write_header(&self, out: &mut SliceWriter<'_>, header: u8) -> Result<(), ArrowError> {
+        out.append_u8(header)?;
+        let num_elements_size = if self.is_large { 4 } else { 1 };
+        out.append_offset(self.num_elements, num_elements_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{VariantBuilder, VariantDecimal16};
+    use chrono::NaiveDate;
+
+    /// Builds a value with nested lists and objects, and a list of more than 255 elements
+    fn build_value(builder: &mut VariantBuilder) {
+        let mut obj = builder.new_object();
+        obj.insert(
+            "b",
+            "a string that is longer than a short string is allowed to be, really",
+        );
+        obj.insert("a", VariantDecimal16::try_new(123, 2).unwrap());
+        let mut list = obj.new_list("c");
+        list.append_value(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        let mut inner = list.new_object();
+        inner.insert("a", 1.5f64);
+        inner.insert("d", b"binary".as_slice());
+        inner.finish().unwrap();
+        list.finish();
+        let mut large = obj.new_list("e");
+        for i in 0..300 {
+            large.append_value(i);
+        }
+        large.finish();
+        obj.finish().unwrap();
+    }
+
+    #[test]
+    fn test_slice_builder_matches_variant_builder() {
+        let mut builder = VariantBuilder::new();
+        build_value(&mut builder);
+        let (expected_metadata, expected_value) = builder.finish_sorted();
+        let expected = Variant::new(&expected_metadata, &expected_value);
+
+        let mut metadata = [0xFFu8; 32];
+        let mut value = [0xFFu8; 4096];
+        let mut builder = VariantBuilder::new_with_slices(&mut metadata, &mut value);
+        builder.try_append_value(expected.clone()).unwrap();
+        let (metadata_len, value_len) = builder.finish().unwrap();
+
+        assert_eq!(&metadata[..metadata_len], expected_metadata.as_slice());
+        assert_eq!(&value[..value_len], expected_value.as_slice());
+        // bytes past the written region are untouched
+        assert!(metadata[metadata_len..].iter().all(|b| *b == 0xFF));
+        assert!(value[value_len..].iter().all(|b| *b == 0xFF));
+    }
+
+    #[test]
+    fn test_slice_builder_capacity_exceeded() {
+        let mut builder = VariantBuilder::new();
+        build_value(&mut builder);
+        let (object_metadata, object_value) = builder.finish();
+        let object = Variant::new(&object_metadata, &object_value);
+
+        let mut metadata = [0u8; 32];
+        let mut value = [0u8; 64];
+        let mut builder = VariantBuilder::new_with_slices(&mut metadata, &mut value);
+        let err = builder.try_append_value(object.clone()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant value does not fit in the provided buffer of 64 bytes"
+        );
+        assert!(builder.finish().is_err());
+
+        let mut metadata = [0u8; 4];
+        let mut value = [0u8; 4096];
+        let mut builder = VariantBuilder::new_with_slices(&mut metadata, &mut value);
+        let err = builder.try_append_value(object).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant metadata does not fit in the provided buffer of 4 bytes"
+        );
+        // A smaller value can still be appended after an error
+        builder.try_append_value(42i8).unwrap();
+        assert_eq!(builder.finish().unwrap(), (3, 2));
+    }
+
+    #[test]
+    fn test_slice_builder_single_value() {
+        let mut metadata = [0u8; 8];
+        let mut value = [0u8; 8];
+        let mut builder = VariantBuilder::new_with_slices(&mut metadata, &mut value);
+        builder.try_append_value("foo").unwrap();
+        let err = builder.try_append_value("bar").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: A value was already appended to this VariantSliceBuilder"
+        );
+        let (metadata_len, value_len) = builder.finish().unwrap();
+        let variant = Variant::try_new(&metadata[..metadata_len], &value[..value_len]).unwrap();
+        assert_eq!(variant, Variant::from("foo"));
+
+        let builder = VariantBuilder::new_with_slices(&mut metadata, &mut value);
+        let err = builder.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: No value was appended to this VariantSliceBuilder"
+        );
+    }
+
+    #[test]
+    fn test_slice_builder_spec_version() {
+        let mut metadata = [0u8; 8];
+        let mut value = [0u8; 32];
+        let mut builder = VariantBuilder::new_with_slices(&mut metadata, &mut value)
+            .with_spec_version(VariantSpecVersion::Initial);
+        assert!(builder.try_append_value(Variant::Uuid([0; 16])).is_err());
+        builder.try_append_value(1i64).unwrap();
+    }
+}
@@ -0,0 +1,324 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StreamingListBuilder`] for encoding lists larger than memory
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use arrow_schema::ArrowError;
+
+use super::{
+    array_header, int_size, write_offset, ListBuilder, MetadataBuilder, ObjectBuilder, ParentState,
+    ValueBuffer, VariantLayout,
+};
+use crate::{Variant, VariantMetadata};
+
+/// A builder for a top-level [`Variant::List`] whose elements are spilled to storage as
+/// they are appended, so that lists larger than memory can be encoded.
+///
+/// The elements of a list are stored after the offsets of all of the elements, whose width
+/// depends on the size of all of the elements. Each completed element is therefore written
+/// to `spill`, and only its offset is kept in memory. [`Self::finish`] then writes the
+/// header and offsets of the list to its output, followed by the elements read back from
+/// `spill`. The field names of the elements are kept in memory, and returned as the
+/// metadata of the list.
+///
+/// `spill` is typically a temporary file, and elements are written to it from its position
+/// when the builder is created.
+///
+/// # Example
+/// ```
+/// # use std::io::Cursor;
+/// # use parquet_variant::{StreamingListBuilder, Variant};
+/// let mut builder = StreamingListBuilder::try_new(Cursor::new(vec![])).unwrap();
+/// for i in 0..1000 {
+///     let mut object = builder.new_object().unwrap();
+///     object.insert("id", i);
+///     object.finish().unwrap();
+/// }
+/// builder.append_value("last").unwrap();
+///
+/// let mut value = vec![];
+/// let metadata = builder.finish(&mut value).unwrap();
+/// let variant = Variant::try_new(&metadata, &value).unwrap();
+/// let list = variant.as_list().unwrap();
+/// assert_eq!(list.len(), 1001);
+/// assert_eq!(list.get(42).unwrap().get_object_field("id"), Some(Variant::from(42)));
+/// ```
+#[derive(Debug)]
+pub struct StreamingListBuilder<S> {
+    spill: S,
+    /// Position of the first element in `spill`
+    spill_start: u64,
+    /// Offsets of the elements written to `spill`
+    offsets: Vec<u32>,
+    /// Size in bytes of the elements written to `spill`
+    data_size: usize,
+    /// The element being built, written to `spill` when the next one is started
+    buffer: ValueBuffer,
+    metadata_builder: MetadataBuilder,
+    validate_unique_fields: bool,
+}
+
+impl<S: Read + Write + Seek> StreamingListBuilder<S> {
+    /// Create a new builder that spills the elements of the list to `spill`
+    pub fn try_new(mut spill: S) -> Result<Self, ArrowError> {
+        let spill_start = spill.stream_position()?;
+        Ok(Self {
+            spill,
+            spill_start,
+            offsets: vec![],
+            data_size: 0,
+            buffer: ValueBuffer::default(),
+            metadata_builder: MetadataBuilder::default(),
+            validate_unique_fields: false,
+        })
+    }
+
+    /// Seed the field names of the metadata with those of an existing [`VariantMetadata`]
+    pub fn with_metadata(mut self, metadata: VariantMetadata) -> Self {
+        self.metadata_builder.extend(metadata.iter());
+        self
+    }
+
+    /// Sets the [`VariantLayout`] of the list and of the lists and objects of its elements
+    ///
+    /// See [`VariantBuilder::with_layout`](crate::VariantBuilder::with_layout)
+    pub fn with_layout(mut self, layout: VariantLayout) -> Self {
+        self.metadata_builder.layout = layout;
+        self
+    }
+
+    /// Enables validation of unique field keys in the objects of the elements
+    ///
+    /// See [`VariantBuilder::with_validate_unique_fields`](crate::VariantBuilder::with_validate_unique_fields)
+    pub fn with_validate_unique_fields(mut self, validate_unique_fields: bool) -> Self {
+        self.validate_unique_fields = validate_unique_fields;
+        self
+    }
+
+    /// Returns the number of elements appended to the list
+    ///
+    /// An element created with [`Self::new_object`] or [`Self::new_list`] is only counted
+    /// once it is finished.
+    pub fn len(&self) -> usize {
+        self.offsets.len() + !self.buffer.inner().is_empty() as usize
+    }
+
+    /// Returns true if no elements have been appended to the list
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Writes the previous element, if any, to `spill`
+    fn flush_element(&mut self) -> Result<(), ArrowError> {
+        let element = self.buffer.inner();
+        if element.is_empty() {
+            return Ok(());
+        }
+        let offset = u32::try_from(self.data_size).map_err(|_| list_too_large())?;
+        self.spill.write_all(element)?;
+        self.offsets.push(offset);
+        self.data_size += element.len();
+        self.buffer.inner_mut().clear();
+        Ok(())
+    }
+
+    fn parent_state(&mut self) -> Result<ParentState<'_>, ArrowError> {
+        self.flush_element()?;
+        Ok(ParentState::Variant {
+            buffer: &mut self.buffer,
+            metadata_builder: &mut self.metadata_builder,
+        })
+    }
+
+    /// Appends a variant to the list
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previous element can not be written to `spill`, or if the
+    /// variant contains duplicate field names in objects when validation is enabled
+    pub fn append_value<'m, 'd, T: Into<Variant<'m, 'd>>>(
+        &mut self,
+        value: T,
+    ) -> Result<(), ArrowError> {
+        self.flush_element()?;
+        let result = self
+            .buffer
+            .try_append_variant(value.into(), &mut self.metadata_builder);
+        if result.is_err() {
+            self.buffer.inner_mut().clear();
+        }
+        result
+    }
+
+    /// Returns a builder for a new object element
+    ///
+    /// WARNING: The element is only appended once [`ObjectBuilder::finish`] is called.
+    pub fn new_object(&mut self) -> Result<ObjectBuilder<'_>, ArrowError> {
+        let validate_unique_fields = self.validate_unique_fields;
+        Ok(ObjectBuilder::new(
+            self.parent_state()?,
+            validate_unique_fields,
+        ))
+    }
+
+    /// Returns a builder for a new list element
+    ///
+    /// WARNING: The element is only appended once [`ListBuilder::finish`] is called.
+    pub fn new_list(&mut self) -> Result<ListBuilder<'_>, ArrowError> {
+        let validate_unique_fields = self.validate_unique_fields;
+        Ok(ListBuilder::new(
+            self.parent_state()?,
+            validate_unique_fields,
+        ))
+    }
+
+    /// Writes the list to `out`, and returns its metadata
+    ///
+    /// The elements are read back from `spill`, which is returned by [`Self::into_inner`]
+    /// afterwards, if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `spill` or `out` fail, or if the elements are larger than the
+    /// maximum size of a Variant list, 4 GiB
+    pub fn finish(mut self, out: &mut impl Write) -> Result<Vec<u8>, ArrowError> {
+        self.flush_element()?;
+        let layout = self.metadata_builder.layout;
+        let num_elements = self.offsets.len();
+        let data_size = u32::try_from(self.data_size).map_err(|_| list_too_large())?;
+        let is_large = layout.large || num_elements > u8::MAX as usize;
+        let offset_size = int_size(self.data_size).max(layout.offset_size);
+
+        let mut header = vec![array_header(is_large, offset_size)];
+        match is_large {
+            true => header.extend_from_slice(&(num_elements as u32).to_le_bytes()),
+            false => header.push(num_elements as u8),
+        }
+        out.write_all(&header)?;
+
+        // Write the offsets in chunks, to avoid materializing all of them again
+        let mut chunk = Vec::with_capacity(8192);
+        let offsets = std::mem::take(&mut self.offsets);
+        for offset in offsets.into_iter().chain(std::iter::once(data_size)) {
+            write_offset(&mut chunk, offset as usize, offset_size);
+            if chunk.len() + 4 > chunk.capacity() {
+                out.write_all(&chunk)?;
+                chunk.clear();
+            }
+        }
+        out.write_all(&chunk)?;
+
+        self.spill.seek(SeekFrom::Start(self.spill_start))?;
+        let copied = std::io::copy(&mut (&mut self.spill).take(self.data_size as u64), out)?;
+        if copied != self.data_size as u64 {
+            return Err(ArrowError::IoError(
+                format!("expected {} spilled bytes, read {copied}", self.data_size),
+                std::io::ErrorKind::UnexpectedEof.into(),
+            ));
+        }
+        Ok(std::mem::take(&mut self.metadata_builder).finish())
+    }
+
+    /// Returns the spill storage, consuming the builder
+    pub fn into_inner(self) -> S {
+        self.spill
+    }
+}
+
+fn list_too_large() -> ArrowError {
+    ArrowError::InvalidArgumentError("Variant list is larger than the maximum of 4 GiB".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::VariantBuilder;
+
+    #[test]
+    fn test_streaming_matches_list_builder() {
+        for num_elements in [0, 3, 300] {
+            let mut expected = VariantBuilder::new();
+            let mut list = expected.new_list();
+            // Existing data in the spill is left untouched
+            let mut spill = Cursor::new(vec![1, 2]);
+            spill.set_position(2);
+            let mut streaming = StreamingListBuilder::try_new(spill).unwrap();
+            for i in 0..num_elements {
+                match i % 3 {
+                    0 => {
+                        list.append_value(i);
+                        streaming.append_value(i).unwrap();
+                    }
+                    1 => {
+                        let mut object = list.new_object();
+                        object.insert("b", "x");
+                        object.insert("a", i);
+                        object.finish().unwrap();
+                        let mut object = streaming.new_object().unwrap();
+                        object.insert("b", "x");
+                        object.insert("a", i);
+                        object.finish().unwrap();
+                    }
+                    _ => {
+                        let mut nested = list.new_list();
+                        nested.append_value(true);
+                        nested.finish();
+                        let mut nested = streaming.new_list().unwrap();
+                        nested.append_value(true);
+                        nested.finish();
+                    }
+                }
+            }
+            // Unfinished elements are discarded
+            drop(streaming.new_object().unwrap());
+            list.finish();
+            assert_eq!(streaming.len(), num_elements as usize);
+
+            let (expected_metadata, expected_value) = expected.finish();
+            let mut value = vec![];
+            let metadata = streaming.finish(&mut value).unwrap();
+            assert_eq!(metadata, expected_metadata);
+            assert_eq!(value, expected_value);
+        }
+    }
+
+    #[test]
+    fn test_streaming_layout() {
+        let layout = VariantLayout::new().with_large(true).with_offset_size(4);
+        let mut streaming = StreamingListBuilder::try_new(Cursor::new(vec![]))
+            .unwrap()
+            .with_layout(layout)
+            .with_validate_unique_fields(true);
+        streaming.append_value(1i8).unwrap();
+        let mut object = streaming.new_object().unwrap();
+        object.insert("a", 1);
+        object.insert("a", 2);
+        assert!(object.finish().is_err());
+        assert!(!streaming.is_empty());
+
+        let mut value = vec![];
+        let metadata = streaming.finish(&mut value).unwrap();
+        // header, 4 byte element count, 2 offsets of 4 bytes, then the int8 value
+        assert_eq!(value.len(), 1 + 4 + 2 * 4 + 2);
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(variant.get_list_element(0), Some(Variant::Int8(1)));
+    }
+}
@@ -0,0 +1,262 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Canonical encoding of [`Variant`]s, see [`canonicalize`]
+
+use std::collections::BTreeSet;
+
+use arrow_schema::ArrowError;
+
+use crate::builder::{AppendTarget, ObjectFieldTarget};
+use crate::{Variant, VariantBuilder, VariantMetadata};
+
+/// Re-encodes `variant` in canonical form, returning its metadata and value
+///
+/// Logically equal variants with the same types have byte for byte identical canonical
+/// encodings, which allows comparing or deduplicating variants by their bytes, for example
+/// by hashing them. The canonical encoding of a variant:
+///
+/// * has a sorted metadata dictionary, containing only the field names used by the value
+/// * uses the smallest offset, field id and size widths of the metadata, objects and lists
+/// * lays out the values of object fields in the order of their field names
+/// * writes strings of at most 63 bytes as short strings
+/// * has no bytes that are not referenced by the value
+///
+/// The types of primitive values are preserved, so `Int8(1)` and `Int64(1)` have different
+/// canonical encodings.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{canonicalize, Variant, VariantBuilder};
+/// let mut builder = VariantBuilder::new();
+/// let mut obj = builder.new_object();
+/// obj.insert("name", "Alice");
+/// obj.insert("age", 30);
+/// obj.finish().unwrap();
+/// let (metadata1, value1) = builder.finish();
+///
+/// let mut builder = VariantBuilder::new();
+/// let mut obj = builder.new_object();
+/// obj.insert("age", 30);
+/// obj.insert("name", "Alice");
+/// obj.finish().unwrap();
+/// let (metadata2, value2) = builder.finish();
+///
+/// // The field names were inserted in a different order
+/// assert_ne!(metadata1, metadata2);
+///
+/// let canonical1 = canonicalize(&Variant::new(&metadata1, &value1));
+/// let canonical2 = canonicalize(&Variant::new(&metadata2, &value2));
+/// assert_eq!(canonical1, canonical2);
+/// ```
+///
+/// # Panics
+///
+/// Panics if a nested object or list is [unvalidated] and contains invalid data
+///
+/// [unvalidated]: Variant#Validation
+pub fn canonicalize(variant: &Variant<'_, '_>) -> (Vec<u8>, Vec<u8>) {
+    let mut field_names = BTreeSet::new();
+    collect_field_names(variant, &mut field_names);
+    let mut builder = VariantBuilder::new().with_field_names(field_names.into_iter());
+    append_canonical(&mut builder, variant).expect("Invalid variant");
+    builder.finish()
+}
+
+/// Returns an error describing why `metadata` and `value` are not the [canonical encoding]
+/// of the variant they encode, if they are not
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{canonicalize, check_canonical, Variant, VariantBuilder};
+/// let mut builder = VariantBuilder::new();
+/// let mut obj = builder.new_object();
+/// obj.insert("b", 1);
+/// obj.insert("a", 2);
+/// obj.finish().unwrap();
+/// let (metadata, value) = builder.finish();
+///
+/// let err = check_canonical(&metadata, &value).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid argument error: Variant is not canonical: metadata dictionary is not sorted"
+/// );
+///
+/// let (metadata, value) = canonicalize(&Variant::new(&metadata, &value));
+/// assert!(check_canonical(&metadata, &value).is_ok());
+/// ```
+///
+/// [canonical encoding]: canonicalize
+pub fn check_canonical(metadata: &[u8], value: &[u8]) -> Result<(), ArrowError> {
+    let variant = Variant::try_new(metadata, value)?;
+    let (canonical_metadata, canonical_value) = canonicalize(&variant);
+    if metadata != canonical_metadata {
+        let metadata = VariantMetadata::try_new(metadata)?;
+        let canonical = VariantMetadata::new(&canonical_metadata);
+        let reason = if !metadata.is_sorted() && !metadata.is_empty() {
+            "metadata dictionary is not sorted"
+        } else if metadata.len() != canonical.len() {
+            "metadata dictionary contains unused field names"
+        } else {
+            "metadata is not minimally encoded"
+        };
+        return Err(non_canonical(reason));
+    }
+    if value != canonical_value {
+        return Err(non_canonical(
+            "value is not minimally encoded, has unsorted object fields or unreferenced bytes",
+        ));
+    }
+    Ok(())
+}
+
+fn non_canonical(reason: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("Variant is not canonical: {reason}"))
+}
+
+/// Adds the names of the fields of all objects in `variant` to `field_names`
+fn collect_field_names<'m>(variant: &Variant<'m, '_>, field_names: &mut BTreeSet<&'m str>) {
+    match variant {
+        Variant::Object(obj) => {
+            for (name, value) in obj.iter() {
+                field_names.insert(name);
+                collect_field_names(&value, field_names);
+            }
+        }
+        Variant::List(list) => {
+            for value in list.iter() {
+                collect_field_names(&value, field_names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Appends the canonical encoding of `variant` to `target`
+fn append_canonical<T: AppendTarget>(
+    target: &mut T,
+    variant: &Variant<'_, '_>,
+) -> Result<(), ArrowError> {
+    match variant {
+        Variant::Object(obj) => {
+            let mut fields: Vec<_> = obj.iter().collect();
+            fields.sort_unstable_by_key(|(name, _)| *name);
+            let mut builder = target.new_object();
+            for (name, value) in &fields {
+                let mut field = ObjectFieldTarget {
+                    builder: &mut builder,
+                    key: name,
+                };
+                append_canonical(&mut field, value)?;
+            }
+            builder.finish()
+        }
+        Variant::List(list) => {
+            let mut builder = target.new_list();
+            for value in list.iter() {
+                append_canonical(&mut builder, &value)?;
+            }
+            builder.finish();
+            Ok(())
+        }
+        Variant::String(s) => target.append_value(Variant::from(*s)),
+        Variant::ShortString(s) => target.append_value(Variant::from(s.as_str())),
+        _ => target.append_value(variant.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ShortString, VariantLayout};
+
+    /// Builds `{"b": [1, "x"], "a": {"d": true, "c": "hello"}}`
+    fn build(mut builder: VariantBuilder) -> (Vec<u8>, Vec<u8>) {
+        let mut obj = builder.new_object();
+        let mut b = obj.new_list("b");
+        b.append_value(1);
+        b.append_value("x");
+        b.finish();
+        let mut a = obj.new_object("a");
+        a.insert("d", true);
+        a.insert("c", Variant::String("hello"));
+        a.finish().unwrap();
+        obj.finish().unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let canonical =
+            build(VariantBuilder::new().with_field_names(["a", "b", "c", "d"].into_iter()));
+        let (metadata, value) = canonicalize(&Variant::new(&canonical.0, &canonical.1));
+        // The long string is rewritten as a short string
+        assert_ne!(value, canonical.1);
+        let variant = Variant::new(&metadata, &value);
+        let c = variant.get_object_field("a").unwrap().get_object_field("c");
+        assert!(matches!(c, Some(Variant::ShortString(s)) if s.as_str() == "hello"));
+        check_canonical(&metadata, &value).unwrap();
+        // Canonicalization is idempotent
+        assert_eq!(canonicalize(&variant), (metadata.clone(), value.clone()));
+
+        let cases = [
+            (
+                build(VariantBuilder::new()),
+                "metadata dictionary is not sorted",
+            ),
+            (
+                build(
+                    VariantBuilder::new().with_field_names(["a", "b", "c", "d", "e"].into_iter()),
+                ),
+                "metadata dictionary contains unused field names",
+            ),
+            (
+                build(
+                    VariantBuilder::new()
+                        .with_field_names(["a", "b", "c", "d"].into_iter())
+                        .with_layout(VariantLayout::new().with_large(true).with_offset_size(4)),
+                ),
+                "value is not minimally encoded, has unsorted object fields or unreferenced bytes",
+            ),
+        ];
+        for ((m, v), reason) in cases {
+            assert_eq!(
+                canonicalize(&Variant::new(&m, &v)),
+                (metadata.clone(), value.clone())
+            );
+            let err = check_canonical(&m, &v).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid argument error: Variant is not canonical: {reason}")
+            );
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_primitives() {
+        let long = "a".repeat(100);
+        // SAFETY: the bytes are valid UTF-8
+        let short = Variant::ShortString(unsafe { ShortString::new_unchecked(long.as_bytes()) });
+        let (metadata, value) = canonicalize(&short);
+        assert_eq!(Variant::new(&metadata, &value), Variant::String(&long));
+        check_canonical(&metadata, &value).unwrap();
+
+        let (metadata, value) = canonicalize(&Variant::Int64(1));
+        assert_eq!(Variant::new(&metadata, &value), Variant::Int64(1));
+        check_canonical(&metadata, &value).unwrap();
+    }
+}
@@ -20,7 +20,7 @@ use crate::utils::{
 use crate::ShortString;
 
 use arrow_schema::ArrowError;
-use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
 /// The basic type of a [`Variant`] value, encoded in the first two bits of the
 /// header byte.
@@ -63,6 +63,10 @@ pub enum VariantPrimitiveType {
     Float = 14,
     Binary = 15,
     String = 16,
+    Time = 17,
+    TimestampNanos = 18,
+    TimestampNtzNanos = 19,
+    Uuid = 20,
 }
 
 /// Extracts the basic type from a header byte
@@ -104,6 +108,10 @@ impl TryFrom<u8> for VariantPrimitiveType {
             14 => Ok(VariantPrimitiveType::Float),
             15 => Ok(VariantPrimitiveType::Binary),
             16 => Ok(VariantPrimitiveType::String),
+            17 => Ok(VariantPrimitiveType::Time),
+            18 => Ok(VariantPrimitiveType::TimestampNanos),
+            19 => Ok(VariantPrimitiveType::TimestampNtzNanos),
+            20 => Ok(VariantPrimitiveType::Uuid),
             _ => Err(ArrowError::InvalidArgumentError(format!(
                 "unknown primitive type: {value}",
             ))),
@@ -111,6 +119,32 @@ impl TryFrom<u8> for VariantPrimitiveType {
     }
 }
 
+impl VariantPrimitiveType {
+    /// Returns the size in bytes of an encoded value of this type after its header byte, or
+    /// `None` for [`Self::Binary`] and [`Self::String`], which have a 4 byte length prefix
+    pub(crate) const fn fixed_size(self) -> Option<usize> {
+        let size = match self {
+            Self::Null | Self::BooleanTrue | Self::BooleanFalse => 0,
+            Self::Int8 => 1,
+            Self::Int16 => 2,
+            Self::Int32 | Self::Date | Self::Float => 4,
+            Self::Decimal4 => 5,
+            Self::Int64
+            | Self::Double
+            | Self::TimestampMicros
+            | Self::TimestampNtzMicros
+            | Self::Time
+            | Self::TimestampNanos
+            | Self::TimestampNtzNanos => 8,
+            Self::Decimal8 => 9,
+            Self::Uuid => 16,
+            Self::Decimal16 => 17,
+            Self::Binary | Self::String => return None,
+        };
+        Some(size)
+    }
+}
+
 /// Used to unpack offset array entries such as metadata dictionary offsets or object/array value
 /// offsets. Also used to unpack object field ids. These are always derived from a two-bit
 /// `XXX_size_minus_one` field in the corresponding header byte.
@@ -295,12 +329,46 @@ pub(crate) fn decode_timestampntz_micros(data: &[u8]) -> Result<NaiveDateTime, A
         .map(|v| v.naive_utc())
 }
 
+/// Decodes a Time (microseconds since midnight) from the value section of a variant.
+pub(crate) fn decode_time_micros(data: &[u8]) -> Result<NaiveTime, ArrowError> {
+    let micros_since_midnight = i64::from_le_bytes(array_from_slice(data, 0)?);
+    let time = (0..86_400_000_000)
+        .contains(&micros_since_midnight)
+        .then(|| {
+            let secs = (micros_since_midnight / 1_000_000) as u32;
+            let nanos = (micros_since_midnight % 1_000_000) as u32 * 1_000;
+            NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)
+        })
+        .flatten();
+    time.ok_or_else(|| {
+        ArrowError::CastError(format!(
+            "Could not cast `{micros_since_midnight}` microseconds into a NaiveTime"
+        ))
+    })
+}
+
+/// Decodes a TimestampNanos from the value section of a variant.
+pub(crate) fn decode_timestamp_nanos(data: &[u8]) -> Result<DateTime<Utc>, ArrowError> {
+    let nanos_since_epoch = i64::from_le_bytes(array_from_slice(data, 0)?);
+    Ok(DateTime::from_timestamp_nanos(nanos_since_epoch))
+}
+
+/// Decodes a TimestampNtzNanos from the value section of a variant.
+pub(crate) fn decode_timestampntz_nanos(data: &[u8]) -> Result<NaiveDateTime, ArrowError> {
+    decode_timestamp_nanos(data).map(|v| v.naive_utc())
+}
+
 /// Decodes a Binary from the value section of a variant.
 pub(crate) fn decode_binary(data: &[u8]) -> Result<&[u8], ArrowError> {
     let len = u32::from_le_bytes(array_from_slice(data, 0)?) as usize;
     slice_from_slice_at_offset(data, 4, 0..len)
 }
 
+/// Decodes a UUID (16 bytes, big-endian) from the value section of a variant.
+pub(crate) fn decode_uuid(data: &[u8]) -> Result<[u8; 16], ArrowError> {
+    array_from_slice(data, 0)
+}
+
 /// Decodes a long string from the value section of a variant.
 pub(crate) fn decode_long_string(data: &[u8]) -> Result<&str, ArrowError> {
     let len = u32::from_le_bytes(array_from_slice(data, 0)?) as usize;
@@ -436,8 +504,57 @@ mod tests {
                 .and_hms_milli_opt(16, 34, 56, 780)
                 .unwrap()
         );
+
+        test_decoder_bounds!(
+            test_time_micros,
+            [0xec, 0xaa, 0x41, 0x75, 0x0e, 0x00, 0x00, 0x00],
+            decode_time_micros,
+            NaiveTime::from_hms_micro_opt(17, 14, 56, 780012).unwrap()
+        );
+
+        #[test]
+        fn test_time_micros_out_of_range() {
+            for micros in [-1i64, 86_400_000_000] {
+                let result = decode_time_micros(&micros.to_le_bytes());
+                assert!(matches!(result, Err(ArrowError::CastError(_))));
+            }
+        }
+
+        test_decoder_bounds!(
+            test_timestamp_nanos,
+            [0x40, 0x9d, 0x1d, 0x97, 0xb9, 0xd9, 0x36, 0x18],
+            decode_timestamp_nanos,
+            NaiveDate::from_ymd_opt(2025, 4, 16)
+                .unwrap()
+                .and_hms_nano_opt(16, 34, 56, 780123456)
+                .unwrap()
+                .and_utc()
+        );
+
+        test_decoder_bounds!(
+            test_timestampntz_nanos,
+            [0x40, 0x9d, 0x1d, 0x97, 0xb9, 0xd9, 0x36, 0x18],
+            decode_timestampntz_nanos,
+            NaiveDate::from_ymd_opt(2025, 4, 16)
+                .unwrap()
+                .and_hms_nano_opt(16, 34, 56, 780123456)
+                .unwrap()
+        );
     }
 
+    test_decoder_bounds!(
+        test_uuid,
+        [
+            0xf2, 0x4f, 0x9b, 0x64, 0x81, 0xfa, 0x49, 0xd1, 0xb7, 0x4e, 0x8c, 0x09, 0xa6, 0xe3,
+            0x1c, 0x56
+        ],
+        decode_uuid,
+        [
+            0xf2, 0x4f, 0x9b, 0x64, 0x81, 0xfa, 0x49, 0xd1, 0xb7, 0x4e, 0x8c, 0x09, 0xa6, 0xe3,
+            0x1c, 0x56
+        ]
+    );
+
     #[test]
     fn test_binary_exact_length() {
         let data = [
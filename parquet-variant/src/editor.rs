@@ -0,0 +1,407 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`VariantEditor`] for producing a modified copy of an existing [`Variant`]
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use arrow_schema::ArrowError;
+
+use crate::builder::{AppendTarget, ObjectFieldTarget};
+use crate::path::{VariantPath, VariantPathElement};
+use crate::{Variant, VariantBuilder};
+
+/// Applies a set of edits to an existing [`Variant`], producing a new metadata and
+/// value pair.
+///
+/// Only the objects and lists along the paths of the edits are re-encoded. All other
+/// nested objects and lists are copied byte for byte from the original value, so the
+/// cost of an edit is proportional to the size of the affected sub-trees rather than
+/// the whole variant. The original metadata dictionary is carried over unchanged, with
+/// any new field names appended to it.
+///
+/// All paths refer to the original variant, i.e. they are not affected by other
+/// renames. Edits whose path does not exist in the original variant are ignored. If
+/// several edits target the same path, a removal takes precedence over a replacement,
+/// and a later edit of the same kind takes precedence over an earlier one.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, VariantBuilder, VariantEditor};
+/// # use parquet_variant::path::VariantPath;
+/// let mut builder = VariantBuilder::new();
+/// let mut obj = builder.new_object();
+/// obj.insert("id", 1);
+/// obj.insert("password", "hunter2");
+/// let mut user = obj.new_object("user");
+/// user.insert("name", "Alice");
+/// user.finish().unwrap();
+/// obj.finish().unwrap();
+/// let (metadata, value) = builder.finish();
+/// let variant = Variant::new(&metadata, &value);
+///
+/// let (metadata, value) = VariantEditor::new(variant)
+///     .remove_field(VariantPath::try_parse("$.password").unwrap())
+///     .rename_field(VariantPath::try_parse("$.user.name").unwrap(), "first_name")
+///     .replace(VariantPath::try_parse("$.id").unwrap(), "u-1")
+///     .finish()
+///     .unwrap();
+///
+/// let edited = Variant::new(&metadata, &value);
+/// let obj = edited.as_object().unwrap();
+/// assert_eq!(obj.get("password"), None);
+/// assert_eq!(obj.get("id"), Some(Variant::from("u-1")));
+/// let path = VariantPath::try_parse("$.user.first_name").unwrap();
+/// assert_eq!(edited.get_path(&path), Some(Variant::from("Alice")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VariantEditor<'a> {
+    variant: Variant<'a, 'a>,
+    edits: Vec<VariantEdit<'a>>,
+}
+
+#[derive(Debug, Clone)]
+enum VariantEdit<'a> {
+    Remove(VariantPath<'a>),
+    Rename(VariantPath<'a>, Cow<'a, str>),
+    Replace(VariantPath<'a>, Variant<'a, 'a>),
+}
+
+impl<'a> VariantEditor<'a> {
+    /// Create a new editor for `variant` with no edits
+    pub fn new(variant: Variant<'a, 'a>) -> Self {
+        Self {
+            variant,
+            edits: vec![],
+        }
+    }
+
+    /// Remove the object field at `path`
+    ///
+    /// [`Self::finish`] returns an error if `path` does not end in a field name.
+    pub fn remove_field(mut self, path: VariantPath<'a>) -> Self {
+        self.edits.push(VariantEdit::Remove(path));
+        self
+    }
+
+    /// Rename the object field at `path` to `new_name`, keeping its value
+    ///
+    /// [`Self::finish`] returns an error if `path` does not end in a field name, or if
+    /// `new_name` is already used by another field of the same object.
+    pub fn rename_field(
+        mut self,
+        path: VariantPath<'a>,
+        new_name: impl Into<Cow<'a, str>>,
+    ) -> Self {
+        self.edits.push(VariantEdit::Rename(path, new_name.into()));
+        self
+    }
+
+    /// Replace the value at `path` with `value`
+    ///
+    /// An empty path replaces the entire variant.
+    pub fn replace(mut self, path: VariantPath<'a>, value: impl Into<Variant<'a, 'a>>) -> Self {
+        self.edits.push(VariantEdit::Replace(path, value.into()));
+        self
+    }
+
+    /// Apply the edits, returning the metadata and value of the new variant
+    pub fn finish(self) -> Result<(Vec<u8>, Vec<u8>), ArrowError> {
+        let mut root = EditNode::default();
+        for edit in &self.edits {
+            match edit {
+                VariantEdit::Remove(path) => root.field_node(path, "remove")?.removed = true,
+                VariantEdit::Rename(path, new_name) => {
+                    root.field_node(path, "rename")?.new_name = Some(new_name.as_ref())
+                }
+                VariantEdit::Replace(path, value) => {
+                    root.node(path).replacement = Some(value.clone())
+                }
+            }
+        }
+
+        // Seeding the builder with the original dictionary keeps the field ids of the
+        // unchanged sub-trees valid, allowing them to be copied without re-encoding
+        let builder = VariantBuilder::new().with_validate_unique_fields(true);
+        let mut builder = match self.variant.metadata() {
+            Some(metadata) => builder.with_metadata(metadata.clone()),
+            None => builder,
+        };
+        write_edited(&mut builder, &self.variant, Some(&root))?;
+        Ok(builder.finish())
+    }
+}
+
+/// The edits to apply to a value and its descendants
+#[derive(Debug, Default)]
+struct EditNode<'e> {
+    removed: bool,
+    new_name: Option<&'e str>,
+    replacement: Option<Variant<'e, 'e>>,
+    fields: HashMap<&'e str, EditNode<'e>>,
+    elements: HashMap<usize, EditNode<'e>>,
+}
+
+impl<'e> EditNode<'e> {
+    /// Returns the node for `path`, creating it and its ancestors if necessary
+    fn node(&mut self, path: &'e [VariantPathElement<'_>]) -> &mut Self {
+        path.iter().fold(self, |node, element| match element {
+            VariantPathElement::Field { name } => node.fields.entry(name.as_ref()).or_default(),
+            VariantPathElement::Index { index } => node.elements.entry(*index).or_default(),
+        })
+    }
+
+    /// Returns the node for `path`, which must end in a field name
+    fn field_node(&mut self, path: &'e VariantPath<'_>, op: &str) -> Result<&mut Self, ArrowError> {
+        match path.last() {
+            Some(VariantPathElement::Field { .. }) => Ok(self.node(path)),
+            _ => Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot {op} '{path}': path must end in a field name"
+            ))),
+        }
+    }
+
+    fn is_unchanged(&self) -> bool {
+        self.replacement.is_none() && self.fields.is_empty() && self.elements.is_empty()
+    }
+}
+
+/// Writes `value` to `target`, applying the edits in `node`
+fn write_edited<T: AppendTarget>(
+    target: &mut T,
+    value: &Variant<'_, '_>,
+    node: Option<&EditNode<'_>>,
+) -> Result<(), ArrowError> {
+    let node = match node {
+        Some(node) if !node.is_unchanged() => node,
+        _ => return write_unchanged(target, value),
+    };
+    if let Some(replacement) = &node.replacement {
+        return target.append_value(replacement.clone());
+    }
+
+    match value {
+        Variant::Object(object) if !node.fields.is_empty() => {
+            let mut builder = target.new_object();
+            for (name, field) in object.iter() {
+                let child = node.fields.get(name);
+                if child.is_some_and(|child| child.removed) {
+                    continue;
+                }
+                let key = child.and_then(|child| child.new_name).unwrap_or(name);
+                let mut target = ObjectFieldTarget {
+                    builder: &mut builder,
+                    key,
+                };
+                write_edited(&mut target, &field, child)?;
+            }
+            builder.finish()
+        }
+        Variant::List(list) if !node.elements.is_empty() => {
+            let mut builder = target.new_list();
+            for (index, element) in list.iter().enumerate() {
+                write_edited(&mut builder, &element, node.elements.get(&index))?;
+            }
+            builder.finish();
+            Ok(())
+        }
+        _ => write_unchanged(target, value),
+    }
+}
+
+/// Writes `value` to `target`, copying the encoded bytes of objects and lists
+fn write_unchanged<T: AppendTarget>(
+    target: &mut T,
+    value: &Variant<'_, '_>,
+) -> Result<(), ArrowError> {
+    match value {
+        Variant::Object(object) => target.append_raw(object.value),
+        Variant::List(list) => target.append_raw(list.value),
+        _ => target.append_value(value.clone())?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path(path: &str) -> VariantPath<'_> {
+        VariantPath::try_parse(path).unwrap()
+    }
+
+    /// Builds `{"a": {"b": 1, "c": [1, {"d": "x"}]}, "e": true}`
+    fn nested() -> (Vec<u8>, Vec<u8>) {
+        let mut builder = VariantBuilder::new();
+        let mut root = builder.new_object();
+        let mut a = root.new_object("a");
+        a.insert("b", 1);
+        let mut c = a.new_list("c");
+        c.append_value(1);
+        let mut d = c.new_object();
+        d.insert("d", "x");
+        d.finish().unwrap();
+        c.finish();
+        a.finish().unwrap();
+        root.insert("e", true);
+        root.finish().unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_no_edits() {
+        let (metadata, value) = nested();
+        let variant = Variant::new(&metadata, &value);
+        let (new_metadata, new_value) = VariantEditor::new(variant).finish().unwrap();
+        assert_eq!(new_metadata, metadata);
+        assert_eq!(new_value, value);
+    }
+
+    #[test]
+    fn test_remove_field() {
+        let (metadata, value) = nested();
+        let variant = Variant::new(&metadata, &value);
+        let (metadata, value) = VariantEditor::new(variant)
+            .remove_field(path("$.a.c[1].d"))
+            .remove_field(path("$.e"))
+            .remove_field(path("$.missing.field"))
+            .finish()
+            .unwrap();
+
+        let edited = Variant::new(&metadata, &value);
+        let root = edited.as_object().unwrap();
+        assert_eq!(root.len(), 1);
+        assert_eq!(edited.get_path(&path("$.a.b")), Some(Variant::Int32(1)));
+        let element = edited.get_path(&path("$.a.c[1]")).unwrap();
+        assert_eq!(element.as_object().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_rename_field() {
+        let (metadata, value) = nested();
+        let variant = Variant::new(&metadata, &value);
+        let (metadata, value) = VariantEditor::new(variant)
+            .rename_field(path("$.a"), "z")
+            .rename_field(path("$.a.b"), "renamed")
+            .finish()
+            .unwrap();
+
+        let edited = Variant::new(&metadata, &value);
+        assert_eq!(edited.get_path(&path("$.a")), None);
+        assert_eq!(
+            edited.get_path(&path("$.z.renamed")),
+            Some(Variant::Int32(1))
+        );
+        assert_eq!(
+            edited.get_path(&path("$.z.c[1].d")),
+            Some(Variant::from("x"))
+        );
+
+        // The unchanged list was copied, so its field ids must still resolve
+        let names: Vec<_> = edited.metadata().unwrap().iter().collect();
+        assert_eq!(names, ["b", "d", "c", "a", "e", "renamed", "z"]);
+    }
+
+    #[test]
+    fn test_replace() {
+        let (metadata, value) = nested();
+        let variant = Variant::new(&metadata, &value);
+
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("new", "value");
+        obj.finish().unwrap();
+        let (replacement_metadata, replacement_value) = builder.finish();
+        let replacement = Variant::new(&replacement_metadata, &replacement_value);
+
+        let (metadata, value) = VariantEditor::new(variant.clone())
+            .replace(path("$.a.c[0]"), replacement)
+            .replace(path("$.e"), Variant::Null)
+            .replace(path("$.a.c[5]"), 1)
+            .finish()
+            .unwrap();
+
+        let edited = Variant::new(&metadata, &value);
+        assert_eq!(
+            edited.get_path(&path("$.a.c[0].new")),
+            Some(Variant::from("value"))
+        );
+        assert_eq!(edited.get_path(&path("$.e")), Some(Variant::Null));
+        assert_eq!(
+            edited
+                .get_path(&path("$.a.c"))
+                .unwrap()
+                .as_list()
+                .unwrap()
+                .len(),
+            2
+        );
+
+        let (metadata, value) = VariantEditor::new(variant)
+            .replace(VariantPath::default(), 42)
+            .finish()
+            .unwrap();
+        assert_eq!(Variant::new(&metadata, &value), Variant::Int32(42));
+    }
+
+    #[test]
+    fn test_remove_takes_precedence() {
+        let (metadata, value) = nested();
+        let variant = Variant::new(&metadata, &value);
+        let (metadata, value) = VariantEditor::new(variant)
+            .replace(path("$.e"), 1)
+            .remove_field(path("$.e"))
+            .finish()
+            .unwrap();
+        let edited = Variant::new(&metadata, &value);
+        assert_eq!(edited.get_path(&path("$.e")), None);
+    }
+
+    #[test]
+    fn test_invalid_edits() {
+        let (metadata, value) = nested();
+        let variant = Variant::new(&metadata, &value);
+
+        let err = VariantEditor::new(variant.clone())
+            .remove_field(path("$.a.c[0]"))
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot remove '$.a.c[0]': path must end in a field name"
+        );
+
+        let err = VariantEditor::new(variant.clone())
+            .rename_field(VariantPath::default(), "x")
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot rename '$': path must end in a field name"
+        );
+
+        let err = VariantEditor::new(variant)
+            .rename_field(path("$.e"), "a")
+            .finish()
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("Duplicate field keys detected"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading and writing of the variant binary test vectors of [parquet-testing]
+//!
+//! [parquet-testing]: https://github.com/apache/parquet-testing/tree/master/variant
+
+use std::fs;
+use std::path::Path;
+
+use arrow_schema::ArrowError;
+
+use crate::{Variant, VariantBuilder};
+
+/// A variant binary test vector, as found in the `variant` directory of [parquet-testing]
+///
+/// Each test vector named `name` is stored as two files: `name.metadata` holding the encoded
+/// metadata, and `name.value` holding the encoded value. The vectors of parquet-testing are
+/// produced by the Spark and Java implementations, so they can be used to check that variants
+/// written by other implementations are read correctly, and vice versa.
+///
+/// ```
+/// # use parquet_variant::{Variant, VariantTestCase};
+/// let dir = std::env::temp_dir().join(format!("variant_test_case_doc_{}", std::process::id()));
+/// VariantTestCase::from_variant("primitive_int8", Variant::Int8(42)).write(&dir).unwrap();
+/// VariantTestCase::from_variant("short_string", Variant::from("abc")).write(&dir).unwrap();
+///
+/// let cases = VariantTestCase::load_dir(&dir).unwrap();
+/// let names: Vec<_> = cases.iter().map(|case| case.name()).collect();
+/// assert_eq!(names, ["primitive_int8", "short_string"]);
+/// assert_eq!(cases[0].variant().unwrap(), Variant::Int8(42));
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+///
+/// [parquet-testing]: https://github.com/apache/parquet-testing/tree/master/variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantTestCase {
+    name: String,
+    metadata: Vec<u8>,
+    value: Vec<u8>,
+}
+
+impl VariantTestCase {
+    /// Create a test case from encoded `metadata` and `value`
+    pub fn new(name: impl Into<String>, metadata: Vec<u8>, value: Vec<u8>) -> Self {
+        Self {
+            name: name.into(),
+            metadata,
+            value,
+        }
+    }
+
+    /// Create a test case by encoding `variant` with a [`VariantBuilder`]
+    pub fn from_variant<'m, 'v>(
+        name: impl Into<String>,
+        variant: impl Into<Variant<'m, 'v>>,
+    ) -> Self {
+        let mut builder = VariantBuilder::new();
+        builder.append_value(variant);
+        let (metadata, value) = builder.finish();
+        Self::new(name, metadata, value)
+    }
+
+    /// Load the test case `name` from the files `name.metadata` and `name.value` in `dir`
+    pub fn load(dir: impl AsRef<Path>, name: &str) -> Result<Self, ArrowError> {
+        let dir = dir.as_ref();
+        let metadata = read(&dir.join(format!("{name}.metadata")))?;
+        let value = read(&dir.join(format!("{name}.value")))?;
+        Ok(Self::new(name, metadata, value))
+    }
+
+    /// Load all test cases in `dir`, ordered by name
+    ///
+    /// Every `name.metadata` file in `dir` is a test case, and other files, such as the JSON
+    /// files describing the cases in parquet-testing, are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` can not be read, or if the `name.value` file of a test case
+    /// is missing
+    pub fn load_dir(dir: impl AsRef<Path>) -> Result<Vec<Self>, ArrowError> {
+        let dir = dir.as_ref();
+        let entries = fs::read_dir(dir).map_err(|e| io_error(dir, e))?;
+        let mut names = vec![];
+        for entry in entries {
+            let path = entry.map_err(|e| io_error(dir, e))?.path();
+            if path.extension().is_some_and(|ext| ext == "metadata") {
+                if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort_unstable();
+        names.iter().map(|name| Self::load(dir, name)).collect()
+    }
+
+    /// Write this test case to the files `name.metadata` and `name.value` in `dir`,
+    /// creating `dir` if it does not exist
+    pub fn write(&self, dir: impl AsRef<Path>) -> Result<(), ArrowError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(|e| io_error(dir, e))?;
+        let metadata = dir.join(format!("{}.metadata", self.name));
+        fs::write(&metadata, &self.metadata).map_err(|e| io_error(&metadata, e))?;
+        let value = dir.join(format!("{}.value", self.name));
+        fs::write(&value, &self.value).map_err(|e| io_error(&value, e))
+    }
+
+    /// Returns the name of this test case
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the encoded metadata of this test case
+    pub fn metadata(&self) -> &[u8] {
+        &self.metadata
+    }
+
+    /// Returns the encoded value of this test case
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Returns the validated [`Variant`] of this test case
+    pub fn variant(&self) -> Result<Variant<'_, '_>, ArrowError> {
+        Variant::try_new(&self.metadata, &self.value)
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, ArrowError> {
+    fs::read(path).map_err(|e| io_error(path, e))
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> ArrowError {
+    ArrowError::IoError(format!("{}: {error}", path.display()), error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_and_load_dir() {
+        let dir = std::env::temp_dir().join(format!("variant_test_case_{}", std::process::id()));
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_value("a");
+        let mut object = list.new_object();
+        object.insert("b", 1i32);
+        object.finish().unwrap();
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let list = VariantTestCase::new("array_nested", metadata, value);
+        let string = VariantTestCase::from_variant("primitive_string", "a longer string");
+        string.write(&dir).unwrap();
+        list.write(&dir).unwrap();
+        fs::write(dir.join("data_dictionary.json"), "{}").unwrap();
+
+        let cases = VariantTestCase::load_dir(&dir).unwrap();
+        assert_eq!(cases, [list.clone(), string]);
+        assert_eq!(cases[0].variant().unwrap(), list.variant().unwrap());
+
+        fs::write(dir.join("invalid.metadata"), [0xff]).unwrap();
+        let err = VariantTestCase::load_dir(&dir).unwrap_err();
+        assert!(err.to_string().contains("invalid.value"), "{err}");
+        fs::write(dir.join("invalid.value"), [0xff]).unwrap();
+        let invalid = VariantTestCase::load(&dir, "invalid").unwrap();
+        assert!(invalid.variant().is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
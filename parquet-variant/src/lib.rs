@@ -28,10 +28,22 @@
 //! [Variant issue]: https://github.com/apache/arrow-rs/issues/6736
 
 mod builder;
+mod canonical;
 mod decoder;
+mod editor;
+mod interop;
+mod merge;
 pub mod path;
 mod utils;
 mod variant;
+#[cfg(feature = "serde")]
+mod variant_serde;
 
 pub use builder::*;
+pub use canonical::{canonicalize, check_canonical};
+pub use editor::VariantEditor;
+pub use interop::VariantTestCase;
+pub use merge::MergeStrategy;
 pub use variant::*;
+#[cfg(feature = "serde")]
+pub use variant_serde::from_variant;
@@ -0,0 +1,264 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Deep merging of two [`Variant`]s, see [`Variant::merge`]
+
+use arrow_schema::ArrowError;
+
+use crate::builder::{AppendTarget, ObjectFieldTarget};
+use crate::path::{VariantPath, VariantPathElement};
+use crate::{Variant, VariantBuilder};
+
+/// How [`Variant::merge`] resolves two values at the same path that can not be merged
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// The value of the variant merged into the original wins
+    #[default]
+    LastWins,
+    /// Return an error, unless the values are logically equal
+    ErrorOnConflict,
+}
+
+impl<'m, 'v> Variant<'m, 'v> {
+    /// Deep merges `other` into this variant, returning the metadata and value of the result
+    ///
+    /// Objects are merged by taking the union of their fields, where fields present in both
+    /// objects are merged recursively, and lists are merged by appending the elements of the
+    /// list of `other` to this list. Any other pair of values conflicts, and is resolved
+    /// according to `strategy`.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{MergeStrategy, Variant, VariantBuilder};
+    /// # use parquet_variant::path::VariantPath;
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("name", "Alice");
+    /// obj.insert("age", 30);
+    /// obj.finish().unwrap();
+    /// let (metadata, value) = builder.finish();
+    /// let original = Variant::new(&metadata, &value);
+    ///
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("age", 31);
+    /// obj.insert("city", "Paris");
+    /// obj.finish().unwrap();
+    /// let (metadata, value) = builder.finish();
+    /// let patch = Variant::new(&metadata, &value);
+    ///
+    /// let (metadata, value) = original.merge(&patch, MergeStrategy::LastWins).unwrap();
+    /// let merged = Variant::new(&metadata, &value);
+    /// let obj = merged.as_object().unwrap();
+    /// assert_eq!(obj.get("name"), Some(Variant::from("Alice")));
+    /// assert_eq!(obj.get("age"), Some(Variant::from(31)));
+    /// assert_eq!(obj.get("city"), Some(Variant::from("Paris")));
+    ///
+    /// let err = original.merge(&patch, MergeStrategy::ErrorOnConflict).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Invalid argument error: Conflicting values at path $.age when merging variants"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a nested object or list is [unvalidated] and contains invalid data
+    ///
+    /// [unvalidated]: Self#Validation
+    pub fn merge(
+        &self,
+        other: &Variant<'_, '_>,
+        strategy: MergeStrategy,
+    ) -> Result<(Vec<u8>, Vec<u8>), ArrowError> {
+        let mut builder = VariantBuilder::new();
+        merge_values(&mut builder, self, other, strategy, &mut Vec::new())?;
+        Ok(builder.finish())
+    }
+}
+
+/// Appends the merge of `left` and `right` at `path` to `target`
+fn merge_values<'m, T: AppendTarget>(
+    target: &mut T,
+    left: &Variant<'m, '_>,
+    right: &Variant<'_, '_>,
+    strategy: MergeStrategy,
+    path: &mut Vec<VariantPathElement<'m>>,
+) -> Result<(), ArrowError> {
+    match (left, right) {
+        (Variant::Object(left), Variant::Object(right)) => {
+            let mut builder = target.new_object();
+            for (name, value) in left.iter() {
+                let mut field = ObjectFieldTarget {
+                    builder: &mut builder,
+                    key: name,
+                };
+                match right.get(name) {
+                    Some(other) => {
+                        path.push(VariantPathElement::field(name.into()));
+                        merge_values(&mut field, &value, &other, strategy, path)?;
+                        path.pop();
+                    }
+                    None => field.append_value(value)?,
+                }
+            }
+            for (name, value) in right.iter() {
+                if left.get(name).is_none() {
+                    builder.try_insert(name, value)?;
+                }
+            }
+            builder.finish()
+        }
+        (Variant::List(left), Variant::List(right)) => {
+            let mut builder = target.new_list();
+            for value in left.iter().chain(right.iter()) {
+                builder.try_append_value(value)?;
+            }
+            builder.finish();
+            Ok(())
+        }
+        _ => match strategy {
+            MergeStrategy::LastWins => target.append_value(right.clone()),
+            MergeStrategy::ErrorOnConflict if left.logical_eq(right) => {
+                target.append_value(left.clone())
+            }
+            MergeStrategy::ErrorOnConflict => Err(ArrowError::InvalidArgumentError(format!(
+                "Conflicting values at path {} when merging variants",
+                VariantPath::new(path.clone())
+            ))),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(f: impl FnOnce(&mut VariantBuilder)) -> (Vec<u8>, Vec<u8>) {
+        let mut builder = VariantBuilder::new();
+        f(&mut builder);
+        builder.finish()
+    }
+
+    #[test]
+    fn test_merge_nested() {
+        // {"a": {"x": 1, "y": [1, 2]}, "b": "left", "c": true}
+        let (left_metadata, left_value) = build(|builder| {
+            let mut obj = builder.new_object();
+            let mut a = obj.new_object("a");
+            a.insert("x", 1);
+            let mut y = a.new_list("y");
+            y.append_value(1);
+            y.append_value(2);
+            y.finish();
+            a.finish().unwrap();
+            obj.insert("b", "left");
+            obj.insert("c", true);
+            obj.finish().unwrap();
+        });
+        // {"a": {"y": [3], "z": null}, "b": "right", "d": 1.5}
+        let (right_metadata, right_value) = build(|builder| {
+            let mut obj = builder.new_object();
+            let mut a = obj.new_object("a");
+            let mut y = a.new_list("y");
+            y.append_value(3);
+            y.finish();
+            a.insert("z", ());
+            a.finish().unwrap();
+            obj.insert("b", "right");
+            obj.insert("d", 1.5);
+            obj.finish().unwrap();
+        });
+        // {"a": {"x": 1, "y": [1, 2, 3], "z": null}, "b": "right", "c": true, "d": 1.5}
+        let (expected_metadata, expected_value) = build(|builder| {
+            let mut obj = builder.new_object();
+            let mut a = obj.new_object("a");
+            a.insert("x", 1);
+            let mut y = a.new_list("y");
+            y.append_value(1);
+            y.append_value(2);
+            y.append_value(3);
+            y.finish();
+            a.insert("z", ());
+            a.finish().unwrap();
+            obj.insert("b", "right");
+            obj.insert("c", true);
+            obj.insert("d", 1.5);
+            obj.finish().unwrap();
+        });
+        let left = Variant::try_new(&left_metadata, &left_value).unwrap();
+        let right = Variant::try_new(&right_metadata, &right_value).unwrap();
+        let expected = Variant::try_new(&expected_metadata, &expected_value).unwrap();
+
+        let (metadata, value) = left.merge(&right, MergeStrategy::LastWins).unwrap();
+        let merged = Variant::try_new(&metadata, &value).unwrap();
+        assert!(merged.logical_eq(&expected), "{merged:?}");
+
+        let err = left
+            .merge(&right, MergeStrategy::ErrorOnConflict)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Conflicting values at path $.b when merging variants"
+        );
+
+        // Logically equal values do not conflict
+        let (metadata, value) = left.merge(&left, MergeStrategy::ErrorOnConflict).unwrap();
+        let merged = Variant::try_new(&metadata, &value).unwrap();
+        let y = merged.get_path(&VariantPath::try_parse("$.a.y").unwrap());
+        assert_eq!(y.unwrap().as_list().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_merge_non_objects() {
+        let (list_metadata, list_value) = build(|builder| {
+            let mut list = builder.new_list();
+            list.append_value("a");
+            list.finish();
+        });
+        let list = Variant::try_new(&list_metadata, &list_value).unwrap();
+
+        let (metadata, value) = list.merge(&list, MergeStrategy::LastWins).unwrap();
+        let merged = Variant::try_new(&metadata, &value).unwrap();
+        let merged = merged.as_list().unwrap();
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged.get(1), Some(Variant::from("a")));
+
+        let (metadata, value) = list
+            .merge(&Variant::from(1), MergeStrategy::LastWins)
+            .unwrap();
+        assert_eq!(
+            Variant::try_new(&metadata, &value).unwrap(),
+            Variant::from(1)
+        );
+
+        let err = Variant::from(1)
+            .merge(&list, MergeStrategy::ErrorOnConflict)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Conflicting values at path $ when merging variants"
+        );
+        let (metadata, value) = Variant::from(1i8)
+            .merge(&Variant::from(1i64), MergeStrategy::ErrorOnConflict)
+            .unwrap();
+        assert_eq!(
+            Variant::try_new(&metadata, &value).unwrap(),
+            Variant::from(1i8)
+        );
+    }
+}
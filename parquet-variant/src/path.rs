@@ -14,20 +14,61 @@
 // KIND, either express or implied.  See the License for the
 // specific language governing permissions and limitations
 // under the License.
+use std::fmt::{Display, Formatter};
 use std::{borrow::Cow, ops::Deref};
 
+use arrow_schema::ArrowError;
+
 /// Represents a qualified path to a potential subfield or index of a variant value.
-#[derive(Debug, Clone)]
+///
+/// Paths can be constructed from their elements, or parsed from a string using
+/// [`VariantPath::try_parse`]:
+///
+/// ```
+/// # use parquet_variant::path::{VariantPath, VariantPathElement};
+/// let path = VariantPath::try_parse("$.user.addresses[0].zip").unwrap();
+/// assert_eq!(
+///     path,
+///     VariantPath::new(vec![
+///         VariantPathElement::field("user".into()),
+///         VariantPathElement::field("addresses".into()),
+///         VariantPathElement::index(0),
+///         VariantPathElement::field("zip".into()),
+///     ])
+/// );
+/// assert_eq!(path.to_string(), "$.user.addresses[0].zip");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct VariantPath<'a>(Vec<VariantPathElement<'a>>);
 
 impl<'a> VariantPath<'a> {
+    /// Create a new path from its elements
     pub fn new(path: Vec<VariantPathElement<'a>>) -> Self {
         Self(path)
     }
 
+    /// Returns the elements of this path
     pub fn path(&self) -> &Vec<VariantPathElement> {
         &self.0
     }
+
+    /// Parse a path from a string, borrowing field names from `path` where possible
+    ///
+    /// The syntax is a subset of [JSONPath]:
+    ///
+    /// * An optional leading `$` denotes the root value. The empty string and `$` are
+    ///   both the empty path, which refers to the value itself
+    /// * `.name` accesses the field `name` of an object
+    /// * `['name']` or `["name"]` accesses a field whose name contains characters such as
+    ///   `.`, `[` or spaces. Within the quotes, `\` escapes the following character
+    /// * `[index]` accesses the element at position `index` of a list
+    ///
+    /// For convenience a leading field may omit the `.`, for example `a.b[0]`.
+    ///
+    /// [JSONPath]: https://www.rfc-editor.org/rfc/rfc9535.html
+    pub fn try_parse(path: &'a str) -> Result<Self, ArrowError> {
+        PathParser { path, pos: 0 }.parse().map(Self)
+    }
 }
 
 impl<'a> From<Vec<VariantPathElement<'a>>> for VariantPath<'a> {
@@ -36,6 +77,14 @@ impl<'a> From<Vec<VariantPathElement<'a>>> for VariantPath<'a> {
     }
 }
 
+impl<'a> TryFrom<&'a str> for VariantPath<'a> {
+    type Error = ArrowError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        Self::try_parse(value)
+    }
+}
+
 impl<'a> Deref for VariantPath<'a> {
     type Target = [VariantPathElement<'a>];
 
@@ -44,8 +93,16 @@ impl<'a> Deref for VariantPath<'a> {
     }
 }
 
+/// Formats the path in the syntax accepted by [`VariantPath::try_parse`]
+impl Display for VariantPath<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "$")?;
+        self.0.iter().try_for_each(|element| write!(f, "{element}"))
+    }
+}
+
 /// Element of a path
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VariantPathElement<'a> {
     /// Access field with name `name`
     Field { name: Cow<'a, str> },
@@ -62,3 +119,218 @@ impl<'a> VariantPathElement<'a> {
         VariantPathElement::Index { index }
     }
 }
+
+impl Display for VariantPathElement<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field { name } if !name.is_empty() && !name.contains(is_delimiter) => {
+                write!(f, ".{name}")
+            }
+            Self::Field { name } => {
+                write!(f, "['")?;
+                for c in name.chars() {
+                    if matches!(c, '\'' | '\\') {
+                        write!(f, "\\")?;
+                    }
+                    write!(f, "{c}")?;
+                }
+                write!(f, "']")
+            }
+            Self::Index { index } => write!(f, "[{index}]"),
+        }
+    }
+}
+
+/// Characters that end an unquoted field name
+fn is_delimiter(c: char) -> bool {
+    matches!(c, '.' | '[' | ']' | '\'' | '"' | '\\' | '$') || c.is_whitespace()
+}
+
+struct PathParser<'a> {
+    path: &'a str,
+    pos: usize,
+}
+
+impl<'a> PathParser<'a> {
+    fn err(&self, msg: &str) -> ArrowError {
+        ArrowError::InvalidArgumentError(format!(
+            "Invalid variant path '{}': {msg} at position {}",
+            self.path, self.pos
+        ))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.path[self.pos..]
+    }
+
+    fn parse(mut self) -> Result<Vec<VariantPathElement<'a>>, ArrowError> {
+        let mut elements = vec![];
+        if self.rest().starts_with('$') {
+            self.pos += 1;
+        } else if self.rest().starts_with(|c| !matches!(c, '.' | '[')) {
+            elements.push(self.parse_unquoted_field()?);
+        }
+
+        while let Some(c) = self.rest().chars().next() {
+            let element = match c {
+                '.' => {
+                    self.pos += 1;
+                    self.parse_unquoted_field()?
+                }
+                '[' => {
+                    self.pos += 1;
+                    let element = match self.rest().chars().next() {
+                        Some(quote @ ('\'' | '"')) => self.parse_quoted_field(quote)?,
+                        _ => self.parse_index()?,
+                    };
+                    if !self.rest().starts_with(']') {
+                        return Err(self.err("expected ']'"));
+                    }
+                    self.pos += 1;
+                    element
+                }
+                _ => return Err(self.err("expected '.' or '['")),
+            };
+            elements.push(element);
+        }
+        Ok(elements)
+    }
+
+    fn parse_unquoted_field(&mut self) -> Result<VariantPathElement<'a>, ArrowError> {
+        let rest = self.rest();
+        let len = rest.find(is_delimiter).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.err("expected field name"));
+        }
+        self.pos += len;
+        Ok(VariantPathElement::field(Cow::Borrowed(&rest[..len])))
+    }
+
+    fn parse_quoted_field(&mut self, quote: char) -> Result<VariantPathElement<'a>, ArrowError> {
+        let start = self.pos + 1;
+        let mut escaped: Option<String> = None;
+        let mut chars = self.path[start..].char_indices();
+        while let Some((offset, c)) = chars.next() {
+            if c == quote {
+                self.pos = start + offset + 1;
+                let name = match escaped {
+                    Some(name) => Cow::Owned(name),
+                    None => Cow::Borrowed(&self.path[start..start + offset]),
+                };
+                return Ok(VariantPathElement::field(name));
+            }
+            if c == '\\' {
+                let name = escaped.get_or_insert_with(|| self.path[start..start + offset].into());
+                match chars.next() {
+                    Some((_, c)) => name.push(c),
+                    None => break,
+                }
+            } else if let Some(name) = escaped.as_mut() {
+                name.push(c);
+            }
+        }
+        self.pos = self.path.len();
+        Err(self.err("unterminated quoted field name"))
+    }
+
+    fn parse_index(&mut self) -> Result<VariantPathElement<'a>, ArrowError> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let index = rest[..len]
+            .parse()
+            .map_err(|_| self.err("expected list index"))?;
+        self.pos += len;
+        Ok(VariantPathElement::index(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str) -> VariantPathElement<'_> {
+        VariantPathElement::field(name.into())
+    }
+
+    fn index(index: usize) -> VariantPathElement<'static> {
+        VariantPathElement::index(index)
+    }
+
+    #[test]
+    fn test_parse() {
+        let cases = [
+            ("", vec![]),
+            ("$", vec![]),
+            ("$.a", vec![field("a")]),
+            ("a", vec![field("a")]),
+            ("a.b[12]", vec![field("a"), field("b"), index(12)]),
+            ("$[0][1]", vec![index(0), index(1)]),
+            ("[0].a", vec![index(0), field("a")]),
+            ("$['a.b']", vec![field("a.b")]),
+            ("$[\"a ]b\"].c", vec![field("a ]b"), field("c")]),
+            ("$['it\\'s']", vec![field("it's")]),
+            ("$['']", vec![field("")]),
+            ("$.é.ü", vec![field("é"), field("ü")]),
+        ];
+        for (input, expected) in cases {
+            let path = VariantPath::try_parse(input).unwrap();
+            assert_eq!(path, VariantPath::new(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_parse_borrows() {
+        let path = VariantPath::try_parse("$.a['b']['c\\'']").unwrap();
+        let borrowed: Vec<_> = path
+            .iter()
+            .map(|e| {
+                matches!(
+                    e,
+                    VariantPathElement::Field {
+                        name: Cow::Borrowed(_)
+                    }
+                )
+            })
+            .collect();
+        assert_eq!(borrowed, vec![true, true, false]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("$.", "expected field name at position 2"),
+            ("$..a", "expected field name at position 2"),
+            ("$[", "expected list index at position 2"),
+            ("$[a]", "expected list index at position 2"),
+            ("$[-1]", "expected list index at position 2"),
+            ("$[1", "expected ']' at position 3"),
+            ("$['a'", "expected ']' at position 5"),
+            ("$['a", "unterminated quoted field name at position 4"),
+            ("$a", "expected '.' or '[' at position 1"),
+            ("a b", "expected '.' or '[' at position 1"),
+        ];
+        for (input, expected) in cases {
+            let err = VariantPath::try_parse(input).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                format!("Invalid argument error: Invalid variant path '{input}': {expected}"),
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let path = VariantPath::new(vec![
+            field("a"),
+            index(3),
+            field("b.c"),
+            field("it's"),
+            field(""),
+        ]);
+        let s = path.to_string();
+        assert_eq!(s, "$.a[3]['b.c']['it\\'s']['']");
+        assert_eq!(VariantPath::try_parse(&s).unwrap(), path);
+    }
+}
@@ -123,19 +123,33 @@ pub(crate) fn try_binary_search_range_by<K, F>(
 where
     K: Ord,
     F: Fn(usize) -> Option<K>,
+{
+    try_binary_search_range_by_result(range, target, |i| key_extractor(i).ok_or(())).ok()
+}
+
+/// Like [`try_binary_search_range_by`], but for a key extraction function that returns an error
+/// on failure, which is returned by the search.
+pub(crate) fn try_binary_search_range_by_result<K, E, F>(
+    range: Range<usize>,
+    target: &K,
+    key_extractor: F,
+) -> Result<Result<usize, usize>, E>
+where
+    K: Ord,
+    F: Fn(usize) -> Result<K, E>,
 {
     let Range { mut start, mut end } = range;
     while start < end {
         let mid = start + (end - start) / 2;
         let key = key_extractor(mid)?;
         match key.cmp(target) {
-            std::cmp::Ordering::Equal => return Some(Ok(mid)),
+            std::cmp::Ordering::Equal => return Ok(Ok(mid)),
             std::cmp::Ordering::Greater => end = mid,
             std::cmp::Ordering::Less => start = mid + 1,
         }
     }
 
-    Some(Err(start))
+    Ok(Err(start))
 }
 
 /// Verifies the expected size of type T, for a type that should only grow if absolutely necessary.
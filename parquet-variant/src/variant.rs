@@ -19,22 +19,30 @@ pub use self::decimal::{VariantDecimal16, VariantDecimal4, VariantDecimal8};
 pub use self::list::VariantList;
 pub use self::metadata::VariantMetadata;
 pub use self::object::VariantObject;
+pub use self::options::VariantDecodeOptions;
+pub use self::strict::{VariantBuffer, VariantValidationError};
+pub use self::view::{VariantListIter, VariantListView, VariantMapIter, VariantMapView};
 use crate::decoder::{
     self, get_basic_type, get_primitive_type, VariantBasicType, VariantPrimitiveType,
 };
 use crate::path::{VariantPath, VariantPathElement};
 use crate::utils::{first_byte_from_slice, slice_from_slice};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
 use arrow_schema::ArrowError;
-use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
 mod decimal;
 mod list;
 mod metadata;
 mod object;
+mod options;
+mod strict;
+mod view;
 
-const MAX_SHORT_STRING_BYTES: usize = 0x3F;
+pub(crate) const MAX_SHORT_STRING_BYTES: usize = 0x3F;
 
 /// A Variant [`ShortString`]
 ///
@@ -51,21 +59,105 @@ impl<'a> ShortString<'a> {
     /// Returns an error if  `value` is longer than the maximum allowed length
     /// of a Variant short string (63 bytes).
     pub fn try_new(value: &'a str) -> Result<Self, ArrowError> {
-        if value.len() > MAX_SHORT_STRING_BYTES {
-            return Err(ArrowError::InvalidArgumentError(format!(
-                "value is larger than {MAX_SHORT_STRING_BYTES} bytes"
-            )));
-        }
+        check_short_string_len(value.len())?;
+        Ok(Self(value))
+    }
 
+    /// Attempts to interpret `bytes` as a variant short string value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not valid UTF-8, or is longer than the maximum
+    /// allowed length of a Variant short string (63 bytes).
+    pub fn try_from_utf8(bytes: &'a [u8]) -> Result<Self, ArrowError> {
+        check_short_string_len(bytes.len())?;
+        let value = std::str::from_utf8(bytes)
+            .map_err(|e| ArrowError::InvalidArgumentError(format!("invalid UTF-8: {e}")))?;
         Ok(Self(value))
     }
 
+    /// Interprets trusted `bytes` as a variant short string value, checking its length
+    /// but not that it is valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is longer than the maximum allowed length of a Variant
+    /// short string (63 bytes).
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid UTF-8
+    pub unsafe fn try_from_utf8_unchecked(bytes: &'a [u8]) -> Result<Self, ArrowError> {
+        check_short_string_len(bytes.len())?;
+        // SAFETY: the caller guarantees that bytes are valid UTF-8
+        Ok(Self(unsafe { std::str::from_utf8_unchecked(bytes) }))
+    }
+
+    /// Interprets trusted `bytes` as a variant short string value, without checking
+    /// that it is valid UTF-8 or its length.
+    ///
+    /// A short string longer than 63 bytes can not be encoded: builders return an error
+    /// when appending it, unless [`VariantBuilder::with_short_string_downgrade`] is
+    /// enabled, in which case it is written as a [`Variant::String`].
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid UTF-8
+    ///
+    /// [`VariantBuilder::with_short_string_downgrade`]: crate::VariantBuilder::with_short_string_downgrade
+    pub unsafe fn new_unchecked(bytes: &'a [u8]) -> Self {
+        // SAFETY: the caller guarantees that bytes are valid UTF-8
+        Self(unsafe { std::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Concatenates `parts` into `buffer`, which is cleared first, and returns the result as
+    /// a variant short string value.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::ShortString;
+    /// let mut buffer = String::new();
+    /// let joined = ShortString::concat(["user", "_", "42"], &mut buffer).unwrap();
+    /// assert_eq!(joined.as_str(), "user_42");
+    /// assert!(ShortString::concat(["a"; 64], &mut buffer).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the concatenation is longer than the maximum allowed length of
+    /// a Variant short string (63 bytes).
+    pub fn concat<'p>(
+        parts: impl IntoIterator<Item = &'p str>,
+        buffer: &'a mut String,
+    ) -> Result<Self, ArrowError> {
+        buffer.clear();
+        parts.into_iter().for_each(|part| buffer.push_str(part));
+        Self::try_new(buffer)
+    }
+
+    /// Returns true if this short string is not longer than the maximum allowed length of
+    /// a Variant short string (63 bytes), which is only possible if it was created with
+    /// [`Self::new_unchecked`]
+    pub fn is_valid_len(&self) -> bool {
+        self.0.len() <= MAX_SHORT_STRING_BYTES
+    }
+
     /// Returns the underlying Variant short string as a &str
     pub fn as_str(&self) -> &'a str {
         self.0
     }
 }
 
+/// Returns an error if `len` is longer than the maximum length of a Variant short string
+pub(crate) fn check_short_string_len(len: usize) -> Result<(), ArrowError> {
+    if len > MAX_SHORT_STRING_BYTES {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "short string of {len} bytes is larger than the maximum of {MAX_SHORT_STRING_BYTES} bytes"
+        )));
+    }
+    Ok(())
+}
+
 impl<'a> From<ShortString<'a>> for &'a str {
     fn from(value: ShortString<'a>) -> Self {
         value.0
@@ -229,6 +321,12 @@ pub enum Variant<'m, 'v> {
     TimestampMicros(DateTime<Utc>),
     /// Primitive (type_id=1): TIMESTAMP(isAdjustedToUTC=false, MICROS)
     TimestampNtzMicros(NaiveDateTime),
+    /// Primitive (type_id=1): TIME(isAdjustedToUTC=false, MICROS)
+    Time(NaiveTime),
+    /// Primitive (type_id=1): TIMESTAMP(isAdjustedToUTC=true, NANOS)
+    TimestampNanos(DateTime<Utc>),
+    /// Primitive (type_id=1): TIMESTAMP(isAdjustedToUTC=false, NANOS)
+    TimestampNtzNanos(NaiveDateTime),
     /// Primitive (type_id=1): DECIMAL(precision, scale) 32-bits
     Decimal4(VariantDecimal4),
     /// Primitive (type_id=1): DECIMAL(precision, scale) 64-bits
@@ -248,6 +346,8 @@ pub enum Variant<'m, 'v> {
     Binary(&'v [u8]),
     /// Primitive (type_id=1): STRING
     String(&'v str),
+    /// Primitive (type_id=1): UUID, as 16 bytes in big-endian order
+    Uuid([u8; 16]),
     /// Short String (type_id=2): STRING
     ShortString(ShortString<'v>),
     // need both metadata & value
@@ -283,6 +383,91 @@ impl<'m, 'v> Variant<'m, 'v> {
         Self::try_new_with_metadata(metadata, value)
     }
 
+    /// Attempts to interpret a metadata and value buffer pair as a new `Variant`, enforcing the
+    /// limits of `options`.
+    ///
+    /// This is intended for decoding untrusted bytes, see [`VariantDecodeOptions`]. The limits
+    /// on the sizes of the buffers are checked before validating them, and the nesting depth is
+    /// checked before validating the contents of each object or list.
+    ///
+    /// The instance is fully [validated].
+    ///
+    /// [validated]: Self#Validation
+    pub fn try_new_with_options(
+        metadata: &'m [u8],
+        value: &'v [u8],
+        options: &VariantDecodeOptions,
+    ) -> Result<Self, ArrowError> {
+        if options.strict_validation() {
+            Self::validate_strict(metadata, value, options)?;
+        }
+        if value.len() > options.max_value_bytes() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Variant value of {} bytes exceeds the maximum of {} bytes",
+                value.len(),
+                options.max_value_bytes()
+            )));
+        }
+        let metadata = VariantMetadata::try_new_with_shallow_validation(metadata)?;
+        if metadata.dictionary_size() > options.max_dictionary_entries() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Variant metadata dictionary of {} entries exceeds the maximum of {} entries",
+                metadata.dictionary_size(),
+                options.max_dictionary_entries()
+            )));
+        }
+        let metadata = metadata.with_full_validation()?;
+        Self::try_new_with_metadata_and_max_depth(metadata, value, 0, options.max_depth())
+    }
+
+    /// Strictly validates a metadata and value buffer pair, returning the byte offset and path of
+    /// the first violation of the [specification], if any.
+    ///
+    /// This is intended for data quality tooling that needs to pinpoint corrupt records. Unlike
+    /// [`Self::try_new`], the value is validated iteratively rather than recursively, so arbitrarily
+    /// deep values can not overflow the stack, and the limits of `options` are enforced. In
+    /// addition to the checks of [full validation], it checks that:
+    ///
+    /// * The offsets of the metadata dictionary and lists start at 0 and are monotonically
+    ///   increasing, and the offsets of object fields are within the object
+    /// * The field ids of objects are in range, and their names are unique and sorted
+    /// * All strings are valid UTF-8, and all primitive types are known
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuffer, VariantBuilder, VariantDecodeOptions};
+    /// let mut builder = VariantBuilder::new();
+    /// let mut list = builder.new_list();
+    /// list.append_value("hello");
+    /// list.finish();
+    /// let (metadata, mut value) = builder.finish();
+    ///
+    /// let options = VariantDecodeOptions::new();
+    /// assert!(Variant::validate_strict(&metadata, &value, &options).is_ok());
+    ///
+    /// // Corrupt the last byte of the string "hello"
+    /// let last = value.len() - 1;
+    /// value[last] = 0xFF;
+    /// let err = Variant::validate_strict(&metadata, &value, &options).unwrap_err();
+    /// assert_eq!(err.buffer(), VariantBuffer::Value);
+    /// assert_eq!(err.offset(), last);
+    /// assert_eq!(err.path().to_string(), "$[0]");
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid UTF-8 in string at byte 9 of the variant value, at path $[0]"
+    /// );
+    /// ```
+    ///
+    /// [specification]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+    /// [full validation]: Self#Validation
+    pub fn validate_strict(
+        metadata: &[u8],
+        value: &[u8],
+        options: &VariantDecodeOptions,
+    ) -> Result<(), VariantValidationError> {
+        strict::validate_strict(metadata, value, options)
+    }
+
     /// Attempts to interpret a metadata and value buffer pair as a new `Variant`.
     ///
     /// The instance is [unvalidated].
@@ -340,6 +525,25 @@ impl<'m, 'v> Variant<'m, 'v> {
             .expect("Invalid variant")
     }
 
+    /// Similar to [`Self::try_new_with_metadata`], for a value nested in `depth` objects or
+    /// lists, returning an error if its nesting depth exceeds `max_depth`.
+    pub(crate) fn try_new_with_metadata_and_max_depth(
+        metadata: VariantMetadata<'m>,
+        value: &'v [u8],
+        depth: usize,
+        max_depth: usize,
+    ) -> Result<Self, ArrowError> {
+        match Self::try_new_with_metadata_and_shallow_validation(metadata, value)? {
+            Variant::List(list) => list
+                .with_full_validation_and_max_depth(depth + 1, max_depth)
+                .map(Variant::List),
+            Variant::Object(obj) => obj
+                .with_full_validation_and_max_depth(depth + 1, max_depth)
+                .map(Variant::Object),
+            variant => Ok(variant),
+        }
+    }
+
     // The actual constructor, which only performs shallow (constant-time) validation.
     fn try_new_with_metadata_and_shallow_validation(
         metadata: VariantMetadata<'m>,
@@ -379,12 +583,22 @@ impl<'m, 'v> Variant<'m, 'v> {
                 VariantPrimitiveType::TimestampNtzMicros => {
                     Variant::TimestampNtzMicros(decoder::decode_timestampntz_micros(value_data)?)
                 }
+                VariantPrimitiveType::Time => {
+                    Variant::Time(decoder::decode_time_micros(value_data)?)
+                }
+                VariantPrimitiveType::TimestampNanos => {
+                    Variant::TimestampNanos(decoder::decode_timestamp_nanos(value_data)?)
+                }
+                VariantPrimitiveType::TimestampNtzNanos => {
+                    Variant::TimestampNtzNanos(decoder::decode_timestampntz_nanos(value_data)?)
+                }
                 VariantPrimitiveType::Binary => {
                     Variant::Binary(decoder::decode_binary(value_data)?)
                 }
                 VariantPrimitiveType::String => {
                     Variant::String(decoder::decode_long_string(value_data)?)
                 }
+                VariantPrimitiveType::Uuid => Variant::Uuid(decoder::decode_uuid(value_data)?),
             },
             VariantBasicType::ShortString => {
                 Variant::ShortString(decoder::decode_short_string(value_metadata, value_data)?)
@@ -537,8 +751,8 @@ impl<'m, 'v> Variant<'m, 'v> {
     /// ```
     pub fn as_datetime_utc(&self) -> Option<DateTime<Utc>> {
         match *self {
-            Variant::TimestampMicros(d) => Some(d),
-            Variant::TimestampNtzMicros(d) => Some(d.and_utc()),
+            Variant::TimestampMicros(d) | Variant::TimestampNanos(d) => Some(d),
+            Variant::TimestampNtzMicros(d) | Variant::TimestampNtzNanos(d) => Some(d.and_utc()),
             _ => None,
         }
     }
@@ -570,8 +784,35 @@ impl<'m, 'v> Variant<'m, 'v> {
     /// ```
     pub fn as_naive_datetime(&self) -> Option<NaiveDateTime> {
         match *self {
-            Variant::TimestampNtzMicros(d) => Some(d),
-            Variant::TimestampMicros(d) => Some(d.naive_utc()),
+            Variant::TimestampNtzMicros(d) | Variant::TimestampNtzNanos(d) => Some(d),
+            Variant::TimestampMicros(d) | Variant::TimestampNanos(d) => Some(d.naive_utc()),
+            _ => None,
+        }
+    }
+
+    /// Converts this variant to a `NaiveTime` if possible.
+    ///
+    /// Returns `Some(NaiveTime)` for time variants,
+    /// `None` for non-time variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parquet_variant::Variant;
+    /// use chrono::NaiveTime;
+    ///
+    /// // you can extract a NaiveTime from a time variant
+    /// let time = NaiveTime::from_hms_micro_opt(12, 34, 56, 780123).unwrap();
+    /// let v1 = Variant::from(time);
+    /// assert_eq!(v1.as_naive_time(), Some(time));
+    ///
+    /// // but not from other variants
+    /// let v2 = Variant::from("hello!");
+    /// assert_eq!(v2.as_naive_time(), None);
+    /// ```
+    pub fn as_naive_time(&self) -> Option<NaiveTime> {
+        match *self {
+            Variant::Time(t) => Some(t),
             _ => None,
         }
     }
@@ -629,6 +870,56 @@ impl<'m, 'v> Variant<'m, 'v> {
         }
     }
 
+    /// Converts this variant to the 16 big-endian bytes of a UUID if possible.
+    ///
+    /// Returns `Some([u8; 16])` for UUID variants,
+    /// `None` for non-UUID variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parquet_variant::Variant;
+    ///
+    /// // you can extract the bytes of a UUID variant
+    /// let bytes = [0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8];
+    /// let v1 = Variant::Uuid(bytes);
+    /// assert_eq!(v1.as_uuid_bytes(), Some(bytes));
+    ///
+    /// // but not from other variants
+    /// let v2 = Variant::from("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// assert_eq!(v2.as_uuid_bytes(), None);
+    /// ```
+    pub fn as_uuid_bytes(&self) -> Option<[u8; 16]> {
+        match *self {
+            Variant::Uuid(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Converts this variant to a [`uuid::Uuid`] if possible.
+    ///
+    /// Returns `Some(Uuid)` for UUID variants,
+    /// `None` for non-UUID variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parquet_variant::Variant;
+    /// use uuid::Uuid;
+    ///
+    /// let uuid = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    /// let v1 = Variant::from(uuid);
+    /// assert_eq!(v1.as_uuid(), Some(uuid));
+    ///
+    /// // but not from other variants
+    /// let v2 = Variant::from("67e55044-10b1-426f-9247-bb680e5fe0c8");
+    /// assert_eq!(v2.as_uuid(), None);
+    /// ```
+    #[cfg(feature = "uuid")]
+    pub fn as_uuid(&self) -> Option<uuid::Uuid> {
+        self.as_uuid_bytes().map(uuid::Uuid::from_bytes)
+    }
+
     /// Converts this variant to an `i8` if possible.
     ///
     /// Returns `Some(i8)` for integer variants that fit in `i8` range,
@@ -1067,14 +1358,373 @@ impl<'m, 'v> Variant<'m, 'v> {
 
     /// Return a new Variant with the path followed.
     ///
-    /// If the path is not found, `None` is returned.
-    pub fn get_path(&self, path: &VariantPath) -> Option<Variant> {
+    /// If the path is not found, `None` is returned. The returned variant borrows from
+    /// the same buffers as this one, no data is copied.
+    ///
+    /// # Examples
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// # use parquet_variant::path::VariantPath;
+    /// # let mut builder = VariantBuilder::new();
+    /// # let mut obj = builder.new_object();
+    /// # let mut addresses = obj.new_list("addresses");
+    /// # let mut address = addresses.new_object();
+    /// # address.insert("zip", "10001");
+    /// # address.finish().unwrap();
+    /// # addresses.finish();
+    /// # obj.finish().unwrap();
+    /// # let (metadata, value) = builder.finish();
+    /// // object that is {"addresses": [{"zip": "10001"}]}
+    /// let variant = Variant::new(&metadata, &value);
+    ///
+    /// let path = VariantPath::try_parse("$.addresses[0].zip").unwrap();
+    /// assert_eq!(variant.get_path(&path), Some(Variant::from("10001")));
+    ///
+    /// let path = VariantPath::try_parse("$.addresses[1].zip").unwrap();
+    /// assert_eq!(variant.get_path(&path), None);
+    /// ```
+    pub fn get_path(&self, path: &VariantPath) -> Option<Variant<'m, 'v>> {
         path.iter()
             .try_fold(self.clone(), |output, element| match element {
                 VariantPathElement::Field { name } => output.get_object_field(name),
                 VariantPathElement::Index { index } => output.get_list_element(*index),
             })
     }
+
+    /// Fallible version of `get_path`. Returns the value at `path`, if any, capturing
+    /// validation errors.
+    ///
+    /// Unlike [`Self::get_path`], this never panics, even if this variant is [unvalidated] and
+    /// contains invalid data. Only the parts of the objects and lists along the path that are
+    /// visited by the lookup are validated, as described in [`VariantObject::try_get`], and the
+    /// value at the end of the path is then fully validated. This makes point lookups into large
+    /// unvalidated variants much cheaper than validating them with [`Self::with_full_validation`].
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// # use parquet_variant::path::VariantPath;
+    /// # let mut builder = VariantBuilder::new();
+    /// # let mut obj = builder.new_object();
+    /// # let mut addresses = obj.new_list("addresses");
+    /// # let mut address = addresses.new_object();
+    /// # address.insert("zip", "10001");
+    /// # address.finish().unwrap();
+    /// # addresses.finish();
+    /// # obj.finish().unwrap();
+    /// # let (metadata, value) = builder.finish();
+    /// // object that is {"addresses": [{"zip": "10001"}]}
+    /// let variant = Variant::new(&metadata, &value);
+    ///
+    /// let path = VariantPath::try_parse("$.addresses[0].zip").unwrap();
+    /// assert_eq!(variant.try_get_path(&path).unwrap(), Some(Variant::from("10001")));
+    ///
+    /// // Invalid data along the path is reported as an error rather than a panic
+    /// let mut invalid = value.clone();
+    /// *invalid.last_mut().unwrap() = 0xFF; // "1000\xFF" is not valid UTF-8
+    /// let variant = Variant::new(&metadata, &invalid);
+    /// assert!(variant.try_get_path(&path).is_err());
+    /// ```
+    ///
+    /// [unvalidated]: Self#Validation
+    pub fn try_get_path(&self, path: &VariantPath) -> Result<Option<Variant<'m, 'v>>, ArrowError> {
+        let mut output = self.clone();
+        for element in path.iter() {
+            let next = match (element, &output) {
+                (VariantPathElement::Field { name }, Variant::Object(object)) => {
+                    object.try_get_with_shallow_validation(name)?
+                }
+                (VariantPathElement::Index { index }, Variant::List(list))
+                    if *index < list.len() =>
+                {
+                    Some(list.try_get_with_shallow_validation(*index)?)
+                }
+                _ => None,
+            };
+            match next {
+                Some(next) => output = next,
+                None => return Ok(None),
+            }
+        }
+        output.with_full_validation().map(Some)
+    }
+
+    /// Returns true if this variant is logically equal to `other`
+    ///
+    /// Unlike `==`, which compares the physical representation of two variants, this compares
+    /// the values they represent:
+    ///
+    /// * Exact numerics (`Int8`, `Int16`, `Int32`, `Int64` and the decimals) are equal if they
+    ///   represent the same number, regardless of their type and scale, and are compared to
+    ///   `Float` and `Double` by converting them to `f64`
+    /// * `String` and `ShortString` are equal if they contain the same string
+    /// * Timestamps are equal to timestamps of the same timezone kind representing the same
+    ///   time, regardless of their precision
+    /// * Objects are equal if they contain the same field names, in any order, with logically
+    ///   equal values
+    /// * Lists are equal if they contain logically equal elements in the same order
+    ///
+    /// Values of different kinds, such as a string and a number, are never equal. Floating
+    /// point `NaN` values are not equal to any value, including themselves.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder, VariantDecimal4};
+    /// let decimal = VariantDecimal4::try_new(4200, 2).unwrap();
+    /// assert!(Variant::from(42i8).logical_eq(&Variant::from(decimal)));
+    /// assert!(Variant::from(42i8).logical_eq(&Variant::from(42.0)));
+    /// assert!(!Variant::from(42i8).logical_eq(&Variant::from("42")));
+    ///
+    /// // {"a": 1, "b": 2}
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("a", 1);
+    /// obj.insert("b", 2);
+    /// obj.finish().unwrap();
+    /// let (m1, v1) = builder.finish();
+    ///
+    /// // {"b": 2, "a": 1.0}
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("b", 2i64);
+    /// obj.insert("a", 1.0);
+    /// obj.finish().unwrap();
+    /// let (m2, v2) = builder.finish();
+    /// assert!(Variant::new(&m1, &v1).logical_eq(&Variant::new(&m2, &v2)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a nested object or list is [unvalidated] and contains invalid data
+    ///
+    /// [unvalidated]: Self#Validation
+    pub fn logical_eq(&self, other: &Variant<'_, '_>) -> bool {
+        self.partial_cmp_logical(other) == Some(Ordering::Equal)
+    }
+
+    /// Compares the value this variant represents to the value `other` represents
+    ///
+    /// Returns `None` if the values are not comparable. Values are comparable if they are of
+    /// the same kind, as described in [`Self::logical_eq`], and:
+    ///
+    /// * Nulls, booleans, numbers, strings, binaries, UUIDs, dates, times and timestamps are
+    ///   ordered by their value, where strings and binaries are ordered by their bytes
+    /// * Lists are ordered lexicographically by their elements, and are not comparable if any
+    ///   compared pair of elements is not comparable
+    /// * Objects are only comparable if they are logically equal, as objects have no order
+    ///
+    /// # Example
+    /// ```
+    /// # use std::cmp::Ordering;
+    /// # use parquet_variant::{Variant, VariantDecimal8};
+    /// let decimal = VariantDecimal8::try_new(1005, 1).unwrap(); // 100.5
+    /// assert_eq!(
+    ///     Variant::from(100).partial_cmp_logical(&Variant::from(decimal)),
+    ///     Some(Ordering::Less)
+    /// );
+    /// assert_eq!(
+    ///     Variant::from("abc").partial_cmp_logical(&Variant::from("abd")),
+    ///     Some(Ordering::Less)
+    /// );
+    /// assert_eq!(Variant::from(true).partial_cmp_logical(&Variant::from(1)), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a nested object or list is [unvalidated] and contains invalid data
+    ///
+    /// [unvalidated]: Self#Validation
+    pub fn partial_cmp_logical(&self, other: &Variant<'_, '_>) -> Option<Ordering> {
+        if let (Some(a), Some(b)) = (self.as_exact_numeric(), other.as_exact_numeric()) {
+            return Some(cmp_exact_numeric(a, b));
+        }
+        if let (Some(a), Some(b)) = (self.as_numeric_f64(), other.as_numeric_f64()) {
+            return a.partial_cmp(&b);
+        }
+        match (self, other) {
+            (Variant::Null, Variant::Null) => Some(Ordering::Equal),
+            (Variant::BooleanTrue | Variant::BooleanFalse, _) => {
+                Some(self.as_boolean()?.cmp(&other.as_boolean()?))
+            }
+            (Variant::String(_) | Variant::ShortString(_), _) => {
+                Some(self.as_string()?.cmp(other.as_string()?))
+            }
+            (Variant::Binary(a), Variant::Binary(b)) => Some(a.cmp(b)),
+            (Variant::Uuid(a), Variant::Uuid(b)) => Some(a.cmp(b)),
+            (Variant::Date(a), Variant::Date(b)) => Some(a.cmp(b)),
+            (Variant::Time(a), Variant::Time(b)) => Some(a.cmp(b)),
+            (
+                Variant::TimestampMicros(a) | Variant::TimestampNanos(a),
+                Variant::TimestampMicros(b) | Variant::TimestampNanos(b),
+            ) => Some(a.cmp(b)),
+            (
+                Variant::TimestampNtzMicros(a) | Variant::TimestampNtzNanos(a),
+                Variant::TimestampNtzMicros(b) | Variant::TimestampNtzNanos(b),
+            ) => Some(a.cmp(b)),
+            (Variant::List(a), Variant::List(b)) => {
+                for (a, b) in a.iter().zip(b.iter()) {
+                    match a.partial_cmp_logical(&b)? {
+                        Ordering::Equal => {}
+                        ordering => return Some(ordering),
+                    }
+                }
+                Some(a.len().cmp(&b.len()))
+            }
+            (Variant::Object(a), Variant::Object(b)) => {
+                if a.len() != b.len() {
+                    return None;
+                }
+                for (name, a) in a.iter() {
+                    if !a.logical_eq(&b.get(name)?) {
+                        return None;
+                    }
+                }
+                Some(Ordering::Equal)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the integer and scale of an exact numeric value
+    fn as_exact_numeric(&self) -> Option<(i128, u8)> {
+        match *self {
+            Variant::Int8(i) => Some((i.into(), 0)),
+            Variant::Int16(i) => Some((i.into(), 0)),
+            Variant::Int32(i) => Some((i.into(), 0)),
+            Variant::Int64(i) => Some((i.into(), 0)),
+            Variant::Decimal4(d) => Some((d.integer().into(), d.scale())),
+            Variant::Decimal8(d) => Some((d.integer().into(), d.scale())),
+            Variant::Decimal16(d) => Some((d.integer(), d.scale())),
+            _ => None,
+        }
+    }
+
+    /// Converts any numeric value to `f64`, potentially losing precision
+    fn as_numeric_f64(&self) -> Option<f64> {
+        match *self {
+            Variant::Float(f) => Some(f.into()),
+            Variant::Double(f) => Some(f),
+            _ => {
+                // Equal decimals of different scales must convert to the same `f64`
+                let (mut integer, mut scale) = self.as_exact_numeric()?;
+                while scale > 0 && integer % 10 == 0 {
+                    integer /= 10;
+                    scale -= 1;
+                }
+                Some(integer as f64 / 10f64.powi(scale.into()))
+            }
+        }
+    }
+}
+
+/// Hashes the value a [`Variant`] represents rather than its encoding
+///
+/// Variants that are [logically equal] produce the same hash, regardless of their encoding.
+/// For example, objects with the same fields hash identically even if their field names
+/// have different ids in their metadata, and numbers hash identically regardless of their
+/// type. This allows variants to be used as keys for grouping and joins. This is also
+/// consistent with `==`, as variants with equal encodings are logically equal.
+///
+/// Each value is hashed as a tag identifying its kind, followed by:
+///
+/// * Numbers: the bits of the value converted to `f64`, where `-0.0` is hashed as `0.0`
+/// * Strings, binaries and UUIDs: their bytes
+/// * Dates, times and timestamps: the date and time they represent, where timestamps of
+///   different precisions hash identically
+/// * Lists: their length and elements in order
+/// * Objects: their length and fields ordered by name, each hashed as the field name
+///   followed by its value
+///
+/// # Example
+/// ```
+/// # use std::collections::hash_map::RandomState;
+/// # use std::hash::BuildHasher;
+/// # use parquet_variant::{Variant, VariantDecimal4};
+/// let state = RandomState::new();
+/// let decimal = VariantDecimal4::try_new(4200, 2).unwrap();
+/// assert_eq!(state.hash_one(Variant::from(42)), state.hash_one(Variant::from(decimal)));
+/// assert_eq!(state.hash_one(Variant::from(42)), state.hash_one(Variant::from(42.0)));
+/// assert_ne!(state.hash_one(Variant::from(42)), state.hash_one(Variant::from("42")));
+/// ```
+///
+/// # Panics
+///
+/// Panics if a nested object or list is [unvalidated] and contains invalid data
+///
+/// [logically equal]: Variant::logical_eq
+/// [unvalidated]: Variant#Validation
+impl Hash for Variant<'_, '_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if let Some(f) = self.as_numeric_f64() {
+            state.write_u8(2);
+            // Normalize zeros, which are equal regardless of their sign
+            let f = if f == 0.0 { 0.0 } else { f };
+            state.write_u64(f.to_bits());
+            return;
+        }
+        match self {
+            Variant::Null => state.write_u8(0),
+            Variant::BooleanTrue | Variant::BooleanFalse => {
+                state.write_u8(1);
+                state.write_u8(matches!(self, Variant::BooleanTrue).into());
+            }
+            Variant::String(s) | Variant::ShortString(ShortString(s)) => {
+                state.write_u8(3);
+                s.hash(state);
+            }
+            Variant::Binary(b) => {
+                state.write_u8(4);
+                b.hash(state);
+            }
+            Variant::Uuid(u) => {
+                state.write_u8(5);
+                u.hash(state);
+            }
+            Variant::Date(d) => {
+                state.write_u8(6);
+                d.hash(state);
+            }
+            Variant::Time(t) => {
+                state.write_u8(7);
+                t.hash(state);
+            }
+            Variant::TimestampMicros(t) | Variant::TimestampNanos(t) => {
+                state.write_u8(8);
+                t.hash(state);
+            }
+            Variant::TimestampNtzMicros(t) | Variant::TimestampNtzNanos(t) => {
+                state.write_u8(9);
+                t.hash(state);
+            }
+            Variant::List(list) => {
+                state.write_u8(10);
+                state.write_usize(list.len());
+                list.iter().for_each(|element| element.hash(state));
+            }
+            Variant::Object(object) => {
+                state.write_u8(11);
+                state.write_usize(object.len());
+                for (name, value) in object.iter() {
+                    name.hash(state);
+                    value.hash(state);
+                }
+            }
+            // Numbers were handled above
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Compares two decimals given as integer and scale
+fn cmp_exact_numeric((a, a_scale): (i128, u8), (b, b_scale): (i128, u8)) -> Ordering {
+    // Rescaling a value to a larger scale only overflows if its magnitude is larger than that
+    // of any value at that scale, in which case its sign determines the ordering
+    let rescale = |v: i128, by: u8| v.checked_mul(10i128.checked_pow(by.into())?);
+    match a_scale.cmp(&b_scale) {
+        Ordering::Equal => a.cmp(&b),
+        Ordering::Less => rescale(a, b_scale - a_scale).map_or(a.cmp(&0), |a| a.cmp(&b)),
+        Ordering::Greater => rescale(b, a_scale - b_scale).map_or(0.cmp(&b), |b| a.cmp(&b)),
+    }
 }
 
 impl From<()> for Variant<'_, '_> {
@@ -1163,6 +1813,19 @@ impl From<NaiveDateTime> for Variant<'_, '_> {
     }
 }
 
+impl From<NaiveTime> for Variant<'_, '_> {
+    fn from(value: NaiveTime) -> Self {
+        Variant::Time(value)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for Variant<'_, '_> {
+    fn from(value: uuid::Uuid) -> Self {
+        Variant::Uuid(value.into_bytes())
+    }
+}
+
 impl<'v> From<&'v [u8]> for Variant<'_, 'v> {
     fn from(value: &'v [u8]) -> Self {
         Variant::Binary(value)
@@ -1213,6 +1876,7 @@ impl TryFrom<(i128, u8)> for Variant<'_, '_> {
 mod tests {
 
     use super::*;
+    use crate::VariantBuilder;
 
     #[test]
     fn test_empty_variant_will_fail() {
@@ -1232,7 +1896,35 @@ mod tests {
 
         let long_string = "a".repeat(MAX_SHORT_STRING_BYTES + 1);
         let res = ShortString::try_new(&long_string);
-        assert!(res.is_err());
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Invalid argument error: short string of 64 bytes is larger than the maximum of 63 bytes"
+        );
+
+        let short_string = ShortString::try_from_utf8("héllo".as_bytes()).unwrap();
+        assert_eq!(short_string.as_str(), "héllo");
+        let err = ShortString::try_from_utf8(&[0xff]).unwrap_err();
+        assert!(err.to_string().contains("invalid UTF-8"), "{err}");
+        assert!(ShortString::try_from_utf8(long_string.as_bytes()).is_err());
+
+        // SAFETY: the bytes are valid UTF-8
+        let short_string = unsafe { ShortString::try_from_utf8_unchecked(b"norm") }.unwrap();
+        assert_eq!(short_string.as_str(), "norm");
+        assert!(unsafe { ShortString::try_from_utf8_unchecked(long_string.as_bytes()) }.is_err());
+        let unchecked = unsafe { ShortString::new_unchecked(long_string.as_bytes()) };
+        assert_eq!(unchecked.as_str(), long_string);
+        assert!(!unchecked.is_valid_len());
+        assert!(short_string.is_valid_len());
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn test_variant_uuid_conversion() {
+        let uuid = uuid::Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+        let variant = Variant::from(uuid);
+        assert_eq!(variant, Variant::Uuid(*uuid.as_bytes()));
+        assert_eq!(variant.as_uuid(), Some(uuid));
+        assert_eq!(variant.as_uuid_bytes(), Some(*uuid.as_bytes()));
     }
 
     #[test]
@@ -1249,4 +1941,175 @@ mod tests {
         let variant = Variant::from(decimal16);
         assert_eq!(variant.as_decimal16(), Some(decimal16));
     }
+
+    #[test]
+    fn test_partial_cmp_logical_numeric() {
+        let d4 = |i, s| Variant::from(VariantDecimal4::try_new(i, s).unwrap());
+        let d16 = |i, s| Variant::from(VariantDecimal16::try_new(i, s).unwrap());
+
+        assert!(Variant::from(1i8).logical_eq(&Variant::from(1i64)));
+        assert!(Variant::from(1i8).logical_eq(&d4(1000, 3)));
+        assert!(d4(1000, 3).logical_eq(&Variant::from(1.0f32)));
+        assert!(!Variant::from(1i8).logical_eq(&Variant::from(1.5)));
+        assert!(!Variant::from(f64::NAN).logical_eq(&Variant::from(f64::NAN)));
+
+        assert_eq!(
+            d4(-15, 1).partial_cmp_logical(&Variant::from(-1)),
+            Some(Ordering::Less)
+        );
+        // Rescaling the largest decimals overflows
+        let max = d16(99999999999999999999999999999999999999, 0);
+        let min = d16(-99999999999999999999999999999999999999, 0);
+        let small = d16(1, 38);
+        assert_eq!(max.partial_cmp_logical(&small), Some(Ordering::Greater));
+        assert_eq!(small.partial_cmp_logical(&max), Some(Ordering::Less));
+        assert_eq!(min.partial_cmp_logical(&small), Some(Ordering::Less));
+        assert_eq!(small.partial_cmp_logical(&min), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_partial_cmp_logical_nested() {
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert(
+            "a",
+            "a string longer than a short string can be, which is 63 bytes",
+        );
+        let mut list = obj.new_list("b");
+        list.append_value(1);
+        list.append_value(2);
+        list.finish();
+        obj.finish().unwrap();
+        let (m1, v1) = builder.finish();
+        let v1 = Variant::new(&m1, &v1);
+
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        let mut list = obj.new_list("b");
+        list.append_value(1.0);
+        list.append_value(2i64);
+        list.finish();
+        obj.insert(
+            "a",
+            "a string longer than a short string can be, which is 63 bytes",
+        );
+        obj.finish().unwrap();
+        let (m2, v2) = builder.finish();
+        let v2 = Variant::new(&m2, &v2);
+
+        assert_ne!(v1, v2);
+        assert!(v1.logical_eq(&v2));
+
+        let list1 = v1.get_object_field("b").unwrap();
+        let list2 = v2.get_object_field("b").unwrap();
+        assert_eq!(list1.partial_cmp_logical(&list2), Some(Ordering::Equal));
+
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_value(1);
+        list.finish();
+        let (m3, v3) = builder.finish();
+        let list3 = Variant::new(&m3, &v3);
+        assert_eq!(list3.partial_cmp_logical(&list1), Some(Ordering::Less));
+
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("a", "different");
+        obj.insert("b", 1);
+        obj.finish().unwrap();
+        let (m4, v4) = builder.finish();
+        let v4 = Variant::new(&m4, &v4);
+        assert!(!v1.logical_eq(&v4));
+        assert_eq!(v1.partial_cmp_logical(&v4), None);
+    }
+
+    #[test]
+    fn test_hash_logical_value() {
+        use std::collections::hash_map::RandomState;
+        use std::hash::BuildHasher;
+
+        let state = RandomState::new();
+        let hash = |metadata: &[u8], value: &[u8]| state.hash_one(Variant::new(metadata, value));
+
+        // The same object with the field names in a different order in the metadata
+        let mut builder = VariantBuilder::new().with_field_names(["a", "b"].into_iter());
+        let mut obj = builder.new_object();
+        obj.insert("b", VariantDecimal8::try_new(-10, 1).unwrap());
+        obj.insert("a", 0.0);
+        obj.finish().unwrap();
+        let (m1, v1) = builder.finish();
+
+        let mut builder = VariantBuilder::new().with_field_names(["b", "a"].into_iter());
+        let mut obj = builder.new_object();
+        obj.insert("a", -0.0);
+        obj.insert("b", -1);
+        obj.finish().unwrap();
+        let (m2, v2) = builder.finish();
+
+        assert_ne!(v1, v2);
+        assert_eq!(hash(&m1, &v1), hash(&m2, &v2));
+
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("a", 0);
+        obj.insert("b", "-1");
+        obj.finish().unwrap();
+        let (m3, v3) = builder.finish();
+        assert_ne!(hash(&m1, &v1), hash(&m3, &v3));
+    }
+
+    #[test]
+    fn test_try_new_with_options() {
+        // {"a": [1, {"b": 2}]} has a depth of 3
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        let mut list = obj.new_list("a");
+        list.append_value(1);
+        let mut inner = list.new_object();
+        inner.insert("b", 2);
+        inner.finish().unwrap();
+        list.finish();
+        obj.finish().unwrap();
+        let (metadata, value) = builder.finish();
+
+        let try_new = |options: VariantDecodeOptions| {
+            Variant::try_new_with_options(&metadata, &value, &options).map_err(|e| e.to_string())
+        };
+
+        let variant = try_new(VariantDecodeOptions::new()).unwrap();
+        assert_eq!(variant, Variant::try_new(&metadata, &value).unwrap());
+        try_new(VariantDecodeOptions::new().with_max_depth(3)).unwrap();
+        assert_eq!(
+            try_new(VariantDecodeOptions::new().with_max_depth(2)).unwrap_err(),
+            "Invalid argument error: Variant nesting depth exceeds the maximum of 2"
+        );
+        assert_eq!(
+            try_new(VariantDecodeOptions::new().with_max_depth(0)).unwrap_err(),
+            "Invalid argument error: Variant nesting depth exceeds the maximum of 0"
+        );
+
+        try_new(VariantDecodeOptions::new().with_max_value_bytes(value.len())).unwrap();
+        assert_eq!(
+            try_new(VariantDecodeOptions::new().with_max_value_bytes(value.len() - 1)).unwrap_err(),
+            format!(
+                "Invalid argument error: Variant value of {} bytes exceeds the maximum of {} bytes",
+                value.len(),
+                value.len() - 1
+            )
+        );
+
+        try_new(VariantDecodeOptions::new().with_max_dictionary_entries(2)).unwrap();
+        assert_eq!(
+            try_new(VariantDecodeOptions::new().with_max_dictionary_entries(1)).unwrap_err(),
+            "Invalid argument error: Variant metadata dictionary of 2 entries exceeds the maximum of 1 entries"
+        );
+
+        // Primitive values have a depth of 0
+        let mut builder = VariantBuilder::new();
+        builder.append_value(1);
+        let (metadata, value) = builder.finish();
+        let options = VariantDecodeOptions::new().with_max_depth(0);
+        let variant = Variant::try_new_with_options(&metadata, &value, &options).unwrap();
+        assert_eq!(variant, Variant::Int32(1));
+    }
 }
@@ -18,6 +18,7 @@ use crate::decoder::{map_bytes_to_offsets, OffsetSizeBytes};
 use crate::utils::{
     first_byte_from_slice, overflow_error, slice_from_slice, slice_from_slice_at_offset,
 };
+use crate::variant::options::check_depth;
 use crate::variant::{Variant, VariantMetadata};
 
 use arrow_schema::ArrowError;
@@ -205,8 +206,20 @@ impl<'m, 'v> VariantList<'m, 'v> {
     /// Performs a full [validation] of this variant array and returns the result.
     ///
     /// [validation]: Self#Validation
-    pub fn with_full_validation(mut self) -> Result<Self, ArrowError> {
+    pub fn with_full_validation(self) -> Result<Self, ArrowError> {
+        self.with_full_validation_and_max_depth(1, usize::MAX)
+    }
+
+    /// Similar to [`Self::with_full_validation`], for an array at nesting `depth`, returning an
+    /// error if its nesting depth exceeds `max_depth`.
+    pub(crate) fn with_full_validation_and_max_depth(
+        mut self,
+        depth: usize,
+        max_depth: usize,
+    ) -> Result<Self, ArrowError> {
         if !self.validated {
+            check_depth(depth, max_depth)?;
+
             // Validate the metadata dictionary first, if not already validated, because we pass it
             // by value to all the children (who would otherwise re-validate it repeatedly).
             self.metadata = self.metadata.with_full_validation()?;
@@ -227,7 +240,12 @@ impl<'m, 'v> VariantList<'m, 'v> {
 
             for next_offset in offset_iter {
                 let value_bytes = slice_from_slice(value_buffer, current_offset..next_offset)?;
-                Variant::try_new_with_metadata(self.metadata.clone(), value_bytes)?;
+                Variant::try_new_with_metadata_and_max_depth(
+                    self.metadata.clone(),
+                    value_bytes,
+                    depth,
+                    max_depth,
+                )?;
                 current_offset = next_offset;
             }
 
@@ -263,12 +281,11 @@ impl<'m, 'v> VariantList<'m, 'v> {
     }
 
     // Fallible version of `get`, performing only basic (constant-time) validation.
-    fn try_get_with_shallow_validation(&self, index: usize) -> Result<Variant<'m, 'v>, ArrowError> {
-        // Fetch the value bytes between the two offsets for this index, from the value array region
-        // of the byte buffer
-        let byte_range = self.get_offset(index)? as _..self.get_offset(index + 1)? as _;
-        let value_bytes =
-            slice_from_slice_at_offset(self.value, self.first_value_byte as _, byte_range)?;
+    pub(crate) fn try_get_with_shallow_validation(
+        &self,
+        index: usize,
+    ) -> Result<Variant<'m, 'v>, ArrowError> {
+        let value_bytes = self.try_get_bytes(index)?;
         Variant::try_new_with_metadata_and_shallow_validation(self.metadata.clone(), value_bytes)
     }
 
@@ -281,6 +298,38 @@ impl<'m, 'v> VariantList<'m, 'v> {
             .map(|result| result.expect("Invalid variant list entry"))
     }
 
+    /// Iterates over the values of this list, skipping the per-element checks of [`Self::iter`],
+    /// or returns `None` if this list is not fully [validated].
+    ///
+    /// This is intended for performance-critical readers of lists that were fully validated,
+    /// e.g. by [`Self::with_full_validation`]. The offsets are decoded directly rather than
+    /// bounds-checked one element at a time. Use [`Self::iter_try`] for fallible iteration over
+    /// unvalidated lists.
+    ///
+    /// [validated]: Self#Validation
+    pub fn iter_validated(&self) -> Option<impl Iterator<Item = Variant<'m, 'v>> + '_> {
+        if !self.validated {
+            return None;
+        }
+        let offsets =
+            &self.value[self.header.first_offset_byte() as usize..self.first_value_byte as usize];
+        let values = &self.value[self.first_value_byte as usize..];
+
+        // Full validation checked the offsets and the values they refer to
+        let mut offsets = map_bytes_to_offsets(offsets, self.header.offset_size);
+        let first_offset = offsets.next().unwrap_or(0);
+        let elements = offsets.scan(first_offset, move |start, end| {
+            let value = Variant::try_new_with_metadata_and_shallow_validation(
+                self.metadata.clone(),
+                &values[*start..end],
+            )
+            .expect("Invalid variant list entry");
+            *start = end;
+            Some(value)
+        });
+        Some(elements)
+    }
+
     /// Fallible iteration over the elements of this list.
     pub fn iter_try(&self) -> impl Iterator<Item = Result<Variant<'m, 'v>, ArrowError>> + '_ {
         self.iter_try_with_shallow_validation()
@@ -294,6 +343,14 @@ impl<'m, 'v> VariantList<'m, 'v> {
         (0..self.len()).map(|i| self.try_get_with_shallow_validation(i))
     }
 
+    // Returns the bytes of the element at `index`
+    pub(crate) fn try_get_bytes(&self, index: usize) -> Result<&'v [u8], ArrowError> {
+        // Fetch the value bytes between the two offsets for this index, from the value array region
+        // of the byte buffer
+        let byte_range = self.get_offset(index)? as _..self.get_offset(index + 1)? as _;
+        slice_from_slice_at_offset(self.value, self.first_value_byte as _, byte_range)
+    }
+
     // Attempts to retrieve the ith offset from the offset array region of the byte buffer.
     fn get_offset(&self, index: usize) -> Result<u32, ArrowError> {
         let byte_range = self.header.first_offset_byte() as _..self.first_value_byte as _;
@@ -627,4 +684,36 @@ mod tests {
             assert_eq!(expected_list.get(i).unwrap(), item_str);
         }
     }
+
+    #[test]
+    fn test_iter_validated() {
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.append_value(1);
+        list.append_value("two");
+        list.append_value(Variant::Null);
+        let mut inner = list.new_list();
+        inner.append_value(3.5);
+        inner.finish();
+        list.finish();
+        let (metadata, value) = builder.finish();
+
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let list = variant.as_list().unwrap();
+        let elements = list.iter_validated().unwrap().collect::<Vec<_>>();
+        assert_eq!(elements, list.iter().collect::<Vec<_>>());
+        assert_eq!(elements.len(), 4);
+        assert_eq!(elements[1], Variant::from("two"));
+
+        // Lists that are not fully validated are not iterated
+        let variant = Variant::new(&metadata, &value);
+        assert!(variant.as_list().unwrap().iter_validated().is_none());
+
+        let mut builder = VariantBuilder::new();
+        builder.new_list().finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let elements = variant.as_list().unwrap().iter_validated().unwrap();
+        assert_eq!(elements.count(), 0);
+    }
 }
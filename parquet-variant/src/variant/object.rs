@@ -17,7 +17,9 @@
 use crate::decoder::{map_bytes_to_offsets, OffsetSizeBytes};
 use crate::utils::{
     first_byte_from_slice, overflow_error, slice_from_slice, try_binary_search_range_by,
+    try_binary_search_range_by_result,
 };
+use crate::variant::options::check_depth;
 use crate::variant::{Variant, VariantMetadata};
 
 use arrow_schema::ArrowError;
@@ -89,7 +91,9 @@ impl VariantObjectHeader {
 ///
 /// _Unvalidated_ instances can be constructed in constant time. They can be useful if the caller
 /// knows the underlying bytes were already validated previously, or if the caller intends to
-/// perform a small number of (fallible) field accesses against a large object.
+/// perform a small number of (fallible) field accesses against a large object. In particular,
+/// [`Self::try_get`] only validates the parts of the object visited by the lookup, so point
+/// lookups do not pay the cost of validating the whole object.
 ///
 /// A _validated_ instance guarantees that:
 ///
@@ -206,8 +210,20 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     /// Performs a full [validation] of this variant object.
     ///
     /// [validation]: Self#Validation
-    pub fn with_full_validation(mut self) -> Result<Self, ArrowError> {
+    pub fn with_full_validation(self) -> Result<Self, ArrowError> {
+        self.with_full_validation_and_max_depth(1, usize::MAX)
+    }
+
+    /// Similar to [`Self::with_full_validation`], for an object at nesting `depth`, returning an
+    /// error if its nesting depth exceeds `max_depth`.
+    pub(crate) fn with_full_validation_and_max_depth(
+        mut self,
+        depth: usize,
+        max_depth: usize,
+    ) -> Result<Self, ArrowError> {
         if !self.validated {
+            check_depth(depth, max_depth)?;
+
             // Validate the metadata dictionary first, if not already validated, because we pass it
             // by value to all the children (who would otherwise re-validate it repeatedly).
             self.metadata = self.metadata.with_full_validation()?;
@@ -270,7 +286,12 @@ impl<'m, 'v> VariantObject<'m, 'v> {
                 .take(num_offsets.saturating_sub(1))
                 .try_for_each(|offset| {
                     let value_bytes = slice_from_slice(value_buffer, offset..)?;
-                    Variant::try_new_with_metadata(self.metadata.clone(), value_bytes)?;
+                    Variant::try_new_with_metadata_and_max_depth(
+                        self.metadata.clone(),
+                        value_bytes,
+                        depth,
+                        max_depth,
+                    )?;
 
                     Ok::<_, ArrowError>(())
                 })?;
@@ -313,11 +334,16 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     // Attempts to retrieve the ith field value from the value region of the byte buffer; it
     // performs only basic (constant-cost) validation.
     fn try_field_with_shallow_validation(&self, i: usize) -> Result<Variant<'m, 'v>, ArrowError> {
-        let value_bytes = slice_from_slice(self.value, self.first_value_byte as _..)?;
-        let value_bytes = slice_from_slice(value_bytes, self.get_offset(i)? as _..)?;
+        let value_bytes = self.try_field_bytes(i)?;
         Variant::try_new_with_metadata_and_shallow_validation(self.metadata.clone(), value_bytes)
     }
 
+    // Returns the bytes of the ith field value, which may extend beyond the end of the value
+    pub(crate) fn try_field_bytes(&self, i: usize) -> Result<&'v [u8], ArrowError> {
+        let value_bytes = slice_from_slice(self.value, self.first_value_byte as _..)?;
+        slice_from_slice(value_bytes, self.get_offset(i)? as _..)
+    }
+
     // Attempts to retrieve the ith offset from the field offset region of the byte buffer.
     fn get_offset(&self, i: usize) -> Result<u32, ArrowError> {
         let byte_range = self.first_field_offset_byte as _..self.first_value_byte as _;
@@ -338,7 +364,7 @@ impl<'m, 'v> VariantObject<'m, 'v> {
     }
 
     /// Fallible version of `field_name`. Returns field name by index, capturing validation errors
-    fn try_field_name(&self, i: usize) -> Result<&'m str, ArrowError> {
+    pub(crate) fn try_field_name(&self, i: usize) -> Result<&'m str, ArrowError> {
         let byte_range = self.header.field_ids_start_byte() as _..self.first_field_offset_byte as _;
         let field_id_bytes = slice_from_slice(self.value, byte_range)?;
         let field_id = self.header.field_id_size.unpack_u32(field_id_bytes, i)?;
@@ -351,6 +377,46 @@ impl<'m, 'v> VariantObject<'m, 'v> {
             .map(|result| result.expect("Invalid variant object field value"))
     }
 
+    /// Returns an iterator of (name, value) pairs over the fields of this object, skipping the
+    /// per-field checks of [`Self::iter`], or `None` if this object is not fully [validated].
+    ///
+    /// This is intended for performance-critical readers of objects that were fully validated,
+    /// e.g. by [`Self::with_full_validation`]. The field ids and offsets are decoded directly
+    /// rather than bounds-checked one field at a time. Use [`Self::iter_try`] for fallible
+    /// iteration over unvalidated objects.
+    ///
+    /// [validated]: Self#Validation
+    pub fn iter_validated(&self) -> Option<impl Iterator<Item = (&'m str, Variant<'m, 'v>)> + '_> {
+        if !self.validated {
+            return None;
+        }
+        let field_ids = &self.value
+            [self.header.field_ids_start_byte() as usize..self.first_field_offset_byte as usize];
+        let field_offsets =
+            &self.value[self.first_field_offset_byte as usize..self.first_value_byte as usize];
+        let values = &self.value[self.first_value_byte as usize..];
+
+        // Full validation checked the field ids and the values they refer to
+        let fields = map_bytes_to_offsets(field_ids, self.header.field_id_size)
+            .zip(map_bytes_to_offsets(
+                field_offsets,
+                self.header.field_offset_size,
+            ))
+            .map(move |(field_id, offset)| {
+                let name = self
+                    .metadata
+                    .get(field_id)
+                    .expect("Invalid variant object field name");
+                let value = Variant::try_new_with_metadata_and_shallow_validation(
+                    self.metadata.clone(),
+                    &values[offset..],
+                )
+                .expect("Invalid variant object field value");
+                (name, value)
+            });
+        Some(fields)
+    }
+
     /// Fallible iteration over the fields of this object.
     pub fn iter_try(
         &self,
@@ -385,6 +451,56 @@ impl<'m, 'v> VariantObject<'m, 'v> {
 
         self.field(i)
     }
+
+    /// Fallible version of `get`. Returns the value of the field with the specified name, if
+    /// any, capturing validation errors.
+    ///
+    /// Unlike [`Self::get`], this never panics, even if this object is [invalid]. Rather than
+    /// validating the whole object, only the parts visited by the lookup are validated: the
+    /// field ids probed by the binary search and their names in the metadata dictionary, and the
+    /// offset of the requested field. The value of the requested field is then fully validated.
+    ///
+    /// NOTE: If the field names of an [invalid] object are not sorted, the binary search may not
+    /// find a field that exists, returning `Ok(None)` rather than an error.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder, VariantMetadata, VariantObject};
+    /// # let mut builder = VariantBuilder::new();
+    /// # let mut obj = builder.new_object();
+    /// # obj.insert("id", 42);
+    /// # obj.insert("name", "Alice");
+    /// # obj.finish().unwrap();
+    /// # let (metadata, value) = builder.finish();
+    /// // Constructing the object only validates its header
+    /// let metadata = VariantMetadata::new(&metadata);
+    /// let object = VariantObject::new(metadata, &value);
+    /// assert!(!object.is_fully_validated());
+    ///
+    /// let name = object.try_get("name").unwrap().unwrap();
+    /// assert!(name.is_fully_validated());
+    /// assert_eq!(name, Variant::from("Alice"));
+    /// assert_eq!(object.try_get("email").unwrap(), None);
+    /// ```
+    ///
+    /// [invalid]: Self#Validation
+    pub fn try_get(&self, name: &str) -> Result<Option<Variant<'m, 'v>>, ArrowError> {
+        self.try_get_with_shallow_validation(name)?
+            .map(Variant::with_full_validation)
+            .transpose()
+    }
+
+    // Fallible version of `get`, validating only the field ids and offset visited by the lookup,
+    // and performing only basic (constant-cost) validation of the field value.
+    pub(crate) fn try_get_with_shallow_validation(
+        &self,
+        name: &str,
+    ) -> Result<Option<Variant<'m, 'v>>, ArrowError> {
+        match try_binary_search_range_by_result(0..self.len(), &name, |i| self.try_field_name(i))? {
+            Ok(i) => self.try_field_with_shallow_validation(i).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -718,4 +834,84 @@ mod tests {
         test_variant_object_with_large_data(16777216 + 1, OffsetSizeBytes::Four);
         // 2^24
     }
+
+    #[test]
+    fn test_variant_object_try_get_lazy_validation() {
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("a", 1i8);
+        obj.insert("b", 2i8);
+        obj.insert("c", 3i8);
+        obj.finish().unwrap();
+        let (metadata_bytes, mut value) = builder.finish();
+
+        // Corrupt the value of field "b" with an invalid primitive type id
+        let metadata = VariantMetadata::new(&metadata_bytes);
+        let object = VariantObject::new(metadata.clone(), &value);
+        let offset = object.first_value_byte + object.get_offset(1).unwrap();
+        value[offset as usize] = 31 << 2;
+
+        let object = VariantObject::new(metadata.clone(), &value);
+        assert!(object.clone().with_full_validation().is_err());
+
+        // Lookups of other fields only validate the traversed path
+        let a = object.try_get("a").unwrap().unwrap();
+        assert_eq!(a, Variant::Int8(1));
+        assert!(a.is_fully_validated());
+        assert_eq!(object.try_get("c").unwrap(), Some(Variant::Int8(3)));
+        assert_eq!(object.try_get("d").unwrap(), None);
+        assert!(object.try_get("b").is_err());
+    }
+
+    #[test]
+    fn test_variant_object_try_get_invalid_field_id() {
+        let mut builder = VariantBuilder::new();
+        builder.append_object([("a", 1i8), ("b", 2i8), ("c", 3i8)]);
+        let (_, value) = builder.finish();
+
+        // A dictionary that does not contain the ids of "b" and "c"
+        let mut builder = VariantBuilder::new();
+        builder.append_object([("a", 1i8)]);
+        let (metadata_bytes, _) = builder.finish();
+
+        let metadata = VariantMetadata::new(&metadata_bytes);
+        let object = VariantObject::new(metadata, &value);
+        let err = object.try_get("c").unwrap_err();
+        assert!(matches!(err, ArrowError::InvalidArgumentError(_)), "{err}");
+    }
+
+    #[test]
+    fn test_iter_validated() {
+        // Fields are inserted out of order, so the field values are not in field id order
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("c", "three");
+        obj.insert("a", 1);
+        let mut list = obj.new_list("b");
+        list.append_value(2);
+        list.finish();
+        obj.finish().unwrap();
+        let (metadata, value) = builder.finish();
+
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let obj = variant.as_object().unwrap();
+        let fields = obj.iter_validated().unwrap().collect::<Vec<_>>();
+        assert_eq!(fields, obj.iter().collect::<Vec<_>>());
+        assert_eq!(
+            fields.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+        assert_eq!(fields[2].1, Variant::from("three"));
+
+        // Objects that are not fully validated are not iterated
+        let variant = Variant::new(&metadata, &value);
+        assert!(variant.as_object().unwrap().iter_validated().is_none());
+
+        let mut builder = VariantBuilder::new();
+        builder.new_object().finish().unwrap();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let fields = variant.as_object().unwrap().iter_validated().unwrap();
+        assert_eq!(fields.count(), 0);
+    }
 }
@@ -0,0 +1,141 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow_schema::ArrowError;
+
+/// Limits enforced when decoding a [`Variant`] with [`Variant::try_new_with_options`]
+///
+/// Decoding untrusted bytes with [`Variant::try_new`] fully validates them, which recurses
+/// once per level of nested objects and lists, and takes time proportional to the size of the
+/// buffers. These options bound that work, and are checked before any recursion into a value
+/// that exceeds them. All limits are unbounded by default.
+///
+/// # Example
+/// ```
+/// # use parquet_variant::{Variant, VariantBuilder, VariantDecodeOptions};
+/// let mut builder = VariantBuilder::new();
+/// let mut outer = builder.new_list();
+/// let mut inner = outer.new_list();
+/// inner.append_value(1);
+/// inner.finish();
+/// outer.finish();
+/// let (metadata, value) = builder.finish();
+///
+/// // [[1]] has a depth of 2
+/// let options = VariantDecodeOptions::new().with_max_depth(2);
+/// assert!(Variant::try_new_with_options(&metadata, &value, &options).is_ok());
+///
+/// let options = VariantDecodeOptions::new().with_max_depth(1);
+/// let err = Variant::try_new_with_options(&metadata, &value, &options).unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "Invalid argument error: Variant nesting depth exceeds the maximum of 1"
+/// );
+/// ```
+///
+/// [`Variant`]: crate::Variant
+/// [`Variant::try_new`]: crate::Variant::try_new
+/// [`Variant::try_new_with_options`]: crate::Variant::try_new_with_options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantDecodeOptions {
+    max_depth: usize,
+    max_value_bytes: usize,
+    max_dictionary_entries: usize,
+    strict_validation: bool,
+}
+
+impl Default for VariantDecodeOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            max_value_bytes: usize::MAX,
+            max_dictionary_entries: usize::MAX,
+            strict_validation: false,
+        }
+    }
+}
+
+impl VariantDecodeOptions {
+    /// Create new options without any limits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum nesting depth of objects and lists
+    ///
+    /// A primitive value has a depth of 0, and an object or list has a depth of one more than
+    /// the maximum depth of its fields or elements.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum size of the value buffer in bytes
+    pub fn with_max_value_bytes(mut self, max_value_bytes: usize) -> Self {
+        self.max_value_bytes = max_value_bytes;
+        self
+    }
+
+    /// Sets the maximum number of entries in the metadata dictionary
+    pub fn with_max_dictionary_entries(mut self, max_dictionary_entries: usize) -> Self {
+        self.max_dictionary_entries = max_dictionary_entries;
+        self
+    }
+
+    /// Enables strict validation, see [`Variant::validate_strict`]
+    ///
+    /// In addition to the checks of full validation, strict validation requires the offsets of
+    /// lists and the metadata dictionary to start at 0, the field names of objects to be unique,
+    /// and rejects unknown primitive types. The error of the first violation reports its byte
+    /// offset and the path of the value containing it.
+    ///
+    /// [`Variant::validate_strict`]: crate::Variant::validate_strict
+    pub fn with_strict_validation(mut self, strict_validation: bool) -> Self {
+        self.strict_validation = strict_validation;
+        self
+    }
+
+    /// Returns the maximum nesting depth of objects and lists
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns the maximum size of the value buffer in bytes
+    pub fn max_value_bytes(&self) -> usize {
+        self.max_value_bytes
+    }
+
+    /// Returns the maximum number of entries in the metadata dictionary
+    pub fn max_dictionary_entries(&self) -> usize {
+        self.max_dictionary_entries
+    }
+
+    /// Returns true if strict validation is enabled
+    pub fn strict_validation(&self) -> bool {
+        self.strict_validation
+    }
+}
+
+/// Returns an error if an object or list at nesting `depth` exceeds `max_depth`
+pub(crate) fn check_depth(depth: usize, max_depth: usize) -> Result<(), ArrowError> {
+    if depth > max_depth {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Variant nesting depth exceeds the maximum of {max_depth}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,723 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Strict validation of variant buffers, reporting the position of the first violation
+
+use crate::decoder::VariantPrimitiveType;
+use crate::path::{VariantPath, VariantPathElement};
+use crate::variant::{Variant, VariantDecodeOptions, VariantMetadata};
+use arrow_schema::ArrowError;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// The buffer of a variant containing a [`VariantValidationError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantBuffer {
+    /// The metadata buffer
+    Metadata,
+    /// The value buffer
+    Value,
+}
+
+impl Display for VariantBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Metadata => write!(f, "metadata"),
+            Self::Value => write!(f, "value"),
+        }
+    }
+}
+
+/// The first violation of the variant encoding found by [`Variant::validate_strict`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantValidationError {
+    buffer: VariantBuffer,
+    offset: usize,
+    path: VariantPath<'static>,
+    message: String,
+}
+
+impl VariantValidationError {
+    /// Returns the buffer containing the violation
+    pub fn buffer(&self) -> VariantBuffer {
+        self.buffer
+    }
+
+    /// Returns the offset in bytes of the violation from the start of its buffer
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the path of the value containing the violation, which is empty for violations in
+    /// the metadata or in the top level value
+    pub fn path(&self) -> &VariantPath<'static> {
+        &self.path
+    }
+
+    /// Returns a description of the violation
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for VariantValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at byte {} of the variant {}",
+            self.message, self.offset, self.buffer
+        )?;
+        if self.buffer == VariantBuffer::Value {
+            write!(f, ", at path {}", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for VariantValidationError {}
+
+impl From<VariantValidationError> for ArrowError {
+    fn from(e: VariantValidationError) -> Self {
+        ArrowError::InvalidArgumentError(e.to_string())
+    }
+}
+
+/// Returns the message of an error returned by the decoder, without the kind of error
+fn error_message(e: ArrowError) -> String {
+    match e {
+        ArrowError::InvalidArgumentError(msg) => msg,
+        e => e.to_string(),
+    }
+}
+
+/// Checks the metadata buffer, returning its dictionary
+fn validate_metadata<'m>(
+    metadata: &'m [u8],
+    options: &VariantDecodeOptions,
+) -> Result<Vec<&'m str>, VariantValidationError> {
+    let err = |offset: usize, message: String| VariantValidationError {
+        buffer: VariantBuffer::Metadata,
+        offset,
+        path: VariantPath::default(),
+        message,
+    };
+    let read = |offset: usize, size: usize, what: &str| match offset
+        .checked_add(size)
+        .and_then(|end| metadata.get(offset..end))
+    {
+        Some(bytes) => Ok(le_uint(bytes)),
+        None => Err(err(
+            offset,
+            format!("{what} extends beyond the end of the metadata"),
+        )),
+    };
+
+    let Some(&header) = metadata.first() else {
+        return Err(err(0, "empty metadata".to_string()));
+    };
+    let version = header & 0x0F;
+    if version != 1 {
+        return Err(err(0, format!("unsupported metadata version {version}")));
+    }
+    let is_sorted = header & 0x10 != 0;
+    let offset_size = (header >> 6) as usize + 1;
+
+    let dictionary_size = read(1, offset_size, "dictionary size")?;
+    if dictionary_size > options.max_dictionary_entries() {
+        return Err(err(
+            1,
+            format!(
+                "dictionary of {dictionary_size} entries exceeds the maximum of {} entries",
+                options.max_dictionary_entries()
+            ),
+        ));
+    }
+
+    let offsets_start = 1 + offset_size;
+    let strings_start = dictionary_size
+        .checked_add(1)
+        .and_then(|n| n.checked_mul(offset_size))
+        .and_then(|len| len.checked_add(offsets_start))
+        .filter(|&end| end <= metadata.len())
+        .ok_or_else(|| {
+            err(
+                offsets_start,
+                "dictionary offsets extend beyond the end of the metadata".to_string(),
+            )
+        })?;
+
+    let mut names = Vec::with_capacity(dictionary_size);
+    let mut start = 0;
+    for i in 0..=dictionary_size {
+        let position = offsets_start + i * offset_size;
+        let end = read(position, offset_size, "dictionary offset")?;
+        if i == 0 {
+            if end != 0 {
+                return Err(err(
+                    position,
+                    format!("first dictionary offset is {end}, expected 0"),
+                ));
+            }
+            continue;
+        }
+        if end < start {
+            return Err(err(
+                position,
+                format!(
+                    "dictionary offsets are not monotonically increasing: {end} follows {start}"
+                ),
+            ));
+        }
+        let bytes = strings_start
+            .checked_add(end)
+            .and_then(|string_end| metadata.get(strings_start + start..string_end))
+            .ok_or_else(|| {
+                err(
+                    position,
+                    format!("dictionary offset {end} is beyond the end of the metadata"),
+                )
+            })?;
+        let name = std::str::from_utf8(bytes).map_err(|e| {
+            err(
+                strings_start + start + e.valid_up_to(),
+                format!("invalid UTF-8 in dictionary string {}", i - 1),
+            )
+        })?;
+        if is_sorted {
+            if let Some(&prev) = names.last() {
+                if name <= prev {
+                    return Err(err(
+                        strings_start + start,
+                        format!("dictionary is marked sorted, but {name:?} follows {prev:?}"),
+                    ));
+                }
+            }
+        }
+        names.push(name);
+        start = end;
+    }
+    Ok(names)
+}
+
+/// Decodes an unsigned little-endian integer of up to 8 bytes
+fn le_uint(bytes: &[u8]) -> usize {
+    let mut buf = [0u8; 8];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    u64::from_le_bytes(buf) as usize
+}
+
+/// A value pending validation, spanning `start..end` of the value buffer
+struct PendingValue<'m> {
+    start: usize,
+    end: usize,
+    /// The number of objects and lists containing the value
+    depth: usize,
+    /// The last element of the path of the value, `None` for the top level value
+    element: Option<VariantPathElement<'m>>,
+}
+
+/// Checks the value buffer, given the dictionary of the (validated) metadata
+struct ValueValidator<'m, 'v> {
+    metadata: VariantMetadata<'m>,
+    names: Vec<&'m str>,
+    value: &'v [u8],
+    max_depth: usize,
+    path: Vec<VariantPathElement<'m>>,
+}
+
+impl<'m> ValueValidator<'m, '_> {
+    fn err(&self, offset: usize, message: String) -> VariantValidationError {
+        let path = self
+            .path
+            .iter()
+            .map(|element| match element {
+                VariantPathElement::Field { name } => {
+                    VariantPathElement::field(Cow::Owned(name.to_string()))
+                }
+                VariantPathElement::Index { index } => VariantPathElement::index(*index),
+            })
+            .collect::<Vec<_>>();
+        VariantValidationError {
+            buffer: VariantBuffer::Value,
+            offset,
+            path: VariantPath::new(path),
+            message,
+        }
+    }
+
+    /// Reads an unsigned integer of `size` bytes at `offset`, which must be before `end`
+    fn read(
+        &self,
+        offset: usize,
+        size: usize,
+        end: usize,
+        what: &str,
+    ) -> Result<usize, VariantValidationError> {
+        match offset.checked_add(size).filter(|&e| e <= end) {
+            Some(e) => Ok(le_uint(&self.value[offset..e])),
+            None => Err(self.err(
+                offset,
+                format!("{what} extends beyond the end of the value"),
+            )),
+        }
+    }
+
+    /// Returns `base + len * size`, or an error at `offset` if it is after `end`
+    fn checked_end(
+        &self,
+        base: usize,
+        len: usize,
+        size: usize,
+        end: usize,
+        offset: usize,
+        what: &str,
+    ) -> Result<usize, VariantValidationError> {
+        len.checked_mul(size)
+            .and_then(|n| n.checked_add(base))
+            .filter(|&e| e <= end)
+            .ok_or_else(|| self.err(offset, format!("{what} extend beyond the end of the value")))
+    }
+
+    /// Validates all values iteratively, so that deeply nested values can not overflow the stack
+    fn validate(&mut self) -> Result<(), VariantValidationError> {
+        let mut pending = vec![PendingValue {
+            start: 0,
+            end: self.value.len(),
+            depth: 0,
+            element: None,
+        }];
+        while let Some(value) = pending.pop() {
+            // Values are validated depth first, so the path of the parent is a prefix of the path
+            self.path.truncate(value.depth.saturating_sub(1));
+            self.path.extend(value.element);
+            self.validate_value(value.start, value.end, value.depth, &mut pending)?;
+        }
+        Ok(())
+    }
+
+    /// Validates the value at `start..end`, pushing its children (if any) to `pending`
+    fn validate_value(
+        &self,
+        start: usize,
+        end: usize,
+        depth: usize,
+        pending: &mut Vec<PendingValue<'m>>,
+    ) -> Result<(), VariantValidationError> {
+        if start >= end {
+            return Err(self.err(start, "empty value".to_string()));
+        }
+        let header = self.value[start];
+        let value_header = header >> 2;
+        match header & 0x03 {
+            0 => {
+                let type_id = value_header;
+                let size = VariantPrimitiveType::try_from(type_id)
+                    .map_err(|e| self.err(start, error_message(e)))?
+                    .fixed_size();
+                let (data_start, len) = match size {
+                    Some(size) => (start + 1, size),
+                    None => (start + 5, self.read(start + 1, 4, end, "length")?),
+                };
+                let data_end = self.checked_end(data_start, len, 1, end, start, "bytes")?;
+                if type_id == VariantPrimitiveType::String as u8 {
+                    self.validate_utf8(data_start, data_end)?;
+                }
+                // Check the decoded value itself, e.g. the scale of decimals
+                Variant::try_new_with_metadata_and_shallow_validation(
+                    self.metadata.clone(),
+                    &self.value[start..data_end],
+                )
+                .map_err(|e| self.err(start, error_message(e)))?;
+            }
+            1 => {
+                let len = value_header as usize;
+                let data_end = self.checked_end(start + 1, len, 1, end, start, "bytes")?;
+                self.validate_utf8(start + 1, data_end)?;
+            }
+            2 => {
+                self.check_depth(start, depth)?;
+                let offset_size = (value_header & 0x03) as usize + 1;
+                let field_id_size = ((value_header >> 2) & 0x03) as usize + 1;
+                let num_elements_size = if value_header & 0x10 != 0 { 4 } else { 1 };
+                let num_elements = self.read(start + 1, num_elements_size, end, "length")?;
+
+                let ids_start = start + 1 + num_elements_size;
+                let offsets_start = self.checked_end(
+                    ids_start,
+                    num_elements,
+                    field_id_size,
+                    end,
+                    ids_start,
+                    "field ids",
+                )?;
+                let values_start = self.checked_end(
+                    offsets_start,
+                    num_elements + 1,
+                    offset_size,
+                    end,
+                    offsets_start,
+                    "field offsets",
+                )?;
+                let last_offset_position = offsets_start + num_elements * offset_size;
+                let values_len = self.read(last_offset_position, offset_size, end, "offset")?;
+                let values_end = values_start
+                    .checked_add(values_len)
+                    .filter(|&e| e <= end)
+                    .ok_or_else(|| {
+                        self.err(
+                            last_offset_position,
+                            format!("field values of {values_len} bytes extend beyond the end of the value"),
+                        )
+                    })?;
+
+                let mut prev_name: Option<&str> = None;
+                let mut fields = Vec::with_capacity(num_elements);
+                for i in 0..num_elements {
+                    let id_position = ids_start + i * field_id_size;
+                    let field_id = self.read(id_position, field_id_size, end, "field id")?;
+                    let Some(&name) = self.names.get(field_id) else {
+                        return Err(self.err(
+                            id_position,
+                            format!(
+                                "field id {field_id} is out of range for a dictionary of {} entries",
+                                self.names.len()
+                            ),
+                        ));
+                    };
+                    if let Some(prev) = prev_name.filter(|&prev| name <= prev) {
+                        return Err(self.err(
+                            id_position,
+                            format!(
+                                "field names are not unique and sorted: {name:?} follows {prev:?}"
+                            ),
+                        ));
+                    }
+                    prev_name = Some(name);
+
+                    // The values of the fields may be in any order, so the offsets are only
+                    // required to be within the field values
+                    let offset_position = offsets_start + i * offset_size;
+                    let offset = self.read(offset_position, offset_size, end, "offset")?;
+                    if offset >= values_len {
+                        return Err(self.err(
+                            offset_position,
+                            format!("field offset {offset} is beyond the end of the field values ({values_len} bytes)"),
+                        ));
+                    }
+                    fields.push(PendingValue {
+                        start: values_start + offset,
+                        end: values_end,
+                        depth: depth + 1,
+                        element: Some(VariantPathElement::field(Cow::Borrowed(name))),
+                    });
+                }
+                pending.extend(fields.into_iter().rev());
+            }
+            _ => {
+                self.check_depth(start, depth)?;
+                let offset_size = (value_header & 0x03) as usize + 1;
+                let num_elements_size = if value_header & 0x04 != 0 { 4 } else { 1 };
+                let num_elements = self.read(start + 1, num_elements_size, end, "length")?;
+
+                let offsets_start = start + 1 + num_elements_size;
+                let values_start = self.checked_end(
+                    offsets_start,
+                    num_elements + 1,
+                    offset_size,
+                    end,
+                    offsets_start,
+                    "offsets",
+                )?;
+
+                let mut elements = Vec::with_capacity(num_elements);
+                let mut prev = 0;
+                for i in 0..=num_elements {
+                    let position = offsets_start + i * offset_size;
+                    let offset = self.read(position, offset_size, end, "offset")?;
+                    if i == 0 {
+                        if offset != 0 {
+                            return Err(
+                                self.err(position, format!("first offset is {offset}, expected 0"))
+                            );
+                        }
+                    } else if offset < prev {
+                        return Err(self.err(
+                            position,
+                            format!(
+                                "offsets are not monotonically increasing: {offset} follows {prev}"
+                            ),
+                        ));
+                    } else {
+                        elements.push(PendingValue {
+                            start: values_start + prev,
+                            end: self.checked_end(
+                                values_start,
+                                offset,
+                                1,
+                                end,
+                                position,
+                                "elements",
+                            )?,
+                            depth: depth + 1,
+                            element: Some(VariantPathElement::index(i - 1)),
+                        });
+                    }
+                    prev = offset;
+                }
+                pending.extend(elements.into_iter().rev());
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_utf8(&self, start: usize, end: usize) -> Result<(), VariantValidationError> {
+        std::str::from_utf8(&self.value[start..end])
+            .map(|_| ())
+            .map_err(|e| {
+                self.err(
+                    start + e.valid_up_to(),
+                    "invalid UTF-8 in string".to_string(),
+                )
+            })
+    }
+
+    /// Checks the nesting depth of an object or list contained in `depth` objects or lists
+    fn check_depth(&self, start: usize, depth: usize) -> Result<(), VariantValidationError> {
+        if depth >= self.max_depth {
+            return Err(self.err(
+                start,
+                format!("nesting depth exceeds the maximum of {}", self.max_depth),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Performs a strict validation of a metadata and value buffer pair, see
+/// [`Variant::validate_strict`]
+pub(crate) fn validate_strict(
+    metadata: &[u8],
+    value: &[u8],
+    options: &VariantDecodeOptions,
+) -> Result<(), VariantValidationError> {
+    let names = validate_metadata(metadata, options)?;
+    // The metadata was checked above, so this only fails if the checks are incomplete
+    let metadata = VariantMetadata::try_new(metadata).map_err(|e| VariantValidationError {
+        buffer: VariantBuffer::Metadata,
+        offset: 0,
+        path: VariantPath::default(),
+        message: error_message(e),
+    })?;
+
+    let mut validator = ValueValidator {
+        metadata,
+        names,
+        value,
+        max_depth: options.max_depth(),
+        path: Vec::new(),
+    };
+    if value.len() > options.max_value_bytes() {
+        return Err(validator.err(
+            0,
+            format!(
+                "value of {} bytes exceeds the maximum of {} bytes",
+                value.len(),
+                options.max_value_bytes()
+            ),
+        ));
+    }
+    validator.validate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Dictionary ["a", "b"]
+    const METADATA: [u8; 7] = [0x01, 2, 0, 1, 2, b'a', b'b'];
+
+    // {"a": [1, "x"]}, where the list starts at byte 5
+    const VALUE: [u8; 14] = [
+        0x02, 1, 0, 0, 9, // object header, 1 field with id 0, offsets [0, 9]
+        0x03, 2, 0, 2, 4, // list header, 2 elements, offsets [0, 2, 4]
+        0x0C, 1, // int8 1
+        0x05, b'x', // short string "x"
+    ];
+
+    fn validate(metadata: &[u8], value: &[u8]) -> Result<(), VariantValidationError> {
+        Variant::validate_strict(metadata, value, &VariantDecodeOptions::new())
+    }
+
+    #[test]
+    fn test_validate_strict_valid() {
+        validate(&METADATA, &VALUE).unwrap();
+
+        let options = VariantDecodeOptions::new().with_strict_validation(true);
+        let variant = Variant::try_new_with_options(&METADATA, &VALUE, &options).unwrap();
+        let list = variant.as_object().unwrap().get("a").unwrap();
+        assert_eq!(list.as_list().unwrap().get(1).unwrap(), Variant::from("x"));
+    }
+
+    #[test]
+    fn test_validate_strict_value_errors() {
+        let corrupt = |position: usize, byte: u8| {
+            let mut value = VALUE;
+            value[position] = byte;
+            validate(&METADATA, &value).unwrap_err()
+        };
+
+        let err = corrupt(2, 5);
+        assert_eq!(err.buffer(), VariantBuffer::Value);
+        assert_eq!(err.offset(), 2);
+        assert_eq!(err.path().to_string(), "$");
+        assert_eq!(
+            err.message(),
+            "field id 5 is out of range for a dictionary of 2 entries"
+        );
+
+        let err = corrupt(9, 1);
+        assert_eq!(
+            err.to_string(),
+            "offsets are not monotonically increasing: 1 follows 2 at byte 9 of the variant value, at path $.a"
+        );
+
+        let err = corrupt(8, 5);
+        assert_eq!(err.offset(), 8);
+        assert_eq!(err.message(), "elements extend beyond the end of the value");
+
+        let err = corrupt(7, 1);
+        assert_eq!(err.message(), "first offset is 1, expected 0");
+        assert_eq!(err.offset(), 7);
+
+        let err = corrupt(13, 0xFF);
+        assert_eq!(err.message(), "invalid UTF-8 in string");
+        assert_eq!(err.offset(), 13);
+        assert_eq!(err.path().to_string(), "$.a[1]");
+
+        let err = corrupt(10, 21 << 2);
+        assert_eq!(err.message(), "unknown primitive type: 21");
+        assert_eq!(err.offset(), 10);
+        assert_eq!(err.path().to_string(), "$.a[0]");
+
+        let err = corrupt(4, 15);
+        assert_eq!(err.offset(), 4);
+        assert_eq!(
+            err.message(),
+            "field values of 15 bytes extend beyond the end of the value"
+        );
+
+        let err = validate(&METADATA, &VALUE[..12]).unwrap_err();
+        assert_eq!(err.offset(), 4);
+
+        // Field names must be unique, which is not checked by full validation
+        let duplicate = [0x02, 2, 0, 0, 0, 1, 2, 0x00, 0x00];
+        assert!(Variant::try_new(&METADATA, &duplicate).is_ok());
+        let err = validate(&METADATA, &duplicate).unwrap_err();
+        assert_eq!(err.offset(), 3);
+        assert_eq!(
+            err.message(),
+            "field names are not unique and sorted: \"a\" follows \"a\""
+        );
+    }
+
+    #[test]
+    fn test_validate_strict_metadata_errors() {
+        let corrupt = |position: usize, byte: u8| {
+            let mut metadata = METADATA;
+            metadata[position] = byte;
+            validate(&metadata, &VALUE).unwrap_err()
+        };
+
+        let err = corrupt(6, 0xFF);
+        assert_eq!(
+            err.to_string(),
+            "invalid UTF-8 in dictionary string 1 at byte 6 of the variant metadata"
+        );
+        assert!(err.path().is_empty());
+
+        let err = corrupt(0, 0x02);
+        assert_eq!(err.message(), "unsupported metadata version 2");
+
+        let err = corrupt(4, 0);
+        assert_eq!(err.offset(), 4);
+        assert_eq!(
+            err.message(),
+            "dictionary offsets are not monotonically increasing: 0 follows 1"
+        );
+
+        let err = corrupt(4, 3);
+        assert_eq!(
+            err.message(),
+            "dictionary offset 3 is beyond the end of the metadata"
+        );
+
+        // Marked sorted, but the names are not unique
+        let metadata = [0x11, 2, 0, 1, 2, b'a', b'a'];
+        let err = validate(&metadata, &[0x00]).unwrap_err();
+        assert_eq!(err.offset(), 6);
+        assert_eq!(
+            err.message(),
+            "dictionary is marked sorted, but \"a\" follows \"a\""
+        );
+    }
+
+    #[test]
+    fn test_validate_strict_options() {
+        let options = VariantDecodeOptions::new().with_max_depth(1);
+        let err = Variant::validate_strict(&METADATA, &VALUE, &options).unwrap_err();
+        assert_eq!(err.offset(), 5);
+        assert_eq!(err.message(), "nesting depth exceeds the maximum of 1");
+
+        let options = VariantDecodeOptions::new().with_max_dictionary_entries(1);
+        let err = Variant::validate_strict(&METADATA, &VALUE, &options).unwrap_err();
+        assert_eq!(err.buffer(), VariantBuffer::Metadata);
+        assert_eq!(err.offset(), 1);
+
+        let options = VariantDecodeOptions::new().with_max_value_bytes(13);
+        let err = Variant::validate_strict(&METADATA, &VALUE, &options).unwrap_err();
+        assert_eq!(err.offset(), 0);
+
+        let mut value = VALUE;
+        value[2] = 5;
+        let options = VariantDecodeOptions::new().with_strict_validation(true);
+        let err = Variant::try_new_with_options(&METADATA, &value, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: field id 5 is out of range for a dictionary of 2 entries at byte 2 of the variant value, at path $"
+        );
+    }
+
+    #[test]
+    fn test_validate_strict_deeply_nested() {
+        // Lists nested 100,000 deep, with 4 byte offsets, around a null
+        let depth = 100_000;
+        let mut value = Vec::with_capacity(depth * 10 + 1);
+        for level in 0..depth {
+            let len = ((depth - level - 1) * 10 + 1) as u32;
+            value.extend_from_slice(&[0x0F, 1, 0, 0, 0, 0]);
+            value.extend_from_slice(&len.to_le_bytes());
+        }
+        value.push(0x00);
+
+        validate(&METADATA, &value).unwrap();
+
+        let options = VariantDecodeOptions::new().with_max_depth(depth - 1);
+        let err = Variant::validate_strict(&METADATA, &value, &options).unwrap_err();
+        assert_eq!(err.offset(), (depth - 1) * 10);
+        assert_eq!(err.path().len(), depth - 1);
+    }
+}
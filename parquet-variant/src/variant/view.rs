@@ -0,0 +1,459 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Borrowed map and sequence views of [`VariantObject`] and [`VariantList`]
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Debug, Formatter};
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::utils::try_binary_search_range_by;
+use crate::{Variant, VariantList, VariantObject};
+
+/// A read-only view of a [`VariantObject`] with the interface of a sorted map, such as
+/// [`BTreeMap`], see [`VariantObject::as_map_view`]
+///
+/// The view borrows the object, and field names and values are decoded on access, so
+/// creating and using it does not copy the object. As the fields of an object are sorted by
+/// name, iteration visits the fields in the same order as a [`BTreeMap`] would.
+///
+/// # Panics
+///
+/// The methods of the view panic if the object is [invalid], like [`VariantObject::iter`]
+///
+/// [invalid]: VariantObject#Validation
+#[derive(Clone, Copy)]
+pub struct VariantMapView<'a, 'm, 'v> {
+    object: &'a VariantObject<'m, 'v>,
+}
+
+impl<'a, 'm, 'v> VariantMapView<'a, 'm, 'v> {
+    /// Returns the number of fields in the object
+    pub fn len(&self) -> usize {
+        self.object.len()
+    }
+
+    /// Returns true if the object contains no fields
+    pub fn is_empty(&self) -> bool {
+        self.object.is_empty()
+    }
+
+    /// Returns the value of the field named `key`, if any
+    pub fn get(&self, key: &str) -> Option<Variant<'m, 'v>> {
+        self.object.get(key)
+    }
+
+    /// Returns the name and value of the field named `key`, if any
+    ///
+    /// The returned name borrows from the metadata rather than `key`.
+    pub fn get_key_value(&self, key: &str) -> Option<(&'m str, Variant<'m, 'v>)> {
+        let i =
+            try_binary_search_range_by(0..self.len(), &key, |i| self.object.field_name(i))?.ok()?;
+        Some((self.object.field_name(i)?, self.object.field(i)?))
+    }
+
+    /// Returns true if the object has a field named `key`
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the first field, which has the smallest name
+    pub fn first_key_value(&self) -> Option<(&'m str, Variant<'m, 'v>)> {
+        self.iter().next()
+    }
+
+    /// Returns the last field, which has the largest name
+    pub fn last_key_value(&self) -> Option<(&'m str, Variant<'m, 'v>)> {
+        self.iter().next_back()
+    }
+
+    /// Returns an iterator over the names and values of the fields, sorted by name
+    pub fn iter(&self) -> VariantMapIter<'a, 'm, 'v> {
+        VariantMapIter {
+            object: self.object,
+            range: 0..self.object.len(),
+        }
+    }
+
+    /// Returns an iterator over the names of the fields, in sorted order
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &'m str> + ExactSizeIterator + 'a {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values of the fields, sorted by name
+    pub fn values(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = Variant<'m, 'v>> + ExactSizeIterator + 'a {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl Debug for VariantMapView<'_, '_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, 'm, 'v> IntoIterator for VariantMapView<'a, 'm, 'v> {
+    type Item = (&'m str, Variant<'m, 'v>);
+    type IntoIter = VariantMapIter<'a, 'm, 'v>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'm, 'v> IntoIterator for &VariantMapView<'a, 'm, 'v> {
+    type Item = (&'m str, Variant<'m, 'v>);
+    type IntoIter = VariantMapIter<'a, 'm, 'v>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Collects the fields into a [`BTreeMap`], borrowing the names and values from the object
+impl<'m, 'v> From<VariantMapView<'_, 'm, 'v>> for BTreeMap<&'m str, Variant<'m, 'v>> {
+    fn from(view: VariantMapView<'_, 'm, 'v>) -> Self {
+        view.iter().collect()
+    }
+}
+
+/// Collects the fields into a [`HashMap`], borrowing the names and values from the object
+impl<'m, 'v> From<VariantMapView<'_, 'm, 'v>> for HashMap<&'m str, Variant<'m, 'v>> {
+    fn from(view: VariantMapView<'_, 'm, 'v>) -> Self {
+        view.iter().collect()
+    }
+}
+
+/// An iterator over the fields of a [`VariantMapView`]
+#[derive(Debug, Clone)]
+pub struct VariantMapIter<'a, 'm, 'v> {
+    object: &'a VariantObject<'m, 'v>,
+    range: Range<usize>,
+}
+
+impl<'m, 'v> VariantMapIter<'_, 'm, 'v> {
+    fn field(&self, i: usize) -> (&'m str, Variant<'m, 'v>) {
+        let name = self
+            .object
+            .field_name(i)
+            .expect("Invalid variant object field name");
+        let value = self
+            .object
+            .field(i)
+            .expect("Invalid variant object field value");
+        (name, value)
+    }
+}
+
+impl<'m, 'v> Iterator for VariantMapIter<'_, 'm, 'v> {
+    type Item = (&'m str, Variant<'m, 'v>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.field(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|i| self.field(i))
+    }
+}
+
+impl DoubleEndedIterator for VariantMapIter<'_, '_, '_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.field(i))
+    }
+}
+
+impl ExactSizeIterator for VariantMapIter<'_, '_, '_> {}
+
+impl FusedIterator for VariantMapIter<'_, '_, '_> {}
+
+/// A read-only view of a [`VariantList`] with the interface of a slice, see
+/// [`VariantList::as_list_view`]
+///
+/// The view borrows the list, and elements are decoded on access, so creating and using it
+/// does not copy the list.
+///
+/// # Panics
+///
+/// The methods of the view panic if the list is [invalid], like [`VariantList::iter`]
+///
+/// [invalid]: VariantList#Validation
+#[derive(Clone, Copy)]
+pub struct VariantListView<'a, 'm, 'v> {
+    list: &'a VariantList<'m, 'v>,
+}
+
+impl<'a, 'm, 'v> VariantListView<'a, 'm, 'v> {
+    /// Returns the number of elements in the list
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the list contains no elements
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds
+    pub fn get(&self, index: usize) -> Option<Variant<'m, 'v>> {
+        self.list.get(index)
+    }
+
+    /// Returns the first element, if any
+    pub fn first(&self) -> Option<Variant<'m, 'v>> {
+        self.get(0)
+    }
+
+    /// Returns the last element, if any
+    pub fn last(&self) -> Option<Variant<'m, 'v>> {
+        self.len().checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns an iterator over the elements
+    pub fn iter(&self) -> VariantListIter<'a, 'm, 'v> {
+        VariantListIter {
+            list: self.list,
+            range: 0..self.list.len(),
+        }
+    }
+}
+
+impl Debug for VariantListView<'_, '_, '_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, 'm, 'v> IntoIterator for VariantListView<'a, 'm, 'v> {
+    type Item = Variant<'m, 'v>;
+    type IntoIter = VariantListIter<'a, 'm, 'v>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, 'm, 'v> IntoIterator for &VariantListView<'a, 'm, 'v> {
+    type Item = Variant<'m, 'v>;
+    type IntoIter = VariantListIter<'a, 'm, 'v>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Collects the elements into a [`Vec`], borrowing the values from the list
+impl<'m, 'v> From<VariantListView<'_, 'm, 'v>> for Vec<Variant<'m, 'v>> {
+    fn from(view: VariantListView<'_, 'm, 'v>) -> Self {
+        view.iter().collect()
+    }
+}
+
+/// An iterator over the elements of a [`VariantListView`]
+#[derive(Debug, Clone)]
+pub struct VariantListIter<'a, 'm, 'v> {
+    list: &'a VariantList<'m, 'v>,
+    range: Range<usize>,
+}
+
+impl<'m, 'v> VariantListIter<'_, 'm, 'v> {
+    fn element(&self, i: usize) -> Variant<'m, 'v> {
+        self.list.get(i).expect("Invalid variant list element")
+    }
+}
+
+impl<'m, 'v> Iterator for VariantListIter<'_, 'm, 'v> {
+    type Item = Variant<'m, 'v>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.element(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.range.nth(n).map(|i| self.element(i))
+    }
+}
+
+impl DoubleEndedIterator for VariantListIter<'_, '_, '_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.element(i))
+    }
+}
+
+impl ExactSizeIterator for VariantListIter<'_, '_, '_> {}
+
+impl FusedIterator for VariantListIter<'_, '_, '_> {}
+
+impl<'m, 'v> VariantObject<'m, 'v> {
+    /// Returns a read-only view of this object with the interface of a sorted map
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let mut builder = VariantBuilder::new();
+    /// let mut obj = builder.new_object();
+    /// obj.insert("b", 2);
+    /// obj.insert("a", 1);
+    /// obj.finish().unwrap();
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value).unwrap();
+    /// let object = variant.as_object().unwrap();
+    ///
+    /// let map = object.as_map_view();
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get("b"), Some(Variant::from(2)));
+    /// assert!(!map.contains_key("c"));
+    /// // Fields are visited in sorted order, like a BTreeMap
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), ["a", "b"]);
+    ///
+    /// let map: BTreeMap<_, _> = map.into();
+    /// assert_eq!(map["a"], Variant::from(1));
+    /// ```
+    pub fn as_map_view(&self) -> VariantMapView<'_, 'm, 'v> {
+        VariantMapView { object: self }
+    }
+}
+
+impl<'m, 'v> VariantList<'m, 'v> {
+    /// Returns a read-only view of this list with the interface of a slice
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{Variant, VariantBuilder};
+    /// let mut builder = VariantBuilder::new();
+    /// let mut list = builder.new_list();
+    /// list.append_value(1);
+    /// list.append_value("two");
+    /// list.finish();
+    /// let (metadata, value) = builder.finish();
+    /// let variant = Variant::try_new(&metadata, &value).unwrap();
+    /// let list = variant.as_list().unwrap();
+    ///
+    /// let view = list.as_list_view();
+    /// assert_eq!(view.last(), Some(Variant::from("two")));
+    /// let values: Vec<_> = view.into_iter().rev().collect();
+    /// assert_eq!(values, [Variant::from("two"), Variant::from(1)]);
+    /// ```
+    pub fn as_list_view(&self) -> VariantListView<'_, 'm, 'v> {
+        VariantListView { list: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VariantBuilder;
+
+    #[test]
+    fn test_map_view() {
+        let mut builder = VariantBuilder::new();
+        let mut obj = builder.new_object();
+        obj.insert("zeta", 26);
+        obj.insert("alpha", "first");
+        let mut list = obj.new_list("mid");
+        list.append_value(true);
+        list.append_value(());
+        list.finish();
+        obj.finish().unwrap();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let object = variant.as_object().unwrap();
+
+        let view = object.as_map_view();
+        assert_eq!(view.len(), 3);
+        assert!(!view.is_empty());
+        assert_eq!(
+            view.get_key_value("zeta"),
+            Some(("zeta", Variant::from(26)))
+        );
+        assert_eq!(view.get_key_value("beta"), None);
+        assert_eq!(view.first_key_value().unwrap().0, "alpha");
+        assert_eq!(view.last_key_value().unwrap().0, "zeta");
+        assert_eq!(
+            view.keys().rev().collect::<Vec<_>>(),
+            ["zeta", "mid", "alpha"]
+        );
+        assert_eq!(view.values().len(), 3);
+        assert_eq!(view.iter().nth(2), Some(("zeta", Variant::from(26))));
+
+        let mut iter = view.iter();
+        assert_eq!(iter.next().unwrap().0, "alpha");
+        assert_eq!(iter.next_back().unwrap().0, "zeta");
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next().unwrap().0, "mid");
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let hash_map: HashMap<_, _> = view.into();
+        let btree_map: BTreeMap<_, _> = view.into();
+        assert_eq!(hash_map.len(), 3);
+        assert_eq!(
+            btree_map.into_iter().collect::<Vec<_>>(),
+            view.iter().collect::<Vec<_>>()
+        );
+
+        let mid = view.get("mid").unwrap();
+        let mid = mid.as_list().unwrap().as_list_view();
+        assert_eq!(
+            format!("{view:?}"),
+            format!(
+                "{{\"alpha\": {:?}, \"mid\": {:?}, \"zeta\": {:?}}}",
+                Variant::from("first"),
+                view.get("mid").unwrap(),
+                Variant::from(26)
+            )
+        );
+        assert_eq!(format!("{mid:?}"), "[BooleanTrue, Null]");
+        assert_eq!(Vec::from(mid), [Variant::BooleanTrue, Variant::Null]);
+    }
+
+    #[test]
+    fn test_empty_views() {
+        let mut builder = VariantBuilder::new();
+        let mut list = builder.new_list();
+        list.new_object().finish().unwrap();
+        list.finish();
+        let (metadata, value) = builder.finish();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        let list = variant.as_list().unwrap();
+        let element = list.get(0).unwrap();
+        let object = element.as_object().unwrap();
+
+        let view = object.as_map_view();
+        assert!(view.is_empty());
+        assert_eq!(view.first_key_value(), None);
+        assert_eq!(view.into_iter().next(), None);
+        assert_eq!(format!("{view:?}"), "{}");
+
+        let view = list.as_list_view();
+        assert_eq!(view.len(), 1);
+        assert_eq!(view.first(), view.last());
+        assert_eq!(view.get(1), None);
+    }
+}
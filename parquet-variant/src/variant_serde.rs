@@ -0,0 +1,1065 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversions between [`Variant`] and types implementing the [`serde`] traits
+//!
+//! Values are mapped in the same way as `serde_json`: structs and maps become objects,
+//! sequences and tuples become lists, and enums are externally tagged, i.e. a unit
+//! variant becomes a string and all other variants become a single-field object keyed
+//! by the variant name.
+
+use std::fmt::{Display, Formatter};
+
+use arrow_schema::ArrowError;
+use serde::de::value::StrDeserializer;
+use serde::de::{
+    DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess, VariantAccess, Visitor,
+};
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    ListBuilder, ObjectBuilder, Variant, VariantBuilder, VariantDecimal16, VariantList,
+    VariantObject,
+};
+
+impl VariantBuilder {
+    /// Append a value implementing [`Serialize`] to the builder.
+    ///
+    /// # Example
+    /// ```
+    /// # use parquet_variant::{from_variant, Variant, VariantBuilder};
+    /// # use serde::{Deserialize, Serialize};
+    /// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    /// struct Span {
+    ///     name: String,
+    ///     duration_ms: u32,
+    ///     tags: Vec<String>,
+    /// }
+    ///
+    /// let span = Span {
+    ///     name: "query".to_string(),
+    ///     duration_ms: 42,
+    ///     tags: vec!["db".to_string()],
+    /// };
+    ///
+    /// let mut builder = VariantBuilder::new();
+    /// builder.append_serialize(&span).unwrap();
+    /// let (metadata, value) = builder.finish();
+    ///
+    /// let variant = Variant::new(&metadata, &value);
+    /// assert_eq!(variant.get_object_field("name"), Some(Variant::from("query")));
+    ///
+    /// let decoded: Span = from_variant(&variant).unwrap();
+    /// assert_eq!(decoded, span);
+    /// ```
+    pub fn append_serialize<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), ArrowError> {
+        value
+            .serialize(VariantSerializer { target: self })
+            .map_err(|e| e.0)
+    }
+}
+
+/// Deserialize an instance of `T` from a [`Variant`]
+///
+/// Strings and binary values are borrowed from the variant's value buffer where `T` allows.
+/// Dates, times, timestamps and UUIDs are presented to `T` as strings, and decimals with a
+/// non-zero scale as `f64`.
+///
+/// See [`VariantBuilder::append_serialize`] for an example.
+pub fn from_variant<'v, T: Deserialize<'v>>(variant: &Variant<'_, 'v>) -> Result<T, ArrowError> {
+    T::deserialize(VariantDeserializer(variant.clone())).map_err(|e| e.0)
+}
+
+/// The error type used by the [`Serializer`] and [`Deserializer`] implementations,
+/// converted back to the wrapped [`ArrowError`] at the public API boundary
+#[derive(Debug)]
+struct SerdeError(ArrowError);
+
+impl Display for SerdeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::error::Error for SerdeError {}
+
+impl From<ArrowError> for SerdeError {
+    fn from(value: ArrowError) -> Self {
+        Self(value)
+    }
+}
+
+impl serde::ser::Error for SerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(ArrowError::InvalidArgumentError(msg.to_string()))
+    }
+}
+
+impl serde::de::Error for SerdeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(ArrowError::InvalidArgumentError(msg.to_string()))
+    }
+}
+
+type Result<T, E = SerdeError> = std::result::Result<T, E>;
+
+/// A standalone `(metadata, value)` pair as returned by [`VariantBuilder::finish`]
+type VariantBuffers = (Vec<u8>, Vec<u8>);
+
+/// A location that a single serialized value can be written to
+trait SerializeTarget {
+    fn try_append(&mut self, value: Variant<'_, '_>) -> Result<()>;
+
+    fn new_list(&mut self) -> ListBuilder<'_>;
+
+    fn new_object(&mut self) -> ObjectBuilder<'_>;
+}
+
+impl SerializeTarget for VariantBuilder {
+    fn try_append(&mut self, value: Variant<'_, '_>) -> Result<()> {
+        Ok(self.try_append_value(value)?)
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        self.new_list()
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        self.new_object()
+    }
+}
+
+impl SerializeTarget for ListBuilder<'_> {
+    fn try_append(&mut self, value: Variant<'_, '_>) -> Result<()> {
+        Ok(self.try_append_value(value)?)
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        self.new_list()
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        self.new_object()
+    }
+}
+
+/// The value of the field `key` of an object
+struct ObjectField<'o, 'a> {
+    object: &'o mut ObjectBuilder<'a>,
+    key: &'o str,
+}
+
+impl SerializeTarget for ObjectField<'_, '_> {
+    fn try_append(&mut self, value: Variant<'_, '_>) -> Result<()> {
+        Ok(self.object.try_insert(self.key, value)?)
+    }
+
+    fn new_list(&mut self) -> ListBuilder<'_> {
+        self.object.new_list(self.key)
+    }
+
+    fn new_object(&mut self) -> ObjectBuilder<'_> {
+        self.object.new_object(self.key)
+    }
+}
+
+/// Serialize `value` into a standalone `(metadata, value)` pair
+fn serialize_buffers<T: Serialize + ?Sized>(value: &T) -> Result<VariantBuffers> {
+    let mut builder = VariantBuilder::new();
+    value.serialize(VariantSerializer {
+        target: &mut builder,
+    })?;
+    Ok(builder.finish())
+}
+
+struct VariantSerializer<'t, T: SerializeTarget> {
+    target: &'t mut T,
+}
+
+impl<'t, T: SerializeTarget> VariantSerializer<'t, T> {
+    fn append(self, value: impl Into<Variant<'t, 't>>) -> Result<()> {
+        self.target.try_append(value.into())
+    }
+}
+
+impl<'t, T: SerializeTarget> Serializer for VariantSerializer<'t, T> {
+    type Ok = ();
+    type Error = SerdeError;
+    type SerializeSeq = ListSerializer<'t>;
+    type SerializeTuple = ListSerializer<'t>;
+    type SerializeTupleStruct = ListSerializer<'t>;
+    type SerializeTupleVariant = TupleVariantSerializer<'t>;
+    type SerializeMap = ObjectSerializer<'t>;
+    type SerializeStruct = ObjectSerializer<'t>;
+    type SerializeStructVariant = StructVariantSerializer<'t>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.append(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.append(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.append(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.append(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.append(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        match i64::try_from(v) {
+            Ok(v) => self.append(v),
+            Err(_) => self.append(VariantDecimal16::try_new(v, 0)?),
+        }
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.append(i16::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.append(i32::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.append(i64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.serialize_i128(i128::from(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        let v = i128::try_from(v).map_err(|_| {
+            ArrowError::InvalidArgumentError(format!("{v} is too large for a Variant"))
+        })?;
+        self.serialize_i128(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.append(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.append(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.append(v.encode_utf8(&mut [0; 4]) as &str)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.target.try_append(Variant::from(v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.target.try_append(Variant::Binary(v))
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.append(())
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.append(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.append(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.append(variant)
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        let mut object = self.target.new_object();
+        value.serialize(VariantSerializer {
+            target: &mut ObjectField {
+                object: &mut object,
+                key: variant,
+            },
+        })?;
+        Ok(object.finish()?)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Ok(ListSerializer {
+            list: self.target.new_list(),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Ok(TupleVariantSerializer {
+            object: self.target.new_object(),
+            variant,
+            elements: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Ok(ObjectSerializer {
+            object: self.target.new_object(),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Ok(StructVariantSerializer {
+            object: self.target.new_object(),
+            variant,
+            fields: Vec::with_capacity(len),
+        })
+    }
+}
+
+struct ListSerializer<'a> {
+    list: ListBuilder<'a>,
+}
+
+impl SerializeSeq for ListSerializer<'_> {
+    type Ok = ();
+    type Error = SerdeError;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(VariantSerializer {
+            target: &mut self.list,
+        })
+    }
+
+    fn end(self) -> Result<()> {
+        self.list.finish();
+        Ok(())
+    }
+}
+
+impl SerializeTuple for ListSerializer<'_> {
+    type Ok = ();
+    type Error = SerdeError;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for ListSerializer<'_> {
+    type Ok = ();
+    type Error = SerdeError;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        SerializeSeq::end(self)
+    }
+}
+
+struct ObjectSerializer<'a> {
+    object: ObjectBuilder<'a>,
+    key: Option<String>,
+}
+
+impl ObjectSerializer<'_> {
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, key: &str, value: &V) -> Result<()> {
+        value.serialize(VariantSerializer {
+            target: &mut ObjectField {
+                object: &mut self.object,
+                key,
+            },
+        })
+    }
+}
+
+impl SerializeMap for ObjectSerializer<'_> {
+    type Ok = ();
+    type Error = SerdeError;
+
+    fn serialize_key<K: Serialize + ?Sized>(&mut self, key: &K) -> Result<()> {
+        self.key = Some(key.serialize(KeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        let key = self.key.take().ok_or_else(|| {
+            ArrowError::InvalidArgumentError("serialize_value called before serialize_key".into())
+        })?;
+        self.serialize_field(&key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.object.finish()?)
+    }
+}
+
+impl SerializeStruct for ObjectSerializer<'_> {
+    type Ok = ();
+    type Error = SerdeError;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        ObjectSerializer::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(self.object.finish()?)
+    }
+}
+
+/// Serializes `Variant::Tuple(..)` as `{"Tuple": [..]}`
+///
+/// The elements are buffered as the list builder cannot borrow from the enclosing
+/// object builder while both are owned by this serializer
+struct TupleVariantSerializer<'a> {
+    object: ObjectBuilder<'a>,
+    variant: &'static str,
+    elements: Vec<VariantBuffers>,
+}
+
+impl SerializeTupleVariant for TupleVariantSerializer<'_> {
+    type Ok = ();
+    type Error = SerdeError;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.elements.push(serialize_buffers(value)?);
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<()> {
+        let mut list = self.object.new_list(self.variant);
+        for (metadata, value) in &self.elements {
+            list.try_append_value(Variant::try_new(metadata, value)?)?;
+        }
+        list.finish();
+        Ok(self.object.finish()?)
+    }
+}
+
+/// Serializes `Variant::Struct { .. }` as `{"Struct": {..}}`
+///
+/// See [`TupleVariantSerializer`] for why the fields are buffered
+struct StructVariantSerializer<'a> {
+    object: ObjectBuilder<'a>,
+    variant: &'static str,
+    fields: Vec<(&'static str, VariantBuffers)>,
+}
+
+impl SerializeStructVariant for StructVariantSerializer<'_> {
+    type Ok = ();
+    type Error = SerdeError;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        self.fields.push((key, serialize_buffers(value)?));
+        Ok(())
+    }
+
+    fn end(mut self) -> Result<()> {
+        let mut inner = self.object.new_object(self.variant);
+        for (key, (metadata, value)) in &self.fields {
+            inner.try_insert(key, Variant::try_new(metadata, value)?)?;
+        }
+        inner.finish()?;
+        Ok(self.object.finish()?)
+    }
+}
+
+/// Serializes map keys, which must be strings or characters
+struct KeySerializer;
+
+fn key_error() -> SerdeError {
+    SerdeError(ArrowError::InvalidArgumentError(
+        "Variant object keys must be strings".to_string(),
+    ))
+}
+
+impl Serializer for KeySerializer {
+    type Ok = String;
+    type Error = SerdeError;
+    type SerializeSeq = serde::ser::Impossible<String, SerdeError>;
+    type SerializeTuple = serde::ser::Impossible<String, SerdeError>;
+    type SerializeTupleStruct = serde::ser::Impossible<String, SerdeError>;
+    type SerializeTupleVariant = serde::ser::Impossible<String, SerdeError>;
+    type SerializeMap = serde::ser::Impossible<String, SerdeError>;
+    type SerializeStruct = serde::ser::Impossible<String, SerdeError>;
+    type SerializeStructVariant = serde::ser::Impossible<String, SerdeError>;
+
+    fn serialize_str(self, v: &str) -> Result<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_char(self, v: char) -> Result<String> {
+        Ok(v.to_string())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<String> {
+        Ok(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<String> {
+        value.serialize(self)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_none(self) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, _value: &V) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_unit(self) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &V,
+    ) -> Result<String> {
+        Err(key_error())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        Err(key_error())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+        Err(key_error())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct> {
+        Err(key_error())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(key_error())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(key_error())
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        Err(key_error())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(key_error())
+    }
+}
+
+struct VariantDeserializer<'m, 'v>(Variant<'m, 'v>);
+
+/// Formats a UUID as a lowercase hyphenated string
+fn format_uuid(bytes: &[u8; 16]) -> String {
+    let mut s = String::with_capacity(36);
+    for (i, b) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            s.push('-');
+        }
+        s.push_str(&format!("{b:02x}"));
+    }
+    s
+}
+
+/// Parses the string representation of a decimal as an `f64`
+fn decimal_to_f64(decimal: impl Display) -> Result<f64> {
+    let s = decimal.to_string();
+    s.parse().map_err(|_| {
+        SerdeError(ArrowError::CastError(format!(
+            "Could not convert decimal {s} to f64"
+        )))
+    })
+}
+
+impl<'de> Deserializer<'de> for VariantDeserializer<'_, 'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            Variant::Null => visitor.visit_unit(),
+            Variant::BooleanTrue => visitor.visit_bool(true),
+            Variant::BooleanFalse => visitor.visit_bool(false),
+            Variant::Int8(v) => visitor.visit_i8(v),
+            Variant::Int16(v) => visitor.visit_i16(v),
+            Variant::Int32(v) => visitor.visit_i32(v),
+            Variant::Int64(v) => visitor.visit_i64(v),
+            Variant::Float(v) => visitor.visit_f32(v),
+            Variant::Double(v) => visitor.visit_f64(v),
+            Variant::Decimal4(d) if d.scale() == 0 => visitor.visit_i32(d.integer()),
+            Variant::Decimal8(d) if d.scale() == 0 => visitor.visit_i64(d.integer()),
+            Variant::Decimal16(d) if d.scale() == 0 => match u64::try_from(d.integer()) {
+                // Large unsigned integers are serialized as Decimal16, see `serialize_i128`
+                Ok(v) => visitor.visit_u64(v),
+                Err(_) => visitor.visit_i128(d.integer()),
+            },
+            Variant::Decimal4(d) => visitor.visit_f64(decimal_to_f64(d)?),
+            Variant::Decimal8(d) => visitor.visit_f64(decimal_to_f64(d)?),
+            Variant::Decimal16(d) => visitor.visit_f64(decimal_to_f64(d)?),
+            Variant::Date(d) => visitor.visit_string(d.to_string()),
+            Variant::TimestampMicros(ts) | Variant::TimestampNanos(ts) => {
+                visitor.visit_string(ts.to_rfc3339())
+            }
+            Variant::TimestampNtzMicros(ts) | Variant::TimestampNtzNanos(ts) => {
+                visitor.visit_string(ts.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+            }
+            Variant::Time(t) => visitor.visit_string(t.to_string()),
+            Variant::Uuid(bytes) => visitor.visit_string(format_uuid(&bytes)),
+            Variant::Binary(b) => visitor.visit_borrowed_bytes(b),
+            Variant::String(s) => visitor.visit_borrowed_str(s),
+            Variant::ShortString(s) => visitor.visit_borrowed_str(s.into()),
+            Variant::List(list) => visitor.visit_seq(ListAccess { list, index: 0 }),
+            Variant::Object(object) => visitor.visit_map(ObjectAccess {
+                object,
+                index: 0,
+                value: None,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            Variant::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.0 {
+            Variant::String(s) => visitor.visit_enum(s.into_deserializer()),
+            Variant::ShortString(s) => {
+                let s: StrDeserializer<'_, SerdeError> = s.as_ref().into_deserializer();
+                visitor.visit_enum(s)
+            }
+            Variant::Object(object) if object.len() == 1 => {
+                let variant = object.field_name(0).ok_or_else(|| {
+                    ArrowError::InvalidArgumentError("Invalid variant object".to_string())
+                })?;
+                let value = object.try_field(0)?;
+                visitor.visit_enum(EnumDeserializer { variant, value })
+            }
+            other => Err(SerdeError(ArrowError::InvalidArgumentError(format!(
+                "Expected a string or single-field object for an enum, got {other:?}"
+            )))),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct ListAccess<'m, 'v> {
+    list: VariantList<'m, 'v>,
+    index: usize,
+}
+
+impl<'de> SeqAccess<'de> for ListAccess<'_, 'de> {
+    type Error = SerdeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.index == self.list.len() {
+            return Ok(None);
+        }
+        let element = self.list.try_get(self.index)?;
+        self.index += 1;
+        seed.deserialize(VariantDeserializer(element)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.list.len() - self.index)
+    }
+}
+
+struct ObjectAccess<'m, 'v> {
+    object: VariantObject<'m, 'v>,
+    index: usize,
+    value: Option<Variant<'m, 'v>>,
+}
+
+impl<'de> MapAccess<'de> for ObjectAccess<'_, 'de> {
+    type Error = SerdeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.index == self.object.len() {
+            return Ok(None);
+        }
+        let key = self.object.field_name(self.index).ok_or_else(|| {
+            ArrowError::InvalidArgumentError("Invalid variant object".to_string())
+        })?;
+        self.value = Some(self.object.try_field(self.index)?);
+        self.index += 1;
+        let key: StrDeserializer<'_, SerdeError> = key.into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = self.value.take().ok_or_else(|| {
+            ArrowError::InvalidArgumentError("next_value called before next_key".to_string())
+        })?;
+        seed.deserialize(VariantDeserializer(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.object.len() - self.index)
+    }
+}
+
+struct EnumDeserializer<'m, 'v> {
+    variant: &'m str,
+    value: Variant<'m, 'v>,
+}
+
+impl<'de, 'm> EnumAccess<'de> for EnumDeserializer<'m, 'de> {
+    type Error = SerdeError;
+    type Variant = VariantDeserializer<'m, 'de>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let variant: StrDeserializer<'_, SerdeError> = self.variant.into_deserializer();
+        let variant = seed.deserialize(variant)?;
+        Ok((variant, VariantDeserializer(self.value)))
+    }
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer<'_, 'de> {
+    type Error = SerdeError;
+
+    fn unit_variant(self) -> Result<()> {
+        Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Point(i32, i32),
+        Rect { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper(String);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record<'a> {
+        id: u64,
+        name: &'a str,
+        owned: Wrapper,
+        score: Option<f32>,
+        missing: Option<i8>,
+        flags: (bool, char),
+        shapes: Vec<Shape>,
+        attributes: BTreeMap<String, i64>,
+    }
+
+    fn roundtrip<T: Serialize>(value: &T) -> (Vec<u8>, Vec<u8>) {
+        let mut builder = VariantBuilder::new();
+        builder.append_serialize(value).unwrap();
+        builder.finish()
+    }
+
+    #[test]
+    fn test_serialize_primitives() {
+        let (m, v) = roundtrip(&42u8);
+        assert_eq!(Variant::new(&m, &v), Variant::Int16(42));
+
+        let (m, v) = roundtrip(&u64::MAX);
+        assert_eq!(
+            Variant::new(&m, &v),
+            Variant::from(VariantDecimal16::try_new(u64::MAX as i128, 0).unwrap())
+        );
+        assert_eq!(
+            from_variant::<u64>(&Variant::new(&m, &v)).unwrap(),
+            u64::MAX
+        );
+
+        let (m, v) = roundtrip(&"hello");
+        assert_eq!(Variant::new(&m, &v), Variant::from("hello"));
+
+        let (m, v) = roundtrip(&Option::<i32>::None);
+        assert_eq!(Variant::new(&m, &v), Variant::Null);
+
+        let (m, v) = roundtrip(&Shape::Empty);
+        assert_eq!(Variant::new(&m, &v), Variant::from("Empty"));
+    }
+
+    #[test]
+    fn test_roundtrip_struct() {
+        let record = Record {
+            id: 7,
+            name: "borrowed",
+            owned: Wrapper("owned".to_string()),
+            score: Some(1.5),
+            missing: None,
+            flags: (true, 'x'),
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(2.0),
+                Shape::Point(1, -1),
+                Shape::Rect {
+                    width: 3,
+                    height: 4,
+                },
+            ],
+            attributes: BTreeMap::from([("a".to_string(), 1), ("b".to_string(), -2)]),
+        };
+
+        let (metadata, value) = roundtrip(&record);
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+
+        let object = variant.as_object().unwrap();
+        assert_eq!(object.get("id"), Some(Variant::Int64(7)));
+        assert_eq!(object.get("missing"), Some(Variant::Null));
+        let shapes = object.get("shapes").unwrap();
+        let shapes = shapes.as_list().unwrap();
+        assert_eq!(
+            shapes.get(1).unwrap().get_object_field("Circle"),
+            Some(Variant::Double(2.0))
+        );
+
+        let decoded: Record = from_variant(&variant).unwrap();
+        assert_eq!(decoded, record);
+    }
+
+    #[test]
+    fn test_deserialize_borrowed_bytes() {
+        let variant = Variant::Binary(b"bytes");
+        let decoded: &[u8] = from_variant(&variant).unwrap();
+        assert_eq!(decoded, b"bytes");
+    }
+
+    #[test]
+    fn test_deserialize_temporal() {
+        let date = chrono::NaiveDate::from_ymd_opt(2025, 4, 16).unwrap();
+        let decoded: String = from_variant(&Variant::Date(date)).unwrap();
+        assert_eq!(decoded, "2025-04-16");
+
+        let decimal = crate::VariantDecimal4::try_new(1234, 2).unwrap();
+        let decoded: f64 = from_variant(&Variant::from(decimal)).unwrap();
+        assert_eq!(decoded, 12.34);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut builder = VariantBuilder::new();
+        let err = builder
+            .append_serialize(&BTreeMap::from([(1, 2)]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Variant object keys must be strings"
+        );
+
+        let err = from_variant::<i32>(&Variant::from("x")).unwrap_err();
+        assert!(err.to_string().contains("invalid type"), "{err}");
+
+        let err = from_variant::<Shape>(&Variant::Int8(1)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Expected a string or single-field object for an enum"),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Checks that [`VariantBuilder`] produces byte-exact output for canonical example variants,
+//! protecting against accidental changes to the encoding.
+//!
+//! The expected output of each case is stored as a pair of `<name>.metadata` and
+//! `<name>.value` files in `tests/golden`, using the same layout as the variant test data of
+//! apache/parquet-testing. After an intentional change to the encoding, or to add a new case,
+//! regenerate the files by running
+//!
+//! ```shell
+//! PARQUET_VARIANT_UPDATE_GOLDEN=1 cargo test -p parquet-variant --test variant_golden
+//! ```
+//!
+//! and review the resulting changes.
+
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+use chrono::{NaiveDate, NaiveTime};
+use parquet_variant::{
+    Variant, VariantBuilder, VariantDecimal16, VariantDecimal4, VariantDecimal8,
+    VariantDecodeOptions, VariantMetadata,
+};
+
+/// The metadata and value of an encoded variant
+type Encoded = (Vec<u8>, Vec<u8>);
+
+/// Environment variable that regenerates the golden files instead of checking them
+const UPDATE_ENV: &str = "PARQUET_VARIANT_UPDATE_GOLDEN";
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+/// The primitive values of the spec, one for each primitive type
+fn primitive_cases() -> Vec<(&'static str, Variant<'static, 'static>)> {
+    let date = NaiveDate::from_ymd_opt(2025, 4, 16).unwrap();
+    let time = NaiveTime::from_hms_micro_opt(12, 34, 56, 780_123).unwrap();
+    let nanos = date.and_hms_nano_opt(12, 34, 56, 780_123_456).unwrap();
+    vec![
+        ("primitive_null", Variant::Null),
+        ("primitive_boolean_true", Variant::BooleanTrue),
+        ("primitive_boolean_false", Variant::BooleanFalse),
+        ("primitive_int8", Variant::Int8(42)),
+        ("primitive_int16", Variant::Int16(-1234)),
+        ("primitive_int32", Variant::Int32(123456)),
+        ("primitive_int64", Variant::Int64(-1234567890123456789)),
+        ("primitive_double", Variant::Double(1234567890.1234)),
+        (
+            "primitive_decimal4",
+            VariantDecimal4::try_new(1234, 2).unwrap().into(),
+        ),
+        (
+            "primitive_decimal8",
+            VariantDecimal8::try_new(-1234567890, 2).unwrap().into(),
+        ),
+        (
+            "primitive_decimal16",
+            VariantDecimal16::try_new(1234567891234567890, 2)
+                .unwrap()
+                .into(),
+        ),
+        ("primitive_date", Variant::Date(date)),
+        (
+            "primitive_timestamp",
+            Variant::TimestampMicros(date.and_time(time).and_utc()),
+        ),
+        (
+            "primitive_timestampntz",
+            Variant::TimestampNtzMicros(date.and_time(time)),
+        ),
+        ("primitive_float", Variant::Float(1234567.5)),
+        (
+            "primitive_binary",
+            Variant::Binary(&[0x13, 0x37, 0xde, 0xad, 0xbe, 0xef]),
+        ),
+        (
+            "primitive_string",
+            Variant::from(
+                "A string of more than 64 bytes, which does not fit in a short string 🐢",
+            ),
+        ),
+        ("primitive_time", Variant::Time(time)),
+        (
+            "primitive_timestamp_nanos",
+            Variant::TimestampNanos(nanos.and_utc()),
+        ),
+        (
+            "primitive_timestampntz_nanos",
+            Variant::TimestampNtzNanos(nanos),
+        ),
+        (
+            "primitive_uuid",
+            Variant::Uuid([
+                0xf2, 0x4f, 0x9b, 0x64, 0x81, 0xfa, 0x49, 0xd1, 0xb7, 0x4e, 0x8c, 0x09, 0xa6, 0xe3,
+                0x1c, 0x56,
+            ]),
+        ),
+        (
+            "short_string",
+            Variant::from("Less than 64 bytes (❤️ with utf8)"),
+        ),
+    ]
+}
+
+/// Builds a variant with a new [`VariantBuilder`]
+fn build(f: impl FnOnce(&mut VariantBuilder)) -> Encoded {
+    let mut builder = VariantBuilder::new();
+    f(&mut builder);
+    builder.finish()
+}
+
+/// Appends `{"b": 1, "a": "x", "c": null}`, inserting the fields in an unsorted order
+fn append_unsorted_object(builder: &mut VariantBuilder) {
+    let mut obj = builder.new_object();
+    obj.insert("b", 1);
+    obj.insert("a", "x");
+    obj.insert("c", ());
+    obj.finish().unwrap();
+}
+
+/// Returns the name, metadata and value of every golden case
+fn golden_cases() -> Vec<(&'static str, Encoded)> {
+    let mut cases: Vec<_> = primitive_cases()
+        .into_iter()
+        .map(|(name, value)| (name, build(|builder| builder.append_value(value))))
+        .collect();
+
+    cases.push((
+        "object_empty",
+        build(|builder| builder.new_object().finish().unwrap()),
+    ));
+    cases.push(("array_empty", build(|builder| builder.new_list().finish())));
+    cases.push(("object_unsorted_dictionary", build(append_unsorted_object)));
+    cases.push(("object_sorted_dictionary", {
+        let mut builder = VariantBuilder::new();
+        append_unsorted_object(&mut builder);
+        builder.finish_sorted()
+    }));
+    cases.push(("object_with_unused_field_names", {
+        let mut builder = VariantBuilder::new().with_field_names(["z", "c", "y"].into_iter());
+        append_unsorted_object(&mut builder);
+        builder.finish()
+    }));
+    cases.push((
+        "array_primitive",
+        build(|builder| {
+            let mut list = builder.new_list();
+            list.append_value(1);
+            list.append_value("two");
+            list.append_value(3.5);
+            list.append_value(());
+            list.append_value(false);
+            list.finish();
+        }),
+    ));
+    cases.push((
+        "object_nested",
+        // {"id": 1, "observation": {"location": "In the Volcano", "time": "12:34:56",
+        //  "value": {"humidity": 456, "temperature": 123}}, "species": {"name": "lava monster",
+        //  "population": 6789}, "tags": ["hot", "rocky"]}
+        build(|builder| {
+            let mut obj = builder.new_object();
+            obj.insert("id", 1);
+            let mut observation = obj.new_object("observation");
+            observation.insert("location", "In the Volcano");
+            observation.insert("time", "12:34:56");
+            let mut value = observation.new_object("value");
+            value.insert("humidity", 456);
+            value.insert("temperature", 123);
+            value.finish().unwrap();
+            observation.finish().unwrap();
+            let mut species = obj.new_object("species");
+            species.insert("name", "lava monster");
+            species.insert("population", 6789);
+            species.finish().unwrap();
+            let mut tags = obj.new_list("tags");
+            tags.append_value("hot");
+            tags.append_value("rocky");
+            tags.finish();
+            obj.finish().unwrap();
+        }),
+    ));
+    cases.push((
+        "array_nested",
+        // [{"id": 1, "thing": {"names": ["Contrarian", "Spider"]}}, null, [[], [1]]]
+        build(|builder| {
+            let mut list = builder.new_list();
+            let mut obj = list.new_object();
+            obj.insert("id", 1);
+            let mut thing = obj.new_object("thing");
+            let mut names = thing.new_list("names");
+            names.append_value("Contrarian");
+            names.append_value("Spider");
+            names.finish();
+            thing.finish().unwrap();
+            obj.finish().unwrap();
+            list.append_value(());
+            let mut inner = list.new_list();
+            inner.new_list().finish();
+            let mut ints = inner.new_list();
+            ints.append_value(1);
+            ints.finish();
+            inner.finish();
+            list.finish();
+        }),
+    ));
+    cases.push((
+        "object_large",
+        // More than 255 fields, which requires the large object header and 2 byte field ids
+        build(|builder| {
+            let mut obj = builder.new_object();
+            for i in 0..300 {
+                obj.insert(&format!("field_{i:03}"), i);
+            }
+            obj.finish().unwrap();
+        }),
+    ));
+    cases.push((
+        "array_large",
+        // More than 255 elements with more than 255 bytes of data, which requires the large
+        // list header and 2 byte offsets
+        build(|builder| {
+            let mut list = builder.new_list();
+            for i in 0..300 {
+                list.append_value(i);
+            }
+            list.finish();
+        }),
+    ));
+    cases
+}
+
+#[test]
+fn variant_builder_golden() {
+    let dir = golden_dir();
+    let update = env::var(UPDATE_ENV).is_ok_and(|v| !v.trim().is_empty());
+    if update {
+        fs::create_dir_all(&dir).unwrap();
+    }
+
+    let mut mismatches = vec![];
+    for (name, (metadata, value)) in golden_cases() {
+        // Every case must be valid, even under strict validation
+        let options = VariantDecodeOptions::new().with_strict_validation(true);
+        Variant::try_new_with_options(&metadata, &value, &options)
+            .unwrap_or_else(|e| panic!("case {name} is invalid: {e}"));
+
+        let metadata_path = dir.join(format!("{name}.metadata"));
+        let value_path = dir.join(format!("{name}.value"));
+        if update {
+            fs::write(&metadata_path, &metadata).unwrap();
+            fs::write(&value_path, &value).unwrap();
+            continue;
+        }
+
+        let read = |path: &Path| {
+            fs::read(path).unwrap_or_else(|e| {
+                panic!("failed to read {path:?}: {e}\nHINT: set {UPDATE_ENV}=1 to generate it")
+            })
+        };
+        if read(&metadata_path) != metadata || read(&value_path) != value {
+            mismatches.push(name);
+        }
+    }
+    assert!(
+        mismatches.is_empty(),
+        "VariantBuilder output differs from the golden files for {mismatches:?}\n\
+         HINT: if the change is intentional, set {UPDATE_ENV}=1 to regenerate them"
+    );
+}
+
+#[test]
+fn variant_golden_primitives_decode() {
+    let dir = golden_dir();
+    for (name, expected) in primitive_cases() {
+        let metadata = fs::read(dir.join(format!("{name}.metadata"))).unwrap();
+        let value = fs::read(dir.join(format!("{name}.value"))).unwrap();
+        let variant = Variant::try_new(&metadata, &value).unwrap();
+        assert_eq!(variant, expected, "{name}");
+    }
+}
+
+#[test]
+fn variant_golden_dictionaries() {
+    let dir = golden_dir();
+    let metadata = |name: &str| fs::read(dir.join(format!("{name}.metadata"))).unwrap();
+
+    let unsorted = metadata("object_unsorted_dictionary");
+    let unsorted = VariantMetadata::try_new(&unsorted).unwrap();
+    assert!(!unsorted.is_sorted());
+    assert_eq!(unsorted.iter().collect::<Vec<_>>(), ["b", "a", "c"]);
+
+    let sorted = metadata("object_sorted_dictionary");
+    let sorted = VariantMetadata::try_new(&sorted).unwrap();
+    assert!(sorted.is_sorted());
+    assert_eq!(sorted.iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+    let unused = metadata("object_with_unused_field_names");
+    let unused = VariantMetadata::try_new(&unused).unwrap();
+    assert_eq!(unused.iter().collect::<Vec<_>>(), ["z", "c", "y", "b", "a"]);
+}
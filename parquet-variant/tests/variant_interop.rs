@@ -18,12 +18,13 @@
 //! End-to-end check: (almost) every sample from apache/parquet-testing/variant
 //! can be parsed into our `Variant`.
 
+use std::env;
 use std::path::{Path, PathBuf};
-use std::{env, fs};
 
 use chrono::NaiveDate;
 use parquet_variant::{
     ShortString, Variant, VariantBuilder, VariantDecimal16, VariantDecimal4, VariantDecimal8,
+    VariantTestCase,
 };
 
 use rand::rngs::StdRng;
@@ -82,24 +83,9 @@ fn cases_dir() -> PathBuf {
     }
 }
 
-struct Case {
-    metadata: Vec<u8>,
-    value: Vec<u8>,
-}
-
-impl Case {
-    /// Load the case with the given name from the parquet testing repository.
-    fn load(name: &str) -> Self {
-        let root = cases_dir();
-        let metadata = fs::read(root.join(format!("{name}.metadata"))).unwrap();
-        let value = fs::read(root.join(format!("{name}.value"))).unwrap();
-        Self { metadata, value }
-    }
-
-    /// Return the Variant for this case.
-    fn variant(&self) -> Variant<'_, '_> {
-        Variant::try_new(&self.metadata, &self.value).expect("Failed to parse variant")
-    }
+/// Load the case with the given name from the parquet testing repository.
+fn load_case(name: &str) -> VariantTestCase {
+    VariantTestCase::load(cases_dir(), name).unwrap()
 }
 
 /// Return a list of the values from the parquet testing repository:
@@ -133,15 +119,15 @@ fn get_primitive_cases() -> Vec<(&'static str, Variant<'static, 'static>)> {
 fn variant_primitive() {
     let cases = get_primitive_cases();
     for (case, want) in cases {
-        let case = Case::load(case);
-        let got = case.variant();
+        let case = load_case(case);
+        let got = case.variant().expect("Failed to parse variant");
         assert_eq!(got, want);
     }
 }
 #[test]
 fn variant_object_empty() {
-    let case = Case::load("object_empty");
-    let Variant::Object(variant_object) = case.variant() else {
+    let case = load_case("object_empty");
+    let Variant::Object(variant_object) = case.variant().expect("Failed to parse variant") else {
         panic!("expected an object");
     };
     assert_eq!(variant_object.len(), 0);
@@ -163,8 +149,8 @@ fn variant_object_primitive() {
     //         "timestamp_field": "2025-04-16T12:34:56.78"
     //     },
     // ```
-    let case = Case::load("object_primitive");
-    let Variant::Object(variant_object) = case.variant() else {
+    let case = load_case("object_primitive");
+    let Variant::Object(variant_object) = case.variant().expect("Failed to parse variant") else {
         panic!("expected an object");
     };
     let expected_fields = vec![
@@ -209,8 +195,8 @@ fn variant_array_primitive() {
     //    9
     // ],
     // ```
-    let case = Case::load("array_primitive");
-    let Variant::List(list) = case.variant() else {
+    let case = load_case("array_primitive");
+    let Variant::List(list) = case.variant().expect("Failed to parse variant") else {
         panic!("expected an array");
     };
     let expected = vec![
@@ -242,8 +228,8 @@ fn variant_array_builder() {
 
     let (built_metadata, built_value) = builder.finish();
     let actual = Variant::try_new(&built_metadata, &built_value).unwrap();
-    let case = Case::load("array_primitive");
-    let expected = case.variant();
+    let case = load_case("array_primitive");
+    let expected = case.variant().expect("Failed to parse variant");
 
     assert_eq!(actual, expected);
 }
@@ -271,8 +257,8 @@ fn variant_object_builder() {
 
     let (built_metadata, built_value) = builder.finish();
     let actual = Variant::try_new(&built_metadata, &built_value).unwrap();
-    let case = Case::load("object_primitive");
-    let expected = case.variant();
+    let case = load_case("object_primitive");
+    let expected = case.variant().expect("Failed to parse variant");
 
     assert_eq!(actual, expected);
 }
@@ -22,6 +22,8 @@ use arrow_schema::{DataType, Fields, SchemaBuilder};
 use crate::arrow::array_reader::byte_view_array::make_byte_view_array_reader;
 use crate::arrow::array_reader::empty_array::make_empty_array_reader;
 use crate::arrow::array_reader::fixed_len_byte_array::make_fixed_len_byte_array_reader;
+#[cfg(feature = "variant_experimental")]
+use crate::arrow::array_reader::VariantArrayReader;
 use crate::arrow::array_reader::{
     make_byte_array_dictionary_reader, make_byte_array_reader, ArrayReader,
     FixedSizeListArrayReader, ListArrayReader, MapArrayReader, NullArrayReader,
@@ -33,6 +35,8 @@ use crate::basic::Type as PhysicalType;
 use crate::data_type::{BoolType, DoubleType, FloatType, Int32Type, Int64Type, Int96Type};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::{ColumnDescriptor, ColumnPath, Type};
+#[cfg(feature = "variant_experimental")]
+use crate::variant::is_projected_shredded_variant;
 
 /// Builds [`ArrayReader`]s from parquet schema, projection mask, and RowGroups reader
 pub struct ArrayReaderBuilder<'a> {
@@ -78,9 +82,33 @@ impl<'a> ArrayReaderBuilder<'a> {
                 DataType::FixedSizeList(_, _) => self.build_fixed_size_list_reader(field, mask),
                 d => unimplemented!("reading group type {} not implemented", d),
             },
+            #[cfg(feature = "variant_experimental")]
+            ParquetFieldType::Variant { ref shredded } => self.build_variant_reader(shredded, mask),
         }
     }
 
+    /// Build array reader for a shredded variant, reassembling the struct of its group
+    ///
+    /// As for the arrow schema of the projection, the struct is read as is if any of its
+    /// columns, such as its `metadata` or the `value` of a shredded field, is not projected
+    #[cfg(feature = "variant_experimental")]
+    fn build_variant_reader(
+        &self,
+        shredded: &ParquetField,
+        mask: &ProjectionMask,
+    ) -> Result<Option<Box<dyn ArrayReader>>> {
+        let Some(reader) = self.build_struct_reader(shredded, mask)? else {
+            return Ok(None);
+        };
+        let fully_projected = leaves_included(shredded, mask);
+        Ok(Some(
+            match is_projected_shredded_variant(fully_projected, reader.get_data_type()) {
+                true => Box::new(VariantArrayReader::new(reader)),
+                false => reader,
+            },
+        ))
+    }
+
     /// Build array reader for map type.
     fn build_map_reader(
         &self,
@@ -352,6 +380,16 @@ impl<'a> ArrayReaderBuilder<'a> {
     }
 }
 
+/// Returns true if all the leaves of `field` are included in `mask`
+#[cfg(feature = "variant_experimental")]
+fn leaves_included(field: &ParquetField, mask: &ProjectionMask) -> bool {
+    match &field.field_type {
+        ParquetFieldType::Primitive { col_idx, .. } => mask.leaf_included(*col_idx),
+        ParquetFieldType::Group { children } => children.iter().all(|c| leaves_included(c, mask)),
+        ParquetFieldType::Variant { shredded } => leaves_included(shredded, mask),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,6 +410,7 @@ mod tests {
             file_metadata.schema_descr(),
             ProjectionMask::all(),
             file_metadata.key_value_metadata(),
+            false,
         )
         .unwrap();
 
@@ -41,6 +41,8 @@ mod map_array;
 mod null_array;
 mod primitive_array;
 mod struct_array;
+#[cfg(feature = "variant_experimental")]
+mod variant_array;
 
 #[cfg(test)]
 mod test_util;
@@ -59,6 +61,8 @@ pub use map_array::MapArrayReader;
 pub use null_array::NullArrayReader;
 pub use primitive_array::PrimitiveArrayReader;
 pub use struct_array::StructArrayReader;
+#[cfg(feature = "variant_experimental")]
+pub use variant_array::VariantArrayReader;
 
 /// Array reader reads parquet data into arrow array.
 pub trait ArrayReader: Send {
@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::Result;
+use crate::variant::{unshred_struct, unshredded_variant_type};
+use arrow_array::cast::AsArray;
+use arrow_array::ArrayRef;
+use arrow_schema::DataType as ArrowType;
+use std::any::Any;
+
+/// Implementation of shredded variant array reader.
+///
+/// Reads the struct of a shredded variant group, and reassembles its `value` and
+/// `typed_value` into an unshredded variant.
+pub struct VariantArrayReader {
+    reader: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+}
+
+impl VariantArrayReader {
+    /// Construct variant array reader from the reader of its shredded struct.
+    pub fn new(reader: Box<dyn ArrayReader>) -> Self {
+        Self {
+            reader,
+            data_type: unshredded_variant_type(),
+        }
+    }
+}
+
+impl ArrayReader for VariantArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let array = self.reader.consume_batch()?;
+        unshred_struct(array.as_struct())
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.reader.get_rep_levels()
+    }
+}
@@ -19,28 +19,39 @@
 
 use arrow_array::cast::AsArray;
 use arrow_array::Array;
-use arrow_array::{RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
+use arrow_array::{RecordBatch, RecordBatchReader, StructArray};
+use arrow_schema::{ArrowError, DataType as ArrowType, Fields, Schema, SchemaRef};
 pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+use pruning::evaluate_page_predicate;
+pub use pruning::{
+    prune_pages, PagePredicateReport, PagePruningPredicate, PageStatistics, PruningReport,
+    RowFilterPredicateReport,
+};
 pub use selection::{RowSelection, RowSelector};
+use std::collections::{HashSet, VecDeque};
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 pub use crate::arrow::array_reader::RowGroups;
 use crate::arrow::array_reader::{ArrayReader, ArrayReaderBuilder};
-use crate::arrow::schema::{parquet_to_arrow_schema_and_fields, ParquetField};
-use crate::arrow::{parquet_to_arrow_field_levels, FieldLevels, ProjectionMask};
+use crate::arrow::schema::{
+    convert_field_levels, parquet_to_arrow_schema_and_fields, ParquetField,
+};
+use crate::arrow::{FieldLevels, ProjectionMask};
+use crate::basic::CompressionCodecFactory;
 use crate::column::page::{PageIterator, PageReader};
 #[cfg(feature = "encryption")]
 use crate::encryption::decrypt::FileDecryptionProperties;
 use crate::errors::{ParquetError, Result};
-use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
+use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader, RowGroupMetaData};
+use crate::file::properties::{ReaderProperties, ReaderPropertiesPtr};
 use crate::file::reader::{ChunkReader, SerializedPageReader};
 use crate::schema::types::SchemaDescriptor;
 
 pub(crate) use read_plan::{ReadPlan, ReadPlanBuilder};
 
 mod filter;
+pub(crate) mod pruning;
 mod read_plan;
 mod selection;
 pub mod statistics;
@@ -101,34 +112,57 @@ pub struct ArrowReaderBuilder<T> {
 
     pub(crate) batch_size: usize,
 
+    pub(crate) max_batch_bytes: Option<usize>,
+
     pub(crate) row_groups: Option<Vec<usize>>,
 
+    pub(crate) row_group_filter: Option<RowGroupFilter>,
+
     pub(crate) projection: ProjectionMask,
 
     pub(crate) filter: Option<RowFilter>,
 
     pub(crate) selection: Option<RowSelection>,
 
+    pub(crate) deleted_rows: Vec<u64>,
+
+    pub(crate) page_pruning_predicates: Vec<Arc<dyn PagePruningPredicate>>,
+
     pub(crate) limit: Option<usize>,
 
     pub(crate) offset: Option<usize>,
+
+    pub(crate) case_insensitive: bool,
+
+    pub(crate) codec_factory: Option<Arc<dyn CompressionCodecFactory>>,
+
+    #[cfg(feature = "async")]
+    pub(crate) prefetch_budget: Option<usize>,
 }
 
 impl<T: Debug> Debug for ArrowReaderBuilder<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ArrowReaderBuilder<T>")
-            .field("input", &self.input)
+        let mut s = f.debug_struct("ArrowReaderBuilder<T>");
+        s.field("input", &self.input)
             .field("metadata", &self.metadata)
             .field("schema", &self.schema)
             .field("fields", &self.fields)
             .field("batch_size", &self.batch_size)
+            .field("max_batch_bytes", &self.max_batch_bytes)
             .field("row_groups", &self.row_groups)
+            .field("row_group_filter", &self.row_group_filter.is_some())
             .field("projection", &self.projection)
             .field("filter", &self.filter)
             .field("selection", &self.selection)
+            .field("deleted_rows", &self.deleted_rows.len())
+            .field("page_pruning_predicates", &self.page_pruning_predicates)
             .field("limit", &self.limit)
             .field("offset", &self.offset)
-            .finish()
+            .field("case_insensitive", &self.case_insensitive)
+            .field("codec_factory", &self.codec_factory);
+        #[cfg(feature = "async")]
+        s.field("prefetch_budget", &self.prefetch_budget);
+        s.finish()
     }
 }
 
@@ -140,15 +174,33 @@ impl<T> ArrowReaderBuilder<T> {
             schema: metadata.schema,
             fields: metadata.fields,
             batch_size: 1024,
+            max_batch_bytes: None,
             row_groups: None,
+            row_group_filter: None,
             projection: ProjectionMask::all(),
             filter: None,
             selection: None,
+            deleted_rows: vec![],
+            page_pruning_predicates: vec![],
             limit: None,
             offset: None,
+            case_insensitive: metadata.case_insensitive,
+            codec_factory: metadata.codec_factory,
+            #[cfg(feature = "async")]
+            prefetch_budget: None,
         }
     }
 
+    /// Returns the [`ReaderProperties`] used to create the page readers of the columns
+    pub(crate) fn reader_properties(&self) -> ReaderPropertiesPtr {
+        let builder = ReaderProperties::builder();
+        let builder = match &self.codec_factory {
+            Some(factory) => builder.set_codec_factory(Arc::clone(factory)),
+            None => builder,
+        };
+        Arc::new(builder.build())
+    }
+
     /// Returns a reference to the [`ParquetMetaData`] for this parquet file
     pub fn metadata(&self) -> &Arc<ParquetMetaData> {
         &self.metadata
@@ -164,6 +216,13 @@ impl<T> ArrowReaderBuilder<T> {
         &self.schema
     }
 
+    /// Returns the fingerprint of the arrow schema stored in this parquet file, if any
+    ///
+    /// See [`ArrowReaderMetadata::schema_fingerprint`]
+    pub fn schema_fingerprint(&self) -> Option<u64> {
+        schema_fingerprint(&self.metadata)
+    }
+
     /// Set the size of [`RecordBatch`] to produce. Defaults to 1024
     /// If the batch_size more than the file row count, use the file row count.
     pub fn with_batch_size(self, batch_size: usize) -> Self {
@@ -172,6 +231,26 @@ impl<T> ArrowReaderBuilder<T> {
         Self { batch_size, ..self }
     }
 
+    /// Limit the number of decoded bytes in each [`RecordBatch`]. Defaults to no limit
+    ///
+    /// This bounds the memory used to decode row groups with large values, such as wide
+    /// string or binary columns, where batches of [`Self::with_batch_size`] rows could
+    /// be very large.
+    ///
+    /// The number of rows read in each batch is adapted to the decoded size of the
+    /// previous rows of the row group, and is never more than the batch size. Batches
+    /// decoded larger than `max_batch_bytes` are split into several smaller batches, so
+    /// that only batches of a single row can exceed it.
+    ///
+    /// The decoded size of a batch is that of the data it references, see
+    /// [`ArrayData::get_slice_memory_size`](arrow_data::ArrayData::get_slice_memory_size).
+    pub fn with_max_batch_bytes(self, max_batch_bytes: usize) -> Self {
+        Self {
+            max_batch_bytes: Some(max_batch_bytes),
+            ..self
+        }
+    }
+
     /// Only read data from the provided row group indexes
     ///
     /// This is also called row group filtering
@@ -182,6 +261,55 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Only read data from the row groups for which `filter` returns true
+    ///
+    /// Unlike [`Self::with_row_groups`], this does not require the caller to compute the
+    /// indexes of the row groups to read up front. Instead `filter` is called lazily with the
+    /// [`RowGroupMetaData`] of each row group, immediately before it would be read, which lets
+    /// engines prune row groups on custom criteria, such as key value metadata or partition
+    /// values, with only the metadata of the file.
+    ///
+    /// `filter` is only called for the row groups selected by [`Self::with_row_groups`], if
+    /// any. A [`RowSelection`] provided with [`Self::with_row_selection`] must still include
+    /// the rows of row groups that are skipped by `filter`, which are then skipped along
+    /// with the row group.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{Int32Array, RecordBatch};
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::file::properties::WriterProperties;
+    /// # let mut file: Vec<u8> = Vec::with_capacity(1024);
+    /// # let schema = Arc::new(Schema::new(vec![Field::new("i32", DataType::Int32, false)]));
+    /// # let props = WriterProperties::builder().set_max_row_group_size(2).build();
+    /// # let mut writer = ArrowWriter::try_new(&mut file, schema.clone(), Some(props)).unwrap();
+    /// # let batch = RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(vec![1, 2, 3]))]).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// // Skip the row groups with fewer than 2 rows
+    /// let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+    ///     .unwrap()
+    ///     .with_row_group_filter(|row_group| row_group.num_rows() >= 2)
+    ///     .build()
+    ///     .unwrap();
+    /// let num_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+    /// assert_eq!(num_rows, 2);
+    /// ```
+    pub fn with_row_group_filter<F>(self, filter: F) -> Self
+    where
+        F: Fn(&RowGroupMetaData) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            row_group_filter: Some(Box::new(filter)),
+            ..self
+        }
+    }
+
     /// Only read data from the provided column indexes
     pub fn with_projection(self, mask: ProjectionMask) -> Self {
         Self {
@@ -190,6 +318,41 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Only read data from the root columns named `names`
+    ///
+    /// Unlike [`ProjectionMask::columns`], which matches paths in the parquet schema, `names`
+    /// are matched against the root fields of [`Self::schema`], after any renaming by
+    /// [`ArrowReaderOptions::with_duplicate_column_names`], and case-insensitively if
+    /// [`ArrowReaderOptions::with_case_insensitive_projection`] is enabled.
+    ///
+    /// Returns an error if a name matches no column, or more than one column.
+    pub fn with_projected_columns<'a>(
+        self,
+        names: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self> {
+        let fields = self.schema.fields();
+        let mut indices = vec![];
+        for name in names {
+            let mut matches =
+                fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| match self.case_insensitive {
+                        true => f.name().to_lowercase() == name.to_lowercase(),
+                        false => f.name() == name,
+                    });
+            let Some((idx, _)) = matches.next() else {
+                return Err(arrow_err!("No column named {}", name));
+            };
+            if matches.next().is_some() {
+                return Err(arrow_err!("Ambiguous column name {}", name));
+            }
+            indices.push(idx);
+        }
+        let mask = ProjectionMask::roots(self.parquet_schema(), indices);
+        Ok(self.with_projection(mask))
+    }
+
     /// Provide a [`RowSelection`] to filter out rows, and avoid fetching their
     /// data into memory.
     ///
@@ -256,6 +419,100 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Skip decoding the rows at the given positions within the file, such as the rows
+    /// removed by the position delete files or deletion vectors of a table format
+    ///
+    /// Unlike [`Self::with_row_selection`], the positions index the rows of all row groups
+    /// of the file, regardless of [`Self::with_row_groups`], and positions beyond the end
+    /// of the file are ignored. The remaining rows are intersected with the row selection,
+    /// if any, before any [`RowFilter`] is evaluated, so deleted rows are never decoded.
+    ///
+    /// # Example
+    ///
+    /// Applying a deletion bitmap of the rows of a file
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use arrow_buffer::BooleanBuffer;
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// # let col = Arc::new(Int32Array::from_iter_values(0..5)) as ArrayRef;
+    /// # let batch = RecordBatch::try_from_iter([("a", col)]).unwrap();
+    /// # let mut buf = vec![];
+    /// # let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let data = Bytes::from(buf);
+    /// let deleted = BooleanBuffer::from(vec![false, true, true, false, true]);
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new(data)
+    ///     .unwrap()
+    ///     .with_deleted_rows(deleted.set_indices().map(|i| i as u64))
+    ///     .build()
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let expected = Arc::new(Int32Array::from(vec![0, 3])) as ArrayRef;
+    /// assert_eq!(batch.column(0), &expected);
+    /// ```
+    pub fn with_deleted_rows(self, deleted_rows: impl IntoIterator<Item = u64>) -> Self {
+        let mut deleted_rows: Vec<_> = deleted_rows.into_iter().collect();
+        deleted_rows.sort_unstable();
+        deleted_rows.dedup();
+        Self {
+            deleted_rows,
+            ..self
+        }
+    }
+
+    /// Skip the data pages that can not contain rows matching `predicate`, based on the
+    /// page index
+    ///
+    /// The pages that may match each predicate are intersected with the
+    /// [`Self::with_row_selection`], if any. This requires the page index to be loaded, see
+    /// [`ArrowReaderOptions::with_page_index`], otherwise no pages are skipped.
+    ///
+    /// See [`PagePruningPredicate`] for an example.
+    pub fn with_page_pruning_predicate(mut self, predicate: Arc<dyn PagePruningPredicate>) -> Self {
+        self.page_pruning_predicates.push(predicate);
+        self
+    }
+
+    /// Returns the [`RowSelection`] of the rows of `row_groups` to read, intersecting the
+    /// selection of [`Self::with_row_selection`] with the rows that were not deleted, see
+    /// [`Self::with_deleted_rows`], and the pages that may match each
+    /// [`PagePruningPredicate`], and a [`PruningReport`] of the pruned pages
+    pub(crate) fn pruned_selection(
+        &self,
+        row_groups: &[usize],
+    ) -> Result<(Option<RowSelection>, PruningReport)> {
+        let mut selection = self.selection.clone();
+        if !self.deleted_rows.is_empty() {
+            let remaining = remaining_rows(&self.metadata, row_groups, &self.deleted_rows)?;
+            selection = match selection {
+                Some(selection) => Some(selection.intersection(&remaining)),
+                None => Some(remaining),
+            };
+        }
+        let mut reports = Vec::with_capacity(self.page_pruning_predicates.len());
+        for predicate in &self.page_pruning_predicates {
+            let (pruned, report) = evaluate_page_predicate(
+                &self.metadata,
+                &self.schema,
+                row_groups,
+                predicate.as_ref(),
+            )?;
+            reports.push(report);
+            selection = match (selection, pruned) {
+                (Some(selection), Some(pruned)) => Some(selection.intersection(&pruned)),
+                (selection, pruned) => selection.or(pruned),
+            };
+        }
+        let row_filter_predicates = self.filter.as_ref().map_or(0, |f| f.predicates.len());
+        let report = PruningReport::new(reports, row_filter_predicates);
+        Ok((selection, report))
+    }
+
     /// Provide a [`RowFilter`] to skip decoding rows
     ///
     /// Row filters are applied after row group selection and row selection
@@ -313,6 +570,16 @@ pub struct ArrowReaderOptions {
     supplied_schema: Option<SchemaRef>,
     /// If true, attempt to read `OffsetIndex` and `ColumnIndex`
     pub(crate) page_index: bool,
+    /// If true, interpret lists using the legacy 2-level list encoding
+    legacy_two_level_lists: bool,
+    /// If true, match column names case-insensitively
+    case_insensitive: bool,
+    /// How to handle root columns with the same name
+    duplicate_column_names: DuplicateColumnNames,
+    /// If provided, used to create the decompression codecs
+    codec_factory: Option<Arc<dyn CompressionCodecFactory>>,
+    /// If true, read columns annotated with unknown logical types as their physical type
+    pub(crate) ignore_unknown_logical_types: bool,
     /// If encryption is enabled, the file decryption properties can be provided
     #[cfg(feature = "encryption")]
     pub(crate) file_decryption_properties: Option<FileDecryptionProperties>,
@@ -417,6 +684,169 @@ impl ArrowReaderOptions {
         Self { page_index, ..self }
     }
 
+    /// Interpret lists written by legacy writers using the 2-level list encoding
+    /// (defaults to `false`)
+    ///
+    /// The [Parquet specification] describes how to determine the element type of a `LIST`
+    /// annotated group whose repeated child group has a single field. Unless the repeated group
+    /// is named `array` or `<list name>_tuple`, the single field is the element type, as in the
+    /// standard 3-level encoding. However, some legacy writers wrote lists of single field
+    /// structs using the 2-level encoding with other names for the repeated group, such as
+    /// `element` or `item`.
+    ///
+    /// If enabled, such a repeated group is always interpreted as the element type, so the
+    /// list elements are structs with a single field. As this misinterprets lists using the
+    /// 3-level encoding, it should only be enabled for files known to use the 2-level encoding.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int32Type;
+    /// # use arrow_schema::{DataType, Field, Fields};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::data_type::Int32Type as ParquetInt32Type;
+    /// # use parquet::file::writer::SerializedFileWriter;
+    /// # use parquet::schema::parser::parse_message_type;
+    /// // A list of structs with a single field, written with the 2-level encoding
+    /// let schema = parse_message_type(
+    ///     "message schema {
+    ///         optional group points (LIST) {
+    ///             repeated group item {
+    ///                 required int32 x;
+    ///             }
+    ///         }
+    ///     }",
+    /// )
+    /// .unwrap();
+    /// # let mut file = Vec::new();
+    /// # let mut writer = SerializedFileWriter::new(&mut file, Arc::new(schema), Default::default()).unwrap();
+    /// # let mut row_group = writer.next_row_group().unwrap();
+    /// # let mut column = row_group.next_column().unwrap().unwrap();
+    /// # column.typed::<ParquetInt32Type>().write_batch(&[1, 2], Some(&[2, 2]), Some(&[0, 1])).unwrap();
+    /// # column.close().unwrap();
+    /// # row_group.close().unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    ///
+    /// // By default, `x` is interpreted as the element type
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new(file.clone()).unwrap();
+    /// let element = Field::new("x", DataType::Int32, false);
+    /// assert_eq!(
+    ///     builder.schema().field(0).data_type(),
+    ///     &DataType::List(Arc::new(element.clone()))
+    /// );
+    ///
+    /// // With the legacy interpretation, `item` is the element type
+    /// let options = ArrowReaderOptions::new().with_legacy_two_level_lists(true);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).unwrap();
+    /// let item = Field::new_struct("item", Fields::from(vec![element]), false);
+    /// assert_eq!(builder.schema().field(0).data_type(), &DataType::List(Arc::new(item)));
+    ///
+    /// let batch = builder.build().unwrap().next().unwrap().unwrap();
+    /// let items = batch.column(0).as_list::<i32>().values().as_struct();
+    /// assert_eq!(items.column(0).as_primitive::<Int32Type>().values(), &[1, 2]);
+    /// ```
+    ///
+    /// [Parquet specification]: https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#backward-compatibility-rules
+    pub fn with_legacy_two_level_lists(self, legacy_two_level_lists: bool) -> Self {
+        Self {
+            legacy_two_level_lists,
+            ..self
+        }
+    }
+
+    /// Match column names case-insensitively (defaults to `false`)
+    ///
+    /// This affects [`ArrowReaderBuilder::with_projected_columns`], and which root columns are
+    /// considered duplicates by [`Self::with_duplicate_column_names`]. It is useful to read
+    /// files written by systems with case-insensitive schemas, whose column names may differ
+    /// in case from those used by the reader.
+    pub fn with_case_insensitive_projection(self, case_insensitive: bool) -> Self {
+        Self {
+            case_insensitive,
+            ..self
+        }
+    }
+
+    /// Set how root columns with the same name are handled (defaults to
+    /// [`DuplicateColumnNames::Allow`])
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::arrow_reader::{
+    /// #     ArrowReaderOptions, DuplicateColumnNames, ParquetRecordBatchReaderBuilder,
+    /// # };
+    /// # use parquet::arrow::ArrowWriter;
+    /// # let columns = [("id", 1), ("ID", 2), ("id", 3)];
+    /// # let columns = columns.map(|(n, v)| (n, Arc::new(Int32Array::from(vec![v])) as ArrayRef));
+    /// # let batch = RecordBatch::try_from_iter(columns).unwrap();
+    /// # let mut file = vec![];
+    /// # let mut writer = ArrowWriter::try_new(&mut file, batch.schema(), None).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// // A file with the columns `id`, `ID` and `id`
+    /// let options = ArrowReaderOptions::new()
+    ///     .with_case_insensitive_projection(true)
+    ///     .with_duplicate_column_names(DuplicateColumnNames::Suffix);
+    /// let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file.clone(), options)
+    ///     .unwrap()
+    ///     .with_projected_columns(["Id_2"])
+    ///     .unwrap();
+    /// let names: Vec<_> = builder.schema().fields().iter().map(|f| f.name()).collect();
+    /// assert_eq!(names, ["id", "ID_1", "id_2"]);
+    /// let batch = builder.build().unwrap().next().unwrap().unwrap();
+    /// assert_eq!(batch.schema().field(0).name(), "id_2");
+    ///
+    /// let options = ArrowReaderOptions::new()
+    ///     .with_duplicate_column_names(DuplicateColumnNames::Error);
+    /// let err = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).unwrap_err();
+    /// assert_eq!(err.to_string(), "Arrow: Duplicate column name id at index 2");
+    /// ```
+    pub fn with_duplicate_column_names(self, duplicate_column_names: DuplicateColumnNames) -> Self {
+        Self {
+            duplicate_column_names,
+            ..self
+        }
+    }
+
+    /// Provide a [`CompressionCodecFactory`] used to create the decompression codecs
+    /// (defaults to `None`)
+    ///
+    /// The factory is consulted before the built-in codecs, see
+    /// [`ReaderPropertiesBuilder::set_codec_factory`].
+    ///
+    /// [`ReaderPropertiesBuilder::set_codec_factory`]: crate::file::properties::ReaderPropertiesBuilder::set_codec_factory
+    pub fn with_codec_factory(self, codec_factory: Arc<dyn CompressionCodecFactory>) -> Self {
+        Self {
+            codec_factory: Some(codec_factory),
+            ..self
+        }
+    }
+
+    /// Read columns annotated with logical types not known to this crate as their physical
+    /// type (defaults to `false`)
+    ///
+    /// By default, reading a file using logical types added to the format after this crate is
+    /// an error. If enabled, the annotations are ignored, and the thrift compact encoding of
+    /// each is stored base64 encoded in the metadata of its arrow field under
+    /// [`PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY`].
+    ///
+    /// See [`ParquetMetaDataReader::with_ignore_unknown_logical_types`].
+    ///
+    /// [`PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY`]: crate::arrow::PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY
+    pub fn with_ignore_unknown_logical_types(self, ignore_unknown_logical_types: bool) -> Self {
+        Self {
+            ignore_unknown_logical_types,
+            ..self
+        }
+    }
+
     /// Provide the file decryption properties to use when reading encrypted parquet files.
     ///
     /// If encryption is enabled and the file is encrypted, the `file_decryption_properties` must be provided.
@@ -438,6 +868,44 @@ impl ArrowReaderOptions {
         self.page_index
     }
 
+    /// Retrieve whether lists are interpreted using the legacy 2-level list encoding.
+    ///
+    /// This can be set via [`with_legacy_two_level_lists`][Self::with_legacy_two_level_lists].
+    pub fn legacy_two_level_lists(&self) -> bool {
+        self.legacy_two_level_lists
+    }
+
+    /// Retrieve whether column names are matched case-insensitively.
+    ///
+    /// This can be set via
+    /// [`with_case_insensitive_projection`][Self::with_case_insensitive_projection].
+    pub fn case_insensitive_projection(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Retrieve how root columns with the same name are handled.
+    ///
+    /// This can be set via [`with_duplicate_column_names`][Self::with_duplicate_column_names].
+    pub fn duplicate_column_names(&self) -> DuplicateColumnNames {
+        self.duplicate_column_names
+    }
+
+    /// Retrieve the [`CompressionCodecFactory`] used to create the decompression codecs.
+    ///
+    /// This can be set via [`with_codec_factory`][Self::with_codec_factory].
+    pub fn codec_factory(&self) -> Option<&Arc<dyn CompressionCodecFactory>> {
+        self.codec_factory.as_ref()
+    }
+
+    /// Retrieve whether columns annotated with unknown logical types are read as their
+    /// physical type.
+    ///
+    /// This can be set via
+    /// [`with_ignore_unknown_logical_types`][Self::with_ignore_unknown_logical_types].
+    pub fn ignore_unknown_logical_types(&self) -> bool {
+        self.ignore_unknown_logical_types
+    }
+
     /// Retrieve the currently set file decryption properties.
     ///
     /// This can be set via
@@ -448,6 +916,27 @@ impl ArrowReaderOptions {
     }
 }
 
+/// How root columns with the same name are handled, see
+/// [`ArrowReaderOptions::with_duplicate_column_names`]
+///
+/// Names are compared case-insensitively if
+/// [`ArrowReaderOptions::with_case_insensitive_projection`] is enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DuplicateColumnNames {
+    /// Keep the duplicate names in the arrow schema
+    ///
+    /// Projecting a duplicate name with [`ArrowReaderBuilder::with_projected_columns`] is an
+    /// error.
+    #[default]
+    Allow,
+    /// Return an error when constructing the [`ArrowReaderMetadata`]
+    Error,
+    /// Rename each column, except the first, with a duplicate name to `<name>_<index>`, where
+    /// `<index>` is the index of the column, incremented until the name differs from the
+    /// names of all other columns
+    Suffix,
+}
+
 /// The metadata necessary to construct a [`ArrowReaderBuilder`]
 ///
 /// Note this structure is cheaply clone-able as it consists of several arcs.
@@ -470,6 +959,10 @@ pub struct ArrowReaderMetadata {
     pub(crate) schema: SchemaRef,
 
     pub(crate) fields: Option<Arc<ParquetField>>,
+    /// If true, match column names case-insensitively
+    pub(crate) case_insensitive: bool,
+    /// If provided, used to create the decompression codecs
+    pub(crate) codec_factory: Option<Arc<dyn CompressionCodecFactory>>,
 }
 
 impl ArrowReaderMetadata {
@@ -484,7 +977,9 @@ impl ArrowReaderMetadata {
     /// `Self::metadata` is missing the page index, this function will attempt
     /// to load the page index by making an object store request.
     pub fn load<T: ChunkReader>(reader: &T, options: ArrowReaderOptions) -> Result<Self> {
-        let metadata = ParquetMetaDataReader::new().with_page_indexes(options.page_index);
+        let metadata = ParquetMetaDataReader::new()
+            .with_page_indexes(options.page_index)
+            .with_ignore_unknown_logical_types(options.ignore_unknown_logical_types);
         #[cfg(feature = "encryption")]
         let metadata =
             metadata.with_decryption_properties(options.file_decryption_properties.as_ref());
@@ -499,8 +994,12 @@ impl ArrowReaderMetadata {
     /// This function does not attempt to load the PageIndex if not present in the metadata.
     /// See [`Self::load`] for more details.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
-        match options.supplied_schema {
-            Some(supplied_schema) => Self::with_supplied_schema(metadata, supplied_schema.clone()),
+        let mut reader_metadata = match &options.supplied_schema {
+            Some(supplied_schema) => Self::with_supplied_schema(
+                metadata,
+                supplied_schema.clone(),
+                options.legacy_two_level_lists,
+            )?,
             None => {
                 let kv_metadata = match options.skip_arrow_metadata {
                     true => None,
@@ -511,32 +1010,109 @@ impl ArrowReaderMetadata {
                     metadata.file_metadata().schema_descr(),
                     ProjectionMask::all(),
                     kv_metadata,
+                    options.legacy_two_level_lists,
                 )?;
 
-                Ok(Self {
+                Self {
                     metadata,
                     schema: Arc::new(schema),
                     fields: fields.map(Arc::new),
-                })
+                    case_insensitive: false,
+                    codec_factory: None,
+                }
+            }
+        };
+        reader_metadata.codec_factory = options.codec_factory.clone();
+        reader_metadata.with_column_names(&options)
+    }
+
+    /// Applies [`ArrowReaderOptions::with_case_insensitive_projection`] and
+    /// [`ArrowReaderOptions::with_duplicate_column_names`] to the root columns
+    fn with_column_names(mut self, options: &ArrowReaderOptions) -> Result<Self> {
+        self.case_insensitive = options.case_insensitive;
+        if options.duplicate_column_names == DuplicateColumnNames::Allow {
+            return Ok(self);
+        }
+
+        let case_insensitive = self.case_insensitive;
+        let normalize = |name: &str| match case_insensitive {
+            true => name.to_lowercase(),
+            false => name.to_string(),
+        };
+        // The names of all columns, including the renamed ones, which the suffixed names
+        // must not collide with
+        let mut names: HashSet<_> = self
+            .schema
+            .fields()
+            .iter()
+            .map(|f| normalize(f.name()))
+            .collect();
+        let mut seen = HashSet::with_capacity(names.len());
+        let mut renamed = vec![];
+        for (idx, field) in self.schema.fields().iter().enumerate() {
+            if seen.insert(normalize(field.name())) {
+                continue;
+            }
+            match options.duplicate_column_names {
+                DuplicateColumnNames::Allow => {}
+                DuplicateColumnNames::Error => {
+                    return Err(arrow_err!(
+                        "Duplicate column name {} at index {}",
+                        field.name(),
+                        idx
+                    ))
+                }
+                DuplicateColumnNames::Suffix => {
+                    let name = (idx..)
+                        .map(|suffix| format!("{}_{suffix}", field.name()))
+                        .find(|name| names.insert(normalize(name)))
+                        .unwrap();
+                    renamed.push((idx, name));
+                }
+            }
+        }
+        if renamed.is_empty() {
+            return Ok(self);
+        }
+
+        let rename = |fields: &Fields| {
+            let mut fields: Vec<_> = fields.iter().cloned().collect();
+            for (idx, name) in &renamed {
+                fields[*idx] = Arc::new(fields[*idx].as_ref().clone().with_name(name));
+            }
+            Fields::from(fields)
+        };
+        self.schema = Arc::new(Schema::new_with_metadata(
+            rename(self.schema.fields()),
+            self.schema.metadata().clone(),
+        ));
+        // The record batches read take their names from the root field
+        if let Some(field) = &mut self.fields {
+            let field = Arc::make_mut(field);
+            if let ArrowType::Struct(fields) = &field.arrow_type {
+                field.arrow_type = ArrowType::Struct(rename(fields));
             }
         }
+        Ok(self)
     }
 
     fn with_supplied_schema(
         metadata: Arc<ParquetMetaData>,
         supplied_schema: SchemaRef,
+        legacy_two_level_lists: bool,
     ) -> Result<Self> {
         let parquet_schema = metadata.file_metadata().schema_descr();
-        let field_levels = parquet_to_arrow_field_levels(
+        let field_levels = convert_field_levels(
             parquet_schema,
             ProjectionMask::all(),
             Some(supplied_schema.fields()),
+            legacy_two_level_lists,
         )?;
         let fields = field_levels.fields;
         let inferred_len = fields.len();
         let supplied_len = supplied_schema.fields().len();
         // Ensure the supplied schema has the same number of columns as the parquet schema.
-        // parquet_to_arrow_field_levels is expected to throw an error if the schemas have
+        // convert_field_levels is expected to throw an error if the schemas have
         // different lengths, but we check here to be safe.
         if inferred_len != supplied_len {
             return Err(arrow_err!(format!(
@@ -587,6 +1163,8 @@ impl ArrowReaderMetadata {
             metadata,
             schema: supplied_schema,
             fields: field_levels.levels.map(Arc::new),
+            case_insensitive: false,
+            codec_factory: None,
         })
     }
 
@@ -604,6 +1182,35 @@ impl ArrowReaderMetadata {
     pub fn schema(&self) -> &SchemaRef {
         &self.schema
     }
+
+    /// Returns the fingerprint of the arrow schema stored in this parquet file, if any
+    ///
+    /// This is the fingerprint stored in [`ARROW_SCHEMA_FINGERPRINT_META_KEY`] by the
+    /// [`ArrowWriter`], which is compared without decoding the schema of the files. Files
+    /// with the same fingerprint have the same fields.
+    ///
+    /// Returns `None` if the file has no stored fingerprint, e.g. if it was written without
+    /// the arrow schema. It is not computed from [`Self::schema`] then, as the schema
+    /// inferred from the parquet schema may differ from the one the file was written with,
+    /// and so its [`arrow_schema_fingerprint`] may not match that of the same data written
+    /// with the arrow schema.
+    ///
+    /// [`ARROW_SCHEMA_FINGERPRINT_META_KEY`]: crate::arrow::ARROW_SCHEMA_FINGERPRINT_META_KEY
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    /// [`arrow_schema_fingerprint`]: crate::arrow::arrow_schema_fingerprint
+    pub fn schema_fingerprint(&self) -> Option<u64> {
+        schema_fingerprint(&self.metadata)
+    }
+}
+
+/// Returns the stored fingerprint of the arrow schema of a file
+fn schema_fingerprint(metadata: &ParquetMetaData) -> Option<u64> {
+    metadata
+        .file_metadata()
+        .key_value_metadata()?
+        .iter()
+        .find(|kv| kv.key == crate::arrow::ARROW_SCHEMA_FINGERPRINT_META_KEY)
+        .and_then(|kv| u64::from_str_radix(kv.value.as_deref()?, 16).ok())
 }
 
 #[doc(hidden)]
@@ -712,22 +1319,36 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .batch_size
             .min(self.metadata.file_metadata().num_rows() as usize);
 
-        let row_groups = self
+        let mut row_groups = self
             .row_groups
+            .clone()
             .unwrap_or_else(|| (0..self.metadata.num_row_groups()).collect());
+        let (mut selection, mut report) = self.pruned_selection(&row_groups)?;
+        if let Some(row_group_filter) = &self.row_group_filter {
+            (row_groups, selection) = filter_row_groups(
+                &self.metadata,
+                row_groups,
+                selection,
+                row_group_filter,
+                &mut report,
+            );
+        }
 
         let reader = ReaderRowGroups {
+            props: self.reader_properties(),
             reader: Arc::new(self.input.0),
             metadata: self.metadata,
             row_groups,
         };
 
         let mut filter = self.filter;
-        let mut plan_builder = ReadPlanBuilder::new(batch_size).with_selection(self.selection);
+        let mut plan_builder = ReadPlanBuilder::new(batch_size)
+            .with_max_batch_bytes(self.max_batch_bytes)
+            .with_selection(selection);
 
         // Update selection based on any filters
         if let Some(filter) = filter.as_mut() {
-            for predicate in filter.predicates.iter_mut() {
+            for (idx, predicate) in filter.predicates.iter_mut().enumerate() {
                 // break early if we have ruled out all rows
                 if !plan_builder.selects_any() {
                     break;
@@ -736,7 +1357,14 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
                 let array_reader = ArrayReaderBuilder::new(&reader)
                     .build_array_reader(self.fields.as_deref(), predicate.projection())?;
 
+                let rows_before = plan_builder
+                    .num_rows_selected()
+                    .unwrap_or(reader.num_rows());
                 plan_builder = plan_builder.with_predicate(array_reader, predicate.as_mut())?;
+                let rows_after = plan_builder
+                    .num_rows_selected()
+                    .unwrap_or(reader.num_rows());
+                report.record_row_filter(idx, rows_before, rows_after);
             }
         }
 
@@ -750,16 +1378,101 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .build_limited()
             .build();
 
-        Ok(ParquetRecordBatchReader::new(array_reader, read_plan))
+        let mut reader = ParquetRecordBatchReader::new(array_reader, read_plan);
+        reader.pruning_report = report;
+        Ok(reader)
     }
 }
 
+/// A callback deciding whether to read a row group, see
+/// [`ArrowReaderBuilder::with_row_group_filter`]
+pub(crate) type RowGroupFilter = Box<dyn Fn(&RowGroupMetaData) -> bool + Send + Sync>;
+
+/// Returns the [`RowSelection`] of the rows of `row_groups` that are not in `deleted_rows`,
+/// the sorted positions of the deleted rows within the file
+fn remaining_rows(
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    deleted_rows: &[u64],
+) -> Result<RowSelection> {
+    let mut first_rows = Vec::with_capacity(metadata.num_row_groups());
+    let mut next_row = 0;
+    for row_group in metadata.row_groups() {
+        first_rows.push(next_row);
+        next_row += row_group.num_rows() as u64;
+    }
+
+    let mut ranges = vec![];
+    let mut offset = 0;
+    for &idx in row_groups {
+        let Some(&first_row) = first_rows.get(idx) else {
+            return Err(general_err!(
+                "row group {} out of bounds 0..{}",
+                idx,
+                first_rows.len()
+            ));
+        };
+        let num_rows = metadata.row_group(idx).num_rows() as usize;
+        let start = deleted_rows.partition_point(|row| *row < first_row);
+        let end = deleted_rows.partition_point(|row| *row < first_row + num_rows as u64);
+
+        let mut next_row = 0;
+        for row in &deleted_rows[start..end] {
+            let row = (row - first_row) as usize;
+            ranges.push(offset + next_row..offset + row);
+            next_row = row + 1;
+        }
+        ranges.push(offset + next_row..offset + num_rows);
+        offset += num_rows;
+    }
+    Ok(RowSelection::from_consecutive_ranges(
+        ranges.into_iter(),
+        offset,
+    ))
+}
+
+/// Returns the `row_groups` for which `filter` returns true, along with `selection` without
+/// the rows of the row groups that were filtered out
+fn filter_row_groups(
+    metadata: &ParquetMetaData,
+    row_groups: Vec<usize>,
+    mut selection: Option<RowSelection>,
+    filter: &RowGroupFilter,
+    report: &mut PruningReport,
+) -> (Vec<usize>, Option<RowSelection>) {
+    let mut selectors = vec![];
+    let row_groups = row_groups
+        .into_iter()
+        .filter(|idx| {
+            let row_group = metadata.row_group(*idx);
+            let row_group_selection = selection
+                .as_mut()
+                .map(|s| s.split_off(row_group.num_rows() as usize));
+            let keep = filter(row_group);
+            if !keep {
+                report.skip_row_group(*idx);
+            }
+            if let Some(row_group_selection) = row_group_selection.filter(|_| keep) {
+                selectors.extend(row_group_selection.iter().copied());
+            }
+            keep
+        })
+        .collect();
+    let selection = selection.map(|remaining| {
+        selectors.extend(remaining.iter().copied());
+        RowSelection::from(selectors)
+    });
+    (row_groups, selection)
+}
+
 struct ReaderRowGroups<T: ChunkReader> {
     reader: Arc<T>,
 
     metadata: Arc<ParquetMetaData>,
     /// Optional list of row group indices to scan
     row_groups: Vec<usize>,
+    /// Properties used to create the page readers
+    props: ReaderPropertiesPtr,
 }
 
 impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
@@ -777,6 +1490,7 @@ impl<T: ChunkReader + 'static> RowGroups for ReaderRowGroups<T> {
             reader: self.reader.clone(),
             metadata: self.metadata.clone(),
             row_groups: self.row_groups.clone().into_iter(),
+            props: Arc::clone(&self.props),
         }))
     }
 }
@@ -786,6 +1500,7 @@ struct ReaderPageIterator<T: ChunkReader> {
     column_idx: usize,
     row_groups: std::vec::IntoIter<usize>,
     metadata: Arc<ParquetMetaData>,
+    props: ReaderPropertiesPtr,
 }
 
 impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
@@ -802,13 +1517,19 @@ impl<T: ChunkReader + 'static> ReaderPageIterator<T> {
         let total_rows = rg.num_rows() as usize;
         let reader = self.reader.clone();
 
-        SerializedPageReader::new(reader, column_chunk_metadata, total_rows, page_locations)?
-            .add_crypto_context(
-                rg_idx,
-                self.column_idx,
-                self.metadata.as_ref(),
-                column_chunk_metadata,
-            )
+        SerializedPageReader::new_with_properties(
+            reader,
+            column_chunk_metadata,
+            total_rows,
+            page_locations,
+            Arc::clone(&self.props),
+        )?
+        .add_crypto_context(
+            rg_idx,
+            self.column_idx,
+            self.metadata.as_ref(),
+            column_chunk_metadata,
+        )
     }
 }
 
@@ -832,6 +1553,12 @@ pub struct ParquetRecordBatchReader {
     array_reader: Box<dyn ArrayReader>,
     schema: SchemaRef,
     read_plan: ReadPlan,
+    /// Decoded bytes per row of the last batch, if limiting the bytes per batch
+    row_bytes: Option<usize>,
+    /// Batches split from the last batch, still to be returned
+    pending: VecDeque<RecordBatch>,
+    /// What was pruned when building the reader
+    pruning_report: PruningReport,
 }
 
 impl Iterator for ParquetRecordBatchReader {
@@ -851,8 +1578,34 @@ impl ParquetRecordBatchReader {
     /// Returns `Result<Option<..>>` rather than `Option<Result<..>>` to
     /// simplify error handling with `?`
     fn next_inner(&mut self) -> Result<Option<RecordBatch>> {
+        if let Some(batch) = self.pending.pop_front() {
+            return Ok(Some(batch));
+        }
+        let Some(max_batch_bytes) = self.read_plan.max_batch_bytes() else {
+            return self.read_batch(self.batch_size());
+        };
+
+        let batch_size = match self.row_bytes {
+            Some(row_bytes) => (max_batch_bytes / row_bytes.max(1)).clamp(1, self.batch_size()),
+            None => self.batch_size(),
+        };
+        let Some(batch) = self.read_batch(batch_size)? else {
+            return Ok(None);
+        };
+
+        let batch_bytes = batch_bytes(&batch)?;
+        self.row_bytes = Some(batch_bytes.div_ceil(batch.num_rows()));
+        if batch_bytes > max_batch_bytes {
+            split_batch(batch, max_batch_bytes, &mut self.pending)?;
+            return Ok(self.pending.pop_front());
+        }
+        Ok(Some(batch))
+    }
+
+    /// Reads a batch of at most `batch_size` rows, or returns `None` if the reader has
+    /// reached the end of the file.
+    fn read_batch(&mut self, batch_size: usize) -> Result<Option<RecordBatch>> {
         let mut read_records = 0;
-        let batch_size = self.batch_size();
         match self.read_plan.selection_mut() {
             Some(selection) => {
                 while read_records < batch_size && !selection.is_empty() {
@@ -911,6 +1664,28 @@ impl ParquetRecordBatchReader {
     }
 }
 
+/// Returns the number of decoded bytes of `batch`
+fn batch_bytes(batch: &RecordBatch) -> Result<usize> {
+    let data = StructArray::from(batch.clone()).into_data();
+    Ok(data.get_slice_memory_size()?)
+}
+
+/// Splits `batch` in halves until each part is at most `max_bytes`, or a single row
+fn split_batch(
+    batch: RecordBatch,
+    max_bytes: usize,
+    batches: &mut VecDeque<RecordBatch>,
+) -> Result<()> {
+    let num_rows = batch.num_rows();
+    if num_rows <= 1 || batch_bytes(&batch)? <= max_bytes {
+        batches.push_back(batch);
+        return Ok(());
+    }
+    let mid = num_rows / 2;
+    split_batch(batch.slice(0, mid), max_bytes, batches)?;
+    split_batch(batch.slice(mid, num_rows - mid), max_bytes, batches)
+}
+
 impl RecordBatchReader for ParquetRecordBatchReader {
     /// Returns the projected [`SchemaRef`] for reading the parquet file.
     ///
@@ -952,6 +1727,9 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(Schema::new(levels.fields.clone())),
             read_plan,
+            row_bytes: None,
+            pending: VecDeque::new(),
+            pruning_report: PruningReport::default(),
         })
     }
 
@@ -968,9 +1746,22 @@ impl ParquetRecordBatchReader {
             array_reader,
             schema: Arc::new(schema),
             read_plan,
+            row_bytes: None,
+            pending: VecDeque::new(),
+            pruning_report: PruningReport::default(),
         }
     }
 
+    /// Returns a [`PruningReport`] of the row groups, pages and rows pruned by the filters
+    /// and predicates of the [`ParquetRecordBatchReaderBuilder`] of this reader
+    ///
+    /// The report is empty for readers that are not built by a
+    /// [`ParquetRecordBatchReaderBuilder`], such as those returned by
+    /// `ParquetRecordBatchStream::next_row_group`.
+    pub fn pruning_report(&self) -> &PruningReport {
+        &self.pruning_report
+    }
+
     #[inline(always)]
     pub(crate) fn batch_size(&self) -> usize {
         self.read_plan.batch_size()
@@ -1007,22 +1798,24 @@ mod tests {
     use tempfile::tempfile;
 
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReader,
+        ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions, DuplicateColumnNames,
+        PagePruningPredicate, PageStatistics, ParquetRecordBatchReader,
         ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
     };
     use crate::arrow::schema::add_encoded_arrow_schema_to_metadata;
-    use crate::arrow::{ArrowWriter, ProjectionMask};
+    use crate::arrow::{ArrowWriter, ProjectionMask, PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY};
     use crate::basic::{ConvertedType, Encoding, Repetition, Type as PhysicalType};
     use crate::column::reader::decoder::REPETITION_LEVELS_BATCH_SIZE;
     use crate::data_type::{
         BoolType, ByteArray, ByteArrayType, DataType, FixedLenByteArray, FixedLenByteArrayType,
         FloatType, Int32Type, Int64Type, Int96, Int96Type,
     };
-    use crate::errors::Result;
+    use crate::errors::{ParquetError, Result};
     use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
     use crate::file::writer::SerializedFileWriter;
     use crate::schema::parser::parse_message_type;
     use crate::schema::types::{Type, TypePtr};
+    use crate::util::test_common::codec_util::XorCodecFactory;
     use crate::util::test_common::rand_gen::RandGen;
 
     #[test]
@@ -2952,6 +3745,160 @@ mod tests {
         assert_eq!(batch.column(0).null_count(), 2);
     }
 
+    #[test]
+    fn test_legacy_two_level_lists() {
+        let message_type = "message legacy {
+          OPTIONAL GROUP points (LIST) {
+            REPEATED GROUP element {
+              OPTIONAL INT32 x;
+            }
+          }
+          OPTIONAL GROUP matrix (LIST) {
+            REPEATED GROUP array (LIST) {
+              REPEATED INT32 array;
+            }
+          }
+        }";
+
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let mut file = Vec::new();
+        {
+            let mut writer =
+                SerializedFileWriter::new(&mut file, schema, Default::default()).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+
+            // [{x: 1}, {x: null}], null, []
+            let mut column_writer = row_group_writer.next_column().unwrap().unwrap();
+            column_writer
+                .typed::<Int32Type>()
+                .write_batch(&[1], Some(&[3, 2, 0, 1]), Some(&[0, 1, 0, 0]))
+                .unwrap();
+            column_writer.close().unwrap();
+
+            // [[1, 2], [3]], [[]], null
+            let mut column_writer = row_group_writer.next_column().unwrap().unwrap();
+            column_writer
+                .typed::<Int32Type>()
+                .write_batch(&[1, 2, 3], Some(&[3, 3, 3, 2, 0]), Some(&[0, 2, 1, 0, 0]))
+                .unwrap();
+            column_writer.close().unwrap();
+
+            row_group_writer.close().unwrap();
+            writer.close().unwrap();
+        }
+        let file = Bytes::from(file);
+
+        // The repeated group is not named array, so its field is the element by default
+        let reader = ParquetRecordBatchReader::try_new(file.clone(), 1024).unwrap();
+        let batch = reader.into_iter().next().unwrap().unwrap();
+        let points = batch.column(0).as_list::<i32>();
+        assert_eq!(
+            points.data_type(),
+            &ArrowDataType::List(Arc::new(Field::new("x", ArrowDataType::Int32, true)))
+        );
+        assert_eq!(
+            points.values().as_ref(),
+            &Int32Array::from(vec![Some(1), None])
+        );
+        let matrix = batch.column(1).clone();
+
+        let options = ArrowReaderOptions::new().with_legacy_two_level_lists(true);
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batch = reader.into_iter().next().unwrap().unwrap();
+
+        let x = Field::new("x", ArrowDataType::Int32, true);
+        let element = Field::new_struct("element", vec![x.clone()], false);
+        let points = batch.column(0).as_list::<i32>();
+        assert_eq!(points.data_type(), &ArrowDataType::List(Arc::new(element)));
+        assert_eq!(points.value_offsets(), &[0, 2, 2, 2]);
+        assert_eq!(points.nulls().unwrap().null_count(), 1);
+        let expected = StructArray::from(vec![(
+            Arc::new(x),
+            Arc::new(Int32Array::from(vec![Some(1), None])) as ArrayRef,
+        )]);
+        assert_eq!(points.values().as_struct(), &expected);
+
+        // Nested lists are not affected
+        assert_eq!(batch.column(1), &matrix);
+        let matrix = matrix.as_list::<i32>();
+        assert_eq!(matrix.value_offsets(), &[0, 2, 3, 3]);
+        assert_eq!(matrix.nulls().unwrap().null_count(), 1);
+        let rows = matrix.values().as_list::<i32>();
+        assert_eq!(rows.value_offsets(), &[0, 2, 3, 3]);
+        assert_eq!(rows.values().as_ref(), &Int32Array::from(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_read_unknown_logical_type() {
+        let message_type = "message schema {
+          REQUIRED FIXED_LEN_BYTE_ARRAY (16) id (UUID);
+        }";
+        let schema = Arc::new(parse_message_type(message_type).unwrap());
+        let mut file = Vec::new();
+        {
+            let mut writer =
+                SerializedFileWriter::new(&mut file, schema, Default::default()).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            let mut column_writer = row_group_writer.next_column().unwrap().unwrap();
+            let value = FixedLenByteArray::from(vec![7; 16]);
+            column_writer
+                .typed::<FixedLenByteArrayType>()
+                .write_batch(&[value], None, None)
+                .unwrap();
+            column_writer.close().unwrap();
+            row_group_writer.close().unwrap();
+            writer.close().unwrap();
+        }
+
+        // Replace the UUID logical type with the member of the union with field id 100, as
+        // written for a logical type added to the format after this crate
+        let metadata_len = u32::from_le_bytes(file[file.len() - 8..][..4].try_into().unwrap());
+        let metadata_start = file.len() - 8 - metadata_len as usize;
+        let mut metadata = file.split_off(metadata_start);
+        metadata.truncate(metadata_len as usize);
+        let uuid = [0xEC, 0x00, 0x00];
+        let position = metadata.windows(3).position(|w| w == uuid).unwrap();
+        let unknown_logical_type = [0x0C, 0xC8, 0x01, 0x00, 0x00];
+        metadata.splice(position..position + 3, unknown_logical_type);
+        file.extend_from_slice(&metadata);
+        file.extend_from_slice(&(metadata.len() as u32).to_le_bytes());
+        file.extend_from_slice(b"PAR1");
+        let file = Bytes::from(file);
+
+        let err = ParquetRecordBatchReaderBuilder::try_new(file.clone()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Could not parse metadata: schema element id is annotated with an \
+            unknown logical type, use `with_ignore_unknown_logical_types` to read it as its \
+            physical type"
+        );
+
+        // The column is read as its physical type
+        let options = ArrowReaderOptions::new().with_ignore_unknown_logical_types(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).unwrap();
+        let column = builder.metadata().file_metadata().schema_descr().column(0);
+        let basic_info = column.self_type().get_basic_info();
+        assert_eq!(basic_info.logical_type(), None);
+        assert_eq!(
+            basic_info.unknown_logical_type(),
+            Some(unknown_logical_type.as_slice())
+        );
+
+        let field = builder.schema().field(0);
+        assert_eq!(field.data_type(), &ArrowDataType::FixedSizeBinary(16));
+        assert_eq!(
+            field.metadata().get(PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY),
+            Some(&"DMgBAAA=".to_string())
+        );
+
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        let ids = batch.column(0).as_fixed_size_binary();
+        assert_eq!(ids.value(0), &[7; 16]);
+    }
+
     #[test]
     fn test_invalid_utf8() {
         // a parquet file with 1 column with invalid utf8
@@ -4046,6 +4993,337 @@ mod tests {
         assert_eq!(reader.read_plan.batch_size(), num_rows as usize);
     }
 
+    #[test]
+    fn test_max_batch_bytes() {
+        // 100 rows of 1000 byte strings, followed by 900 rows of 10 byte strings
+        let values = (0..1000).map(|i| {
+            let len = if i < 100 { 1000 } else { 10 };
+            Some(format!("{i:0>len$}"))
+        });
+        let batch = RecordBatch::try_from_iter([
+            ("s", Arc::new(StringArray::from_iter(values)) as ArrayRef),
+            (
+                "i",
+                Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let buf = Bytes::from(buf);
+
+        let max_batch_bytes = 20_000;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(buf.clone())
+            .unwrap()
+            .with_batch_size(500)
+            .with_max_batch_bytes(max_batch_bytes)
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        for batch in &batches {
+            let size = StructArray::from(batch.clone())
+                .to_data()
+                .get_slice_memory_size()
+                .unwrap();
+            assert!(batch.num_rows() <= 500);
+            assert!(size <= max_batch_bytes, "{size} > {max_batch_bytes}");
+        }
+        // Small rows are read in larger batches
+        assert!(batches.iter().any(|b| b.num_rows() > 200));
+        assert_eq!(concat_batches(&batch.schema(), &batches).unwrap(), batch);
+
+        // Also with a selection, and a limit smaller than a single row
+        let selection = RowSelection::from(vec![RowSelector::skip(50), RowSelector::select(100)]);
+        let reader = ParquetRecordBatchReaderBuilder::try_new(buf)
+            .unwrap()
+            .with_max_batch_bytes(1)
+            .with_row_selection(selection)
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert!(batches.iter().all(|b| b.num_rows() == 1));
+        assert_eq!(
+            concat_batches(&batch.schema(), &batches).unwrap(),
+            batch.slice(50, 100)
+        );
+    }
+
+    /// Matches the pages whose minimum of `column` is even
+    #[derive(Debug)]
+    struct EvenMinPages(&'static str);
+
+    impl PagePruningPredicate for EvenMinPages {
+        fn column(&self) -> &str {
+            self.0
+        }
+
+        fn evaluate(&self, statistics: &PageStatistics) -> Result<BooleanArray> {
+            assert_eq!(statistics.row_counts().values(), &vec![2; statistics.len()]);
+            let mins = statistics.mins().as_primitive::<types::Int32Type>();
+            Ok(mins.iter().map(|min| min.map(|min| min % 2 == 0)).collect())
+        }
+    }
+
+    #[test]
+    fn test_page_pruning_predicate() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values([
+                    0, 1, 3, 5, 6, 7, 8, 9, 11, 13, 14, 15,
+                ])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(Int32Array::from_iter_values(0..12)) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(6)
+            .set_data_page_row_count_limit(2)
+            .set_write_batch_size(2)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |builder: ParquetRecordBatchReaderBuilder<Bytes>| {
+            let batches = builder
+                .with_page_pruning_predicate(Arc::new(EvenMinPages("a")))
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let batch = concat_batches(&batch.schema(), &batches).unwrap();
+            batch
+                .column(1)
+                .as_primitive::<types::Int32Type>()
+                .values()
+                .to_vec()
+        };
+
+        // Without the page index, no pages are skipped
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        assert_eq!(read(builder), (0..12).collect::<Vec<_>>());
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options.clone())
+                .unwrap();
+        assert_eq!(read(builder), [0, 1, 4, 5, 6, 7, 10, 11]);
+
+        // The pruned pages are intersected with the selection of the selected row groups
+        let selection = RowSelection::from(vec![RowSelector::skip(5), RowSelector::select(1)]);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+            .unwrap()
+            .with_row_groups(vec![1])
+            .with_row_selection(selection);
+        assert_eq!(read(builder), [11]);
+
+        // The column must be in the arrow schema
+        let predicate = EvenMinPages("c");
+        let metadata = crate::arrow::arrow_reader::ArrowReaderMetadata::load(
+            &data,
+            ArrowReaderOptions::new().with_page_index(true),
+        )
+        .unwrap();
+        let err = crate::arrow::arrow_reader::prune_pages(
+            metadata.metadata(),
+            metadata.schema(),
+            &[0],
+            &predicate,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Column 'c' not found"), "{err}");
+    }
+
+    #[test]
+    fn test_pruning_report() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "a",
+                Arc::new(Int32Array::from_iter_values([
+                    0, 1, 3, 5, 6, 7, 8, 9, 11, 13, 14, 15,
+                ])) as ArrayRef,
+            ),
+            (
+                "b",
+                Arc::new(Int32Array::from_iter_values(0..12)) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(4)
+            .set_data_page_row_count_limit(2)
+            .set_write_batch_size(2)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+                .unwrap();
+        let predicate =
+            ArrowPredicateFn::new(ProjectionMask::leaves(builder.parquet_schema(), [0]), |b| {
+                let a = b.column(0).as_primitive::<types::Int32Type>();
+                Ok(BooleanArray::from_unary(a, |a| a > 5))
+            });
+        let reader = builder
+            .with_page_pruning_predicate(Arc::new(EvenMinPages("a")))
+            .with_row_group_filter(|rg| rg.ordinal() != Some(1))
+            .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+            .build()
+            .unwrap();
+
+        let report = reader.pruning_report().clone();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let batch = concat_batches(&batch.schema(), &batches).unwrap();
+        assert_eq!(
+            batch.column(1).as_primitive::<types::Int32Type>().values(),
+            &[10, 11]
+        );
+
+        assert_eq!(report.skipped_row_groups(), &[1]);
+
+        // The page predicates are evaluated on all the row groups
+        let [a] = report.page_predicates() else {
+            panic!("expected 1 page predicate")
+        };
+        assert_eq!(a.column(), "a");
+        assert!(a.is_evaluated());
+        assert_eq!((a.pages(), a.pruned_pages(), a.pruned_rows()), (6, 2, 4));
+
+        // The row filter is only evaluated on the rows that were not pruned
+        let [filter] = report.row_filter_predicates() else {
+            panic!("expected 1 row filter predicate")
+        };
+        assert_eq!(filter.rows_evaluated(), 4);
+        assert_eq!(filter.rows_selected(), 2);
+        assert_eq!(filter.rows_pruned(), 2);
+    }
+
+    #[test]
+    fn test_row_group_filter() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..8)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |builder: ParquetRecordBatchReaderBuilder<Bytes>| {
+            let batches = builder
+                .with_row_group_filter(|rg| rg.ordinal().is_some_and(|o| o % 2 == 1))
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let batch = concat_batches(&batch.schema(), &batches).unwrap();
+            batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .values()
+                .to_vec()
+        };
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        assert_eq!(builder.metadata().num_row_groups(), 4);
+        assert_eq!(read(builder), [2, 3, 6, 7]);
+
+        // The filter is applied after the explicitly selected row groups
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .with_row_groups(vec![0, 1, 2]);
+        assert_eq!(read(builder), [2, 3]);
+
+        // The selection includes the rows of row groups skipped by the filter
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(3),
+            RowSelector::select(3),
+            RowSelector::skip(1),
+            RowSelector::select(1),
+        ]);
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_row_group_filter(|_| true)
+            .with_row_groups(vec![0, 1, 2, 3])
+            .with_row_selection(selection);
+        assert_eq!(read(builder), [3, 7]);
+    }
+
+    #[test]
+    fn test_deleted_rows() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..30)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |builder: ParquetRecordBatchReaderBuilder<Bytes>| {
+            let batches = builder
+                .with_deleted_rows([13, 3, 29, 12, 100, 3, 20])
+                .build()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let batch = concat_batches(&batch.schema(), &batches).unwrap();
+            batch
+                .column(0)
+                .as_primitive::<arrow_array::types::Int32Type>()
+                .values()
+                .to_vec()
+        };
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let expected: Vec<_> = (0..30)
+            .filter(|x| ![3, 12, 13, 20, 29].contains(x))
+            .collect();
+        assert_eq!(read(builder), expected);
+
+        // The positions of the deleted rows are independent of the selected row groups
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .with_row_groups(vec![2, 1]);
+        let expected: Vec<_> = (21..29).chain(10..12).chain(14..20).collect();
+        assert_eq!(read(builder), expected);
+
+        // The deleted rows are intersected with the row selection and skipped by filters
+        let selection = RowSelection::from(vec![RowSelector::select(5), RowSelector::skip(5)]);
+        let filter = ArrowPredicateFn::new(ProjectionMask::all(), |batch| {
+            assert!(batch.num_rows() <= 3);
+            Ok(BooleanArray::from(vec![true; batch.num_rows()]))
+        });
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_row_groups(vec![1])
+            .with_row_selection(selection)
+            .with_row_filter(RowFilter::new(vec![Box::new(filter)]));
+        assert_eq!(read(builder), [10, 11, 14]);
+    }
+
     #[test]
     fn test_read_with_page_index_enabled() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -4648,4 +5926,118 @@ mod tests {
         assert_eq!(c0.len(), c1.len());
         c0.iter().zip(c1.iter()).for_each(|(l, r)| assert_eq!(l, r));
     }
+
+    #[test]
+    fn test_projected_columns() {
+        let columns = [("a", 1), ("B", 2), ("b", 3)]
+            .map(|(name, v)| (name, Arc::new(Int32Array::from(vec![v])) as ArrayRef));
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+        let mut buffer = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buffer);
+
+        let read = |options: ArrowReaderOptions, names: &[&str]| {
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)?
+                .with_projected_columns(names.iter().copied())?
+                .build()?
+                .next()
+                .unwrap()
+                .map_err(ParquetError::from)
+        };
+        let values = |batch: &RecordBatch| -> Vec<(String, i32)> {
+            let schema = batch.schema();
+            let columns = batch
+                .columns()
+                .iter()
+                .map(|c| c.as_primitive::<arrow_array::types::Int32Type>());
+            let fields = schema.fields().iter();
+            fields
+                .zip(columns)
+                .map(|(f, c)| (f.name().clone(), c.value(0)))
+                .collect()
+        };
+
+        let batch = read(ArrowReaderOptions::new(), &["b", "a"]).unwrap();
+        assert_eq!(values(&batch), [("a".to_string(), 1), ("b".to_string(), 3)]);
+        let err = read(ArrowReaderOptions::new(), &["A"]).unwrap_err();
+        assert_eq!(err.to_string(), "Arrow: No column named A");
+
+        let options = ArrowReaderOptions::new().with_case_insensitive_projection(true);
+        let batch = read(options.clone(), &["A"]).unwrap();
+        assert_eq!(values(&batch), [("a".to_string(), 1)]);
+        let err = read(options.clone(), &["b"]).unwrap_err();
+        assert_eq!(err.to_string(), "Arrow: Ambiguous column name b");
+
+        // Only the columns after the first with the same name are renamed
+        let options = options.with_duplicate_column_names(DuplicateColumnNames::Suffix);
+        let batch = read(options.clone(), &["b", "b_2"]).unwrap();
+        assert_eq!(
+            values(&batch),
+            [("B".to_string(), 2), ("b_2".to_string(), 3)]
+        );
+
+        let options = options.with_duplicate_column_names(DuplicateColumnNames::Error);
+        let err = read(options, &[]).unwrap_err();
+        assert_eq!(err.to_string(), "Arrow: Duplicate column name b at index 2");
+
+        // Without case-insensitive matching, the columns are not duplicates
+        let options =
+            ArrowReaderOptions::new().with_duplicate_column_names(DuplicateColumnNames::Error);
+        let batch = read(options, &["B"]).unwrap();
+        assert_eq!(values(&batch), [("B".to_string(), 2)]);
+    }
+
+    #[test]
+    fn test_duplicate_column_names_suffix_collision() {
+        let columns = [("a", 1), ("a", 2), ("a_1", 3), ("a_2", 4)]
+            .map(|(name, v)| (name, Arc::new(Int32Array::from(vec![v])) as ArrayRef));
+        let batch = RecordBatch::try_from_iter(columns).unwrap();
+        let mut buffer = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        // The suffix is incremented until the name differs from the other columns
+        let options =
+            ArrowReaderOptions::new().with_duplicate_column_names(DuplicateColumnNames::Suffix);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buffer), options)
+                .unwrap();
+        let names: Vec<_> = builder.schema().fields().iter().map(|f| f.name()).collect();
+        assert_eq!(names, ["a", "a_3", "a_1", "a_2"]);
+    }
+
+    #[test]
+    fn test_codec_factory() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_compression(crate::basic::Compression::LZO)
+            .set_codec_factory(Arc::new(XorCodecFactory))
+            .set_max_row_group_size(30)
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Reading without the factory fails as there is no built-in LZO codec
+        let mut reader = ParquetRecordBatchReader::try_new(data.clone(), 1024).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(err.to_string().contains("not supported"), "{err}");
+
+        let options = ArrowReaderOptions::new().with_codec_factory(Arc::new(XorCodecFactory));
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(concat_batches(&batch.schema(), &batches).unwrap(), batch);
+    }
 }
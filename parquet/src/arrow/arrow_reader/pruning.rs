@@ -0,0 +1,409 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Skipping data pages with user supplied predicates evaluated on the page index, and
+//! reporting what was pruned
+
+use std::fmt::Debug;
+use std::ops::Range;
+
+use arrow_array::{Array, ArrayRef, BooleanArray, UInt64Array};
+use arrow_schema::Schema;
+
+use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+use crate::arrow::arrow_reader::RowSelection;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+use crate::file::page_index::offset_index::OffsetIndexMetaData;
+
+/// The statistics of the data pages of a column, read from the page index, with one
+/// element per data page of the row groups being pruned
+///
+/// See [`PagePruningPredicate`]
+#[derive(Debug, Clone)]
+pub struct PageStatistics {
+    mins: ArrayRef,
+    maxes: ArrayRef,
+    null_counts: UInt64Array,
+    row_counts: UInt64Array,
+}
+
+impl PageStatistics {
+    /// Returns the number of data pages
+    pub fn len(&self) -> usize {
+        self.row_counts.len()
+    }
+
+    /// Returns true if there are no data pages
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the minimum value of each page, which is null if unknown or if all the
+    /// values of the page are null
+    pub fn mins(&self) -> &ArrayRef {
+        &self.mins
+    }
+
+    /// Returns the maximum value of each page, which is null if unknown or if all the
+    /// values of the page are null
+    pub fn maxes(&self) -> &ArrayRef {
+        &self.maxes
+    }
+
+    /// Returns the number of null values of each page, which is null if unknown
+    pub fn null_counts(&self) -> &UInt64Array {
+        &self.null_counts
+    }
+
+    /// Returns the number of rows of each page
+    pub fn row_counts(&self) -> &UInt64Array {
+        &self.row_counts
+    }
+}
+
+/// A predicate evaluated on the [`PageStatistics`] of a column to skip the data pages
+/// that can not contain matching rows
+///
+/// This allows query engines to skip pages based on arbitrary expressions, which are
+/// evaluated on the page index of the column instead of the decoded rows. Page pruning
+/// predicates are added to a reader with [`ArrowReaderBuilder::with_page_pruning_predicate`],
+/// or evaluated directly with [`prune_pages`]. They require the page index, see
+/// [`ArrowReaderOptions::with_page_index`], and have no effect without it.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{cast::AsArray, types::Int32Type, ArrayRef, BooleanArray, Int32Array, RecordBatch};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::arrow_reader::{
+/// #     ArrowReaderOptions, PagePruningPredicate, PageStatistics, ParquetRecordBatchReaderBuilder,
+/// # };
+/// # use parquet::arrow::ArrowWriter;
+/// # use parquet::errors::Result;
+/// # use parquet::file::properties::WriterProperties;
+/// /// Matches the rows where `column > value`
+/// #[derive(Debug)]
+/// struct GreaterThan {
+///     column: String,
+///     value: i32,
+/// }
+///
+/// impl PagePruningPredicate for GreaterThan {
+///     fn column(&self) -> &str {
+///         &self.column
+///     }
+///
+///     fn evaluate(&self, statistics: &PageStatistics) -> Result<BooleanArray> {
+///         let maxes = statistics.maxes().as_primitive::<Int32Type>();
+///         Ok(maxes.iter().map(|max| max.map(|max| max > self.value)).collect())
+///     }
+/// }
+///
+/// // Write a file with pages of 2 rows
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+/// let column: ArrayRef = Arc::new(Int32Array::from_iter_values(0..6));
+/// let batch = RecordBatch::try_new(schema.clone(), vec![column]).unwrap();
+/// let props = WriterProperties::builder()
+///     .set_data_page_row_count_limit(2)
+///     .set_write_batch_size(2)
+///     .build();
+/// let mut buffer = vec![];
+/// let mut writer = ArrowWriter::try_new(&mut buffer, schema, Some(props)).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.close().unwrap();
+///
+/// let options = ArrowReaderOptions::new().with_page_index(true);
+/// let predicate = GreaterThan { column: "a".to_string(), value: 3 };
+/// let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buffer), options)
+///     .unwrap()
+///     .with_page_pruning_predicate(Arc::new(predicate))
+///     .build()
+///     .unwrap();
+///
+/// // Only the last page, with the rows 4 and 5, is read
+/// let values: Vec<i32> = reader
+///     .flat_map(|b| b.unwrap().column(0).as_primitive::<Int32Type>().values().to_vec())
+///     .collect();
+/// assert_eq!(values, [4, 5]);
+/// ```
+///
+/// [`ArrowReaderBuilder::with_page_pruning_predicate`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_page_pruning_predicate
+/// [`ArrowReaderOptions::with_page_index`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_page_index
+pub trait PagePruningPredicate: Debug + Send + Sync {
+    /// Returns the name of the column of the arrow schema of the file whose page
+    /// statistics the predicate is evaluated on
+    fn column(&self) -> &str;
+
+    /// Evaluates the predicate on the statistics of the data pages of [`Self::column`],
+    /// returning an array with one element per page that is false if no row of the page
+    /// can match, and true or null otherwise
+    fn evaluate(&self, statistics: &PageStatistics) -> Result<BooleanArray>;
+}
+
+/// Returns a [`RowSelection`] of the rows of `row_groups` that may match `predicate`,
+/// based on the page index of the file with `metadata` and the arrow `schema`
+///
+/// Returns `None` if the page index was not loaded, or if the column of the predicate is
+/// not in the file.
+pub fn prune_pages(
+    metadata: &ParquetMetaData,
+    schema: &Schema,
+    row_groups: &[usize],
+    predicate: &dyn PagePruningPredicate,
+) -> Result<Option<RowSelection>> {
+    Ok(evaluate_page_predicate(metadata, schema, row_groups, predicate)?.0)
+}
+
+/// Evaluates `predicate` like [`prune_pages`], also returning a [`PagePredicateReport`]
+/// of the pages it pruned
+pub(crate) fn evaluate_page_predicate(
+    metadata: &ParquetMetaData,
+    schema: &Schema,
+    row_groups: &[usize],
+    predicate: &dyn PagePruningPredicate,
+) -> Result<(Option<RowSelection>, PagePredicateReport)> {
+    let mut report = PagePredicateReport {
+        column: predicate.column().to_string(),
+        evaluated: false,
+        pages: 0,
+        pruned_pages: 0,
+        pruned_rows: 0,
+    };
+    let (Some(column_index), Some(offset_index)) =
+        (metadata.column_index(), metadata.offset_index())
+    else {
+        return Ok((None, report));
+    };
+    let parquet_schema = metadata.file_metadata().schema_descr();
+    let converter = StatisticsConverter::try_new(predicate.column(), schema, parquet_schema)?;
+    let Some(column) = converter.parquet_column_index() else {
+        return Ok((None, report));
+    };
+
+    let row_counts =
+        converter.data_page_row_counts(offset_index, metadata.row_groups(), row_groups)?;
+    let statistics = PageStatistics {
+        mins: converter.data_page_mins(column_index, offset_index, row_groups)?,
+        maxes: converter.data_page_maxes(column_index, offset_index, row_groups)?,
+        null_counts: converter
+            .with_missing_null_counts_as_zero(false)
+            .data_page_null_counts(column_index, offset_index, row_groups)?,
+        row_counts: row_counts.unwrap_or_else(|| UInt64Array::from(Vec::<u64>::new())),
+    };
+    let may_match = predicate.evaluate(&statistics)?;
+    if may_match.len() != statistics.len() {
+        return Err(general_err!(
+            "Page pruning predicate on column {} returned {} values for {} pages",
+            predicate.column(),
+            may_match.len(),
+            statistics.len()
+        ));
+    }
+
+    let ranges = page_row_ranges(metadata, offset_index, row_groups, column);
+    let total_rows = row_groups
+        .iter()
+        .map(|i| metadata.row_group(*i).num_rows() as usize)
+        .sum();
+    let mut keep = Vec::with_capacity(ranges.len());
+    for (i, range) in ranges.into_iter().enumerate() {
+        if may_match.is_null(i) || may_match.value(i) {
+            keep.push(range);
+        } else {
+            report.pruned_pages += 1;
+            report.pruned_rows += range.len();
+        }
+    }
+    report.evaluated = true;
+    report.pages = statistics.len();
+    let selection = RowSelection::from_consecutive_ranges(keep.into_iter(), total_rows);
+    Ok((Some(selection), report))
+}
+
+/// Describes which row groups, pages and rows of a file were pruned by a reader, and by
+/// which of its filters and predicates
+///
+/// This helps to understand why predicate pushdown does or does not reduce the amount of
+/// data decoded, for example to tune the layout of the files being read. Rows are only
+/// counted once they are pruned, so the report of a reader is updated as it progresses.
+///
+/// See [`ParquetRecordBatchReader::pruning_report`] and
+/// `ParquetRecordBatchStream::pruning_report`
+///
+/// [`ParquetRecordBatchReader::pruning_report`]: crate::arrow::arrow_reader::ParquetRecordBatchReader::pruning_report
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruningReport {
+    skipped_row_groups: Vec<usize>,
+    page_predicates: Vec<PagePredicateReport>,
+    row_filter_predicates: Vec<RowFilterPredicateReport>,
+}
+
+impl PruningReport {
+    /// Returns the indices of the row groups skipped by the row group filter, see
+    /// [`ArrowReaderBuilder::with_row_group_filter`]
+    ///
+    /// [`ArrowReaderBuilder::with_row_group_filter`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_row_group_filter
+    pub fn skipped_row_groups(&self) -> &[usize] {
+        &self.skipped_row_groups
+    }
+
+    /// Returns a report for each [`PagePruningPredicate`] of the reader, in the order they
+    /// were added
+    pub fn page_predicates(&self) -> &[PagePredicateReport] {
+        &self.page_predicates
+    }
+
+    /// Returns a report for each predicate of the [`RowFilter`] of the reader, in the order
+    /// of [`RowFilter::new`]
+    ///
+    /// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+    /// [`RowFilter::new`]: crate::arrow::arrow_reader::RowFilter::new
+    pub fn row_filter_predicates(&self) -> &[RowFilterPredicateReport] {
+        &self.row_filter_predicates
+    }
+
+    pub(crate) fn new(
+        page_predicates: Vec<PagePredicateReport>,
+        row_filter_predicates: usize,
+    ) -> Self {
+        Self {
+            skipped_row_groups: vec![],
+            page_predicates,
+            row_filter_predicates: vec![Default::default(); row_filter_predicates],
+        }
+    }
+
+    pub(crate) fn skip_row_group(&mut self, row_group: usize) {
+        self.skipped_row_groups.push(row_group);
+    }
+
+    /// Records that the row filter predicate `idx` selected `rows_selected` of the
+    /// `rows_evaluated` rows it was evaluated on
+    pub(crate) fn record_row_filter(
+        &mut self,
+        idx: usize,
+        rows_evaluated: usize,
+        rows_selected: usize,
+    ) {
+        let report = &mut self.row_filter_predicates[idx];
+        report.rows_evaluated += rows_evaluated;
+        report.rows_selected += rows_selected;
+    }
+}
+
+/// The pages pruned by a [`PagePruningPredicate`], see [`PruningReport::page_predicates`]
+///
+/// The counts are those of the predicate alone, whereas a reader only reads the rows
+/// selected by all its predicates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PagePredicateReport {
+    column: String,
+    evaluated: bool,
+    pages: usize,
+    pruned_pages: usize,
+    pruned_rows: usize,
+}
+
+impl PagePredicateReport {
+    /// Returns the column the predicate was evaluated on
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+
+    /// Returns false if the predicate was not evaluated, because the page index was not
+    /// loaded or the column is not in the file
+    pub fn is_evaluated(&self) -> bool {
+        self.evaluated
+    }
+
+    /// Returns the number of pages the predicate was evaluated on
+    pub fn pages(&self) -> usize {
+        self.pages
+    }
+
+    /// Returns the number of pages the predicate pruned
+    pub fn pruned_pages(&self) -> usize {
+        self.pruned_pages
+    }
+
+    /// Returns the number of rows of the pages the predicate pruned
+    pub fn pruned_rows(&self) -> usize {
+        self.pruned_rows
+    }
+}
+
+/// The rows filtered out by an [`ArrowPredicate`] of a [`RowFilter`], see
+/// [`PruningReport::row_filter_predicates`]
+///
+/// Predicates are evaluated in order, each only on the rows selected by the previous ones,
+/// so a predicate that is never reached has not evaluated any row.
+///
+/// [`ArrowPredicate`]: crate::arrow::arrow_reader::ArrowPredicate
+/// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RowFilterPredicateReport {
+    rows_evaluated: usize,
+    rows_selected: usize,
+}
+
+impl RowFilterPredicateReport {
+    /// Returns the number of rows the predicate was evaluated on
+    pub fn rows_evaluated(&self) -> usize {
+        self.rows_evaluated
+    }
+
+    /// Returns the number of rows the predicate selected
+    pub fn rows_selected(&self) -> usize {
+        self.rows_selected
+    }
+
+    /// Returns the number of rows the predicate filtered out
+    pub fn rows_pruned(&self) -> usize {
+        self.rows_evaluated - self.rows_selected
+    }
+}
+
+/// Returns the row ranges of the data pages of the leaf `column` in `row_groups`, relative
+/// to the first row of `row_groups`
+pub(crate) fn page_row_ranges(
+    metadata: &ParquetMetaData,
+    offset_index: &[Vec<OffsetIndexMetaData>],
+    row_groups: &[usize],
+    column: usize,
+) -> Vec<Range<usize>> {
+    let mut row_group_offset = 0;
+    let mut ranges = vec![];
+    for &row_group in row_groups {
+        let num_rows = metadata.row_group(row_group).num_rows() as usize;
+        let locations = offset_index[row_group][column].page_locations();
+        let starts: Vec<_> = locations
+            .iter()
+            .map(|l| l.first_row_index as usize)
+            .collect();
+        let ends = starts.iter().skip(1).copied().chain([num_rows]);
+        ranges.extend(
+            starts
+                .iter()
+                .zip(ends)
+                .map(|(start, end)| start + row_group_offset..end + row_group_offset),
+        );
+        row_group_offset += num_rows;
+    }
+    ranges
+}
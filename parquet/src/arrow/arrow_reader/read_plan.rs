@@ -31,6 +31,8 @@ use std::collections::VecDeque;
 #[derive(Clone)]
 pub(crate) struct ReadPlanBuilder {
     batch_size: usize,
+    /// Maximum number of decoded bytes in each batch, if any
+    max_batch_bytes: Option<usize>,
     /// Current to apply, includes all filters
     selection: Option<RowSelection>,
 }
@@ -40,10 +42,17 @@ impl ReadPlanBuilder {
     pub(crate) fn new(batch_size: usize) -> Self {
         Self {
             batch_size,
+            max_batch_bytes: None,
             selection: None,
         }
     }
 
+    /// Set the maximum number of decoded bytes in each batch
+    pub(crate) fn with_max_batch_bytes(mut self, max_batch_bytes: Option<usize>) -> Self {
+        self.max_batch_bytes = max_batch_bytes;
+        self
+    }
+
     /// Set the current selection to the given value
     pub(crate) fn with_selection(mut self, selection: Option<RowSelection>) -> Self {
         self.selection = selection;
@@ -76,7 +85,6 @@ impl ReadPlanBuilder {
     }
 
     /// Returns the number of rows selected, or `None` if all rows are selected.
-    #[cfg(feature = "async")]
     pub(crate) fn num_rows_selected(&self) -> Option<usize> {
         self.selection.as_ref().map(|s| s.row_count())
     }
@@ -130,6 +138,7 @@ impl ReadPlanBuilder {
         }
         let Self {
             batch_size,
+            max_batch_bytes,
             selection,
         } = self;
 
@@ -137,6 +146,7 @@ impl ReadPlanBuilder {
 
         ReadPlan {
             batch_size,
+            max_batch_bytes,
             selection,
         }
     }
@@ -233,6 +243,8 @@ impl LimitedReadPlanBuilder {
 pub(crate) struct ReadPlan {
     /// The number of rows to read in each batch
     batch_size: usize,
+    /// The maximum number of decoded bytes in each batch, if any
+    max_batch_bytes: Option<usize>,
     /// Row ranges to be selected from the data source
     selection: Option<VecDeque<RowSelector>>,
 }
@@ -248,4 +260,10 @@ impl ReadPlan {
     pub fn batch_size(&self) -> usize {
         self.batch_size
     }
+
+    /// Return the maximum number of decoded bytes in each output batch, if any
+    #[inline(always)]
+    pub fn max_batch_bytes(&self) -> Option<usize> {
+        self.max_batch_bytes
+    }
 }
@@ -15,7 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`StatisticsConverter`] to convert statistics in parquet format to arrow [`ArrayRef`].
+//! [`StatisticsConverter`] and [`RowGroupStatistics`] to convert statistics in parquet format
+//! to arrow [`ArrayRef`].
 
 /// Notice that all the corresponding tests are in
 /// `arrow-rs/parquet/tests/arrow_reader/statistics.rs`.
@@ -34,14 +35,15 @@ use arrow_array::builder::{
 };
 use arrow_array::{
     new_empty_array, new_null_array, ArrayRef, BinaryArray, BooleanArray, Date32Array, Date64Array,
-    Decimal128Array, Decimal256Array, Decimal32Array, Decimal64Array, Float16Array, Float32Array,
-    Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, LargeBinaryArray,
+    Decimal128Array, Decimal256Array, Decimal32Array, Decimal64Array, DurationMicrosecondArray,
+    DurationMillisecondArray, DurationNanosecondArray, DurationSecondArray, Float16Array,
+    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, LargeBinaryArray,
     Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
     TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
     TimestampSecondArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
 };
 use arrow_buffer::i256;
-use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, TimeUnit};
 use half::f16;
 use paste::paste;
 use std::sync::Arc;
@@ -575,9 +577,18 @@ macro_rules! get_statistics {
                 }
                 Ok(Arc::new(builder.finish()))
             }
+            DataType::Duration(unit) => {
+                let iter = [<$stat_type_prefix Int64StatsIterator>]::new($iterator).map(|x| x.copied());
+                Ok(match unit {
+                    TimeUnit::Second => Arc::new(DurationSecondArray::from_iter(iter)),
+                    TimeUnit::Millisecond => Arc::new(DurationMillisecondArray::from_iter(iter)),
+                    TimeUnit::Microsecond => Arc::new(DurationMicrosecondArray::from_iter(iter)),
+                    TimeUnit::Nanosecond => Arc::new(DurationNanosecondArray::from_iter(iter)),
+                })
+            },
 
             DataType::Map(_,_) |
-            DataType::Duration(_) |
+            // INTERVAL has an undefined sort order, so its statistics are meaningless
             DataType::Interval(_) |
             DataType::Date64 |  // required to cover $physical_type match guard
             DataType::Null |
@@ -1129,9 +1140,18 @@ macro_rules! get_data_page_statistics {
                     }
                     Ok(Arc::new(builder.finish()))
                 },
+                DataType::Duration(unit) => {
+                    let iter = [<$stat_type_prefix Int64DataPageStatsIterator>]::new($iterator).flatten();
+                    Ok(match unit {
+                        TimeUnit::Second => Arc::new(DurationSecondArray::from_iter(iter)),
+                        TimeUnit::Millisecond => Arc::new(DurationMillisecondArray::from_iter(iter)),
+                        TimeUnit::Microsecond => Arc::new(DurationMicrosecondArray::from_iter(iter)),
+                        TimeUnit::Nanosecond => Arc::new(DurationNanosecondArray::from_iter(iter)),
+                    })
+                },
                 DataType::Date64 |  // required to cover $physical_type match guard
                 DataType::Null |
-                DataType::Duration(_) |
+                // INTERVAL has an undefined sort order, so its statistics are meaningless
                 DataType::Interval(_) |
                 DataType::List(_) |
                 DataType::ListView(_) |
@@ -1142,7 +1162,8 @@ macro_rules! get_data_page_statistics {
                 DataType::Union(_, _) |
                 DataType::Map(_, _) |
                 DataType::RunEndEncoded(_, _) => {
-                    let len = $iterator.count();
+                    // one null per data page of each row group
+                    let len = $iterator.map(|(len, _)| len).sum();
                     // don't know how to extract statistics, so return a null array
                     Ok(new_null_array($data_type, len))
                 },
@@ -1244,7 +1265,11 @@ where
             .iter()
             .map(|x| x.null_count.map(|x| x as u64))
             .collect::<Vec<_>>(),
-        _ => unimplemented!(),
+        Index::INT96(native_index) => native_index
+            .indexes
+            .iter()
+            .map(|x| x.null_count.map(|x| x as u64))
+            .collect::<Vec<_>>(),
     });
 
     Ok(UInt64Array::from_iter(iter))
@@ -1410,6 +1435,25 @@ impl<'a> StatisticsConverter<'a> {
         })
     }
 
+    /// Create a new `StatisticsConverter` for the leaf column `parquet_column_index` of
+    /// `parquet_schema`, read as `arrow_field`
+    ///
+    /// This is used for columns that are not fields of the arrow schema, such as the typed
+    /// columns of shredded variants.
+    #[cfg(feature = "variant_experimental")]
+    pub(crate) fn for_parquet_column(
+        parquet_column_index: usize,
+        arrow_field: &'a Field,
+        parquet_schema: &SchemaDescriptor,
+    ) -> Self {
+        Self {
+            parquet_column_index: Some(parquet_column_index),
+            arrow_field,
+            missing_null_counts_as_zero: true,
+            physical_type: Some(parquet_schema.column(parquet_column_index).physical_type()),
+        }
+    }
+
     /// Extract the minimum values from row group statistics in [`RowGroupMetaData`]
     ///
     /// # Return Value
@@ -1772,3 +1816,136 @@ impl<'a> StatisticsConverter<'a> {
 }
 
 // See tests in parquet/tests/arrow_reader/statistics.rs
+
+/// The statistics of a column in each row group of a file, see [`RowGroupStatistics`]
+#[derive(Debug, Clone)]
+pub struct ColumnStatistics {
+    mins: ArrayRef,
+    maxes: ArrayRef,
+    null_counts: UInt64Array,
+}
+
+impl ColumnStatistics {
+    /// Returns the minimum value of the column in each row group, see
+    /// [`StatisticsConverter::row_group_mins`]
+    pub fn mins(&self) -> &ArrayRef {
+        &self.mins
+    }
+
+    /// Returns the maximum value of the column in each row group, see
+    /// [`StatisticsConverter::row_group_maxes`]
+    pub fn maxes(&self) -> &ArrayRef {
+        &self.maxes
+    }
+
+    /// Returns the number of nulls of the column in each row group, see
+    /// [`StatisticsConverter::row_group_null_counts`]
+    pub fn null_counts(&self) -> &UInt64Array {
+        &self.null_counts
+    }
+}
+
+/// Extracts the statistics of all columns of an Arrow schema in each row group of a file
+///
+/// This is equivalent to creating a [`StatisticsConverter`] for each column, and
+/// converting the statistics of all row groups at once, so that predicates can be
+/// evaluated against all row groups using vectorized Arrow kernels.
+///
+/// # Example
+/// ```no_run
+/// # use arrow::compute::kernels::cmp::gt;
+/// # use arrow::datatypes::Schema;
+/// # use arrow_array::Int32Array;
+/// # use parquet::arrow::arrow_reader::statistics::RowGroupStatistics;
+/// # use parquet::file::metadata::ParquetMetaData;
+/// # fn get_parquet_metadata() -> ParquetMetaData { unimplemented!() }
+/// # fn get_arrow_schema() -> Schema { unimplemented!() }
+/// let metadata: ParquetMetaData = get_parquet_metadata();
+/// let arrow_schema: Schema = get_arrow_schema();
+/// let statistics = RowGroupStatistics::try_new(
+///     &arrow_schema,
+///     metadata.file_metadata().schema_descr(),
+///     metadata.row_groups(),
+/// )
+/// .unwrap();
+///
+/// // find the row groups that may contain rows where "foo" > 10
+/// let foo = statistics.column("foo").unwrap();
+/// let ten = Int32Array::new_scalar(10);
+/// let may_match = gt(foo.maxes(), &ten).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RowGroupStatistics {
+    fields: Fields,
+    columns: Vec<ColumnStatistics>,
+    row_counts: UInt64Array,
+}
+
+impl RowGroupStatistics {
+    /// Extracts the statistics of each column of `arrow_schema` from `row_groups`
+    ///
+    /// The statistics of columns of `arrow_schema` that are not in `parquet_schema` are
+    /// null, see [`StatisticsConverter::try_new`]
+    pub fn try_new<'a, I>(
+        arrow_schema: &'a Schema,
+        parquet_schema: &'a SchemaDescriptor,
+        row_groups: I,
+    ) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a RowGroupMetaData>,
+    {
+        let row_groups: Vec<_> = row_groups.into_iter().collect();
+        let columns = arrow_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let converter =
+                    StatisticsConverter::try_new(field.name(), arrow_schema, parquet_schema)?;
+                Ok(ColumnStatistics {
+                    mins: converter.row_group_mins(row_groups.iter().copied())?,
+                    maxes: converter.row_group_maxes(row_groups.iter().copied())?,
+                    null_counts: converter.row_group_null_counts(row_groups.iter().copied())?,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        let row_counts = row_groups
+            .iter()
+            .map(|row_group| {
+                let row_count = row_group.num_rows();
+                u64::try_from(row_count).map_err(|e| {
+                    arrow_err!(format!(
+                        "Parquet row count {row_count} too large to convert to u64: {e}"
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            fields: arrow_schema.fields().clone(),
+            columns,
+            row_counts: UInt64Array::from(row_counts),
+        })
+    }
+
+    /// Returns the number of row groups
+    pub fn num_row_groups(&self) -> usize {
+        self.row_counts.len()
+    }
+
+    /// Returns the number of rows in each row group
+    pub fn row_counts(&self) -> &UInt64Array {
+        &self.row_counts
+    }
+
+    /// Returns the statistics of the column named `name`, if it is in the Arrow schema
+    pub fn column(&self, name: &str) -> Option<&ColumnStatistics> {
+        let (idx, _) = self.fields.find(name)?;
+        Some(&self.columns[idx])
+    }
+
+    /// Returns the fields of the Arrow schema and the statistics of their columns
+    pub fn columns(&self) -> impl Iterator<Item = (&FieldRef, &ColumnStatistics)> {
+        self.fields.iter().zip(&self.columns)
+    }
+}
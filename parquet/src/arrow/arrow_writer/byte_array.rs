@@ -16,8 +16,10 @@
 // under the License.
 
 use crate::basic::Encoding;
-use crate::bloom_filter::Sbbf;
-use crate::column::writer::encoder::{ColumnValueEncoder, DataPageValues, DictionaryPage};
+use crate::bloom_filter::{Sbbf, SbbfBuilder};
+use crate::column::writer::encoder::{
+    ColumnValueEncoder, DataPageValues, DictionaryPage, EncodingChain,
+};
 use crate::data_type::{AsBytes, ByteArray, Int32Type};
 use crate::encodings::encoding::{DeltaBitPackEncoder, Encoder};
 use crate::encodings::rle::RleEncoder;
@@ -98,6 +100,7 @@ macro_rules! downcast_op {
 /// A fallback encoder, i.e. non-dictionary, for [`ByteArray`]
 struct FallbackEncoder {
     encoder: FallbackEncoderImpl,
+    chain: EncodingChain,
     num_values: usize,
     variable_length_bytes: i64,
 }
@@ -121,25 +124,16 @@ enum FallbackEncoderImpl {
     },
 }
 
-impl FallbackEncoder {
-    /// Create the fallback encoder for the given [`ColumnDescPtr`] and [`WriterProperties`]
-    fn new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
-        // Set either main encoder or fallback encoder.
-        let encoding =
-            props
-                .encoding(descr.path())
-                .unwrap_or_else(|| match props.writer_version() {
-                    WriterVersion::PARQUET_1_0 => Encoding::PLAIN,
-                    WriterVersion::PARQUET_2_0 => Encoding::DELTA_BYTE_ARRAY,
-                });
-
-        let encoder = match encoding {
-            Encoding::PLAIN => FallbackEncoderImpl::Plain { buffer: vec![] },
-            Encoding::DELTA_LENGTH_BYTE_ARRAY => FallbackEncoderImpl::DeltaLength {
+impl FallbackEncoderImpl {
+    /// Create the encoder for `encoding`
+    fn try_new(encoding: Encoding) -> Result<Self> {
+        Ok(match encoding {
+            Encoding::PLAIN => Self::Plain { buffer: vec![] },
+            Encoding::DELTA_LENGTH_BYTE_ARRAY => Self::DeltaLength {
                 buffer: vec![],
                 lengths: Box::new(DeltaBitPackEncoder::new()),
             },
-            Encoding::DELTA_BYTE_ARRAY => FallbackEncoderImpl::Delta {
+            Encoding::DELTA_BYTE_ARRAY => Self::Delta {
                 buffer: vec![],
                 last_value: vec![],
                 prefix_lengths: Box::new(DeltaBitPackEncoder::new()),
@@ -151,10 +145,26 @@ impl FallbackEncoder {
                     encoding
                 ))
             }
+        })
+    }
+}
+
+impl FallbackEncoder {
+    /// Create the fallback encoder for the given [`ColumnDescPtr`] and [`WriterProperties`]
+    fn new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
+        // Set either main encoder or fallback encoder.
+        let default = match props.writer_version() {
+            WriterVersion::PARQUET_1_0 => Encoding::PLAIN,
+            WriterVersion::PARQUET_2_0 => Encoding::DELTA_BYTE_ARRAY,
         };
+        let chain = EncodingChain::new(descr, props, default);
+        for encoding in &chain.encodings()[1..] {
+            FallbackEncoderImpl::try_new(*encoding)?;
+        }
 
         Ok(Self {
-            encoder,
+            encoder: FallbackEncoderImpl::try_new(chain.encoding())?,
+            chain,
             num_values: 0,
             variable_length_bytes: 0,
         })
@@ -277,13 +287,21 @@ impl FallbackEncoder {
             }
         };
 
+        // Each value is prefixed by its 4 byte length when PLAIN encoded
+        let plain_size = self.num_values * 4 + self.variable_length_bytes as usize;
+
         // Capture value of variable_length_bytes and reset for next page
         let variable_length_bytes = Some(self.variable_length_bytes);
         self.variable_length_bytes = 0;
+        let num_values = std::mem::take(&mut self.num_values);
+
+        if let Some(next) = self.chain.observe(buf.len(), plain_size) {
+            self.encoder = FallbackEncoderImpl::try_new(next)?;
+        }
 
         Ok(DataPageValues {
             buf: buf.into(),
-            num_values: std::mem::take(&mut self.num_values),
+            num_values,
             encoding,
             min_value,
             max_value,
@@ -372,6 +390,15 @@ impl DictEncoder {
         self.interner.storage().page.len()
     }
 
+    /// Returns the distinct values in the dictionary, in dictionary order
+    fn values(&self) -> impl Iterator<Item = &[u8]> {
+        let storage = self.interner.storage();
+        storage
+            .values
+            .iter()
+            .map(|range| &storage.page[range.clone()])
+    }
+
     fn flush_dict_page(self) -> DictionaryPage {
         let storage = self.interner.into_inner();
 
@@ -420,14 +447,14 @@ pub struct ByteArrayEncoder {
     statistics_enabled: EnabledStatistics,
     min_value: Option<ByteArray>,
     max_value: Option<ByteArray>,
-    bloom_filter: Option<Sbbf>,
+    bloom_filter: Option<SbbfBuilder>,
 }
 
 impl ColumnValueEncoder for ByteArrayEncoder {
     type T = ByteArray;
     type Values = dyn Array;
     fn flush_bloom_filter(&mut self) -> Option<Sbbf> {
-        self.bloom_filter.take()
+        self.bloom_filter.take().map(SbbfBuilder::finish)
     }
 
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self>
@@ -440,9 +467,11 @@ impl ColumnValueEncoder for ByteArrayEncoder {
 
         let fallback = FallbackEncoder::new(descr, props)?;
 
+        let from_dictionary =
+            dictionary.is_some() && props.bloom_filter_from_dictionary(descr.path());
         let bloom_filter = props
             .bloom_filter_properties(descr.path())
-            .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
+            .map(|props| SbbfBuilder::try_new(props, from_dictionary))
             .transpose()?;
 
         let statistics_enabled = props.statistics_enabled(descr.path());
@@ -521,6 +550,9 @@ impl ColumnValueEncoder for ByteArrayEncoder {
                     ));
                 }
 
+                if let Some(bloom_filter) = &mut self.bloom_filter {
+                    bloom_filter.insert_dictionary(encoder.values());
+                }
                 Ok(Some(encoder.flush_dict_page()))
             }
             _ => Ok(None),
@@ -559,7 +591,11 @@ where
     }
 
     // encode the values into bloom filter if enabled
-    if let Some(bloom_filter) = &mut encoder.bloom_filter {
+    if let Some(bloom_filter) = encoder
+        .bloom_filter
+        .as_mut()
+        .and_then(SbbfBuilder::filter_mut)
+    {
         let valid = indices.iter().cloned();
         for idx in valid {
             bloom_filter.insert(values.value(idx).as_ref());
@@ -47,7 +47,7 @@ use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{KeyValue, RowGroupMetaData};
 use crate::file::properties::{WriterProperties, WriterPropertiesPtr};
 use crate::file::reader::{ChunkReader, Length};
-use crate::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
+use crate::file::writer::{SerializedFileWriter, SerializedRowGroupWriter, WriterMetrics};
 use crate::schema::types::{ColumnDescPtr, SchemaDescriptor};
 use crate::thrift::TSerializable;
 use levels::{calculate_array_levels, ArrayLevels};
@@ -145,6 +145,17 @@ pub struct ArrowWriter<W: Write> {
 
     /// The length of arrays to write to each row group
     max_row_group_size: usize,
+
+    /// The target maximum encoded size of each row group in bytes
+    max_row_group_bytes: Option<usize>,
+
+    /// The index and shredding schema of each shredded variant column
+    #[cfg(feature = "variant_experimental")]
+    variant_shredding: Vec<(usize, ArrowDataType)>,
+
+    /// The bloom filters over the field names of variant columns
+    #[cfg(feature = "variant_experimental")]
+    variant_field_bloom_filters: Vec<crate::variant::VariantFieldBloomFilter>,
 }
 
 impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
@@ -156,6 +167,7 @@ impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
             .field("in_progress_rows", &self.in_progress_rows())
             .field("arrow_schema", &self.arrow_schema)
             .field("max_row_group_size", &self.max_row_group_size)
+            .field("max_row_group_bytes", &self.max_row_group_bytes)
             .finish()
     }
 }
@@ -185,6 +197,10 @@ impl<W: Write + Send> ArrowWriter<W> {
         arrow_schema: SchemaRef,
         options: ArrowWriterOptions,
     ) -> Result<Self> {
+        #[cfg(feature = "variant_experimental")]
+        let (arrow_schema, variant_shredding) =
+            crate::variant::shred_schema(&arrow_schema, &options.variant_shredding)?;
+
         let mut props = options.properties;
         let mut converter = ArrowSchemaConverter::new().with_coerce_types(props.coerce_types());
         if let Some(schema_root) = &options.schema_root {
@@ -197,11 +213,23 @@ impl<W: Write + Send> ArrowWriter<W> {
         }
 
         let max_row_group_size = props.max_row_group_size();
+        let max_row_group_bytes = props.max_row_group_bytes();
+
+        #[cfg(feature = "variant_experimental")]
+        let variant_field_bloom_filters =
+            crate::variant::VariantFieldBloomFilter::try_new_all(&arrow_schema, &props)?;
+        #[cfg(all(feature = "variant_experimental", feature = "encryption"))]
+        if !variant_field_bloom_filters.is_empty() && props.file_encryption_properties.is_some() {
+            return Err(nyi_err!(
+                "Variant field bloom filters are not supported for encrypted files"
+            ));
+        }
 
         let file_writer =
             SerializedFileWriter::new(writer, schema.root_schema_ptr(), Arc::new(props))?;
 
-        let row_group_writer_factory = ArrowRowGroupWriterFactory::new(&file_writer);
+        let row_group_writer_factory =
+            ArrowRowGroupWriterFactory::new(&file_writer, options.encoding_threads);
 
         Ok(Self {
             writer: file_writer,
@@ -209,6 +237,11 @@ impl<W: Write + Send> ArrowWriter<W> {
             arrow_schema,
             row_group_writer_factory,
             max_row_group_size,
+            max_row_group_bytes,
+            #[cfg(feature = "variant_experimental")]
+            variant_shredding,
+            #[cfg(feature = "variant_experimental")]
+            variant_field_bloom_filters,
         })
     }
 
@@ -236,11 +269,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// columns.
     pub fn in_progress_size(&self) -> usize {
         match &self.in_progress {
-            Some(in_progress) => in_progress
-                .writers
-                .iter()
-                .map(|x| x.get_estimated_total_bytes())
-                .sum(),
+            Some(in_progress) => in_progress.estimated_total_bytes(),
             None => 0,
         }
     }
@@ -258,14 +287,29 @@ impl<W: Write + Send> ArrowWriter<W> {
         self.writer.bytes_written()
     }
 
+    /// Returns metrics describing the time spent writing bloom filters, page indexes
+    /// and the footer, see [`SerializedFileWriter::metrics`]
+    pub fn metrics(&self) -> &WriterMetrics {
+        self.writer.metrics()
+    }
+
     /// Encodes the provided [`RecordBatch`]
     ///
     /// If this would cause the current row group to exceed [`WriterProperties::max_row_group_size`]
     /// rows, the contents of `batch` will be written to one or more row groups such that all but
     /// the final row group in the file contain [`WriterProperties::max_row_group_size`] rows.
     ///
+    /// Similarly, if [`WriterProperties::max_row_group_bytes`] is set, the contents of `batch`
+    /// will be split such that the current row group is flushed once its estimated encoded size
+    /// reaches the limit.
+    ///
     /// This will fail if the `batch`'s schema does not match the writer's schema.
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.write_batch(batch)
+    }
+
+    /// Encodes the provided [`RecordBatch`], see [`Self::write`]
+    fn write_batch(&mut self, batch: &RecordBatch) -> Result<()> {
         if batch.num_rows() == 0 {
             return Ok(());
         }
@@ -285,13 +329,47 @@ impl<W: Write + Send> ArrowWriter<W> {
             let to_write = self.max_row_group_size - in_progress.buffered_rows;
             let a = batch.slice(0, to_write);
             let b = batch.slice(to_write, batch.num_rows() - to_write);
-            self.write(&a)?;
-            return self.write(&b);
+            self.write_batch(&a)?;
+            return self.write_batch(&b);
+        }
+
+        // If would exceed max_row_group_bytes, split batch based on the average encoded
+        // size of the rows buffered so far
+        if let Some(max_bytes) = self.max_row_group_bytes {
+            let to_write = match in_progress.buffered_rows {
+                // Without any buffered rows to estimate the row size from, write a single
+                // write batch to obtain an estimate
+                0 => self.writer.properties().write_batch_size().max(1),
+                buffered_rows => {
+                    let size = in_progress.estimated_total_bytes();
+                    let row_size = (size / buffered_rows).max(1);
+                    (max_bytes.saturating_sub(size) / row_size).max(1)
+                }
+            };
+            if batch.num_rows() > to_write {
+                let a = batch.slice(0, to_write);
+                let b = batch.slice(to_write, batch.num_rows() - to_write);
+                self.write_batch(&a)?;
+                return self.write_batch(&b);
+            }
         }
 
+        // Variant columns are shredded once the batch has been split into row groups, so
+        // that the field names of each row group are collected from the unshredded rows
+        #[cfg(feature = "variant_experimental")]
+        let batch = &{
+            for filter in &mut self.variant_field_bloom_filters {
+                filter.insert(batch)?;
+            }
+            crate::variant::shred_batch(batch, &self.arrow_schema, &self.variant_shredding)?
+        };
+
         in_progress.write(batch)?;
 
-        if in_progress.buffered_rows >= self.max_row_group_size {
+        let exceeds_bytes = self
+            .max_row_group_bytes
+            .is_some_and(|max_bytes| in_progress.estimated_total_bytes() >= max_bytes);
+        if in_progress.buffered_rows >= self.max_row_group_size || exceeds_bytes {
             self.flush()?
         }
         Ok(())
@@ -317,6 +395,12 @@ impl<W: Write + Send> ArrowWriter<W> {
             chunk.append_to_row_group(&mut row_group_writer)?;
         }
         row_group_writer.close()?;
+
+        #[cfg(feature = "variant_experimental")]
+        for filter in &mut self.variant_field_bloom_filters {
+            let row_group = self.writer.flushed_row_groups().len() - 1;
+            filter.flush(&mut self.writer, &self.arrow_schema, row_group)?;
+        }
         Ok(())
     }
 
@@ -385,6 +469,9 @@ pub struct ArrowWriterOptions {
     properties: WriterProperties,
     skip_arrow_metadata: bool,
     schema_root: Option<String>,
+    encoding_threads: usize,
+    #[cfg(feature = "variant_experimental")]
+    variant_shredding: Vec<(String, ArrowDataType)>,
 }
 
 impl ArrowWriterOptions {
@@ -418,6 +505,43 @@ impl ArrowWriterOptions {
             ..self
         }
     }
+
+    /// Set the number of threads used to encode the columns of each row group (defaults to `1`)
+    ///
+    /// With more than one thread, the leaf columns of each written [`RecordBatch`] are split
+    /// into contiguous groups, which are encoded and compressed concurrently on scoped
+    /// threads, as are the final pages of each column when a row group is flushed. This can
+    /// considerably reduce the time to write wide tables, and produces the same file as
+    /// encoding on a single thread.
+    ///
+    /// For finer-grained control, such as encoding on an existing thread pool, see
+    /// [`ArrowColumnWriter`].
+    pub fn with_encoding_threads(self, encoding_threads: usize) -> Self {
+        Self {
+            encoding_threads,
+            ..self
+        }
+    }
+
+    /// Shred the variant column named `column` according to the shredding schema `as_type`
+    ///
+    /// The column must be a variant, and is shredded by the [`ArrowWriter`] with
+    /// [`shred_variant`] before being written. The Arrow schema of the file contains the
+    /// [`shredded_variant_type`] of the column instead of the schema passed to the writer.
+    ///
+    /// See the [`variant`](crate::variant) module for an example.
+    ///
+    /// [`shred_variant`]: crate::variant::shred_variant
+    /// [`shredded_variant_type`]: crate::variant::shredded_variant_type
+    #[cfg(feature = "variant_experimental")]
+    pub fn with_variant_shredding(
+        mut self,
+        column: impl Into<String>,
+        as_type: ArrowDataType,
+    ) -> Self {
+        self.variant_shredding.push((column.into(), as_type));
+        self
+    }
 }
 
 /// A single column chunk produced by [`ArrowColumnWriter`]
@@ -759,52 +883,137 @@ struct ArrowRowGroupWriter {
     writers: Vec<ArrowColumnWriter>,
     schema: SchemaRef,
     buffered_rows: usize,
+    /// The number of threads to encode the columns on, see
+    /// [`ArrowWriterOptions::with_encoding_threads`]
+    encoding_threads: usize,
 }
 
 impl ArrowRowGroupWriter {
-    fn new(writers: Vec<ArrowColumnWriter>, arrow: &SchemaRef) -> Self {
+    fn new(writers: Vec<ArrowColumnWriter>, arrow: &SchemaRef, encoding_threads: usize) -> Self {
         Self {
             writers,
             schema: arrow.clone(),
             buffered_rows: 0,
+            encoding_threads,
         }
     }
 
+    /// Returns the number of columns to encode on each thread, or `None` to encode all
+    /// columns on the current thread
+    fn columns_per_thread(&self) -> Option<usize> {
+        (self.encoding_threads > 1 && self.writers.len() > 1)
+            .then(|| self.writers.len().div_ceil(self.encoding_threads))
+    }
+
     fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         self.buffered_rows += batch.num_rows();
-        let mut writers = self.writers.iter_mut();
-        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
-            for leaf in compute_leaves(field.as_ref(), column)? {
-                writers.next().unwrap().write(&leaf)?
+        let Some(chunk_size) = self.columns_per_thread() else {
+            let mut writers = self.writers.iter_mut();
+            for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+                for leaf in compute_leaves(field.as_ref(), column)? {
+                    writers.next().unwrap().write(&leaf)?
+                }
             }
+            return Ok(());
+        };
+
+        let mut leaves = Vec::with_capacity(self.writers.len());
+        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+            leaves.extend(compute_leaves(field.as_ref(), column)?);
         }
-        Ok(())
+        std::thread::scope(|s| {
+            let handles: Vec<_> = self
+                .writers
+                .chunks_mut(chunk_size)
+                .zip(leaves.chunks(chunk_size))
+                .map(|(writers, leaves)| {
+                    s.spawn(move || {
+                        writers
+                            .iter_mut()
+                            .zip(leaves)
+                            .try_for_each(|(writer, leaf)| writer.write(leaf))
+                    })
+                })
+                .collect();
+            handles.into_iter().try_for_each(join_encoding_thread)
+        })
     }
 
-    fn close(self) -> Result<Vec<ArrowColumnChunk>> {
+    /// Returns the estimated encoded size of the buffered rows, see
+    /// [`ArrowColumnWriter::get_estimated_total_bytes`]
+    fn estimated_total_bytes(&self) -> usize {
         self.writers
-            .into_iter()
-            .map(|writer| writer.close())
-            .collect()
+            .iter()
+            .map(|x| x.get_estimated_total_bytes())
+            .sum()
+    }
+
+    fn close(self) -> Result<Vec<ArrowColumnChunk>> {
+        let Some(chunk_size) = self.columns_per_thread() else {
+            return self
+                .writers
+                .into_iter()
+                .map(|writer| writer.close())
+                .collect();
+        };
+
+        let num_columns = self.writers.len();
+        let mut writers = self.writers.into_iter();
+        std::thread::scope(|s| {
+            let handles: Vec<_> = std::iter::from_fn(|| {
+                let writers: Vec<_> = writers.by_ref().take(chunk_size).collect();
+                (!writers.is_empty()).then_some(writers)
+            })
+            .map(|writers| {
+                s.spawn(move || {
+                    writers
+                        .into_iter()
+                        .map(|writer| writer.close())
+                        .collect::<Result<Vec<_>>>()
+                })
+            })
+            .collect();
+
+            let mut chunks = Vec::with_capacity(num_columns);
+            for handle in handles {
+                chunks.extend(join_encoding_thread(handle)?);
+            }
+            Ok(chunks)
+        })
     }
 }
 
+/// Waits for a thread spawned by [`ArrowRowGroupWriter`], propagating any panic
+fn join_encoding_thread<T>(handle: std::thread::ScopedJoinHandle<'_, T>) -> T {
+    handle
+        .join()
+        .unwrap_or_else(|e| std::panic::resume_unwind(e))
+}
+
 struct ArrowRowGroupWriterFactory {
     #[cfg(feature = "encryption")]
     file_encryptor: Option<Arc<FileEncryptor>>,
+    encoding_threads: usize,
 }
 
 impl ArrowRowGroupWriterFactory {
     #[cfg(feature = "encryption")]
-    fn new<W: Write + Send>(file_writer: &SerializedFileWriter<W>) -> Self {
+    fn new<W: Write + Send>(
+        file_writer: &SerializedFileWriter<W>,
+        encoding_threads: usize,
+    ) -> Self {
         Self {
             file_encryptor: file_writer.file_encryptor(),
+            encoding_threads,
         }
     }
 
     #[cfg(not(feature = "encryption"))]
-    fn new<W: Write + Send>(_file_writer: &SerializedFileWriter<W>) -> Self {
-        Self {}
+    fn new<W: Write + Send>(
+        _file_writer: &SerializedFileWriter<W>,
+        encoding_threads: usize,
+    ) -> Self {
+        Self { encoding_threads }
     }
 
     #[cfg(feature = "encryption")]
@@ -822,7 +1031,11 @@ impl ArrowRowGroupWriterFactory {
             self.file_encryptor.clone(),
             row_group_index,
         )?;
-        Ok(ArrowRowGroupWriter::new(writers, arrow))
+        Ok(ArrowRowGroupWriter::new(
+            writers,
+            arrow,
+            self.encoding_threads,
+        ))
     }
 
     #[cfg(not(feature = "encryption"))]
@@ -834,7 +1047,11 @@ impl ArrowRowGroupWriterFactory {
         _row_group_index: usize,
     ) -> Result<ArrowRowGroupWriter> {
         let writers = get_column_writers(parquet, props, arrow)?;
-        Ok(ArrowRowGroupWriter::new(writers, arrow))
+        Ok(ArrowRowGroupWriter::new(
+            writers,
+            arrow,
+            self.encoding_threads,
+        ))
     }
 }
 
@@ -949,7 +1166,7 @@ impl ArrowColumnWriterFactory {
         let col = |desc: &ColumnDescPtr| -> Result<ArrowColumnWriter> {
             let page_writer = self.create_page_writer(desc, out.len())?;
             let chunk = page_writer.buffer.clone();
-            let writer = get_column_writer(desc.clone(), props.clone(), page_writer);
+            let writer = get_column_writer(desc.clone(), props.clone(), page_writer)?;
             Ok(ArrowColumnWriter {
                 chunk,
                 writer: ArrowColumnWriterImpl::Column(writer),
@@ -959,7 +1176,7 @@ impl ArrowColumnWriterFactory {
         let bytes = |desc: &ColumnDescPtr| -> Result<ArrowColumnWriter> {
             let page_writer = self.create_page_writer(desc, out.len())?;
             let chunk = page_writer.buffer.clone();
-            let writer = GenericColumnWriter::new(desc.clone(), props.clone(), page_writer);
+            let writer = GenericColumnWriter::new(desc.clone(), props.clone(), page_writer)?;
             Ok(ArrowColumnWriter {
                 chunk,
                 writer: ArrowColumnWriterImpl::ByteArray(writer),
@@ -1339,7 +1556,9 @@ mod tests {
     use std::io::Seek;
 
     use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
-    use crate::arrow::ARROW_SCHEMA_META_KEY;
+    use crate::arrow::{
+        arrow_schema_fingerprint, ARROW_SCHEMA_FINGERPRINT_META_KEY, ARROW_SCHEMA_META_KEY,
+    };
     use crate::column::page::{Page, PageReader};
     use crate::file::page_encoding_stats::PageEncodingStats;
     use crate::file::reader::SerializedPageReader;
@@ -1362,7 +1581,7 @@ mod tests {
     use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData, ParquetMetaDataReader};
     use crate::file::page_index::index::Index;
     use crate::file::properties::{
-        BloomFilterPosition, EnabledStatistics, ReaderProperties, WriterVersion,
+        BloomFilterPosition, EnabledStatistics, PageIndexPosition, ReaderProperties, WriterVersion,
     };
     use crate::file::serialized_reader::ReadOptionsBuilder;
     use crate::file::{
@@ -2677,6 +2896,139 @@ mod tests {
         check_bloom_filter(files, "col".to_string(), optional_raw_values, vec![""]);
     }
 
+    #[test]
+    fn test_bloom_filter_from_dictionary() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("i", DataType::Int32, false),
+            Field::new("s", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_iter_values((0..1000).map(|i| i % 10))),
+                Arc::new(StringArray::from_iter_values(
+                    (0..1000).map(|i| format!("value {}", i % 10)),
+                )),
+            ],
+        )
+        .unwrap();
+
+        // Returns the bloom filters of the columns, and their lengths in the file
+        let write = |builder: crate::file::properties::WriterPropertiesBuilder| {
+            let props = builder
+                .set_bloom_filter_ndv(1000)
+                .set_write_batch_size(100)
+                .build();
+            let mut buf = Vec::new();
+            let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            writer.close().unwrap();
+
+            let props = ReaderProperties::builder()
+                .set_read_bloom_filter(true)
+                .build();
+            let options = ReadOptionsBuilder::new()
+                .with_reader_properties(props)
+                .build();
+            let reader = SerializedFileReader::new_with_options(Bytes::from(buf), options).unwrap();
+            let row_group = reader.get_row_group(0).unwrap();
+            let filters: Vec<_> = (0..2)
+                .map(|i| {
+                    let filter = row_group.get_column_bloom_filter(i).unwrap().clone();
+                    (filter, row_group.metadata().column(i).bloom_filter_length())
+                })
+                .collect();
+            for v in 0..10 {
+                assert!(filters[0].0.check(&v));
+                assert!(filters[1].0.check(&format!("value {v}").as_str()));
+            }
+            filters
+                .into_iter()
+                .map(|(_, len)| len.unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        let eager = write(WriterProperties::builder());
+        let from_dictionary =
+            write(WriterProperties::builder().set_bloom_filter_from_dictionary(true));
+        for (from_dictionary, eager) in from_dictionary.iter().zip(&eager) {
+            assert!(from_dictionary < eager);
+        }
+
+        // Only the bloom filter of "s" is built from its dictionary
+        let s = write(
+            WriterProperties::builder()
+                .set_column_bloom_filter_from_dictionary(ColumnPath::from("s"), true),
+        );
+        assert_eq!(s, vec![eager[0], from_dictionary[1]]);
+
+        // Falling back from dictionary encoding builds the filter with the configured ndv
+        let fallback = write(
+            WriterProperties::builder()
+                .set_bloom_filter_from_dictionary(true)
+                .set_dictionary_page_size_limit(1),
+        );
+        assert_eq!(fallback, eager);
+
+        // Columns without dictionary encoding are not affected
+        let no_dictionary = write(
+            WriterProperties::builder()
+                .set_bloom_filter_from_dictionary(true)
+                .set_dictionary_enabled(false),
+        );
+        assert_eq!(no_dictionary, eager);
+    }
+
+    #[test]
+    fn test_encoding_threads() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("i32", DataType::Int32, true),
+            Field::new("i64", DataType::Int64, false),
+            Field::new("f64", DataType::Float64, true),
+            Field::new("utf8", DataType::Utf8, true),
+            Field::new("binary", DataType::Binary, false),
+            Field::new_list("list", Field::new_list_field(DataType::Int32, true), true),
+            Field::new_struct(
+                "struct",
+                vec![
+                    Field::new("a", DataType::Utf8, true),
+                    Field::new("b", DataType::Boolean, true),
+                ],
+                true,
+            ),
+        ]));
+        let batch =
+            arrow::util::data_gen::create_random_batch(schema.clone(), 1000, 0.2, 0.5).unwrap();
+
+        let write = |encoding_threads: usize| {
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(300)
+                .set_data_page_row_count_limit(100)
+                .set_compression(crate::basic::Compression::SNAPPY)
+                .build();
+            let options = ArrowWriterOptions::new()
+                .with_properties(props)
+                .with_encoding_threads(encoding_threads);
+            let mut buf = Vec::new();
+            let mut writer =
+                ArrowWriter::try_new_with_options(&mut buf, schema.clone(), options).unwrap();
+            writer.write(&batch.slice(0, 500)).unwrap();
+            writer.write(&batch.slice(500, 500)).unwrap();
+            writer.close().unwrap();
+            buf
+        };
+
+        let expected = write(1);
+        for encoding_threads in [2, 3, 8, 100] {
+            let buf = write(encoding_threads);
+            assert_eq!(buf, expected, "{encoding_threads} threads");
+        }
+
+        let reader = ParquetRecordBatchReader::try_new(Bytes::from(expected), 1000).unwrap();
+        let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+
     #[test]
     fn large_binary_single_column() {
         let one_vec: Vec<u8> = (0..SMALL_SIZE as u8).collect();
@@ -3481,6 +3833,50 @@ mod tests {
         assert_eq!(back.column(0).as_ref(), batch.column(0).as_ref());
     }
 
+    #[test]
+    fn test_max_row_group_bytes() {
+        let schema = Arc::new(Schema::new(vec![Field::new("s", DataType::Utf8, false)]));
+        let batch = |start: usize| {
+            let values = (start..start + 1000).map(|i| format!("{i:0100}"));
+            let array = Arc::new(StringArray::from_iter_values(values)) as ArrayRef;
+            RecordBatch::try_new(schema.clone(), vec![array]).unwrap()
+        };
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_max_row_group_bytes(Some(100_000))
+            .build();
+        assert_eq!(props.max_row_group_bytes(), Some(100_000));
+        let mut writer = ArrowWriter::try_new(vec![], schema.clone(), Some(props)).unwrap();
+        for i in 0..10 {
+            writer.write(&batch(i * 1000)).unwrap();
+        }
+        let buf = writer.into_inner().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap();
+        let row_groups = builder.metadata().row_groups();
+        assert!(row_groups.len() > 5, "{}", row_groups.len());
+        let (last, row_groups) = row_groups.split_last().unwrap();
+        for row_group in row_groups {
+            // Rows take 104 bytes, so row groups contain roughly 100_000 / 104 rows
+            let size = row_group.compressed_size();
+            assert!((95_000..=110_000).contains(&size), "{size}");
+        }
+        assert!(last.compressed_size() <= 110_000);
+
+        let read: Vec<_> = builder
+            .build()
+            .unwrap()
+            .collect::<ArrowResult<_>>()
+            .unwrap();
+        let read = arrow_select::concat::concat_batches(&schema, &read).unwrap();
+        let expected = (0..10).map(|i| batch(i * 1000)).collect::<Vec<_>>();
+        assert_eq!(
+            read,
+            arrow_select::concat::concat_batches(&schema, &expected).unwrap()
+        );
+    }
+
     #[test]
     fn in_progress_accounting() {
         // define schema
@@ -3633,6 +4029,130 @@ mod tests {
         assert!(matches!(b_idx, Index::NONE), "{b_idx:?}");
     }
 
+    #[test]
+    fn test_page_index_position() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_iter_values(0..100)),
+                Arc::new(StringArray::from_iter(
+                    (0..100).map(|i| (i % 3 != 0).then(|| i.to_string())),
+                )),
+            ],
+        )
+        .unwrap();
+
+        let write = |position: PageIndexPosition| {
+            let props = WriterProperties::builder()
+                .set_max_row_group_size(30)
+                .set_data_page_row_count_limit(10)
+                .set_write_batch_size(10)
+                .set_page_index_position(position)
+                .set_bloom_filter_enabled(true)
+                .set_bloom_filter_ndv(100)
+                .build();
+            let mut buf = Vec::with_capacity(1024);
+            let mut writer = ArrowWriter::try_new(&mut buf, schema.clone(), Some(props)).unwrap();
+            writer.write(&batch).unwrap();
+            assert_eq!(writer.flushed_row_groups().len(), 3);
+            if position == PageIndexPosition::AfterRowGroup {
+                assert!(writer.metrics().page_index_bytes > 0);
+                // the page index is written immediately and recorded in the row group metadata
+                let row_group = &writer.flushed_row_groups()[0];
+                let column = row_group.column(0);
+                let next_row_group_offset = writer.flushed_row_groups()[1].file_offset().unwrap();
+                assert!(column.column_index_offset().unwrap() < next_row_group_offset);
+                assert!(column.offset_index_offset().unwrap() < next_row_group_offset);
+                assert!(
+                    column.bloom_filter_offset().unwrap() < column.column_index_offset().unwrap()
+                );
+            } else {
+                assert_eq!(writer.metrics().page_index_bytes, 0);
+            }
+            writer.close().unwrap();
+            Bytes::from(buf)
+        };
+
+        let at_end = write(PageIndexPosition::End);
+        let after_row_group = write(PageIndexPosition::AfterRowGroup);
+        assert_eq!(at_end.len(), after_row_group.len());
+
+        let options = ReadOptionsBuilder::new().with_page_index().build();
+        let expected = SerializedFileReader::new_with_options(at_end, options).unwrap();
+        let options = ReadOptionsBuilder::new().with_page_index().build();
+        let actual =
+            SerializedFileReader::new_with_options(after_row_group.clone(), options).unwrap();
+        assert_eq!(actual.metadata().num_row_groups(), 4);
+        assert_eq!(
+            actual.metadata().column_index(),
+            expected.metadata().column_index()
+        );
+        // page locations are shifted by the different position of the page index
+        let page_sizes = |reader: &SerializedFileReader<Bytes>| {
+            let offset_index = reader.metadata().offset_index().unwrap();
+            offset_index
+                .iter()
+                .flatten()
+                .flat_map(|c| c.page_locations.iter())
+                .map(|p| (p.compressed_page_size, p.first_row_index))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(page_sizes(&actual), page_sizes(&expected));
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(after_row_group)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        assert_eq!(
+            arrow::compute::concat_batches(&schema, &batches).unwrap(),
+            batch
+        );
+    }
+
+    #[test]
+    fn test_writer_metrics() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2]))])
+                .unwrap();
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_bloom_filter_position(BloomFilterPosition::End)
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, schema, Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.flush().unwrap();
+        assert_eq!(writer.metrics().bloom_filter_bytes, 0);
+        writer.finish().unwrap();
+
+        let metrics = writer.metrics().clone();
+        assert!(metrics.bloom_filter_bytes > 0);
+        assert!(metrics.page_index_bytes > 0);
+        assert!(metrics.footer_bytes > 0);
+        let file_len = writer.bytes_written();
+        let footer_start = file_len - metrics.footer_bytes;
+        drop(writer);
+        let bytes = Bytes::from(buf);
+        assert_eq!(bytes.len(), file_len);
+        // the footer is preceded by the page index, and the page index by the bloom filters
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&bytes)
+            .unwrap();
+        let column = metadata.row_group(0).column(0);
+        let page_index_end = column.offset_index_offset().unwrap() as usize
+            + column.offset_index_length().unwrap() as usize;
+        assert_eq!(page_index_end, footer_start);
+        let bloom_filter_end = column.bloom_filter_offset().unwrap() as usize
+            + column.bloom_filter_length().unwrap() as usize;
+        assert_eq!(bloom_filter_end, footer_start - metrics.page_index_bytes);
+    }
+
     #[test]
     fn test_disabled_statistics_with_chunk() {
         let file_schema = Schema::new(vec![
@@ -3733,6 +4253,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_arrow_writer_schema_fingerprint() {
+        let write = |schema: Schema, options: ArrowWriterOptions| {
+            let mut buf = vec![];
+            let schema = Arc::new(schema);
+            let mut writer =
+                ArrowWriter::try_new_with_options(&mut buf, schema.clone(), options).unwrap();
+            writer.write(&RecordBatch::new_empty(schema)).unwrap();
+            writer.close().unwrap();
+            ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf)).unwrap()
+        };
+        let fields = vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new_list("b", Field::new_list_field(DataType::Utf8, true), true),
+        ];
+        let schema = Schema::new(fields.clone());
+
+        let reader = write(schema.clone(), ArrowWriterOptions::new());
+        let kv = reader
+            .metadata()
+            .file_metadata()
+            .key_value_metadata()
+            .unwrap();
+        let stored = kv
+            .iter()
+            .find(|kv| kv.key == ARROW_SCHEMA_FINGERPRINT_META_KEY)
+            .and_then(|kv| kv.value.clone())
+            .unwrap();
+        assert_eq!(
+            stored,
+            format!("{:016x}", reader.schema_fingerprint().unwrap())
+        );
+        // The fingerprint is not part of the schema read back
+        assert_eq!(reader.schema().as_ref(), &schema);
+        // The fingerprint is stable
+        assert_eq!(reader.schema_fingerprint(), Some(0x29efd42650c308c0));
+
+        // Without the embedded arrow metadata, there is no fingerprint
+        let skip_options = ArrowWriterOptions::new().with_skip_arrow_metadata(true);
+        let reader = write(schema.clone(), skip_options);
+        assert_eq!(reader.schema_fingerprint(), None);
+
+        // The schema metadata does not change the fingerprint, unlike the fields
+        let with_metadata = schema
+            .clone()
+            .with_metadata([("k".into(), "v".into())].into());
+        let reader = write(with_metadata, ArrowWriterOptions::new());
+        assert_eq!(
+            reader.schema_fingerprint(),
+            Some(arrow_schema_fingerprint(&schema))
+        );
+
+        let mut changed = fields.clone();
+        changed[1] = Field::new_list("b", Field::new_list_field(DataType::Utf8, false), true);
+        let reader = write(Schema::new(changed), ArrowWriterOptions::new());
+        assert_ne!(
+            reader.schema_fingerprint(),
+            Some(arrow_schema_fingerprint(&schema))
+        );
+
+        let mut changed = fields;
+        changed[0] = changed[0]
+            .clone()
+            .with_metadata([("PARQUET:field_id".into(), "1".into())].into());
+        let reader = write(Schema::new(changed), ArrowWriterOptions::new());
+        assert_ne!(
+            reader.schema_fingerprint(),
+            Some(arrow_schema_fingerprint(&schema))
+        );
+    }
+
     #[test]
     fn mismatched_schemas() {
         let batch_schema = Schema::new(vec![Field::new("count", DataType::Int32, false)]);
@@ -3925,6 +4516,59 @@ mod tests {
         assert_eq!(stats.min_value.unwrap(), "Bl".as_bytes());
     }
 
+    #[test]
+    fn test_fallback_encodings() {
+        // Values with long shared prefixes, then values without
+        let prefixed = (0..100).map(|i| format!("common/path/to/file_{i:03}"));
+        let random =
+            (0..100).map(|i| format!("{:x}", (i as u64 + 1).wrapping_mul(0x9E3779B97F4A7C15)));
+        let values = StringArray::from_iter_values(prefixed.chain(random));
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(DataType::Utf8, false)),
+            arrow_buffer::OffsetBuffer::from_lengths(vec![1; 200]),
+            Arc::new(values.clone()),
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([
+            ("flat", Arc::new(values) as ArrayRef),
+            ("nested", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(50)
+            .set_write_batch_size(50)
+            .set_fallback_encodings(vec![Encoding::DELTA_BYTE_ARRAY, Encoding::PLAIN])
+            .set_fallback_encoding_ratio(0.7)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let data = Bytes::from(buf);
+        let reader = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .build()
+            .unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            arrow_select::concat::concat_batches(&batch.schema(), &read).unwrap(),
+            batch
+        );
+
+        let reader = SerializedFileReader::new(data).unwrap();
+        let row_group = reader.metadata().row_group(0);
+        for column in row_group.columns() {
+            let stats = column.page_encoding_stats().unwrap();
+            let encodings: Vec<_> = stats.iter().map(|s| (s.encoding, s.count)).collect();
+            // DELTA_BYTE_ARRAY barely reduces the size of the first page of random values
+            assert_eq!(encodings.first(), Some(&(Encoding::DELTA_BYTE_ARRAY, 3)));
+            assert_eq!(encodings.last().unwrap().0, Encoding::PLAIN);
+        }
+    }
+
     #[test]
     fn test_page_encoding_statistics_roundtrip() {
         let batch_schema = Schema::new(vec![Field::new(
@@ -26,7 +26,7 @@ use std::fmt::Formatter;
 use std::io::SeekFrom;
 use std::ops::Range;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 
 use bytes::{Buf, Bytes};
@@ -41,7 +41,7 @@ use arrow_schema::{DataType, Fields, Schema, SchemaRef};
 use crate::arrow::array_reader::{ArrayReaderBuilder, RowGroups};
 use crate::arrow::arrow_reader::{
     ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
-    RowFilter, RowSelection,
+    PruningReport, RowFilter, RowGroupFilter, RowSelection,
 };
 use crate::arrow::ProjectionMask;
 
@@ -52,6 +52,7 @@ use crate::column::page::{PageIterator, PageReader};
 use crate::errors::{ParquetError, Result};
 use crate::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
+use crate::file::properties::ReaderPropertiesPtr;
 use crate::file::reader::{ChunkReader, Length, SerializedPageReader};
 use crate::format::{BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash};
 
@@ -173,7 +174,10 @@ impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader for T {
     ) -> BoxFuture<'a, Result<Arc<ParquetMetaData>>> {
         async move {
             let metadata_reader = ParquetMetaDataReader::new()
-                .with_page_indexes(options.is_some_and(|o| o.page_index));
+                .with_page_indexes(options.is_some_and(|o| o.page_index))
+                .with_ignore_unknown_logical_types(
+                    options.is_some_and(|o| o.ignore_unknown_logical_types),
+                );
 
             #[cfg(feature = "encryption")]
             let metadata_reader = metadata_reader.with_decryption_properties(
@@ -412,6 +416,28 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         Self::new_builder(AsyncReader(input), metadata)
     }
 
+    /// Prefetch the next row group while decoding the current one, as long as the
+    /// compressed size of the projected column chunks of both row groups does not exceed
+    /// `memory_budget` bytes
+    ///
+    /// By default, the [`ParquetRecordBatchStream`] only starts fetching the data of a row
+    /// group once all batches of the previous one have been returned, so that decoding
+    /// and I/O never overlap. With prefetching, the fetches of the next row group are
+    /// issued as soon as the current one has been fetched, and progress whenever the
+    /// stream is polled for a batch, which hides the latency of object stores from
+    /// consumers that keep polling the stream.
+    ///
+    /// Prefetching is adaptive: a row group that does not fit in the budget alongside
+    /// the row group being decoded is fetched once the latter has been decoded, as
+    /// without prefetching, so the memory buffered by the stream stays within the budget
+    /// except for row groups that do not fit in it on their own.
+    pub fn with_prefetch_budget(self, memory_budget: usize) -> Self {
+        Self {
+            prefetch_budget: Some(memory_budget),
+            ..self
+        }
+    }
+
     /// Read bloom filter for a column in a row group
     ///
     /// Returns `None` if the column does not have a bloom filter
@@ -485,7 +511,7 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
     pub fn build(self) -> Result<ParquetRecordBatchStream<T>> {
         let num_row_groups = self.metadata.row_groups().len();
 
-        let row_groups = match self.row_groups {
+        let mut row_groups: VecDeque<usize> = match &self.row_groups {
             Some(row_groups) => {
                 if let Some(col) = row_groups.iter().find(|x| **x >= num_row_groups) {
                     return Err(general_err!(
@@ -494,15 +520,18 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
                         num_row_groups
                     ));
                 }
-                row_groups.into()
+                row_groups.iter().copied().collect()
             }
             None => (0..self.metadata.row_groups().len()).collect(),
         };
+        let (selection, report) = self.pruned_selection(row_groups.make_contiguous())?;
+        let report = Arc::new(Mutex::new(report));
 
         // Try to avoid allocate large buffer
         let batch_size = self
             .batch_size
             .min(self.metadata.file_metadata().num_rows() as usize);
+        let props = self.reader_properties();
         let reader_factory = ReaderFactory {
             input: self.input.0,
             filter: self.filter,
@@ -510,6 +539,9 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             fields: self.fields,
             limit: self.limit,
             offset: self.offset,
+            max_batch_bytes: self.max_batch_bytes,
+            report: Arc::clone(&report),
+            props,
         };
 
         // Ensure schema of ParquetRecordBatchStream respects projection, and does
@@ -527,11 +559,16 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             metadata: self.metadata,
             batch_size,
             row_groups,
+            row_group_filter: self.row_group_filter,
             projection: self.projection,
-            selection: self.selection,
+            selection,
             schema,
             reader_factory: Some(reader_factory),
             state: StreamState::Init,
+            prefetch_budget: self.prefetch_budget,
+            decoding_bytes: 0,
+            prefetch: None,
+            report,
         })
     }
 }
@@ -560,6 +597,15 @@ struct ReaderFactory<T> {
 
     /// Offset to apply to the next
     offset: Option<usize>,
+
+    /// Maximum number of decoded bytes in each batch
+    max_batch_bytes: Option<usize>,
+
+    /// The pruning report, shared with the [`ParquetRecordBatchStream`]
+    report: Arc<Mutex<PruningReport>>,
+
+    /// Properties used to create the page readers
+    props: ReaderPropertiesPtr,
 }
 
 impl<T> ReaderFactory<T>
@@ -595,14 +641,17 @@ where
             offset_index,
             row_group_idx,
             metadata: self.metadata.as_ref(),
+            props: &self.props,
         };
 
         let filter = self.filter.as_mut();
-        let mut plan_builder = ReadPlanBuilder::new(batch_size).with_selection(selection);
+        let mut plan_builder = ReadPlanBuilder::new(batch_size)
+            .with_max_batch_bytes(self.max_batch_bytes)
+            .with_selection(selection);
 
         // Update selection based on any filters
         if let Some(filter) = filter {
-            for predicate in filter.predicates.iter_mut() {
+            for (idx, predicate) in filter.predicates.iter_mut().enumerate() {
                 if !plan_builder.selects_any() {
                     return Ok((self, None)); // ruled out entire row group
                 }
@@ -616,7 +665,17 @@ where
                 let array_reader = ArrayReaderBuilder::new(&row_group)
                     .build_array_reader(self.fields.as_deref(), predicate.projection())?;
 
+                let rows_before = plan_builder
+                    .num_rows_selected()
+                    .unwrap_or(row_group.row_count);
                 plan_builder = plan_builder.with_predicate(array_reader, predicate.as_mut())?;
+                let rows_after = plan_builder
+                    .num_rows_selected()
+                    .unwrap_or(row_group.row_count);
+                self.report
+                    .lock()
+                    .unwrap()
+                    .record_row_filter(idx, rows_before, rows_after);
             }
         }
 
@@ -716,6 +775,9 @@ pub struct ParquetRecordBatchStream<T> {
 
     row_groups: VecDeque<usize>,
 
+    /// Optional callback deciding whether to read each row group
+    row_group_filter: Option<RowGroupFilter>,
+
     projection: ProjectionMask,
 
     batch_size: usize,
@@ -726,6 +788,18 @@ pub struct ParquetRecordBatchStream<T> {
     reader_factory: Option<ReaderFactory<T>>,
 
     state: StreamState<T>,
+
+    /// Maximum compressed size of the row groups being decoded and prefetched
+    prefetch_budget: Option<usize>,
+
+    /// Compressed size of the projected column chunks of the row group being decoded
+    decoding_bytes: usize,
+
+    /// The compressed size and read of the prefetched row group, if any
+    prefetch: Option<(usize, BoxFuture<'static, ReadResult<T>>)>,
+
+    /// The pruning report of the stream, shared with the [`ReaderFactory`]
+    report: Arc<Mutex<PruningReport>>,
 }
 
 impl<T> std::fmt::Debug for ParquetRecordBatchStream<T> {
@@ -736,6 +810,8 @@ impl<T> std::fmt::Debug for ParquetRecordBatchStream<T> {
             .field("batch_size", &self.batch_size)
             .field("projection", &self.projection)
             .field("state", &self.state)
+            .field("prefetch_budget", &self.prefetch_budget)
+            .field("prefetching", &self.prefetch.is_some())
             .finish()
     }
 }
@@ -748,6 +824,57 @@ impl<T> ParquetRecordBatchStream<T> {
     pub fn schema(&self) -> &SchemaRef {
         &self.schema
     }
+
+    /// Returns a [`PruningReport`] of the row groups, pages and rows pruned so far by the
+    /// filters and predicates of the [`ParquetRecordBatchStreamBuilder`] of this stream
+    ///
+    /// The row groups are pruned as they are read, so the report is only complete once
+    /// the stream has ended.
+    pub fn pruning_report(&self) -> PruningReport {
+        self.report.lock().unwrap().clone()
+    }
+
+    /// Returns false if the row group filter skips the row group `row_group_idx`, recording
+    /// it in the pruning report
+    fn should_read_row_group(&self, row_group_idx: usize) -> bool {
+        let row_group = self.metadata.row_group(row_group_idx);
+        let read = self.row_group_filter.as_ref().is_none_or(|f| f(row_group));
+        if !read {
+            self.report.lock().unwrap().skip_row_group(row_group_idx);
+        }
+        read
+    }
+
+    /// Pops the next row group to read, skipping the row groups skipped by the row group
+    /// filter, and returns its index, selection and the compressed size of its projected
+    /// column chunks
+    ///
+    /// Returns `None` if there are no more row groups, or if the size of the next row group
+    /// exceeds `max_bytes`, in which case it is not popped. The row group filter is only
+    /// called for the row groups that are popped, so that it is called once per row group.
+    fn pop_row_group(&mut self, max_bytes: usize) -> Option<(usize, Option<RowSelection>, usize)> {
+        while let Some(&row_group_idx) = self.row_groups.front() {
+            let row_group = self.metadata.row_group(row_group_idx);
+            let bytes = row_group
+                .columns()
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| self.projection.leaf_included(*idx))
+                .map(|(_, column)| column.compressed_size() as usize)
+                .sum();
+            if bytes > max_bytes {
+                return None;
+            }
+
+            self.row_groups.pop_front();
+            let row_count = row_group.num_rows() as usize;
+            let selection = self.selection.as_mut().map(|s| s.split_off(row_count));
+            if self.should_read_row_group(row_group_idx) {
+                return Some((row_group_idx, selection, bytes));
+            }
+        }
+        None
+    }
 }
 
 impl<T> ParquetRecordBatchStream<T>
@@ -785,6 +912,10 @@ where
 
                     let selection = self.selection.as_mut().map(|s| s.split_off(row_count));
 
+                    if !self.should_read_row_group(row_group_idx) {
+                        continue;
+                    }
+
                     let reader_factory = self.reader_factory.take().expect("lost reader factory");
 
                     let (reader_factory, maybe_reader) = reader_factory
@@ -813,6 +944,43 @@ where
     }
 }
 
+impl<T> ParquetRecordBatchStream<T>
+where
+    T: AsyncFileReader + Unpin + Send + 'static,
+{
+    /// Starts reading the next row group if it fits in the prefetch budget alongside the
+    /// row group being decoded, see [`ParquetRecordBatchStreamBuilder::with_prefetch_budget`]
+    fn start_prefetch(&mut self) {
+        let Some(budget) = self.prefetch_budget else {
+            return;
+        };
+        let max_bytes = budget.saturating_sub(self.decoding_bytes);
+        let Some((row_group_idx, selection, bytes)) = self.pop_row_group(max_bytes) else {
+            return;
+        };
+        let reader_factory = self.reader_factory.take().expect("lost reader factory");
+        let fut = reader_factory
+            .read_row_group(
+                row_group_idx,
+                selection,
+                self.projection.clone(),
+                self.batch_size,
+            )
+            .boxed();
+        self.prefetch = Some((bytes, fut));
+    }
+
+    /// Drives the read of the prefetched row group, if any, keeping its result until the
+    /// row group being decoded has been fully returned
+    fn poll_prefetch(&mut self, cx: &mut Context<'_>) {
+        if let Some((_, fut)) = &mut self.prefetch {
+            if let Poll::Ready(result) = fut.poll_unpin(cx) {
+                *fut = futures::future::ready(result).boxed();
+            }
+        }
+    }
+}
+
 impl<T> Stream for ParquetRecordBatchStream<T>
 where
     T: AsyncFileReader + Unpin + Send + 'static,
@@ -824,6 +992,7 @@ where
             match &mut self.state {
                 StreamState::Decoding(batch_reader) => match batch_reader.next() {
                     Some(Ok(batch)) => {
+                        self.poll_prefetch(cx);
                         return Poll::Ready(Some(Ok(batch)));
                     }
                     Some(Err(e)) => {
@@ -833,17 +1002,20 @@ where
                     None => self.state = StreamState::Init,
                 },
                 StreamState::Init => {
-                    let row_group_idx = match self.row_groups.pop_front() {
-                        Some(idx) => idx,
+                    if let Some((bytes, fut)) = self.prefetch.take() {
+                        self.decoding_bytes = bytes;
+                        self.state = StreamState::Reading(fut);
+                        continue;
+                    }
+
+                    let (row_group_idx, selection, bytes) = match self.pop_row_group(usize::MAX) {
+                        Some(next) => next,
                         None => return Poll::Ready(None),
                     };
+                    self.decoding_bytes = bytes;
 
                     let reader = self.reader_factory.take().expect("lost reader factory");
 
-                    let row_count = self.metadata.row_group(row_group_idx).num_rows() as usize;
-
-                    let selection = self.selection.as_mut().map(|s| s.split_off(row_count));
-
                     let fut = reader
                         .read_row_group(
                             row_group_idx,
@@ -860,7 +1032,10 @@ where
                         self.reader_factory = Some(reader_factory);
                         match maybe_reader {
                             // Read records from [`ParquetRecordBatchReader`]
-                            Some(reader) => self.state = StreamState::Decoding(reader),
+                            Some(reader) => {
+                                self.state = StreamState::Decoding(reader);
+                                self.start_prefetch();
+                            }
                             // All rows skipped, read next row group
                             None => self.state = StreamState::Init,
                         }
@@ -884,6 +1059,7 @@ struct InMemoryRowGroup<'a> {
     row_count: usize,
     row_group_idx: usize,
     metadata: &'a ParquetMetaData,
+    props: &'a ReaderPropertiesPtr,
 }
 
 impl InMemoryRowGroup<'_> {
@@ -1006,11 +1182,12 @@ impl RowGroups for InMemoryRowGroup<'_> {
                     .filter(|index| !index.is_empty())
                     .map(|index| index[i].page_locations.clone());
                 let column_chunk_metadata = self.metadata.row_group(self.row_group_idx).column(i);
-                let page_reader = SerializedPageReader::new(
+                let page_reader = SerializedPageReader::new_with_properties(
                     data.clone(),
                     column_chunk_metadata,
                     self.row_count,
                     page_locations,
+                    Arc::clone(self.props),
                 )?;
                 let page_reader = page_reader.add_crypto_context(
                     self.row_group_idx,
@@ -1113,20 +1290,22 @@ mod tests {
     use crate::arrow::schema::parquet_to_arrow_schema_and_fields;
     use crate::arrow::ArrowWriter;
     use crate::file::metadata::ParquetMetaDataReader;
-    use crate::file::properties::WriterProperties;
+    use crate::file::properties::{ReaderProperties, WriterProperties};
+    use crate::util::test_common::codec_util::XorCodecFactory;
     use arrow::compute::kernels::cmp::eq;
     use arrow::error::Result as ArrowResult;
     use arrow_array::builder::{ListBuilder, StringBuilder};
     use arrow_array::cast::AsArray;
     use arrow_array::types::Int32Type;
     use arrow_array::{
-        Array, ArrayRef, Int32Array, Int8Array, RecordBatchReader, Scalar, StringArray,
-        StructArray, UInt64Array,
+        Array, ArrayRef, BooleanArray, Int32Array, Int8Array, RecordBatchReader, Scalar,
+        StringArray, StructArray, UInt64Array,
     };
     use arrow_schema::{DataType, Field, Schema};
     use futures::{StreamExt, TryStreamExt};
     use rand::{rng, Rng};
     use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
     use tempfile::tempfile;
 
@@ -1684,6 +1863,159 @@ mod tests {
         assert_eq!(requests.lock().unwrap().len(), 3);
     }
 
+    #[tokio::test]
+    async fn test_row_group_filter() {
+        let data = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..8)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let test = TestReader::new(buf.into());
+
+        // The selection includes the rows of row groups skipped by the filter
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(1),
+            RowSelector::select(4),
+            RowSelector::skip(2),
+            RowSelector::select(1),
+        ]);
+        let stream = ParquetRecordBatchStreamBuilder::new(test.clone())
+            .await
+            .unwrap()
+            .with_row_group_filter(|rg| rg.ordinal() != Some(2))
+            .with_row_selection(selection)
+            .build()
+            .unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        assert_eq!(values, [1, 2, 3, 7]);
+        // The pruned row group is not fetched
+        assert_eq!(test.requests.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_pruning_report() {
+        let data = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..8)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let test = TestReader::new(buf.into());
+
+        let builder = ParquetRecordBatchStreamBuilder::new(test).await.unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        let predicate = |f: fn(i32) -> bool| {
+            ArrowPredicateFn::new(mask.clone(), move |b| {
+                let a = b.column(0).as_primitive::<Int32Type>();
+                Ok(BooleanArray::from_unary(a, f))
+            })
+        };
+        let filter = RowFilter::new(vec![
+            Box::new(predicate(|a| a % 2 == 0)),
+            Box::new(predicate(|a| a > 2)),
+        ]);
+        let mut stream = builder
+            .with_row_group_filter(|rg| rg.ordinal() != Some(2))
+            .with_row_filter(filter)
+            .build()
+            .unwrap();
+        let batches: Vec<_> = (&mut stream).try_collect().await.unwrap();
+        let values: Vec<_> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        assert_eq!(values, [6]);
+
+        let report = stream.pruning_report();
+        assert_eq!(report.skipped_row_groups(), &[2]);
+        assert!(report.page_predicates().is_empty());
+        let rows: Vec<_> = report
+            .row_filter_predicates()
+            .iter()
+            .map(|p| (p.rows_evaluated(), p.rows_selected()))
+            .collect();
+        assert_eq!(rows, [(6, 3), (3, 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_budget() {
+        let data = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..12)) as ArrayRef,
+        )])
+        .unwrap();
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(4)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let data: Bytes = buf.into();
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        let row_group_bytes = metadata.row_group(0).compressed_size() as usize;
+
+        // Returns the number of requests issued once the first batch has been returned,
+        // and the values of all batches
+        let read = |budget: Option<usize>| {
+            let test = TestReader::new(data.clone());
+            async move {
+                let filter_calls = Arc::new(AtomicUsize::new(0));
+                let calls = Arc::clone(&filter_calls);
+                let mut builder = ParquetRecordBatchStreamBuilder::new(test.clone())
+                    .await
+                    .unwrap()
+                    .with_batch_size(2)
+                    .with_row_group_filter(move |_| {
+                        calls.fetch_add(1, Ordering::Relaxed);
+                        true
+                    });
+                if let Some(budget) = budget {
+                    builder = builder.with_prefetch_budget(budget);
+                }
+                let mut stream = builder.build().unwrap();
+                let first = stream.next().await.unwrap().unwrap();
+                let requests = test.requests.lock().unwrap().len();
+                let batches: Vec<_> = stream.try_collect().await.unwrap();
+                let values: Vec<_> = std::iter::once(&first)
+                    .chain(&batches)
+                    .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+                    .collect();
+                assert_eq!(test.requests.lock().unwrap().len(), 3);
+                // The filter is called once per row group, even if a row group is deferred
+                // as it does not fit in the budget
+                assert_eq!(filter_calls.load(Ordering::Relaxed), 3);
+                (requests, values)
+            }
+        };
+
+        let expected: Vec<_> = (0..12).collect();
+        assert_eq!(read(None).await, (1, expected.clone()));
+        // The next row group is fetched while the first one is decoded
+        assert_eq!(read(Some(2 * row_group_bytes)).await, (2, expected.clone()));
+        // Unless it does not fit in the budget
+        assert_eq!(read(Some(row_group_bytes)).await, (1, expected));
+    }
+
     #[tokio::test]
     async fn test_limit_multiple_row_groups() {
         let a = StringArray::from_iter_values(["a", "b", "b", "b", "c", "c"]);
@@ -1869,6 +2201,7 @@ mod tests {
             metadata.file_metadata().schema_descr(),
             ProjectionMask::all(),
             None,
+            false,
         )
         .unwrap();
 
@@ -1883,6 +2216,9 @@ mod tests {
             filter: None,
             limit: None,
             offset: None,
+            max_batch_bytes: None,
+            report: Default::default(),
+            props: Arc::new(ReaderProperties::builder().build()),
         };
 
         let mut skip = true;
@@ -2386,4 +2722,44 @@ mod tests {
         let result = reader.try_collect::<Vec<_>>().await.unwrap();
         assert_eq!(result.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_codec_factory() {
+        let batch = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+        )])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_compression(crate::basic::Compression::LZO)
+            .set_codec_factory(Arc::new(XorCodecFactory))
+            .set_max_row_group_size(30)
+            .build();
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Reading without the factory fails as there is no built-in LZO codec
+        let async_reader = TestReader::new(data.clone());
+        let stream = ParquetRecordBatchStreamBuilder::new(async_reader)
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        let err = stream.try_collect::<Vec<_>>().await.unwrap_err();
+        assert!(err.to_string().contains("not supported"), "{err}");
+
+        let async_reader = TestReader::new(data);
+        let options = ArrowReaderOptions::new().with_codec_factory(Arc::new(XorCodecFactory));
+        let stream = ParquetRecordBatchStreamBuilder::new_with_options(async_reader, options)
+            .await
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches = stream.try_collect::<Vec<_>>().await.unwrap();
+        let read = arrow_select::concat::concat_batches(&batch.schema(), &batches).unwrap();
+        assert_eq!(read, batch);
+    }
 }
@@ -202,7 +202,10 @@ impl AsyncFileReader for ParquetObjectReader {
             let mut metadata = ParquetMetaDataReader::new()
                 .with_column_indexes(self.preload_column_index)
                 .with_offset_indexes(self.preload_offset_index)
-                .with_prefetch_hint(self.metadata_size_hint);
+                .with_prefetch_hint(self.metadata_size_hint)
+                .with_ignore_unknown_logical_types(
+                    options.is_some_and(|o| o.ignore_unknown_logical_types),
+                );
 
             #[cfg(feature = "encryption")]
             if let Some(options) = options {
@@ -64,7 +64,7 @@ use crate::{
     arrow::arrow_writer::ArrowWriterOptions,
     arrow::ArrowWriter,
     errors::{ParquetError, Result},
-    file::{metadata::RowGroupMetaData, properties::WriterProperties},
+    file::{metadata::RowGroupMetaData, properties::WriterProperties, writer::WriterMetrics},
     format::{FileMetaData, KeyValue},
 };
 use arrow_array::RecordBatch;
@@ -210,6 +210,14 @@ impl<W: AsyncFileWriter> AsyncArrowWriter<W> {
         self.sync_writer.bytes_written()
     }
 
+    /// Returns metrics describing the time spent writing bloom filters, page indexes
+    /// and the footer, see [`SerializedFileWriter::metrics`]
+    ///
+    /// [`SerializedFileWriter::metrics`]: crate::file::writer::SerializedFileWriter::metrics
+    pub fn metrics(&self) -> &WriterMetrics {
+        self.sync_writer.metrics()
+    }
+
     /// Enqueues the provided `RecordBatch` to be written
     ///
     /// After every sync write by the inner [ArrowWriter], the inner buffer will be
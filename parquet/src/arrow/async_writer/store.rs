@@ -28,6 +28,15 @@ use tokio::io::AsyncWriteExt;
 
 /// [`ParquetObjectWriter`] for writing to parquet to [`ObjectStore`]
 ///
+/// The [`AsyncArrowWriter`] writes the encoded data of each row group once it has been
+/// flushed, which the underlying [`BufWriter`] uploads as the parts of a multipart upload,
+/// so the entire file is never buffered in memory. Once the configured number of parts are
+/// being uploaded, writes wait for an upload to complete, applying backpressure to the
+/// encoding of further row groups. The part size and upload concurrency are configured with
+/// [`BufWriter::with_capacity`] and [`BufWriter::with_max_concurrency`].
+///
+/// [`AsyncArrowWriter`]: crate::arrow::AsyncArrowWriter
+///
 /// ```
 /// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
 /// # use object_store::memory::InMemory;
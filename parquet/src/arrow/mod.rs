@@ -191,7 +191,7 @@ pub mod async_reader;
 pub mod async_writer;
 
 mod record_reader;
-experimental!(mod schema);
+experimental!(pub(crate) mod schema);
 
 use std::sync::Arc;
 
@@ -204,8 +204,9 @@ use crate::schema::types::{SchemaDescriptor, Type};
 use arrow_schema::{FieldRef, Schema};
 
 pub use self::schema::{
-    add_encoded_arrow_schema_to_metadata, encode_arrow_schema, parquet_to_arrow_field_levels,
-    parquet_to_arrow_schema, parquet_to_arrow_schema_by_columns, ArrowSchemaConverter, FieldLevels,
+    add_encoded_arrow_schema_to_metadata, arrow_schema_fingerprint, encode_arrow_schema,
+    parquet_to_arrow_field_levels, parquet_to_arrow_schema, parquet_to_arrow_schema_by_columns,
+    ArrowSchemaConverter, FieldLevels,
 };
 
 /// Schema metadata key used to store serialized Arrow schema
@@ -214,6 +215,13 @@ pub use self::schema::{
 /// encoded. This is the same format used by arrow-cpp systems, such as pyarrow.
 pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 
+/// Schema metadata key used to store the fingerprint of the Arrow schema
+///
+/// The value is the [`arrow_schema_fingerprint`] of the Arrow schema stored in
+/// [`ARROW_SCHEMA_META_KEY`], encoded as 16 lowercase hexadecimal digits. This allows
+/// detecting whether files have compatible schemas without decoding their full schemas.
+pub const ARROW_SCHEMA_FINGERPRINT_META_KEY: &str = "ARROW:schema_fingerprint";
+
 /// The value of this metadata key, if present on [`Field::metadata`], will be used
 /// to populate [`BasicTypeInfo::id`]
 ///
@@ -221,6 +229,14 @@ pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
 /// [`BasicTypeInfo::id`]: crate::schema::types::BasicTypeInfo::id
 pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
 
+/// The key of the [`Field::metadata`] storing the base64 encoded thrift compact encoding of a
+/// logical type annotation not known to this crate, see
+/// [`ArrowReaderOptions::with_ignore_unknown_logical_types`]
+///
+/// [`Field::metadata`]: arrow_schema::Field::metadata
+/// [`ArrowReaderOptions::with_ignore_unknown_logical_types`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_ignore_unknown_logical_types
+pub const PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY: &str = "PARQUET:unknown_logical_type";
+
 /// A [`ProjectionMask`] identifies a set of columns within a potentially nested schema to project
 ///
 /// In particular, a [`ProjectionMask`] can be constructed from a list of leaf column indices
@@ -478,7 +494,7 @@ mod test {
             .unwrap();
         assert_eq!(
             err.to_string(),
-            "EOF: Parquet file too small. Page index range 82..115 overlaps with file metadata 0..357"
+            "EOF: Parquet file too small. Page index range 82..115 overlaps with file metadata 0..402"
         );
     }
 
@@ -19,12 +19,16 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::arrow::schema::primitive::convert_primitive;
-use crate::arrow::{ProjectionMask, PARQUET_FIELD_ID_META_KEY};
+use crate::arrow::{
+    ProjectionMask, PARQUET_FIELD_ID_META_KEY, PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY,
+};
 use crate::basic::{ConvertedType, Repetition};
 use crate::errors::ParquetError;
 use crate::errors::Result;
 use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
 use arrow_schema::{DataType, Field, Fields, SchemaBuilder};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 
 fn get_repetition(t: &Type) -> Repetition {
     let info = t.get_basic_info();
@@ -76,6 +80,33 @@ impl ParquetField {
         match &self.field_type {
             ParquetFieldType::Primitive { .. } => None,
             ParquetFieldType::Group { children } => Some(children),
+            #[cfg(feature = "variant_experimental")]
+            ParquetFieldType::Variant { shredded } => shredded.children(),
+        }
+    }
+
+    /// Converts `self` into an unshredded variant if it is a shredded variant group, whose
+    /// columns are all projected, as indicated by `fully_projected`
+    ///
+    /// Otherwise, e.g. if only some typed columns are projected, the shredded group is read
+    /// as a regular struct, as reassembling it would drop the values of the columns that
+    /// are not projected
+    #[cfg(feature = "variant_experimental")]
+    fn into_unshredded_variant(self, parquet_type: &Type, fully_projected: bool) -> Self {
+        let is_shredded = parquet_type.get_basic_info().logical_type()
+            == Some(crate::basic::LogicalType::Variant)
+            && crate::variant::is_projected_shredded_variant(fully_projected, &self.arrow_type);
+        if !is_shredded {
+            return self;
+        }
+        ParquetField {
+            rep_level: self.rep_level,
+            def_level: self.def_level,
+            nullable: self.nullable,
+            arrow_type: crate::variant::unshredded_variant_type(),
+            field_type: ParquetFieldType::Variant {
+                shredded: Box::new(self),
+            },
         }
     }
 }
@@ -91,6 +122,13 @@ pub enum ParquetFieldType {
     Group {
         children: Vec<ParquetField>,
     },
+    /// A shredded variant, which is read by reassembling the `value` and `typed_value` of
+    /// its group
+    #[cfg(feature = "variant_experimental")]
+    Variant {
+        /// The struct of the shredded variant group
+        shredded: Box<ParquetField>,
+    },
 }
 
 /// Encodes the context of the parent of the field currently under consideration
@@ -124,6 +162,9 @@ struct Visitor {
 
     /// Mask of columns to include
     mask: ProjectionMask,
+
+    /// Whether repeated groups with a single field in a list are always the element type
+    legacy_two_level_lists: bool,
 }
 
 impl Visitor {
@@ -171,6 +212,8 @@ impl Visitor {
         let (def_level, rep_level, nullable) = context.levels(repetition);
 
         let parquet_fields = struct_type.get_fields();
+        #[cfg(feature = "variant_experimental")]
+        let first_col_idx = self.next_col_idx;
 
         // Extract the arrow fields
         let arrow_fields = match &context.data_type {
@@ -240,6 +283,13 @@ impl Visitor {
             field_type: ParquetFieldType::Group { children },
         };
 
+        #[cfg(feature = "variant_experimental")]
+        let struct_field = {
+            let fully_projected =
+                (first_col_idx..self.next_col_idx).all(|idx| self.mask.leaf_included(idx));
+            struct_field.into_unshredded_variant(struct_type, fully_projected)
+        };
+
         Ok(Some(match repetition {
             Repetition::REPEATED => struct_field.into_list(struct_type.name()),
             _ => struct_field,
@@ -458,7 +508,8 @@ impl Visitor {
         if items.len() != 1
             || (!repeated_field.is_list()
                 && !repeated_field.has_single_repeated_child()
-                && (repeated_field.name() == "array"
+                && (self.legacy_two_level_lists
+                    || repeated_field.name() == "array"
                     || repeated_field.name() == format!("{}_tuple", list_type.name())))
         {
             // If the repeated field is a group with multiple fields, then its type is the element
@@ -468,6 +519,9 @@ impl Visitor {
             // the LIST-annotated group's name with _tuple appended then the repeated type is the
             // element type and elements are required. But this rule only applies if the
             // repeated field is not annotated, and the single child field is not `repeated`.
+            //
+            // Files written by legacy writers using 2-level lists may use other names, in
+            // which case the rule is applied regardless of the name if requested.
             let context = VisitorContext {
                 rep_level: context.rep_level,
                 def_level,
@@ -549,7 +603,7 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
     let data_type = field.arrow_type.clone();
     let nullable = field.nullable;
 
-    match arrow_hint {
+    let ret = match arrow_hint {
         Some(hint) => {
             // If the inferred type is a dictionary, preserve dictionary metadata
             #[allow(deprecated)]
@@ -575,8 +629,24 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
                 );
                 ret.set_metadata(meta);
             }
+            #[cfg(feature = "variant_experimental")]
+            if matches!(field.field_type, ParquetFieldType::Variant { .. }) {
+                ret = ret.with_extension_type(crate::variant::VariantType);
+            }
             ret
         }
+    };
+
+    match parquet_type.get_basic_info().unknown_logical_type() {
+        Some(unknown_logical_type) => {
+            let mut meta = ret.metadata().clone();
+            meta.insert(
+                PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY.to_string(),
+                BASE64_STANDARD.encode(unknown_logical_type),
+            );
+            ret.with_metadata(meta)
+        }
+        None => ret,
     }
 }
 
@@ -584,15 +654,22 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
 /// the indexes of leaf columns to project, and `embedded_arrow_schema` the optional
 /// [`Fields`] embedded in the parquet metadata
 ///
+/// If `legacy_two_level_lists` is true, repeated groups with a single field in a list are
+/// always interpreted as the element type, see [`ArrowReaderOptions::with_legacy_two_level_lists`]
+///
 /// Note: This does not support out of order column projection
+///
+/// [`ArrowReaderOptions::with_legacy_two_level_lists`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_legacy_two_level_lists
 pub fn convert_schema(
     schema: &SchemaDescriptor,
     mask: ProjectionMask,
     embedded_arrow_schema: Option<&Fields>,
+    legacy_two_level_lists: bool,
 ) -> Result<Option<ParquetField>> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask,
+        legacy_two_level_lists,
     };
 
     let context = VisitorContext {
@@ -609,6 +686,7 @@ pub fn convert_type(parquet_type: &TypePtr) -> Result<ParquetField> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask: ProjectionMask::all(),
+        legacy_two_level_lists: false,
     };
 
     let context = VisitorContext {
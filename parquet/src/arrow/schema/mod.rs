@@ -25,7 +25,7 @@ use std::sync::Arc;
 use arrow_ipc::writer;
 #[cfg(feature = "arrow_canonical_extension_types")]
 use arrow_schema::extension::{Json, Uuid};
-use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit};
+use arrow_schema::{DataType, Field, Fields, IntervalUnit, Schema, TimeUnit, UnionMode};
 
 use crate::basic::{
     ConvertedType, LogicalType, Repetition, TimeUnit as ParquetTimeUnit, Type as PhysicalType,
@@ -40,7 +40,10 @@ mod primitive;
 use crate::arrow::ProjectionMask;
 pub(crate) use complex::{ParquetField, ParquetFieldType};
 
-use super::PARQUET_FIELD_ID_META_KEY;
+use super::{PARQUET_FIELD_ID_META_KEY, PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY};
+
+/// The name of the Arrow extension type of Parquet variant columns
+const VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
 
 /// Convert Parquet schema to Arrow schema including optional metadata
 ///
@@ -60,7 +63,7 @@ pub fn parquet_to_arrow_schema_by_columns(
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
 ) -> Result<Schema> {
-    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata)?.0)
+    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata, false)?.0)
 }
 
 /// Extracts the arrow metadata
@@ -68,12 +71,14 @@ pub(crate) fn parquet_to_arrow_schema_and_fields(
     parquet_schema: &SchemaDescriptor,
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
+    legacy_two_level_lists: bool,
 ) -> Result<(Schema, Option<ParquetField>)> {
     let mut metadata = parse_key_value_metadata(key_value_metadata).unwrap_or_default();
     let maybe_schema = metadata
         .remove(super::ARROW_SCHEMA_META_KEY)
         .map(|value| get_arrow_schema_from_metadata(&value))
         .transpose()?;
+    metadata.remove(super::ARROW_SCHEMA_FINGERPRINT_META_KEY);
 
     // Add the Arrow metadata to the Parquet metadata skipping keys that collide
     if let Some(arrow_schema) = &maybe_schema {
@@ -83,7 +88,7 @@ pub(crate) fn parquet_to_arrow_schema_and_fields(
     }
 
     let hint = maybe_schema.as_ref().map(|s| s.fields());
-    let field_levels = parquet_to_arrow_field_levels(parquet_schema, mask, hint)?;
+    let field_levels = convert_field_levels(parquet_schema, mask, hint, legacy_two_level_lists)?;
     let schema = Schema::new_with_metadata(field_levels.fields, metadata);
     Ok((schema, field_levels.levels))
 }
@@ -126,7 +131,20 @@ pub fn parquet_to_arrow_field_levels(
     mask: ProjectionMask,
     hint: Option<&Fields>,
 ) -> Result<FieldLevels> {
-    match complex::convert_schema(schema, mask, hint)? {
+    convert_field_levels(schema, mask, hint, false)
+}
+
+/// Convert a parquet [`SchemaDescriptor`] to [`FieldLevels`], see [`parquet_to_arrow_field_levels`]
+///
+/// If `legacy_two_level_lists` is true, repeated groups with a single field in a list are
+/// always interpreted as the element type
+pub(crate) fn convert_field_levels(
+    schema: &SchemaDescriptor,
+    mask: ProjectionMask,
+    hint: Option<&Fields>,
+    legacy_two_level_lists: bool,
+) -> Result<FieldLevels> {
+    match complex::convert_schema(schema, mask, hint, legacy_two_level_lists)? {
         Some(field) => match &field.arrow_type {
             DataType::Struct(fields) => Ok(FieldLevels {
                 fields: fields.clone(),
@@ -199,39 +217,231 @@ pub fn encode_arrow_schema(schema: &Schema) -> String {
 }
 
 /// Mutates writer metadata by storing the encoded Arrow schema hint in
-/// [`ARROW_SCHEMA_META_KEY`].
+/// [`ARROW_SCHEMA_META_KEY`], and its fingerprint in [`ARROW_SCHEMA_FINGERPRINT_META_KEY`].
 ///
 /// If there is an existing Arrow schema metadata, it is replaced.
 ///
 /// [`ARROW_SCHEMA_META_KEY`]: crate::arrow::ARROW_SCHEMA_META_KEY
+/// [`ARROW_SCHEMA_FINGERPRINT_META_KEY`]: crate::arrow::ARROW_SCHEMA_FINGERPRINT_META_KEY
 pub fn add_encoded_arrow_schema_to_metadata(schema: &Schema, props: &mut WriterProperties) {
-    let encoded = encode_arrow_schema(schema);
-
-    let schema_kv = KeyValue {
-        key: super::ARROW_SCHEMA_META_KEY.to_string(),
-        value: Some(encoded),
-    };
-
     let meta = props
         .key_value_metadata
         .get_or_insert_with(Default::default);
 
-    // check if ARROW:schema exists, and overwrite it
-    let schema_meta = meta
-        .iter()
-        .enumerate()
-        .find(|(_, kv)| kv.key.as_str() == super::ARROW_SCHEMA_META_KEY);
-    match schema_meta {
-        Some((i, _)) => {
-            meta.remove(i);
-            meta.push(schema_kv);
+    let encoded = encode_arrow_schema(schema);
+    set_key_value(meta, super::ARROW_SCHEMA_META_KEY, encoded);
+    let fingerprint = format!("{:016x}", arrow_schema_fingerprint(schema));
+    set_key_value(meta, super::ARROW_SCHEMA_FINGERPRINT_META_KEY, fingerprint);
+}
+
+/// Sets the value of `key` in `meta`, replacing any existing value
+fn set_key_value(meta: &mut Vec<KeyValue>, key: &str, value: String) {
+    meta.retain(|kv| kv.key != key);
+    meta.push(KeyValue::new(key.to_string(), value));
+}
+
+/// The version of the encoding of the schemas hashed by [`arrow_schema_fingerprint`]
+///
+/// This is the first byte of the encoding, and must be incremented whenever the encoding
+/// of an existing schema changes.
+const FINGERPRINT_VERSION: u8 = 1;
+
+/// Returns a fingerprint of the fields of `schema`, to quickly check whether schemas match
+///
+/// The fingerprint is a hash of the name, data type, nullability and metadata of every
+/// field, including nested fields. Every data type and its parameters are encoded with
+/// explicit tags, rather than their [`Debug`](std::fmt::Debug) representation, and the
+/// encoding is versioned. Unlike [`std::hash::Hash`], the fingerprint is therefore stable
+/// across platforms and releases, and can be persisted, see
+/// [`ARROW_SCHEMA_FINGERPRINT_META_KEY`].
+///
+/// The schema level metadata is not part of the fingerprint, as it commonly contains values
+/// that are specific to a file, such as statistics.
+///
+/// # Example
+/// ```
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use parquet::arrow::arrow_schema_fingerprint;
+/// let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+/// let with_metadata = schema.clone().with_metadata([("k".into(), "v".into())].into());
+/// assert_eq!(arrow_schema_fingerprint(&schema), arrow_schema_fingerprint(&with_metadata));
+///
+/// let not_null = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+/// assert_ne!(arrow_schema_fingerprint(&schema), arrow_schema_fingerprint(&not_null));
+/// ```
+///
+/// [`ARROW_SCHEMA_FINGERPRINT_META_KEY`]: crate::arrow::ARROW_SCHEMA_FINGERPRINT_META_KEY
+pub fn arrow_schema_fingerprint(schema: &Schema) -> u64 {
+    let mut buffer = vec![FINGERPRINT_VERSION];
+    write_fields_fingerprint(&mut buffer, schema.fields());
+    twox_hash::XxHash64::oneshot(0, &buffer)
+}
+
+/// Writes an unambiguous encoding of `fields` to `buffer`
+fn write_fields_fingerprint(buffer: &mut Vec<u8>, fields: &Fields) {
+    buffer.extend_from_slice(&(fields.len() as u64).to_le_bytes());
+    for field in fields {
+        write_field_fingerprint(buffer, field);
+    }
+}
+
+fn write_field_fingerprint(buffer: &mut Vec<u8>, field: &Field) {
+    write_str(buffer, field.name());
+    buffer.push(field.is_nullable() as u8);
+
+    // The metadata is a hash map, whose iteration order is not deterministic
+    let mut metadata: Vec<_> = field.metadata().iter().collect();
+    metadata.sort_unstable();
+    buffer.extend_from_slice(&(metadata.len() as u64).to_le_bytes());
+    for (key, value) in metadata {
+        write_str(buffer, key);
+        write_str(buffer, value);
+    }
+
+    write_data_type_fingerprint(buffer, field.data_type());
+}
+
+/// Writes a tag identifying `data_type`, followed by its parameters
+///
+/// The tags and the encoding of the parameters must not change for existing data types,
+/// see [`FINGERPRINT_VERSION`].
+fn write_data_type_fingerprint(buffer: &mut Vec<u8>, data_type: &DataType) {
+    match data_type {
+        DataType::Null => buffer.push(0),
+        DataType::Boolean => buffer.push(1),
+        DataType::Int8 => buffer.push(2),
+        DataType::Int16 => buffer.push(3),
+        DataType::Int32 => buffer.push(4),
+        DataType::Int64 => buffer.push(5),
+        DataType::UInt8 => buffer.push(6),
+        DataType::UInt16 => buffer.push(7),
+        DataType::UInt32 => buffer.push(8),
+        DataType::UInt64 => buffer.push(9),
+        DataType::Float16 => buffer.push(10),
+        DataType::Float32 => buffer.push(11),
+        DataType::Float64 => buffer.push(12),
+        DataType::Timestamp(unit, tz) => {
+            buffer.push(13);
+            write_time_unit(buffer, unit);
+            match tz {
+                Some(tz) => {
+                    buffer.push(1);
+                    write_str(buffer, tz);
+                }
+                None => buffer.push(0),
+            }
+        }
+        DataType::Date32 => buffer.push(14),
+        DataType::Date64 => buffer.push(15),
+        DataType::Time32(unit) => {
+            buffer.push(16);
+            write_time_unit(buffer, unit);
+        }
+        DataType::Time64(unit) => {
+            buffer.push(17);
+            write_time_unit(buffer, unit);
+        }
+        DataType::Duration(unit) => {
+            buffer.push(18);
+            write_time_unit(buffer, unit);
+        }
+        DataType::Interval(unit) => {
+            buffer.push(19);
+            buffer.push(match unit {
+                IntervalUnit::YearMonth => 0,
+                IntervalUnit::DayTime => 1,
+                IntervalUnit::MonthDayNano => 2,
+            });
+        }
+        DataType::Binary => buffer.push(20),
+        DataType::FixedSizeBinary(size) => {
+            buffer.push(21);
+            buffer.extend_from_slice(&size.to_le_bytes());
+        }
+        DataType::LargeBinary => buffer.push(22),
+        DataType::BinaryView => buffer.push(23),
+        DataType::Utf8 => buffer.push(24),
+        DataType::LargeUtf8 => buffer.push(25),
+        DataType::Utf8View => buffer.push(26),
+        DataType::List(f) => {
+            buffer.push(27);
+            write_field_fingerprint(buffer, f);
+        }
+        DataType::ListView(f) => {
+            buffer.push(28);
+            write_field_fingerprint(buffer, f);
+        }
+        DataType::FixedSizeList(f, size) => {
+            buffer.push(29);
+            buffer.extend_from_slice(&size.to_le_bytes());
+            write_field_fingerprint(buffer, f);
+        }
+        DataType::LargeList(f) => {
+            buffer.push(30);
+            write_field_fingerprint(buffer, f);
+        }
+        DataType::LargeListView(f) => {
+            buffer.push(31);
+            write_field_fingerprint(buffer, f);
+        }
+        DataType::Struct(fields) => {
+            buffer.push(32);
+            write_fields_fingerprint(buffer, fields);
+        }
+        DataType::Union(fields, mode) => {
+            buffer.push(33);
+            buffer.push(match mode {
+                UnionMode::Sparse => 0,
+                UnionMode::Dense => 1,
+            });
+            buffer.extend_from_slice(&(fields.len() as u64).to_le_bytes());
+            for (type_id, f) in fields.iter() {
+                buffer.push(type_id as u8);
+                write_field_fingerprint(buffer, f);
+            }
         }
-        None => {
-            meta.push(schema_kv);
+        DataType::Dictionary(key, value) => {
+            buffer.push(34);
+            write_data_type_fingerprint(buffer, key);
+            write_data_type_fingerprint(buffer, value);
+        }
+        DataType::Decimal32(precision, scale) => write_decimal(buffer, 35, *precision, *scale),
+        DataType::Decimal64(precision, scale) => write_decimal(buffer, 36, *precision, *scale),
+        DataType::Decimal128(precision, scale) => write_decimal(buffer, 37, *precision, *scale),
+        DataType::Decimal256(precision, scale) => write_decimal(buffer, 38, *precision, *scale),
+        DataType::Map(f, sorted) => {
+            buffer.push(39);
+            buffer.push(*sorted as u8);
+            write_field_fingerprint(buffer, f);
+        }
+        DataType::RunEndEncoded(run_ends, values) => {
+            buffer.push(40);
+            write_field_fingerprint(buffer, run_ends);
+            write_field_fingerprint(buffer, values);
         }
     }
 }
 
+fn write_time_unit(buffer: &mut Vec<u8>, unit: &TimeUnit) {
+    buffer.push(match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    });
+}
+
+fn write_decimal(buffer: &mut Vec<u8>, tag: u8, precision: u8, scale: i8) {
+    buffer.push(tag);
+    buffer.push(precision);
+    buffer.push(scale as u8);
+}
+
+fn write_str(buffer: &mut Vec<u8>, s: &str) {
+    buffer.extend_from_slice(&(s.len() as u64).to_le_bytes());
+    buffer.extend_from_slice(s.as_bytes());
+}
+
 /// Converter for Arrow schema to Parquet schema
 ///
 /// See the documentation on the [`arrow`] module for background
@@ -399,6 +609,12 @@ pub fn parquet_to_arrow_field(parquet_column: &ColumnDescriptor) -> Result<Field
             basic_info.id().to_string(),
         );
     }
+    if let Some(unknown_logical_type) = basic_info.unknown_logical_type() {
+        meta.insert(
+            PARQUET_UNKNOWN_LOGICAL_TYPE_META_KEY.to_string(),
+            BASE64_STANDARD.encode(unknown_logical_type),
+        );
+    }
     #[cfg(feature = "arrow_canonical_extension_types")]
     if let Some(logical_type) = basic_info.logical_type() {
         match logical_type {
@@ -726,8 +942,12 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
                 .iter()
                 .map(|f| arrow_to_parquet_type(f, coerce_types).map(Arc::new))
                 .collect::<Result<_>>()?;
+            // Variant columns are structs annotated with the variant extension type
+            let logical_type = (field.extension_type_name() == Some(VARIANT_EXTENSION_NAME))
+                .then_some(LogicalType::Variant);
             Type::group_type_builder(name)
                 .with_fields(fields)
+                .with_logical_type(logical_type)
                 .with_repetition(repetition)
                 .with_id(id)
                 .build()
@@ -791,7 +1011,8 @@ fn field_id(field: &Field) -> Option<i32> {
 mod tests {
     use super::*;
 
-    use std::{collections::HashMap, sync::Arc};
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Arc;
 
     use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
 
@@ -2272,4 +2493,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_arrow_schema_fingerprint_data_types() {
+        let list = |f| DataType::List(Arc::new(f));
+        let data_types = [
+            DataType::Int32,
+            DataType::UInt32,
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+            DataType::Interval(IntervalUnit::DayTime),
+            DataType::Interval(IntervalUnit::MonthDayNano),
+            DataType::Decimal128(10, 2),
+            DataType::Decimal128(10, 3),
+            DataType::Decimal256(10, 2),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8)),
+            list(Field::new_list_field(DataType::Int32, true)),
+            DataType::LargeList(Arc::new(Field::new_list_field(DataType::Int32, true))),
+            list(Field::new_list_field(DataType::Int32, false)),
+            list(Field::new("element", DataType::Int32, true)),
+        ];
+        let fingerprints: HashSet<_> = data_types
+            .iter()
+            .map(|data_type| {
+                let schema = Schema::new(vec![Field::new("a", data_type.clone(), true)]);
+                arrow_schema_fingerprint(&schema)
+            })
+            .collect();
+        assert_eq!(fingerprints.len(), data_types.len());
+
+        // Persisted fingerprints must not change, e.g. with the debug representation of types
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".into())),
+            true,
+        )]);
+        assert_eq!(arrow_schema_fingerprint(&schema), 0x8276aa5287c37632);
+    }
 }
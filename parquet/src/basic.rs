@@ -21,7 +21,9 @@
 use std::str::FromStr;
 use std::{fmt, str};
 
-pub use crate::compression::{BrotliLevel, GzipLevel, ZstdLevel};
+pub use crate::compression::{
+    BrotliLevel, Codec, CodecOptions, CompressionCodecFactory, GzipLevel, ZstdLevel,
+};
 use crate::format as parquet;
 
 use crate::errors::{ParquetError, Result};
@@ -385,8 +387,11 @@ impl FromStr for Encoding {
 /// [`Compression::LZ4_RAW`] provides much faster decompression speeds, at the cost of typically
 /// worse compression ratios. However, it is not as widely supported by the ecosystem, with the
 /// Hadoop ecosystem historically favoring the non-standard and now deprecated [`Compression::LZ4`].
+///
+/// This enum is `#[non_exhaustive]`, as codecs may be added to the parquet format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
+#[non_exhaustive]
 pub enum Compression {
     /// No compression.
     UNCOMPRESSED,
@@ -404,9 +409,34 @@ pub enum Compression {
     ZSTD(ZstdLevel),
     /// [LZ4 compression](https://lz4.org/).
     LZ4_RAW,
+    /// A codec id not defined by the parquet format, such as one of an extension range.
+    ///
+    /// There is no built-in codec for these ids, they can only be read and written with a
+    /// [`CompressionCodecFactory`]. Negative ids, and the ids of the codecs defined by the
+    /// format, are rejected when creating a codec, as they would alias the other variants.
+    EXTENSION(i32),
 }
 
 impl Compression {
+    /// Returns an error if this is a [`Compression::EXTENSION`] whose id is negative, or
+    /// the id of a codec defined by the parquet format.
+    pub(crate) fn check_extension_id(self) -> Result<()> {
+        match self {
+            Compression::EXTENSION(id) => {
+                match Compression::try_from(parquet::CompressionCodec(id)) {
+                    Ok(Compression::EXTENSION(_)) => Ok(()),
+                    Ok(compression) => Err(general_err!(
+                        "extension codec id {} is the id of {}",
+                        id,
+                        compression.codec_to_string()
+                    )),
+                    Err(_) => Err(general_err!("invalid extension codec id {}", id)),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Returns the codec type of this compression setting as a string, without the compression
     /// level.
     pub(crate) fn codec_to_string(self) -> String {
@@ -1044,6 +1074,7 @@ impl TryFrom<parquet::CompressionCodec> for Compression {
             parquet::CompressionCodec::LZ4 => Compression::LZ4,
             parquet::CompressionCodec::ZSTD => Compression::ZSTD(Default::default()),
             parquet::CompressionCodec::LZ4_RAW => Compression::LZ4_RAW,
+            parquet::CompressionCodec(id) if id >= 0 => Compression::EXTENSION(id),
             _ => {
                 return Err(general_err!(
                     "unexpected parquet compression codec: {}",
@@ -1065,6 +1096,7 @@ impl From<Compression> for parquet::CompressionCodec {
             Compression::LZ4 => parquet::CompressionCodec::LZ4,
             Compression::ZSTD(_) => parquet::CompressionCodec::ZSTD,
             Compression::LZ4_RAW => parquet::CompressionCodec::LZ4_RAW,
+            Compression::EXTENSION(id) => parquet::CompressionCodec(id),
         }
     }
 }
@@ -2050,6 +2082,11 @@ mod tests {
             Compression::try_from(parquet::CompressionCodec::ZSTD).unwrap(),
             Compression::ZSTD(Default::default())
         );
+        assert_eq!(
+            Compression::try_from(parquet::CompressionCodec(100)).unwrap(),
+            Compression::EXTENSION(100)
+        );
+        assert!(Compression::try_from(parquet::CompressionCodec(-1)).is_err());
     }
 
     #[test]
@@ -192,6 +192,7 @@ fn compression(compression: Compression) -> Option<&'static str> {
         Compression::LZ4 => Some("lz4"),
         Compression::ZSTD(_) => Some("zstd"),
         Compression::LZ4_RAW => Some("lz4_raw"),
+        _ => Some("extension"),
     }
 }
 
@@ -41,7 +41,10 @@ use parquet::{
     arrow::{arrow_reader::ParquetRecordBatchReaderBuilder, ArrowWriter},
     basic::{Compression, Encoding},
     file::{
-        properties::{BloomFilterPosition, EnabledStatistics, WriterProperties, WriterVersion},
+        properties::{
+            BloomFilterPosition, EnabledStatistics, PageIndexPosition, WriterProperties,
+            WriterVersion,
+        },
         reader::FileReader,
         serialized_reader::SerializedFileReader,
     },
@@ -204,6 +207,24 @@ impl From<BloomFilterPositionArgs> for BloomFilterPosition {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
+enum PageIndexPositionArgs {
+    /// Write the page index of each row group right after the row group
+    AfterRowGroup,
+
+    /// Write the page index at the end of the file
+    End,
+}
+
+impl From<PageIndexPositionArgs> for PageIndexPosition {
+    fn from(value: PageIndexPositionArgs) -> Self {
+        match value {
+            PageIndexPositionArgs::AfterRowGroup => Self::AfterRowGroup,
+            PageIndexPositionArgs::End => Self::End,
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 #[clap(author, version, about("Read and write parquet file with potentially different settings"), long_about = None)]
 struct Args {
@@ -278,6 +299,10 @@ struct Args {
     #[clap(long)]
     bloom_filter_position: Option<BloomFilterPositionArgs>,
 
+    /// Sets the position of the page index
+    #[clap(long)]
+    page_index_position: Option<PageIndexPositionArgs>,
+
     /// Sets flag to enable/disable statistics for all columns.
     #[clap(long)]
     statistics_enabled: Option<EnabledStatisticsArgs>,
@@ -364,6 +389,9 @@ fn main() {
             }
         }
     }
+    if let Some(value) = args.page_index_position {
+        writer_properties_builder = writer_properties_builder.set_page_index_position(value.into());
+    }
     if let Some(value) = args.statistics_enabled {
         writer_properties_builder = writer_properties_builder.set_statistics_enabled(value.into());
     }
@@ -75,6 +75,7 @@
 use crate::data_type::AsBytes;
 use crate::errors::ParquetError;
 use crate::file::metadata::ColumnChunkMetaData;
+use crate::file::properties::BloomFilterProperties;
 use crate::file::reader::ChunkReader;
 use crate::format::{
     BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
@@ -220,15 +221,20 @@ fn num_of_bits_from_ndv_fpp(ndv: u64, fpp: f64) -> usize {
     num_bits as usize
 }
 
+fn check_fpp(fpp: f64) -> Result<(), ParquetError> {
+    if !(0.0..1.0).contains(&fpp) {
+        return Err(ParquetError::General(format!(
+            "False positive probability must be between 0.0 and 1.0, got {fpp}"
+        )));
+    }
+    Ok(())
+}
+
 impl Sbbf {
     /// Create a new [Sbbf] with given number of distinct values and false positive probability.
     /// Will return an error if `fpp` is greater than or equal to 1.0 or less than 0.0.
     pub(crate) fn new_with_ndv_fpp(ndv: u64, fpp: f64) -> Result<Self, ParquetError> {
-        if !(0.0..1.0).contains(&fpp) {
-            return Err(ParquetError::General(format!(
-                "False positive probability must be between 0.0 and 1.0, got {fpp}"
-            )));
-        }
+        check_fpp(fpp)?;
         let num_bits = num_of_bits_from_ndv_fpp(ndv, fpp);
         Ok(Self::new_with_num_of_bytes(num_bits / 8))
     }
@@ -273,7 +279,7 @@ impl Sbbf {
 
     /// Write the bitset in serialized form to the writer.
     #[cfg(not(target_endian = "little"))]
-    fn write_bitset<W: Write>(&self, mut writer: W) -> Result<(), ParquetError> {
+    pub(crate) fn write_bitset<W: Write>(&self, mut writer: W) -> Result<(), ParquetError> {
         for block in &self.0 {
             writer
                 .write_all(block.to_le_bytes().as_slice())
@@ -286,7 +292,7 @@ impl Sbbf {
 
     /// Write the bitset in serialized form to the writer.
     #[cfg(target_endian = "little")]
-    fn write_bitset<W: Write>(&self, mut writer: W) -> Result<(), ParquetError> {
+    pub(crate) fn write_bitset<W: Write>(&self, mut writer: W) -> Result<(), ParquetError> {
         // Safety: Block is repr(transparent) and [u32; 8] can be reinterpreted as [u8; 32].
         let slice = unsafe {
             std::slice::from_raw_parts(
@@ -324,7 +330,17 @@ impl Sbbf {
             return Ok(None);
         };
 
-        let buffer = match column_metadata.bloom_filter_length() {
+        Self::read_from_offset(offset, column_metadata.bloom_filter_length(), reader).map(Some)
+    }
+
+    /// Read a bloom filter of `length` bytes, including its header, if known, from the given
+    /// offset in the given reader.
+    pub(crate) fn read_from_offset<R: ChunkReader>(
+        offset: u64,
+        length: Option<i32>,
+        reader: &R,
+    ) -> Result<Self, ParquetError> {
+        let buffer = match length {
             Some(length) => reader.get_bytes(offset, length as usize),
             None => reader.get_bytes(offset, SBBF_HEADER_SIZE_ESTIMATE),
         }?;
@@ -348,7 +364,7 @@ impl Sbbf {
             }
         }
 
-        let bitset = match column_metadata.bloom_filter_length() {
+        let bitset = match length {
             Some(_) => buffer.slice((bitset_offset - offset) as usize..),
             None => {
                 let bitset_length: usize = header.num_bytes.try_into().map_err(|_| {
@@ -358,7 +374,7 @@ impl Sbbf {
             }
         };
 
-        Ok(Some(Self::new(&bitset)))
+        Ok(Self::new(&bitset))
     }
 
     #[inline]
@@ -398,6 +414,101 @@ impl Sbbf {
     }
 }
 
+/// Builds the [`Sbbf`] of a column chunk as it is written
+///
+/// If the filter is built from the dictionary, see
+/// [`WriterPropertiesBuilder::set_bloom_filter_from_dictionary`], no filter is allocated
+/// until the dictionary page is flushed with [`Self::insert_dictionary`]. The hashes of the
+/// dictionary values are then kept until either more values are written after falling back
+/// from dictionary encoding, in which case the filter is built with the configured ndv, or
+/// the filter is finished, in which case it is sized for the number of dictionary values.
+///
+/// [`WriterPropertiesBuilder::set_bloom_filter_from_dictionary`]: crate::file::properties::WriterPropertiesBuilder::set_bloom_filter_from_dictionary
+#[derive(Debug)]
+pub(crate) struct SbbfBuilder {
+    ndv: u64,
+    fpp: f64,
+    filter: Option<Sbbf>,
+    /// Hashes of the values of the flushed dictionary page, not yet inserted into `filter`
+    dictionary_hashes: Option<Vec<u64>>,
+}
+
+impl SbbfBuilder {
+    /// Create a new [`SbbfBuilder`], which builds the filter from the dictionary of the
+    /// column chunk if `from_dictionary` is true
+    pub(crate) fn try_new(
+        props: &BloomFilterProperties,
+        from_dictionary: bool,
+    ) -> Result<Self, ParquetError> {
+        let filter = match from_dictionary {
+            true => {
+                check_fpp(props.fpp)?;
+                None
+            }
+            false => Some(Sbbf::new_with_ndv_fpp(props.ndv, props.fpp)?),
+        };
+        Ok(Self {
+            ndv: props.ndv,
+            fpp: props.fpp,
+            filter,
+            dictionary_hashes: None,
+        })
+    }
+
+    /// Returns the filter to insert written values into, or `None` if the values are
+    /// dictionary encoded and the filter is built from the dictionary
+    pub(crate) fn filter_mut(&mut self) -> Option<&mut Sbbf> {
+        if let Some(hashes) = self.dictionary_hashes.take() {
+            let mut filter = Self::new_filter(self.ndv, self.fpp);
+            hashes.into_iter().for_each(|hash| filter.insert_hash(hash));
+            self.filter = Some(filter);
+        }
+        self.filter.as_mut()
+    }
+
+    /// Inserts the `values` of a flushed dictionary page, if the filter is built from the
+    /// dictionary
+    pub(crate) fn insert_dictionary<'a, T, I>(&mut self, values: I)
+    where
+        T: AsBytes + ?Sized + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        if self.filter.is_none() {
+            let hashes = values.into_iter().map(hash_as_bytes).collect();
+            self.dictionary_hashes = Some(hashes);
+        }
+    }
+
+    /// Returns the built filter
+    pub(crate) fn finish(self) -> Sbbf {
+        match self.filter {
+            Some(filter) => filter,
+            None => {
+                let hashes = self.dictionary_hashes.unwrap_or_default();
+                let mut filter = Self::new_filter(self.ndv.min(hashes.len() as u64), self.fpp);
+                hashes.into_iter().for_each(|hash| filter.insert_hash(hash));
+                filter
+            }
+        }
+    }
+
+    /// Return the in memory size of the filter, or of the dictionary hashes not yet
+    /// inserted into it, in bytes
+    pub(crate) fn estimated_memory_size(&self) -> usize {
+        let filter_size = self.filter.as_ref().map(Sbbf::estimated_memory_size);
+        let hashes_size = self
+            .dictionary_hashes
+            .as_ref()
+            .map(|h| h.capacity() * std::mem::size_of::<u64>());
+        filter_size.or(hashes_size).unwrap_or_default()
+    }
+
+    fn new_filter(ndv: u64, fpp: f64) -> Sbbf {
+        // fpp is validated by `try_new`
+        Sbbf::new_with_num_of_bytes(num_of_bits_from_ndv_fpp(ndv, fpp) / 8)
+    }
+}
+
 // per spec we use xxHash with seed=0
 const SEED: u64 = 0;
 
@@ -19,7 +19,7 @@ use bytes::Bytes;
 use half::f16;
 
 use crate::basic::{ConvertedType, Encoding, LogicalType, Type};
-use crate::bloom_filter::Sbbf;
+use crate::bloom_filter::{Sbbf, SbbfBuilder};
 use crate::column::writer::{
     compare_greater, fallback_encoding, has_dictionary_support, is_nan, update_max, update_min,
 };
@@ -123,15 +123,89 @@ pub trait ColumnValueEncoder {
     fn flush_bloom_filter(&mut self) -> Option<Sbbf>;
 }
 
+/// The non-dictionary encodings of a column chunk, tried in order, see
+/// [`WriterProperties::fallback_encodings`]
+#[derive(Debug)]
+pub(crate) struct EncodingChain {
+    encodings: Vec<Encoding>,
+    current: usize,
+    max_ratio: f64,
+}
+
+impl EncodingChain {
+    /// Create the chain of `descr`, which only contains the encoding of
+    /// [`WriterProperties::encoding`], or `default`, if no fallback encodings are set
+    pub(crate) fn new(descr: &ColumnDescPtr, props: &WriterProperties, default: Encoding) -> Self {
+        let encodings = match props.fallback_encodings(descr.path()) {
+            Some(encodings) => encodings.to_vec(),
+            None => vec![props.encoding(descr.path()).unwrap_or(default)],
+        };
+        Self {
+            encodings,
+            current: 0,
+            max_ratio: props.fallback_encoding_ratio(),
+        }
+    }
+
+    /// Returns all the encodings of the chain
+    pub(crate) fn encodings(&self) -> &[Encoding] {
+        &self.encodings
+    }
+
+    /// Returns the encoding in use
+    pub(crate) fn encoding(&self) -> Encoding {
+        self.encodings[self.current]
+    }
+
+    /// Returns true if the chain has an encoding after the one in use
+    pub(crate) fn has_next(&self) -> bool {
+        self.current + 1 < self.encodings.len()
+    }
+
+    /// Records that the values of a data page, whose `PLAIN` encoding has `plain_size`
+    /// bytes, were encoded in `encoded_size` bytes, returning the next encoding of the
+    /// chain if the ratio of these sizes exceeds [`WriterProperties::fallback_encoding_ratio`]
+    pub(crate) fn observe(&mut self, encoded_size: usize, plain_size: usize) -> Option<Encoding> {
+        if plain_size == 0 || !self.has_next() {
+            return None;
+        }
+        if encoded_size as f64 <= plain_size as f64 * self.max_ratio {
+            return None;
+        }
+        self.current += 1;
+        Some(self.encoding())
+    }
+}
+
+/// Returns the size of `values` when `PLAIN` encoded
+fn plain_encoded_size<T: DataType>(values: &[T::T]) -> usize {
+    match T::get_physical_type() {
+        Type::BOOLEAN => values.len().div_ceil(8),
+        Type::BYTE_ARRAY => values
+            .iter()
+            .map(|v| {
+                let (base_size, num_elements) = v.dict_encoding_size();
+                base_size + num_elements
+            })
+            .sum(),
+        Type::FIXED_LEN_BYTE_ARRAY => values.iter().map(|v| v.dict_encoding_size().1).sum(),
+        _ => values.iter().map(|v| v.dict_encoding_size().0).sum(),
+    }
+}
+
 pub struct ColumnValueEncoderImpl<T: DataType> {
     encoder: Box<dyn Encoder<T>>,
+    chain: EncodingChain,
+    /// The size of the values of the page being encoded with `encoder` when `PLAIN`
+    /// encoded, if `chain` has a next encoding
+    plain_size: usize,
     dict_encoder: Option<DictEncoder<T>>,
     descr: ColumnDescPtr,
     num_values: usize,
     statistics_enabled: EnabledStatistics,
     min_value: Option<T::T>,
     max_value: Option<T::T>,
-    bloom_filter: Option<Sbbf>,
+    bloom_filter: Option<SbbfBuilder>,
     variable_length_bytes: Option<i64>,
 }
 
@@ -159,7 +233,7 @@ impl<T: DataType> ColumnValueEncoderImpl<T> {
         }
 
         // encode the values into bloom filter if enabled
-        if let Some(bloom_filter) = &mut self.bloom_filter {
+        if let Some(bloom_filter) = self.bloom_filter.as_mut().and_then(SbbfBuilder::filter_mut) {
             for value in slice {
                 bloom_filter.insert(value);
             }
@@ -167,7 +241,12 @@ impl<T: DataType> ColumnValueEncoderImpl<T> {
 
         match &mut self.dict_encoder {
             Some(encoder) => encoder.put(slice),
-            _ => self.encoder.put(slice),
+            _ => {
+                if self.chain.has_next() {
+                    self.plain_size += plain_encoded_size::<T>(slice);
+                }
+                self.encoder.put(slice)
+            }
         }
     }
 }
@@ -178,7 +257,7 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
     type Values = [T::T];
 
     fn flush_bloom_filter(&mut self) -> Option<Sbbf> {
-        self.bloom_filter.take()
+        self.bloom_filter.take().map(SbbfBuilder::finish)
     }
 
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
@@ -187,22 +266,28 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
         let dict_encoder = dict_supported.then(|| DictEncoder::new(descr.clone()));
 
         // Set either main encoder or fallback encoder.
-        let encoder = get_encoder(
-            props
-                .encoding(descr.path())
-                .unwrap_or_else(|| fallback_encoding(T::get_physical_type(), props)),
+        let chain = EncodingChain::new(
             descr,
-        )?;
+            props,
+            fallback_encoding(T::get_physical_type(), props),
+        );
+        for encoding in &chain.encodings()[1..] {
+            get_encoder::<T>(*encoding, descr)?;
+        }
+        let encoder = get_encoder(chain.encoding(), descr)?;
 
         let statistics_enabled = props.statistics_enabled(descr.path());
 
+        let from_dictionary = dict_supported && props.bloom_filter_from_dictionary(descr.path());
         let bloom_filter = props
             .bloom_filter_properties(descr.path())
-            .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
+            .map(|props| SbbfBuilder::try_new(props, from_dictionary))
             .transpose()?;
 
         Ok(Self {
             encoder,
+            chain,
+            plain_size: 0,
             dict_encoder,
             descr: descr.clone(),
             num_values: 0,
@@ -281,6 +366,9 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
                 }
 
                 let buf = encoder.write_dict()?;
+                if let Some(bloom_filter) = &mut self.bloom_filter {
+                    bloom_filter.insert_dictionary(encoder.values());
+                }
 
                 Ok(Some(DictionaryPage {
                     buf,
@@ -295,7 +383,15 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
     fn flush_data_page(&mut self) -> Result<DataPageValues<T::T>> {
         let (buf, encoding) = match &mut self.dict_encoder {
             Some(encoder) => (encoder.write_indices()?, Encoding::RLE_DICTIONARY),
-            _ => (self.encoder.flush_buffer()?, self.encoder.encoding()),
+            _ => {
+                let buf = self.encoder.flush_buffer()?;
+                let encoding = self.encoder.encoding();
+                let plain_size = std::mem::take(&mut self.plain_size);
+                if let Some(next) = self.chain.observe(buf.len(), plain_size) {
+                    self.encoder = get_encoder(next, &self.descr)?;
+                }
+                (buf, encoding)
+            }
         };
 
         Ok(DataPageValues {
@@ -103,37 +103,39 @@ impl ColumnWriter<'_> {
 }
 
 /// Gets a specific column writer corresponding to column descriptor `descr`.
+///
+/// Returns an error if the codec of the column can not be created.
 pub fn get_column_writer<'a>(
     descr: ColumnDescPtr,
     props: WriterPropertiesPtr,
     page_writer: Box<dyn PageWriter + 'a>,
-) -> ColumnWriter<'a> {
-    match descr.physical_type() {
+) -> Result<ColumnWriter<'a>> {
+    Ok(match descr.physical_type() {
         Type::BOOLEAN => {
-            ColumnWriter::BoolColumnWriter(ColumnWriterImpl::new(descr, props, page_writer))
+            ColumnWriter::BoolColumnWriter(ColumnWriterImpl::new(descr, props, page_writer)?)
         }
         Type::INT32 => {
-            ColumnWriter::Int32ColumnWriter(ColumnWriterImpl::new(descr, props, page_writer))
+            ColumnWriter::Int32ColumnWriter(ColumnWriterImpl::new(descr, props, page_writer)?)
         }
         Type::INT64 => {
-            ColumnWriter::Int64ColumnWriter(ColumnWriterImpl::new(descr, props, page_writer))
+            ColumnWriter::Int64ColumnWriter(ColumnWriterImpl::new(descr, props, page_writer)?)
         }
         Type::INT96 => {
-            ColumnWriter::Int96ColumnWriter(ColumnWriterImpl::new(descr, props, page_writer))
+            ColumnWriter::Int96ColumnWriter(ColumnWriterImpl::new(descr, props, page_writer)?)
         }
         Type::FLOAT => {
-            ColumnWriter::FloatColumnWriter(ColumnWriterImpl::new(descr, props, page_writer))
+            ColumnWriter::FloatColumnWriter(ColumnWriterImpl::new(descr, props, page_writer)?)
         }
         Type::DOUBLE => {
-            ColumnWriter::DoubleColumnWriter(ColumnWriterImpl::new(descr, props, page_writer))
+            ColumnWriter::DoubleColumnWriter(ColumnWriterImpl::new(descr, props, page_writer)?)
         }
         Type::BYTE_ARRAY => {
-            ColumnWriter::ByteArrayColumnWriter(ColumnWriterImpl::new(descr, props, page_writer))
+            ColumnWriter::ByteArrayColumnWriter(ColumnWriterImpl::new(descr, props, page_writer)?)
         }
         Type::FIXED_LEN_BYTE_ARRAY => ColumnWriter::FixedLenByteArrayColumnWriter(
-            ColumnWriterImpl::new(descr, props, page_writer),
+            ColumnWriterImpl::new(descr, props, page_writer)?,
         ),
-    }
+    })
 }
 
 /// Gets a typed column writer for the specific type `T`, by "up-casting" `col_writer` of
@@ -354,15 +356,19 @@ pub struct GenericColumnWriter<'a, E: ColumnValueEncoder> {
 
 impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
     /// Returns a new instance of [`GenericColumnWriter`].
+    ///
+    /// Returns an error if the codec or encoder of the column can not be created.
     pub fn new(
         descr: ColumnDescPtr,
         props: WriterPropertiesPtr,
         page_writer: Box<dyn PageWriter + 'a>,
-    ) -> Self {
+    ) -> Result<Self> {
         let codec = props.compression(descr.path());
-        let codec_options = CodecOptionsBuilder::default().build();
-        let compressor = create_codec(codec, &codec_options).unwrap();
-        let encoder = E::try_new(&descr, props.as_ref()).unwrap();
+        let codec_options = CodecOptionsBuilder::default()
+            .set_codec_factory(props.codec_factory().cloned())
+            .build();
+        let compressor = create_codec(codec, &codec_options)?;
+        let encoder = E::try_new(&descr, props.as_ref())?;
 
         let statistics_enabled = props.statistics_enabled(descr.path());
 
@@ -395,7 +401,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             _ => None,
         };
 
-        Self {
+        Ok(Self {
             descr,
             props,
             statistics_enabled,
@@ -415,7 +421,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             data_page_boundary_ascending: true,
             data_page_boundary_descending: true,
             last_non_null_data_page_min_max: None,
-        }
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -818,12 +824,14 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                         self.column_index_builder.append(
                             null_page,
                             self.truncate_min_value(
-                                self.props.column_index_truncate_length(),
+                                self.props
+                                    .column_column_index_truncate_length(self.descr.path()),
                                 stat.min_bytes_opt().unwrap(),
                             )
                             .0,
                             self.truncate_max_value(
-                                self.props.column_index_truncate_length(),
+                                self.props
+                                    .column_column_index_truncate_length(self.descr.path()),
                                 stat.max_bytes_opt().unwrap(),
                             )
                             .0,
@@ -946,11 +954,13 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         match statistics {
             Statistics::ByteArray(stats) if stats._internal_has_min_max_set() => {
                 let (min, did_truncate_min) = self.truncate_min_value(
-                    self.props.statistics_truncate_length(),
+                    self.props
+                        .column_statistics_truncate_length(self.descr.path()),
                     stats.min_bytes_opt().unwrap(),
                 );
                 let (max, did_truncate_max) = self.truncate_max_value(
-                    self.props.statistics_truncate_length(),
+                    self.props
+                        .column_statistics_truncate_length(self.descr.path()),
                     stats.max_bytes_opt().unwrap(),
                 );
                 Statistics::ByteArray(
@@ -969,11 +979,13 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                 if (stats._internal_has_min_max_set() && self.can_truncate_value()) =>
             {
                 let (min, did_truncate_min) = self.truncate_min_value(
-                    self.props.statistics_truncate_length(),
+                    self.props
+                        .column_statistics_truncate_length(self.descr.path()),
                     stats.min_bytes_opt().unwrap(),
                 );
                 let (max, did_truncate_max) = self.truncate_max_value(
-                    self.props.statistics_truncate_length(),
+                    self.props
+                        .column_statistics_truncate_length(self.descr.path()),
                     stats.max_bytes_opt().unwrap(),
                 );
                 Statistics::FixedLenByteArray(
@@ -2060,6 +2072,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_column_writer_fallback_encodings() {
+        // Sequential values that DELTA_BINARY_PACKED encodes in a few bytes, then random
+        // values that it encodes in more bytes than PLAIN
+        let mut values: Vec<i32> = (0..1000).collect();
+        random_numbers_range(1000, i32::MIN, i32::MAX, &mut values);
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_row_count_limit(500)
+            .set_write_batch_size(500)
+            .set_fallback_encodings(vec![Encoding::DELTA_BINARY_PACKED, Encoding::PLAIN])
+            .build();
+        let meta = column_write_and_get_metadata::<Int32Type>(props.clone(), &values);
+        assert_eq!(
+            meta.encodings(),
+            &vec![
+                Encoding::PLAIN,
+                Encoding::RLE,
+                Encoding::DELTA_BINARY_PACKED
+            ]
+        );
+        assert_eq!(
+            meta.page_encoding_stats().unwrap(),
+            &[
+                encoding_stats(PageType::DATA_PAGE, Encoding::DELTA_BINARY_PACKED, 3),
+                encoding_stats(PageType::DATA_PAGE, Encoding::PLAIN, 1),
+            ]
+        );
+
+        column_roundtrip::<Int32Type>(props, &values, None, None);
+    }
+
     #[test]
     fn test_column_writer_check_metadata() {
         let page_writer = get_test_page_writer();
@@ -3301,6 +3346,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_truncate_length() {
+        let write = |builder: crate::file::properties::WriterPropertiesBuilder| {
+            let props = Arc::new(builder.build());
+            let page_writer = get_test_page_writer();
+            let mut writer = get_test_column_writer::<ByteArrayType>(page_writer, 0, 0, props);
+            let data = vec![ByteArray::from("Blart Versenwald III")];
+            writer.write_batch(&data, None, None).unwrap();
+            let r = writer.close().unwrap();
+
+            let stats = r.metadata.statistics().unwrap();
+            let column_index = r.column_index.unwrap();
+            (
+                stats.min_bytes_opt().unwrap().len(),
+                column_index.min_values[0].len(),
+            )
+        };
+
+        let builder = || {
+            WriterProperties::builder()
+                .set_statistics_enabled(EnabledStatistics::Page)
+                .set_statistics_truncate_length(Some(2))
+                .set_column_index_truncate_length(Some(4))
+        };
+        assert_eq!(write(builder()), (2, 4));
+
+        // The truncate lengths of the column take precedence
+        let col = ColumnPath::from("col");
+        let builder = builder()
+            .set_column_statistics_truncate_length(col.clone(), None)
+            .set_column_column_index_truncate_length(col, Some(1));
+        assert_eq!(write(builder), (20, 1));
+    }
+
     #[test]
     fn test_statistics_truncating_fixed_len_byte_array() {
         let page_writer = get_test_page_writer();
@@ -3606,7 +3685,7 @@ mod tests {
                 .set_statistics_enabled(EnabledStatistics::None)
                 .build(),
         );
-        let column_writer = get_column_writer(descr, props, get_test_page_writer());
+        let column_writer = get_column_writer(descr, props, get_test_page_writer()).unwrap();
         let mut writer = get_typed_column_writer::<Int32Type>(column_writer);
 
         let data = Vec::new();
@@ -3629,7 +3708,7 @@ mod tests {
                 .set_offset_index_disabled(true)
                 .build(),
         );
-        let column_writer = get_column_writer(descr, props, get_test_page_writer());
+        let column_writer = get_column_writer(descr, props, get_test_page_writer()).unwrap();
         let mut writer = get_typed_column_writer::<Int32Type>(column_writer);
 
         let data = Vec::new();
@@ -3652,7 +3731,7 @@ mod tests {
                 .set_offset_index_disabled(true)
                 .build(),
         );
-        let column_writer = get_column_writer(descr, props, get_test_page_writer());
+        let column_writer = get_column_writer(descr, props, get_test_page_writer()).unwrap();
         let mut writer = get_typed_column_writer::<Int32Type>(column_writer);
 
         let data = Vec::new();
@@ -3846,7 +3925,7 @@ mod tests {
             column_descr.clone(),
             Default::default(),
             get_test_page_writer(),
-        );
+        )?;
         let mut writer = get_typed_column_writer::<T>(column_writer);
 
         for &page in pages {
@@ -4052,7 +4131,7 @@ mod tests {
         props: WriterPropertiesPtr,
     ) -> ColumnWriterImpl<'a, T> {
         let descr = Arc::new(get_test_column_descr::<T>(max_def_level, max_rep_level));
-        let column_writer = get_column_writer(descr, props, page_writer);
+        let column_writer = get_column_writer(descr, props, page_writer).unwrap();
         get_typed_column_writer::<T>(column_writer)
     }
 
@@ -4132,7 +4211,7 @@ mod tests {
             max_def_level,
             max_rep_level,
         ));
-        let column_writer = get_column_writer(descr, props, page_writer);
+        let column_writer = get_column_writer(descr, props, page_writer).unwrap();
         get_typed_column_writer::<T>(column_writer)
     }
 
@@ -4175,7 +4254,7 @@ mod tests {
         props: WriterPropertiesPtr,
     ) -> ColumnWriterImpl<'static, FixedLenByteArrayType> {
         let descr = Arc::new(get_test_float16_column_descr(0, 0));
-        let column_writer = get_column_writer(descr, props, page_writer);
+        let column_writer = get_column_writer(descr, props, page_writer).unwrap();
         get_typed_column_writer::<FixedLenByteArrayType>(column_writer)
     }
 
@@ -4194,7 +4273,7 @@ mod tests {
         page_writer: Box<dyn PageWriter>,
     ) -> ColumnWriterImpl<'static, FixedLenByteArrayType> {
         let descr = Arc::new(get_test_interval_column_descr());
-        let column_writer = get_column_writer(descr, Default::default(), page_writer);
+        let column_writer = get_column_writer(descr, Default::default(), page_writer).unwrap();
         get_typed_column_writer::<FixedLenByteArrayType>(column_writer)
     }
 
@@ -4220,7 +4299,7 @@ mod tests {
             max_def_level,
             max_rep_level,
         ));
-        let column_writer = get_column_writer(descr, props, page_writer);
+        let column_writer = get_column_writer(descr, props, page_writer).unwrap();
         get_typed_column_writer::<T>(column_writer)
     }
 
@@ -49,6 +49,7 @@ assert_eq!(output, data);
 )]
 use crate::basic::Compression as CodecType;
 use crate::errors::{ParquetError, Result};
+use std::sync::Arc;
 
 /// Parquet compression codec interface.
 pub trait Codec: Send {
@@ -74,11 +75,61 @@ pub trait Codec: Send {
     ) -> Result<usize>;
 }
 
+/// A factory for user-provided [`Codec`] implementations.
+///
+/// Registering a factory via [`ReaderPropertiesBuilder::set_codec_factory`] or
+/// [`WriterPropertiesBuilder::set_codec_factory`] allows overriding the codec used for
+/// any [`Compression`](crate::basic::Compression), for example to use ZSTD with a custom
+/// dictionary, to support codecs such as `LZO` that are not implemented by this crate,
+/// or to wrap another codec.
+///
+/// Codec ids that are not defined by the parquet format, such as those of an extension
+/// range, are passed to the factory as [`Compression::EXTENSION`](crate::basic::Compression::EXTENSION)
+/// with the raw id.
+///
+/// The arrow readers use the factory provided with `ArrowReaderOptions::with_codec_factory`.
+///
+/// [`ReaderPropertiesBuilder::set_codec_factory`]: crate::file::properties::ReaderPropertiesBuilder::set_codec_factory
+/// [`WriterPropertiesBuilder::set_codec_factory`]: crate::file::properties::WriterPropertiesBuilder::set_codec_factory
+pub trait CompressionCodecFactory: std::fmt::Debug + Send + Sync {
+    /// Returns a codec for the compression type `codec`, or `None` to fall back to the
+    /// built-in implementation.
+    ///
+    /// This is never called for [`Compression::UNCOMPRESSED`](crate::basic::Compression::UNCOMPRESSED).
+    fn create_codec(
+        &self,
+        codec: CodecType,
+        options: &CodecOptions,
+    ) -> Result<Option<Box<dyn Codec>>>;
+}
+
 /// Struct to hold `Codec` creation options.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct CodecOptions {
     /// Whether or not to fallback to other LZ4 older implementations on error in LZ4_HADOOP.
     backward_compatible_lz4: bool,
+    /// User-provided factory consulted before the built-in codecs
+    codec_factory: Option<Arc<dyn CompressionCodecFactory>>,
+}
+
+impl PartialEq for CodecOptions {
+    fn eq(&self, other: &Self) -> bool {
+        let factory_eq = match (&self.codec_factory, &other.codec_factory) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.backward_compatible_lz4 == other.backward_compatible_lz4 && factory_eq
+    }
+}
+
+impl Eq for CodecOptions {}
+
+impl CodecOptions {
+    /// Returns whether to fallback to older LZ4 implementations on error in LZ4_HADOOP.
+    pub fn backward_compatible_lz4(&self) -> bool {
+        self.backward_compatible_lz4
+    }
 }
 
 impl Default for CodecOptions {
@@ -90,12 +141,15 @@ impl Default for CodecOptions {
 pub struct CodecOptionsBuilder {
     /// Whether or not to fallback to other LZ4 older implementations on error in LZ4_HADOOP.
     backward_compatible_lz4: bool,
+    /// User-provided factory consulted before the built-in codecs
+    codec_factory: Option<Arc<dyn CompressionCodecFactory>>,
 }
 
 impl Default for CodecOptionsBuilder {
     fn default() -> Self {
         Self {
             backward_compatible_lz4: true,
+            codec_factory: None,
         }
     }
 }
@@ -114,9 +168,19 @@ impl CodecOptionsBuilder {
         self
     }
 
+    /// Sets a [`CompressionCodecFactory`] that is consulted before the built-in codecs.
+    pub fn set_codec_factory(
+        mut self,
+        value: Option<Arc<dyn CompressionCodecFactory>>,
+    ) -> CodecOptionsBuilder {
+        self.codec_factory = value;
+        self
+    }
+
     pub fn build(self) -> CodecOptions {
         CodecOptions {
             backward_compatible_lz4: self.backward_compatible_lz4,
+            codec_factory: self.codec_factory,
         }
     }
 }
@@ -144,7 +208,18 @@ pub(crate) trait CompressionLevel<T: std::fmt::Display + std::cmp::PartialOrd> {
 /// Given the compression type `codec`, returns a codec used to compress and decompress
 /// bytes for the compression type.
 /// This returns `None` if the codec type is `UNCOMPRESSED`.
-pub fn create_codec(codec: CodecType, _options: &CodecOptions) -> Result<Option<Box<dyn Codec>>> {
+///
+/// If a [`CompressionCodecFactory`] is registered in `options` it is consulted first.
+pub fn create_codec(codec: CodecType, options: &CodecOptions) -> Result<Option<Box<dyn Codec>>> {
+    codec.check_extension_id()?;
+    if let Some(factory) = &options.codec_factory {
+        if codec != CodecType::UNCOMPRESSED {
+            if let Some(codec) = factory.create_codec(codec, options)? {
+                return Ok(Some(codec));
+            }
+        }
+    }
+
     #[allow(unreachable_code, unused_variables)]
     match codec {
         CodecType::BROTLI(level) => {
@@ -171,7 +246,7 @@ pub fn create_codec(codec: CodecType, _options: &CodecOptions) -> Result<Option<
         CodecType::LZ4 => {
             #[cfg(any(feature = "lz4", test))]
             return Ok(Some(Box::new(LZ4HadoopCodec::new(
-                _options.backward_compatible_lz4,
+                options.backward_compatible_lz4,
             ))));
             Err(ParquetError::General(
                 "Disabled feature at compile time: lz4".into(),
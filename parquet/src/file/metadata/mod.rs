@@ -124,7 +124,7 @@ pub use reader::{FooterTail, ParquetMetaDataReader};
 use std::ops::Range;
 use std::sync::Arc;
 pub use writer::ParquetMetaDataWriter;
-pub(crate) use writer::ThriftMetadataWriter;
+pub(crate) use writer::{PageIndexWriter, ThriftMetadataWriter};
 
 /// Page level statistics for each column chunk of each row group.
 ///
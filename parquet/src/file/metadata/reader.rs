@@ -35,8 +35,10 @@ use crate::format::{ColumnOrder as TColumnOrder, FileMetaData as TFileMetaData};
 #[cfg(feature = "encryption")]
 use crate::format::{EncryptionAlgorithm, FileCryptoMetaData as TFileCryptoMetaData};
 use crate::schema::types;
-use crate::schema::types::SchemaDescriptor;
-use crate::thrift::{TCompactSliceInputProtocol, TSerializable};
+use crate::schema::types::{SchemaDescPtr, SchemaDescriptor};
+#[cfg(feature = "encryption")]
+use crate::thrift::TSerializable;
+use crate::thrift::{read_file_metadata, TCompactSliceInputProtocol};
 
 #[cfg(all(feature = "async", feature = "arrow"))]
 use crate::arrow::async_reader::{MetadataFetch, MetadataSuffixFetch};
@@ -75,6 +77,7 @@ pub struct ParquetMetaDataReader {
     column_index: bool,
     offset_index: bool,
     prefetch_hint: Option<usize>,
+    ignore_unknown_logical_types: bool,
     // Size of the serialized thrift metadata plus the 8 byte footer. Only set if
     // `self.parse_metadata` is called.
     metadata_size: Option<usize>,
@@ -158,6 +161,20 @@ impl ParquetMetaDataReader {
         self
     }
 
+    /// Ignore logical type annotations that are not known to this crate (defaults to `false`)
+    ///
+    /// Files written with logical types added to the format after this crate, such as new
+    /// geospatial types, can not be read by default, as their columns may not be interpreted
+    /// correctly. If enabled, such annotations are dropped, so that the annotated columns are
+    /// read as their physical type, and their thrift compact encoding is kept in
+    /// [`BasicTypeInfo::unknown_logical_type`].
+    ///
+    /// [`BasicTypeInfo::unknown_logical_type`]: crate::schema::types::BasicTypeInfo::unknown_logical_type
+    pub fn with_ignore_unknown_logical_types(mut self, val: bool) -> Self {
+        self.ignore_unknown_logical_types = val;
+        self
+    }
+
     /// Provide the FileDecryptionProperties to use when decrypting the file.
     ///
     /// This is only necessary when the file is encrypted.
@@ -875,6 +892,7 @@ impl ParquetMetaDataReader {
             buf,
             footer_tail.is_encrypted_footer(),
             self.file_decryption_properties.as_ref(),
+            self.ignore_unknown_logical_types,
         );
         #[cfg(not(feature = "encryption"))]
         let result = {
@@ -883,7 +901,7 @@ impl ParquetMetaDataReader {
                     "Parquet file has an encrypted footer but the encryption feature is disabled"
                 ))
             } else {
-                Self::decode_metadata(buf)
+                Self::decode_unencrypted_metadata(buf, self.ignore_unknown_logical_types)
             }
         };
         result
@@ -903,6 +921,7 @@ impl ParquetMetaDataReader {
         buf: &[u8],
         encrypted_footer: bool,
         file_decryption_properties: Option<&FileDecryptionProperties>,
+        ignore_unknown_logical_types: bool,
     ) -> Result<ParquetMetaData> {
         let mut prot = TCompactSliceInputProtocol::new(buf);
         let mut file_decryptor = None;
@@ -947,10 +966,8 @@ impl ParquetMetaDataReader {
             }
         }
 
-        let t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
-            .map_err(|e| general_err!("Could not parse metadata: {}", e))?;
-        let schema = types::from_thrift(&t_file_metadata.schema)?;
-        let schema_descr = Arc::new(SchemaDescriptor::new(schema));
+        let (t_file_metadata, schema_descr) =
+            Self::decode_file_metadata(&mut prot, ignore_unknown_logical_types)?;
 
         if let (Some(algo), Some(file_decryption_properties)) = (
             t_file_metadata.encryption_algorithm,
@@ -1003,12 +1020,17 @@ impl ParquetMetaDataReader {
     ///
     /// [Parquet Spec]: https://github.com/apache/parquet-format#metadata
     pub fn decode_metadata(buf: &[u8]) -> Result<ParquetMetaData> {
+        Self::decode_unencrypted_metadata(buf, false)
+    }
+
+    fn decode_unencrypted_metadata(
+        buf: &[u8],
+        ignore_unknown_logical_types: bool,
+    ) -> Result<ParquetMetaData> {
         let mut prot = TCompactSliceInputProtocol::new(buf);
 
-        let t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
-            .map_err(|e| general_err!("Could not parse metadata: {}", e))?;
-        let schema = types::from_thrift(&t_file_metadata.schema)?;
-        let schema_descr = Arc::new(SchemaDescriptor::new(schema));
+        let (t_file_metadata, schema_descr) =
+            Self::decode_file_metadata(&mut prot, ignore_unknown_logical_types)?;
 
         let mut row_groups = Vec::new();
         for rg in t_file_metadata.row_groups {
@@ -1029,6 +1051,32 @@ impl ParquetMetaDataReader {
         Ok(ParquetMetaData::new(file_metadata, row_groups))
     }
 
+    /// Decodes the thrift [`TFileMetaData`] and its schema
+    ///
+    /// Returns an error if a schema element is annotated with a logical type not known to this
+    /// crate, unless `ignore_unknown_logical_types` is true.
+    fn decode_file_metadata(
+        prot: &mut TCompactSliceInputProtocol,
+        ignore_unknown_logical_types: bool,
+    ) -> Result<(TFileMetaData, SchemaDescPtr)> {
+        let (t_file_metadata, unknown_logical_types) = read_file_metadata(prot)
+            .map_err(|e| general_err!("Could not parse metadata: {}", e))?;
+        if let (false, Some(unknown)) =
+            (ignore_unknown_logical_types, unknown_logical_types.first())
+        {
+            return Err(general_err!(
+                "Could not parse metadata: schema element {} is annotated with an unknown logical \
+                type, use `with_ignore_unknown_logical_types` to read it as its physical type",
+                t_file_metadata.schema[unknown.element].name
+            ));
+        }
+        let schema = types::from_thrift_with_unknown_logical_types(
+            &t_file_metadata.schema,
+            &unknown_logical_types,
+        )?;
+        Ok((t_file_metadata, Arc::new(SchemaDescriptor::new(schema))))
+    }
+
     /// Parses column orders from Thrift definition.
     /// If no column orders are defined, returns `None`.
     fn parse_column_orders(
@@ -22,10 +22,9 @@ use crate::encryption::{
     },
     modules::{create_footer_aad, create_module_aad, ModuleType},
 };
-#[cfg(feature = "encryption")]
 use crate::errors::ParquetError;
 use crate::errors::Result;
-use crate::file::metadata::{KeyValue, ParquetMetaData};
+use crate::file::metadata::{KeyValue, ParquetMetaData, RowGroupMetaData};
 use crate::file::page_index::index::Index;
 use crate::file::writer::{get_file_magic, TrackedWrite};
 use crate::format::EncryptionAlgorithm;
@@ -225,6 +224,94 @@ impl<'a, W: Write> ThriftMetadataWriter<'a, W> {
     }
 }
 
+/// Writes the page index of individual row groups, see
+/// [`PageIndexPosition`](crate::file::properties::PageIndexPosition)
+#[derive(Debug, Default)]
+pub(crate) struct PageIndexWriter {
+    object_writer: MetadataObjectWriter,
+}
+
+impl PageIndexWriter {
+    #[cfg(feature = "encryption")]
+    pub fn with_file_encryptor(mut self, file_encryptor: Option<Arc<FileEncryptor>>) -> Self {
+        self.object_writer = self.object_writer.with_file_encryptor(file_encryptor);
+        self
+    }
+
+    /// Serialize the column indexes of `row_group` to `buf`, taking them from `column_indexes`
+    ///
+    /// Note: also updates the column index offset and length of each column chunk
+    /// to reflect the position and length of the serialized column indexes.
+    pub fn write_column_indexes<W: Write>(
+        &self,
+        buf: &mut TrackedWrite<W>,
+        row_group: &mut RowGroupMetaData,
+        column_indexes: &mut [Option<ColumnIndex>],
+    ) -> Result<()> {
+        let row_group_idx = row_group_ordinal(row_group)?;
+        for (column_idx, column_chunk) in row_group.columns_mut().iter_mut().enumerate() {
+            if let Some(column_index) = column_indexes[column_idx].take() {
+                let start_offset = buf.bytes_written();
+                self.object_writer.write_column_index(
+                    &column_index,
+                    &column_chunk.to_thrift(),
+                    row_group_idx,
+                    column_idx,
+                    &mut *buf,
+                )?;
+                let end_offset = buf.bytes_written();
+                *column_chunk = column_chunk
+                    .clone()
+                    .into_builder()
+                    .set_column_index_offset(Some(start_offset as i64))
+                    .set_column_index_length(Some((end_offset - start_offset) as i32))
+                    .build()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Serialize the offset indexes of `row_group` to `buf`, taking them from `offset_indexes`
+    ///
+    /// Note: also updates the offset index offset and length of each column chunk
+    /// to reflect the position and length of the serialized offset indexes.
+    pub fn write_offset_indexes<W: Write>(
+        &self,
+        buf: &mut TrackedWrite<W>,
+        row_group: &mut RowGroupMetaData,
+        offset_indexes: &mut [Option<OffsetIndex>],
+    ) -> Result<()> {
+        let row_group_idx = row_group_ordinal(row_group)?;
+        for (column_idx, column_chunk) in row_group.columns_mut().iter_mut().enumerate() {
+            if let Some(offset_index) = offset_indexes[column_idx].take() {
+                let start_offset = buf.bytes_written();
+                self.object_writer.write_offset_index(
+                    &offset_index,
+                    &column_chunk.to_thrift(),
+                    row_group_idx,
+                    column_idx,
+                    &mut *buf,
+                )?;
+                let end_offset = buf.bytes_written();
+                *column_chunk = column_chunk
+                    .clone()
+                    .into_builder()
+                    .set_offset_index_offset(Some(start_offset as i64))
+                    .set_offset_index_length(Some((end_offset - start_offset) as i32))
+                    .build()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn row_group_ordinal(row_group: &RowGroupMetaData) -> Result<usize> {
+    let ordinal = row_group
+        .ordinal()
+        .ok_or_else(|| general_err!("Missing row group ordinal"))?;
+    usize::try_from(ordinal).map_err(|_| general_err!("Negative row group ordinal: {}", ordinal))
+}
+
 /// Writes [`ParquetMetaData`] to a byte stream
 ///
 /// This structure handles the details of writing the various parts of Parquet
@@ -16,7 +16,7 @@
 // under the License.
 
 //! Configuration via [`WriterProperties`] and [`ReaderProperties`]
-use crate::basic::{Compression, Encoding};
+use crate::basic::{Compression, CompressionCodecFactory, Encoding};
 use crate::compression::{CodecOptions, CodecOptionsBuilder};
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::FileEncryptionProperties;
@@ -40,6 +40,8 @@ pub const DEFAULT_DICTIONARY_ENABLED: bool = true;
 pub const DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT: usize = DEFAULT_PAGE_SIZE;
 /// Default value for [`WriterProperties::data_page_row_count_limit`]
 pub const DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT: usize = 20_000;
+/// Default value for [`WriterProperties::fallback_encoding_ratio`]
+pub const DEFAULT_FALLBACK_ENCODING_RATIO: f64 = 1.0;
 /// Default value for [`WriterProperties::statistics_enabled`]
 pub const DEFAULT_STATISTICS_ENABLED: EnabledStatistics = EnabledStatistics::Page;
 /// Default value for [`WriterProperties::write_page_header_statistics`]
@@ -48,6 +50,8 @@ pub const DEFAULT_WRITE_PAGE_HEADER_STATISTICS: bool = false;
 pub const DEFAULT_MAX_ROW_GROUP_SIZE: usize = 1024 * 1024;
 /// Default value for [`WriterProperties::bloom_filter_position`]
 pub const DEFAULT_BLOOM_FILTER_POSITION: BloomFilterPosition = BloomFilterPosition::AfterRowGroup;
+/// Default value for [`WriterProperties::page_index_position`]
+pub const DEFAULT_PAGE_INDEX_POSITION: PageIndexPosition = PageIndexPosition::End;
 /// Default value for [`WriterProperties::created_by`]
 pub const DEFAULT_CREATED_BY: &str = concat!("parquet-rs version ", env!("CARGO_PKG_VERSION"));
 /// Default value for [`WriterProperties::column_index_truncate_length`]
@@ -56,6 +60,8 @@ pub const DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH: Option<usize> = Some(64);
 pub const DEFAULT_BLOOM_FILTER_FPP: f64 = 0.05;
 /// Default value for [`BloomFilterProperties::ndv`]
 pub const DEFAULT_BLOOM_FILTER_NDV: u64 = 1_000_000_u64;
+/// Default value for [`WriterProperties::bloom_filter_from_dictionary`]
+pub const DEFAULT_BLOOM_FILTER_FROM_DICTIONARY: bool = false;
 /// Default values for [`WriterProperties::statistics_truncate_length`]
 pub const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = Some(64);
 /// Default value for [`WriterProperties::offset_index_disabled`]
@@ -115,6 +121,29 @@ pub enum BloomFilterPosition {
     End,
 }
 
+/// Where in the file [`ArrowWriter`](crate::arrow::arrow_writer::ArrowWriter) should
+/// write the [page index] (`ColumnIndex` and `OffsetIndex` structures)
+///
+/// Basic constant, which is not part of the Thrift definition.
+///
+/// [page index]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageIndexPosition {
+    /// Write the page index of each row group right after the row group, and after
+    /// any Bloom Filters written with [`BloomFilterPosition::AfterRowGroup`]
+    ///
+    /// This saves memory and avoids a large write when closing the file, which reduces
+    /// the latency of the final write when streaming to object storage. Readers locate
+    /// the page index using the offsets recorded in the footer, however this requires
+    /// more requests for readers that fetch the page index of multiple row groups.
+    AfterRowGroup,
+    /// Write the page indexes of all row groups together just before the footer
+    ///
+    /// This allows readers to fetch the page index of the whole file in one request,
+    /// at the cost of memory usage for writers.
+    End,
+}
+
 /// Reference counted writer properties.
 pub type WriterPropertiesPtr = Arc<WriterProperties>;
 
@@ -156,19 +185,25 @@ pub type WriterPropertiesPtr = Arc<WriterProperties>;
 pub struct WriterProperties {
     data_page_size_limit: usize,
     data_page_row_count_limit: usize,
+    fallback_encoding_ratio: f64,
     write_batch_size: usize,
     max_row_group_size: usize,
+    max_row_group_bytes: Option<usize>,
     bloom_filter_position: BloomFilterPosition,
+    page_index_position: PageIndexPosition,
     writer_version: WriterVersion,
     created_by: String,
     offset_index_disabled: bool,
     pub(crate) key_value_metadata: Option<Vec<KeyValue>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
+    #[cfg(feature = "variant_experimental")]
+    variant_field_bloom_filters: HashMap<ColumnPath, BloomFilterProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    codec_factory: Option<Arc<dyn CompressionCodecFactory>>,
     #[cfg(feature = "encryption")]
     pub(crate) file_encryption_properties: Option<FileEncryptionProperties>,
 }
@@ -231,6 +266,14 @@ impl WriterProperties {
         self.data_page_row_count_limit
     }
 
+    /// Returns the maximum ratio of the size of a data page to its `PLAIN` encoded size,
+    /// above which the next fallback encoding is used.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_fallback_encoding_ratio`]
+    pub fn fallback_encoding_ratio(&self) -> f64 {
+        self.fallback_encoding_ratio
+    }
+
     /// Returns configured batch size for writes.
     ///
     /// When writing a batch of data, this setting allows to split it internally into
@@ -249,6 +292,13 @@ impl WriterProperties {
         self.max_row_group_size
     }
 
+    /// Returns the target maximum encoded size of a row group in bytes, if any.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_max_row_group_bytes`]
+    pub fn max_row_group_bytes(&self) -> Option<usize> {
+        self.max_row_group_bytes
+    }
+
     /// Returns bloom filter position.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_bloom_filter_position`]
@@ -256,6 +306,13 @@ impl WriterProperties {
         self.bloom_filter_position
     }
 
+    /// Returns page index position.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_page_index_position`]
+    pub fn page_index_position(&self) -> PageIndexPosition {
+        self.page_index_position
+    }
+
     /// Returns configured writer version.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_writer_version`]
@@ -322,6 +379,30 @@ impl WriterProperties {
         self.statistics_truncate_length
     }
 
+    /// Returns the maximum length of truncated min/max values in the column index of a
+    /// specific column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_column_index_truncate_length`]
+    pub fn column_column_index_truncate_length(&self, col: &ColumnPath) -> Option<usize> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.column_index_truncate_length())
+            .unwrap_or(self.column_index_truncate_length)
+    }
+
+    /// Returns the maximum length of truncated min/max values in the [`Statistics`] of a
+    /// specific column.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_statistics_truncate_length`]
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn column_statistics_truncate_length(&self, col: &ColumnPath) -> Option<usize> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_truncate_length())
+            .unwrap_or(self.statistics_truncate_length)
+    }
+
     /// Returns `true` if type coercion is enabled.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_coerce_types`]
@@ -329,6 +410,13 @@ impl WriterProperties {
         self.coerce_types
     }
 
+    /// Returns the [`CompressionCodecFactory`] used to create codecs, if any.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_codec_factory`]
+    pub fn codec_factory(&self) -> Option<&Arc<dyn CompressionCodecFactory>> {
+        self.codec_factory.as_ref()
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     ///
     /// This is not configurable.
@@ -362,6 +450,16 @@ impl WriterProperties {
             .or_else(|| self.default_column_properties.encoding())
     }
 
+    /// Returns the chain of fallback encodings for a column, if set.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_column_fallback_encodings`]
+    pub fn fallback_encodings(&self, col: &ColumnPath) -> Option<&[Encoding]> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.fallback_encodings())
+            .or_else(|| self.default_column_properties.fallback_encodings())
+    }
+
     /// Returns compression codec for a column.
     ///
     /// For more details see [`WriterPropertiesBuilder::set_column_compression`]
@@ -423,6 +521,43 @@ impl WriterProperties {
             .or_else(|| self.default_column_properties.bloom_filter_properties())
     }
 
+    /// Returns `true` if the bloom filter of the given column is built from its
+    /// dictionary when the dictionary page is written, rather than from each value
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_bloom_filter_from_dictionary`]
+    pub fn bloom_filter_from_dictionary(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.bloom_filter_from_dictionary())
+            .or_else(|| {
+                self.default_column_properties
+                    .bloom_filter_from_dictionary()
+            })
+            .unwrap_or(DEFAULT_BLOOM_FILTER_FROM_DICTIONARY)
+    }
+
+    /// Returns the [`BloomFilterProperties`] of the bloom filter over the field names of the
+    /// given variant column
+    ///
+    /// Returns `None` if the filter is disabled
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`]
+    #[cfg(feature = "variant_experimental")]
+    pub fn variant_field_bloom_filter_properties(
+        &self,
+        col: &ColumnPath,
+    ) -> Option<&BloomFilterProperties> {
+        self.variant_field_bloom_filters.get(col)
+    }
+
+    /// Returns the variant columns with a bloom filter over their field names
+    #[cfg(feature = "variant_experimental")]
+    pub(crate) fn variant_field_bloom_filters(
+        &self,
+    ) -> impl Iterator<Item = (&ColumnPath, &BloomFilterProperties)> {
+        self.variant_field_bloom_filters.iter()
+    }
+
     /// Return file encryption properties
     ///
     /// For more details see [`WriterPropertiesBuilder::with_file_encryption_properties`]
@@ -438,19 +573,25 @@ impl WriterProperties {
 pub struct WriterPropertiesBuilder {
     data_page_size_limit: usize,
     data_page_row_count_limit: usize,
+    fallback_encoding_ratio: f64,
     write_batch_size: usize,
     max_row_group_size: usize,
+    max_row_group_bytes: Option<usize>,
     bloom_filter_position: BloomFilterPosition,
+    page_index_position: PageIndexPosition,
     writer_version: WriterVersion,
     created_by: String,
     offset_index_disabled: bool,
     key_value_metadata: Option<Vec<KeyValue>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
+    #[cfg(feature = "variant_experimental")]
+    variant_field_bloom_filters: HashMap<ColumnPath, BloomFilterProperties>,
     sorting_columns: Option<Vec<SortingColumn>>,
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    codec_factory: Option<Arc<dyn CompressionCodecFactory>>,
     #[cfg(feature = "encryption")]
     file_encryption_properties: Option<FileEncryptionProperties>,
 }
@@ -461,19 +602,25 @@ impl WriterPropertiesBuilder {
         Self {
             data_page_size_limit: DEFAULT_PAGE_SIZE,
             data_page_row_count_limit: DEFAULT_DATA_PAGE_ROW_COUNT_LIMIT,
+            fallback_encoding_ratio: DEFAULT_FALLBACK_ENCODING_RATIO,
             write_batch_size: DEFAULT_WRITE_BATCH_SIZE,
             max_row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            max_row_group_bytes: None,
             bloom_filter_position: DEFAULT_BLOOM_FILTER_POSITION,
+            page_index_position: DEFAULT_PAGE_INDEX_POSITION,
             writer_version: DEFAULT_WRITER_VERSION,
             created_by: DEFAULT_CREATED_BY.to_string(),
             offset_index_disabled: DEFAULT_OFFSET_INDEX_DISABLED,
             key_value_metadata: None,
             default_column_properties: Default::default(),
             column_properties: HashMap::new(),
+            #[cfg(feature = "variant_experimental")]
+            variant_field_bloom_filters: HashMap::new(),
             sorting_columns: None,
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
             statistics_truncate_length: DEFAULT_STATISTICS_TRUNCATE_LENGTH,
             coerce_types: DEFAULT_COERCE_TYPES,
+            codec_factory: None,
             #[cfg(feature = "encryption")]
             file_encryption_properties: None,
         }
@@ -484,19 +631,25 @@ impl WriterPropertiesBuilder {
         WriterProperties {
             data_page_size_limit: self.data_page_size_limit,
             data_page_row_count_limit: self.data_page_row_count_limit,
+            fallback_encoding_ratio: self.fallback_encoding_ratio,
             write_batch_size: self.write_batch_size,
             max_row_group_size: self.max_row_group_size,
+            max_row_group_bytes: self.max_row_group_bytes,
             bloom_filter_position: self.bloom_filter_position,
+            page_index_position: self.page_index_position,
             writer_version: self.writer_version,
             created_by: self.created_by,
             offset_index_disabled: self.offset_index_disabled,
             key_value_metadata: self.key_value_metadata,
             default_column_properties: self.default_column_properties,
             column_properties: self.column_properties,
+            #[cfg(feature = "variant_experimental")]
+            variant_field_bloom_filters: self.variant_field_bloom_filters,
             sorting_columns: self.sorting_columns,
             column_index_truncate_length: self.column_index_truncate_length,
             statistics_truncate_length: self.statistics_truncate_length,
             coerce_types: self.coerce_types,
+            codec_factory: self.codec_factory,
             #[cfg(feature = "encryption")]
             file_encryption_properties: self.file_encryption_properties,
         }
@@ -546,6 +699,24 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the maximum ratio of the size of a data page to the size of its values when
+    /// `PLAIN` encoded, above which the following pages are encoded with the next fallback
+    /// encoding (defaults to `1.0` via [`DEFAULT_FALLBACK_ENCODING_RATIO`]).
+    ///
+    /// Lower values only keep an encoding if it substantially reduces the size of the
+    /// pages. See [`Self::set_column_fallback_encodings`].
+    ///
+    /// # Panics
+    /// If the value is not positive.
+    pub fn set_fallback_encoding_ratio(mut self, value: f64) -> Self {
+        assert!(
+            value > 0.,
+            "Fallback encoding ratio must be positive, got {value}"
+        );
+        self.fallback_encoding_ratio = value;
+        self
+    }
+
     /// Sets write batch size (defaults to 1024 via [`DEFAULT_WRITE_BATCH_SIZE`]).
     ///
     /// For performance reasons, data for each column is written in
@@ -571,6 +742,29 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the target maximum encoded size of a row group in bytes (defaults to `None`)
+    ///
+    /// When set, [`ArrowWriter`] flushes the in progress row group once its estimated
+    /// encoded size, after compression, reaches `value` bytes, so that row groups have a
+    /// predictable size regardless of the width of the rows. Row groups are also still
+    /// limited to [`set_max_row_group_size`](Self::set_max_row_group_size) rows.
+    ///
+    /// The size is estimated from the pages written so far and the data buffered for the
+    /// next page, see [`ArrowWriter::in_progress_size`]. As the writer can only flush
+    /// between rows, incoming batches are split based on the average encoded size of the
+    /// rows written so far, and a row group may slightly exceed `value`.
+    ///
+    /// # Panics
+    /// If the value is set to 0.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::ArrowWriter
+    /// [`ArrowWriter::in_progress_size`]: crate::arrow::ArrowWriter::in_progress_size
+    pub fn set_max_row_group_bytes(mut self, value: Option<usize>) -> Self {
+        assert_ne!(value, Some(0), "Cannot have a 0 max row group bytes");
+        self.max_row_group_bytes = value;
+        self
+    }
+
     /// Sets where in the final file Bloom Filters are written (defaults to  [`AfterRowGroup`]
     /// via [`DEFAULT_BLOOM_FILTER_POSITION`])
     ///
@@ -580,6 +774,15 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets where in the final file the page index is written (defaults to [`End`]
+    /// via [`DEFAULT_PAGE_INDEX_POSITION`])
+    ///
+    /// [`End`]: PageIndexPosition::End
+    pub fn set_page_index_position(mut self, value: PageIndexPosition) -> Self {
+        self.page_index_position = value;
+        self
+    }
+
     /// Sets "created by" property (defaults to `parquet-rs version <VERSION>` via
     /// [`DEFAULT_CREATED_BY`]).
     ///
@@ -691,6 +894,16 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets a [`CompressionCodecFactory`] used to create the compression codecs for
+    /// all columns (defaults to `None`).
+    ///
+    /// The factory is consulted before the built-in codecs, allowing custom implementations
+    /// of any [`Compression`] to be used when writing.
+    pub fn set_codec_factory(mut self, value: Arc<dyn CompressionCodecFactory>) -> Self {
+        self.codec_factory = Some(value);
+        self
+    }
+
     /// Sets FileEncryptionProperties (defaults to `None`)
     #[cfg(feature = "encryption")]
     pub fn with_file_encryption_properties(
@@ -719,6 +932,19 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the default chain of fallback encodings for all columns.
+    ///
+    /// See [`Self::set_column_fallback_encodings`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `encodings` is empty or contains a dictionary encoding.
+    pub fn set_fallback_encodings(mut self, encodings: Vec<Encoding>) -> Self {
+        self.default_column_properties
+            .set_fallback_encodings(encodings);
+        self
+    }
+
     /// Sets default compression codec for all columns (default to [`UNCOMPRESSED`] via
     /// [`DEFAULT_COMPRESSION`]).
     ///
@@ -833,6 +1059,30 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets whether the bloom filters of dictionary encoded columns are built from the
+    /// dictionary, for all columns (defaults to `false` via
+    /// [`DEFAULT_BLOOM_FILTER_FROM_DICTIONARY`]).
+    ///
+    /// By default, the bloom filter of a column chunk is allocated with the size for the
+    /// configured [`ndv`] when the column chunk is started, and each value is inserted as it is
+    /// written. When enabled, the bloom filter of a dictionary encoded column chunk is only
+    /// built once its dictionary page is written, from the distinct values of the dictionary.
+    /// This reduces the memory used while the column chunk is written, and the filter is sized
+    /// for the lesser of [`ndv`] and the actual number of distinct values, which can greatly
+    /// reduce the size of the bloom filters of columns with few distinct values.
+    ///
+    /// If the column chunk falls back from dictionary encoding, the bloom filter is built with
+    /// the configured [`ndv`] at that point, and the remaining values are inserted as they
+    /// are written. This has no effect on columns without bloom filters or dictionary
+    /// encoding.
+    ///
+    /// [`ndv`]: BloomFilterProperties::ndv
+    pub fn set_bloom_filter_from_dictionary(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_bloom_filter_from_dictionary(value);
+        self
+    }
+
     // ----------------------------------------------------------------------
     // Setters for a specific column
 
@@ -859,6 +1109,36 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets a chain of fallback encodings for a specific column, tried in order.
+    ///
+    /// Takes precedence over [`Self::set_fallback_encodings`], and over the encoding set
+    /// with [`Self::set_encoding`] or [`Self::set_column_encoding`].
+    ///
+    /// Data pages are encoded with the first encoding of the chain, until the ratio of the
+    /// size of a data page to its `PLAIN` encoded size exceeds
+    /// [`Self::set_fallback_encoding_ratio`], in which case the following pages of the
+    /// column chunk are encoded with the next encoding of the chain. This allows, for
+    /// example, to try `DELTA_BYTE_ARRAY` for string columns, and fall back to the faster
+    /// to decode `PLAIN` for the values that do not share prefixes.
+    ///
+    /// Dictionary encoding can not be part of the chain, as the dictionary page must
+    /// precede the data pages of a column chunk, so that a column chunk can not switch to
+    /// it after its first pages. Instead, if dictionary encoding is enabled with
+    /// [`Self::set_column_dictionary_enabled`], it is always tried first, and the chain is
+    /// used once the dictionary exceeds [`Self::set_column_dictionary_page_size_limit`].
+    ///
+    /// # Panics
+    ///
+    /// If `encodings` is empty or contains a dictionary encoding.
+    pub fn set_column_fallback_encodings(
+        mut self,
+        col: ColumnPath,
+        encodings: Vec<Encoding>,
+    ) -> Self {
+        self.get_mut_props(col).set_fallback_encodings(encodings);
+        self
+    }
+
     /// Sets compression codec for a specific column.
     ///
     /// Takes precedence over [`Self::set_compression`].
@@ -930,6 +1210,104 @@ impl WriterPropertiesBuilder {
         self.get_mut_props(col).set_bloom_filter_ndv(value);
         self
     }
+
+    /// Sets whether the bloom filter is built from the dictionary for a specific column.
+    ///
+    /// Takes precedence over [`Self::set_bloom_filter_from_dictionary`].
+    pub fn set_column_bloom_filter_from_dictionary(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col)
+            .set_bloom_filter_from_dictionary(value);
+        self
+    }
+
+    /// Sets whether a bloom filter over the top-level field names of the objects of a
+    /// specific variant column should be written, so that readers can skip the row groups
+    /// that contain no object with a given field.
+    ///
+    /// The filter of each row group is written to the file like the bloom filters of the
+    /// columns, and is read with [`read_variant_field_bloom_filter`]. Only applies to the
+    /// top-level variant columns written by the [`ArrowWriter`].
+    ///
+    /// [`read_variant_field_bloom_filter`]: crate::variant::read_variant_field_bloom_filter
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    #[cfg(feature = "variant_experimental")]
+    pub fn set_variant_field_bloom_filter_enabled(mut self, col: ColumnPath, value: bool) -> Self {
+        if value {
+            self.variant_field_bloom_filters.entry(col).or_default();
+        } else {
+            self.variant_field_bloom_filters.remove(&col);
+        }
+        self
+    }
+
+    /// Sets the false positive probability of the bloom filter over the field names of a
+    /// specific variant column, and implicitly enables it.
+    ///
+    /// See [`Self::set_variant_field_bloom_filter_enabled`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `value` is not between 0 and 1 exclusive
+    #[cfg(feature = "variant_experimental")]
+    pub fn set_variant_field_bloom_filter_fpp(mut self, col: ColumnPath, value: f64) -> Self {
+        assert!(
+            value > 0. && value < 1.0,
+            "fpp must be between 0 and 1 exclusive, got {value}"
+        );
+        self.variant_field_bloom_filters.entry(col).or_default().fpp = value;
+        self
+    }
+
+    /// Sets the number of distinct field names of the bloom filter over the field names of
+    /// a specific variant column, and implicitly enables it.
+    ///
+    /// See [`Self::set_variant_field_bloom_filter_enabled`].
+    #[cfg(feature = "variant_experimental")]
+    pub fn set_variant_field_bloom_filter_ndv(mut self, col: ColumnPath, value: u64) -> Self {
+        self.variant_field_bloom_filters.entry(col).or_default().ndv = value;
+        self
+    }
+
+    /// Sets the max length of min/max value fields when writing the column [`Index`] of a
+    /// specific column.
+    ///
+    /// Takes precedence over [`Self::set_column_index_truncate_length`], allowing the
+    /// values of columns of large binary values to be truncated more aggressively. Whether
+    /// the column [`Index`] of a column is written at all is controlled by
+    /// [`Self::set_column_statistics_enabled`].
+    ///
+    /// * If `Some`, must be greater than 0, otherwise will panic
+    /// * If `None`, there's no effective limit.
+    ///
+    /// [`Index`]: crate::file::page_index::index::Index
+    pub fn set_column_column_index_truncate_length(
+        mut self,
+        col: ColumnPath,
+        max_length: Option<usize>,
+    ) -> Self {
+        self.get_mut_props(col)
+            .set_column_index_truncate_length(max_length);
+        self
+    }
+
+    /// Sets the max length of min/max value fields in row group and data page header
+    /// [`Statistics`] of a specific column.
+    ///
+    /// Takes precedence over [`Self::set_statistics_truncate_length`].
+    ///
+    /// * If `Some`, must be greater than 0, otherwise will panic
+    /// * If `None`, there's no effective limit.
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    pub fn set_column_statistics_truncate_length(
+        mut self,
+        col: ColumnPath,
+        max_length: Option<usize>,
+    ) -> Self {
+        self.get_mut_props(col)
+            .set_statistics_truncate_length(max_length);
+        self
+    }
 }
 
 /// Controls the level of statistics to be computed by the writer and stored in
@@ -1026,6 +1404,7 @@ impl Default for BloomFilterProperties {
 #[derive(Debug, Clone, Default, PartialEq)]
 struct ColumnProperties {
     encoding: Option<Encoding>,
+    fallback_encodings: Option<Vec<Encoding>>,
     codec: Option<Compression>,
     dictionary_page_size_limit: Option<usize>,
     dictionary_enabled: Option<bool>,
@@ -1033,6 +1412,9 @@ struct ColumnProperties {
     write_page_header_statistics: Option<bool>,
     /// bloom filter related properties
     bloom_filter_properties: Option<BloomFilterProperties>,
+    bloom_filter_from_dictionary: Option<bool>,
+    column_index_truncate_length: Option<Option<usize>>,
+    statistics_truncate_length: Option<Option<usize>>,
 }
 
 impl ColumnProperties {
@@ -1052,6 +1434,20 @@ impl ColumnProperties {
         self.encoding = Some(value);
     }
 
+    /// Sets the chain of fallback encodings for this column.
+    ///
+    /// Panics if `encodings` is empty or contains a dictionary encoding.
+    fn set_fallback_encodings(&mut self, encodings: Vec<Encoding>) {
+        assert!(!encodings.is_empty(), "Fallback encodings can not be empty");
+        if encodings
+            .iter()
+            .any(|e| *e == Encoding::PLAIN_DICTIONARY || *e == Encoding::RLE_DICTIONARY)
+        {
+            panic!("Dictionary encoding can not be used as fallback encoding");
+        }
+        self.fallback_encodings = Some(encodings);
+    }
+
     /// Sets compression codec for this column.
     fn set_compression(&mut self, value: Compression) {
         self.codec = Some(value);
@@ -1113,11 +1509,37 @@ impl ColumnProperties {
             .ndv = value;
     }
 
+    /// Sets whether the bloom filter is built from the dictionary for this column.
+    fn set_bloom_filter_from_dictionary(&mut self, value: bool) {
+        self.bloom_filter_from_dictionary = Some(value);
+    }
+
+    /// Sets the max length of min/max values in the column index for this column.
+    fn set_column_index_truncate_length(&mut self, max_length: Option<usize>) {
+        if let Some(value) = max_length {
+            assert!(value > 0, "Cannot have a 0 column index truncate length. If you wish to disable min/max value truncation, set it to `None`.");
+        }
+        self.column_index_truncate_length = Some(max_length);
+    }
+
+    /// Sets the max length of min/max values in the statistics for this column.
+    fn set_statistics_truncate_length(&mut self, max_length: Option<usize>) {
+        if let Some(value) = max_length {
+            assert!(value > 0, "Cannot have a 0 statistics truncate length. If you wish to disable min/max value truncation, set it to `None`.");
+        }
+        self.statistics_truncate_length = Some(max_length);
+    }
+
     /// Returns optional encoding for this column.
     fn encoding(&self) -> Option<Encoding> {
         self.encoding
     }
 
+    /// Returns optional chain of fallback encodings for this column.
+    fn fallback_encodings(&self) -> Option<&[Encoding]> {
+        self.fallback_encodings.as_deref()
+    }
+
     /// Returns optional compression codec for this column.
     fn compression(&self) -> Option<Compression> {
         self.codec
@@ -1153,6 +1575,23 @@ impl ColumnProperties {
     fn bloom_filter_properties(&self) -> Option<&BloomFilterProperties> {
         self.bloom_filter_properties.as_ref()
     }
+
+    /// Returns `Some(true)` if the bloom filter is built from the dictionary for this column.
+    fn bloom_filter_from_dictionary(&self) -> Option<bool> {
+        self.bloom_filter_from_dictionary
+    }
+
+    /// Returns the max length of min/max values in the column index for this column. If
+    /// result is `None`, then no setting has been provided.
+    fn column_index_truncate_length(&self) -> Option<Option<usize>> {
+        self.column_index_truncate_length
+    }
+
+    /// Returns the max length of min/max values in the statistics for this column. If
+    /// result is `None`, then no setting has been provided.
+    fn statistics_truncate_length(&self) -> Option<Option<usize>> {
+        self.statistics_truncate_length
+    }
 }
 
 /// Reference counted reader properties.
@@ -1250,6 +1689,16 @@ impl ReaderPropertiesBuilder {
         self.read_bloom_filter = Some(value);
         self
     }
+
+    /// Sets a [`CompressionCodecFactory`] used to create the decompression codecs
+    /// (defaults to `None`).
+    ///
+    /// The factory is consulted before the built-in codecs, allowing custom implementations
+    /// of any [`Compression`] to be used when reading.
+    pub fn set_codec_factory(mut self, value: Arc<dyn CompressionCodecFactory>) -> Self {
+        self.codec_options_builder = self.codec_options_builder.set_codec_factory(Some(value));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -1272,7 +1721,9 @@ mod tests {
         );
         assert_eq!(props.write_batch_size(), DEFAULT_WRITE_BATCH_SIZE);
         assert_eq!(props.max_row_group_size(), DEFAULT_MAX_ROW_GROUP_SIZE);
+        assert_eq!(props.max_row_group_bytes(), None);
         assert_eq!(props.bloom_filter_position(), DEFAULT_BLOOM_FILTER_POSITION);
+        assert_eq!(props.page_index_position(), DEFAULT_PAGE_INDEX_POSITION);
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
         assert_eq!(props.key_value_metadata(), None);
@@ -1481,6 +1932,136 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_bloom_filter_from_dictionary() {
+        let col = ColumnPath::from("col");
+        let other = ColumnPath::from("other");
+        assert!(!WriterProperties::builder()
+            .build()
+            .bloom_filter_from_dictionary(&col));
+
+        let props = WriterProperties::builder()
+            .set_bloom_filter_from_dictionary(true)
+            .set_column_bloom_filter_from_dictionary(other.clone(), false)
+            .build();
+        assert!(props.bloom_filter_from_dictionary(&col));
+        assert!(!props.bloom_filter_from_dictionary(&other));
+        // Does not enable bloom filters
+        assert_eq!(props.bloom_filter_properties(&col), None);
+    }
+
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn test_writer_properties_variant_field_bloom_filter() {
+        let v = ColumnPath::from("v");
+        let other = ColumnPath::from("other");
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_variant_field_bloom_filter_fpp(v.clone(), 0.01)
+            .set_variant_field_bloom_filter_ndv(v.clone(), 100)
+            .build();
+        assert_eq!(
+            props.variant_field_bloom_filter_properties(&v),
+            Some(&BloomFilterProperties {
+                fpp: 0.01,
+                ndv: 100
+            })
+        );
+        // Not enabled by the bloom filters of the columns
+        assert_eq!(props.variant_field_bloom_filter_properties(&other), None);
+
+        let props = WriterProperties::builder()
+            .set_variant_field_bloom_filter_enabled(v.clone(), true)
+            .set_variant_field_bloom_filter_enabled(other.clone(), true)
+            .set_variant_field_bloom_filter_enabled(other.clone(), false)
+            .build();
+        assert_eq!(
+            props.variant_field_bloom_filter_properties(&v),
+            Some(&BloomFilterProperties::default())
+        );
+        assert_eq!(props.variant_field_bloom_filter_properties(&other), None);
+        // Does not enable the bloom filters of the columns
+        assert_eq!(props.bloom_filter_properties(&v), None);
+    }
+
+    #[test]
+    fn test_writer_properties_column_truncate_length() {
+        let col = ColumnPath::from("col");
+        let other = ColumnPath::from("other");
+        let props = WriterProperties::builder()
+            .set_column_index_truncate_length(Some(32))
+            .set_statistics_truncate_length(None)
+            .set_column_column_index_truncate_length(col.clone(), None)
+            .set_column_statistics_truncate_length(col.clone(), Some(8))
+            .build();
+
+        assert_eq!(props.column_column_index_truncate_length(&col), None);
+        assert_eq!(props.column_statistics_truncate_length(&col), Some(8));
+        assert_eq!(props.column_column_index_truncate_length(&other), Some(32));
+        assert_eq!(props.column_statistics_truncate_length(&other), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot have a 0 statistics truncate length")]
+    fn test_writer_properties_column_statistics_truncate_length_zero() {
+        WriterProperties::builder()
+            .set_column_statistics_truncate_length(ColumnPath::from("col"), Some(0));
+    }
+
+    #[test]
+    fn test_writer_properties_fallback_encodings() {
+        let props = WriterProperties::builder()
+            .set_encoding(Encoding::PLAIN)
+            .set_fallback_encodings(vec![Encoding::DELTA_LENGTH_BYTE_ARRAY, Encoding::PLAIN])
+            .set_column_fallback_encodings(
+                ColumnPath::from("col"),
+                vec![Encoding::DELTA_BYTE_ARRAY, Encoding::PLAIN],
+            )
+            .build();
+
+        assert_eq!(
+            props.fallback_encodings(&ColumnPath::from("col")),
+            Some([Encoding::DELTA_BYTE_ARRAY, Encoding::PLAIN].as_slice())
+        );
+        assert_eq!(
+            props.fallback_encodings(&ColumnPath::from("other")),
+            Some([Encoding::DELTA_LENGTH_BYTE_ARRAY, Encoding::PLAIN].as_slice())
+        );
+        assert_eq!(
+            WriterProperties::builder()
+                .build()
+                .fallback_encodings(&ColumnPath::from("col")),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Dictionary encoding can not be used as fallback encoding")]
+    fn test_writer_properties_panic_when_dictionary_is_in_fallback_encodings() {
+        WriterProperties::builder()
+            .set_column_fallback_encodings(
+                ColumnPath::from("col"),
+                vec![Encoding::DELTA_BYTE_ARRAY, Encoding::RLE_DICTIONARY],
+            )
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Dictionary encoding can not be used as fallback encoding")]
+    fn test_writer_properties_panic_when_dictionary_is_in_default_fallback_encodings() {
+        WriterProperties::builder()
+            .set_fallback_encodings(vec![Encoding::PLAIN_DICTIONARY, Encoding::PLAIN])
+            .build();
+    }
+
+    #[test]
+    #[should_panic(expected = "Fallback encodings can not be empty")]
+    fn test_writer_properties_panic_when_fallback_encodings_are_empty() {
+        WriterProperties::builder()
+            .set_fallback_encodings(vec![])
+            .build();
+    }
+
     #[test]
     fn test_writer_properties_column_dictionary_page_size_limit() {
         let props = WriterProperties::builder()
@@ -24,6 +24,7 @@ use crate::format::{ColumnIndex, OffsetIndex};
 use crate::thrift::TSerializable;
 use std::fmt::Debug;
 use std::io::{BufWriter, IoSlice, Read};
+use std::time::Duration;
 use std::{io::Write, sync::Arc};
 use thrift::protocol::TCompactOutputProtocol;
 
@@ -39,7 +40,7 @@ use crate::encryption::encrypt::{
     get_column_crypto_metadata, FileEncryptionProperties, FileEncryptor,
 };
 use crate::errors::{ParquetError, Result};
-use crate::file::properties::{BloomFilterPosition, WriterPropertiesPtr};
+use crate::file::properties::{BloomFilterPosition, PageIndexPosition, WriterPropertiesPtr};
 use crate::file::reader::ChunkReader;
 #[cfg(feature = "encryption")]
 use crate::file::PARQUET_MAGIC_ENCR_FOOTER;
@@ -138,6 +139,53 @@ pub type OnCloseRowGroup<'a, W> = Box<
 // ----------------------------------------------------------------------
 // Serialized impl for file & row group writers
 
+/// Metrics describing the time spent writing the structures of a parquet file other
+/// than the row group data, see [`SerializedFileWriter::metrics`]
+///
+/// These can be used to tune [`BloomFilterPosition`] and [`PageIndexPosition`], for example
+/// to reduce the latency of closing a file that is streamed to object storage.
+///
+/// Times include only serializing the structures to the underlying writer, which is
+/// buffered, and are not measured when targeting `wasm32-unknown-unknown`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriterMetrics {
+    /// Time spent writing bloom filters
+    pub bloom_filter_write_time: Duration,
+    /// Number of bytes of bloom filters written
+    pub bloom_filter_bytes: usize,
+    /// Time spent writing column and offset indexes
+    pub page_index_write_time: Duration,
+    /// Number of bytes of column and offset indexes written
+    pub page_index_bytes: usize,
+    /// Time spent writing the file metadata and footer
+    pub footer_write_time: Duration,
+    /// Number of bytes of file metadata and footer written
+    pub footer_bytes: usize,
+}
+
+/// Runs `f`, adding the time it takes and the number of bytes it writes to `buf`
+/// to `time` and `bytes` respectively
+fn measure<W: Write, T>(
+    buf: &mut TrackedWrite<W>,
+    time: &mut Duration,
+    bytes: &mut usize,
+    f: impl FnOnce(&mut TrackedWrite<W>) -> Result<T>,
+) -> Result<T> {
+    // `Instant::now` panics on wasm32-unknown-unknown
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    let start = std::time::Instant::now();
+    let start_offset = buf.bytes_written();
+    let result = f(buf)?;
+    *bytes += buf.bytes_written() - start_offset;
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    {
+        *time += start.elapsed();
+    }
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    let _ = time;
+    Ok(result)
+}
+
 /// Parquet file writer API.
 /// Provides methods to write row groups sequentially.
 ///
@@ -160,6 +208,7 @@ pub struct SerializedFileWriter<W: Write> {
     // kv_metadatas will be appended to `props` when `write_metadata`
     kv_metadatas: Vec<KeyValue>,
     finished: bool,
+    metrics: WriterMetrics,
     #[cfg(feature = "encryption")]
     file_encryptor: Option<Arc<FileEncryptor>>,
 }
@@ -199,6 +248,7 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             row_group_index: 0,
             kv_metadatas: Vec::new(),
             finished: false,
+            metrics: WriterMetrics::default(),
             #[cfg(feature = "encryption")]
             file_encryptor,
         })
@@ -244,25 +294,52 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             .expect("SerializedFileWriter::row_group_index overflowed");
 
         let bloom_filter_position = self.properties().bloom_filter_position();
+        let page_index_position = self.properties().page_index_position();
+        let page_index_writer = self.page_index_writer();
         let row_groups = &mut self.row_groups;
         let row_bloom_filters = &mut self.bloom_filters;
         let row_column_indexes = &mut self.column_indexes;
         let row_offset_indexes = &mut self.offset_indexes;
-        let on_close = move |buf,
+        let metrics = &mut self.metrics;
+        let on_close = move |buf: &mut TrackedWrite<W>,
                              mut metadata,
                              row_group_bloom_filter,
-                             row_group_column_index,
-                             row_group_offset_index| {
+                             mut row_group_column_index: Vec<_>,
+                             mut row_group_offset_index: Vec<_>| {
             row_bloom_filters.push(row_group_bloom_filter);
-            row_column_indexes.push(row_group_column_index);
-            row_offset_indexes.push(row_group_offset_index);
             // write bloom filters out immediately after the row group if requested
             match bloom_filter_position {
-                BloomFilterPosition::AfterRowGroup => {
-                    write_bloom_filters(buf, row_bloom_filters, &mut metadata)?
-                }
+                BloomFilterPosition::AfterRowGroup => measure(
+                    buf,
+                    &mut metrics.bloom_filter_write_time,
+                    &mut metrics.bloom_filter_bytes,
+                    |buf| write_bloom_filters(buf, row_bloom_filters, &mut metadata),
+                )?,
                 BloomFilterPosition::End => (),
             };
+            // followed by the page index if requested
+            match page_index_position {
+                PageIndexPosition::AfterRowGroup => measure(
+                    buf,
+                    &mut metrics.page_index_write_time,
+                    &mut metrics.page_index_bytes,
+                    |buf| {
+                        page_index_writer.write_column_indexes(
+                            buf,
+                            &mut metadata,
+                            &mut row_group_column_index,
+                        )?;
+                        page_index_writer.write_offset_indexes(
+                            buf,
+                            &mut metadata,
+                            &mut row_group_offset_index,
+                        )
+                    },
+                )?,
+                PageIndexPosition::End => (),
+            };
+            row_column_indexes.push(row_group_column_index);
+            row_offset_indexes.push(row_group_offset_index);
             row_groups.push(metadata);
             Ok(())
         };
@@ -285,6 +362,19 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         &self.row_groups
     }
 
+    /// Returns metrics describing the time spent writing bloom filters, page indexes
+    /// and the footer so far
+    pub fn metrics(&self) -> &WriterMetrics {
+        &self.metrics
+    }
+
+    fn page_index_writer(&self) -> PageIndexWriter {
+        let writer = PageIndexWriter::default();
+        #[cfg(feature = "encryption")]
+        let writer = writer.with_file_encryptor(self.file_encryptor.clone());
+        writer
+    }
+
     /// Close and finalize the underlying Parquet writer
     ///
     /// Unlike [`Self::close`] this does not consume self
@@ -323,9 +413,40 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         self.finished = true;
 
         // write out any remaining bloom filters after all row groups
-        for row_group in &mut self.row_groups {
-            write_bloom_filters(&mut self.buf, &mut self.bloom_filters, row_group)?;
-        }
+        let metrics = &mut self.metrics;
+        measure(
+            &mut self.buf,
+            &mut metrics.bloom_filter_write_time,
+            &mut metrics.bloom_filter_bytes,
+            |buf| {
+                for row_group in &mut self.row_groups {
+                    write_bloom_filters(buf, &mut self.bloom_filters, row_group)?;
+                }
+                Ok(())
+            },
+        )?;
+
+        // followed by any remaining column indexes and then offset indexes
+        let page_index_writer = self.page_index_writer();
+        let metrics = &mut self.metrics;
+        measure(
+            &mut self.buf,
+            &mut metrics.page_index_write_time,
+            &mut metrics.page_index_bytes,
+            |buf| {
+                for (row_group, column_indexes) in
+                    self.row_groups.iter_mut().zip(&mut self.column_indexes)
+                {
+                    page_index_writer.write_column_indexes(buf, row_group, column_indexes)?;
+                }
+                for (row_group, offset_indexes) in
+                    self.row_groups.iter_mut().zip(&mut self.offset_indexes)
+                {
+                    page_index_writer.write_offset_indexes(buf, row_group, offset_indexes)?;
+                }
+                Ok(())
+            },
+        )?;
 
         let key_value_metadata = match self.props.key_value_metadata() {
             Some(kv) => Some(kv.iter().chain(&self.kv_metadatas).cloned().collect()),
@@ -339,26 +460,32 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             .map(|v| v.to_thrift())
             .collect::<Vec<_>>();
 
-        let mut encoder = ThriftMetadataWriter::new(
+        let metrics = &mut self.metrics;
+        measure(
             &mut self.buf,
-            &self.schema,
-            &self.descr,
-            row_groups,
-            Some(self.props.created_by().to_string()),
-            self.props.writer_version().as_num(),
-        );
-
-        #[cfg(feature = "encryption")]
-        {
-            encoder = encoder.with_file_encryptor(self.file_encryptor.clone());
-        }
+            &mut metrics.footer_write_time,
+            &mut metrics.footer_bytes,
+            |buf| {
+                let mut encoder = ThriftMetadataWriter::new(
+                    buf,
+                    &self.schema,
+                    &self.descr,
+                    row_groups,
+                    Some(self.props.created_by().to_string()),
+                    self.props.writer_version().as_num(),
+                );
+
+                #[cfg(feature = "encryption")]
+                {
+                    encoder = encoder.with_file_encryptor(self.file_encryptor.clone());
+                }
 
-        if let Some(key_value_metadata) = key_value_metadata {
-            encoder = encoder.with_key_value_metadata(key_value_metadata)
-        }
-        encoder = encoder.with_column_indexes(&self.column_indexes);
-        encoder = encoder.with_offset_indexes(&self.offset_indexes);
-        encoder.finish()
+                if let Some(key_value_metadata) = key_value_metadata {
+                    encoder = encoder.with_key_value_metadata(key_value_metadata)
+                }
+                encoder.finish()
+            },
+        )
     }
 
     #[inline]
@@ -640,7 +767,7 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
     /// closed returns `Err`.
     pub fn next_column(&mut self) -> Result<Option<SerializedColumnWriter<'_>>> {
         self.next_column_with_factory(|descr, props, page_writer, on_close| {
-            let column_writer = get_column_writer(descr, props, page_writer);
+            let column_writer = get_column_writer(descr, props, page_writer)?;
             Ok(SerializedColumnWriter::new(column_writer, Some(on_close)))
         })
     }
@@ -1022,7 +1149,9 @@ mod tests {
     };
     use crate::column::page::{Page, PageReader};
     use crate::column::reader::get_typed_column_reader;
-    use crate::compression::{create_codec, Codec, CodecOptionsBuilder};
+    use crate::compression::{
+        create_codec, Codec, CodecOptions, CodecOptionsBuilder, CompressionCodecFactory,
+    };
     use crate::data_type::{BoolType, ByteArrayType, Int32Type};
     use crate::file::page_index::index::Index;
     use crate::file::properties::EnabledStatistics;
@@ -1037,6 +1166,7 @@ mod tests {
     use crate::schema::parser::parse_message_type;
     use crate::schema::types;
     use crate::schema::types::{ColumnDescriptor, ColumnPath};
+    use crate::util::test_common::codec_util::{XorCodecFactory, XOR_CODEC_ID};
     use crate::util::test_common::rand_gen::RandGen;
 
     #[test]
@@ -1768,6 +1898,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_custom_codec_factory_roundtrip() {
+        custom_codec_factory_roundtrip(Compression::LZO);
+    }
+
+    #[test]
+    fn test_custom_codec_factory_extension_id() {
+        custom_codec_factory_roundtrip(Compression::EXTENSION(XOR_CODEC_ID));
+    }
+
+    #[test]
+    fn test_custom_codec_factory_errors() {
+        #[derive(Debug)]
+        struct ErrorCodecFactory;
+
+        impl CompressionCodecFactory for ErrorCodecFactory {
+            fn create_codec(
+                &self,
+                _codec: Compression,
+                _options: &CodecOptions,
+            ) -> Result<Option<Box<dyn Codec>>> {
+                Err(general_err!("codec is unavailable"))
+            }
+        }
+
+        let next_column = |compression, factory: Arc<dyn CompressionCodecFactory>| {
+            let schema =
+                Arc::new(parse_message_type("message schema { REQUIRED INT32 col1; }").unwrap());
+            let props = WriterProperties::builder()
+                .set_compression(compression)
+                .set_codec_factory(factory)
+                .build();
+            let mut writer = SerializedFileWriter::new(vec![], schema, Arc::new(props)).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            row_group_writer.next_column().err().unwrap().to_string()
+        };
+
+        let err = next_column(Compression::SNAPPY, Arc::new(ErrorCodecFactory));
+        assert_eq!(err, "Parquet error: codec is unavailable");
+
+        // The ids of the codecs defined by the format are not extension ids
+        let err = next_column(Compression::EXTENSION(1), Arc::new(XorCodecFactory));
+        assert_eq!(
+            err,
+            "Parquet error: extension codec id 1 is the id of SNAPPY"
+        );
+        let err = next_column(Compression::EXTENSION(-1), Arc::new(XorCodecFactory));
+        assert_eq!(err, "Parquet error: invalid extension codec id -1");
+    }
+
+    fn custom_codec_factory_roundtrip(compression: Compression) {
+        let schema =
+            Arc::new(parse_message_type("message schema { REQUIRED INT32 col1; }").unwrap());
+        let props = WriterProperties::builder()
+            .set_compression(compression)
+            .set_codec_factory(Arc::new(XorCodecFactory))
+            .build();
+
+        let mut file = Vec::with_capacity(1024);
+        let mut writer = SerializedFileWriter::new(&mut file, schema, Arc::new(props)).unwrap();
+        let mut row_group_writer = writer.next_row_group().unwrap();
+        let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+        col_writer
+            .typed::<Int32Type>()
+            .write_batch(&[1, 2, 3, 4, 5], None, None)
+            .unwrap();
+        col_writer.close().unwrap();
+        row_group_writer.close().unwrap();
+        writer.close().unwrap();
+        let file = Bytes::from(file);
+
+        let reader = SerializedFileReader::new(file.clone()).unwrap();
+        let column = reader.metadata().row_group(0).column(0);
+        assert_eq!(column.compression(), compression);
+
+        // Reading without the factory fails as there is no built-in codec
+        let err = reader
+            .get_row_group(0)
+            .unwrap()
+            .get_row_iter(None)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("not supported"), "{err}");
+
+        let options = ReadOptionsBuilder::new()
+            .with_reader_properties(
+                ReaderProperties::builder()
+                    .set_codec_factory(Arc::new(XorCodecFactory))
+                    .build(),
+            )
+            .build();
+        let reader = SerializedFileReader::new_with_options(file, options).unwrap();
+        let values: Vec<_> = reader
+            .get_row_group(0)
+            .unwrap()
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.unwrap().get_int(0).unwrap())
+            .collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+    }
+
     #[test]
     fn test_column_offset_index_file() {
         let file = tempfile::tempfile().unwrap();
@@ -1927,7 +2159,7 @@ mod tests {
             column_state_slice = tail;
 
             let page_writer = Box::new(SerializedPageWriter::new(buf));
-            let col_writer = get_column_writer(c.clone(), props.clone(), page_writer);
+            let col_writer = get_column_writer(c.clone(), props.clone(), page_writer).unwrap();
             column_writers.push(SerializedColumnWriter::new(
                 col_writer,
                 Some(Box::new(|on_close| {
@@ -178,4 +178,7 @@ pub mod file;
 pub mod record;
 pub mod schema;
 
+#[cfg(feature = "variant_experimental")]
+pub mod variant;
+
 pub mod thrift;
@@ -21,6 +21,7 @@ use std::{collections::HashMap, fmt, sync::Arc};
 
 use crate::file::metadata::HeapSize;
 use crate::format::SchemaElement;
+use crate::thrift::UnknownLogicalType;
 
 use crate::basic::{
     ColumnOrder, ConvertedType, LogicalType, Repetition, SortOrder, TimeUnit, Type as PhysicalType,
@@ -240,6 +241,7 @@ pub struct PrimitiveTypeBuilder<'a> {
     precision: i32,
     scale: i32,
     id: Option<i32>,
+    unknown_logical_type: Option<Vec<u8>>,
 }
 
 impl<'a> PrimitiveTypeBuilder<'a> {
@@ -255,6 +257,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             precision: -1,
             scale: -1,
             id: None,
+            unknown_logical_type: None,
         }
     }
 
@@ -306,6 +309,15 @@ impl<'a> PrimitiveTypeBuilder<'a> {
         Self { id, ..self }
     }
 
+    /// Sets the raw bytes of a logical type annotation not known to this crate, see
+    /// [`BasicTypeInfo::unknown_logical_type`]
+    pub(crate) fn with_unknown_logical_type(self, unknown_logical_type: Option<Vec<u8>>) -> Self {
+        Self {
+            unknown_logical_type,
+            ..self
+        }
+    }
+
     /// Creates a new `PrimitiveType` instance from the collected attributes.
     /// Returns `Err` in case of any building conditions are not met.
     pub fn build(self) -> Result<Type> {
@@ -315,6 +327,7 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             converted_type: self.converted_type,
             logical_type: self.logical_type.clone(),
             id: self.id,
+            unknown_logical_type: self.unknown_logical_type.clone(),
         };
 
         // Check length before logical type, since it is used for logical type validation.
@@ -589,6 +602,7 @@ pub struct GroupTypeBuilder<'a> {
     logical_type: Option<LogicalType>,
     fields: Vec<TypePtr>,
     id: Option<i32>,
+    unknown_logical_type: Option<Vec<u8>>,
 }
 
 impl<'a> GroupTypeBuilder<'a> {
@@ -601,6 +615,7 @@ impl<'a> GroupTypeBuilder<'a> {
             logical_type: None,
             fields: Vec::new(),
             id: None,
+            unknown_logical_type: None,
         }
     }
 
@@ -637,6 +652,15 @@ impl<'a> GroupTypeBuilder<'a> {
         Self { id, ..self }
     }
 
+    /// Sets the raw bytes of a logical type annotation not known to this crate, see
+    /// [`BasicTypeInfo::unknown_logical_type`]
+    pub(crate) fn with_unknown_logical_type(self, unknown_logical_type: Option<Vec<u8>>) -> Self {
+        Self {
+            unknown_logical_type,
+            ..self
+        }
+    }
+
     /// Creates a new `GroupType` instance from the gathered attributes.
     pub fn build(self) -> Result<Type> {
         let mut basic_info = BasicTypeInfo {
@@ -645,6 +669,7 @@ impl<'a> GroupTypeBuilder<'a> {
             converted_type: self.converted_type,
             logical_type: self.logical_type.clone(),
             id: self.id,
+            unknown_logical_type: self.unknown_logical_type,
         };
         // Populate the converted type if only the logical type is populated
         if self.logical_type.is_some() && self.converted_type == ConvertedType::NONE {
@@ -666,12 +691,13 @@ pub struct BasicTypeInfo {
     converted_type: ConvertedType,
     logical_type: Option<LogicalType>,
     id: Option<i32>,
+    unknown_logical_type: Option<Vec<u8>>,
 }
 
 impl HeapSize for BasicTypeInfo {
     fn heap_size(&self) -> usize {
         // no heap allocations in any other subfield
-        self.name.heap_size()
+        self.name.heap_size() + self.unknown_logical_type.as_ref().map_or(0, Vec::capacity)
     }
 }
 
@@ -715,6 +741,16 @@ impl BasicTypeInfo {
         assert!(self.id.is_some());
         self.id.unwrap()
     }
+
+    /// Returns the thrift compact encoding of the logical type annotation of the type, if it
+    /// is not known to this crate and was ignored when reading the file.
+    ///
+    /// See [`ParquetMetaDataReader::with_ignore_unknown_logical_types`].
+    ///
+    /// [`ParquetMetaDataReader::with_ignore_unknown_logical_types`]: crate::file::metadata::ParquetMetaDataReader::with_ignore_unknown_logical_types
+    pub fn unknown_logical_type(&self) -> Option<&[u8]> {
+        self.unknown_logical_type.as_deref()
+    }
 }
 
 // ----------------------------------------------------------------------
@@ -1161,10 +1197,19 @@ fn build_tree<'a>(
 
 /// Method to convert from Thrift.
 pub fn from_thrift(elements: &[SchemaElement]) -> Result<TypePtr> {
+    from_thrift_with_unknown_logical_types(elements, &[])
+}
+
+/// Converts from Thrift, keeping the raw bytes of the `unknown_logical_types` annotating
+/// `elements`, see [`BasicTypeInfo::unknown_logical_type`]
+pub(crate) fn from_thrift_with_unknown_logical_types(
+    elements: &[SchemaElement],
+    unknown_logical_types: &[UnknownLogicalType],
+) -> Result<TypePtr> {
     let mut index = 0;
     let mut schema_nodes = Vec::new();
     while index < elements.len() {
-        let t = from_thrift_helper(elements, index)?;
+        let t = from_thrift_helper(elements, unknown_logical_types, index)?;
         index = t.0;
         schema_nodes.push(t.1);
     }
@@ -1198,7 +1243,11 @@ fn check_logical_type(logical_type: &Option<LogicalType>) -> Result<()> {
 /// The first result is the starting index for the next Type after this one. If it is
 /// equal to `elements.len()`, then this Type is the last one.
 /// The second result is the result Type.
-fn from_thrift_helper(elements: &[SchemaElement], index: usize) -> Result<(usize, TypePtr)> {
+fn from_thrift_helper(
+    elements: &[SchemaElement],
+    unknown_logical_types: &[UnknownLogicalType],
+    index: usize,
+) -> Result<(usize, TypePtr)> {
     // Whether or not the current node is root (message type).
     // There is only one message type node in the schema tree.
     let is_root_node = index == 0;
@@ -1227,6 +1276,10 @@ fn from_thrift_helper(elements: &[SchemaElement], index: usize) -> Result<(usize
         .map(|value| LogicalType::from(value.clone()));
 
     check_logical_type(&logical_type)?;
+    let unknown_logical_type = unknown_logical_types
+        .iter()
+        .find(|unknown| unknown.element == index)
+        .map(|unknown| unknown.bytes.clone());
 
     let field_id = elements[index].field_id;
     match elements[index].num_children {
@@ -1256,13 +1309,15 @@ fn from_thrift_helper(elements: &[SchemaElement], index: usize) -> Result<(usize
                     .with_length(length)
                     .with_precision(precision)
                     .with_scale(scale)
-                    .with_id(field_id);
+                    .with_id(field_id)
+                    .with_unknown_logical_type(unknown_logical_type);
                 Ok((index + 1, Arc::new(builder.build()?)))
             } else {
                 let mut builder = Type::group_type_builder(&elements[index].name)
                     .with_converted_type(converted_type)
                     .with_logical_type(logical_type)
-                    .with_id(field_id);
+                    .with_id(field_id)
+                    .with_unknown_logical_type(unknown_logical_type);
                 if !is_root_node {
                     // Sometimes parquet-cpp and parquet-mr set repetition level REQUIRED or
                     // REPEATED for root node.
@@ -1285,7 +1340,7 @@ fn from_thrift_helper(elements: &[SchemaElement], index: usize) -> Result<(usize
             let mut fields = vec![];
             let mut next_index = index + 1;
             for _ in 0..n {
-                let child_result = from_thrift_helper(elements, next_index)?;
+                let child_result = from_thrift_helper(elements, unknown_logical_types, next_index)?;
                 next_index = child_result.0;
                 fields.push(child_result.1);
             }
@@ -1294,7 +1349,8 @@ fn from_thrift_helper(elements: &[SchemaElement], index: usize) -> Result<(usize
                 .with_converted_type(converted_type)
                 .with_logical_type(logical_type)
                 .with_fields(fields)
-                .with_id(field_id);
+                .with_id(field_id)
+                .with_unknown_logical_type(unknown_logical_type);
             if let Some(rep) = repetition {
                 // Sometimes parquet-cpp and parquet-mr set repetition level REQUIRED or
                 // REPEATED for root node.
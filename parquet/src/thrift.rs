@@ -17,6 +17,7 @@
 
 //! Custom thrift definitions
 
+use crate::format::FileMetaData;
 pub use thrift::protocol::TCompactOutputProtocol;
 use thrift::protocol::{
     TFieldIdentifier, TInputProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier,
@@ -33,6 +34,40 @@ pub trait TSerializable: Sized {
     fn write_to_out_protocol<T: TOutputProtocol>(&self, o_prot: &mut T) -> thrift::Result<()>;
 }
 
+/// A logical type annotation of a schema element that is not known to this crate, see
+/// [`read_file_metadata`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UnknownLogicalType {
+    /// The index of the annotated element in [`FileMetaData::schema`]
+    pub element: usize,
+    /// The thrift compact encoding of the [`LogicalType`] union
+    ///
+    /// [`LogicalType`]: crate::format::LogicalType
+    pub bytes: Vec<u8>,
+}
+
+/// Reads a [`FileMetaData`], skipping the logical type annotations of its schema elements
+/// whose union member is not known to this crate, such as logical types added to the format
+/// after this crate was generated
+///
+/// The skipped annotations are returned in the order of the annotated elements.
+pub(crate) fn read_file_metadata(
+    i_prot: &mut TCompactSliceInputProtocol<'_>,
+) -> thrift::Result<(FileMetaData, Vec<UnknownLogicalType>)> {
+    i_prot.unknown_logical_types = Some(Vec::new());
+    let file_metadata = FileMetaData::read_from_in_protocol(i_prot);
+    let unknown_logical_types = i_prot.unknown_logical_types.take().unwrap_or_default();
+    Ok((file_metadata?, unknown_logical_types))
+}
+
+/// Returns true if `id` is the field id of a member of the [`LogicalType`] union read by
+/// the generated code
+///
+/// [`LogicalType`]: crate::format::LogicalType
+fn is_known_logical_type(id: i16) -> bool {
+    matches!(id, 1..=8 | 10..=18)
+}
+
 /// A more performant implementation of [`TCompactInputProtocol`] that reads a slice
 ///
 /// [`TCompactInputProtocol`]: thrift::protocol::TCompactInputProtocol
@@ -46,6 +81,11 @@ pub(crate) struct TCompactSliceInputProtocol<'a> {
     // Saved because boolean fields and their value are encoded in a single byte,
     // and reading the field only occurs after the field id is read.
     pending_read_bool_value: Option<bool>,
+    // If set, a `FileMetaData` is read, and the unknown logical type annotations of its schema
+    // elements are skipped and collected here.
+    unknown_logical_types: Option<Vec<UnknownLogicalType>>,
+    // Number of schema elements of the `FileMetaData` read so far.
+    schema_elements: usize,
 }
 
 impl<'a> TCompactSliceInputProtocol<'a> {
@@ -55,6 +95,8 @@ impl<'a> TCompactSliceInputProtocol<'a> {
             last_read_field_id: 0,
             read_field_id_stack: Vec::with_capacity(16),
             pending_read_bool_value: None,
+            unknown_logical_types: None,
+            schema_elements: 0,
         }
     }
 
@@ -94,41 +136,31 @@ impl<'a> TCompactSliceInputProtocol<'a> {
 
         Ok((element_type, element_count))
     }
-}
-
-macro_rules! thrift_unimplemented {
-    () => {
-        Err(thrift::Error::Protocol(thrift::ProtocolError {
-            kind: thrift::ProtocolErrorKind::NotImplemented,
-            message: "not implemented".to_string(),
-        }))
-    };
-}
-
-impl TInputProtocol for TCompactSliceInputProtocol<'_> {
-    fn read_message_begin(&mut self) -> thrift::Result<TMessageIdentifier> {
-        unimplemented!()
-    }
 
-    fn read_message_end(&mut self) -> thrift::Result<()> {
-        thrift_unimplemented!()
-    }
-
-    fn read_struct_begin(&mut self) -> thrift::Result<Option<TStructIdentifier>> {
-        self.read_field_id_stack.push(self.last_read_field_id);
-        self.last_read_field_id = 0;
-        Ok(None)
+    // Whether the struct being read is an element of `FileMetaData::schema`.
+    fn in_schema_element(&self) -> bool {
+        // `FileMetaData` was started after no field, and the schema is its field 2
+        self.read_field_id_stack == [0, 2]
     }
 
-    fn read_struct_end(&mut self) -> thrift::Result<()> {
-        self.last_read_field_id = self
-            .read_field_id_stack
-            .pop()
-            .expect("should have previous field ids");
-        Ok(())
+    /// Skips the [`LogicalType`] union starting at the current position if its member is not
+    /// known to this crate, returning its bytes
+    ///
+    /// [`LogicalType`]: crate::format::LogicalType
+    fn skip_unknown_logical_type(&mut self) -> thrift::Result<Option<&'a [u8]>> {
+        let member = TCompactSliceInputProtocol::new(self.buf).read_field_begin()?;
+        match member.id {
+            Some(id) if !is_known_logical_type(id) => {
+                let start = self.buf;
+                self.skip(TType::Struct)?;
+                Ok(Some(&start[..start.len() - self.buf.len()]))
+            }
+            // An empty union, or a known member, is left to the generated code
+            _ => Ok(None),
+        }
     }
 
-    fn read_field_begin(&mut self) -> thrift::Result<TFieldIdentifier> {
+    fn read_field_header(&mut self) -> thrift::Result<TFieldIdentifier> {
         // we can read at least one byte, which is:
         // - the type
         // - the field delta and the type
@@ -183,6 +215,64 @@ impl TInputProtocol for TCompactSliceInputProtocol<'_> {
             }
         }
     }
+}
+
+macro_rules! thrift_unimplemented {
+    () => {
+        Err(thrift::Error::Protocol(thrift::ProtocolError {
+            kind: thrift::ProtocolErrorKind::NotImplemented,
+            message: "not implemented".to_string(),
+        }))
+    };
+}
+
+impl TInputProtocol for TCompactSliceInputProtocol<'_> {
+    fn read_message_begin(&mut self) -> thrift::Result<TMessageIdentifier> {
+        unimplemented!()
+    }
+
+    fn read_message_end(&mut self) -> thrift::Result<()> {
+        thrift_unimplemented!()
+    }
+
+    fn read_struct_begin(&mut self) -> thrift::Result<Option<TStructIdentifier>> {
+        self.read_field_id_stack.push(self.last_read_field_id);
+        self.last_read_field_id = 0;
+        if self.unknown_logical_types.is_some() && self.in_schema_element() {
+            self.schema_elements += 1;
+        }
+        Ok(None)
+    }
+
+    fn read_struct_end(&mut self) -> thrift::Result<()> {
+        self.last_read_field_id = self
+            .read_field_id_stack
+            .pop()
+            .expect("should have previous field ids");
+        Ok(())
+    }
+
+    fn read_field_begin(&mut self) -> thrift::Result<TFieldIdentifier> {
+        let field = self.read_field_header()?;
+        // The `logicalType` of a schema element
+        if field.id == Some(10)
+            && field.field_type == TType::Struct
+            && self.unknown_logical_types.is_some()
+            && self.in_schema_element()
+        {
+            if let Some(bytes) = self.skip_unknown_logical_type()? {
+                let unknown = UnknownLogicalType {
+                    element: self.schema_elements - 1,
+                    bytes: bytes.to_vec(),
+                };
+                if let Some(unknown_logical_types) = self.unknown_logical_types.as_mut() {
+                    unknown_logical_types.push(unknown);
+                }
+                return self.read_field_begin();
+            }
+        }
+        Ok(field)
+    }
 
     fn read_field_end(&mut self) -> thrift::Result<()> {
         Ok(())
@@ -317,8 +407,108 @@ fn eof_error() -> thrift::Error {
 
 #[cfg(test)]
 mod tests {
-    use crate::format::{BoundaryOrder, ColumnIndex};
-    use crate::thrift::{TCompactSliceInputProtocol, TSerializable};
+    use crate::format::{
+        BoundaryOrder, ColumnIndex, FieldRepetitionType, FileMetaData, LogicalType, SchemaElement,
+        Type, UUIDType,
+    };
+    use crate::thrift::{
+        read_file_metadata, TCompactOutputProtocol, TCompactSliceInputProtocol, TSerializable,
+        UnknownLogicalType,
+    };
+
+    /// Returns a `FileMetaData` whose column `a` is annotated with the [`LogicalType`] union
+    /// encoded as `logical_type`, and whose column `b` has the field id 42
+    fn write_file_metadata(logical_type: &[u8]) -> Vec<u8> {
+        let element = |name: &str, type_: Type, field_id, logical_type| {
+            let repetition = FieldRepetitionType::REQUIRED;
+            let name = name.to_string();
+            let (scale, precision) = (None, None);
+            SchemaElement::new(
+                type_,
+                None,
+                repetition,
+                name,
+                None,
+                None,
+                scale,
+                precision,
+                field_id,
+                logical_type,
+            )
+        };
+        let schema = vec![
+            SchemaElement::new(
+                None,
+                None,
+                None,
+                "schema".to_string(),
+                2,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            element(
+                "a",
+                Type::BYTE_ARRAY,
+                None,
+                Some(LogicalType::UUID(UUIDType {})),
+            ),
+            element("b", Type::INT32, Some(42), None),
+        ];
+        let file_metadata = FileMetaData::new(1, schema, 0, vec![], None, None, None, None, None);
+
+        let mut buf = vec![];
+        let mut o_prot = TCompactOutputProtocol::new(&mut buf);
+        file_metadata.write_to_out_protocol(&mut o_prot).unwrap();
+
+        // The UUID member, its empty struct, and the end of the union
+        let uuid = [0xEC, 0x00, 0x00];
+        let position = buf.windows(3).position(|w| w == uuid).unwrap();
+        assert_eq!(buf.windows(3).filter(|w| *w == uuid).count(), 1);
+        buf.splice(position..position + 3, logical_type.iter().copied());
+        buf
+    }
+
+    #[test]
+    fn read_unknown_logical_type() {
+        // A logical type known to this crate
+        let buf = write_file_metadata(&[0xEC, 0x00, 0x00]);
+        let mut protocol = TCompactSliceInputProtocol::new(buf.as_slice());
+        let (file_metadata, unknown) = read_file_metadata(&mut protocol).unwrap();
+        let logical_type = Some(LogicalType::UUID(UUIDType {}));
+        assert_eq!(file_metadata.schema[1].logical_type, logical_type);
+        assert_eq!(file_metadata.schema[2].field_id, Some(42));
+        assert!(unknown.is_empty());
+
+        // The member with field id 100, encoded with a separate field id, is not known
+        let bytes = [0x0C, 0xC8, 0x01, 0x00, 0x00];
+        let buf = write_file_metadata(&bytes);
+        let mut protocol = TCompactSliceInputProtocol::new(buf.as_slice());
+        let (file_metadata, unknown) = read_file_metadata(&mut protocol).unwrap();
+        assert_eq!(file_metadata.schema[1].name, "a");
+        assert_eq!(file_metadata.schema[1].logical_type, None);
+        assert_eq!(file_metadata.schema[2].field_id, Some(42));
+        let expected = UnknownLogicalType {
+            element: 1,
+            bytes: bytes.to_vec(),
+        };
+        assert_eq!(unknown, vec![expected]);
+
+        // Without `read_file_metadata` an unknown logical type is an error
+        let mut protocol = TCompactSliceInputProtocol::new(buf.as_slice());
+        FileMetaData::read_from_in_protocol(&mut protocol).unwrap_err();
+
+        // An empty union is still an error
+        let buf = write_file_metadata(&[0x00]);
+        let mut protocol = TCompactSliceInputProtocol::new(buf.as_slice());
+        let err = read_file_metadata(&mut protocol).unwrap_err();
+        assert!(
+            matches!(&err, thrift::Error::Protocol(e) if e.message.contains("empty union")),
+            "{err:?}"
+        );
+    }
 
     #[test]
     pub fn read_boolean_list_field_type() {
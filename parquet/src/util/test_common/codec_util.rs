@@ -0,0 +1,61 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::basic::Compression;
+use crate::compression::{Codec, CodecOptions, CompressionCodecFactory};
+use crate::errors::Result;
+
+/// A trivial codec that XORs every byte, standing in for LZO which has no built-in codec
+struct XorCodec;
+
+impl Codec for XorCodec {
+    fn compress(&mut self, input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
+        output_buf.extend(input_buf.iter().map(|b| b ^ 0x5A));
+        Ok(())
+    }
+
+    fn decompress(
+        &mut self,
+        input_buf: &[u8],
+        output_buf: &mut Vec<u8>,
+        _uncompress_size: Option<usize>,
+    ) -> Result<usize> {
+        output_buf.extend(input_buf.iter().map(|b| b ^ 0x5A));
+        Ok(input_buf.len())
+    }
+}
+
+/// The codec id of an extension codec provided by [`XorCodecFactory`]
+pub const XOR_CODEC_ID: i32 = 100;
+
+/// A [`CompressionCodecFactory`] providing [`XorCodec`] for [`Compression::LZO`] and
+/// the extension codec id [`XOR_CODEC_ID`]
+#[derive(Debug)]
+pub struct XorCodecFactory;
+
+impl CompressionCodecFactory for XorCodecFactory {
+    fn create_codec(
+        &self,
+        codec: Compression,
+        _options: &CodecOptions,
+    ) -> Result<Option<Box<dyn Codec>>> {
+        Ok(match codec {
+            Compression::LZO | Compression::EXTENSION(XOR_CODEC_ID) => Some(Box::new(XorCodec)),
+            _ => None,
+        })
+    }
+}
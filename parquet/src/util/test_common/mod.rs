@@ -17,6 +17,9 @@
 
 pub mod page_util;
 
+#[cfg(test)]
+pub mod codec_util;
+
 #[cfg(test)]
 pub mod file_util;
 
@@ -0,0 +1,687 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Experimental support for reading and writing [Variant] columns
+//!
+//! A Variant column is represented in Arrow by a [`VariantArray`], and is written by the
+//! [`ArrowWriter`] as a group annotated with the Variant logical type if its field is
+//! annotated with the [`VariantType`] extension type.
+//!
+//! Variant columns can be [shredded] with [`ArrowWriterOptions::with_variant_shredding`]:
+//! the values that match a shredding schema are then written to regular typed columns,
+//! with statistics and dictionary encoding, while the remaining values are written to the
+//! residual `value` column. A shredding schema can be inferred from sample values with
+//! [`infer_shredding_schema`].
+//!
+//! When reading a shredded variant group, the [`ParquetRecordBatchReader`] reassembles the
+//! typed columns and the residual `value` into an unshredded [`VariantArray`], see
+//! [`unshred_variant`]. This requires all the columns of the group to be projected: a
+//! projection of only some of its columns, e.g. without `metadata` or without the `value`
+//! of a shredded field, reads them as a regular struct instead.
+//!
+//! [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`] writes a bloom filter
+//! over the top-level field names of the objects of a variant column for each row group,
+//! with which readers can skip the row groups without a given field, see
+//! [`read_variant_field_bloom_filter`].
+//!
+//! # Example
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, RecordBatch};
+//! # use arrow_schema::{DataType, Field, Schema};
+//! # use parquet::arrow::arrow_writer::{ArrowWriter, ArrowWriterOptions};
+//! # use parquet::variant::VariantArrayBuilder;
+//! let mut builder = VariantArrayBuilder::new(2);
+//! builder.append_json_str(r#"{"id": 1, "tags": ["a"]}"#).unwrap();
+//! builder.append_json_str(r#"{"id": 2}"#).unwrap();
+//! let array = builder.build();
+//! let schema = Arc::new(Schema::new(vec![array.field("v")]));
+//! let column: ArrayRef = Arc::new(array.into_inner());
+//! let batch = RecordBatch::try_new(schema.clone(), vec![column]).unwrap();
+//!
+//! // Shred the `id` field of the objects into an Int64 column
+//! let as_type = DataType::Struct(vec![Field::new("id", DataType::Int64, true)].into());
+//! let options = ArrowWriterOptions::new().with_variant_shredding("v", as_type);
+//! let mut writer = ArrowWriter::try_new_with_options(vec![], schema, options).unwrap();
+//! writer.write(&batch).unwrap();
+//! let metadata = writer.close().unwrap();
+//!
+//! let columns: Vec<_> = metadata.schema[1..]
+//!     .iter()
+//!     .filter(|e| e.num_children.is_none())
+//!     .map(|e| e.name.as_str())
+//!     .collect();
+//! // v.metadata, v.value, v.typed_value.id.value and v.typed_value.id.typed_value
+//! assert_eq!(columns, ["metadata", "value", "value", "typed_value"]);
+//! ```
+//!
+//! [`ParquetRecordBatchReader`]: crate::arrow::arrow_reader::ParquetRecordBatchReader
+//!
+//! [Variant]: https://github.com/apache/parquet-format/blob/master/VariantEncoding.md
+//! [shredded]: https://github.com/apache/parquet-format/blob/master/VariantShredding.md
+//! [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+//! [`ArrowWriterOptions::with_variant_shredding`]: crate::arrow::arrow_writer::ArrowWriterOptions::with_variant_shredding
+//! [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{new_null_array, Array, ArrayRef, RecordBatch, StructArray};
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{DataType, Field, Fields, Schema, SchemaRef};
+
+use crate::bloom_filter::Sbbf;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{FileMetaData, KeyValue};
+use crate::file::properties::{BloomFilterProperties, WriterProperties};
+use crate::file::reader::ChunkReader;
+use crate::file::writer::SerializedFileWriter;
+
+mod predicate;
+
+pub use parquet_variant::{Variant, VariantBuilder, VariantMetadata};
+pub use parquet_variant_compute::{
+    infer_shredding_schema, shred_variant, shredded_variant_type, unshred_variant,
+    ShreddingSchemaOptions, VariantArray, VariantArrayBuilder, VariantType,
+};
+pub use predicate::{VariantComparison, VariantPathPredicate};
+
+/// Returns `schema` with the data type of each variant column in `shredding` replaced by
+/// its [`shredded_variant_type`], along with the index of each shredded column
+pub(crate) fn shred_schema(
+    schema: &SchemaRef,
+    shredding: &[(String, DataType)],
+) -> Result<(SchemaRef, Vec<(usize, DataType)>)> {
+    if shredding.is_empty() {
+        return Ok((schema.clone(), vec![]));
+    }
+    let mut fields: Vec<_> = schema.fields().iter().cloned().collect();
+    let mut shredded = Vec::with_capacity(shredding.len());
+    for (name, as_type) in shredding {
+        let idx = schema.index_of(name)?;
+        let field = &fields[idx];
+        VariantType.supports_data_type(field.data_type())?;
+        if shredded.iter().any(|(i, _)| *i == idx) {
+            return Err(general_err!(
+                "Variant column {} is shredded more than once",
+                name
+            ));
+        }
+        let field = field
+            .as_ref()
+            .clone()
+            .with_data_type(shredded_variant_type(as_type)?)
+            .with_extension_type(VariantType);
+        fields[idx] = Arc::new(field);
+        shredded.push((idx, as_type.clone()));
+    }
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    Ok((Arc::new(schema), shredded))
+}
+
+/// Shreds the variant columns of `batch` at the indices of `shredding` with
+/// [`shred_variant`], returning a batch of the shredded `schema`
+pub(crate) fn shred_batch(
+    batch: &RecordBatch,
+    schema: &SchemaRef,
+    shredding: &[(usize, DataType)],
+) -> Result<RecordBatch> {
+    if shredding.is_empty() {
+        return Ok(batch.clone());
+    }
+    let mut columns = batch.columns().to_vec();
+    for (idx, as_type) in shredding {
+        let array = VariantArray::try_new(columns[*idx].clone())?;
+        let shredded = shred_variant(&array, as_type)?;
+        columns[*idx] = Arc::new(shredded.into_inner()) as ArrayRef;
+    }
+    RecordBatch::try_new(schema.clone(), columns).map_err(|e| {
+        ParquetError::General(format!("Failed to shred variant columns of batch: {e}"))
+    })
+}
+
+/// The prefix of the key-value metadata keys storing the location of the bloom filters
+/// written by [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`]
+///
+/// The filter of the variant column `v` in the row group `i` is written to the file like the
+/// bloom filters of the columns, and its offset and length, including its header, are stored
+/// as `<offset>,<length>` under the key `parquet-rs.variant_field_bloom_filter.v.i`.
+///
+/// [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled
+pub const VARIANT_FIELD_BLOOM_FILTER_META_KEY: &str = "parquet-rs.variant_field_bloom_filter";
+
+fn variant_field_bloom_filter_key(column: &str, row_group: usize) -> String {
+    format!("{VARIANT_FIELD_BLOOM_FILTER_META_KEY}.{column}.{row_group}")
+}
+
+/// Reads the bloom filter over the top-level field names of the variant column `column` in
+/// the row group `row_group` from `reader`, or `None` if the file has no such filter
+///
+/// The filter is written if enabled with
+/// [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`], and can be checked
+/// with a field name, e.g. `sbbf.check(&"id")`.
+///
+/// [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled
+pub fn read_variant_field_bloom_filter<R: ChunkReader>(
+    reader: &R,
+    metadata: &FileMetaData,
+    column: &str,
+    row_group: usize,
+) -> Result<Option<Sbbf>> {
+    let key = variant_field_bloom_filter_key(column, row_group);
+    let value = metadata
+        .key_value_metadata()
+        .and_then(|kv| kv.iter().find(|kv| kv.key == key))
+        .and_then(|kv| kv.value.as_deref());
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let location = value.split_once(',').and_then(|(offset, length)| {
+        let length = length.parse::<i32>().ok().filter(|length| *length >= 0)?;
+        Some((offset.parse::<u64>().ok()?, length))
+    });
+    let Some((offset, length)) = location else {
+        return Err(general_err!(
+            "Invalid variant field bloom filter location {}: {}",
+            key,
+            value
+        ));
+    };
+    Sbbf::read_from_offset(offset, Some(length), reader).map(Some)
+}
+
+/// A [`Sbbf`] over the top-level field names of the objects of a variant column
+///
+/// See [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`]
+///
+/// [`WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_variant_field_bloom_filter_enabled
+#[derive(Debug)]
+pub(crate) struct VariantFieldBloomFilter {
+    /// The index of the variant column in the arrow schema
+    column: usize,
+    properties: BloomFilterProperties,
+    filter: Sbbf,
+}
+
+impl VariantFieldBloomFilter {
+    /// Creates a [`VariantFieldBloomFilter`] for each variant column of `arrow_schema` with
+    /// a field bloom filter enabled in `props`, in the order of the columns
+    pub(crate) fn try_new_all(
+        arrow_schema: &Schema,
+        props: &WriterProperties,
+    ) -> Result<Vec<Self>> {
+        let mut filters = vec![];
+        for (path, properties) in props.variant_field_bloom_filters() {
+            let column = arrow_schema.index_of(&path.string())?;
+            VariantType.supports_data_type(arrow_schema.field(column).data_type())?;
+            filters.push(Self {
+                column,
+                properties: properties.clone(),
+                filter: Sbbf::new_with_ndv_fpp(properties.ndv, properties.fpp)?,
+            });
+        }
+        filters.sort_unstable_by_key(|f| f.column);
+        Ok(filters)
+    }
+
+    /// Inserts the top-level field names of the objects of the unshredded variant column
+    /// in `batch`
+    pub(crate) fn insert(&mut self, batch: &RecordBatch) -> Result<()> {
+        let array = VariantArray::try_new(batch.column(self.column).clone())?;
+        let metadata = array.metadata_field().as_binary_view();
+        let value = array.value_field().as_binary_view();
+        for i in 0..array.len() {
+            if array.is_null(i) || value.is_null(i) {
+                continue;
+            }
+            if let Variant::Object(object) = Variant::try_new(metadata.value(i), value.value(i))? {
+                for name in (0..object.len()).filter_map(|i| object.field_name(i)) {
+                    self.filter.insert(name);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the filter of the row group `row_group` of `schema` to `writer`, along with
+    /// its location in the key-value metadata, resetting the filter for the next row group
+    pub(crate) fn flush<W: Write + Send>(
+        &mut self,
+        writer: &mut SerializedFileWriter<W>,
+        schema: &Schema,
+        row_group: usize,
+    ) -> Result<()> {
+        let filter = Sbbf::new_with_ndv_fpp(self.properties.ndv, self.properties.fpp)?;
+        let filter = std::mem::replace(&mut self.filter, filter);
+        let mut buf = vec![];
+        filter.write(&mut buf)?;
+        let offset = writer.bytes_written();
+        writer.write_all(&buf)?;
+        let key = variant_field_bloom_filter_key(schema.field(self.column).name(), row_group);
+        let location = format!("{offset},{}", buf.len());
+        writer.append_key_value_metadata(KeyValue::new(key, location));
+        Ok(())
+    }
+}
+
+/// Returns true if `data_type` is the struct of a shredded variant group whose `metadata`
+/// and `typed_value` are projected, and which can therefore be reassembled
+///
+/// A group is only reassembled if all its columns are projected, as indicated by
+/// `fully_projected`, as the values of the `value` or `typed_value` columns that are not
+/// projected, at any level of the group, would otherwise be silently dropped.
+pub(crate) fn is_projected_shredded_variant(fully_projected: bool, data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Struct(fields) => {
+            fully_projected
+                && fields.find("metadata").is_some()
+                && fields.find("typed_value").is_some()
+        }
+        _ => false,
+    }
+}
+
+/// Returns the data type of an unshredded [`VariantArray`], as read from a shredded variant
+/// group
+pub(crate) fn unshredded_variant_type() -> DataType {
+    DataType::Struct(Fields::from(vec![
+        Field::new("metadata", DataType::BinaryView, false),
+        Field::new("value", DataType::BinaryView, false),
+    ]))
+}
+
+/// Reassembles the struct read from a shredded variant group into an unshredded
+/// [`VariantArray`] of [`unshredded_variant_type`]
+pub(crate) fn unshred_struct(array: &StructArray) -> Result<ArrayRef> {
+    // The binary columns are read as `Binary` unless the embedded arrow schema says otherwise
+    let as_binary_view = |column: &ArrayRef| match column.data_type() {
+        DataType::BinaryView => Ok(column.clone()),
+        _ => arrow_cast::cast(column, &DataType::BinaryView),
+    };
+    let metadata = array
+        .column_by_name("metadata")
+        .ok_or_else(|| general_err!("Shredded variant is missing its metadata"))?;
+    let value = match array.column_by_name("value") {
+        Some(value) => as_binary_view(value)?,
+        None => new_null_array(&DataType::BinaryView, array.len()),
+    };
+    let typed_value = array
+        .column_by_name("typed_value")
+        .ok_or_else(|| general_err!("Shredded variant is missing its typed_value"))?;
+
+    let fields = Fields::from(vec![
+        Field::new("metadata", DataType::BinaryView, true),
+        Field::new("value", DataType::BinaryView, true),
+        Field::new("typed_value", typed_value.data_type().clone(), true),
+    ]);
+    let columns = vec![as_binary_view(metadata)?, value, typed_value.clone()];
+    let shredded = StructArray::try_new(fields, columns, array.nulls().cloned())?;
+    let unshredded = unshred_variant(&VariantArray::try_new(Arc::new(shredded))?)?;
+    Ok(Arc::new(unshredded.into_inner()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    use crate::arrow::arrow_writer::{ArrowWriter, ArrowWriterOptions};
+    use crate::arrow::ProjectionMask;
+    use crate::basic::LogicalType;
+    use crate::file::properties::WriterProperties;
+    use crate::file::reader::FileReader;
+    use crate::file::serialized_reader::SerializedFileReader;
+    use crate::file::statistics::Statistics;
+    use crate::schema::types::ColumnPath;
+    use arrow_array::types::Int64Type;
+    use arrow_array::Array;
+    use arrow_schema::Field;
+    use bytes::Bytes;
+
+    /// Returns a batch with a variant column `v` of the JSON `values`, with a null row for
+    /// each `None`, and an `id` column of the row numbers
+    pub(super) fn variant_batch(values: &[Option<&str>]) -> RecordBatch {
+        let mut builder = VariantArrayBuilder::new(values.len());
+        for value in values {
+            match value {
+                Some(json) => builder.append_json_str(json).unwrap(),
+                None => builder.append_null(),
+            }
+        }
+        let array = builder.build();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            array.field("v"),
+        ]));
+        let ids = arrow_array::Int32Array::from_iter_values(0..values.len() as i32);
+        let columns: Vec<ArrayRef> = vec![Arc::new(ids), Arc::new(array.into_inner())];
+        RecordBatch::try_new(schema, columns).unwrap()
+    }
+
+    #[test]
+    fn test_write_shredded_variant() {
+        let batch = variant_batch(&[
+            Some(r#"{"a": 1, "b": "x"}"#),
+            Some(r#"{"a": "one"}"#),
+            None,
+            Some(r#"{"a": 3}"#),
+        ]);
+        let as_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let options = ArrowWriterOptions::new().with_variant_shredding("v", as_type.clone());
+        let mut buffer = vec![];
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buffer, batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+
+        // The variant is a group annotated with the variant logical type, whose typed columns
+        // have statistics
+        let metadata = builder.metadata().clone();
+        let schema = metadata.file_metadata().schema_descr();
+        let root = schema.root_schema();
+        let v = &root.get_fields()[1];
+        assert_eq!(
+            v.get_basic_info().logical_type(),
+            Some(LogicalType::Variant)
+        );
+        let paths: Vec<_> = schema.columns().iter().map(|c| c.path().string()).collect();
+        assert_eq!(
+            paths,
+            [
+                "id",
+                "v.metadata",
+                "v.value",
+                "v.typed_value.a.value",
+                "v.typed_value.a.typed_value",
+            ]
+        );
+        let stats = metadata.row_group(0).column(4).statistics().unwrap();
+        let Statistics::Int64(stats) = stats else {
+            panic!("unexpected statistics {stats:?}")
+        };
+        assert_eq!(stats.min_opt(), Some(&1));
+        assert_eq!(stats.max_opt(), Some(&3));
+
+        // The file is read as the unshredded variant, including partially shredded rows
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        let v = read.column(1);
+        assert_eq!(v.data_type(), &unshredded_variant_type());
+        let field = read.schema().field(1).clone();
+        assert!(field.try_extension_type::<VariantType>().is_ok());
+        assert_eq!(v.logical_nulls(), batch.column(1).logical_nulls());
+        assert_eq!(to_json(v), to_json(batch.column(1)));
+    }
+
+    #[test]
+    fn test_variant_field_bloom_filter() {
+        let batch = variant_batch(&[
+            Some(r#"{"a": 1, "b": {"c": 2}}"#),
+            Some(r#"{"a": 2}"#),
+            Some(r#"{"d": "x"}"#),
+            None,
+            Some("[1, 2]"),
+        ]);
+        let as_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(2)
+            .set_variant_field_bloom_filter_fpp(ColumnPath::from("v"), 0.01)
+            .set_variant_field_bloom_filter_ndv(ColumnPath::from("v"), 1000)
+            .build();
+        let options = ArrowWriterOptions::new()
+            .with_properties(props)
+            .with_variant_shredding("v", as_type);
+        let mut buffer = vec![];
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buffer, batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let data = Bytes::from(buffer);
+        let reader = SerializedFileReader::new(data.clone()).unwrap();
+        let row_groups = reader.metadata().num_row_groups();
+        assert_eq!(row_groups, 3);
+        let file_metadata = reader.metadata().file_metadata();
+        let filters: Vec<_> = (0..row_groups)
+            .map(|i| {
+                // The bloom filter slot of the metadata column is left to spec-conforming
+                // filters over its values
+                let column = reader.metadata().row_group(i).column(1);
+                assert_eq!(column.column_path().string(), "v.metadata");
+                assert!(column.bloom_filter_offset().is_none());
+                read_variant_field_bloom_filter(&data, file_metadata, "v", i)
+                    .unwrap()
+                    .unwrap()
+            })
+            .collect();
+        assert!(
+            read_variant_field_bloom_filter(&data, file_metadata, "v", 3)
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            read_variant_field_bloom_filter(&data, file_metadata, "id", 0)
+                .unwrap()
+                .is_none()
+        );
+
+        // The filters are written to the file body, and sized for the configured ndv and fpp
+        let expected = Sbbf::new_with_ndv_fpp(1000, 0.01).unwrap();
+        assert_eq!(
+            filters[0].estimated_memory_size(),
+            expected.estimated_memory_size()
+        );
+        let location = file_metadata
+            .key_value_metadata()
+            .unwrap()
+            .iter()
+            .find(|kv| kv.key == "parquet-rs.variant_field_bloom_filter.v.0")
+            .and_then(|kv| kv.value.clone())
+            .unwrap();
+        let (offset, length) = location.split_once(',').unwrap();
+        let length: usize = length.parse().unwrap();
+        assert!(offset.parse::<usize>().unwrap() + length < data.len());
+        assert!(length > expected.estimated_memory_size());
+
+        // Only the top-level field names of each row group are inserted
+        assert!(filters[0].check(&"a"));
+        assert!(filters[0].check(&"b"));
+        assert!(!filters[0].check(&"d"));
+        assert!(!filters[0].check(&"c"));
+        assert!(filters[1].check(&"d"));
+        assert!(!filters[1].check(&"a"));
+        assert!(!filters[2].check(&"a"));
+        assert!(!filters[2].check(&"d"));
+
+        let props = WriterProperties::builder()
+            .set_variant_field_bloom_filter_enabled(ColumnPath::from("id"), true)
+            .build();
+        let err = ArrowWriter::try_new(vec![], batch.schema(), Some(props)).unwrap_err();
+        assert!(
+            err.to_string().contains("Variant data type mismatch"),
+            "{err}"
+        );
+    }
+
+    /// Returns the rows of the [`VariantArray`] `array` as JSON
+    fn to_json(array: &ArrayRef) -> Vec<Option<String>> {
+        let json = parquet_variant_compute::batch_variant_to_json_string(array).unwrap();
+        json.iter().map(|v| v.map(str::to_string)).collect()
+    }
+
+    /// Writes `batch` with its variant column `v` shredded as `as_type`
+    fn write_shredded(batch: &RecordBatch, as_type: DataType) -> Bytes {
+        let options = ArrowWriterOptions::new().with_variant_shredding("v", as_type);
+        let mut buffer = vec![];
+        let mut writer =
+            ArrowWriter::try_new_with_options(&mut buffer, batch.schema(), options).unwrap();
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    #[test]
+    fn test_read_shredded_variant() {
+        let batch = variant_batch(&[
+            Some(r#"{"a": [1, {"b": 2}], "c": {"d": "x", "e": 1.5}}"#),
+            Some(r#"{"a": "not a list", "c": 3}"#),
+            Some(r#"[{"a": 1}]"#),
+            None,
+            Some(r#"{"c": {"e": null}, "f": [true]}"#),
+            Some("null"),
+        ]);
+        let c = DataType::Struct(vec![Field::new("d", DataType::Utf8, true)].into());
+        let as_type = DataType::Struct(
+            vec![
+                Field::new_list("a", Field::new("element", DataType::Int64, true), true),
+                Field::new("c", c, true),
+            ]
+            .into(),
+        );
+        let data = write_shredded(&batch, as_type);
+
+        // Without the embedded arrow schema, the columns are read as `Binary` and `Utf8`
+        for skip_arrow_metadata in [false, true] {
+            let options = ArrowReaderOptions::new().with_skip_arrow_metadata(skip_arrow_metadata);
+            let reader =
+                ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                    .unwrap()
+                    .with_batch_size(4)
+                    .build()
+                    .unwrap();
+            let batches: Vec<_> = reader.map(|b| b.unwrap()).collect();
+            let read =
+                arrow_select::concat::concat_batches(&batches[0].schema(), &batches).unwrap();
+            let field = read.schema().field(1).clone();
+            assert!(field.try_extension_type::<VariantType>().is_ok());
+            assert_eq!(to_json(read.column(1)), to_json(batch.column(1)));
+        }
+    }
+
+    #[test]
+    fn test_read_shredded_variant_projection() {
+        let batch = variant_batch(&[Some(r#"{"a": 1, "b": "x"}"#), Some(r#"{"a": "one"}"#)]);
+        let as_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let data = write_shredded(&batch, as_type);
+
+        // Projecting only a typed column exposes it directly
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [4]);
+        let read = builder.with_projection(mask).build().unwrap();
+        let read = read.map(|b| b.unwrap()).next().unwrap();
+        let v = read.column(0).as_struct();
+        let a = v.column_by_name("typed_value").unwrap().as_struct();
+        let a = a.column_by_name("a").unwrap().as_struct();
+        let a = a.column_by_name("typed_value").unwrap();
+        let a: Vec<_> = a.as_primitive::<Int64Type>().iter().collect();
+        assert_eq!(a, [Some(1), None]);
+
+        // Projecting the metadata, value and typed columns reassembles the variants
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [1, 2, 3, 4]);
+        let read = builder.with_projection(mask).build().unwrap();
+        let read = read.map(|b| b.unwrap()).next().unwrap();
+        let json = to_json(read.column(0));
+        assert_eq!(
+            json,
+            [
+                Some(r#"{"a":1,"b":"x"}"#.to_string()),
+                Some(r#"{"a":"one"}"#.to_string())
+            ]
+        );
+
+        // Without the residual value, or the value of a shredded field, the values that
+        // were not shredded would be lost, and the group is read as a regular struct
+        let v = read_leaves(&data, [1, 3, 4]);
+        assert!(v.column_by_name("value").is_none());
+        assert!(v.column_by_name("typed_value").is_some());
+
+        let v = read_leaves(&data, [1, 2, 4]);
+        let a = v.column_by_name("typed_value").unwrap().as_struct();
+        let a = a.column_by_name("a").unwrap().as_struct();
+        assert!(a.column_by_name("value").is_none());
+        assert!(a.column_by_name("typed_value").is_some());
+    }
+
+    #[test]
+    fn test_read_shredded_variant_field_projection() {
+        let batch = variant_batch(&[Some(r#"{"a": 1, "b": "x"}"#), Some(r#"{"b": "y"}"#)]);
+        let as_type = DataType::Struct(
+            vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", DataType::Utf8, true),
+            ]
+            .into(),
+        );
+        let data = write_shredded(&batch, as_type);
+
+        // All the columns of the group are projected
+        let v = read_leaves(&data, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            to_json(&(Arc::new(v.clone()) as ArrayRef)),
+            [
+                Some(r#"{"a":1,"b":"x"}"#.to_string()),
+                Some(r#"{"b":"y"}"#.to_string())
+            ]
+        );
+
+        // Without the columns of the shredded field `b`, its values would be lost
+        let v = read_leaves(&data, [1, 2, 3, 4]);
+        let typed_value = v.column_by_name("typed_value").unwrap().as_struct();
+        assert!(typed_value.column_by_name("a").is_some());
+        assert!(typed_value.column_by_name("b").is_none());
+    }
+
+    /// Reads the variant column `v` of `data` projected to the `leaves`
+    fn read_leaves(data: &Bytes, leaves: impl IntoIterator<Item = usize>) -> StructArray {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), leaves);
+        let read = builder.with_projection(mask).build().unwrap();
+        let read = read.map(|b| b.unwrap()).next().unwrap();
+        read.column(0).as_struct().clone()
+    }
+
+    #[test]
+    fn test_variant_shredding_invalid_column() {
+        let batch = variant_batch(&[Some("1")]);
+        let as_type = DataType::Int64;
+
+        let options = ArrowWriterOptions::new().with_variant_shredding("id", as_type.clone());
+        let err = ArrowWriter::try_new_with_options(vec![], batch.schema(), options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "External: Invalid argument error: Variant data type mismatch, expected Struct, found Int32"
+        );
+
+        let options = ArrowWriterOptions::new().with_variant_shredding("x", as_type.clone());
+        let err = ArrowWriter::try_new_with_options(vec![], batch.schema(), options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "External: Schema error: Unable to get field named \"x\". Valid fields: [\"id\", \"v\"]"
+        );
+
+        let options = ArrowWriterOptions::new()
+            .with_variant_shredding("v", as_type.clone())
+            .with_variant_shredding("v", as_type);
+        let err = ArrowWriter::try_new_with_options(vec![], batch.schema(), options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Variant column v is shredded more than once"
+        );
+    }
+}
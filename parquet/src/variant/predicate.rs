@@ -0,0 +1,716 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Pushdown of predicates on the paths of variant columns, see [`VariantPathPredicate`]
+
+use std::borrow::Cow;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray, Datum, RecordBatch, Scalar};
+use arrow_ord::cmp;
+use arrow_schema::{ArrowError, Field};
+use parquet_variant::path::{VariantPath, VariantPathElement};
+use parquet_variant_compute::variant_get::{variant_get, GetOptions};
+
+use super::VariantArray;
+use crate::arrow::arrow_reader::pruning::page_row_ranges;
+use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+use crate::arrow::arrow_reader::{ArrowPredicate, RowSelection};
+use crate::arrow::schema::parquet_to_arrow_field;
+use crate::arrow::ProjectionMask;
+use crate::basic::{ConvertedType, LogicalType};
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+use crate::schema::types::{SchemaDescriptor, Type};
+
+/// The comparison operator of a [`VariantPathPredicate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariantComparison {
+    /// `=`
+    Eq,
+    /// `!=`
+    NotEq,
+    /// `<`
+    Lt,
+    /// `<=`
+    LtEq,
+    /// `>`
+    Gt,
+    /// `>=`
+    GtEq,
+}
+
+impl VariantComparison {
+    fn compare(self, l: &dyn Datum, r: &dyn Datum) -> Result<BooleanArray, ArrowError> {
+        match self {
+            Self::Eq => cmp::eq(l, r),
+            Self::NotEq => cmp::neq(l, r),
+            Self::Lt => cmp::lt(l, r),
+            Self::LtEq => cmp::lt_eq(l, r),
+            Self::Gt => cmp::gt(l, r),
+            Self::GtEq => cmp::gt_eq(l, r),
+        }
+    }
+}
+
+/// A comparison of the value at a path of a variant column with a literal, such as
+/// `$.status = 'error'`
+///
+/// A row matches if the value at the path exists, can be converted to the type of the
+/// literal, and satisfies the comparison, as with [`variant_get`] and safe casting.
+///
+/// If the path is [shredded] into a typed column, the predicate is evaluated against the
+/// statistics of this column to skip row groups, with [`Self::prune_row_groups`], and data
+/// pages, with [`Self::prune_pages`]. Row groups and pages are only skipped if the residual
+/// `value` column at the path is null, i.e. if every value at the path was shredded.
+///
+/// The predicate can then be evaluated on the remaining rows as a [`RowFilter`], with
+/// [`Self::arrow_predicate`], which only reads the columns of the shredded path.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, RecordBatch, StringArray};
+/// # use arrow_schema::{DataType, Field, Schema};
+/// # use bytes::Bytes;
+/// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, RowFilter};
+/// # use parquet::arrow::arrow_writer::{ArrowWriter, ArrowWriterOptions};
+/// # use parquet::file::properties::WriterProperties;
+/// # use parquet::variant::{VariantArrayBuilder, VariantComparison, VariantPathPredicate};
+/// // Write a variant column, shredding the `status` field of objects, in row groups of 2 rows
+/// let mut builder = VariantArrayBuilder::new(4);
+/// builder.append_json_str(r#"{"status": "ok"}"#).unwrap();
+/// builder.append_json_str(r#"{"status": "ok", "id": 2}"#).unwrap();
+/// builder.append_json_str(r#"{"status": "error"}"#).unwrap();
+/// builder.append_json_str(r#"{"status": "ok"}"#).unwrap();
+/// let array = builder.build();
+/// let schema = Arc::new(Schema::new(vec![array.field("v")]));
+/// let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array.into_inner())]).unwrap();
+/// let as_type = DataType::Struct(vec![Field::new("status", DataType::Utf8, true)].into());
+/// let props = WriterProperties::builder().set_max_row_group_size(2).build();
+/// let options = ArrowWriterOptions::new()
+///     .with_properties(props)
+///     .with_variant_shredding("v", as_type);
+/// let mut buffer = vec![];
+/// let mut writer = ArrowWriter::try_new_with_options(&mut buffer, schema, options).unwrap();
+/// writer.write(&batch).unwrap();
+/// writer.close().unwrap();
+///
+/// // Filter the rows where `$.status = 'error'`
+/// let literal: ArrayRef = Arc::new(StringArray::from(vec!["error"]));
+/// let predicate =
+///     VariantPathPredicate::try_new("v", "$.status", VariantComparison::Eq, literal).unwrap();
+/// let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).unwrap();
+///
+/// // Only the second row group may contain matching rows
+/// let row_groups = predicate.prune_row_groups(builder.metadata()).unwrap();
+/// assert_eq!(row_groups, vec![1]);
+///
+/// let filter = predicate.arrow_predicate(builder.parquet_schema()).unwrap();
+/// let reader = builder
+///     .with_row_groups(row_groups)
+///     .with_row_filter(RowFilter::new(vec![filter]))
+///     .build()
+///     .unwrap();
+/// let rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+/// assert_eq!(rows, 1);
+/// ```
+///
+/// [shredded]: crate::arrow::arrow_writer::ArrowWriterOptions::with_variant_shredding
+/// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+#[derive(Debug, Clone)]
+pub struct VariantPathPredicate {
+    column: String,
+    path: VariantPath<'static>,
+    op: VariantComparison,
+    literal: ArrayRef,
+}
+
+/// The leaf columns of a shredded path
+struct ShreddedPath {
+    /// The leaf of the `typed_value` at the path
+    typed_value: usize,
+    /// The leaf of the residual `value` at the path, if any
+    value: Option<usize>,
+    /// Whether the path is within a list, in which case the leaves are repeated
+    repeated: bool,
+    /// The column path of the group of the shredded path
+    prefix: Vec<String>,
+}
+
+impl VariantPathPredicate {
+    /// Create a predicate comparing the value at `path` of the top-level variant column
+    /// `column` with `literal`, an array of a single non-null value
+    ///
+    /// See [`VariantPath::try_parse`] for the syntax of `path`.
+    pub fn try_new(
+        column: impl Into<String>,
+        path: &str,
+        op: VariantComparison,
+        literal: ArrayRef,
+    ) -> Result<Self> {
+        if literal.len() != 1 || literal.is_null(0) {
+            return Err(general_err!(
+                "The literal of a variant path predicate must be a single non-null value, got {:?}",
+                literal
+            ));
+        }
+        let path = VariantPath::try_parse(path)?
+            .iter()
+            .map(|element| match element {
+                VariantPathElement::Field { name } => {
+                    VariantPathElement::field(Cow::Owned(name.to_string()))
+                }
+                VariantPathElement::Index { index } => VariantPathElement::index(*index),
+            })
+            .collect::<Vec<_>>()
+            .into();
+        Ok(Self {
+            column: column.into(),
+            path,
+            op,
+            literal,
+        })
+    }
+
+    /// Returns the indices of the row groups of the file with `metadata` that may contain
+    /// matching rows, based on the statistics of the shredded path
+    ///
+    /// Returns every row group if the path is not shredded.
+    pub fn prune_row_groups(&self, metadata: &ParquetMetaData) -> Result<Vec<usize>> {
+        let schema = metadata.file_metadata().schema_descr();
+        let row_groups = metadata.row_groups();
+        let Some(shredded) = self.shredded_path(schema) else {
+            return Ok((0..row_groups.len()).collect());
+        };
+
+        let field = parquet_to_arrow_field(&schema.column(shredded.typed_value))?;
+        let converter =
+            StatisticsConverter::for_parquet_column(shredded.typed_value, &field, schema);
+        let mins = converter.row_group_mins(row_groups)?;
+        let maxes = converter.row_group_maxes(row_groups)?;
+        let may_match = self.may_match(&mins, &maxes)?;
+
+        let keep = row_groups.iter().enumerate().filter(|(i, row_group)| {
+            let typed_value = row_group.column(shredded.typed_value);
+            let all_null = typed_value
+                .statistics()
+                .and_then(|s| s.null_count_opt())
+                .is_some_and(|nulls| nulls as i64 == typed_value.num_values());
+            let typed_may_match = !all_null && (may_match.is_null(*i) || may_match.value(*i));
+            let has_residual = shredded.value.is_some_and(|value| {
+                let value = row_group.column(value);
+                let nulls = value.statistics().and_then(|s| s.null_count_opt());
+                nulls.map(|nulls| nulls as i64) != Some(value.num_values())
+            });
+            typed_may_match || has_residual
+        });
+        Ok(keep.map(|(i, _)| i).collect())
+    }
+
+    /// Returns a [`RowSelection`] of the rows of `row_groups` that may match, based on the
+    /// page index of the shredded path
+    ///
+    /// Returns `None` if the path is not shredded, if it is within a list, or if the page
+    /// index was not loaded, see [`ArrowReaderOptions::with_page_index`].
+    ///
+    /// [`ArrowReaderOptions::with_page_index`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_page_index
+    pub fn prune_pages(
+        &self,
+        metadata: &ParquetMetaData,
+        row_groups: &[usize],
+    ) -> Result<Option<RowSelection>> {
+        let schema = metadata.file_metadata().schema_descr();
+        let (Some(column_index), Some(offset_index)) =
+            (metadata.column_index(), metadata.offset_index())
+        else {
+            return Ok(None);
+        };
+        let Some(shredded) = self.shredded_path(schema).filter(|s| !s.repeated) else {
+            return Ok(None);
+        };
+
+        let field = parquet_to_arrow_field(&schema.column(shredded.typed_value))?;
+        let converter =
+            StatisticsConverter::for_parquet_column(shredded.typed_value, &field, schema);
+        let mins = converter.data_page_mins(column_index, offset_index, row_groups)?;
+        let maxes = converter.data_page_maxes(column_index, offset_index, row_groups)?;
+        let nulls = converter.data_page_null_counts(column_index, offset_index, row_groups)?;
+        let may_match = self.may_match(&mins, &maxes)?;
+        let typed_may_match = (0..may_match.len()).map(|i| {
+            // The statistics of a page whose values are all null are null
+            let all_null = !nulls.is_null(i) && mins.is_null(i) && maxes.is_null(i);
+            !all_null && (may_match.is_null(i) || may_match.value(i))
+        });
+
+        let residual = shredded.value.map(|value| {
+            let field = Field::new("value", arrow_schema::DataType::Binary, true);
+            let converter = StatisticsConverter::for_parquet_column(value, &field, schema);
+            converter
+                .with_missing_null_counts_as_zero(false)
+                .data_page_null_counts(column_index, offset_index, row_groups)
+                .map(|nulls| (value, nulls))
+        });
+        let residual = residual.transpose()?;
+
+        // The row ranges of the pages of a column, relative to the selected row groups
+        let typed_ranges =
+            page_row_ranges(metadata, offset_index, row_groups, shredded.typed_value);
+        let residual_ranges = match &residual {
+            Some((value, _)) => page_row_ranges(metadata, offset_index, row_groups, *value),
+            None => vec![],
+        };
+        let num_rows = row_groups
+            .iter()
+            .map(|i| metadata.row_group(*i).num_rows() as usize)
+            .sum();
+
+        let mut keep: Vec<Range<usize>> = typed_ranges
+            .into_iter()
+            .zip(typed_may_match)
+            .filter_map(|(range, keep)| keep.then_some(range))
+            .collect();
+        if let Some((_, nulls)) = &residual {
+            let residual_keep = residual_ranges
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, r)| {
+                    let all_null = !nulls.is_null(i) && nulls.value(i) as usize == r.len();
+                    (!all_null).then_some(r)
+                });
+            keep.extend(residual_keep);
+        }
+        Ok(Some(RowSelection::from_consecutive_ranges(
+            merge_ranges(keep).into_iter(),
+            num_rows,
+        )))
+    }
+
+    /// Returns an [`ArrowPredicate`] evaluating this predicate on the rows of a file with
+    /// the parquet `schema`
+    ///
+    /// If the path is shredded, the predicate only reads the `metadata` and the columns of
+    /// the shredded path, otherwise it reads the entire variant column.
+    pub fn arrow_predicate(&self, schema: &SchemaDescriptor) -> Result<Box<dyn ArrowPredicate>> {
+        let (root, _) = self.variant_root(schema)?;
+        let prefixes = match self.shredded_path(schema).filter(|s| !s.repeated) {
+            // A shredded path within a list is only reassembled from the entire list
+            Some(shredded) => vec![
+                vec![root.to_string(), "metadata".to_string()],
+                shredded.prefix,
+            ],
+            None => vec![vec![root.to_string()]],
+        };
+        let leaves = schema.columns().iter().enumerate().filter(|(_, column)| {
+            let parts = column.path().parts();
+            prefixes.iter().any(|prefix| parts.starts_with(prefix))
+        });
+        let projection = ProjectionMask::leaves(schema, leaves.map(|(i, _)| i));
+        Ok(Box::new(VariantPathArrowPredicate {
+            projection,
+            predicate: self.clone(),
+        }))
+    }
+
+    /// Returns whether a column with the given `mins` and `maxes` may contain matching
+    /// values, or null if unknown
+    fn may_match(&self, mins: &ArrayRef, maxes: &ArrayRef) -> Result<BooleanArray> {
+        let unknown = || Ok(BooleanArray::new_null(mins.len()));
+        let (literal_type, stats_type) = (self.literal.data_type(), mins.data_type());
+        let literal = if literal_type == stats_type {
+            self.literal.clone()
+        } else {
+            // Ordering is only preserved by casts between numeric types
+            let ordered = literal_type.is_numeric() && stats_type.is_numeric();
+            if !ordered && !matches!(self.op, VariantComparison::Eq | VariantComparison::NotEq) {
+                return unknown();
+            }
+            let literal = arrow_cast::cast(&self.literal, stats_type)?;
+            if literal.is_null(0) {
+                // The literal can not be compared with the values of the column
+                return unknown();
+            }
+            // A lossy cast, e.g. of 5.5 to an integer, can not be used for pruning
+            let back = arrow_cast::cast(&literal, literal_type)?;
+            if back.to_data() != self.literal.to_data() {
+                return unknown();
+            }
+            literal
+        };
+        let literal = Scalar::new(literal);
+        let compare = |op: VariantComparison, values: &ArrayRef| op.compare(values, &literal);
+        Ok(match self.op {
+            VariantComparison::Eq => and(
+                &compare(VariantComparison::LtEq, mins)?,
+                &compare(VariantComparison::GtEq, maxes)?,
+            ),
+            VariantComparison::NotEq => {
+                let all_equal = and(
+                    &compare(VariantComparison::Eq, mins)?,
+                    &compare(VariantComparison::Eq, maxes)?,
+                );
+                all_equal.iter().map(|v| v.map(|v| !v)).collect()
+            }
+            VariantComparison::Lt | VariantComparison::LtEq => compare(self.op, mins)?,
+            VariantComparison::Gt | VariantComparison::GtEq => compare(self.op, maxes)?,
+        })
+    }
+
+    /// Returns the name and type of the top-level variant column in `schema`
+    fn variant_root<'s>(&self, schema: &'s SchemaDescriptor) -> Result<(&'s str, &'s Type)> {
+        let root = schema
+            .root_schema()
+            .get_fields()
+            .iter()
+            .find(|f| f.name() == self.column);
+        match root {
+            Some(root) if root.get_basic_info().logical_type() == Some(LogicalType::Variant) => {
+                Ok((root.name(), root))
+            }
+            Some(_) => Err(general_err!("Column {} is not a variant", self.column)),
+            None => Err(general_err!("Variant column {} not found", self.column)),
+        }
+    }
+
+    /// Returns the leaf columns of the path if it is shredded into a primitive column
+    fn shredded_path(&self, schema: &SchemaDescriptor) -> Option<ShreddedPath> {
+        let (name, mut node) = self.variant_root(schema).ok()?;
+        let mut prefix = vec![name.to_string()];
+        let mut repeated = false;
+        fn child<'t>(node: &'t Type, name: &str) -> Option<&'t Type> {
+            let fields = node.get_fields();
+            fields.iter().find(|f| f.name() == name).map(|f| f.as_ref())
+        }
+
+        for element in self.path.iter() {
+            let typed_value = child(node, "typed_value").filter(|t| t.is_group())?;
+            let is_list = typed_value.get_basic_info().logical_type() == Some(LogicalType::List)
+                || typed_value.get_basic_info().converted_type() == ConvertedType::LIST;
+            prefix.push("typed_value".to_string());
+            match element {
+                VariantPathElement::Field { name } if !is_list => {
+                    node = child(typed_value, name)?;
+                    prefix.push(name.to_string());
+                }
+                VariantPathElement::Index { .. } if is_list => {
+                    let list = typed_value.get_fields().first()?;
+                    let element = list.get_fields().first()?;
+                    prefix.push(list.name().to_string());
+                    prefix.push(element.name().to_string());
+                    node = element;
+                    repeated = true;
+                }
+                _ => return None,
+            }
+        }
+
+        child(node, "typed_value").filter(|t| t.is_primitive())?;
+        let leaf = |name: &str| {
+            schema.columns().iter().position(|column| {
+                let parts = column.path().parts();
+                parts.len() == prefix.len() + 1
+                    && parts.starts_with(&prefix)
+                    && parts[prefix.len()] == name
+            })
+        };
+        Some(ShreddedPath {
+            typed_value: leaf("typed_value")?,
+            value: leaf("value"),
+            repeated,
+            prefix,
+        })
+    }
+}
+
+/// Kleene logical and of `l` and `r`
+fn and(l: &BooleanArray, r: &BooleanArray) -> BooleanArray {
+    l.iter()
+        .zip(r.iter())
+        .map(|(l, r)| match (l, r) {
+            (Some(false), _) | (_, Some(false)) => Some(false),
+            (Some(true), Some(true)) => Some(true),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Sorts and merges overlapping or adjacent `ranges`
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_unstable_by_key(|r| r.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// The [`ArrowPredicate`] of a [`VariantPathPredicate`]
+struct VariantPathArrowPredicate {
+    projection: ProjectionMask,
+    predicate: VariantPathPredicate,
+}
+
+impl ArrowPredicate for VariantPathArrowPredicate {
+    fn projection(&self) -> &ProjectionMask {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> Result<BooleanArray, ArrowError> {
+        // Unless all the columns of the group are projected, the shredded group is read as
+        // a regular struct, whose projected columns suffice to evaluate the path
+        let column = batch.column(0);
+        let column = match column.as_struct_opt() {
+            Some(s) if s.column_by_name("typed_value").is_some() => super::unshred_struct(s)?,
+            _ => column.clone(),
+        };
+        let variant: ArrayRef = Arc::new(VariantArray::try_new(column)?);
+        let literal = &self.predicate.literal;
+        let as_type = Field::new("value", literal.data_type().clone(), true);
+        let options =
+            GetOptions::new_with_path(self.predicate.path.clone()).with_as_type(Some(as_type));
+        let values = variant_get(&variant, options)?;
+        self.predicate
+            .op
+            .compare(&values, &Scalar::new(literal.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::{
+        ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowFilter,
+    };
+    use crate::arrow::arrow_writer::{ArrowWriter, ArrowWriterOptions};
+    use crate::file::properties::{EnabledStatistics, WriterProperties};
+    use crate::variant::tests::variant_batch;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Float64Array, Int64Array, StringArray};
+    use arrow_schema::DataType;
+    use bytes::Bytes;
+
+    /// Writes the JSON `values` as a variant column `v` shredded as `as_type`, with an `id`
+    /// column of the row numbers, in row groups and pages of `rows_per_page` rows
+    fn write(values: &[&str], as_type: Option<DataType>, rows_per_page: usize) -> Bytes {
+        let values: Vec<_> = values.iter().copied().map(Some).collect();
+        let batch = variant_batch(&values);
+        let schema = batch.schema();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(rows_per_page * 2)
+            .set_data_page_row_count_limit(rows_per_page)
+            .set_write_batch_size(rows_per_page)
+            .set_statistics_enabled(EnabledStatistics::Page)
+            .build();
+        let mut options = ArrowWriterOptions::new().with_properties(props);
+        if let Some(as_type) = as_type {
+            options = options.with_variant_shredding("v", as_type);
+        }
+        let mut buffer = vec![];
+        let mut writer = ArrowWriter::try_new_with_options(&mut buffer, schema, options).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    fn status_type() -> DataType {
+        DataType::Struct(vec![Field::new("status", DataType::Utf8, true)].into())
+    }
+
+    fn predicate(path: &str, op: VariantComparison, literal: ArrayRef) -> VariantPathPredicate {
+        VariantPathPredicate::try_new("v", path, op, literal).unwrap()
+    }
+
+    /// Returns the ids of the rows of `data` matching `predicate`, after pruning
+    fn filter(data: Bytes, predicate: &VariantPathPredicate) -> Vec<i32> {
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        let metadata = builder.metadata().clone();
+        let row_groups = predicate.prune_row_groups(&metadata).unwrap();
+        let selection = predicate.prune_pages(&metadata, &row_groups).unwrap();
+        let filter = predicate.arrow_predicate(builder.parquet_schema()).unwrap();
+        let mut builder = builder
+            .with_row_groups(row_groups)
+            .with_row_filter(RowFilter::new(vec![filter]));
+        if let Some(selection) = selection {
+            builder = builder.with_row_selection(selection);
+        }
+        let reader = builder.build().unwrap();
+        reader
+            .flat_map(|b| {
+                let b = b.unwrap();
+                let ids = b.column(0).as_primitive::<Int32Type>().clone();
+                ids.values().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_prune_shredded_path() {
+        let values = [
+            r#"{"status": "ok"}"#,
+            r#"{"status": "ok"}"#,
+            r#"{"status": "ok"}"#,
+            r#"{"status": "warn"}"#,
+            r#"{"status": "error", "code": 1}"#,
+            r#"{"status": "ok"}"#,
+            r#"{"status": "ok"}"#,
+            r#"{"other": 1}"#,
+        ];
+        let data = write(&values, Some(status_type()), 2);
+        let literal: ArrayRef = Arc::new(StringArray::from(vec!["error"]));
+        let eq = predicate("$.status", VariantComparison::Eq, literal.clone());
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            data.clone(),
+            ArrowReaderOptions::new().with_page_index(true),
+        )
+        .unwrap();
+        let metadata = builder.metadata();
+        assert_eq!(metadata.num_row_groups(), 2);
+        assert_eq!(eq.prune_row_groups(metadata).unwrap(), vec![1]);
+        // Only the first page of the row group may contain "error"
+        let selection = eq.prune_pages(metadata, &[1]).unwrap().unwrap();
+        assert_eq!(selection.row_count(), 2);
+        assert_eq!(filter(data.clone(), &eq), vec![4]);
+
+        let gt = predicate("$.status", VariantComparison::Gt, literal.clone());
+        assert_eq!(gt.prune_row_groups(metadata).unwrap(), vec![0, 1]);
+        assert_eq!(filter(data.clone(), &gt), vec![0, 1, 2, 3, 5, 6]);
+
+        let ne = predicate("status", VariantComparison::NotEq, literal);
+        assert_eq!(filter(data, &ne), vec![0, 1, 2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn test_shredded_path_of_multiple_fields() {
+        // Only the columns of `status` are read, not those of `code` or the residual value
+        let values = [
+            r#"{"status": "ok", "code": 1}"#,
+            r#"{"status": "error", "code": 2}"#,
+            r#"{"status": "ok", "other": 3}"#,
+        ];
+        let as_type = DataType::Struct(
+            vec![
+                Field::new("status", DataType::Utf8, true),
+                Field::new("code", DataType::Int64, true),
+            ]
+            .into(),
+        );
+        let data = write(&values, Some(as_type), 1);
+        let literal: ArrayRef = Arc::new(StringArray::from(vec!["ok"]));
+        let eq = predicate("status", VariantComparison::Eq, literal);
+        assert_eq!(filter(data, &eq), vec![0, 2]);
+    }
+
+    #[test]
+    fn test_prune_partially_shredded_path() {
+        // The statuses that are not strings are stored in the residual value, whose row group
+        // can not be pruned
+        let values = [
+            r#"{"status": 1}"#,
+            r#"{"status": "ok"}"#,
+            r#"{"status": "ok"}"#,
+            r#"{"status": "ok"}"#,
+        ];
+        let data = write(&values, Some(status_type()), 1);
+        let literal: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        let eq = predicate("$.status", VariantComparison::Eq, literal);
+
+        let metadata = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .metadata()
+            .clone();
+        assert_eq!(eq.prune_row_groups(&metadata).unwrap(), vec![0]);
+        assert_eq!(filter(data, &eq), vec![0]);
+    }
+
+    #[test]
+    fn test_lossy_literal() {
+        let values = [r#"{"a": 5}"#, r#"{"a": 5}"#, r#"{"a": 6}"#, r#"{"a": 6}"#];
+        let as_type = DataType::Struct(vec![Field::new("a", DataType::Int64, true)].into());
+        let data = write(&values, Some(as_type), 1);
+        let metadata = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .metadata()
+            .clone();
+
+        // 5.5 must not be pruned as if it were 5
+        let literal: ArrayRef = Arc::new(Float64Array::from(vec![5.5]));
+        let gt = predicate("$.a", VariantComparison::Gt, literal.clone());
+        assert_eq!(gt.prune_row_groups(&metadata).unwrap(), vec![0, 1]);
+        assert_eq!(filter(data.clone(), &gt), vec![2, 3]);
+        let lt = predicate("$.a", VariantComparison::Lt, literal);
+        assert_eq!(lt.prune_row_groups(&metadata).unwrap(), vec![0, 1]);
+        assert_eq!(filter(data.clone(), &lt), vec![0, 1]);
+
+        // A literal converting losslessly is still pruned
+        let literal: ArrayRef = Arc::new(Float64Array::from(vec![6.0]));
+        let eq = predicate("$.a", VariantComparison::Eq, literal);
+        assert_eq!(eq.prune_row_groups(&metadata).unwrap(), vec![1]);
+        assert_eq!(filter(data, &eq), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_unshredded_path() {
+        let values = [r#"{"a": [1, 2]}"#, r#"{"a": [3]}"#, r#"{"b": 1}"#];
+        let literal: ArrayRef = Arc::new(Int64Array::from(vec![3]));
+        let eq = predicate("$.a[0]", VariantComparison::Eq, literal);
+
+        // Without shredding, every row group is read
+        let data = write(&values, None, 1);
+        let metadata = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .metadata()
+            .clone();
+        assert_eq!(eq.prune_row_groups(&metadata).unwrap(), vec![0, 1]);
+        assert!(eq.prune_pages(&metadata, &[0, 1]).unwrap().is_none());
+        assert_eq!(filter(data, &eq), vec![1]);
+
+        // A shredded list is pruned by row group, but not by page
+        let element = Field::new("element", DataType::Int64, true);
+        let as_type = DataType::Struct(vec![Field::new_list("a", element, true)].into());
+        let data = write(&values, Some(as_type), 1);
+        let metadata = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .metadata()
+            .clone();
+        assert_eq!(eq.prune_row_groups(&metadata).unwrap(), vec![0]);
+        assert!(eq.prune_pages(&metadata, &[0]).unwrap().is_none());
+        assert_eq!(filter(data, &eq), vec![1]);
+    }
+
+    #[test]
+    fn test_invalid_predicate() {
+        let literal: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+        let err =
+            VariantPathPredicate::try_new("v", "$.a", VariantComparison::Eq, literal).unwrap_err();
+        assert!(err.to_string().contains("must be a single non-null value"));
+
+        let data = write(&["1"], None, 1);
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data).unwrap();
+        let literal: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        let id = VariantPathPredicate::try_new("id", "$", VariantComparison::Eq, literal).unwrap();
+        let err = id.arrow_predicate(builder.parquet_schema()).err().unwrap();
+        assert_eq!(err.to_string(), "Parquet error: Column id is not a variant");
+    }
+}
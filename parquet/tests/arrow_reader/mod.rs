@@ -18,9 +18,10 @@
 use arrow_array::types::{Int32Type, Int8Type};
 use arrow_array::{
     Array, ArrayRef, BinaryArray, BinaryViewArray, BooleanArray, Date32Array, Date64Array,
-    Decimal128Array, Decimal256Array, DictionaryArray, FixedSizeBinaryArray, Float16Array,
-    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, LargeBinaryArray,
-    LargeStringArray, RecordBatch, StringArray, StringViewArray, StructArray,
+    Decimal128Array, Decimal256Array, DictionaryArray, DurationMicrosecondArray,
+    DurationMillisecondArray, DurationNanosecondArray, DurationSecondArray, FixedSizeBinaryArray,
+    Float16Array, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array,
+    LargeBinaryArray, LargeStringArray, RecordBatch, StringArray, StringViewArray, StructArray,
     Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
     TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
     TimestampSecondArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
@@ -80,6 +81,7 @@ enum Scenario {
     Time32Millisecond,
     Time64Nanosecond,
     Time64Microsecond,
+    Duration,
     /// 7 Rows, for each i8, i16, i32, i64, u8, u16, u32, u64, f32, f64
     /// -MIN, -100, -1, 0, 1, 100, MAX
     NumericLimits,
@@ -291,6 +293,35 @@ fn make_time32_batches(scenario: Scenario, v: Vec<i32>) -> RecordBatch {
     }
 }
 
+/// Return record batch with a column for each duration unit, all with values `v`
+///
+/// Columns are named:
+/// "duration_second" --> DurationSecondArray
+/// "duration_millisecond" --> DurationMillisecondArray
+/// "duration_microsecond" --> DurationMicrosecondArray
+/// "duration_nanosecond" --> DurationNanosecondArray
+fn make_duration_batch(v: Vec<Option<i64>>) -> RecordBatch {
+    RecordBatch::try_from_iter([
+        (
+            "duration_second",
+            Arc::new(DurationSecondArray::from(v.clone())) as ArrayRef,
+        ),
+        (
+            "duration_millisecond",
+            Arc::new(DurationMillisecondArray::from(v.clone())) as ArrayRef,
+        ),
+        (
+            "duration_microsecond",
+            Arc::new(DurationMicrosecondArray::from(v.clone())) as ArrayRef,
+        ),
+        (
+            "duration_nanosecond",
+            Arc::new(DurationNanosecondArray::from(v)) as ArrayRef,
+        ),
+    ])
+    .unwrap()
+}
+
 /// Return record batch with Time64Microsecond, Time64Nanosecond sequences
 fn make_time64_batches(scenario: Scenario, v: Vec<i64>) -> RecordBatch {
     match scenario {
@@ -907,6 +938,13 @@ fn create_data_batch(scenario: Scenario) -> Vec<RecordBatch> {
             )]));
             vec![RecordBatch::try_new(schema, vec![struct_array_data]).unwrap()]
         }
+        Scenario::Duration => {
+            vec![
+                make_duration_batch(vec![Some(-5), Some(-1), Some(0), None, Some(2)]),
+                make_duration_batch(vec![None, None, None, None, None]),
+                make_duration_batch(vec![Some(10), Some(20), Some(30), Some(40), Some(50)]),
+            ]
+        }
         Scenario::Time32Second => {
             vec![
                 make_time32_batches(Scenario::Time32Second, vec![18506, 18507, 18508, 18509]),
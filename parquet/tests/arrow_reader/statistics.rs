@@ -31,16 +31,17 @@ use arrow::datatypes::{
 };
 use arrow_array::{
     make_array, new_null_array, Array, ArrayRef, BinaryArray, BinaryViewArray, BooleanArray,
-    Date32Array, Date64Array, Decimal128Array, Decimal256Array, FixedSizeBinaryArray, Float16Array,
-    Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array, LargeBinaryArray,
-    LargeStringArray, RecordBatch, StringArray, StringViewArray, Time32MillisecondArray,
-    Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray, TimestampMicrosecondArray,
-    TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray, UInt16Array,
-    UInt32Array, UInt64Array, UInt8Array,
+    Date32Array, Date64Array, Decimal128Array, Decimal256Array, DurationMicrosecondArray,
+    DurationMillisecondArray, DurationNanosecondArray, DurationSecondArray, FixedSizeBinaryArray,
+    Float16Array, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array, Int8Array,
+    LargeBinaryArray, LargeStringArray, RecordBatch, StringArray, StringViewArray,
+    Time32MillisecondArray, Time32SecondArray, Time64MicrosecondArray, Time64NanosecondArray,
+    TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+    TimestampSecondArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
 };
-use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow_schema::{DataType, Field, IntervalUnit, Schema, SchemaRef, TimeUnit};
 use half::f16;
-use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+use parquet::arrow::arrow_reader::statistics::{RowGroupStatistics, StatisticsConverter};
 use parquet::arrow::arrow_reader::{
     ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
 };
@@ -1395,6 +1396,58 @@ async fn test_dates_32_diff_rg_sizes() {
     .run();
 }
 
+#[tokio::test]
+async fn test_duration() {
+    // This creates a parquet file of 4 columns, one per duration unit, each with 3 row
+    // groups of 5 rows, the second of which is all nulls
+    let reader = TestReader {
+        scenario: Scenario::Duration,
+        row_per_group: 5,
+    }
+    .build()
+    .await;
+
+    let min = vec![Some(-5), None, Some(10)];
+    let max = vec![Some(2), None, Some(50)];
+    let columns: [(&str, ArrayRef, ArrayRef); 4] = [
+        (
+            "duration_second",
+            Arc::new(DurationSecondArray::from(min.clone())),
+            Arc::new(DurationSecondArray::from(max.clone())),
+        ),
+        (
+            "duration_millisecond",
+            Arc::new(DurationMillisecondArray::from(min.clone())),
+            Arc::new(DurationMillisecondArray::from(max.clone())),
+        ),
+        (
+            "duration_microsecond",
+            Arc::new(DurationMicrosecondArray::from(min.clone())),
+            Arc::new(DurationMicrosecondArray::from(max.clone())),
+        ),
+        (
+            "duration_nanosecond",
+            Arc::new(DurationNanosecondArray::from(min)),
+            Arc::new(DurationNanosecondArray::from(max)),
+        ),
+    ];
+
+    for (column_name, expected_min, expected_max) in columns {
+        Test {
+            reader: &reader,
+            expected_min,
+            expected_max,
+            expected_null_counts: UInt64Array::from(vec![1, 5, 0]),
+            expected_row_counts: Some(UInt64Array::from(vec![5, 5, 5])),
+            expected_max_value_exact: BooleanArray::from(vec![true, false, true]),
+            expected_min_value_exact: BooleanArray::from(vec![true, false, true]),
+            column_name,
+            check: Check::Both,
+        }
+        .run();
+    }
+}
+
 #[tokio::test]
 async fn test_time32_second_diff_rg_sizes() {
     let reader = TestReader {
@@ -2468,6 +2521,117 @@ fn missing_null_counts_as_zero() {
     );
 }
 
+#[test]
+fn interval_statistics() {
+    // The sort order of INTERVAL is undefined, so the statistics written by other writers
+    // are ignored
+    fn interval(months: u32, days: u32, millis: u32) -> FixedLenByteArray {
+        let mut bytes = months.to_le_bytes().to_vec();
+        bytes.extend(days.to_le_bytes());
+        bytes.extend(millis.to_le_bytes());
+        bytes.into()
+    }
+
+    use parquet::basic::{ConvertedType, Type as PhysicalType};
+    use parquet::data_type::FixedLenByteArray;
+    use parquet::schema::types::Type as ParquetType;
+    let parquet_schema = ParquetType::group_type_builder("schema")
+        .with_fields(vec![Arc::new(
+            ParquetType::primitive_type_builder("i", PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                .with_converted_type(ConvertedType::INTERVAL)
+                .with_length(12)
+                .build()
+                .unwrap(),
+        )])
+        .build()
+        .unwrap();
+    let parquet_schema = Arc::new(SchemaDescriptor::new(Arc::new(parquet_schema)));
+
+    let row_groups: Vec<_> = [
+        Some((interval(1, 2, 3), interval(14, 0, 0))),
+        None,
+        Some((interval(0, 7, 86_399_999), interval(0, 30, 1))),
+    ]
+    .into_iter()
+    .map(|min_max| {
+        let (min, max) = min_max.unzip();
+        let stats =
+            Statistics::FixedLenByteArray(ValueStatistics::new(min, max, None, Some(0), false));
+        let column_chunk = ColumnChunkMetaData::builder(parquet_schema.column(0))
+            .set_statistics(stats)
+            .build()
+            .unwrap();
+        RowGroupMetaData::builder(parquet_schema.clone())
+            .set_column_metadata(vec![column_chunk])
+            .build()
+            .unwrap()
+    })
+    .collect();
+
+    for unit in [IntervalUnit::YearMonth, IntervalUnit::DayTime] {
+        let data_type = DataType::Interval(unit);
+        let schema = Schema::new(vec![Field::new("i", data_type.clone(), true)]);
+        let converter = StatisticsConverter::try_new("i", &schema, &parquet_schema).unwrap();
+        let expected = new_null_array(&data_type, 3);
+        let mins = converter.row_group_mins(&row_groups).unwrap();
+        assert_eq!(&mins, &expected);
+        let maxes = converter.row_group_maxes(&row_groups).unwrap();
+        assert_eq!(&maxes, &expected);
+    }
+}
+
+#[tokio::test]
+async fn test_row_group_statistics() {
+    // file has 20 rows, 4 row groups of 5 rows, with an additional column in the
+    // arrow schema that is not in the parquet file
+    let reader = TestReader {
+        scenario: Scenario::Int,
+        row_per_group: 5,
+    }
+    .build()
+    .await;
+    let mut fields = reader.schema().fields().to_vec();
+    fields.push(Arc::new(Field::new(
+        "i_do_not_exist",
+        DataType::Int64,
+        true,
+    )));
+    let schema = Schema::new(fields);
+    let row_groups = reader.metadata().row_groups();
+
+    let statistics =
+        RowGroupStatistics::try_new(&schema, reader.parquet_schema(), row_groups).unwrap();
+    assert_eq!(statistics.num_row_groups(), 4);
+    assert_eq!(
+        statistics.row_counts(),
+        &UInt64Array::from(vec![5, 5, 5, 5])
+    );
+    assert_eq!(statistics.columns().count(), 5);
+    for (field, column) in statistics.columns() {
+        let converter =
+            StatisticsConverter::try_new(field.name(), &schema, reader.parquet_schema()).unwrap();
+        assert_eq!(
+            column.mins(),
+            &converter.row_group_mins(row_groups).unwrap()
+        );
+        assert_eq!(
+            column.maxes(),
+            &converter.row_group_maxes(row_groups).unwrap()
+        );
+        assert_eq!(
+            column.null_counts(),
+            &converter.row_group_null_counts(row_groups).unwrap()
+        );
+    }
+
+    let i32 = statistics.column("i32").unwrap();
+    let expected: ArrayRef = Arc::new(Int32Array::from(vec![-5, -4, 0, 5]));
+    assert_eq!(i32.mins(), &expected);
+    let missing = statistics.column("i_do_not_exist").unwrap();
+    assert_eq!(missing.maxes().null_count(), 4);
+    assert!(statistics.column("not_a_column").is_none());
+}
+
 /// return an Arrow schema and corresponding Parquet SchemaDescriptor for
 /// a schema with a single boolean column "b"
 fn bool_arrow_and_parquet_schema() -> (SchemaRef, SchemaDescPtr) {
@@ -34,7 +34,7 @@ use parquet::encryption::decrypt::FileDecryptionProperties;
 use parquet::encryption::encrypt::FileEncryptionProperties;
 use parquet::errors::ParquetError;
 use parquet::file::metadata::ParquetMetaData;
-use parquet::file::properties::WriterProperties;
+use parquet::file::properties::{PageIndexPosition, WriterProperties};
 use parquet::file::writer::SerializedFileWriter;
 use parquet::schema::parser::parse_message_type;
 use std::fs::File;
@@ -398,27 +398,33 @@ fn row_group_sizes(metadata: &ParquetMetaData) -> Vec<i64> {
 
 #[test]
 fn test_uniform_encryption_roundtrip() {
-    uniform_encryption_roundtrip(false, false).unwrap();
+    uniform_encryption_roundtrip(false, false, PageIndexPosition::End).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_dictionary() {
-    uniform_encryption_roundtrip(false, true).unwrap();
+    uniform_encryption_roundtrip(false, true, PageIndexPosition::End).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_page_index() {
-    uniform_encryption_roundtrip(true, false).unwrap();
+    uniform_encryption_roundtrip(true, false, PageIndexPosition::End).unwrap();
+}
+
+#[test]
+fn test_uniform_encryption_roundtrip_with_page_index_after_row_group() {
+    uniform_encryption_roundtrip(true, false, PageIndexPosition::AfterRowGroup).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_page_index_and_dictionary() {
-    uniform_encryption_roundtrip(true, true).unwrap();
+    uniform_encryption_roundtrip(true, true, PageIndexPosition::End).unwrap();
 }
 
 fn uniform_encryption_roundtrip(
     page_index: bool,
     dictionary_encoding: bool,
+    page_index_position: PageIndexPosition,
 ) -> parquet::errors::Result<()> {
     let x0_arrays = [
         Int32Array::from((0..100).collect::<Vec<_>>()),
@@ -447,6 +453,7 @@ fn uniform_encryption_roundtrip(
         .set_write_batch_size(20)
         .set_data_page_row_count_limit(20)
         .set_dictionary_enabled(dictionary_encoding)
+        .set_page_index_position(page_index_position)
         .with_file_encryption_properties(file_encryption_properties)
         .build();
 